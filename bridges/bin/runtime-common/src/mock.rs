@@ -183,6 +183,7 @@ impl pallet_bridge_grandpa::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = BridgedUnderlyingChain;
 	type MaxFreeMandatoryHeadersPerBlock = ConstU32<4>;
+	type FreeHeadersPolicy = pallet_bridge_grandpa::MandatoryHeaders;
 	type HeadersToKeep = ConstU32<8>;
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<TestRuntime>;
 }
@@ -0,0 +1,94 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Policies deciding which bridged headers may be submitted for free.
+
+use frame_support::traits::Get;
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+/// A policy deciding which bridged headers may be imported for free (with `Pays::No`).
+///
+/// Free imports are always limited by `MaxFreeMandatoryHeadersPerBlock`. Non-mandatory headers
+/// are additionally only free while the submitting relayer has some free headers allowance left.
+pub trait FreeHeadersPolicy<BlockNumber> {
+	/// Returns `true` if the import of the header `number` may be free.
+	///
+	/// `best_finalized` is the number of the best finalized header before the import and
+	/// `is_mandatory` is `true` if the header changes the GRANDPA authorities set.
+	fn is_free(number: BlockNumber, best_finalized: BlockNumber, is_mandatory: bool) -> bool;
+}
+
+/// Only mandatory headers may be imported for free.
+pub struct MandatoryHeaders;
+
+impl<BlockNumber> FreeHeadersPolicy<BlockNumber> for MandatoryHeaders {
+	fn is_free(_number: BlockNumber, _best_finalized: BlockNumber, is_mandatory: bool) -> bool {
+		is_mandatory
+	}
+}
+
+/// Mandatory headers and headers at least `Interval` blocks ahead of the best finalized header
+/// may be imported for free.
+pub struct EveryNthHeader<Interval>(PhantomData<Interval>);
+
+impl<BlockNumber, Interval> FreeHeadersPolicy<BlockNumber> for EveryNthHeader<Interval>
+where
+	BlockNumber: PartialOrd + Saturating,
+	Interval: Get<BlockNumber>,
+{
+	fn is_free(number: BlockNumber, best_finalized: BlockNumber, is_mandatory: bool) -> bool {
+		is_mandatory || number >= best_finalized.saturating_add(Interval::get())
+	}
+}
+
+/// Mandatory headers may be imported for free, and other headers while `Pending` returns `true`,
+/// e.g. while there are messages waiting to be relayed that require a newer header.
+pub struct OnDemandHeaders<Pending>(PhantomData<Pending>);
+
+impl<BlockNumber, Pending: Get<bool>> FreeHeadersPolicy<BlockNumber> for OnDemandHeaders<Pending> {
+	fn is_free(_number: BlockNumber, _best_finalized: BlockNumber, is_mandatory: bool) -> bool {
+		is_mandatory || Pending::get()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::traits::{ConstBool, ConstU64};
+
+	#[test]
+	fn mandatory_headers_policy_works() {
+		assert!(MandatoryHeaders::is_free(11u64, 10, true));
+		assert!(!MandatoryHeaders::is_free(100u64, 10, false));
+	}
+
+	#[test]
+	fn every_nth_header_policy_works() {
+		type Policy = EveryNthHeader<ConstU64<5>>;
+		assert!(Policy::is_free(11u64, 10, true));
+		assert!(!Policy::is_free(14u64, 10, false));
+		assert!(Policy::is_free(15u64, 10, false));
+		assert!(Policy::is_free(u64::MAX, u64::MAX - 1, false));
+	}
+
+	#[test]
+	fn on_demand_headers_policy_works() {
+		assert!(OnDemandHeaders::<ConstBool<false>>::is_free(11u64, 10, true));
+		assert!(!OnDemandHeaders::<ConstBool<false>>::is_free(11u64, 10, false));
+		assert!(OnDemandHeaders::<ConstBool<true>>::is_free(11u64, 10, false));
+	}
+}
@@ -52,6 +52,7 @@ use sp_runtime::{
 use sp_std::{boxed::Box, convert::TryInto, prelude::*};
 
 mod call_ext;
+mod free_headers;
 #[cfg(test)]
 mod mock;
 mod storage_types;
@@ -64,6 +65,7 @@ pub mod benchmarking;
 
 // Re-export in crate namespace for `construct_runtime!`
 pub use call_ext::*;
+pub use free_headers::{EveryNthHeader, FreeHeadersPolicy, MandatoryHeaders, OnDemandHeaders};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -114,6 +116,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxFreeMandatoryHeadersPerBlock: Get<u32>;
 
+		/// Policy deciding which headers may be submitted for free.
+		///
+		/// All free headers count towards the `MaxFreeMandatoryHeadersPerBlock` limit. Free
+		/// non-mandatory headers also consume the [`FreeHeadersAllowance`] of the relayer that
+		/// submits them. Use [`MandatoryHeaders`] to only accept mandatory headers for free.
+		type FreeHeadersPolicy: FreeHeadersPolicy<BridgedBlockNumber<Self, I>>;
+
 		/// Maximal number of finalized headers to keep in the storage.
 		///
 		/// The setting is there to prevent growing the on-chain state indefinitely. Note
@@ -170,7 +179,7 @@ pub mod pallet {
 		///
 		/// - header contains forced authorities set change or change with non-zero delay.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T::WeightInfo as WeightInfo>::submit_finality_proof(
+		#[pallet::weight(submit_finality_proof_weight::<T, I>(
 			justification.commit.precommits.len().saturated_into(),
 			justification.votes_ancestries.len().saturated_into(),
 		))]
@@ -180,7 +189,7 @@ pub mod pallet {
 			justification: GrandpaJustification<BridgedHeader<T, I>>,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
-			ensure_signed(origin)?;
+			let relayer = ensure_signed(origin)?;
 
 			let (hash, number) = (finality_target.hash(), *finality_target.number());
 			log::trace!(
@@ -190,6 +199,9 @@ pub mod pallet {
 			);
 
 			SubmitFinalityProofHelper::<T, I>::check_obsolete(number)?;
+			// `check_obsolete` makes sure that the pallet is initialized
+			let best_finalized =
+				<BestFinalized<T, I>>::get().map(|id| id.number()).unwrap_or_else(Zero::zero);
 
 			let authority_set = <CurrentAuthoritySet<T, I>>::get();
 			let unused_proof_size = authority_set.unused_proof_size();
@@ -199,9 +211,13 @@ pub mod pallet {
 
 			let maybe_new_authority_set =
 				try_enact_authority_change::<T, I>(&finality_target, set_id)?;
-			let may_refund_call_fee = maybe_new_authority_set.is_some() &&
-				// if we have seen too many mandatory headers in this block, we don't want to refund
+			let is_mandatory = maybe_new_authority_set.is_some();
+			let may_refund_call_fee =
+				T::FreeHeadersPolicy::is_free(number, best_finalized, is_mandatory) &&
+				// if we have seen too many free headers in this block, we don't want to refund
 				Self::free_mandatory_headers_remaining() > 0 &&
+				// if the relayer has used up its allowance, we don't refund non-mandatory headers
+				(is_mandatory || Self::free_headers_allowance(&relayer) > 0) &&
 				// if arguments out of expected bounds, we don't want to refund
 				submit_finality_proof_info_from_args::<T, I>(&finality_target, &justification)
 					.fits_limits();
@@ -209,6 +225,11 @@ pub mod pallet {
 				FreeMandatoryHeadersRemaining::<T, I>::mutate(|count| {
 					*count = count.saturating_sub(1)
 				});
+				if !is_mandatory {
+					FreeHeadersAllowance::<T, I>::mutate(&relayer, |allowance| {
+						*allowance = allowance.saturating_sub(1)
+					});
+				}
 			}
 			insert_header::<T, I>(*finality_target, hash);
 			log::info!(
@@ -221,7 +242,8 @@ pub mod pallet {
 			// further without importing this header. So every bridge MUST import mandatory headers.
 			//
 			// We don't want to charge extra costs for mandatory operations. So relayer is not
-			// paying fee for mandatory headers import transactions.
+			// paying fee for mandatory headers import transactions. Other headers are free if the
+			// `FreeHeadersPolicy` allows it and the relayer has some free headers allowance left.
 			//
 			// If size/weight of the call is exceeds our estimated limits, the relayer still needs
 			// to pay for the transaction.
@@ -231,7 +253,7 @@ pub mod pallet {
 			// `MaxBridgedAuthorities` in the `CurrentAuthoritySet` (we use `MaxEncodedLen`
 			// estimation). But if their number is lower, then we may "refund" some `proof_size`,
 			// making proof smaller and leaving block space to other useful transactions
-			let pre_dispatch_weight = T::WeightInfo::submit_finality_proof(
+			let pre_dispatch_weight = submit_finality_proof_weight::<T, I>(
 				justification.commit.precommits.len().saturated_into(),
 				justification.votes_ancestries.len().saturated_into(),
 			);
@@ -300,12 +322,31 @@ pub mod pallet {
 		) -> DispatchResult {
 			<Self as OwnedBridgeModule<_>>::set_operating_mode(origin, operating_mode)
 		}
+
+		/// Set the number of non-mandatory headers `relayer` may submit for free.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
+		pub fn set_free_headers_allowance(
+			origin: OriginFor<T>,
+			relayer: T::AccountId,
+			allowance: u32,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+			if allowance == 0 {
+				FreeHeadersAllowance::<T, I>::remove(&relayer);
+			} else {
+				FreeHeadersAllowance::<T, I>::insert(&relayer, allowance);
+			}
+			Ok(())
+		}
 	}
 
-	/// Number mandatory headers that we may accept in the current block for free (returning
+	/// Number of headers that we may accept in the current block for free (returning
 	/// `Pays::No`).
 	///
-	/// If the `FreeMandatoryHeadersRemaining` hits zero, all following mandatory headers in the
+	/// If the `FreeMandatoryHeadersRemaining` hits zero, all following headers in the
 	/// current block are accepted with fee (`Pays::Yes` is returned).
 	///
 	/// The `FreeMandatoryHeadersRemaining` is an ephemeral value that is set to
@@ -317,6 +358,14 @@ pub mod pallet {
 	pub(super) type FreeMandatoryHeadersRemaining<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, u32, ValueQuery>;
 
+	/// Number of non-mandatory headers that relayers may still submit for free.
+	///
+	/// Only headers which are free according to the `FreeHeadersPolicy` consume the allowance.
+	#[pallet::storage]
+	#[pallet::getter(fn free_headers_allowance)]
+	pub type FreeHeadersAllowance<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	/// Hash of the header used to bootstrap the pallet.
 	#[pallet::storage]
 	pub(super) type InitialHash<T: Config<I>, I: 'static = ()> =
@@ -491,6 +540,19 @@ pub mod pallet {
 		Ok(None)
 	}
 
+	/// The weight of the `submit_finality_proof` call with `precommits` precommits and
+	/// `votes_ancestries` headers in the votes ancestries of its justification.
+	///
+	/// On top of the benchmarked weight, it includes reading and updating the
+	/// [`FreeHeadersAllowance`] of the relayer, which the benchmark does not exercise.
+	pub(crate) fn submit_finality_proof_weight<T: Config<I>, I: 'static>(
+		precommits: u32,
+		votes_ancestries: u32,
+	) -> Weight {
+		T::WeightInfo::submit_finality_proof(precommits, votes_ancestries)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
 	/// Verify a GRANDPA justification (finality proof) for a given header.
 	///
 	/// Will use the GRANDPA current authorities known to the pallet.
@@ -654,8 +716,8 @@ pub fn initialize_for_benchmarks<T: Config<I>, I: 'static>(header: BridgedHeader
 mod tests {
 	use super::*;
 	use crate::mock::{
-		run_test, test_header, RuntimeEvent as TestEvent, RuntimeOrigin, System, TestBridgedChain,
-		TestHeader, TestNumber, TestRuntime, MAX_BRIDGED_AUTHORITIES,
+		run_test, test_header, FreeHeadersPending, RuntimeEvent as TestEvent, RuntimeOrigin, System,
+		TestBridgedChain, TestHeader, TestNumber, TestRuntime, MAX_BRIDGED_AUTHORITIES,
 	};
 	use bp_header_chain::BridgeGrandpaCall;
 	use bp_runtime::BasicOperatingMode;
@@ -901,7 +963,7 @@ mod tests {
 			let header = test_header(header_number.into());
 			let justification = make_default_justification(&header);
 
-			let pre_dispatch_weight = <TestRuntime as Config>::WeightInfo::submit_finality_proof(
+			let pre_dispatch_weight = submit_finality_proof_weight::<TestRuntime, ()>(
 				justification.commit.precommits.len().try_into().unwrap_or(u32::MAX),
 				justification.votes_ancestries.len().try_into().unwrap_or(u32::MAX),
 			);
@@ -1359,6 +1421,53 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn free_headers_policy_and_allowance_are_respected() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			FreeHeadersPending::set(true);
+
+			// without allowance, non-mandatory headers are not free
+			let result = submit_finality_proof(1);
+			assert_eq!(result.expect("call failed").pays_fee, Pays::Yes);
+
+			// only root or the owner may change the allowance
+			assert_noop!(
+				Pallet::<TestRuntime>::set_free_headers_allowance(RuntimeOrigin::signed(1), 1, 1),
+				DispatchError::BadOrigin,
+			);
+			assert_ok!(Pallet::<TestRuntime>::set_free_headers_allowance(
+				RuntimeOrigin::root(),
+				1,
+				1
+			));
+
+			// free non-mandatory headers consume the allowance
+			let result = submit_finality_proof(2);
+			assert_eq!(result.expect("call failed").pays_fee, Pays::No);
+			assert_eq!(Pallet::<TestRuntime>::free_headers_allowance(1), 0);
+
+			let result = submit_finality_proof(3);
+			assert_eq!(result.expect("call failed").pays_fee, Pays::Yes);
+
+			// headers that the policy doesn't accept for free are paid, whatever the allowance
+			assert_ok!(Pallet::<TestRuntime>::set_free_headers_allowance(
+				RuntimeOrigin::root(),
+				1,
+				1
+			));
+			FreeHeadersPending::set(false);
+			let result = submit_finality_proof(4);
+			assert_eq!(result.expect("call failed").pays_fee, Pays::Yes);
+			assert_eq!(Pallet::<TestRuntime>::free_headers_allowance(1), 1);
+
+			// mandatory headers don't consume the allowance
+			let result = submit_mandatory_finality_proof(5, 1);
+			assert_eq!(result.expect("call failed").pays_fee, Pays::No);
+			assert_eq!(Pallet::<TestRuntime>::free_headers_allowance(1), 1);
+		})
+	}
+
 	#[test]
 	fn should_prune_headers_over_headers_to_keep_parameter() {
 		run_test(|| {
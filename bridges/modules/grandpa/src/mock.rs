@@ -50,12 +50,14 @@ impl frame_system::Config for TestRuntime {
 parameter_types! {
 	pub const MaxFreeMandatoryHeadersPerBlock: u32 = 2;
 	pub const HeadersToKeep: u32 = 5;
+	pub static FreeHeadersPending: bool = false;
 }
 
 impl grandpa::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxFreeMandatoryHeadersPerBlock = MaxFreeMandatoryHeadersPerBlock;
+	type FreeHeadersPolicy = grandpa::OnDemandHeaders<FreeHeadersPending>;
 	type HeadersToKeep = HeadersToKeep;
 	type WeightInfo = ();
 }
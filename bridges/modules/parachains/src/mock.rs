@@ -166,6 +166,7 @@ impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance1> for TestRun
 	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxFreeMandatoryHeadersPerBlock = ConstU32<2>;
+	type FreeHeadersPolicy = pallet_bridge_grandpa::MandatoryHeaders;
 	type HeadersToKeep = HeadersToKeep;
 	type WeightInfo = ();
 }
@@ -174,6 +175,7 @@ impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance2> for TestRun
 	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxFreeMandatoryHeadersPerBlock = ConstU32<2>;
+	type FreeHeadersPolicy = pallet_bridge_grandpa::MandatoryHeaders;
 	type HeadersToKeep = HeadersToKeep;
 	type WeightInfo = ();
 }
@@ -330,6 +330,14 @@ pub struct RunCmd {
 	/// Will use the specified relay chain chainspec.
 	#[arg(long, conflicts_with_all = ["relay_chain_rpc_urls", "collator"])]
 	pub relay_chain_light_client: bool,
+
+	/// Disable the parachain informant.
+	///
+	/// By default the node logs, for every new best relay chain block, the relay chain best and
+	/// finalized blocks, whether the core of the parachain is occupied by a candidate pending
+	/// availability and the last backed candidate of the parachain.
+	#[arg(long)]
+	pub no_parachain_informant: bool,
 }
 
 impl RunCmd {
@@ -352,7 +360,7 @@ impl RunCmd {
 				_ => RelayChainMode::Embedded,
 			};
 
-		CollatorOptions { relay_chain_mode }
+		CollatorOptions { relay_chain_mode, parachain_informant: !self.no_parachain_informant }
 	}
 }
 
@@ -372,6 +380,8 @@ pub enum RelayChainMode {
 pub struct CollatorOptions {
 	/// How this collator retrieves relay chain information
 	pub relay_chain_mode: RelayChainMode,
+	/// Whether to log the state of the parachain on the relay chain
+	pub parachain_informant: bool,
 }
 
 /// A non-redundant version of the `RunCmd` that sets the `validator` field when the
@@ -474,6 +484,10 @@ impl sc_cli::CliConfiguration for NormalizedRunCmd {
 		self.base.max_runtime_instances()
 	}
 
+	fn min_runtime_instances(&self) -> sc_cli::Result<Option<usize>> {
+		self.base.min_runtime_instances()
+	}
+
 	fn runtime_cache_size(&self) -> sc_cli::Result<u8> {
 		self.base.runtime_cache_size()
 	}
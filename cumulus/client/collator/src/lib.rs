@@ -17,7 +17,7 @@
 //! Cumulus Collator implementation for Substrate.
 
 use cumulus_primitives_core::{
-	relay_chain::Hash as PHash, CollectCollationInfo, PersistedValidationData,
+	relay_chain::Hash as PHash, CollectCollationInfo, ParachainBlockData, PersistedValidationData,
 };
 
 use sc_client_api::BlockBackend;
@@ -26,7 +26,10 @@ use sp_core::traits::SpawnNamed;
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
 
 use cumulus_client_consensus_common::ParachainConsensus;
-use polkadot_node_primitives::{CollationGenerationConfig, CollationResult, MaybeCompressedPoV};
+use polkadot_node_primitives::{
+	Collation, CollationGenerationConfig, CollationPreValidationFn, CollationResult,
+	MaybeCompressedPoV, POV_BOMB_LIMIT,
+};
 use polkadot_node_subsystem::messages::{CollationGenerationMessage, CollatorProtocolMessage};
 use polkadot_overseer::Handle as OverseerHandle;
 use polkadot_primitives::{CollatorPair, Id as ParaId};
@@ -155,7 +158,9 @@ pub mod relay_chain_driven {
 		channel::{mpsc, oneshot},
 		prelude::*,
 	};
-	use polkadot_node_primitives::{CollationGenerationConfig, CollationResult};
+	use polkadot_node_primitives::{
+		CollationGenerationConfig, CollationPreValidationFn, CollationResult,
+	};
 	use polkadot_node_subsystem::messages::{CollationGenerationMessage, CollatorProtocolMessage};
 	use polkadot_overseer::Handle as OverseerHandle;
 	use polkadot_primitives::{CollatorPair, Id as ParaId};
@@ -190,10 +195,14 @@ pub mod relay_chain_driven {
 
 	/// Initialize the collator with Polkadot's collation-generation
 	/// subsystem, returning a stream of collation requests to handle.
+	///
+	/// Collations are only advertised if they pass `pre_validation`, if given. See
+	/// [`check_block_data`](super::check_block_data) for a check suitable for most collators.
 	pub async fn init(
 		key: CollatorPair,
 		para_id: ParaId,
 		overseer_handle: OverseerHandle,
+		pre_validation: Option<CollationPreValidationFn>,
 	) -> mpsc::Receiver<CollationRequest> {
 		let mut overseer_handle = overseer_handle;
 
@@ -220,6 +229,7 @@ pub mod relay_chain_driven {
 					this_rx.await.ok().flatten()
 				})
 			})),
+			pre_validation,
 		};
 
 		overseer_handle
@@ -238,10 +248,14 @@ pub mod relay_chain_driven {
 ///
 /// This must be done prior to collation, and does not set up any callback for collation.
 /// For callback-driven collators, use the [`relay_chain_driven`] module.
+///
+/// Submitted collations are only advertised if they pass `pre_validation`, if given. See
+/// [`check_block_data`] for a check suitable for most collators.
 pub async fn initialize_collator_subsystems(
 	overseer_handle: &mut OverseerHandle,
 	key: CollatorPair,
 	para_id: ParaId,
+	pre_validation: Option<CollationPreValidationFn>,
 ) {
 	overseer_handle
 		.send_msg(
@@ -249,6 +263,7 @@ pub async fn initialize_collator_subsystems(
 				key,
 				para_id,
 				collator: None,
+				pre_validation,
 			}),
 			"StartCollator",
 		)
@@ -313,7 +328,13 @@ pub fn start_collator_sync<Block, RA, BS, Spawner>(
 	let collator = Collator::new(collator_service, parachain_consensus);
 
 	let collation_future = Box::pin(async move {
-		let mut request_stream = relay_chain_driven::init(key, para_id, overseer_handle).await;
+		let mut request_stream = relay_chain_driven::init(
+			key,
+			para_id,
+			overseer_handle,
+			Some(check_block_data::<Block>()),
+		)
+		.await;
 		while let Some(request) = request_stream.next().await {
 			let collation = collator
 				.clone()
@@ -330,12 +351,51 @@ pub fn start_collator_sync<Block, RA, BS, Spawner>(
 	spawner.spawn("cumulus-relay-driven-collator", None, collation_future);
 }
 
+/// A [`CollationPreValidationFn`] checking that the block in the PoV of a collation can be decoded,
+/// builds on the parent head of the validation data and matches the head data of the collation.
+///
+/// These are cheap checks catching collations which would certainly fail validation, e.g. because
+/// they were built on a parent the relay chain does not consider the head of the parachain.
+pub fn check_block_data<Block: BlockT>() -> CollationPreValidationFn {
+	Box::new(|_, validation_data, collation| {
+		let result = check_block_data_inner::<Block>(validation_data, collation);
+		async move { result }.boxed()
+	})
+}
+
+fn check_block_data_inner<Block: BlockT>(
+	validation_data: &PersistedValidationData,
+	collation: &Collation,
+) -> Result<(), String> {
+	let pov = match &collation.proof_of_validity {
+		MaybeCompressedPoV::Raw(pov) | MaybeCompressedPoV::Compressed(pov) => pov,
+	};
+	let block_data = sp_maybe_compressed_blob::decompress(&pov.block_data.0, POV_BOMB_LIMIT)
+		.map_err(|e| format!("Failed to decompress the block data: {}", e))?;
+	let block_data = ParachainBlockData::<Block>::decode(&mut &block_data[..])
+		.map_err(|e| format!("Failed to decode the block data: {}", e))?;
+	let parent = Block::Header::decode(&mut &validation_data.parent_head.0[..])
+		.map_err(|e| format!("Failed to decode the parent head: {}", e))?;
+
+	let header = block_data.header();
+	if *header.parent_hash() != parent.hash() {
+		return Err(format!(
+			"Block builds on {:?}, but the parent head is {:?}",
+			header.parent_hash(),
+			parent.hash(),
+		))
+	}
+	if collation.head_data.0 != header.encode() {
+		return Err("Head data does not match the header of the block".into())
+	}
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use async_trait::async_trait;
 	use cumulus_client_consensus_common::ParachainCandidate;
-	use cumulus_primitives_core::ParachainBlockData;
 	use cumulus_test_client::{
 		Client, ClientBlockImportExt, DefaultTestClientBuilderExt, InitBlockBuilder,
 		TestClientBuilder, TestClientBuilderExt,
@@ -434,11 +494,12 @@ mod tests {
 			.0
 			.expect("message should be send by `start_collator` above.");
 
-		let collator_fn = match msg {
+		let (collator_fn, pre_validation) = match msg {
 			CollationGenerationMessage::Initialize(CollationGenerationConfig {
 				collator: Some(c),
+				pre_validation: Some(p),
 				..
-			}) => c,
+			}) => (c, p),
 			_ => panic!("unexpected message or no collator fn"),
 		};
 
@@ -450,6 +511,15 @@ mod tests {
 			.expect("Collation is build")
 			.collation;
 
+		// The collation passes pre-validation against the parent it was built on only.
+		assert!(block_on(pre_validation(relay_parent, &validation_data, &collation)).is_ok());
+		let other_validation_data = PersistedValidationData {
+			parent_head: collation.head_data.clone(),
+			..Default::default()
+		};
+		let result = block_on(pre_validation(relay_parent, &other_validation_data, &collation));
+		assert!(result.is_err());
+
 		let pov = collation.proof_of_validity.into_compressed();
 
 		let decompressed =
@@ -123,6 +123,7 @@ where
 					params.collator_key,
 					params.para_id,
 					params.overseer_handle,
+					Some(cumulus_client_collator::check_block_data::<Block>()),
 				)
 				.await,
 		};
@@ -21,6 +21,10 @@
 //! The size of the backlog is determined by invoking a runtime API. If that runtime API
 //! is not supported, this assumes a maximum backlog size of 1.
 //!
+//! The number of blocks built per relay-chain block follows the authoring velocity the runtime
+//! exposes, which is read anew for every relay parent, so that changes to it are honored
+//! without restarting the node. If the runtime does not expose it, a velocity of 1 is assumed.
+//!
 //! This takes more advantage of asynchronous backing, though not complete advantage.
 //! When the backlog is not saturated, this approach lets the backlog temporarily 'catch up'
 //! with periods of higher throughput. When the backlog is saturated, we typically
@@ -55,7 +59,7 @@ use futures::{channel::oneshot, prelude::*};
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf};
 use sc_consensus::BlockImport;
 use sc_consensus_aura::standalone as aura_internal;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_application_crypto::AppPublic;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::SyncOracle;
@@ -149,6 +153,7 @@ where
 			&mut params.overseer_handle,
 			params.collator_key,
 			params.para_id,
+			Some(cumulus_client_collator::check_block_data::<Block>()),
 		)
 		.await;
 
@@ -295,9 +300,8 @@ where
 			let mut parent_header = initial_parent.header;
 			let overseer_handle = &mut params.overseer_handle;
 
-			// This needs to change to support elastic scaling, but for continuously
-			// scheduled chains this ensures that the backlog will grow steadily.
-			for n_built in 0..2 {
+			let max_blocks = max_blocks_per_relay_parent::<Block, _>(para_client, parent_hash);
+			for n_built in 0..max_blocks {
 				let slot_claim = match can_build_upon(parent_hash).await {
 					None => break,
 					Some(c) => c,
@@ -306,7 +310,8 @@ where
 				tracing::debug!(
 					target: crate::LOG_TARGET,
 					?relay_parent,
-					unincluded_segment_len = initial_parent.depth + n_built,
+					unincluded_segment_len = initial_parent.depth + n_built as usize,
+					max_blocks,
 					"Slot claimed. Building"
 				);
 
@@ -430,6 +435,48 @@ where
 	Some(SlotClaim::unchecked::<P>(author_pub, slot, timestamp))
 }
 
+/// The maximum number of blocks to build for a single relay parent, on top of the given block.
+///
+/// This is one more than the authoring velocity, so that for continuously scheduled chains the
+/// backlog will grow steadily.
+fn max_blocks_per_relay_parent<Block: BlockT, Client>(client: &Client, at: Block::Hash) -> u32
+where
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: AuraUnincludedSegmentApi<Block>,
+{
+	authoring_velocity::<Block, _>(client, at).saturating_add(1)
+}
+
+/// Reads the authoring velocity, i.e. the number of blocks to build per relay-chain block, from
+/// the runtime at the given block.
+///
+/// Falls back to 1 for runtimes which do not expose it, or in case of an error.
+fn authoring_velocity<Block: BlockT, Client>(client: &Client, at: Block::Hash) -> u32
+where
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: AuraUnincludedSegmentApi<Block>,
+{
+	let runtime_api = client.runtime_api();
+	let velocity = match runtime_api.api_version::<dyn AuraUnincludedSegmentApi<Block>>(at) {
+		Ok(Some(version)) if version >= 2 => runtime_api.authoring_velocity(at),
+		Ok(_) => return 1,
+		Err(err) => Err(err),
+	};
+
+	match velocity {
+		Ok(velocity) => velocity.max(1),
+		Err(err) => {
+			tracing::error!(
+				target: crate::LOG_TARGET,
+				?err,
+				?at,
+				"Failed to query authoring velocity runtime API",
+			);
+			1
+		},
+	}
+}
+
 /// Reads allowed ancestry length parameter from the relay chain storage at the given relay parent.
 ///
 /// Falls back to 0 in case of an error.
@@ -495,3 +542,65 @@ async fn is_para_scheduled(
 
 	cores.iter().any(|core| core.para_id() == Some(para_id))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_api::{mock_impl_runtime_apis, ApiError, ApiRef};
+	use sp_core::H256;
+	use sp_runtime::testing::{Block as RawBlock, ExtrinsicWrapper};
+
+	type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+	/// The block at which the runtime raises the authoring velocity from 1 to 3, e.g. because
+	/// governance changed it.
+	const VELOCITY_RAISED_AT: u64 = 3;
+
+	/// The hash of the parachain block with the given number.
+	fn block_hash(number: u64) -> H256 {
+		H256::from_low_u64_be(number)
+	}
+
+	struct TestClient;
+
+	#[derive(Clone)]
+	struct TestApi;
+
+	impl ProvideRuntimeApi<Block> for TestClient {
+		type Api = TestApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			TestApi.into()
+		}
+	}
+
+	mock_impl_runtime_apis! {
+		impl AuraUnincludedSegmentApi<Block> for TestApi {
+			fn can_build_upon(_included_hash: H256, _slot: Slot) -> bool {
+				true
+			}
+
+			#[advanced]
+			fn authoring_velocity(&self, at: H256) -> Result<u32, ApiError> {
+				Ok(if at.to_low_u64_be() >= VELOCITY_RAISED_AT { 3 } else { 1 })
+			}
+		}
+	}
+
+	#[test]
+	fn blocks_per_slot_follow_velocity_changes() {
+		// The same client is used throughout, as a running node would.
+		let client = TestClient;
+		let mut best_number = 0;
+		let mut built_per_slot = Vec::new();
+		for _slot in 0..4 {
+			let built = max_blocks_per_relay_parent::<Block, _>(&client, block_hash(best_number));
+			best_number += u64::from(built);
+			built_per_slot.push(built);
+		}
+
+		// Two blocks per slot at velocity 1, until the runtime raises it to 3 at block 3. The
+		// slot building upon block 4 already builds four blocks.
+		assert_eq!(built_per_slot, vec![2, 2, 4, 4]);
+	}
+}
@@ -0,0 +1,76 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Health check of the collation of the parachain, reported by the `system_healthReport` RPC.
+
+use parking_lot::Mutex;
+use sc_rpc::system::{HealthCheck, HealthStatus, SubsystemHealth};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+/// The time the best parachain block may stay the same before the collation is reported as
+/// degraded.
+const MAX_STALL: Duration = Duration::from_secs(120);
+
+/// Reports the collation of the parachain as degraded if the best parachain block has not
+/// advanced for a while.
+///
+/// Parachain blocks only become the best block once their candidate is included in the relay
+/// chain, so a stalled best block means that no collation of the parachain is being included.
+pub struct CollationHealth<Block: BlockT, Client> {
+	client: Arc<Client>,
+	/// The last best block seen, and when it was first seen.
+	last_best: Mutex<(NumberFor<Block>, Instant)>,
+}
+
+impl<Block: BlockT, Client: HeaderBackend<Block>> CollationHealth<Block, Client> {
+	/// Check the collation of the parachain of `client`.
+	pub fn new(client: Arc<Client>) -> Self {
+		let best_number = client.info().best_number;
+		CollationHealth { client, last_best: Mutex::new((best_number, Instant::now())) }
+	}
+}
+
+impl<Block, Client> HealthCheck for CollationHealth<Block, Client>
+where
+	Block: BlockT,
+	Client: HeaderBackend<Block> + Send + Sync,
+{
+	fn check(&self) -> SubsystemHealth {
+		let best_number = self.client.info().best_number;
+		let mut last_best = self.last_best.lock();
+		if last_best.0 != best_number {
+			*last_best = (best_number, Instant::now());
+		}
+		let stalled_for = last_best.1.elapsed();
+		SubsystemHealth {
+			name: "collation".into(),
+			status: if stalled_for > MAX_STALL {
+				HealthStatus::Degraded
+			} else {
+				HealthStatus::Healthy
+			},
+			details: serde_json::json!({
+				"bestBlock": best_number,
+				"secondsSinceBestBlock": stalled_for.as_secs(),
+			}),
+		}
+	}
+}
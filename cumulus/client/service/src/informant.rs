@@ -0,0 +1,117 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Informant reporting the state of the parachain on the relay chain.
+//!
+//! The Substrate informant only knows about the local chain. This one complements it with a
+//! status line per new best relay chain block, showing the relay chain heights, whether the core
+//! of the parachain is occupied by a candidate pending availability and the last backed candidate.
+
+use cumulus_primitives_core::{
+	relay_chain::{BlockId, BlockNumber as RBlockNumber},
+	ParaId,
+};
+use cumulus_relay_chain_interface::{PHash, PHeader, RelayChainInterface, RelayChainResult};
+use futures::StreamExt;
+use sc_telemetry::log;
+use sp_runtime::traits::Header as _;
+
+const LOG_TARGET: &str = "cumulus-informant";
+
+/// The last candidate of the parachain that was seen pending availability.
+struct BackedCandidate {
+	/// The head of the parachain the candidate produces.
+	para_head: PHash,
+	/// The relay chain block in which the candidate was first seen pending availability.
+	relay_number: RBlockNumber,
+}
+
+/// Run the parachain informant until the relay chain stops notifying new best blocks.
+pub async fn run_parachain_informant<RCInterface>(para_id: ParaId, relay_chain: RCInterface)
+where
+	RCInterface: RelayChainInterface,
+{
+	let mut best_blocks = match relay_chain.new_best_notification_stream().await {
+		Ok(best_blocks) => best_blocks,
+		Err(err) => {
+			log::error!(
+				target: LOG_TARGET,
+				"Failed to subscribe to new best relay chain blocks: {:?}",
+				err,
+			);
+			return
+		},
+	};
+
+	let mut last_backed = None;
+	while let Some(header) = best_blocks.next().await {
+		if let Err(err) = display(para_id, &relay_chain, header, &mut last_backed).await {
+			log::debug!(
+				target: LOG_TARGET,
+				"Failed to fetch the state of the parachain from the relay chain: {:?}",
+				err,
+			);
+		}
+	}
+}
+
+/// Display the status line for the new best relay chain block `header`.
+async fn display(
+	para_id: ParaId,
+	relay_chain: &impl RelayChainInterface,
+	header: PHeader,
+	last_backed: &mut Option<BackedCandidate>,
+) -> RelayChainResult<()> {
+	let best_hash = header.hash();
+	let finalized_hash = relay_chain.finalized_block_hash().await?;
+	let finalized = relay_chain
+		.header(BlockId::Hash(finalized_hash))
+		.await?
+		.map_or_else(|| "?".into(), |header| header.number.to_string());
+
+	let pending = relay_chain.candidate_pending_availability(best_hash, para_id).await?;
+	let core = match &pending {
+		Some(candidate) => format!("occupied by {}", candidate.descriptor.para_head),
+		None => "free".into(),
+	};
+	if let Some(candidate) = pending {
+		let para_head = candidate.descriptor.para_head;
+		if last_backed.as_ref().map_or(true, |backed| backed.para_head != para_head) {
+			*last_backed = Some(BackedCandidate { para_head, relay_number: header.number });
+		}
+	}
+	let backed = match last_backed {
+		Some(backed) => format!(
+			"{} ({} relay blocks ago)",
+			backed.para_head,
+			header.number.saturating_sub(backed.relay_number),
+		),
+		None => "none".into(),
+	};
+
+	log::info!(
+		target: LOG_TARGET,
+		"🪂 Parachain {}: relay best #{} ({}), finalized #{}, core {}, last backed {}",
+		u32::from(para_id),
+		header.number,
+		best_hash,
+		finalized,
+		core,
+		backed,
+	);
+
+	Ok(())
+}
@@ -52,6 +52,11 @@ use sp_core::{traits::SpawnNamed, Decode};
 use sp_runtime::traits::{Block as BlockT, BlockIdTo, Header};
 use std::{sync::Arc, time::Duration};
 
+mod health;
+mod informant;
+
+pub use health::CollationHealth;
+
 // Given the sporadic nature of the explicit recovery operation and the
 // possibility to retry infinite times this value is more than enough.
 // In practice here we expect no more than one queued messages.
@@ -98,6 +103,9 @@ pub struct StartRelayChainTasksParams<'a, Block: BlockT, Client, RCInterface> {
 	pub relay_chain_slot_duration: Duration,
 	pub recovery_handle: Box<dyn RecoveryHandle>,
 	pub sync_service: Arc<SyncingService<Block>>,
+	/// Whether to log the state of the parachain on the relay chain for every new best relay
+	/// chain block, see [`CollatorOptions::parachain_informant`].
+	pub parachain_informant: bool,
 }
 
 /// Parameters given to [`start_full_node`].
@@ -169,6 +177,7 @@ where
 		relay_chain_slot_duration,
 		recovery_handle,
 		sync_service,
+		parachain_informant: false,
 	})?;
 
 	#[allow(deprecated)]
@@ -206,6 +215,7 @@ pub fn start_relay_chain_tasks<Block, Client, Backend, RCInterface>(
 		relay_chain_slot_duration,
 		recovery_handle,
 		sync_service,
+		parachain_informant,
 	}: StartRelayChainTasksParams<Block, Client, RCInterface>,
 ) -> sc_service::error::Result<()>
 where
@@ -259,6 +269,14 @@ where
 		DARecoveryProfile::Other(profile) => profile,
 	};
 
+	if parachain_informant {
+		task_manager.spawn_handle().spawn(
+			"cumulus-informant",
+			None,
+			informant::run_parachain_informant(para_id, relay_chain_interface.clone()),
+		);
+	}
+
 	let pov_recovery = PoVRecovery::new(
 		recovery_handle,
 		da_recovery_profile,
@@ -319,6 +337,7 @@ where
 		recovery_handle,
 		sync_service,
 		da_recovery_profile: DARecoveryProfile::FullNode,
+		parachain_informant: false,
 	})
 }
 
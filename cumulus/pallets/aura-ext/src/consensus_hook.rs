@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
-//! The definition of a [`FixedVelocityConsensusHook`] for consensus logic to manage
-//! block velocity.
+//! The definition of a [`FixedVelocityConsensusHook`] and a
+//! [`ConfigurableVelocityConsensusHook`] for consensus logic to manage block velocity.
 //!
 //! The velocity `V` refers to the rate of block processing by the relay chain.
 
@@ -25,33 +25,61 @@ use cumulus_pallet_parachain_system::{
 	consensus_hook::{ConsensusHook, UnincludedSegmentCapacity},
 	relay_state_snapshot::RelayChainStateProof,
 };
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::ConstU32};
 use sp_consensus_aura::{Slot, SlotDuration};
 use sp_std::{marker::PhantomData, num::NonZeroU32};
 
 /// A consensus hook for a fixed block processing velocity and unincluded segment capacity.
 ///
 /// Relay chain slot duration must be provided in milliseconds.
-pub struct FixedVelocityConsensusHook<
+pub type FixedVelocityConsensusHook<
 	T,
 	const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
 	const V: u32,
 	const C: u32,
->(PhantomData<T>);
+> = ConfigurableVelocityConsensusHook<
+	T,
+	RELAY_CHAIN_SLOT_DURATION_MILLIS,
+	ConstU32<V>,
+	ConstU32<C>,
+>;
+
+/// A consensus hook for a block processing velocity `V` and unincluded segment capacity `C` which
+/// are read anew for every block.
+///
+/// This allows governance to change the velocity without a runtime upgrade, e.g. by backing `V`
+/// with a storage parameter. Collators learn about the current velocity through
+/// `AuraUnincludedSegmentApi::authoring_velocity`, which should return
+/// [`Self::authoring_velocity`].
+///
+/// Relay chain slot duration must be provided in milliseconds.
+pub struct ConfigurableVelocityConsensusHook<T, const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32, V, C>(
+	PhantomData<(T, V, C)>,
+);
+
+impl<T, const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32, V: Get<u32>, C>
+	ConfigurableVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
+{
+	/// The number of blocks which may be authored per relay chain block.
+	///
+	/// This is `V`, but at least 1.
+	pub fn authoring_velocity() -> u32 {
+		V::get().max(1)
+	}
+}
 
 impl<
 		T: pallet::Config,
 		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
-		const V: u32,
-		const C: u32,
-	> ConsensusHook for FixedVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
+		V: Get<u32>,
+		C: Get<u32>,
+	> ConsensusHook for ConfigurableVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
 where
 	<T as pallet_timestamp::Config>::Moment: Into<u64>,
 {
 	// Validates the number of authored blocks within the slot with respect to the `V + 1` limit.
 	fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
-		// Ensure velocity is non-zero.
-		let velocity = V.max(1);
+		let velocity = Self::authoring_velocity();
 		let relay_chain_slot = state_proof.read_slot().expect("failed to read relay chain slot");
 
 		let (slot, authored) = pallet::Pallet::<T>::slot_info()
@@ -74,7 +102,7 @@ where
 
 		(
 			weight,
-			NonZeroU32::new(sp_std::cmp::max(C, 1))
+			NonZeroU32::new(sp_std::cmp::max(C::get(), 1))
 				.expect("1 is the minimum value and non-zero; qed")
 				.into(),
 		)
@@ -84,9 +112,9 @@ where
 impl<
 		T: pallet::Config + parachain_system::Config,
 		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
-		const V: u32,
-		const C: u32,
-	> FixedVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
+		V: Get<u32>,
+		C: Get<u32>,
+	> ConfigurableVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
 {
 	/// Whether it is legal to extend the chain, assuming the given block is the most
 	/// recently included one as-of the relay parent that will be built against, and
@@ -99,7 +127,7 @@ impl<
 	/// whose state we are querying against, this must always return `true` as long as the slot
 	/// is more recent than the included block itself.
 	pub fn can_build_upon(included_hash: T::Hash, new_slot: Slot) -> bool {
-		let velocity = V.max(1);
+		let velocity = Self::authoring_velocity();
 		let (last_slot, authored_so_far) = match pallet::Pallet::<T>::slot_info() {
 			None => return true,
 			Some(x) => x,
@@ -109,7 +137,7 @@ impl<
 			parachain_system::Pallet::<T>::unincluded_segment_size_after(included_hash);
 
 		// can never author when the unincluded segment is full.
-		if size_after_included >= C {
+		if size_after_included >= C::get() {
 			return false
 		}
 
@@ -121,3 +149,35 @@ impl<
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub storage Velocity: u32 = 1;
+	}
+
+	type Hook = ConfigurableVelocityConsensusHook<(), 6000, Velocity, ConstU32<3>>;
+
+	#[test]
+	fn velocity_changes_take_effect_immediately() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(Hook::authoring_velocity(), 1);
+
+			Velocity::set(&3);
+			assert_eq!(Hook::authoring_velocity(), 3);
+
+			// The velocity is never zero.
+			Velocity::set(&0);
+			assert_eq!(Hook::authoring_velocity(), 1);
+		});
+	}
+
+	#[test]
+	fn fixed_velocity_is_at_least_one() {
+		assert_eq!(FixedVelocityConsensusHook::<(), 6000, 0, 1>::authoring_velocity(), 1);
+		assert_eq!(FixedVelocityConsensusHook::<(), 6000, 2, 1>::authoring_velocity(), 2);
+	}
+}
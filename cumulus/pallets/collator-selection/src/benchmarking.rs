@@ -21,7 +21,7 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as CollatorSelection;
-use codec::Decode;
+use codec::{Decode, Encode};
 use frame_benchmarking::{
 	account, impl_benchmark_test_suite, v2::*, whitelisted_caller, BenchmarkError,
 };
@@ -55,8 +55,21 @@ fn create_funded_user<T: Config>(
 	user
 }
 
-fn keys<T: Config + session::Config>(c: u32) -> <T as session::Config>::Keys {
+/// Session keys for `owner` together with a proof of their ownership.
+///
+/// Falls back to keys derived from `c` and an empty proof if the keys cannot generate proofs.
+fn keys<T: Config + session::Config>(
+	c: u32,
+	owner: &T::AccountId,
+) -> (<T as session::Config>::Keys, Vec<u8>) {
 	use rand::{RngCore, SeedableRng};
+	use sp_runtime::traits::OpaqueKeys;
+
+	if let Some(generated) =
+		<T as session::Config>::Keys::generate_with_ownership_proof(&owner.encode(), None)
+	{
+		return generated
+	}
 
 	let keys = {
 		let mut keys = [0u8; 128];
@@ -69,18 +82,22 @@ fn keys<T: Config + session::Config>(c: u32) -> <T as session::Config>::Keys {
 		keys
 	};
 
-	Decode::decode(&mut &keys[..]).unwrap()
+	(Decode::decode(&mut &keys[..]).unwrap(), Vec::new())
 }
 
-fn validator<T: Config + session::Config>(c: u32) -> (T::AccountId, <T as session::Config>::Keys) {
-	(create_funded_user::<T>("candidate", c, 1000), keys::<T>(c))
+fn validator<T: Config + session::Config>(
+	c: u32,
+) -> (T::AccountId, (<T as session::Config>::Keys, Vec<u8>)) {
+	let who = create_funded_user::<T>("candidate", c, 1000);
+	let keys = keys::<T>(c, &who);
+	(who, keys)
 }
 
 fn register_validators<T: Config + session::Config>(count: u32) -> Vec<T::AccountId> {
 	let validators = (0..count).map(|c| validator::<T>(c)).collect::<Vec<_>>();
 
-	for (who, keys) in validators.clone() {
-		<session::Pallet<T>>::set_keys(RawOrigin::Signed(who).into(), keys, Vec::new()).unwrap();
+	for (who, (keys, proof)) in validators.clone() {
+		<session::Pallet<T>>::set_keys(RawOrigin::Signed(who).into(), keys, proof).unwrap();
 	}
 
 	validators.into_iter().map(|(who, _)| who).collect()
@@ -155,9 +172,8 @@ mod benchmarks {
 		let (new_invulnerable, new_invulnerable_keys) = validator::<T>(b.max(c) + 1);
 		candidates.push((new_invulnerable.clone(), new_invulnerable_keys));
 		// set their keys ...
-		for (who, keys) in candidates.clone() {
-			<session::Pallet<T>>::set_keys(RawOrigin::Signed(who).into(), keys, Vec::new())
-				.unwrap();
+		for (who, (keys, proof)) in candidates.clone() {
+			<session::Pallet<T>>::set_keys(RawOrigin::Signed(who).into(), keys, proof).unwrap();
 		}
 		// ... and register them.
 		for (who, _) in candidates.iter() {
@@ -295,12 +311,9 @@ mod benchmarks {
 		let bond: BalanceOf<T> = T::Currency::minimum_balance() * 2u32.into();
 		T::Currency::make_free_balance_be(&caller, bond);
 
-		<session::Pallet<T>>::set_keys(
-			RawOrigin::Signed(caller.clone()).into(),
-			keys::<T>(c + 1),
-			Vec::new(),
-		)
-		.unwrap();
+		let (keys, proof) = keys::<T>(c + 1, &caller);
+		<session::Pallet<T>>::set_keys(RawOrigin::Signed(caller.clone()).into(), keys, proof)
+			.unwrap();
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
@@ -322,12 +335,9 @@ mod benchmarks {
 		let bond: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
 		T::Currency::make_free_balance_be(&caller, bond);
 
-		<session::Pallet<T>>::set_keys(
-			RawOrigin::Signed(caller.clone()).into(),
-			keys::<T>(c + 1),
-			Vec::new(),
-		)
-		.unwrap();
+		let (keys, proof) = keys::<T>(c + 1, &caller);
+		<session::Pallet<T>>::set_keys(RawOrigin::Signed(caller.clone()).into(), keys, proof)
+			.unwrap();
 
 		let target = <CandidateList<T>>::get().iter().last().unwrap().who.clone();
 
@@ -61,6 +61,10 @@
 //! would fall below `MinEligibleCollators`. This is to ensure that some collators will always
 //! exist, i.e. someone is eligible to produce a block.
 //!
+//! Governance can remove a candidate reported for misbehaviour through `remove_reported_candidate`,
+//! e.g. when notified by the relay chain of a collator repeatedly submitting invalid candidates.
+//! This is subject to `MinEligibleCollators` just the same.
+//!
 //! When a new session starts, candidates with the highest deposits will be selected in order until
 //! the desired number of collators is reached. Candidates can increase or decrease their deposits
 //! between sessions in order to ensure they receive a slot in the collator list.
@@ -792,6 +796,31 @@ pub mod pallet {
 			Self::deposit_event(Event::CandidateReplaced { old: target, new: who, deposit });
 			Ok(Some(T::WeightInfo::take_candidate_slot(length as u32)).into())
 		}
+
+		/// Remove the collator candidate `who`, reported for misbehaviour. The `CandidacyBond` of
+		/// `who` will be unreserved immediately.
+		///
+		/// This call will fail if `who` is not a collator candidate, or if the total number of
+		/// candidates would drop below `MinEligibleCollators`.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::leave_intent(T::MaxCandidates::get()))]
+		pub fn remove_reported_candidate(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::eligible_collators() > T::MinEligibleCollators::get(),
+				Error::<T>::TooFewEligibleCollators
+			);
+			// Removing a candidate is the same work as leaving, so it uses the same weight.
+			let length = <CandidateList<T>>::decode_len().unwrap_or_default();
+			Self::try_remove_candidate(&who, true)?;
+
+			Ok(Some(T::WeightInfo::leave_intent(length.saturating_sub(1) as u32)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -74,6 +74,8 @@ impl system::Config for Test {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 parameter_types! {
@@ -117,6 +119,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<1>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
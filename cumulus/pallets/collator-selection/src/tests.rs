@@ -15,6 +15,7 @@
 
 use crate as collator_selection;
 use crate::{mock::*, CandidateInfo, Error};
+use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{Currency, OnInitialize},
@@ -121,7 +122,8 @@ fn invulnerable_limit_works() {
 			if ii > 5 {
 				Balances::make_free_balance_be(&ii, 100);
 				let key = MockSessionKeys { aura: UintAuthorityId(ii) };
-				Session::set_keys(RuntimeOrigin::signed(ii).into(), key, Vec::new()).unwrap();
+				let proof = key.create_ownership_proof(&ii.encode()).unwrap();
+				Session::set_keys(RuntimeOrigin::signed(ii).into(), key, proof).unwrap();
 			}
 			assert_eq!(Balances::free_balance(ii), 100);
 			if ii < 21 {
@@ -483,7 +485,8 @@ fn cannot_register_candidate_if_too_many() {
 		for i in 6..=23 {
 			Balances::make_free_balance_be(&i, 100);
 			let key = MockSessionKeys { aura: UintAuthorityId(i) };
-			Session::set_keys(RuntimeOrigin::signed(i).into(), key, Vec::new()).unwrap();
+			let proof = key.create_ownership_proof(&i.encode()).unwrap();
+			Session::set_keys(RuntimeOrigin::signed(i).into(), key, proof).unwrap();
 		}
 
 		for c in 3..=22 {
@@ -767,7 +770,8 @@ fn take_candidate_slot_works() {
 
 		Balances::make_free_balance_be(&6, 100);
 		let key = MockSessionKeys { aura: UintAuthorityId(6) };
-		Session::set_keys(RuntimeOrigin::signed(6).into(), key, Vec::new()).unwrap();
+		let proof = key.create_ownership_proof(&6.encode()).unwrap();
+		Session::set_keys(RuntimeOrigin::signed(6).into(), key, proof).unwrap();
 
 		assert_ok!(CollatorSelection::take_candidate_slot(
 			RuntimeOrigin::signed(6),
@@ -1161,6 +1165,44 @@ fn leave_intent() {
 	});
 }
 
+#[test]
+fn remove_reported_candidate_works() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)));
+		assert_eq!(Balances::free_balance(3), 90);
+
+		// only the update origin can remove a reported candidate.
+		assert_noop!(
+			CollatorSelection::remove_reported_candidate(RuntimeOrigin::signed(5), 3),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorSelection::remove_reported_candidate(
+				RuntimeOrigin::signed(RootAccount::get()),
+				4
+			),
+			Error::<Test>::NotCandidate
+		);
+
+		// bond is returned
+		assert_ok!(CollatorSelection::remove_reported_candidate(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(
+			crate::Event::CandidateRemoved { account_id: 3 },
+		));
+		assert_eq!(
+			<crate::CandidateList<Test>>::get(),
+			vec![CandidateInfo { who: 5, deposit: 10 }]
+		);
+		assert_eq!(Balances::free_balance(3), 100);
+		assert_eq!(CollatorSelection::last_authored_block(3), 0);
+	});
+}
+
 #[test]
 fn authorship_event_handler() {
 	new_test_ext().execute_with(|| {
@@ -35,6 +35,7 @@ use cumulus_primitives_core::{
 	XcmpMessageHandler, XcmpMessageSource,
 };
 use cumulus_primitives_parachain_inherent::{MessageQueueChain, ParachainInherentData};
+use cumulus_primitives_proof_size_hostfunction::{storage_proof_size, PROOF_RECORDING_DISABLED};
 use frame_support::{
 	defensive,
 	dispatch::{DispatchResult, Pays, PostDispatchInfo},
@@ -1027,6 +1028,24 @@ impl<T: Config> Pallet<T> {
 		let segment = UnincludedSegment::<T>::get();
 		crate::unincluded_segment::size_after_included(included_hash, &segment)
 	}
+
+	/// Returns how much of the PoV budget of the current block is still unused.
+	///
+	/// This is the `max_pov_size` of the [`PersistedValidationData`] less the size of the storage
+	/// proof recorded so far, and can be used to stop packing work into the block (e.g. in
+	/// `on_idle`) before the PoV overflows. The header and the extrinsics of the block are not
+	/// accounted for.
+	///
+	/// Returns `None` before the validation data is set for the block or if no storage proof is
+	/// being recorded, e.g. because the node does not record proofs while importing blocks.
+	pub fn remaining_proof_size() -> Option<u64> {
+		let max_pov_size = ValidationData::<T>::get()?.max_pov_size as u64;
+		let proof_size = storage_proof_size::storage_proof_size();
+		if proof_size == PROOF_RECORDING_DISABLED {
+			return None
+		}
+		Some(max_pov_size.saturating_sub(proof_size))
+	}
 }
 
 impl<T: Config> FeeTracker for Pallet<T> {
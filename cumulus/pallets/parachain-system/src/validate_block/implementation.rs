@@ -33,15 +33,53 @@ use sp_core::storage::{ChildInfo, StateVersion};
 use sp_externalities::{set_and_run_with_externalities, Externalities};
 use sp_io::KillStorageResult;
 use sp_runtime::traits::{Block as BlockT, Extrinsic, HashingFor, Header as HeaderT};
-use sp_std::prelude::*;
-use sp_trie::MemoryDB;
+use sp_state_machine::TrieBackendStorage;
+use sp_std::{cell::RefCell, collections::btree_set::BTreeSet, prelude::*};
+use sp_trie::{DBValue, MemoryDB};
+use trie_db::Hasher;
 
 type TrieBackend<B> = sp_state_machine::TrieBackend<
-	MemoryDB<HashingFor<B>>,
+	ProofSizeRecordingStorage<HashingFor<B>>,
 	HashingFor<B>,
 	trie_cache::CacheProvider<HashingFor<B>>,
 >;
 
+// The encoded size of the storage proof accessed so far while executing the block.
+environmental::environmental!(recorded_proof_size: usize);
+
+/// Storage of the [`TrieBackend`] that sums up the encoded size of the distinct nodes read from the
+/// storage proof, the same way the proof recorder does while building the block.
+struct ProofSizeRecordingStorage<H: Hasher> {
+	db: MemoryDB<H>,
+	accessed: RefCell<BTreeSet<H::Out>>,
+}
+
+impl<H: Hasher> ProofSizeRecordingStorage<H> {
+	fn new(db: MemoryDB<H>) -> Self {
+		Self { db, accessed: Default::default() }
+	}
+}
+
+impl<H: Hasher> TrieBackendStorage<H> for ProofSizeRecordingStorage<H> {
+	fn get(
+		&self,
+		key: &H::Out,
+		prefix: (&[u8], Option<u8>),
+	) -> Result<Option<DBValue>, sp_state_machine::DefaultError> {
+		let value = TrieBackendStorage::<H>::get(&self.db, key, prefix)?;
+		if let Some(ref value) = value {
+			if self.accessed.borrow_mut().insert(*key) {
+				recorded_proof_size::with(|size| *size = size.saturating_add(value.encoded_size()));
+			}
+		}
+		Ok(value)
+	}
+}
+
+// This is safe here since we are single-threaded in WASM
+unsafe impl<H: Hasher> Send for ProofSizeRecordingStorage<H> {}
+unsafe impl<H: Hasher> Sync for ProofSizeRecordingStorage<H> {}
+
 type Ext<'a, B> = sp_state_machine::Ext<'a, HashingFor<B>, TrieBackend<B>>;
 
 fn with_externalities<F: FnOnce(&mut dyn Externalities) -> R, R>(f: F) -> R {
@@ -120,13 +158,12 @@ where
 
 	sp_std::mem::drop(storage_proof);
 
-	let cache_provider = trie_cache::CacheProvider::new();
 	// We use the storage root of the `parent_head` to ensure that it is the correct root.
 	// This is already being done above while creating the in-memory db, but let's be paranoid!!
 	let backend = sp_state_machine::TrieBackendBuilder::new_with_cache(
-		db,
+		ProofSizeRecordingStorage::new(db),
 		*parent_header.state_root(),
-		cache_provider,
+		trie_cache::CacheProvider::new(),
 	)
 	.build();
 
@@ -136,6 +173,7 @@ where
 		sp_io::storage::host_set.replace_implementation(host_storage_set),
 		sp_io::storage::host_get.replace_implementation(host_storage_get),
 		sp_io::storage::host_exists.replace_implementation(host_storage_exists),
+		sp_io::storage::host_batch_get.replace_implementation(host_storage_batch_get),
 		sp_io::storage::host_clear.replace_implementation(host_storage_clear),
 		sp_io::storage::host_root.replace_implementation(host_storage_root),
 		sp_io::storage::host_clear_prefix.replace_implementation(host_storage_clear_prefix),
@@ -167,6 +205,8 @@ where
 			.replace_implementation(host_default_child_storage_next_key),
 		sp_io::offchain_index::host_set.replace_implementation(host_offchain_index_set),
 		sp_io::offchain_index::host_clear.replace_implementation(host_offchain_index_clear),
+		cumulus_primitives_proof_size_hostfunction::storage_proof_size::host_storage_proof_size
+			.replace_implementation(host_storage_proof_size),
 	);
 
 	run_with_externalities::<B, _, _>(&backend, || {
@@ -190,10 +230,20 @@ where
 		}
 	});
 
+	// The storage accessed while checking the inherents is not part of the proof size reported to
+	// the runtime while building the block, so we start over with fresh caches.
+	let backend = sp_state_machine::TrieBackendBuilder::new_with_cache(
+		ProofSizeRecordingStorage::new(backend.into_storage().db),
+		*parent_header.state_root(),
+		trie_cache::CacheProvider::new(),
+	)
+	.build();
+	let mut proof_size = 0;
+
 	run_with_externalities::<B, _, _>(&backend, || {
 		let head_data = HeadData(block.header().encode());
 
-		E::execute_block(block);
+		recorded_proof_size::using(&mut proof_size, || E::execute_block(block));
 
 		let new_validation_code = crate::NewValidationCode::<PSC>::get();
 		let upward_messages = crate::UpwardMessages::<PSC>::get().try_into().expect(
@@ -276,6 +326,11 @@ fn run_with_externalities<B: BlockT, R, F: FnOnce() -> R>(
 	set_and_run_with_externalities(&mut ext, || execute())
 }
 
+fn host_storage_proof_size() -> u64 {
+	recorded_proof_size::with(|size| *size as u64)
+		.unwrap_or(cumulus_primitives_proof_size_hostfunction::PROOF_RECORDING_DISABLED)
+}
+
 fn host_storage_read(key: &[u8], value_out: &mut [u8], value_offset: u32) -> Option<u32> {
 	match with_externalities(|ext| ext.storage(key)) {
 		Some(value) => {
@@ -301,6 +356,10 @@ fn host_storage_exists(key: &[u8]) -> bool {
 	with_externalities(|ext| ext.exists_storage(key))
 }
 
+fn host_storage_batch_get(keys: &[Vec<u8>]) -> Vec<Option<Vec<u8>>> {
+	with_externalities(|ext| keys.iter().map(|key| ext.storage(key)).collect())
+}
+
 fn host_storage_clear(key: &[u8]) {
 	with_externalities(|ext| ext.place_storage(key.to_vec(), None))
 }
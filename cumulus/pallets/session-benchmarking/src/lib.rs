@@ -16,28 +16,37 @@
 //! Benchmarking setup for pallet-session
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg(feature = "runtime-benchmarks")]
-use sp_std::{prelude::*, vec};
+use sp_std::prelude::*;
 
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 use pallet_session::*;
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::traits::OpaqueKeys;
 pub struct Pallet<T: Config>(pallet_session::Pallet<T>);
 pub trait Config: pallet_session::Config {}
 
+/// Generates session keys for `owner` together with a proof of their ownership.
+///
+/// Falls back to zeroed keys and an empty proof if `T::Keys` cannot generate proofs.
+fn generate_keys<T: Config>(owner: &T::AccountId) -> (T::Keys, Vec<u8>) {
+	T::Keys::generate_with_ownership_proof(&owner.encode(), None).unwrap_or_else(|| {
+		let zeroes = &mut sp_runtime::traits::TrailingZeroInput::zeroes();
+		(T::Keys::decode(zeroes).unwrap(), Vec::new())
+	})
+}
+
 benchmarks! {
 	set_keys {
 		let caller: T::AccountId = whitelisted_caller();
 		frame_system::Pallet::<T>::inc_providers(&caller);
-		let keys = T::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = generate_keys::<T>(&caller);
 	}: _(RawOrigin::Signed(caller), keys, proof)
 
 	purge_keys {
 		let caller: T::AccountId = whitelisted_caller();
 		frame_system::Pallet::<T>::inc_providers(&caller);
-		let keys = T::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = generate_keys::<T>(&caller);
 		let _t = pallet_session::Pallet::<T>::set_keys(RawOrigin::Signed(caller.clone()).into(), keys, proof);
 	}: _(RawOrigin::Signed(caller))
 }
@@ -172,6 +172,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 pub type XcmRouter = (
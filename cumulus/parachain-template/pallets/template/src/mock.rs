@@ -46,6 +46,8 @@ impl system::Config for Test {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 impl crate::Config for Test {
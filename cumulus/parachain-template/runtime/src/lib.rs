@@ -323,6 +323,8 @@ impl frame_system::Config for Runtime {
 	/// The action to take on a Runtime Upgrade
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -330,6 +332,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -378,6 +381,7 @@ impl pallet_transaction_payment::Config for Runtime {
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	type AnnouncementDelay = ();
 	type WeightInfo = ();
 }
 
@@ -572,6 +576,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_consensus_aura::AuraPerformanceApi<Block, AuraId> for Runtime {
+		fn authoring_performance() -> Vec<(AuraId, sp_consensus_aura::SlotPerformance)> {
+			Aura::authoring_performance()
+		}
+	}
+
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
 			VERSION
@@ -637,6 +647,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -647,6 +658,15 @@ impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: codec::Encode::encode(&keys), proof }
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
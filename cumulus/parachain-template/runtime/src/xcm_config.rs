@@ -136,6 +136,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 /// No local origins on this chain are allowed to dispatch XCM sends/executions.
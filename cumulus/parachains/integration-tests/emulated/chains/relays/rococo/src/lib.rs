@@ -37,6 +37,7 @@ decl_test_relay_chains! {
 			Sudo: rococo_runtime::Sudo,
 			Balances: rococo_runtime::Balances,
 			Hrmp: rococo_runtime::Hrmp,
+			ParasGovernance: rococo_runtime::ParasGovernance,
 		}
 	},
 }
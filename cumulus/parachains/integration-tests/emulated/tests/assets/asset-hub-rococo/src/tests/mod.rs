@@ -13,7 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod paras_governance;
 mod reserve_transfer;
+mod scenario;
 mod send;
 mod set_xcm_versions;
 mod swap;
@@ -0,0 +1,94 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::traits::Hooks;
+use polkadot_runtime_common::paras_governance::{self, ParaAction, ParaActionKind};
+
+/// Relay Chain governance should be able to queue a downward message to a System Parachain
+/// through the paras governance pallet.
+#[test]
+fn relay_governance_queues_downward_xcm_to_system_para() {
+	// Init tests variables
+	let root_origin = <Rococo as Chain>::RuntimeOrigin::root();
+	let parent_location = AssetHubRococo::parent_location();
+	let force_xcm_version_call =
+		<AssetHubRococo as Chain>::RuntimeCall::PolkadotXcm(pallet_xcm::Call::<
+			<AssetHubRococo as Chain>::Runtime,
+		>::force_xcm_version {
+			location: bx!(parent_location),
+			version: XCM_V3,
+		})
+		.encode()
+		.into();
+	let xcm = xcm_transact_unpaid_execution(force_xcm_version_call, OriginKind::Superuser);
+
+	// Relay Chain governance schedules the downward message
+	let when = Rococo::execute_with(|| {
+		type RuntimeEvent = <Rococo as Chain>::RuntimeEvent;
+
+		assert_ok!(<Rococo as RococoPallet>::ParasGovernance::schedule(
+			root_origin,
+			bx!(ParaAction::QueueDownwardXcm { id: AssetHubRococo::para_id(), xcm: bx!(xcm) }),
+		));
+
+		let when = <Rococo as Chain>::System::block_number() + 1;
+		assert_expected_events!(
+			Rococo,
+			vec![
+				RuntimeEvent::ParasGovernance(paras_governance::Event::Scheduled {
+					kind: ParaActionKind::QueueDownwardXcm,
+					when: scheduled_at,
+					..
+				}) => { scheduled_at: *scheduled_at == when, },
+			]
+		);
+		when
+	});
+
+	// The message is queued once the action is enacted
+	Rococo::execute_with(|| {
+		type RuntimeEvent = <Rococo as Chain>::RuntimeEvent;
+
+		<Rococo as Chain>::System::set_block_number(when);
+		<Rococo as RococoPallet>::ParasGovernance::on_initialize(when);
+
+		assert_expected_events!(
+			Rococo,
+			vec![
+				RuntimeEvent::ParasGovernance(
+					paras_governance::Event::Enacted { result: Ok(()), .. }
+				) => {},
+			]
+		);
+	});
+
+	// System Parachain receives and executes the XCM message
+	AssetHubRococo::execute_with(|| {
+		type RuntimeEvent = <AssetHubRococo as Chain>::RuntimeEvent;
+
+		AssetHubRococo::assert_dmp_queue_complete(Some(Weight::from_parts(1_019_210_000, 200_000)));
+
+		assert_expected_events!(
+			AssetHubRococo,
+			vec![
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::SupportedVersionChanged {
+					location,
+					version: XCM_V3
+				}) => { location: *location == parent_location, },
+			]
+		);
+	});
+}
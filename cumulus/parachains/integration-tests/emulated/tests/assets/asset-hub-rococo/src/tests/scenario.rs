@@ -0,0 +1,61 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use emulated_integration_tests_common::xcm_emulator::{
+	scenario, DeliveredMessage, Network, Scenario, ScenarioOrigin,
+};
+use rococo_system_emulated_network::RococoMockNet;
+
+/// A recorded teleport from the Relay Chain to the System Parachain should replay identically
+/// after being saved and loaded.
+#[test]
+fn recorded_teleport_from_relay_to_system_para_replays() {
+	let amount_to_send: Balance = ROCOCO_ED * 1000;
+	let teleport = <Rococo as Chain>::RuntimeCall::XcmPallet(pallet_xcm::Call::<
+		<Rococo as Chain>::Runtime,
+	>::limited_teleport_assets {
+		dest: bx!(Rococo::child_location_of(AssetHubRococo::para_id()).into()),
+		beneficiary: bx!(AccountId32Junction {
+			network: None,
+			id: AssetHubRococoReceiver::get().into()
+		}
+		.into()),
+		assets: bx!((Here, amount_to_send).into()),
+		fee_asset_item: 0,
+		weight_limit: WeightLimit::Unlimited,
+	});
+
+	// Record the teleport
+	RococoMockNet::reset();
+	scenario::start_recording::<RococoMockNet>();
+	assert_ok!(scenario::dispatch::<Rococo>(ScenarioOrigin::Signed(RococoSender::get()), teleport));
+	let recorded = scenario::stop_recording::<RococoMockNet>().unwrap();
+	let para_id: u32 = AssetHubRococo::para_id().into();
+	assert!(recorded
+		.messages()
+		.any(|m| matches!(m, DeliveredMessage::Downward { to, .. } if *to == para_id)));
+
+	// Save and load it
+	let path = std::env::temp_dir().join("recorded_teleport_from_relay_to_system_para.scale");
+	recorded.save(&path).unwrap();
+	let loaded = Scenario::load(&path).unwrap();
+	std::fs::remove_file(&path).unwrap();
+	assert_eq!(loaded, recorded);
+
+	// Replaying it against the same runtimes does not diverge
+	let replayed = loaded.replay::<RococoMockNet>().unwrap();
+	assert_eq!(recorded.first_divergence(&replayed), None);
+}
@@ -348,3 +348,126 @@ fn cannot_create_pool_from_pool_assets() {
 		);
 	});
 }
+
+#[test]
+fn exchange_asset_swaps_through_asset_conversion() {
+	let asset_native = Box::new(asset_hub_westend_runtime::xcm_config::WestendLocation::get());
+	let asset_one = Box::new(MultiLocation {
+		parents: 0,
+		interior: X2(PalletInstance(ASSETS_PALLET_ID), GeneralIndex(ASSET_ID.into())),
+	});
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		let signed = || {
+			<AssetHubWestend as Chain>::RuntimeOrigin::signed(AssetHubWestendSender::get())
+		};
+
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Assets::create(
+			signed(),
+			ASSET_ID.into(),
+			AssetHubWestendSender::get().into(),
+			1000,
+		));
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Assets::mint(
+			signed(),
+			ASSET_ID.into(),
+			AssetHubWestendSender::get().into(),
+			3_000_000_000_000,
+		));
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::AssetConversion::create_pool(
+			signed(),
+			asset_native.clone(),
+			asset_one.clone(),
+		));
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::AssetConversion::add_liquidity(
+			signed(),
+			asset_native.clone(),
+			asset_one.clone(),
+			1_000_000_000_000,
+			2_000_000_000_000,
+			0,
+			0,
+			AssetHubWestendSender::get().into()
+		));
+		// Keep the exchange account alive, so that it can receive the non-sufficient asset.
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::Balances::transfer_keep_alive(
+			signed(),
+			asset_hub_westend_runtime::xcm_config::AssetExchangeAccount::get().into(),
+			ASSET_HUB_WESTEND_ED,
+		));
+
+		let beneficiary: MultiLocation =
+			AccountId32Junction { network: None, id: AssetHubWestendReceiver::get().into() }.into();
+		let exchange = |want: u128, maximal: bool| {
+			let xcm = Xcm(vec![
+				WithdrawAsset((*asset_native, 100u128).into()),
+				ExchangeAsset {
+					give: Wild(AllCounted(1)),
+					want: (*asset_one, want).into(),
+					maximal,
+				},
+				DepositAsset { assets: Wild(AllCounted(2)), beneficiary },
+			]);
+			<AssetHubWestend as AssetHubWestendPallet>::PolkadotXcm::execute(
+				signed(),
+				bx!(VersionedXcm::from(xcm)),
+				Weight::from_parts(10_000_000_000, 1_000_000),
+			)
+		};
+
+		// The exchange fails if the pool can't provide the wanted amount for what is given.
+		assert_ok!(exchange(1_000, true));
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Attempted {
+					outcome: Outcome::Incomplete(_, Error::NoDeal)
+				}) => {},
+			]
+		);
+
+		// All of the given asset is swapped for at least the wanted amount.
+		assert_ok!(exchange(150, true));
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::AssetConversion(pallet_asset_conversion::Event::SwapExecuted {
+					amount_in, amount_out, ..
+				}) => {
+					amount_in: *amount_in == 100,
+					amount_out: *amount_out == 199,
+				},
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Attempted {
+					outcome: Outcome::Complete(_)
+				}) => {},
+			]
+		);
+
+		// Exactly the wanted amount is bought, and what remains of the given asset is deposited
+		// along with it.
+		assert_ok!(exchange(50, false));
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::AssetConversion(pallet_asset_conversion::Event::SwapExecuted {
+					amount_in, amount_out, ..
+				}) => {
+					amount_in: *amount_in < 100,
+					amount_out: *amount_out == 50,
+				},
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Attempted {
+					outcome: Outcome::Complete(_)
+				}) => {},
+			]
+		);
+
+		assert_eq!(
+			<AssetHubWestend as AssetHubWestendPallet>::Assets::balance(
+				ASSET_ID,
+				AssetHubWestendReceiver::get()
+			),
+			249
+		);
+	});
+}
@@ -19,25 +19,34 @@ use frame_support::traits::fungibles::{Create, Inspect, Mutate};
 use polkadot_runtime_common::impls::VersionedLocatableAsset;
 use xcm_executor::traits::ConvertLocation;
 
-#[test]
-fn create_and_claim_treasury_spend() {
-	const ASSET_ID: u32 = 1984;
-	const SPEND_AMOUNT: u128 = 1_000_000;
+const ASSET_ID: u32 = 1984;
+const SPEND_AMOUNT: u128 = 1_000_000;
+
+/// The account of the Relay Chain treasury on Asset Hub.
+fn treasury_account() -> AccountId {
 	// treasury location from a sibling parachain.
 	let treasury_location: MultiLocation = MultiLocation::new(1, PalletInstance(37));
-	// treasury account on a sibling parachain.
-	let treasury_account =
-		asset_hub_westend_runtime::xcm_config::LocationToAccountId::convert_location(
-			&treasury_location,
-		)
-		.unwrap();
+	asset_hub_westend_runtime::xcm_config::LocationToAccountId::convert_location(
+		&treasury_location,
+	)
+	.unwrap()
+}
+
+/// The asset kind of the treasury spends: the `ASSET_ID` asset on Asset Hub.
+fn spend_asset_kind() -> VersionedLocatableAsset {
 	let asset_hub_location = MultiLocation::new(0, Parachain(AssetHubWestend::para_id().into()));
-	let root = <Westend as Chain>::RuntimeOrigin::root();
-	// asset kind to be spend from the treasury.
-	let asset_kind = VersionedLocatableAsset::V3 {
+	VersionedLocatableAsset::V3 {
 		location: asset_hub_location,
 		asset_id: AssetId::Concrete((PalletInstance(50), GeneralIndex(ASSET_ID.into())).into()),
-	};
+	}
+}
+
+#[test]
+fn create_and_claim_treasury_spend() {
+	let treasury_account = treasury_account();
+	let root = <Westend as Chain>::RuntimeOrigin::root();
+	// asset kind to be spend from the treasury.
+	let asset_kind = spend_asset_kind();
 	// treasury spend beneficiary.
 	let alice: AccountId = Westend::account_id_of(ALICE);
 	let bob: AccountId = Westend::account_id_of(BOB);
@@ -124,3 +133,121 @@ fn create_and_claim_treasury_spend() {
 		);
 	});
 }
+
+#[test]
+fn failed_treasury_spend_can_be_retried_or_voided() {
+	let treasury_account = treasury_account();
+	let root = <Westend as Chain>::RuntimeOrigin::root();
+	let asset_kind = spend_asset_kind();
+	let alice: AccountId = Westend::account_id_of(ALICE);
+	let bob: AccountId = Westend::account_id_of(BOB);
+	let bob_signed = <Westend as Chain>::RuntimeOrigin::signed(bob.clone());
+
+	AssetHubWestend::execute_with(|| {
+		type Assets = <AssetHubWestend as AssetHubWestendPallet>::Assets;
+
+		// create an asset class, but do not fund the treasury account yet.
+		assert_ok!(<Assets as Create<_>>::create(
+			ASSET_ID,
+			treasury_account.clone(),
+			true,
+			SPEND_AMOUNT / 2
+		));
+	});
+
+	Westend::execute_with(|| {
+		type Treasury = <Westend as WestendPallet>::Treasury;
+		type AssetRate = <Westend as WestendPallet>::AssetRate;
+
+		assert_ok!(AssetRate::create(root.clone(), Box::new(asset_kind.clone()), 2.into()));
+
+		// create and approve two treasury spends, and claim both.
+		for _ in 0..2 {
+			assert_ok!(Treasury::spend(
+				root.clone(),
+				Box::new(asset_kind.clone()),
+				SPEND_AMOUNT,
+				Box::new(MultiLocation::new(0, Into::<[u8; 32]>::into(alice.clone())).into()),
+				None,
+			));
+		}
+		assert_ok!(Treasury::payout(bob_signed.clone(), 0));
+		assert_ok!(Treasury::payout(bob_signed.clone(), 1));
+	});
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		type Assets = <AssetHubWestend as AssetHubWestendPallet>::Assets;
+
+		// the transfers failed, and the errors were reported back to the Relay Chain.
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::ParachainSystem(cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. }) => {},
+			]
+		);
+		assert_eq!(<Assets as Inspect<_>>::balance(ASSET_ID, &alice,), 0u128,);
+
+		// fund the treasury account for the retried payment.
+		assert_ok!(<Assets as Mutate<_>>::mint_into(ASSET_ID, &treasury_account, SPEND_AMOUNT * 4));
+	});
+
+	Westend::execute_with(|| {
+		type RuntimeEvent = <Westend as Chain>::RuntimeEvent;
+		type Treasury = <Westend as WestendPallet>::Treasury;
+
+		// both payments are known to have failed.
+		assert_ok!(Treasury::check_status(bob_signed.clone(), 0));
+		assert_ok!(Treasury::check_status(bob_signed.clone(), 1));
+		assert_expected_events!(
+			Westend,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::PaymentFailed { index: 0, .. }) => {},
+				RuntimeEvent::Treasury(pallet_treasury::Event::PaymentFailed { index: 1, .. }) => {},
+			]
+		);
+
+		// the first spend is retried, while the second one is voided.
+		assert_ok!(Treasury::payout(bob_signed.clone(), 0));
+		assert_ok!(Treasury::void_spend(root, 1));
+		assert_expected_events!(
+			Westend,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::Paid { index: 0, .. }) => {},
+				RuntimeEvent::Treasury(pallet_treasury::Event::AssetSpendVoided { index: 1 }) => {},
+			]
+		);
+	});
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		type Assets = <AssetHubWestend as AssetHubWestendPallet>::Assets;
+
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::Assets(pallet_assets::Event::Transferred { asset_id: id, from, to, amount }) => {
+					id: id == &ASSET_ID,
+					from: from == &treasury_account,
+					to: to == &alice,
+					amount: amount == &SPEND_AMOUNT,
+				},
+			]
+		);
+		// only the retried spend was paid.
+		assert_eq!(<Assets as Inspect<_>>::balance(ASSET_ID, &alice,), SPEND_AMOUNT,);
+	});
+
+	Westend::execute_with(|| {
+		type RuntimeEvent = <Westend as Chain>::RuntimeEvent;
+		type Treasury = <Westend as WestendPallet>::Treasury;
+
+		assert_ok!(Treasury::check_status(bob_signed, 0));
+		assert_expected_events!(
+			Westend,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::SpendProcessed { index: 0 }) => {},
+			]
+		);
+	});
+}
@@ -143,6 +143,63 @@ fn send_rocs_from_asset_hub_rococo_to_asset_hub_westend() {
 	assert_eq!(rocs_in_reserve_on_ahr_after, rocs_in_reserve_on_ahr_before + amount);
 }
 
+#[test]
+fn send_rocs_from_asset_hub_rococo_to_pre_registered_foreign_asset_on_asset_hub_westend() {
+	use asset_hub_westend_runtime::xcm_config::bridging::to_rococo::RegisterBridgedForeignAssets;
+	use frame_support::traits::{fungibles::metadata::Inspect as _, OnRuntimeUpgrade};
+
+	let roc_at_asset_hub_rococo: MultiLocation = Parent.into();
+	let roc_at_asset_hub_westend =
+		MultiLocation { parents: 2, interior: X1(GlobalConsensus(NetworkId::Rococo)) };
+
+	// register ROC on AHW like a runtime upgrade would, instead of creating it manually
+	AssetHubWestend::execute_with(|| {
+		type ForeignAssets = <AssetHubWestend as AssetHubWestendPallet>::ForeignAssets;
+		assert!(!<ForeignAssets as Inspect<_>>::asset_exists(roc_at_asset_hub_westend));
+		RegisterBridgedForeignAssets::on_runtime_upgrade();
+		assert!(<ForeignAssets as Inspect<_>>::asset_exists(roc_at_asset_hub_westend));
+		assert_eq!(ForeignAssets::symbol(roc_at_asset_hub_westend), b"ROC".to_vec());
+		assert_eq!(ForeignAssets::decimals(roc_at_asset_hub_westend), 12);
+
+		// the registration is idempotent
+		RegisterBridgedForeignAssets::on_runtime_upgrade();
+	});
+
+	let receiver_rocs_before = AssetHubWestend::execute_with(|| {
+		type Assets = <AssetHubWestend as AssetHubWestendPallet>::ForeignAssets;
+		<Assets as Inspect<_>>::balance(roc_at_asset_hub_westend, &AssetHubWestendReceiver::get())
+	});
+	assert_eq!(receiver_rocs_before, 0);
+
+	let amount = ASSET_HUB_ROCOCO_ED * 1_000;
+	send_asset_from_asset_hub_rococo_to_asset_hub_westend(roc_at_asset_hub_rococo, amount);
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				// issue ROCs on AHW
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Issued { asset_id, owner, .. }) => {
+					asset_id: *asset_id == roc_at_asset_hub_westend,
+					owner: *owner == AssetHubWestendReceiver::get(),
+				},
+				// message processed successfully
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+	});
+
+	let receiver_rocs_after = AssetHubWestend::execute_with(|| {
+		type Assets = <AssetHubWestend as AssetHubWestendPallet>::ForeignAssets;
+		<Assets as Inspect<_>>::balance(roc_at_asset_hub_westend, &AssetHubWestendReceiver::get())
+	});
+
+	// Receiver's balance is increased
+	assert!(receiver_rocs_after > receiver_rocs_before);
+}
+
 #[test]
 fn send_wnds_from_asset_hub_rococo_to_asset_hub_westend() {
 	let prefund_amount = 10_000_000_000_000u128;
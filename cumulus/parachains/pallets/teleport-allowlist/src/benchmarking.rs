@@ -0,0 +1,78 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The pallet benchmarks.
+
+use super::{Pallet as TeleportAllowlist, *};
+use frame_benchmarking::{impl_benchmark_test_suite, v2::*};
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// Returns the pattern of the asset with the general index `i`.
+fn pattern(i: u32) -> AssetPattern {
+	AssetPattern::Concrete(MultiLocation::new(1, X1(GeneralIndex(i.into()))))
+}
+
+/// Trusts `location` to teleport `n` assets.
+fn allow<T: Config>(location: MultiLocation, n: u32) {
+	let patterns: Vec<_> = (0..n).map(pattern).collect();
+	Allowlist::<T>::insert(location, BoundedVec::truncate_from(patterns));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn allow_teleporter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = MultiLocation::parent();
+		let n = T::MaxPatterns::get().saturating_sub(1);
+		allow::<T>(location, n);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(location), pattern(n));
+
+		assert!(Allowlist::<T>::get(location).contains(&pattern(n)));
+		assert_last_event::<T>(Event::TeleporterAllowed { location, pattern: pattern(n) }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn disallow_teleporter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = MultiLocation::parent();
+		let n = T::MaxPatterns::get();
+		allow::<T>(location, n);
+		let last = pattern(n.saturating_sub(1));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(location), last);
+
+		assert!(!Allowlist::<T>::get(location).contains(&last));
+		assert_last_event::<T>(Event::TeleporterDisallowed { location, pattern: last }.into());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(TeleportAllowlist, super::mock::new_bench_ext(), super::mock::Test);
+}
@@ -0,0 +1,197 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Teleport Allowlist Pallet
+//!
+//! The pallet stores which locations are trusted to teleport which assets to the chain, so that
+//! governance can trust a new teleporter without a runtime upgrade.
+//!
+//! Each entry of the [allowlist](pallet::Allowlist) pairs a location with the
+//! [patterns](AssetPattern) of the assets it may teleport. Entries are managed by
+//! [AdminOrigin](pallet::Config::AdminOrigin). The pallet implements `ContainsPair<MultiAsset,
+//! MultiLocation>`, so it can be used as (part of) the `IsTeleporter` filter of the XCM executor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::ContainsPair;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+
+/// The assets a trusted location may teleport.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetPattern {
+	/// The concrete asset identified by the location.
+	Concrete(MultiLocation),
+	/// The concrete assets identified by the location or by any location interior to it.
+	ConcretePrefix(MultiLocation),
+}
+
+impl AssetPattern {
+	/// Returns `true` if `asset` matches the pattern.
+	pub fn matches(&self, asset: &MultiAsset) -> bool {
+		let Concrete(id) = &asset.id else { return false };
+		match self {
+			AssetPattern::Concrete(location) => id == location,
+			AssetPattern::ConcretePrefix(prefix) => id.starts_with(prefix),
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{ensure, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// The module configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin to manage the allowlist.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of asset patterns of a trusted location.
+		#[pallet::constant]
+		type MaxPatterns: Get<u32>;
+
+		/// Weight information needed for the pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location is already trusted to teleport the assets of the pattern.
+		AlreadyAllowed,
+		/// The location is not trusted to teleport the assets of the pattern.
+		NotAllowed,
+		/// Number of asset patterns of the location exceeds `MaxPatterns`.
+		TooManyPatterns,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The location is now trusted to teleport the assets of the pattern.
+		TeleporterAllowed { location: MultiLocation, pattern: AssetPattern },
+		/// The location is no longer trusted to teleport the assets of the pattern.
+		TeleporterDisallowed { location: MultiLocation, pattern: AssetPattern },
+	}
+
+	/// The asset patterns each trusted location may teleport.
+	#[pallet::storage]
+	pub type Allowlist<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		BoundedVec<AssetPattern, T::MaxPatterns>,
+		ValueQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Trust `location` to teleport the assets matching `pattern`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be the [Config::AdminOrigin].
+		/// - `location`: The location to trust, relative to this chain.
+		/// - `pattern`: The [pattern](super::AssetPattern) of the assets, relative to this chain.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::allow_teleporter())]
+		pub fn allow_teleporter(
+			origin: OriginFor<T>,
+			location: Box<MultiLocation>,
+			pattern: AssetPattern,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Allowlist::<T>::try_mutate(*location, |patterns| {
+				ensure!(!patterns.contains(&pattern), Error::<T>::AlreadyAllowed);
+				patterns.try_push(pattern).map_err(|_| Error::<T>::TooManyPatterns)
+			})?;
+
+			Self::deposit_event(Event::<T>::TeleporterAllowed { location: *location, pattern });
+			Ok(())
+		}
+
+		/// Stop trusting `location` to teleport the assets matching `pattern`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be the [Config::AdminOrigin].
+		/// - `location`: The trusted location, relative to this chain.
+		/// - `pattern`: The [pattern](super::AssetPattern) passed to
+		///   [allow_teleporter](Pallet::allow_teleporter).
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::disallow_teleporter())]
+		pub fn disallow_teleporter(
+			origin: OriginFor<T>,
+			location: Box<MultiLocation>,
+			pattern: AssetPattern,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Allowlist::<T>::try_mutate_exists(*location, |maybe_patterns| {
+				let patterns = maybe_patterns.as_mut().ok_or(Error::<T>::NotAllowed)?;
+				let index =
+					patterns.iter().position(|p| p == &pattern).ok_or(Error::<T>::NotAllowed)?;
+				patterns.remove(index);
+				if patterns.is_empty() {
+					*maybe_patterns = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TeleporterDisallowed { location: *location, pattern });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> ContainsPair<MultiAsset, MultiLocation> for Pallet<T> {
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		let allowed = Allowlist::<T>::get(origin).iter().any(|pattern| pattern.matches(asset));
+		log::trace!(
+			target: "xcm::contains",
+			"TeleportAllowlist asset: {:?}, origin: {:?}, allowed: {}",
+			asset,
+			origin,
+			allowed,
+		);
+		allowed
+	}
+}
@@ -0,0 +1,149 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests.
+
+use super::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+
+fn sibling(id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(id)))
+}
+
+fn asset(id: MultiLocation) -> MultiAsset {
+	(id, 100u128).into()
+}
+
+#[test]
+fn allow_teleporter_works() {
+	new_test_ext().execute_with(|| {
+		let pattern = AssetPattern::Concrete(MultiLocation::parent());
+
+		// wrong origin.
+		let origin = RuntimeOrigin::signed(SomeAccount::get());
+		assert_noop!(
+			TeleportAllowlist::allow_teleporter(origin, Box::new(sibling(1000)), pattern),
+			BadOrigin
+		);
+
+		// success.
+		let origin = RuntimeOrigin::signed(Admin::get());
+		assert_ok!(TeleportAllowlist::allow_teleporter(
+			origin.clone(),
+			Box::new(sibling(1000)),
+			pattern
+		));
+		assert_eq!(Allowlist::<Test>::get(sibling(1000)).into_inner(), vec![pattern]);
+		System::assert_last_event(RuntimeEvent::TeleportAllowlist(Event::TeleporterAllowed {
+			location: sibling(1000),
+			pattern,
+		}));
+
+		// the same pattern can only be allowed once.
+		assert_noop!(
+			TeleportAllowlist::allow_teleporter(origin.clone(), Box::new(sibling(1000)), pattern),
+			Error::<Test>::AlreadyAllowed
+		);
+
+		// up to `MaxPatterns` patterns per location.
+		let prefix = AssetPattern::ConcretePrefix(sibling(1000));
+		assert_ok!(TeleportAllowlist::allow_teleporter(
+			origin.clone(),
+			Box::new(sibling(1000)),
+			prefix
+		));
+		assert_noop!(
+			TeleportAllowlist::allow_teleporter(
+				origin,
+				Box::new(sibling(1000)),
+				AssetPattern::Concrete(MultiLocation::here())
+			),
+			Error::<Test>::TooManyPatterns
+		);
+	});
+}
+
+#[test]
+fn disallow_teleporter_works() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(Admin::get());
+		let pattern = AssetPattern::Concrete(MultiLocation::parent());
+		assert_noop!(
+			TeleportAllowlist::disallow_teleporter(
+				origin.clone(),
+				Box::new(sibling(1000)),
+				pattern
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(TeleportAllowlist::allow_teleporter(
+			origin.clone(),
+			Box::new(sibling(1000)),
+			pattern
+		));
+
+		// wrong origin.
+		assert_noop!(
+			TeleportAllowlist::disallow_teleporter(
+				RuntimeOrigin::signed(SomeAccount::get()),
+				Box::new(sibling(1000)),
+				pattern
+			),
+			BadOrigin
+		);
+
+		// success, the entry is removed together with its last pattern.
+		assert_ok!(TeleportAllowlist::disallow_teleporter(
+			origin,
+			Box::new(sibling(1000)),
+			pattern
+		));
+		assert!(!Allowlist::<Test>::contains_key(sibling(1000)));
+		System::assert_last_event(RuntimeEvent::TeleportAllowlist(Event::TeleporterDisallowed {
+			location: sibling(1000),
+			pattern,
+		}));
+	});
+}
+
+#[test]
+fn allowlist_filters_teleporters() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(Admin::get());
+		assert_ok!(TeleportAllowlist::allow_teleporter(
+			origin.clone(),
+			Box::new(sibling(1000)),
+			AssetPattern::Concrete(MultiLocation::parent())
+		));
+		assert_ok!(TeleportAllowlist::allow_teleporter(
+			origin,
+			Box::new(sibling(1000)),
+			AssetPattern::ConcretePrefix(sibling(1000))
+		));
+
+		type IsTeleporter = TeleportAllowlist;
+		assert!(IsTeleporter::contains(&asset(MultiLocation::parent()), &sibling(1000)));
+		assert!(IsTeleporter::contains(&asset(sibling(1000)), &sibling(1000)));
+		let local_asset = MultiLocation::new(1, X2(Parachain(1000), GeneralIndex(1)));
+		assert!(IsTeleporter::contains(&asset(local_asset), &sibling(1000)));
+
+		// other assets and locations are not trusted.
+		let other_asset = MultiLocation::new(1, X2(Parachain(1001), GeneralIndex(1)));
+		assert!(!IsTeleporter::contains(&asset(other_asset), &sibling(1000)));
+		assert!(!IsTeleporter::contains(&asset(MultiLocation::parent()), &sibling(1001)));
+		let abstract_asset: MultiAsset = (AssetId::Abstract([0; 32]), 100u128).into();
+		assert!(!IsTeleporter::contains(&abstract_asset, &sibling(1000)));
+	});
+}
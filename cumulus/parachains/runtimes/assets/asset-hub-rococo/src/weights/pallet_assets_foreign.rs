@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: Not regenerated for the foreign assets of asset-hub-rococo since sponsorships and the
+//! asset transfer hook were added. `sponsor`, `unsponsor` and `on_asset_transfer` are PLACEHOLDERS
+//! estimated by hand. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -531,4 +535,40 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `ForeignAssets::SponsoredAccounts` (`max_values`: None, `max_size`: Some(670), added: 3145, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:1 w:1)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Sponsorships` (r:0 w:1)
+	/// Proof: `ForeignAssets::Sponsorships` (`max_values`: None, `max_size`: Some(698), added: 3173, mode: `MaxEncodedLen`)
+	fn sponsor() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(39_422_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `ForeignAssets::Sponsorships` (r:1 w:1)
+	/// Proof: `ForeignAssets::Sponsorships` (`max_values`: None, `max_size`: Some(698), added: 3173, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:1 w:1)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `ForeignAssets::SponsoredAccounts` (`max_values`: None, `max_size`: Some(670), added: 3145, mode: `MaxEncodedLen`)
+	fn unsponsor() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(42_139_000, 0)
+			.saturating_add(Weight::from_parts(0, 4273))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn on_asset_transfer() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(1_113_000, 0)
+	}
 }
@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: Not regenerated for the trust-backed assets of asset-hub-rococo since sponsorships and the
+//! asset transfer hook were added. `sponsor`, `unsponsor` and `on_asset_transfer` are PLACEHOLDERS
+//! estimated by hand. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -528,4 +532,40 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `Assets::SponsoredAccounts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Sponsorships` (r:0 w:1)
+	/// Proof: `Assets::Sponsorships` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn sponsor() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(39_422_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Assets::Sponsorships` (r:1 w:1)
+	/// Proof: `Assets::Sponsorships` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::SponsoredAccounts` (r:1 w:1)
+	/// Proof: `Assets::SponsoredAccounts` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn unsponsor() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(42_139_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn on_asset_transfer() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(1_113_000, 0)
+	}
 }
@@ -21,6 +21,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since `pallet_multisig` started queueing operations for expiry and
+//! requesting the preimages of their calls. The storage accesses of the existing weights were
+//! adjusted by hand, and `approve_as_multi_complete` and `expire_multisig` are PLACEHOLDERS
+//! derived from `as_multi_complete` and `cancel_as_multi`. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -63,6 +68,16 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryCursor (r:1 w:1)
+	/// Proof: Multisig ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
@@ -76,8 +91,8 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(128_927, 0).saturating_mul(s.into()))
 			// Standard Error: 16
 			.saturating_add(Weight::from_parts(1_543, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -101,6 +116,14 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
@@ -114,11 +137,21 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(183_764, 0).saturating_mul(s.into()))
 			// Standard Error: 21
 			.saturating_add(Weight::from_parts(1_653, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryCursor (r:1 w:1)
+	/// Proof: Multisig ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -129,8 +162,8 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 6811))
 			// Standard Error: 913
 			.saturating_add(Weight::from_parts(121_410, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -149,6 +182,46 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:1 w:0)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn approve_as_multi_complete(s: u32, z: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `as_multi_complete`.
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + s * (33 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 51_506_000 picoseconds.
+		Weight::from_parts(36_510_777, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 2_183
+			.saturating_add(Weight::from_parts(183_764, 0).saturating_mul(s.into()))
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(1_653, 0).saturating_mul(z.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -159,7 +232,33 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 6811))
 			// Standard Error: 1_220
 			.saturating_add(Weight::from_parts(122_011, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: Multisig ExpiryQueue (r:0 w:1)
+	/// Proof: Multisig ExpiryQueue (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Preimage RequestStatusFor (r:1 w:1)
+	/// Proof: Preimage RequestStatusFor (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Multisig ExpiryMigration (r:1 w:0)
+	/// Proof: Multisig ExpiryMigration (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn expire_multisig(s: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `cancel_as_multi`.
+		// Proof Size summary in bytes:
+		//  Measured:  `454 + s * (1 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 32_107_000 picoseconds.
+		Weight::from_parts(33_674_827, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 1_220
+			.saturating_add(Weight::from_parts(122_011, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }
@@ -55,11 +55,9 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	/// Storage: `Session::KeyOwner` (r:1 w:1)
 	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `270`
-		//  Estimated: `3735`
-		// Minimum execution time: 16_932_000 picoseconds.
-		Weight::from_parts(17_357_000, 0)
+		// PLACEHOLDER: Asset Hub Rococo has a single session key; the base adds the cost of
+		// checking its proof of possession, estimated from `sr25519_verification`.
+		Weight::from_parts(72_813_705, 0)
 			.saturating_add(Weight::from_parts(0, 3735))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
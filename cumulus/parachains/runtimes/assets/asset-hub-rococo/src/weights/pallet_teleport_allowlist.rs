@@ -0,0 +1,51 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_teleport_allowlist` in asset-hub-rococo.
+//!
+//! These weights were written by hand, estimated from the storage accesses of each call. They are
+//! PLACEHOLDERS until the `pallet_teleport_allowlist` benchmarks are run for this runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_teleport_allowlist`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_teleport_allowlist::WeightInfo for WeightInfo<T> {
+	/// Storage: `TeleportAllowlist::Allowlist` (r:1 w:1)
+	/// Proof: `TeleportAllowlist::Allowlist` (`max_values`: None, `max_size`: Some(9191), added: 11666, mode: `MaxEncodedLen`)
+	fn allow_teleporter() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(25_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 12656))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TeleportAllowlist::Allowlist` (r:1 w:1)
+	/// Proof: `TeleportAllowlist::Allowlist` (`max_values`: None, `max_size`: Some(9191), added: 11666, mode: `MaxEncodedLen`)
+	fn disallow_teleporter() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(24_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 12656))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
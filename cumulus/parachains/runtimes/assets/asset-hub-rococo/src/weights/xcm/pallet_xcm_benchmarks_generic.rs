@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-yprdrvc7-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: Some("asset-hub-rococo-dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since `ExchangeAsset` swaps through the asset conversion pools. The
+//! weight of `exchange_asset` is a PLACEHOLDER; re-run the benchmarks to replace it.
 
 // Executed Command:
 // target/production/polkadot-parachain
@@ -352,4 +355,16 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		// Minimum execution time: 2_265_000 picoseconds.
 		Weight::from_parts(2_332_000, 0)
 	}
+	// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	// Storage: `ForeignAssets::Account` (r:6 w:6)
+	// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	pub fn exchange_asset() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses of a pool swap.
+		Weight::from_parts(250_000_000, 20232)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
 }
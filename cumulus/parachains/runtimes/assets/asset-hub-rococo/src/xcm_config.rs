@@ -16,16 +16,18 @@
 use super::{
 	AccountId, AllPalletsWithSystem, Assets, Authorship, Balance, Balances, BaseDeliveryFee,
 	FeeAssetId, ForeignAssets, ForeignAssetsInstance, ParachainInfo, ParachainSystem, PolkadotXcm,
-	PoolAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, ToWestendXcmRouter,
-	TransactionByteFee, TrustBackedAssetsInstance, WeightToFee, XcmpQueue,
+	PoolAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, TeleportAllowlist,
+	ToWestendXcmRouter, TransactionByteFee, TrustBackedAssetsInstance, WeightToFee, XcmpQueue,
 };
 use assets_common::{
+	asset_exchange::AssetConversionExchange,
 	local_and_foreign_assets::MatchesLocalAndForeignAssetsMultiLocation,
 	matching::{FromSiblingParachain, IsForeignConcreteAsset},
 };
 use frame_support::{
 	match_types, parameter_types,
 	traits::{ConstU32, Contains, Equals, Everything, Nothing, PalletInfoAccess},
+	PalletId,
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
@@ -75,6 +77,9 @@ parameter_types! {
 	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 	pub const GovernanceLocation: MultiLocation = MultiLocation::parent();
 	pub TreasuryAccount: AccountId = TREASURY_PALLET_ID.into_account_truncating();
+	pub AssetExchangeAccount: AccountId = PalletId(*b"py/xcmex").into_account_truncating();
+	pub AssetExchangeLocation: MultiLocation =
+		AccountId32 { network: None, id: AssetExchangeAccount::get().into() }.into();
 	pub RelayTreasuryLocation: MultiLocation = (Parent, PalletInstance(rococo_runtime_constants::TREASURY_PALLET_ID)).into();
 }
 
@@ -461,7 +466,7 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 					pallet_uniques::Call::buy_item { .. }
 			) | RuntimeCall::ToWestendXcmRouter(
 				pallet_xcm_bridge_hub_router::Call::report_bridge_status { .. }
-			)
+			) | RuntimeCall::TeleportAllowlist(..)
 		)
 	}
 }
@@ -535,11 +540,13 @@ pub type WaivedLocations =
 
 /// Cases where a remote origin is accepted as trusted Teleporter for a given asset:
 ///
-/// - ROC with the parent Relay Chain and sibling system parachains; and
-/// - Sibling parachains' assets from where they originate (as `ForeignCreators`).
+/// - ROC with the parent Relay Chain and sibling system parachains;
+/// - Sibling parachains' assets from where they originate (as `ForeignCreators`); and
+/// - The assets governance allowed the origin to teleport (as `TeleportAllowlist`).
 pub type TrustedTeleporters = (
 	ConcreteAssetFromSystem<TokenLocation>,
 	IsForeignConcreteAsset<FromSiblingParachain<parachain_info::Pallet<Runtime>>>,
+	TeleportAllowlist,
 );
 
 pub struct XcmConfig;
@@ -597,7 +604,13 @@ impl xcm_executor::Config for XcmConfig {
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type AssetLocker = ();
-	type AssetExchanger = ();
+	// Incoming XCMs can swap assets with `ExchangeAsset`, e.g. into the asset paying for fees.
+	type AssetExchanger = AssetConversionExchange<
+		Runtime,
+		AssetExchangeLocation,
+		LocationToAccountId,
+		AssetTransactors,
+	>;
 	type FeeManager = XcmFeeManagerFromComponents<
 		WaivedLocations,
 		XcmFeeToAccount<Self::AssetTransactor, AccountId, TreasuryAccount>,
@@ -607,6 +620,12 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
+	#[cfg(not(feature = "xcm-trace"))]
+	type Tracer = ();
+	type ProcessedTopics = ();
+	#[cfg(feature = "xcm-trace")]
+	type Tracer = xcm_executor::traits::RecordXcmTrace;
+	type ProcessedTopics = ();
 }
 
 /// Converts a local signed origin into an XCM multilocation.
@@ -667,6 +686,14 @@ impl pallet_xcm::Config for Runtime {
 	type RemoteLockConsumerIdentifier = ();
 }
 
+impl pallet_teleport_allowlist::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Managed by the governance of the Relay Chain.
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxPatterns = ConstU32<16>;
+	type WeightInfo = crate::weights::pallet_teleport_allowlist::WeightInfo<Runtime>;
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
@@ -757,6 +784,7 @@ pub mod bridging {
 
 	pub mod to_westend {
 		use super::*;
+		use assets_common::foreign_asset_registration::ForeignAssetRegistration;
 
 		parameter_types! {
 			pub SiblingBridgeHubWithBridgeHubWestendInstance: MultiLocation = MultiLocation::new(
@@ -776,6 +804,20 @@ pub mod bridging {
 				AssetHubWestend::get()
 			);
 
+			/// Foreign assets bridged from Westend which are registered without waiting for anybody
+			/// to create them (see `assets_common::foreign_asset_registration`).
+			pub BridgedForeignAssets: sp_std::vec::Vec<ForeignAssetRegistration<Balance>> = sp_std::vec![
+				ForeignAssetRegistration {
+					id: WndLocation::get(),
+					reserve: AssetHubWestend::get(),
+					is_sufficient: true,
+					min_balance: crate::ExistentialDeposit::get(),
+					name: b"Westend".to_vec(),
+					symbol: b"WND".to_vec(),
+					decimals: 12,
+				},
+			];
+
 			/// Set up exporters configuration.
 			/// `Option<MultiAsset>` represents static "base fee" which is used for total delivery fee calculation.
 			pub BridgeTable: sp_std::vec::Vec<NetworkExportTableItem> = sp_std::vec![
@@ -819,6 +861,28 @@ pub mod bridging {
 				),
 			>;
 
+		/// Registers the [`BridgedForeignAssets`] which do not exist yet with a runtime upgrade.
+		pub type RegisterBridgedForeignAssets =
+			assets_common::foreign_asset_registration::RegisterForeignAssets<
+				Runtime,
+				ForeignAssetsInstance,
+				BridgedForeignAssets,
+				IsTrustedBridgedReserveLocationForConcreteAsset,
+				LocationToAccountId,
+			>;
+
+		/// The genesis config of `ForeignAssets` registering the [`BridgedForeignAssets`].
+		pub fn foreign_assets_genesis() -> crate::ForeignAssetsConfig {
+			let mut config = Default::default();
+			assets_common::foreign_asset_registration::extend_genesis_config::<
+				Runtime,
+				ForeignAssetsInstance,
+				IsTrustedBridgedReserveLocationForConcreteAsset,
+				LocationToAccountId,
+			>(&mut config, BridgedForeignAssets::get());
+			config
+		}
+
 		impl Contains<RuntimeCall> for ToWestendXcmRouter {
 			fn contains(call: &RuntimeCall) -> bool {
 				matches!(
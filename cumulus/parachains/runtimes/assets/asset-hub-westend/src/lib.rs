@@ -174,6 +174,8 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -181,6 +183,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+	type DriftCheck = ();
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
@@ -264,7 +267,9 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type OnAssetTransfer = ();
 	type AssetAccountDeposit = AssetAccountDeposit;
+	type MaxSponsoredAccounts = ConstU32<1_000>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -295,6 +300,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ForceOrigin = AssetsForceOrigin;
 	type AssetDeposit = ConstU128<0>;
 	type AssetAccountDeposit = ConstU128<0>;
+	type MaxSponsoredAccounts = ConstU32<1_000>;
 	type MetadataDepositBase = ConstU128<0>;
 	type MetadataDepositPerByte = ConstU128<0>;
 	type ApprovalDeposit = ConstU128<0>;
@@ -303,6 +309,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type OnAssetTransfer = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -374,7 +381,9 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type OnAssetTransfer = ();
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
+	type MaxSponsoredAccounts = ConstU32<1_000>;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
@@ -385,6 +394,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigExpiry: BlockNumber = 30 * DAYS;
 	pub const MaxSignatories: u32 = 100;
 }
 
@@ -395,6 +405,9 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type Preimages = ();
+	type MaxNotedCallLen = ConstU32<0>;
+	type MultisigExpiry = MultisigExpiry;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -414,6 +427,7 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const PureRecoveryDelay: BlockNumber = 28 * DAYS;
 	pub const MaxPending: u16 = 32;
 }
 
@@ -580,6 +594,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type RecoveryDelay = PureRecoveryDelay;
 }
 
 parameter_types! {
@@ -799,6 +815,7 @@ impl pallet_nfts::Config for Runtime {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RoyaltyAsset = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
 	type ForceOrigin = AssetsForceOrigin;
 	type Locker = ();
@@ -887,6 +904,7 @@ construct_runtime!(
 		// Bridge utilities.
 		ToRococoXcmRouter: pallet_xcm_bridge_hub_router::<Instance1>::{Pallet, Storage, Call} = 34,
 		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>} = 35,
+		TeleportAllowlist: pallet_teleport_allowlist::{Pallet, Call, Storage, Event<T>} = 36,
 
 		// Handy utilities.
 		Utility: pallet_utility::{Pallet, Call, Event} = 40,
@@ -897,7 +915,7 @@ construct_runtime!(
 		Assets: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>} = 50,
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 51,
 		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>} = 52,
-		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>} = 53,
+		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>} = 53,
 		NftFractionalization: pallet_nft_fractionalization::{Pallet, Call, Storage, Event<T>, HoldReason} = 54,
 		PoolAssets: pallet_assets::<Instance3>::{Pallet, Call, Storage, Event<T>} = 55,
 		AssetConversion: pallet_asset_conversion::{Pallet, Call, Storage, Event<T>} = 56,
@@ -937,10 +955,15 @@ pub type Migrations = (
 	migrations::NativeAssetParents0ToParents1Migration<Runtime>,
 	// unreleased
 	pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+	// unreleased
+	pallet_xcm::migration::InitMigratedXcmVersion<Runtime>,
 	// unreleased
 	InitStorageVersions,
 	// unreleased
 	DeleteUndecodableStorage,
+	// unreleased
+	xcm_config::bridging::to_rococo::RegisterBridgedForeignAssets,
 );
 
 /// Asset Hub Westend has some undecodable storage, delete it.
@@ -1070,6 +1093,7 @@ mod benches {
 		[pallet_nfts, Nfts]
 		[pallet_proxy, Proxy]
 		[pallet_session, SessionBench::<Runtime>]
+		[pallet_teleport_allowlist, TeleportAllowlist]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
 		[pallet_timestamp, Timestamp]
@@ -1160,6 +1184,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -1170,6 +1195,15 @@ impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: keys.encode(), proof }
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -1222,6 +1256,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xcm::TrappedAssetsApi<Block> for Runtime {
+		fn trapped_assets(
+			origin: xcm::VersionedMultiLocation,
+		) -> Vec<(xcm::VersionedMultiAssets, u32)> {
+			match MultiLocation::try_from(origin) {
+				Ok(origin) => PolkadotXcm::trapped_assets(origin),
+				Err(()) => Vec::new(),
+			}
+		}
+	}
+
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -1242,6 +1287,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_message_queue::MessageQueueApi<Block, AggregateMessageOrigin, xcm::VersionedXcm<()>>
+		for Runtime
+	{
+		fn queue_heads(
+			max_messages: u32,
+		) -> Vec<pallet_message_queue::QueueHead<AggregateMessageOrigin, xcm::VersionedXcm<()>>> {
+			MessageQueue::queue_heads::<
+				xcm_builder::DescribeXcmMessage<frame_support::traits::ConstU32<8>>,
+			>(max_messages)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
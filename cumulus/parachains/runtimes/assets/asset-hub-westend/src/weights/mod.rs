@@ -30,6 +30,7 @@ pub mod pallet_nft_fractionalization;
 pub mod pallet_nfts;
 pub mod pallet_proxy;
 pub mod pallet_session;
+pub mod pallet_teleport_allowlist;
 pub mod pallet_timestamp;
 pub mod pallet_uniques;
 pub mod pallet_utility;
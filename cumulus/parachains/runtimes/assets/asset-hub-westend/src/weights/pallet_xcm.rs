@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-yprdrvc7-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-westend-dev")`, DB CACHE: 1024
+//!
+//! NOTE: Asset Hub Westend has not been re-benchmarked since `claim_trapped_assets`,
+//! `note_processed_topic`, `teleport_assets_multi` and `set_weight_hints` were introduced, so their
+//! weights are PLACEHOLDERS. Replace them with measured values when the file is regenerated.
 
 // Executed Command:
 // target/production/polkadot-parachain
@@ -319,4 +323,47 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `PolkadotXcm::TrappedAssets` (r:1 w:1)
+	/// Proof: `PolkadotXcm::TrappedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
+	/// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_trapped_assets() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(8_933_000, 0)
+			.saturating_add(Weight::from_parts(0, 3607))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `PolkadotXcm::RecentTopicPositions` (r:1 w:2)
+	/// Proof: `PolkadotXcm::RecentTopicPositions` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::NextTopicPosition` (r:1 w:1)
+	/// Proof: `PolkadotXcm::NextTopicPosition` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::RecentTopics` (r:1 w:1)
+	/// Proof: `PolkadotXcm::RecentTopics` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn note_processed_topic() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(11_587_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `b` is `[1, 64]`.
+	fn teleport_assets_multi(b: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand per beneficiary.
+		Weight::from_parts(22_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(1_146_622, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: UNKNOWN KEY `0x3a78636d5f7765696768745f68696e74733a` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x3a78636d5f7765696768745f68696e74733a` (r:0 w:1)
+	fn set_weight_hints() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -128,7 +128,7 @@ impl<Call> XcmWeightInfo<Call> for AssetHubWestendXcmWeight<Call> {
 		assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::deposit_reserve_asset())
 	}
 	fn exchange_asset(_give: &MultiAssetFilter, _receive: &MultiAssets, _maximal: &bool) -> Weight {
-		Weight::MAX
+		XcmGeneric::<Runtime>::exchange_asset()
 	}
 	fn initiate_reserve_withdraw(
 		assets: &MultiAssetFilter,
@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-vmdtonbz-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: Compiled, CHAIN: Some("asset-hub-westend-dev"), DB CACHE: 1024
+//!
+//! NOTE: `exchange_asset` was weighed by hand when the asset conversion pools could be used by
+//! `ExchangeAsset`. Its weight is a PLACEHOLDER until the benchmarks are re-run.
 
 // Executed Command:
 // target/production/polkadot-parachain
@@ -351,4 +354,16 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		// Minimum execution time: 1_990_000 picoseconds.
 		Weight::from_parts(2_076_000, 0)
 	}
+	// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	// Storage: `ForeignAssets::Account` (r:6 w:6)
+	// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	pub fn exchange_asset() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses of a pool swap.
+		Weight::from_parts(250_000_000, 20232)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
 }
@@ -0,0 +1,166 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An [`AssetExchange`] backed by `pallet-asset-conversion`, so that XCM programs can swap the
+//! assets of their Holding Register with `ExchangeAsset`, e.g. into the asset paying for fees.
+
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+	BoundedVec,
+};
+use sp_runtime::{traits::Zero, DispatchError};
+use sp_std::{marker::PhantomData, vec};
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{AssetExchange, ConvertLocation, TransactAsset},
+	Assets,
+};
+
+/// Exchanges assets of the Holding Register through the pools of `pallet-asset-conversion`.
+///
+/// A single fungible asset can be given for a single fungible asset, swapped through the pool of
+/// the two. For the duration of the swap the assets are deposited to, and withdrawn from, the
+/// account of `ExchangeLocation` with `Transactor`; all of it is reverted if the swap fails.
+///
+/// `want` is the slippage limit: an exchange that is `maximal` swaps all of `give` for at least
+/// `want`, otherwise exactly `want` is bought for at most `give`, and what is left of `give` is
+/// returned along with it.
+///
+/// The account of `ExchangeLocation` should be kept alive with the existential deposit of the
+/// native asset, so that it can receive assets that are not sufficient.
+pub struct AssetConversionExchange<T, ExchangeLocation, LocationToAccountId, Transactor>(
+	PhantomData<(T, ExchangeLocation, LocationToAccountId, Transactor)>,
+);
+
+impl<T, ExchangeLocation, LocationToAccountId, Transactor> AssetExchange
+	for AssetConversionExchange<T, ExchangeLocation, LocationToAccountId, Transactor>
+where
+	T: pallet_asset_conversion::Config,
+	T::MultiAssetId: From<MultiLocation>,
+	T::AssetBalance: TryFrom<u128> + Into<u128>,
+	ExchangeLocation: Get<MultiLocation>,
+	LocationToAccountId: ConvertLocation<T::AccountId>,
+	Transactor: TransactAsset,
+{
+	fn exchange_asset(
+		_origin: Option<&MultiLocation>,
+		give: Assets,
+		want: &MultiAssets,
+		maximal: bool,
+	) -> Result<Assets, Assets> {
+		let Some((give_id, give_amount)) = Self::single_fungible(&give) else { return Err(give) };
+		let (want_id, want_amount) = match want.inner().as_slice() {
+			[MultiAsset { id: Concrete(id), fun: Fungible(amount) }] => (*id, *amount),
+			_ => return Err(give),
+		};
+
+		with_transaction(|| {
+			match Self::swap(give_id, give_amount, want_id, want_amount, maximal) {
+				Ok(acquired) => TransactionOutcome::Commit(Ok(acquired)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+		.map_err(|e| {
+			log::debug!(
+				target: "xcm::exchange_asset",
+				"Failed to exchange {:?} of {:?} for {:?} of {:?}: {:?}",
+				give_amount,
+				give_id,
+				want_amount,
+				want_id,
+				e,
+			);
+			give
+		})
+	}
+}
+
+impl<T, ExchangeLocation, LocationToAccountId, Transactor>
+	AssetConversionExchange<T, ExchangeLocation, LocationToAccountId, Transactor>
+where
+	T: pallet_asset_conversion::Config,
+	T::MultiAssetId: From<MultiLocation>,
+	T::AssetBalance: TryFrom<u128> + Into<u128>,
+	ExchangeLocation: Get<MultiLocation>,
+	LocationToAccountId: ConvertLocation<T::AccountId>,
+	Transactor: TransactAsset,
+{
+	/// The id and the amount of `assets` if it consists of a single concrete fungible asset.
+	fn single_fungible(assets: &Assets) -> Option<(MultiLocation, u128)> {
+		if !assets.non_fungible.is_empty() || assets.fungible.len() != 1 {
+			return None
+		}
+		match assets.fungible.iter().next()? {
+			(Concrete(id), amount) => Some((*id, *amount)),
+			_ => None,
+		}
+	}
+
+	/// Swap `give_amount` of `give_id` for `want_amount` of `want_id`, returning the assets to put
+	/// into the Holding Register.
+	fn swap(
+		give_id: MultiLocation,
+		give_amount: u128,
+		want_id: MultiLocation,
+		want_amount: u128,
+		maximal: bool,
+	) -> Result<Assets, DispatchError> {
+		let exchange = ExchangeLocation::get();
+		let account = LocationToAccountId::convert_location(&exchange)
+			.ok_or(DispatchError::Other("Failed to convert the exchange location"))?;
+		let balance = |amount: u128| {
+			T::AssetBalance::try_from(amount).map_err(|_| DispatchError::Other("Amount too large"))
+		};
+		let path = BoundedVec::try_from(vec![give_id.into(), want_id.into()])
+			.map_err(|_| pallet_asset_conversion::Error::<T>::PathError)?;
+
+		Transactor::deposit_asset(&(give_id, give_amount).into(), &exchange, None)
+			.map_err(|_| DispatchError::Other("Failed to deposit the given assets"))?;
+
+		let (amount_in, amount_out) = if maximal {
+			let amount_out = pallet_asset_conversion::Pallet::<T>::do_swap_exact_tokens_for_tokens(
+				account.clone(),
+				path,
+				balance(give_amount)?,
+				Some(balance(want_amount)?).filter(|min| !min.is_zero()),
+				account,
+				false,
+			)?;
+			(give_amount, amount_out.into())
+		} else {
+			let amount_in = pallet_asset_conversion::Pallet::<T>::do_swap_tokens_for_exact_tokens(
+				account.clone(),
+				path,
+				balance(want_amount)?,
+				Some(balance(give_amount)?),
+				account,
+				false,
+			)?;
+			(amount_in.into(), want_amount)
+		};
+
+		let withdraw = |id: MultiLocation, amount: u128| {
+			Transactor::withdraw_asset(&(id, amount).into(), &exchange, None)
+				.map_err(|_| DispatchError::Other("Failed to withdraw the exchanged assets"))
+		};
+		let mut acquired = withdraw(want_id, amount_out)?;
+		let rest = give_amount.saturating_sub(amount_in);
+		if !rest.is_zero() {
+			acquired.subsume_assets(withdraw(give_id, rest)?);
+		}
+		Ok(acquired)
+	}
+}
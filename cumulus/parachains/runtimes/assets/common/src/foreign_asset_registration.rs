@@ -0,0 +1,197 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pre-registration of foreign assets, e.g. of the assets commonly bridged from other consensus
+//! systems, so that they can be received without waiting for somebody to create them first.
+//!
+//! The same set of registrations can be applied at genesis ([`extend_genesis_config`]), with a
+//! runtime upgrade ([`RegisterForeignAssets`]) or through governance ([`registration_calls`]).
+
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{fungibles::Inspect, ContainsPair, Get, OnRuntimeUpgrade},
+};
+use sp_runtime::{
+	traits::{Saturating, StaticLookup},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::ConvertLocation;
+
+/// A foreign asset to register together with its metadata.
+///
+/// The asset is owned by the (sovereign account of the) `reserve` location.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ForeignAssetRegistration<Balance> {
+	/// The location of the asset, which is also its id.
+	pub id: MultiLocation,
+	/// The reserve location of the asset.
+	pub reserve: MultiLocation,
+	/// Whether the asset is sufficient for an account to exist.
+	pub is_sufficient: bool,
+	/// The minimum balance of the asset.
+	pub min_balance: Balance,
+	/// The name of the asset.
+	pub name: Vec<u8>,
+	/// The symbol of the asset.
+	pub symbol: Vec<u8>,
+	/// The number of decimals of the asset.
+	pub decimals: u8,
+}
+
+impl<Balance> ForeignAssetRegistration<Balance> {
+	/// Returns the owner of the asset if its `reserve` is a trusted reserve of the asset according
+	/// to `IsReserve`.
+	fn owner<AccountId, IsReserve, AccountOf>(&self) -> Option<AccountId>
+	where
+		IsReserve: ContainsPair<MultiAsset, MultiLocation>,
+		AccountOf: ConvertLocation<AccountId>,
+	{
+		let asset: MultiAsset = (self.id, 1u128).into();
+		if !IsReserve::contains(&asset, &self.reserve) {
+			log::error!(
+				target: "runtime::assets",
+				"{:?} is not a trusted reserve of the foreign asset {:?}; not registering it",
+				self.reserve,
+				self.id,
+			);
+			return None
+		}
+		let owner = AccountOf::convert_location(&self.reserve);
+		if owner.is_none() {
+			log::error!(
+				target: "runtime::assets",
+				"Failed to convert the reserve {:?} of the foreign asset {:?} to an account",
+				self.reserve,
+				self.id,
+			);
+		}
+		owner
+	}
+}
+
+/// Add the foreign asset `registrations` to the genesis `config` of a `pallet-assets` instance.
+///
+/// Registrations whose reserve is not trusted according to `IsReserve` are skipped.
+pub fn extend_genesis_config<T, I, IsReserve, AccountOf>(
+	config: &mut pallet_assets::GenesisConfig<T, I>,
+	registrations: impl IntoIterator<Item = ForeignAssetRegistration<T::Balance>>,
+) where
+	T: pallet_assets::Config<I, AssetId = MultiLocation>,
+	I: 'static,
+	IsReserve: ContainsPair<MultiAsset, MultiLocation>,
+	AccountOf: ConvertLocation<T::AccountId>,
+{
+	for registration in registrations {
+		let Some(owner) = registration.owner::<_, IsReserve, AccountOf>() else { continue };
+		let ForeignAssetRegistration {
+			id,
+			is_sufficient,
+			min_balance,
+			name,
+			symbol,
+			decimals,
+			..
+		} = registration;
+		config.assets.push((id, owner, is_sufficient, min_balance));
+		config.metadata.push((id, name, symbol, decimals));
+	}
+}
+
+/// The `pallet-assets` calls registering the foreign asset `registrations`, e.g. to be dispatched
+/// with `ForceOrigin` as a batch.
+///
+/// Registrations whose reserve is not trusted according to `IsReserve` are skipped.
+pub fn registration_calls<T, I, IsReserve, AccountOf>(
+	registrations: impl IntoIterator<Item = ForeignAssetRegistration<T::Balance>>,
+) -> Vec<pallet_assets::Call<T, I>>
+where
+	T: pallet_assets::Config<I, AssetId = MultiLocation>,
+	I: 'static,
+	IsReserve: ContainsPair<MultiAsset, MultiLocation>,
+	AccountOf: ConvertLocation<T::AccountId>,
+{
+	let mut calls = Vec::new();
+	for registration in registrations {
+		let Some(owner) = registration.owner::<_, IsReserve, AccountOf>() else { continue };
+		let ForeignAssetRegistration {
+			id,
+			is_sufficient,
+			min_balance,
+			name,
+			symbol,
+			decimals,
+			..
+		} = registration;
+		calls.push(pallet_assets::Call::force_create {
+			id: id.into(),
+			owner: T::Lookup::unlookup(owner),
+			is_sufficient,
+			min_balance,
+		});
+		calls.push(pallet_assets::Call::force_set_metadata {
+			id: id.into(),
+			name,
+			symbol,
+			decimals,
+			is_frozen: false,
+		});
+	}
+	calls
+}
+
+/// Register the foreign assets returned by `Registrations` with a runtime upgrade.
+///
+/// Assets that already exist are left untouched, so this can remain in the migrations of a runtime
+/// for any number of upgrades. `ForceOrigin` of the `pallet-assets` instance must accept `Root`.
+pub struct RegisterForeignAssets<T, I, Registrations, IsReserve, AccountOf>(
+	PhantomData<(T, I, Registrations, IsReserve, AccountOf)>,
+);
+impl<T, I, Registrations, IsReserve, AccountOf> OnRuntimeUpgrade
+	for RegisterForeignAssets<T, I, Registrations, IsReserve, AccountOf>
+where
+	T: pallet_assets::Config<I, AssetId = MultiLocation>,
+	I: 'static,
+	Registrations: Get<Vec<ForeignAssetRegistration<T::Balance>>>,
+	IsReserve: ContainsPair<MultiAsset, MultiLocation>,
+	AccountOf: ConvertLocation<T::AccountId>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let registrations = Registrations::get();
+		let mut reads = registrations.len() as u64;
+		let mut writes = 0;
+
+		let new = registrations
+			.into_iter()
+			.filter(|r| !<pallet_assets::Pallet<T, I> as Inspect<_>>::asset_exists(r.id));
+		for call in registration_calls::<T, I, IsReserve, AccountOf>(new) {
+			use frame_support::traits::UnfilteredDispatchable;
+			// Creating an asset reads and writes the asset and writes an event, setting its
+			// metadata reads and writes the asset and its metadata and writes an event.
+			reads.saturating_accrue(2);
+			writes.saturating_accrue(3);
+			if let Err(e) = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into()) {
+				log::error!(
+					target: "runtime::assets",
+					"Failed to register a foreign asset: {:?}",
+					e,
+				);
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("bridge-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: `set_block_length` was added after these weights were generated for bridge-hub-rococo. Its
+//! weight is a PLACEHOLDER until the `frame_system` benchmarks are re-run.
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -85,6 +88,13 @@ impl<T: frame_system::Config> frame_system::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `System::BlockLengthOverride` (r:0 w:1)
+	/// Proof: `System::BlockLengthOverride` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_block_length() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage access.
+		Weight::from_parts(2_247_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
 	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `ParachainSystem::UpgradeRestrictionSignal` (r:1 w:0)
@@ -55,11 +55,9 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	/// Storage: `Session::KeyOwner` (r:1 w:1)
 	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `297`
-		//  Estimated: `3762`
-		// Minimum execution time: 16_965_000 picoseconds.
-		Weight::from_parts(17_384_000, 0)
+		// PLACEHOLDER: estimated by hand. Adds one sr25519 verification to the base for the bridge
+		// hub's Aura key proof.
+		Weight::from_parts(72_840_705, 0)
 			.saturating_add(Weight::from_parts(0, 3762))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
@@ -268,6 +268,12 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
+	#[cfg(not(feature = "xcm-trace"))]
+	type Tracer = ();
+	type ProcessedTopics = ();
+	#[cfg(feature = "xcm-trace")]
+	type Tracer = xcm_executor::traits::RecordXcmTrace;
+	type ProcessedTopics = ();
 }
 
 pub type PriceForParentDelivery =
@@ -108,6 +108,8 @@ impl pallet_ranked_collective::Config<AmbassadorCollectiveInstance> for Runtime
 	type Polls = AmbassadorReferenda;
 	type MinRankOfClass = sp_runtime::traits::Identity;
 	type VoteWeight = pallet_ranked_collective::Linear;
+	type InactivityPeriod = ();
+	type ExemptOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -212,6 +214,8 @@ impl pallet_core_fellowship::Config<AmbassadorCoreInstance> for Runtime {
 	type ApproveOrigin = PromoteOrigin;
 	type PromoteOrigin = PromoteOrigin;
 	type EvidenceSize = ConstU32<65536>;
+	// Unjudged evidence is archived after 90 days.
+	type EvidenceRetentionPeriod = ConstU32<{ 90 * DAYS }>;
 }
 
 pub type AmbassadorSalaryInstance = pallet_salary::Instance2;
@@ -237,6 +241,10 @@ pub type AmbassadorSalaryPaymaster = PayOverXcm<
 impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 	type WeightInfo = weights::pallet_salary_ambassador_salary::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	// Salaries are paid in the asset the `Paymaster` is configured with.
+	type AssetKind = ();
+	type DefaultAssetKind = ();
+	type PayoutAssetOrigin = EnsureRoot<AccountId>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = AmbassadorSalaryPaymaster;
@@ -257,6 +265,8 @@ impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 	type RegistrationPeriod = ConstU32<{ 15 * DAYS }>;
 	// 15 days to claim the salary payment.
 	type PayoutPeriod = ConstU32<{ 15 * DAYS }>;
+	// 7 days into the next cycle to claim an extended salary payment.
+	type ExtensionPeriod = ConstU32<{ 7 * DAYS }>;
 	// Total monthly salary budget.
 	type Budget = ConstU128<{ 10_000 * DOLLARS }>;
 }
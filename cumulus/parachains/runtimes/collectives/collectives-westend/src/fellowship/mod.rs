@@ -131,6 +131,8 @@ impl pallet_ranked_collective::Config<FellowshipCollectiveInstance> for Runtime
 	type Polls = FellowshipReferenda;
 	type MinRankOfClass = tracks::MinRankOfClass;
 	type VoteWeight = pallet_ranked_collective::Geometric;
+	type InactivityPeriod = ();
+	type ExemptOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 pub type FellowshipCoreInstance = pallet_core_fellowship::Instance1;
@@ -187,6 +189,8 @@ impl pallet_core_fellowship::Config<FellowshipCoreInstance> for Runtime {
 		EnsureCanPromoteTo,
 	>;
 	type EvidenceSize = ConstU32<65536>;
+	// Unjudged evidence is archived after 90 days.
+	type EvidenceRetentionPeriod = ConstU32<{ 90 * DAYS }>;
 }
 
 pub type FellowshipSalaryInstance = pallet_salary::Instance1;
@@ -216,6 +220,10 @@ pub type FellowshipSalaryPaymaster = PayOverXcm<
 impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
 	type WeightInfo = weights::pallet_salary_fellowship_salary::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	// Salaries are paid in the asset the `Paymaster` is configured with.
+	type AssetKind = ();
+	type DefaultAssetKind = ();
+	type PayoutAssetOrigin = frame_system::EnsureRoot<AccountId>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = FellowshipSalaryPaymaster;
@@ -233,6 +241,8 @@ impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
 	type RegistrationPeriod = ConstU32<{ 15 * DAYS }>;
 	// 15 days to claim the salary payment.
 	type PayoutPeriod = ConstU32<{ 15 * DAYS }>;
+	// 7 days into the next cycle to claim an extended salary payment.
+	type ExtensionPeriod = ConstU32<{ 7 * DAYS }>;
 	// Total monthly salary budget.
 	type Budget = ConstU128<{ 100_000 * USDT_UNITS }>;
 }
@@ -178,6 +178,8 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = ConstU16<0>;
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -185,6 +187,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+	type DriftCheck = ();
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
@@ -236,6 +239,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigExpiry: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_multisig::Config for Runtime {
@@ -245,6 +249,9 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type Preimages = Preimage;
+	type MaxNotedCallLen = ConstU32<{ 16 * 1024 }>;
+	type MultisigExpiry = MultisigExpiry;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -263,6 +270,7 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const PureRecoveryDelay: BlockNumber = 28 * DAYS;
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -369,6 +377,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type RecoveryDelay = PureRecoveryDelay;
 }
 
 parameter_types! {
@@ -529,6 +539,7 @@ impl pallet_collective::Config<AllianceCollective> for Runtime {
 	type MaxProposals = ConstU32<ALLIANCE_MAX_PROPOSALS>;
 	type MaxMembers = ConstU32<ALLIANCE_MAX_MEMBERS>;
 	type DefaultVote = pallet_collective::MoreThanMajorityThenPrimeDefaultVote;
+	type MemberRank = ();
 	type SetMembersOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -555,6 +566,7 @@ impl pallet_alliance::Config for Runtime {
 	type MembershipManager = RootOrAllianceTwoThirdsMajority;
 	type AnnouncementOrigin = RootOrAllianceTwoThirdsMajority;
 	type Currency = Balances;
+	type Preimages = Preimage;
 	type Slashed = ToParentTreasury<WestendTreasuryAccount, AlliancePalletAccount, Runtime>;
 	type InitializeMembers = AllianceMotion;
 	type MembershipChanged = AllianceMotion;
@@ -707,6 +719,12 @@ pub type UncheckedExtrinsic =
 type Migrations = (
 	// unreleased
 	pallet_collator_selection::migration::v1::MigrateToV1<Runtime>,
+	pallet_alliance::migration::Migration<Runtime>,
+	pallet_core_fellowship::migration::v1::MigrateToV1<Runtime, fellowship::FellowshipCoreInstance>,
+	pallet_core_fellowship::migration::v1::MigrateToV1<Runtime, ambassador::AmbassadorCoreInstance>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+	pallet_xcm::migration::InitMigratedXcmVersion<Runtime>,
+	pallet_preimage::migration::tickets::KeepTickets<Runtime>,
 );
 
 /// Executive: handles dispatch to the various modules.
@@ -826,6 +844,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -836,6 +855,15 @@ impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: keys.encode(), proof }
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -888,6 +916,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_core_fellowship_runtime_api::CoreFellowshipApi<Block, AccountId, BlockNumber, Hash>
+		for Runtime
+	{
+		fn evidence_metadata(
+			who: AccountId,
+		) -> Option<pallet_core_fellowship::EvidenceMetadata<BlockNumber, Hash>> {
+			FellowshipCore::evidence_metadata(&who)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
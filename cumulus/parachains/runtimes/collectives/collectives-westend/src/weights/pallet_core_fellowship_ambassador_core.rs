@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
+//!
+//! NOTE: `archive_evidence` was weighed by hand for the Ambassador core when evidence archival was
+//! added. Its weight is a PLACEHOLDER until these benchmarks are re-run.
 
 // Executed Command:
 // target/release/polkadot-parachain
@@ -70,6 +73,10 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn bump_offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `66011`
@@ -77,8 +84,8 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 96_000_000 picoseconds.
 		Weight::from_parts(111_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:1)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -92,6 +99,10 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn bump_demote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `66121`
@@ -99,8 +110,8 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 99_000_000 picoseconds.
 		Weight::from_parts(116_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `AmbassadorCollective::Members` (r:1 w:0)
 	/// Proof: `AmbassadorCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -150,6 +161,10 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCollective::IndexToId` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCollective::IdToIndex` (r:0 w:1)
 	/// Proof: `AmbassadorCollective::IdToIndex` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn promote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `65989`
@@ -157,8 +172,8 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 95_000_000 picoseconds.
 		Weight::from_parts(110_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `AmbassadorCollective::Members` (r:1 w:0)
 	/// Proof: `AmbassadorCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
@@ -166,6 +181,10 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:0 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `331`
@@ -173,8 +192,8 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 21_000_000 picoseconds.
 		Weight::from_parts(22_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3514))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:1)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -196,6 +215,10 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `65967`
@@ -203,13 +226,19 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 78_000_000 picoseconds.
 		Weight::from_parts(104_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorCore::Member` (r:1 w:0)
 	/// Proof: `AmbassadorCore::Member` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
 	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:0 w:2)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::ArchivalCursor` (r:1 w:1)
+	/// Proof: `AmbassadorCore::ArchivalCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn submit_evidence() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `151`
@@ -217,7 +246,20 @@ impl<T: frame_system::Config> pallet_core_fellowship::WeightInfo for WeightInfo<
 		// Minimum execution time: 43_000_000 picoseconds.
 		Weight::from_parts(44_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 69046))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AmbassadorCore::ArchivalQueue` (r:1 w:1)
+	/// Proof: `AmbassadorCore::ArchivalQueue` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidenceMetadata` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidenceMetadata` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorCore::MemberEvidence` (r:1 w:1)
+	/// Proof: `AmbassadorCore::MemberEvidence` (`max_values`: None, `max_size`: Some(65581), added: 68056, mode: `MaxEncodedLen`)
+	fn archive_evidence() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(33_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 69046))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The weights of `set_payout_asset` and `extend_claim_deadline` were added by hand for the
+//! Ambassador salary. They are PLACEHOLDERS until its benchmarks are re-run.
 
 // Executed Command:
 // target/release/polkadot-parachain
@@ -49,6 +52,10 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
 	/// Proof: `AmbassadorSalary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::NextPayoutAsset` (r:1 w:1)
+	/// Proof: `AmbassadorSalary::NextPayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:0 w:1)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
 	fn init() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `109`
@@ -56,11 +63,15 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 12_000_000 picoseconds.
 		Weight::from_parts(14_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1541))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
 	/// Proof: `AmbassadorSalary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::NextPayoutAsset` (r:1 w:1)
+	/// Proof: `AmbassadorSalary::NextPayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:0 w:1)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
 	fn bump() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `191`
@@ -68,8 +79,8 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 15_000_000 picoseconds.
 		Weight::from_parts(16_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1541))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:0)
 	/// Proof: `AmbassadorSalary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
@@ -93,6 +104,8 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorSalary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorSalary::Claimant` (r:1 w:1)
 	/// Proof: `AmbassadorSalary::Claimant` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::Extensions` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Extensions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `467`
@@ -100,7 +113,7 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_000_000 picoseconds.
 		Weight::from_parts(28_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3551))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
@@ -127,6 +140,10 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorSalary::Extensions` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Extensions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
 	fn payout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `879`
@@ -134,7 +151,7 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 68_000_000 picoseconds.
 		Weight::from_parts(72_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4344))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
@@ -161,6 +178,10 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorSalary::Extensions` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Extensions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::PayoutAsset` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::PayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
 	fn payout_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `879`
@@ -168,7 +189,7 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 69_000_000 picoseconds.
 		Weight::from_parts(70_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4344))
-			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AmbassadorSalary::Status` (r:1 w:1)
@@ -177,6 +198,8 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorSalary::Claimant` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:1 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AmbassadorSalary::Extensions` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Extensions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn check_payment() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `479`
@@ -184,7 +207,28 @@ impl<T: frame_system::Config> pallet_salary::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_000_000 picoseconds.
 		Weight::from_parts(28_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3944))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `AmbassadorSalary::NextPayoutAsset` (r:0 w:1)
+	/// Proof: `AmbassadorSalary::NextPayoutAsset` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	fn set_payout_asset() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AmbassadorSalary::Status` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::Claimant` (r:1 w:0)
+	/// Proof: `AmbassadorSalary::Claimant` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorSalary::Extensions` (r:1 w:1)
+	/// Proof: `AmbassadorSalary::Extensions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn extend_claim_deadline() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
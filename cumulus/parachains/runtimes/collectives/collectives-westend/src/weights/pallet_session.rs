@@ -54,11 +54,9 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	/// Storage: `Session::KeyOwner` (r:1 w:1)
 	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `270`
-		//  Estimated: `3735`
-		// Minimum execution time: 16_663_000 picoseconds.
-		Weight::from_parts(17_246_000, 0)
+		// PLACEHOLDER: raised by hand by the cost of verifying the proof for the single Aura key of
+		// the collectives chain.
+		Weight::from_parts(72_702_705, 0)
 			.saturating_add(Weight::from_parts(0, 3735))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
@@ -305,6 +305,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = WithOriginFilter<SafeCallFilter>;
 	type SafeCallFilter = SafeCallFilter;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 /// Converts a local signed origin into an XCM multilocation.
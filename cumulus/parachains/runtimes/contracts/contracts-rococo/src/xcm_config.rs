@@ -209,6 +209,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 /// Converts a local signed origin into an XCM multilocation.
@@ -84,6 +84,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
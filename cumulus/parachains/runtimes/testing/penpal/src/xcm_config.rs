@@ -279,6 +279,12 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	#[cfg(not(feature = "xcm-trace"))]
+	type Tracer = ();
+	type ProcessedTopics = ();
+	#[cfg(feature = "xcm-trace")]
+	type Tracer = xcm_executor::traits::RecordXcmTrace;
+	type ProcessedTopics = ();
 }
 
 /// No local origins on this chain are allowed to dispatch XCM sends/executions.
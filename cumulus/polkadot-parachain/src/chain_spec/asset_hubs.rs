@@ -202,6 +202,8 @@ fn asset_hub_westend_genesis(
 		"polkadotXcm": {
 			"safeXcmVersion": Some(SAFE_XCM_VERSION),
 		},
+		"foreignAssets":
+			asset_hub_westend_runtime::xcm_config::bridging::to_rococo::foreign_assets_genesis(),
 	})
 }
 
@@ -395,6 +397,8 @@ fn asset_hub_rococo_genesis(
 		"polkadotXcm": asset_hub_rococo_runtime::PolkadotXcmConfig {
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
 			..Default::default()
-		}
+		},
+		"foreignAssets":
+			asset_hub_rococo_runtime::xcm_config::bridging::to_westend::foreign_assets_genesis(),
 	})
 }
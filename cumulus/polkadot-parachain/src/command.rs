@@ -906,6 +906,10 @@ impl CliConfiguration<Self> for RelayChainCli {
 		self.base.base.max_runtime_instances()
 	}
 
+	fn min_runtime_instances(&self) -> Result<Option<usize>> {
+		self.base.base.min_runtime_instances()
+	}
+
 	fn announce_block(&self) -> Result<bool> {
 		self.base.base.announce_block()
 	}
@@ -68,6 +68,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
 		fn can_build_upon(
 			_: <Block as BlockT>::Hash,
@@ -75,6 +76,10 @@ sp_api::impl_runtime_apis! {
 		) -> bool {
 			unimplemented!()
 		}
+
+		fn authoring_velocity() -> u32 {
+			unimplemented!()
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -111,6 +116,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(_: Option<Vec<u8>>) -> Vec<u8> {
 			unimplemented!()
@@ -121,6 +127,13 @@ sp_api::impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
 			unimplemented!()
 		}
+
+		fn generate_session_keys_and_proof(
+			_: Vec<u8>,
+			_: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			unimplemented!()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
@@ -34,6 +34,7 @@ sp_api::decl_runtime_apis! {
 	/// When the unincluded segment is short, Aura chains will allow authors to create multiple
 	/// blocks per slot in order to build a backlog. When it is saturated, this API will limit
 	/// the amount of blocks that can be created.
+	#[api_version(2)]
 	pub trait AuraUnincludedSegmentApi {
 		/// Whether it is legal to extend the chain, assuming the given block is the most
 		/// recently included one as-of the relay parent that will be built against, and
@@ -46,5 +47,12 @@ sp_api::decl_runtime_apis! {
 		/// whose state we are querying against, this must always return `true` as long as the slot
 		/// is more recent than the included block itself.
 		fn can_build_upon(included_hash: Block::Hash, slot: Slot) -> bool;
+
+		/// The number of blocks authors should build per relay chain block.
+		///
+		/// This may change at any block, e.g. through governance, so authors should query it
+		/// anew for every block they build upon.
+		#[api_version(2)]
+		fn authoring_velocity() -> u32;
 	}
 }
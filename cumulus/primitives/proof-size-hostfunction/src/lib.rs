@@ -0,0 +1,87 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Host function exposing the storage proof size to the runtime.
+//!
+//! Provides the [`storage_proof_size`] host function, which returns the size of the storage proof
+//! recorded so far while building a block. The runtime can use it to find out how much of the PoV
+//! budget is left, e.g. to stop servicing queues in `on_idle` before the PoV overflows.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_runtime_interface::runtime_interface;
+
+#[cfg(feature = "std")]
+use sp_externalities::ExternalitiesExt;
+
+#[cfg(feature = "std")]
+use sp_trie::proof_size_extension::ProofSizeExt;
+
+/// Returned by [`storage_proof_size`] if proof recording is disabled.
+pub const PROOF_RECORDING_DISABLED: u64 = u64::MAX;
+
+/// Interface that provides access to the current storage proof size.
+///
+/// Should return the same values during block building and block import.
+#[runtime_interface]
+pub trait StorageProofSize {
+	/// Returns the current storage proof size, or [`PROOF_RECORDING_DISABLED`] if no proof is
+	/// being recorded.
+	fn storage_proof_size(&mut self) -> u64 {
+		self.extension::<ProofSizeExt>()
+			.map_or(PROOF_RECORDING_DISABLED, |e| e.storage_proof_size())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::Blake2Hasher;
+	use sp_state_machine::TestExternalities;
+	use sp_trie::{proof_size_extension::ProofSizeExt, ProofSizeProvider};
+
+	use crate::{storage_proof_size, PROOF_RECORDING_DISABLED};
+
+	const TEST_SIZE: usize = 567;
+
+	struct TestRecorder {
+		test_size: usize,
+	}
+
+	impl ProofSizeProvider for TestRecorder {
+		fn estimate_encoded_size(&self) -> usize {
+			self.test_size
+		}
+	}
+
+	#[test]
+	fn host_function_returns_size_from_recorder() {
+		let mut ext = TestExternalities::<Blake2Hasher>::default();
+		ext.register_extension(ProofSizeExt::new(TestRecorder { test_size: TEST_SIZE }));
+
+		ext.execute_with(|| {
+			assert_eq!(storage_proof_size::storage_proof_size(), TEST_SIZE as u64);
+		});
+	}
+
+	#[test]
+	fn host_function_returns_max_without_extension() {
+		let mut ext = TestExternalities::<Blake2Hasher>::default();
+
+		ext.execute_with(|| {
+			assert_eq!(storage_proof_size::storage_proof_size(), PROOF_RECORDING_DISABLED);
+		});
+	}
+}
@@ -45,6 +45,5 @@ polkadot-js-api \
     --sudo \
     --seed "${seed?}" \
     --types "${types?}" \
-    tx.parasSudoWrapper.sudoScheduleParaInitialize \
-        "${parachain_id?}" \
-        "{ \"genesisHead\":\"${genesis?}\", \"validationCode\":\"${wasm?}\", \"parachain\": true }" \
+    tx.parasGovernance.schedule \
+        "{ \"ScheduleParaInitialize\": { \"id\": ${parachain_id?}, \"genesis\": { \"genesisHead\":\"${genesis?}\", \"validationCode\":\"${wasm?}\", \"paraKind\": true } } }" \
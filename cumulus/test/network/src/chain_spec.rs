@@ -0,0 +1,155 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Building the chain specs of the network with the binaries of its nodes.
+
+use crate::{Error, ParachainConfig, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+use tokio::process::Command;
+
+/// The genesis of a parachain, as registered in the genesis of the relay chain.
+pub(crate) struct ParachainGenesis {
+	/// The id of the parachain.
+	pub id: u32,
+	/// The hex encoded genesis head of the parachain.
+	pub head: String,
+	/// The hex encoded validation code of the parachain.
+	pub code: String,
+}
+
+/// Run `binary` with `args` to completion, returning what it printed to stdout.
+pub(crate) async fn run(binary: &Path, args: &[&str]) -> Result<Vec<u8>> {
+	let output = Command::new(binary).args(args).output().await?;
+	if !output.status.success() {
+		return Err(Error::Command {
+			binary: binary.display().to_string(),
+			args: args.join(" "),
+			stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+		})
+	}
+	Ok(output.stdout)
+}
+
+/// Export the hex encoded genesis head and validation code of the `parachain`.
+pub(crate) async fn export_genesis(parachain: &ParachainConfig) -> Result<ParachainGenesis> {
+	let export = |command: &'static str| async move {
+		let output = run(&parachain.binary, &[command, "--chain", &parachain.chain]).await?;
+		Ok::<_, Error>(String::from_utf8_lossy(&output).trim().to_string())
+	};
+
+	Ok(ParachainGenesis {
+		id: parachain.id,
+		head: export("export-genesis-state").await?,
+		code: export("export-genesis-wasm").await?,
+	})
+}
+
+/// Build the raw chain spec of the relay chain `chain` into `dir`, with the `parachains`
+/// registered at genesis, and return its path.
+pub(crate) async fn build_relay_chain_spec(
+	binary: &Path,
+	chain: &str,
+	parachains: &[ParachainGenesis],
+	dir: &Path,
+) -> Result<String> {
+	let plain = run(binary, &["build-spec", "--chain", chain, "--disable-default-bootnode"]).await?;
+	let mut spec: Value = serde_json::from_slice(&plain)?;
+	register_parachains(&mut spec, parachains)?;
+
+	let plain_path = dir.join("relay-chain-plain.json");
+	std::fs::write(&plain_path, serde_json::to_vec_pretty(&spec)?)?;
+	let plain_path = plain_path.display().to_string();
+
+	let raw =
+		run(binary, &["build-spec", "--chain", &plain_path, "--raw", "--disable-default-bootnode"])
+			.await?;
+	let raw_path = dir.join("relay-chain-raw.json");
+	std::fs::write(&raw_path, raw)?;
+	Ok(raw_path.display().to_string())
+}
+
+/// Add the `parachains` to the genesis config of the `paras` pallet in the plain chain `spec`.
+pub(crate) fn register_parachains(spec: &mut Value, parachains: &[ParachainGenesis]) -> Result<()> {
+	if parachains.is_empty() {
+		return Ok(())
+	}
+
+	let paras = spec
+		.get_mut("genesis")
+		.and_then(find_paras_genesis)
+		.ok_or_else(|| Error::ChainSpec("no genesis config of the `paras` pallet found".into()))?;
+	for parachain in parachains {
+		paras.push(json!([
+			parachain.id,
+			{
+				"genesis_head": parachain.head,
+				"validation_code": parachain.code,
+				"parachain": true,
+			}
+		]));
+	}
+	Ok(())
+}
+
+/// Find the list of genesis paras in the genesis config of the `paras` pallet.
+///
+/// Where the runtime genesis config is found depends on how the chain spec was created, so it is
+/// searched for.
+fn find_paras_genesis(genesis: &mut Value) -> Option<&mut Vec<Value>> {
+	let object = genesis.as_object_mut()?;
+	if object.get("paras").and_then(|paras| paras.get("paras")).map_or(false, Value::is_array) {
+		return object.get_mut("paras")?.get_mut("paras")?.as_array_mut()
+	}
+	object.values_mut().find_map(find_paras_genesis)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn register_parachains_works() {
+		let mut spec = json!({
+			"name": "Rococo Local Testnet",
+			"genesis": {
+				"runtime": {
+					"system": {},
+					"paras": { "paras": [] },
+				}
+			}
+		});
+		let parachain = ParachainGenesis { id: 2000, head: "0x01".into(), code: "0x02".into() };
+
+		register_parachains(&mut spec, &[parachain]).unwrap();
+
+		assert_eq!(
+			spec["genesis"]["runtime"]["paras"]["paras"],
+			json!([[
+				2000,
+				{ "genesis_head": "0x01", "validation_code": "0x02", "parachain": true },
+			]]),
+		);
+	}
+
+	#[test]
+	fn register_parachains_fails_without_paras_pallet() {
+		let mut spec = json!({ "genesis": { "runtime": { "system": {} } } });
+		let parachain = ParachainGenesis { id: 2000, head: "0x01".into(), code: "0x02".into() };
+
+		assert!(matches!(register_parachains(&mut spec, &[parachain]), Err(Error::ChainSpec(_))));
+	}
+}
@@ -0,0 +1,386 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Launch local networks of a relay chain and parachains from Rust tests.
+//!
+//! Other than `xcm-emulator`, which only executes runtimes, the networks are made of the native
+//! binaries of the nodes, like with zombienet, but they are described and controlled from the
+//! tests themselves:
+//!
+//! ```no_run
+//! # async fn test() -> cumulus_test_network::Result<()> {
+//! use cumulus_test_network::{Network, NetworkConfig, ParachainConfig};
+//! use std::time::Duration;
+//!
+//! let network = Network::spawn(
+//! 	NetworkConfig::new("polkadot", "rococo-local")
+//! 		.with_validator("alice")
+//! 		.with_validator("bob")
+//! 		.with_parachain(
+//! 			ParachainConfig::new(1000, "polkadot-parachain", "asset-hub-rococo-local")
+//! 				.with_collator("alice"),
+//! 		),
+//! )
+//! .await?;
+//!
+//! network.collators(1000)[0].wait_for_block(5, Duration::from_secs(120)).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The parachains are registered in the genesis of the relay chain, so they start producing
+//! blocks right away. Nodes are named after well-known development accounts (e.g. `alice`), whose
+//! keys they use. The processes of the nodes are killed and their data removed when the
+//! [`Network`] is dropped.
+
+#![warn(missing_docs)]
+
+mod chain_spec;
+mod node;
+
+pub use node::Node;
+
+use std::{
+	collections::BTreeMap,
+	net::TcpListener,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+use tempfile::TempDir;
+
+const LOG_TARGET: &str = "cumulus-test-network";
+
+/// The names of the development accounts nodes can be named after.
+const WELL_KNOWN_NAMES: [&str; 8] =
+	["alice", "bob", "charlie", "dave", "eve", "ferdie", "one", "two"];
+
+/// How long to wait for a node to start by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Errors that can occur while launching or inspecting a network.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The network config is invalid.
+	#[error("Invalid network config: {0}")]
+	Config(String),
+	/// A binary failed.
+	#[error("`{binary} {args}` failed: {stderr}")]
+	Command {
+		/// The binary that failed.
+		binary: String,
+		/// The arguments it was run with.
+		args: String,
+		/// What it printed to stderr.
+		stderr: String,
+	},
+	/// The chain spec could not be built.
+	#[error("Invalid chain spec: {0}")]
+	ChainSpec(String),
+	/// A node returned an unexpected RPC response.
+	#[error("Invalid RPC response: {0}")]
+	InvalidResponse(String),
+	/// A node did not reach the expected state in time.
+	#[error("Timed out waiting for {0}")]
+	Timeout(String),
+	/// An RPC request failed.
+	#[error(transparent)]
+	Rpc(#[from] jsonrpsee::core::Error),
+	/// An I/O error.
+	#[error(transparent)]
+	Io(#[from] std::io::Error),
+	/// A JSON error.
+	#[error(transparent)]
+	Json(#[from] serde_json::Error),
+}
+
+/// A specialized `Result` type for network operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The configuration of a parachain of the network.
+#[derive(Clone, Debug)]
+pub struct ParachainConfig {
+	id: u32,
+	binary: PathBuf,
+	chain: String,
+	collators: Vec<String>,
+}
+
+impl ParachainConfig {
+	/// The parachain `id` running the chain spec `chain` with the nodes `binary`.
+	///
+	/// `chain` is passed to `--chain`, so it can be a built-in chain or the path of a chain spec.
+	pub fn new(id: u32, binary: impl Into<PathBuf>, chain: impl Into<String>) -> Self {
+		Self { id, binary: binary.into(), chain: chain.into(), collators: Vec::new() }
+	}
+
+	/// Add the collator `name` to the parachain.
+	pub fn with_collator(mut self, name: impl Into<String>) -> Self {
+		self.collators.push(name.into());
+		self
+	}
+}
+
+/// The configuration of a network.
+#[derive(Clone, Debug)]
+pub struct NetworkConfig {
+	binary: PathBuf,
+	chain: String,
+	validators: Vec<String>,
+	parachains: Vec<ParachainConfig>,
+	timeout: Duration,
+}
+
+impl NetworkConfig {
+	/// A relay chain running the chain spec `chain` with the nodes `binary`.
+	///
+	/// `chain` is passed to `--chain` when building the chain spec of the relay chain, so it can be
+	/// a built-in chain or the path of a chain spec.
+	pub fn new(binary: impl Into<PathBuf>, chain: impl Into<String>) -> Self {
+		Self {
+			binary: binary.into(),
+			chain: chain.into(),
+			validators: Vec::new(),
+			parachains: Vec::new(),
+			timeout: DEFAULT_TIMEOUT,
+		}
+	}
+
+	/// Add the validator `name` to the relay chain.
+	pub fn with_validator(mut self, name: impl Into<String>) -> Self {
+		self.validators.push(name.into());
+		self
+	}
+
+	/// Add a parachain to the network.
+	pub fn with_parachain(mut self, parachain: ParachainConfig) -> Self {
+		self.parachains.push(parachain);
+		self
+	}
+
+	/// Set how long to wait for each node to start.
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Check that the network can be launched.
+	fn validate(&self) -> Result<()> {
+		if self.validators.is_empty() {
+			return Err(Error::Config("the relay chain needs at least one validator".into()))
+		}
+		let mut names =
+			self.validators.iter().chain(self.parachains.iter().flat_map(|p| &p.collators));
+		if let Some(name) = names.find(|name| !WELL_KNOWN_NAMES.contains(&name.as_str())) {
+			return Err(Error::Config(format!("`{}` is not a well-known development account", name)))
+		}
+		for (i, parachain) in self.parachains.iter().enumerate() {
+			if parachain.collators.is_empty() {
+				return Err(Error::Config(format!("parachain {} has no collators", parachain.id)))
+			}
+			if self.parachains[..i].iter().any(|p| p.id == parachain.id) {
+				return Err(Error::Config(format!("parachain {} is duplicated", parachain.id)))
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A running network.
+pub struct Network {
+	validators: Vec<Node>,
+	collators: BTreeMap<u32, Vec<Node>>,
+	relay_chain_spec: String,
+	// Dropped last, once the processes of the nodes are killed.
+	base_dir: TempDir,
+}
+
+impl Network {
+	/// Launch the network described by `config`.
+	///
+	/// Returns once the RPC endpoints of all of its nodes accept connections.
+	pub async fn spawn(config: NetworkConfig) -> Result<Self> {
+		config.validate()?;
+		let base_dir = tempfile::tempdir()?;
+
+		let mut parachains = Vec::new();
+		for parachain in &config.parachains {
+			parachains.push(chain_spec::export_genesis(parachain).await?);
+		}
+		let relay_chain_spec = chain_spec::build_relay_chain_spec(
+			&config.binary,
+			&config.chain,
+			&parachains,
+			base_dir.path(),
+		)
+		.await?;
+
+		let mut network =
+			Self { validators: Vec::new(), collators: BTreeMap::new(), relay_chain_spec, base_dir };
+		let mut bootnode = None;
+		for name in &config.validators {
+			let (rpc_port, p2p_port) = (free_port()?, free_port()?);
+			let mut args = network.node_args(name, &network.relay_chain_spec, rpc_port, p2p_port);
+			args.extend([format!("--{}", name), "--validator".into()]);
+			if let Some(bootnode) = &bootnode {
+				args.extend(["--bootnodes".into(), bootnode.clone()]);
+			}
+
+			let node = network.spawn_node(name, &config.binary, args, rpc_port, p2p_port)?;
+			node.wait_until_ready(config.timeout).await?;
+			if bootnode.is_none() {
+				bootnode = Some(node.multiaddr().await?);
+			}
+			network.validators.push(node);
+		}
+		let relay_bootnode = bootnode.expect("the config has at least one validator; qed");
+
+		for parachain in &config.parachains {
+			network.spawn_collators(parachain, &relay_bootnode, config.timeout).await?;
+		}
+
+		Ok(network)
+	}
+
+	/// The validators of the relay chain.
+	pub fn validators(&self) -> &[Node] {
+		&self.validators
+	}
+
+	/// The validator `name` of the relay chain.
+	pub fn validator(&self, name: &str) -> Option<&Node> {
+		self.validators.iter().find(|node| node.name() == name)
+	}
+
+	/// The collators of the parachain `id`, empty if there is no such parachain.
+	pub fn collators(&self, id: u32) -> &[Node] {
+		self.collators.get(&id).map(Vec::as_slice).unwrap_or_default()
+	}
+
+	/// The path of the raw chain spec of the relay chain.
+	pub fn relay_chain_spec(&self) -> &str {
+		&self.relay_chain_spec
+	}
+
+	/// The directory the data and the logs of the nodes are kept in.
+	pub fn base_dir(&self) -> &Path {
+		self.base_dir.path()
+	}
+
+	/// Spawn the collators of `parachain`, connecting their relay chain nodes to `relay_bootnode`.
+	async fn spawn_collators(
+		&mut self,
+		parachain: &ParachainConfig,
+		relay_bootnode: &str,
+		timeout: Duration,
+	) -> Result<()> {
+		let mut collators = Vec::new();
+		let mut bootnode = None;
+		for name in &parachain.collators {
+			let (rpc_port, p2p_port) = (free_port()?, free_port()?);
+			let node_name = format!("{}-{}", parachain.id, name);
+			let mut args = self.node_args(&node_name, &parachain.chain, rpc_port, p2p_port);
+			args.extend([format!("--{}", name), "--collator".into(), "--force-authoring".into()]);
+			if let Some(bootnode) = &bootnode {
+				args.extend(["--bootnodes".into(), bootnode.clone()]);
+			}
+
+			// The arguments of the embedded relay chain node, which keeps its data in the base
+			// path of the collator.
+			args.extend([
+				"--".into(),
+				"--chain".into(),
+				self.relay_chain_spec.clone(),
+				"--port".into(),
+				free_port()?.to_string(),
+				"--rpc-port".into(),
+				free_port()?.to_string(),
+				"--no-prometheus".into(),
+				"--bootnodes".into(),
+				relay_bootnode.into(),
+			]);
+
+			let node = self.spawn_node(&node_name, &parachain.binary, args, rpc_port, p2p_port)?;
+			node.wait_until_ready(timeout).await?;
+			if bootnode.is_none() {
+				bootnode = Some(node.multiaddr().await?);
+			}
+			collators.push(node);
+		}
+		self.collators.insert(parachain.id, collators);
+		Ok(())
+	}
+
+	/// The arguments common to all nodes, keeping their data in the directory `name`.
+	fn node_args(&self, name: &str, chain: &str, rpc_port: u16, p2p_port: u16) -> Vec<String> {
+		let base_path = self.base_dir.path().join(name);
+		vec![
+			"--chain".into(),
+			chain.into(),
+			"--base-path".into(),
+			base_path.display().to_string(),
+			"--port".into(),
+			p2p_port.to_string(),
+			"--rpc-port".into(),
+			rpc_port.to_string(),
+			"--no-prometheus".into(),
+			"--no-telemetry".into(),
+			"--no-hardware-benchmarks".into(),
+		]
+	}
+
+	/// Spawn the node `name`, writing its logs into the base directory.
+	fn spawn_node(
+		&self,
+		name: &str,
+		binary: &Path,
+		args: Vec<String>,
+		rpc_port: u16,
+		p2p_port: u16,
+	) -> Result<Node> {
+		let log_path = self.base_dir.path().join(format!("{}.log", name));
+		log::info!(target: LOG_TARGET, "Spawning {}, logging to {}", name, log_path.display());
+		Node::spawn(name.into(), binary, args, rpc_port, p2p_port, log_path)
+	}
+}
+
+/// A port that is free on the local host.
+fn free_port() -> Result<u16> {
+	Ok(TcpListener::bind("127.0.0.1:0")?.local_addr()?.port())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn validate_rejects_invalid_configs() {
+		let config = NetworkConfig::new("polkadot", "rococo-local");
+		assert!(matches!(config.validate(), Err(Error::Config(_))));
+
+		let config = config.with_validator("alice");
+		assert!(config.validate().is_ok());
+		let unknown = config.clone().with_validator("mallory");
+		assert!(matches!(unknown.validate(), Err(Error::Config(_))));
+
+		let parachain = ParachainConfig::new(1000, "polkadot-parachain", "asset-hub-rococo-local");
+		let no_collators = config.clone().with_parachain(parachain.clone());
+		assert!(matches!(no_collators.validate(), Err(Error::Config(_))));
+
+		let parachain = parachain.with_collator("bob");
+		let duplicated = config.clone().with_parachain(parachain.clone()).with_parachain(parachain);
+		assert!(matches!(duplicated.validate(), Err(Error::Config(_))));
+	}
+}
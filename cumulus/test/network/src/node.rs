@@ -0,0 +1,180 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Handles to the nodes of a running network.
+
+use crate::{Error, Result, LOG_TARGET};
+use jsonrpsee::{
+	core::client::ClientT,
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use serde_json::Value;
+use std::{
+	fs::File,
+	future::Future,
+	path::{Path, PathBuf},
+	process::Stdio,
+	time::Duration,
+};
+use tokio::{
+	process::{Child, Command},
+	time::Instant,
+};
+
+/// How often the state of a node is polled while waiting for it.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A node of a running network.
+///
+/// The process of the node is killed when the handle is dropped.
+pub struct Node {
+	name: String,
+	rpc_port: u16,
+	p2p_port: u16,
+	log_path: PathBuf,
+	process: Child,
+}
+
+impl Node {
+	/// Spawn `binary` with `args`, writing its output to `log_path`.
+	pub(crate) fn spawn(
+		name: String,
+		binary: &Path,
+		args: Vec<String>,
+		rpc_port: u16,
+		p2p_port: u16,
+		log_path: PathBuf,
+	) -> Result<Self> {
+		log::debug!(
+			target: LOG_TARGET,
+			"Spawning {}: {} {}",
+			name,
+			binary.display(),
+			args.join(" "),
+		);
+
+		let log = File::create(&log_path)?;
+		let process = Command::new(binary)
+			.args(&args)
+			.stdout(Stdio::from(log.try_clone()?))
+			.stderr(Stdio::from(log))
+			.kill_on_drop(true)
+			.spawn()?;
+
+		Ok(Self { name, rpc_port, p2p_port, log_path, process })
+	}
+
+	/// The name of the node.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// The URL of the RPC endpoint of the node.
+	pub fn rpc_url(&self) -> String {
+		format!("ws://127.0.0.1:{}", self.rpc_port)
+	}
+
+	/// The port the node listens on for peers.
+	pub fn p2p_port(&self) -> u16 {
+		self.p2p_port
+	}
+
+	/// The file the output of the node is written to.
+	pub fn log_path(&self) -> &Path {
+		&self.log_path
+	}
+
+	/// Returns `true` if the process of the node has not exited.
+	pub fn is_running(&mut self) -> bool {
+		matches!(self.process.try_wait(), Ok(None))
+	}
+
+	/// Connect an RPC client to the node.
+	pub async fn rpc_client(&self) -> Result<WsClient> {
+		Ok(WsClientBuilder::default().build(self.rpc_url()).await?)
+	}
+
+	/// The number of the best block of the node.
+	pub async fn best_block_number(&self) -> Result<u32> {
+		let client = self.rpc_client().await?;
+		let header: Value = client.request("chain_getHeader", rpc_params![]).await?;
+		block_number(&header)
+	}
+
+	/// The number of the finalized block of the node.
+	pub async fn finalized_block_number(&self) -> Result<u32> {
+		let client = self.rpc_client().await?;
+		let hash: String = client.request("chain_getFinalizedHead", rpc_params![]).await?;
+		let header: Value = client.request("chain_getHeader", rpc_params![hash]).await?;
+		block_number(&header)
+	}
+
+	/// Wait for the best block of the node to reach `number`.
+	pub async fn wait_for_block(&self, number: u32, timeout: Duration) -> Result<()> {
+		let what = format!("{} to import block #{}", self.name, number);
+		wait_for(what, timeout, || async { Ok(self.best_block_number().await? >= number) }).await
+	}
+
+	/// Wait for the finalized block of the node to reach `number`.
+	pub async fn wait_for_finalized_block(&self, number: u32, timeout: Duration) -> Result<()> {
+		let what = format!("{} to finalize block #{}", self.name, number);
+		wait_for(what, timeout, || async { Ok(self.finalized_block_number().await? >= number) })
+			.await
+	}
+
+	/// Wait for the RPC endpoint of the node to accept connections.
+	pub(crate) async fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+		let what = format!("{} to start", self.name);
+		wait_for(what, timeout, || async { Ok(self.rpc_client().await.is_ok()) }).await
+	}
+
+	/// The address other nodes can use to connect to this node.
+	pub(crate) async fn multiaddr(&self) -> Result<String> {
+		let client = self.rpc_client().await?;
+		let peer_id: String = client.request("system_localPeerId", rpc_params![]).await?;
+		Ok(format!("/ip4/127.0.0.1/tcp/{}/p2p/{}", self.p2p_port, peer_id))
+	}
+}
+
+/// Poll `condition` until it holds, failing if it does not within `timeout`.
+async fn wait_for<F, Fut>(what: String, timeout: Duration, mut condition: F) -> Result<()>
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = Result<bool>>,
+{
+	let deadline = Instant::now() + timeout;
+	loop {
+		match condition().await {
+			Ok(true) => return Ok(()),
+			Ok(false) => {},
+			Err(err) => log::trace!(target: LOG_TARGET, "Waiting for {}: {:?}", what, err),
+		}
+		if Instant::now() >= deadline {
+			return Err(Error::Timeout(what))
+		}
+		tokio::time::sleep(POLL_INTERVAL).await;
+	}
+}
+
+/// The number of the block of the JSON `header`.
+fn block_number(header: &Value) -> Result<u32> {
+	header
+		.get("number")
+		.and_then(Value::as_str)
+		.and_then(|number| u32::from_str_radix(number.trim_start_matches("0x"), 16).ok())
+		.ok_or_else(|| Error::InvalidResponse(format!("invalid block header: {}", header)))
+}
@@ -27,7 +27,11 @@ pub use std::{
 pub use cumulus_primitives_core::AggregateMessageOrigin as CumulusAggregateMessageOrigin;
 pub use frame_support::{
 	assert_ok,
-	sp_runtime::{traits::Header as HeaderT, DispatchResult},
+	dispatch::DispatchResultWithPostInfo,
+	sp_runtime::{
+		traits::{Dispatchable, Header as HeaderT},
+		DispatchError, DispatchResult,
+	},
 	traits::{
 		EnqueueMessage, ExecuteOverweightError, Get, Hooks, OnInitialize, OriginTrait,
 		ProcessMessage, ProcessMessageError, ServiceQueues,
@@ -63,7 +67,12 @@ pub use xcm::v3::prelude::{
 	Ancestor, MultiAssets, MultiLocation, Parachain as ParachainJunction, Parent, WeightLimit,
 	XcmHash, X1,
 };
-pub use xcm_executor::traits::ConvertLocation;
+pub use xcm_executor::traits::{ConvertLocation, RecordXcmTrace};
+
+pub mod scenario;
+pub use scenario::{
+	DeliveredMessage, Scenario, ScenarioChain, ScenarioError, ScenarioOrigin, ScenarioStep,
+};
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
@@ -165,6 +174,12 @@ pub trait Network {
 	fn process_horizontal_messages();
 	fn process_upward_messages();
 	fn process_bridged_messages();
+	/// Dispatch the encoded `call` on the chain named `chain`, see [`scenario::dispatch_encoded`].
+	fn dispatch_encoded(
+		chain: &str,
+		origin: ScenarioOrigin,
+		call: Vec<u8>,
+	) -> Result<DispatchResultWithPostInfo, ScenarioError>;
 	fn hrmp_channel_parachain_inherent_data(
 		para_id: u32,
 		relay_parent_number: u32,
@@ -388,6 +403,22 @@ macro_rules! decl_test_relay_chains {
 				}
 			}
 
+			impl<N: $crate::Network> $crate::ScenarioChain for $name<N> {
+				fn scenario_name() -> &'static str {
+					stringify!($name)
+				}
+
+				fn execute_encoded(
+					origin: $crate::ScenarioOrigin,
+					call: &[u8],
+				) -> Result<$crate::DispatchResultWithPostInfo, $crate::ScenarioError> {
+					use $crate::{Decode, Dispatchable, TestExt};
+					let call = <Self as $crate::Chain>::RuntimeCall::decode(&mut &call[..])
+						.map_err($crate::ScenarioError::Decode)?;
+					Ok(Self::execute_with(|| call.dispatch(origin.into_origin())))
+				}
+			}
+
 			$crate::paste::paste! {
 				pub trait [<$name RelayPallet>] {
 					$(
@@ -610,6 +641,22 @@ macro_rules! decl_test_parachains {
 				}
 			}
 
+			impl<N: $crate::Network> $crate::ScenarioChain for $name<N> {
+				fn scenario_name() -> &'static str {
+					stringify!($name)
+				}
+
+				fn execute_encoded(
+					origin: $crate::ScenarioOrigin,
+					call: &[u8],
+				) -> Result<$crate::DispatchResultWithPostInfo, $crate::ScenarioError> {
+					use $crate::{Decode, Dispatchable, TestExt};
+					let call = <Self as $crate::Chain>::RuntimeCall::decode(&mut &call[..])
+						.map_err($crate::ScenarioError::Decode)?;
+					Ok(Self::execute_with(|| call.dispatch(origin.into_origin())))
+				}
+			}
+
 			impl<N: $crate::Network> $crate::Parachain for $name<N> {
 				type XcmpMessageHandler = $xcmp_message_handler;
 				type LocationToAccountId = $location_to_account;
@@ -655,6 +702,10 @@ macro_rules! decl_test_parachains {
 							.clone()
 						);
 						<Self as Chain>::System::initialize(&block_number, &parent_head_data.hash(), &Default::default());
+						$crate::scenario::record::<N>(|| $crate::ScenarioStep::Block {
+							chain: stringify!($name).into(),
+							number: block_number,
+						});
 						<<Self as Parachain>::ParachainSystem as Hooks<$crate::BlockNumber>>::on_initialize(block_number);
 
 						let _ = <Self as Parachain>::ParachainSystem::set_validation_data(
@@ -963,6 +1014,24 @@ macro_rules! decl_test_networks {
 					})
 				}
 
+				fn dispatch_encoded(
+					chain: &str,
+					origin: $crate::ScenarioOrigin,
+					call: Vec<u8>,
+				) -> Result<$crate::DispatchResultWithPostInfo, $crate::ScenarioError> {
+					use $crate::{scenario, ScenarioChain};
+
+					if chain == <$relay_chain<Self>>::scenario_name() {
+						return scenario::dispatch_encoded::<$relay_chain<Self>>(origin, call)
+					}
+					$(
+						if chain == <$parachain<Self>>::scenario_name() {
+							return scenario::dispatch_encoded::<$parachain<Self>>(origin, call)
+						}
+					)*
+					Err($crate::ScenarioError::UnknownChain(chain.into()))
+				}
+
 				fn process_messages() {
 					while Self::has_unprocessed_messages() {
 						Self::process_upward_messages();
@@ -1013,6 +1082,9 @@ macro_rules! decl_test_networks {
 										);
 									});
 									$crate::log::debug!(target: concat!("dmp::", stringify!($name)) , "DMP messages processed {:?} to para_id {:?}", msgs.clone(), &to_para_id);
+									$crate::scenario::record::<Self>(|| $crate::ScenarioStep::Message(
+										$crate::DeliveredMessage::Downward { to: to_para_id, sent_at: block, msg: msg.clone() },
+									));
 									$crate::DMP_DONE.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().push_back((to_para_id, block, msg)));
 								}
 							}
@@ -1036,6 +1108,11 @@ macro_rules! decl_test_networks {
 									let _ =  <$parachain<Self> as Parachain>::MessageProcessor::service_queues($crate::Weight::MAX);
 								});
 								$crate::log::debug!(target: concat!("hrmp::", stringify!($name)) , "HRMP messages processed {:?} to para_id {:?}", &messages, &to_para_id);
+								for (from, sent_at, msg) in &messages {
+									$crate::scenario::record::<Self>(|| $crate::ScenarioStep::Message(
+										$crate::DeliveredMessage::Horizontal { from: *from, to: to_para_id, sent_at: *sent_at, msg: msg.clone() },
+									));
+								}
 							}
 						)*
 					}
@@ -1055,6 +1132,9 @@ macro_rules! decl_test_networks {
 							);
 						});
 						$crate::log::debug!(target: concat!("ump::", stringify!($name)) , "Upward message processed {:?} from para_id {:?}", &msg, &from_para_id);
+						$crate::scenario::record::<Self>(|| $crate::ScenarioStep::Message(
+							$crate::DeliveredMessage::Upward { from: from_para_id, msg },
+						));
 					}
 				}
 
@@ -1075,6 +1155,9 @@ macro_rules! decl_test_networks {
 									<<Self::Bridge as Bridge>::Handler as BridgeMessageHandler>::notify_source_message_delivery(msg.id);
 								});
 								$crate::log::debug!(target: concat!("bridge::", stringify!($name)) , "Bridged message processed {:?}", msg.clone());
+								$crate::scenario::record::<Self>(|| $crate::ScenarioStep::Message(
+									$crate::DeliveredMessage::Bridged { id: msg.id, nonce: msg.nonce, payload: msg.payload },
+								));
 							}
 						}
 					}
@@ -1287,6 +1370,7 @@ macro_rules! assert_expected_events {
 			<$chain as $crate::Chain>::events().iter().for_each(|event| {
 				$crate::log::debug!(target: concat!("events::", stringify!($chain)), "{:?}", event);
 			});
+			message.push($crate::helpers::failed_xcm_instructions());
 			panic!("{}", message.concat())
 		}
 	}
@@ -1554,4 +1638,26 @@ pub mod helpers {
 			.public();
 		sp_runtime::MultiSigner::from(pubkey).into_account()
 	}
+
+	/// Describe the XCM instructions that failed since the last call, in the order in which they
+	/// were executed.
+	///
+	/// Only instructions executed by runtimes that trace with [`RecordXcmTrace`] are included, as
+	/// the system runtimes do when built with their `xcm-trace` feature.
+	pub fn failed_xcm_instructions() -> String {
+		let failed = RecordXcmTrace::take()
+			.into_iter()
+			.filter_map(|trace| {
+				let error = trace.result.err()?;
+				Some(format!(
+					" - Instruction #{} {:?} of origin {:?} failed with {:?}\n",
+					trace.index, trace.instruction, trace.origin, error
+				))
+			})
+			.collect::<Vec<_>>();
+		if failed.is_empty() {
+			return String::new()
+		}
+		format!("\n\nFailed XCM instructions:\n{}", failed.concat())
+	}
 }
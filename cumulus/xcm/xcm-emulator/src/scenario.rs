@@ -0,0 +1,255 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Recording and replay of emulated test runs.
+//!
+//! While a [`Network`] is recording, the extrinsics dispatched with [`dispatch`], the blocks of
+//! its parachains and the messages delivered between its chains are recorded into a
+//! [`Scenario`]. A scenario can be saved to a file and replayed later, e.g. against a modified
+//! runtime, to find the first step at which the replayed run diverges from the recorded one.
+
+use crate::{
+	AccountId, Chain, Decode, DispatchError, DispatchResultWithPostInfo, Encode, Error, HashMap,
+	Network, OriginTrait, ParaId, RefCell, RelayBlockNumber,
+};
+use std::{fmt, path::Path};
+
+thread_local! {
+	/// The scenario being recorded for each network.
+	pub static RECORDINGS: RefCell<HashMap<String, Scenario>> = RefCell::new(HashMap::new());
+}
+
+/// The origin of a recorded extrinsic.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum ScenarioOrigin {
+	/// The root origin.
+	Root,
+	/// A signed origin.
+	Signed(AccountId),
+	/// The none origin.
+	None,
+}
+
+impl ScenarioOrigin {
+	/// Convert into the origin of a runtime.
+	pub fn into_origin<O: OriginTrait<AccountId = AccountId>>(self) -> O {
+		match self {
+			Self::Root => O::root(),
+			Self::Signed(who) => O::signed(who),
+			Self::None => O::none(),
+		}
+	}
+}
+
+/// A message delivered to a chain of a network.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum DeliveredMessage {
+	/// A downward message processed by the parachain `to`.
+	Downward { to: u32, sent_at: RelayBlockNumber, msg: Vec<u8> },
+	/// A horizontal message processed by the parachain `to`.
+	Horizontal { from: ParaId, to: u32, sent_at: RelayBlockNumber, msg: Vec<u8> },
+	/// An upward message from the parachain `from` processed by the relay chain.
+	Upward { from: u32, msg: Vec<u8> },
+	/// A message dispatched by the target of the bridge of the network.
+	Bridged { id: u32, nonce: u64, payload: Vec<u8> },
+}
+
+/// A step of a recorded [`Scenario`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum ScenarioStep {
+	/// The block `number` of the parachain `chain` was initialized.
+	Block { chain: String, number: u32 },
+	/// The encoded `call` was dispatched on `chain` from `origin`, with `result`.
+	Extrinsic {
+		chain: String,
+		origin: ScenarioOrigin,
+		call: Vec<u8>,
+		result: Result<(), DispatchError>,
+	},
+	/// A message was delivered.
+	Message(DeliveredMessage),
+}
+
+/// The first step at which two runs of a scenario differ.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+	/// The index of the step.
+	pub index: usize,
+	/// The step of the recorded run, if it has that many steps.
+	pub recorded: Option<ScenarioStep>,
+	/// The step of the replayed run, if it has that many steps.
+	pub replayed: Option<ScenarioStep>,
+}
+
+/// A recorded run of a [`Network`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct Scenario {
+	/// The name of the network.
+	pub network: String,
+	/// The recorded steps, in order.
+	pub steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+	/// Save the scenario to the file at `path`.
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ScenarioError> {
+		std::fs::write(path, self.encode()).map_err(ScenarioError::Io)
+	}
+
+	/// Load a scenario saved to the file at `path`.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+		let encoded = std::fs::read(path).map_err(ScenarioError::Io)?;
+		Self::decode(&mut &encoded[..]).map_err(ScenarioError::Decode)
+	}
+
+	/// The messages delivered during the scenario.
+	pub fn messages(&self) -> impl Iterator<Item = &DeliveredMessage> {
+		self.steps.iter().filter_map(|step| match step {
+			ScenarioStep::Message(message) => Some(message),
+			_ => None,
+		})
+	}
+
+	/// Reset `N` and dispatch the recorded extrinsics again, in order, returning the replayed run.
+	///
+	/// The blocks and messages of the replayed run are produced by the current runtimes of `N`,
+	/// so that they can be compared to the recorded ones with [`Self::first_divergence`].
+	pub fn replay<N: Network>(&self) -> Result<Scenario, ScenarioError> {
+		if self.network != N::name() {
+			return Err(ScenarioError::UnknownNetwork(self.network.clone()))
+		}
+
+		N::reset();
+		start_recording::<N>();
+		let replayed = self.steps.iter().try_for_each(|step| match step {
+			ScenarioStep::Extrinsic { chain, origin, call, .. } =>
+				N::dispatch_encoded(chain, origin.clone(), call.clone()).map(|_| ()),
+			_ => Ok(()),
+		});
+		let scenario = stop_recording::<N>().unwrap_or_default();
+		replayed.map(|()| scenario)
+	}
+
+	/// The first step at which `replayed` differs from this scenario, if any.
+	pub fn first_divergence(&self, replayed: &Scenario) -> Option<Divergence> {
+		let len = self.steps.len().max(replayed.steps.len());
+		(0..len).find_map(|index| {
+			let (recorded, replayed) = (self.steps.get(index), replayed.steps.get(index));
+			(recorded != replayed).then(|| Divergence {
+				index,
+				recorded: recorded.cloned(),
+				replayed: replayed.cloned(),
+			})
+		})
+	}
+}
+
+/// A chain whose extrinsics can be recorded and replayed.
+///
+/// Implemented by [`decl_test_relay_chains`](crate::decl_test_relay_chains) and
+/// [`decl_test_parachains`](crate::decl_test_parachains).
+pub trait ScenarioChain: Chain {
+	/// The name of the chain in scenarios.
+	fn scenario_name() -> &'static str;
+
+	/// Decode `call` and dispatch it from `origin` with [`TestExt::execute_with`].
+	///
+	/// [`TestExt::execute_with`]: crate::TestExt::execute_with
+	fn execute_encoded(
+		origin: ScenarioOrigin,
+		call: &[u8],
+	) -> Result<DispatchResultWithPostInfo, ScenarioError>;
+}
+
+/// Start recording a scenario of `N`, discarding any scenario being recorded.
+pub fn start_recording<N: Network>() {
+	RECORDINGS.with(|r| {
+		let scenario = Scenario { network: N::name().into(), steps: Vec::new() };
+		r.borrow_mut().insert(N::name().into(), scenario)
+	});
+}
+
+/// Stop recording the scenario of `N`, and return it if it was being recorded.
+pub fn stop_recording<N: Network>() -> Option<Scenario> {
+	RECORDINGS.with(|r| r.borrow_mut().remove(N::name()))
+}
+
+/// Record the step built by `step` if a scenario of `N` is being recorded.
+pub fn record<N: Network>(step: impl FnOnce() -> ScenarioStep) {
+	RECORDINGS.with(|r| {
+		if let Some(scenario) = r.borrow_mut().get_mut(N::name()) {
+			scenario.steps.push(step());
+		}
+	});
+}
+
+/// Dispatch `call` on `C` from `origin` with [`TestExt::execute_with`], recording it.
+///
+/// Must not be called from within [`TestExt::execute_with`].
+///
+/// [`TestExt::execute_with`]: crate::TestExt::execute_with
+pub fn dispatch<C: ScenarioChain>(
+	origin: ScenarioOrigin,
+	call: C::RuntimeCall,
+) -> DispatchResultWithPostInfo
+where
+	C::RuntimeCall: Encode,
+{
+	dispatch_encoded::<C>(origin, call.encode()).expect("an encoded call decodes; qed")
+}
+
+/// Dispatch the encoded `call` on `C` from `origin`, recording it.
+///
+/// Fails if `call` is not a call of `C`.
+pub fn dispatch_encoded<C: ScenarioChain>(
+	origin: ScenarioOrigin,
+	call: Vec<u8>,
+) -> Result<DispatchResultWithPostInfo, ScenarioError> {
+	let result = C::execute_encoded(origin.clone(), &call)?;
+	record::<C::Network>(|| ScenarioStep::Extrinsic {
+		chain: C::scenario_name().into(),
+		origin,
+		call,
+		result: result.map(|_| ()).map_err(|e| e.error),
+	});
+	Ok(result)
+}
+
+/// An error recording or replaying a [`Scenario`].
+#[derive(Debug)]
+pub enum ScenarioError {
+	/// The scenario file could not be read or written.
+	Io(std::io::Error),
+	/// The scenario or one of its calls could not be decoded.
+	Decode(codec::Error),
+	/// The scenario was recorded on another network.
+	UnknownNetwork(String),
+	/// The network has no chain of that name.
+	UnknownChain(String),
+}
+
+impl Error for ScenarioError {}
+
+impl fmt::Display for ScenarioError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Io(e) => write!(f, "Scenario file error: {}", e),
+			Self::Decode(e) => write!(f, "Decoding error: {}", e),
+			Self::UnknownNetwork(network) => write!(f, "Scenario recorded on network {}", network),
+			Self::UnknownChain(chain) => write!(f, "No chain {} in the network", chain),
+		}
+	}
+}
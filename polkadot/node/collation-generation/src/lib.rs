@@ -25,8 +25,10 @@
 //!   * Determine if the para is scheduled on any core by fetching the `availability_cores` Runtime
 //!     API.
 //!   * Use the Runtime API subsystem to fetch the full validation data.
-//!   * Invoke the `collator`, and use its outputs to produce a [`CandidateReceipt`], signed with
-//!     the configuration's `key`.
+//!   * Invoke the `collator`, and check its output with the `pre_validation` function, if any.
+//!     Collations failing the check are dropped.
+//!   * Use the outputs of the `collator` to produce a [`CandidateReceipt`], signed with the
+//!     configuration's `key`.
 //!   * Dispatch a [`CollatorProtocolMessage::DistributeCollation`]`(receipt, pov)`.
 
 #![deny(missing_docs)]
@@ -34,8 +36,8 @@
 use futures::{channel::oneshot, future::FutureExt, join, select};
 use parity_scale_codec::Encode;
 use polkadot_node_primitives::{
-	AvailableData, Collation, CollationGenerationConfig, CollationSecondedSignal, PoV,
-	SubmitCollationParams,
+	AvailableData, Collation, CollationGenerationConfig, CollationPreValidationFn,
+	CollationSecondedSignal, PoV, SubmitCollationParams,
 };
 use polkadot_node_subsystem::{
 	messages::{CollationGenerationMessage, CollatorProtocolMessage},
@@ -350,6 +352,7 @@ async fn handle_new_activations<Context>(
 							n_validators,
 						},
 						task_config.key.clone(),
+						task_config.pre_validation.as_ref(),
 						&mut task_sender,
 						result_sender,
 						&metrics,
@@ -419,6 +422,7 @@ async fn handle_submit_collation<Context>(
 	construct_and_distribute_receipt(
 		collation,
 		config.key.clone(),
+		config.pre_validation.as_ref(),
 		ctx.sender(),
 		result_sender,
 		metrics,
@@ -439,9 +443,12 @@ struct PreparedCollation {
 
 /// Takes a prepared collation, along with its context, and produces a candidate receipt
 /// which is distributed to validators.
+///
+/// The collation is dropped if it fails the `pre_validation` check.
 async fn construct_and_distribute_receipt(
 	collation: PreparedCollation,
 	key: CollatorPair,
+	pre_validation: Option<&CollationPreValidationFn>,
 	sender: &mut impl overseer::CollationGenerationSenderTrait,
 	result_sender: Option<oneshot::Sender<CollationSecondedSignal>>,
 	metrics: &Metrics,
@@ -455,6 +462,21 @@ async fn construct_and_distribute_receipt(
 		n_validators,
 	} = collation;
 
+	if let Some(pre_validation) = pre_validation {
+		let _timer = metrics.time_collation_pre_validation();
+		if let Err(reason) = pre_validation(relay_parent, &validation_data, &collation).await {
+			gum::debug!(
+				target: LOG_TARGET,
+				para_id = %para_id,
+				?relay_parent,
+				%reason,
+				"collation failed pre-validation",
+			);
+			metrics.on_collation_pre_validation_rejected();
+			return
+		}
+	}
+
 	let persisted_validation_data_hash = validation_data.hash();
 	let parent_head_data_hash = validation_data.parent_head.hash();
 
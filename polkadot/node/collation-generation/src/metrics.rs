@@ -23,6 +23,8 @@ pub(crate) struct MetricsInner {
 	pub(crate) new_activations_per_relay_parent: prometheus::Histogram,
 	pub(crate) new_activations_per_availability_core: prometheus::Histogram,
 	pub(crate) submit_collation: prometheus::Histogram,
+	pub(crate) collations_pre_validation_rejected_total: prometheus::Counter<prometheus::U64>,
+	pub(crate) collation_pre_validation: prometheus::Histogram,
 }
 
 /// `CollationGenerationSubsystem` metrics.
@@ -63,6 +65,19 @@ impl Metrics {
 	pub fn time_submit_collation(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.submit_collation.start_timer())
 	}
+
+	pub fn on_collation_pre_validation_rejected(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.collations_pre_validation_rejected_total.inc();
+		}
+	}
+
+	/// Provide a timer for pre-validating a collation which updates on drop.
+	pub fn time_collation_pre_validation(
+		&self,
+	) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
+		self.0.as_ref().map(|metrics| metrics.collation_pre_validation.start_timer())
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -111,6 +126,22 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			collations_pre_validation_rejected_total: prometheus::register(
+				prometheus::Counter::new(
+					"polkadot_parachain_collations_pre_validation_rejected_total",
+					"Number of collations which failed pre-validation and were not advertised."
+				)?,
+				registry,
+			)?,
+			collation_pre_validation: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_collation_generation_pre_validation",
+						"Time spent pre-validating a collation before advertising it",
+					)
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
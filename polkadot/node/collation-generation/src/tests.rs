@@ -116,6 +116,7 @@ fn test_config<Id: Into<ParaId>>(para_id: Id) -> CollationGenerationConfig {
 	CollationGenerationConfig {
 		key: CollatorPair::generate().0,
 		collator: Some(Box::new(|_: Hash, _vd: &PersistedValidationData| TestCollator.boxed())),
+		pre_validation: None,
 		para_id: para_id.into(),
 	}
 }
@@ -124,6 +125,7 @@ fn test_config_no_collator<Id: Into<ParaId>>(para_id: Id) -> CollationGeneration
 	CollationGenerationConfig {
 		key: CollatorPair::generate().0,
 		collator: None,
+		pre_validation: None,
 		para_id: para_id.into(),
 	}
 }
@@ -634,3 +636,68 @@ fn submit_collation_leads_to_distribution() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn collations_failing_pre_validation_are_not_distributed() {
+	let rejected_relay_parent = Hash::repeat_byte(0);
+	let relay_parent = Hash::repeat_byte(1);
+	let para_id = ParaId::from(5);
+	let pre_validation: CollationPreValidationFn = Box::new(move |relay_parent, _, _| {
+		let result = if relay_parent == rejected_relay_parent {
+			Err("invalid collation".into())
+		} else {
+			Ok(())
+		};
+		async move { result }.boxed()
+	});
+	let config = CollationGenerationConfig {
+		pre_validation: Some(pre_validation),
+		..test_config_no_collator(para_id)
+	};
+
+	test_harness(|mut virtual_overseer| async move {
+		virtual_overseer
+			.send(FromOrchestra::Communication {
+				msg: CollationGenerationMessage::Initialize(config),
+			})
+			.await;
+
+		for relay_parent in [rejected_relay_parent, relay_parent] {
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: CollationGenerationMessage::SubmitCollation(SubmitCollationParams {
+						relay_parent,
+						collation: test_collation(),
+						parent_head: vec![1, 2, 3].into(),
+						validation_code_hash: Hash::repeat_byte(42).into(),
+						result_sender: None,
+					}),
+				})
+				.await;
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::Validators(tx))) => {
+					let _ = tx.send(Ok(vec![Sr25519Keyring::Alice.public().into()]));
+				}
+			);
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::PersistedValidationData(_, _, tx))) => {
+					let _ = tx.send(Ok(Some(test_validation_data())));
+				}
+			);
+		}
+
+		// Only the collation passing pre-validation is distributed.
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::CollatorProtocol(CollatorProtocolMessage::DistributeCollation(ccr, ..)) => {
+				assert_eq!(ccr.descriptor().relay_parent, relay_parent);
+			}
+		);
+
+		virtual_overseer
+	});
+}
@@ -823,6 +823,7 @@ fn test_collator_generation_msg() -> CollationGenerationMessage {
 	CollationGenerationMessage::Initialize(CollationGenerationConfig {
 		key: CollatorPair::generate().0,
 		collator: Some(Box::new(|_, _| TestCollator.boxed())),
+		pre_validation: None,
 		para_id: Default::default(),
 	})
 }
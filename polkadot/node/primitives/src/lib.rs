@@ -486,6 +486,25 @@ pub type CollatorFn = Box<
 		+ Sync,
 >;
 
+/// Collation pre-validation function.
+///
+/// Will be called with the hash of the relay parent, the [`PersistedValidationData`] and the
+/// [`Collation`] produced by the collator, before the collation is advertised to validators.
+///
+/// This is meant to be a fast local check of the validity of the collation, so that collations
+/// which would be rejected by the validators are not advertised in the first place. Returns an
+/// error describing why the collation is invalid, if it is.
+#[cfg(not(target_os = "unknown"))]
+pub type CollationPreValidationFn = Box<
+	dyn Fn(
+			Hash,
+			&PersistedValidationData,
+			&Collation,
+		) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>
+		+ Send
+		+ Sync,
+>;
+
 /// Configuration for the collation generator
 #[cfg(not(target_os = "unknown"))]
 pub struct CollationGenerationConfig {
@@ -496,6 +515,10 @@ pub struct CollationGenerationConfig {
 	/// If this is `None`, it implies that collations are intended to be submitted
 	/// out-of-band and not pulled out of the function.
 	pub collator: Option<CollatorFn>,
+	/// Collation pre-validation function. See [`CollationPreValidationFn`] for more details.
+	///
+	/// If this is `None`, collations are advertised without being checked first.
+	pub pre_validation: Option<CollationPreValidationFn>,
 	/// The parachain that this collator collates for
 	pub para_id: ParaId,
 }
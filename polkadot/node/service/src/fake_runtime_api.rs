@@ -257,6 +257,10 @@ sp_api::impl_runtime_apis! {
 		) -> Option<beefy_primitives::OpaqueKeyOwnershipProof> {
 			unimplemented!()
 		}
+
+		fn signature_scheme() -> beefy_primitives::SignatureScheme {
+			unimplemented!()
+		}
 	}
 
 	impl sp_mmr_primitives::MmrApi<Block, Hash, BlockNumber> for Runtime {
@@ -275,6 +279,13 @@ sp_api::impl_runtime_apis! {
 			unimplemented!()
 		}
 
+		fn generate_leaf_proof(
+			_: Vec<sp_mmr_primitives::LeafIndex>,
+			_: Option<BlockNumber>,
+		) -> Result<(Vec<sp_mmr_primitives::EncodableOpaqueLeaf>, sp_mmr_primitives::Proof<Hash>), sp_mmr_primitives::Error> {
+			unimplemented!()
+		}
+
 		fn verify_proof(_: Vec<sp_mmr_primitives::EncodableOpaqueLeaf>, _: sp_mmr_primitives::Proof<Hash>)
 			-> Result<(), sp_mmr_primitives::Error>
 		{
@@ -355,6 +366,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(_: Option<Vec<u8>>) -> Vec<u8> {
 			unimplemented!()
@@ -365,6 +377,13 @@ sp_api::impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
 			unimplemented!()
 		}
+
+		fn generate_session_keys_and_proof(
+			_: Vec<u8>,
+			_: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			unimplemented!()
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -126,6 +126,24 @@ pub type FullClient = service::TFullClient<
 /// imported and generated.
 const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
 
+/// The quotas of the persistent offchain storage written by the offchain workers.
+#[cfg(feature = "full-node")]
+fn offchain_storage_quotas() -> Vec<sc_offchain::StorageQuota> {
+	const MIB: u64 = 1024 * 1024;
+	vec![
+		sc_offchain::StorageQuota {
+			namespace: b"parity/im-online-heartbeat/".to_vec(),
+			max_bytes: MIB,
+		},
+		sc_offchain::StorageQuota {
+			namespace: b"parity/multi-phase-unsigned-election".to_vec(),
+			max_bytes: 16 * MIB,
+		},
+		// Everything else.
+		sc_offchain::StorageQuota { namespace: Vec::new(), max_bytes: 64 * MIB },
+	]
+}
+
 /// Provides the header and block number for a hash.
 ///
 /// Decouples `sc_client_api::Backend` and `sp_blockchain::HeaderBackend`.
@@ -919,7 +937,9 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
 				runtime_api_provider: client.clone(),
 				keystore: Some(keystore_container.keystore()),
-				offchain_db: backend.offchain_storage(),
+				offchain_db: backend
+					.offchain_storage()
+					.map(|db| sc_offchain::QuotaStorage::new(db, offchain_storage_quotas())),
 				transaction_pool: Some(OffchainTransactionPoolFactory::new(
 					transaction_pool.clone(),
 				)),
@@ -978,6 +998,8 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		network: network.clone(),
 		sync_service: sync_service.clone(),
 		rpc_builder: Box::new(rpc_extensions_builder),
+		transaction_estimator: None,
+		health_checks: Vec::new(),
 		transaction_pool: transaction_pool.clone(),
 		task_manager: &mut task_manager,
 		system_rpc_tx,
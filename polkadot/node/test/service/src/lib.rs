@@ -191,6 +191,7 @@ pub fn node_config(
 		tracing_targets: None,
 		tracing_receiver: Default::default(),
 		max_runtime_instances: 8,
+		min_runtime_instances: 1,
 		runtime_cache_size: 2,
 		announce_block: true,
 		data_path: root,
@@ -340,8 +341,12 @@ impl PolkadotTestNode {
 		para_id: ParaId,
 		collator: CollatorFn,
 	) {
-		let config =
-			CollationGenerationConfig { key: collator_key, collator: Some(collator), para_id };
+		let config = CollationGenerationConfig {
+			key: collator_key,
+			collator: Some(collator),
+			pre_validation: None,
+			para_id,
+		};
 
 		self.overseer_handle
 			.send_msg(CollationGenerationMessage::Initialize(config), "Collator")
@@ -119,6 +119,7 @@ fn main() -> Result<()> {
 					collator: Some(
 						collator.create_collation_function(full_node.task_manager.spawn_handle()),
 					),
+					pre_validation: None,
 					para_id,
 				};
 				overseer_handle
@@ -0,0 +1,786 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A pallet allowing validators to report collators which repeatedly submit invalid candidates.
+//!
+//! Paras register the accounts behind the collator keys signing their candidates, usually by
+//! sending an XCM `Transact` to the relay chain. Active validators report the invalid candidates
+//! of a registered collator they come across, e.g. while backing. Once `ReportThreshold` distinct
+//! validators reported a collator, the para is notified with an XCM `Transact` of the call it
+//! registered with [`Pallet::set_notification_call`], taking the account of the collator as its
+//! only argument, e.g. to remove the collator from its collator selection.
+//!
+//! Reports are not verified on chain, validators are held accountable for them through the events
+//! of this pallet. A validator counts once towards the threshold of a collator, however many of
+//! its candidates it reports. Reports only count within the session they were made in, so that
+//! validators which left the active set do not count towards the threshold.
+//!
+//! A para may register up to `MaxCollatorsPerPara` collators.
+
+use frame_support::{pallet_prelude::*, traits::ValidatorSet};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use parity_scale_codec::Encode;
+use primitives::{AccountId, CandidateHash, CollatorId, Id as ParaId, SessionIndex};
+use runtime_parachains::{ensure_parachain, Origin as ParaOrigin};
+use sp_std::{prelude::*, result};
+use xcm::latest::prelude::*;
+
+pub trait WeightInfo {
+	fn register_collator() -> Weight;
+	fn deregister_collator() -> Weight;
+	fn set_notification_call() -> Weight;
+	fn report_invalid_candidate() -> Weight;
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn register_collator() -> Weight {
+		Weight::zero()
+	}
+	fn deregister_collator() -> Weight {
+		Weight::zero()
+	}
+	fn set_notification_call() -> Weight {
+		Weight::zero()
+	}
+	fn report_invalid_candidate() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The aggregated origin type must support the `parachains` origin. We require that we can
+		/// infallibly convert between this origin and the system origin, but in reality, they're
+		/// the same type, we just can't express that to the Rust type system without writing a
+		/// `where` clause everywhere.
+		type RuntimeOrigin: From<<Self as frame_system::Config>::RuntimeOrigin>
+			+ Into<result::Result<ParaOrigin, <Self as Config>::RuntimeOrigin>>;
+
+		/// The validators which may report collators.
+		type ValidatorSet: ValidatorSet<Self::AccountId, ValidatorId = Self::AccountId>;
+
+		/// The router notifying paras of their reported collators.
+		type XcmSender: SendXcm;
+
+		/// The number of distinct validators to report invalid candidates of a collator before its
+		/// para is notified.
+		#[pallet::constant]
+		type ReportThreshold: Get<u32>;
+
+		/// The maximum number of collators a para may register.
+		#[pallet::constant]
+		type MaxCollatorsPerPara: Get<u32>;
+
+		/// The weight the notification call may use on the para.
+		#[pallet::constant]
+		type NotificationWeight: Get<Weight>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A para registered the account of one of its collators.
+		CollatorRegistered { para: ParaId, collator: CollatorId, account: AccountId },
+		/// A para deregistered one of its collators.
+		CollatorDeregistered { para: ParaId, collator: CollatorId },
+		/// A para set the call notifying it of its reported collators.
+		NotificationCallSet { para: ParaId, call: Option<[u8; 2]> },
+		/// A validator reported an invalid candidate of a collator.
+		InvalidCandidateReported {
+			para: ParaId,
+			collator: CollatorId,
+			candidate: CandidateHash,
+			reporter: T::AccountId,
+		},
+		/// A collator reached the report threshold. Its para was notified if it set a
+		/// notification call and the notification could be sent.
+		CollatorReported {
+			para: ParaId,
+			collator: CollatorId,
+			account: AccountId,
+			notified: bool,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The sender is not an active validator.
+		NotValidator,
+		/// The collator is not registered by the para.
+		UnknownCollator,
+		/// The validator already reported the collator.
+		AlreadyReported,
+		/// The para registered `MaxCollatorsPerPara` collators already.
+		TooManyCollators,
+	}
+
+	/// The accounts of the collators of each para, by their collator key.
+	#[pallet::storage]
+	pub type Collators<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ParaId, Blake2_128Concat, CollatorId, AccountId>;
+
+	/// The number of collators registered by each para.
+	#[pallet::storage]
+	pub type CollatorCount<T: Config> = StorageMap<_, Twox64Concat, ParaId, u32, ValueQuery>;
+
+	/// The pallet and call index of the call notifying each para of its reported collators.
+	#[pallet::storage]
+	pub type NotificationCalls<T: Config> = StorageMap<_, Twox64Concat, ParaId, [u8; 2]>;
+
+	/// The validators which reported each collator since its para was last notified, with the
+	/// invalid candidate they reported, and the session they reported it in. Reports of past
+	/// sessions are discarded on the next report of the collator.
+	#[pallet::storage]
+	pub type Reports<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ParaId,
+		Blake2_128Concat,
+		CollatorId,
+		(SessionIndex, BoundedVec<(T::AccountId, CandidateHash), T::ReportThreshold>),
+		ValueQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `account` as the account of the collator signing candidates with `collator`,
+		/// replacing any account registered before.
+		///
+		/// The para may register up to `MaxCollatorsPerPara` collators.
+		///
+		/// The origin must be the para of the collator.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_collator())]
+		pub fn register_collator(
+			origin: OriginFor<T>,
+			collator: CollatorId,
+			account: AccountId,
+		) -> DispatchResult {
+			let para = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+
+			if !Collators::<T>::contains_key(para, &collator) {
+				CollatorCount::<T>::try_mutate(para, |count| {
+					ensure!(*count < T::MaxCollatorsPerPara::get(), Error::<T>::TooManyCollators);
+					*count += 1;
+					Ok::<_, Error<T>>(())
+				})?;
+			}
+			Collators::<T>::insert(para, &collator, &account);
+
+			Self::deposit_event(Event::<T>::CollatorRegistered { para, collator, account });
+			Ok(())
+		}
+
+		/// Deregister the collator signing candidates with `collator`, discarding its reports.
+		///
+		/// The origin must be the para of the collator.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_collator())]
+		pub fn deregister_collator(origin: OriginFor<T>, collator: CollatorId) -> DispatchResult {
+			let para = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+
+			Collators::<T>::take(para, &collator).ok_or(Error::<T>::UnknownCollator)?;
+			CollatorCount::<T>::mutate_exists(para, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			});
+			Reports::<T>::remove(para, &collator);
+
+			Self::deposit_event(Event::<T>::CollatorDeregistered { para, collator });
+			Ok(())
+		}
+
+		/// Set the pallet and call index of the call notifying the para of its reported
+		/// collators, or stop the notifications with `None`.
+		///
+		/// The call is dispatched by the para with the `Superuser` origin kind, and must take the
+		/// account of the reported collator as its only argument.
+		///
+		/// The origin must be the para.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_notification_call())]
+		pub fn set_notification_call(
+			origin: OriginFor<T>,
+			call: Option<[u8; 2]>,
+		) -> DispatchResult {
+			let para = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+
+			NotificationCalls::<T>::set(para, call);
+
+			Self::deposit_event(Event::<T>::NotificationCallSet { para, call });
+			Ok(())
+		}
+
+		/// Report the invalid `candidate` of the collator signing candidates with `collator` on
+		/// `para`.
+		///
+		/// The para is notified once `ReportThreshold` distinct validators reported the collator
+		/// within a session. The report is recorded even if the notification cannot be sent.
+		///
+		/// The origin must be signed by an active validator.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_invalid_candidate())]
+		pub fn report_invalid_candidate(
+			origin: OriginFor<T>,
+			para: ParaId,
+			collator: CollatorId,
+			candidate: CandidateHash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::ValidatorSet::validators().contains(&who), Error::<T>::NotValidator);
+			let account = Collators::<T>::get(para, &collator).ok_or(Error::<T>::UnknownCollator)?;
+
+			let session = T::ValidatorSet::session_index();
+			let (reported_in, mut reports) = Reports::<T>::get(para, &collator);
+			if reported_in != session {
+				reports = Default::default();
+			}
+			ensure!(!reports.iter().any(|(r, _)| *r == who), Error::<T>::AlreadyReported);
+			Self::deposit_event(Event::<T>::InvalidCandidateReported {
+				para,
+				collator: collator.clone(),
+				candidate,
+				reporter: who.clone(),
+			});
+
+			let pushed = reports.try_push((who, candidate)).is_ok();
+			if pushed && (reports.len() as u32) < T::ReportThreshold::get() {
+				Reports::<T>::insert(para, &collator, (session, reports));
+				return Ok(())
+			}
+
+			Reports::<T>::remove(para, &collator);
+			let notified = match NotificationCalls::<T>::get(para) {
+				Some(call) => match Self::notify(para, call, &account) {
+					Ok(_) => true,
+					Err(e) => {
+						log::debug!(
+							target: "runtime::collator_offences",
+							"Failed to notify para {:?} of collator {:?}: {:?}",
+							para,
+							account,
+							e,
+						);
+						false
+					},
+				},
+				None => false,
+			};
+
+			Self::deposit_event(Event::<T>::CollatorReported { para, collator, account, notified });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Send `para` an XCM dispatching the notification `call` for the collator `account`.
+	fn notify(para: ParaId, call: [u8; 2], account: &AccountId) -> Result<XcmHash, SendError> {
+		let message = Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Superuser,
+				require_weight_at_most: T::NotificationWeight::get(),
+				call: (call, account).encode().into(),
+			},
+		]);
+		send_xcm::<T::XcmSender>(Parachain(para.into()).into(), message).map(|(hash, _)| hash)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::collator_offences;
+	use frame_support::{
+		assert_noop, assert_ok, derive_impl, error::BadOrigin, parameter_types, traits::ConstU32,
+	};
+	use runtime_parachains::origin;
+	use sp_core::{sr25519, H256};
+	use sp_io::TestExternalities;
+	use sp_runtime::{
+		traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+		BuildStorage,
+	};
+	use std::cell::{Cell, RefCell};
+
+	type Block = frame_system::mocking::MockBlockU32<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test
+		{
+			System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+			ParachainsOrigin: origin::{Pallet, Origin},
+			CollatorOffences: collator_offences::{Pallet, Call, Storage, Event<T>},
+		}
+	);
+
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Nonce = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<u64>;
+		type Block = Block;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = ConstU32<250>;
+		type DbWeight = ();
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = ();
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	impl origin::Config for Test {}
+
+	pub struct TestValidatorSet;
+	impl ValidatorSet<u64> for TestValidatorSet {
+		type ValidatorId = u64;
+		type ValidatorIdOf = ConvertInto;
+
+		fn session_index() -> SessionIndex {
+			SESSION.with(|s| s.get())
+		}
+
+		fn validators() -> Vec<u64> {
+			VALIDATORS.to_vec()
+		}
+	}
+
+	/// The active validators.
+	const VALIDATORS: [u64; 2] = [1, 2];
+
+	thread_local! {
+		static SESSION: Cell<SessionIndex> = Cell::new(0);
+		static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+	}
+
+	/// Sender recording the messages sent to paras below 2000, and failing for the others.
+	pub struct TestSendXcm;
+	impl SendXcm for TestSendXcm {
+		type Ticket = (MultiLocation, Xcm<()>);
+
+		fn validate(
+			dest: &mut Option<MultiLocation>,
+			msg: &mut Option<Xcm<()>>,
+		) -> SendResult<(MultiLocation, Xcm<()>)> {
+			match dest.as_ref() {
+				Some(MultiLocation { parents: 0, interior: X1(Parachain(id)) }) if *id < 2000 =>
+					Ok(((dest.take().unwrap(), msg.take().unwrap()), MultiAssets::new())),
+				_ => Err(SendError::Unroutable),
+			}
+		}
+
+		fn deliver(ticket: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
+			SENT_XCM.with(|q| q.borrow_mut().push(ticket));
+			Ok([0; 32])
+		}
+	}
+
+	fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+		SENT_XCM.with(|q| q.borrow().clone())
+	}
+
+	parameter_types! {
+		pub NotificationWeight: Weight = Weight::from_parts(1_000_000, 1_000);
+	}
+
+	impl Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type RuntimeOrigin = RuntimeOrigin;
+		type ValidatorSet = TestValidatorSet;
+		type XcmSender = TestSendXcm;
+		type ReportThreshold = ConstU32<2>;
+		type MaxCollatorsPerPara = ConstU32<2>;
+		type NotificationWeight = NotificationWeight;
+		type WeightInfo = TestWeightInfo;
+	}
+
+	fn new_test_ext() -> TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		let mut ext: TestExternalities = t.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn para_origin(id: u32) -> RuntimeOrigin {
+		origin::Origin::Parachain(id.into()).into()
+	}
+
+	fn collator(i: u8) -> CollatorId {
+		sr25519::Public::from_raw([i; 32]).into()
+	}
+
+	fn candidate(i: u8) -> CandidateHash {
+		CandidateHash(H256::repeat_byte(i))
+	}
+
+	fn last_event() -> Event<Test> {
+		System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::CollatorOffences(event) => Some(event),
+				_ => None,
+			})
+			.last()
+			.expect("an event was deposited")
+	}
+
+	#[test]
+	fn collators_are_registered_by_their_para() {
+		new_test_ext().execute_with(|| {
+			let account = AccountId::from([7; 32]);
+			assert_noop!(
+				CollatorOffences::register_collator(
+					RuntimeOrigin::signed(1),
+					collator(1),
+					account.clone()
+				),
+				BadOrigin,
+			);
+			assert_ok!(CollatorOffences::register_collator(
+				para_origin(1000),
+				collator(1),
+				account.clone()
+			));
+			assert_eq!(Collators::<Test>::get(ParaId::from(1000), collator(1)), Some(account));
+			assert_eq!(Collators::<Test>::get(ParaId::from(1001), collator(1)), None);
+
+			// Only the para of the collator can deregister it.
+			assert_noop!(
+				CollatorOffences::deregister_collator(para_origin(1001), collator(1)),
+				Error::<Test>::UnknownCollator,
+			);
+			assert_ok!(CollatorOffences::deregister_collator(para_origin(1000), collator(1)));
+			assert_eq!(
+				last_event(),
+				Event::CollatorDeregistered { para: 1000.into(), collator: collator(1) }
+			);
+			assert_eq!(Collators::<Test>::get(ParaId::from(1000), collator(1)), None);
+		});
+	}
+
+	#[test]
+	fn paras_register_a_bounded_number_of_collators() {
+		new_test_ext().execute_with(|| {
+			let account = AccountId::from([7; 32]);
+			let register = |para: u32, i| {
+				CollatorOffences::register_collator(para_origin(para), collator(i), account.clone())
+			};
+			assert_ok!(register(1000, 1));
+			assert_ok!(register(1000, 2));
+			assert_noop!(register(1000, 3), Error::<Test>::TooManyCollators);
+			// Other paras have their own bound.
+			assert_ok!(register(1001, 3));
+
+			// Registered collators may be registered again.
+			assert_ok!(register(1000, 2));
+			assert_eq!(CollatorCount::<Test>::get(ParaId::from(1000)), 2);
+
+			assert_ok!(CollatorOffences::deregister_collator(para_origin(1000), collator(2)));
+			assert_ok!(register(1000, 3));
+			assert_ok!(CollatorOffences::deregister_collator(para_origin(1001), collator(3)));
+			assert!(!CollatorCount::<Test>::contains_key(ParaId::from(1001)));
+		});
+	}
+
+	#[test]
+	fn reports_of_past_sessions_are_discarded() {
+		new_test_ext().execute_with(|| {
+			let account = AccountId::from([7; 32]);
+			assert_ok!(CollatorOffences::register_collator(
+				para_origin(1000),
+				collator(1),
+				account
+			));
+			assert_ok!(CollatorOffences::report_invalid_candidate(
+				RuntimeOrigin::signed(1),
+				1000.into(),
+				collator(1),
+				candidate(1)
+			));
+
+			// The report of validator 1 no longer counts in the next session.
+			SESSION.with(|s| s.set(1));
+			assert_ok!(CollatorOffences::report_invalid_candidate(
+				RuntimeOrigin::signed(2),
+				1000.into(),
+				collator(1),
+				candidate(2)
+			));
+			let (session, reports) = Reports::<Test>::get(ParaId::from(1000), collator(1));
+			assert_eq!((session, reports.into_inner()), (1, vec![(2, candidate(2))]));
+			assert!(!System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::CollatorOffences(Event::CollatorReported { .. })
+			)));
+		});
+	}
+
+	#[test]
+	fn reports_require_an_active_validator_and_a_registered_collator() {
+		new_test_ext().execute_with(|| {
+			let account = AccountId::from([7; 32]);
+			assert_ok!(CollatorOffences::register_collator(
+				para_origin(1000),
+				collator(1),
+				account
+			));
+
+			assert_noop!(
+				CollatorOffences::report_invalid_candidate(
+					RuntimeOrigin::signed(3),
+					1000.into(),
+					collator(1),
+					candidate(1)
+				),
+				Error::<Test>::NotValidator,
+			);
+			assert_noop!(
+				CollatorOffences::report_invalid_candidate(
+					RuntimeOrigin::signed(1),
+					1001.into(),
+					collator(1),
+					candidate(1)
+				),
+				Error::<Test>::UnknownCollator,
+			);
+			assert_ok!(CollatorOffences::report_invalid_candidate(
+				RuntimeOrigin::signed(1),
+				1000.into(),
+				collator(1),
+				candidate(1)
+			));
+
+			// A validator counts once, whichever candidates it reports.
+			assert_noop!(
+				CollatorOffences::report_invalid_candidate(
+					RuntimeOrigin::signed(1),
+					1000.into(),
+					collator(1),
+					candidate(2)
+				),
+				Error::<Test>::AlreadyReported,
+			);
+			assert_eq!(Reports::<Test>::get(ParaId::from(1000), collator(1)).1.len(), 1);
+		});
+	}
+
+	#[test]
+	fn paras_are_notified_of_repeatedly_reported_collators() {
+		new_test_ext().execute_with(|| {
+			let account = AccountId::from([7; 32]);
+			let report = |para: u32, who, i| {
+				CollatorOffences::report_invalid_candidate(
+					RuntimeOrigin::signed(who),
+					para.into(),
+					collator(1),
+					candidate(i),
+				)
+			};
+			for para in [1000, 2000] {
+				assert_ok!(CollatorOffences::register_collator(
+					para_origin(para),
+					collator(1),
+					account.clone()
+				));
+				assert_ok!(CollatorOffences::set_notification_call(
+					para_origin(para),
+					Some([21, 9])
+				));
+			}
+
+			assert_ok!(report(1000, 1, 1));
+			assert!(sent_xcm().is_empty());
+			assert_ok!(report(1000, 2, 2));
+			assert_eq!(
+				last_event(),
+				Event::CollatorReported {
+					para: 1000.into(),
+					collator: collator(1),
+					account: account.clone(),
+					notified: true,
+				}
+			);
+			let call = ([21u8, 9u8], &account).encode();
+			assert_eq!(
+				sent_xcm(),
+				vec![(
+					Parachain(1000).into(),
+					Xcm(vec![
+						UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+						Transact {
+							origin_kind: OriginKind::Superuser,
+							require_weight_at_most: NotificationWeight::get(),
+							call: call.into(),
+						},
+					])
+				)]
+			);
+
+			// The reports start over after the notification.
+			assert!(!Reports::<Test>::contains_key(ParaId::from(1000), collator(1)));
+			assert_ok!(report(1000, 1, 1));
+
+			// Reports are recorded even when the notification cannot be sent.
+			assert_ok!(report(2000, 1, 1));
+			assert_ok!(report(2000, 2, 2));
+			assert_eq!(
+				last_event(),
+				Event::CollatorReported {
+					para: 2000.into(),
+					collator: collator(1),
+					account: account.clone(),
+					notified: false,
+				}
+			);
+			assert!(!Reports::<Test>::contains_key(ParaId::from(2000), collator(1)));
+
+			// Paras without a notification call are not notified.
+			assert_ok!(CollatorOffences::set_notification_call(para_origin(2000), None));
+			assert_ok!(report(2000, 1, 3));
+			assert_ok!(report(2000, 2, 4));
+			assert_eq!(
+				last_event(),
+				Event::CollatorReported {
+					para: 2000.into(),
+					collator: collator(1),
+					account,
+					notified: false,
+				}
+			);
+			assert_eq!(sent_xcm().len(), 1);
+		});
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::{Pallet as CollatorOffences, *};
+	use frame_benchmarking::{account, benchmarks, BenchmarkError};
+	use frame_system::RawOrigin;
+
+	fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+		let events = frame_system::Pallet::<T>::events();
+		let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+		// compare to the last event record
+		let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+		assert_eq!(event, &system_event);
+	}
+
+	fn para_origin(id: u32) -> ParaOrigin {
+		ParaOrigin::Parachain(id.into())
+	}
+
+	fn collator(i: u8) -> CollatorId {
+		sp_core::sr25519::Public::from_raw([i; 32]).into()
+	}
+
+	fn candidate(i: u32) -> CandidateHash {
+		CandidateHash(sp_core::blake2_256(&i.encode()).into())
+	}
+
+	benchmarks! {
+		where_clause { where ParaOrigin: Into<<T as frame_system::Config>::RuntimeOrigin> }
+
+		register_collator {
+			let account: AccountId = account("collator", 0, 0);
+		}: _(para_origin(1000), collator(1), account)
+		verify {
+			assert!(Collators::<T>::contains_key(ParaId::from(1000), collator(1)));
+		}
+
+		deregister_collator {
+			let account: AccountId = account("collator", 0, 0);
+			CollatorOffences::<T>::register_collator(
+				para_origin(1000).into(),
+				collator(1),
+				account,
+			)?;
+		}: _(para_origin(1000), collator(1))
+		verify {
+			assert_last_event::<T>(
+				Event::<T>::CollatorDeregistered { para: 1000.into(), collator: collator(1) }.into()
+			);
+		}
+
+		set_notification_call {
+		}: _(para_origin(1000), Some([u8::MAX; 2]))
+		verify {
+			assert_eq!(NotificationCalls::<T>::get(ParaId::from(1000)), Some([u8::MAX; 2]));
+		}
+
+		// The report of the last validator to reach the threshold, notifying the para.
+		report_invalid_candidate {
+			let reporter = T::ValidatorSet::validators()
+				.first()
+				.cloned()
+				.ok_or(BenchmarkError::Weightless)?;
+			let account: AccountId = account("collator", 0, 0);
+			CollatorOffences::<T>::register_collator(
+				para_origin(1000).into(),
+				collator(1),
+				account.clone(),
+			)?;
+			CollatorOffences::<T>::set_notification_call(
+				para_origin(1000).into(),
+				Some([u8::MAX; 2]),
+			)?;
+			let threshold = T::ReportThreshold::get();
+			let reports: BoundedVec<_, _> = (1..threshold)
+				.map(|i| (account("reporter", i, 0), candidate(i)))
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("fewer reports than the threshold; qed");
+			let session = T::ValidatorSet::session_index();
+			Reports::<T>::insert(ParaId::from(1000), collator(1), (session, reports));
+		}: _(RawOrigin::Signed(reporter), 1000.into(), collator(1), candidate(threshold))
+		verify {
+			assert_last_event::<T>(
+				Event::<T>::CollatorReported {
+					para: 1000.into(),
+					collator: collator(1),
+					account,
+					notified: true,
+				}
+				.into()
+			);
+		}
+	}
+}
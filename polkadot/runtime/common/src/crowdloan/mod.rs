@@ -68,14 +68,14 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Codec, Decode, Encode};
 use primitives::Id as ParaId;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
 		AccountIdConversion, CheckedAdd, Hash, IdentifyAccount, One, Saturating, Verify, Zero,
 	},
-	MultiSignature, MultiSigner, RuntimeDebug,
+	DispatchError, MultiSignature, MultiSigner, RuntimeDebug,
 };
 use sp_std::vec::Vec;
 
@@ -174,6 +174,18 @@ pub struct FundInfo<AccountId, Balance, BlockNumber, LeasePeriod> {
 	pub fund_index: FundIndex,
 }
 
+/// An authenticated snapshot of the contributions to a crowdloan.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ContributionsRoot<Hash, Balance> {
+	/// The root of the binary Merkle tree whose leaves are the SCALE encoded `(who, amount)` of
+	/// each contribution, ordered as in the child trie of the fund.
+	pub root: Hash,
+	/// The number of contributions, i.e. leaves of the tree.
+	pub contributors: u32,
+	/// The total amount raised by the fund.
+	pub raised: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -277,6 +289,8 @@ pub mod pallet {
 		MemoUpdated { who: T::AccountId, para_id: ParaId, memo: Vec<u8> },
 		/// A parachain has been moved to `NewRaise`
 		AddedToNewRaise { para_id: ParaId },
+		/// A batch of contributors of an ended crowdloan has been refunded.
+		BatchRefunded { para_id: ParaId, count: u32 },
 	}
 
 	#[pallet::error]
@@ -519,38 +533,12 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
-			let mut fund = Self::funds(index).ok_or(Error::<T>::InvalidParaId)?;
-			let now = frame_system::Pallet::<T>::block_number();
-			let fund_account = Self::fund_account_id(fund.fund_index);
-			Self::ensure_crowdloan_ended(now, &fund_account, &fund)?;
-
-			let mut refund_count = 0u32;
-			// Try killing the crowdloan child trie
-			let contributions = Self::contribution_iterator(fund.fund_index);
-			// Assume everyone will be refunded.
-			let mut all_refunded = true;
-			for (who, (balance, _)) in contributions {
-				if refund_count >= T::RemoveKeysLimit::get() {
-					// Not everyone was able to be refunded this time around.
-					all_refunded = false;
-					break
-				}
-				CurrencyOf::<T>::transfer(&fund_account, &who, balance, AllowDeath)?;
-				CurrencyOf::<T>::reactivate(balance);
-				Self::contribution_kill(fund.fund_index, &who);
-				fund.raised = fund.raised.saturating_sub(balance);
-				refund_count += 1;
-			}
-
-			// Save the changes.
-			Funds::<T>::insert(index, &fund);
-
+			let (refund_count, all_refunded) =
+				Self::do_refund(index, T::RemoveKeysLimit::get())?;
 			if all_refunded {
-				Self::deposit_event(Event::<T>::AllRefunded { para_id: index });
 				// Refund for unused refund count.
 				Ok(Some(T::WeightInfo::refund(refund_count)).into())
 			} else {
-				Self::deposit_event(Event::<T>::PartiallyRefunded { para_id: index });
 				// No weight to refund since we did not finish the loop.
 				Ok(().into())
 			}
@@ -669,10 +657,84 @@ pub mod pallet {
 			let value = CurrencyOf::<T>::free_balance(&who);
 			Self::do_contribute(who, index, value, signature, AllowDeath)
 		}
+
+		/// Refund up to `max_count` contributors of an ended crowdloan.
+		///
+		/// Like `refund`, but the caller chooses the size of the batch, which is bounded by
+		/// `RemoveKeysLimit`. Calling this repeatedly refunds all contributors.
+		///
+		/// Origin must be signed, but can come from anyone.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::refund((*max_count).min(T::RemoveKeysLimit::get())))]
+		pub fn refund_batch_for(
+			origin: OriginFor<T>,
+			#[pallet::compact] para_id: ParaId,
+			max_count: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let max_count = max_count.min(T::RemoveKeysLimit::get());
+			let (refund_count, _) = Self::do_refund(para_id, max_count)?;
+			Self::deposit_event(Event::<T>::BatchRefunded { para_id, count: refund_count });
+			Ok(Some(T::WeightInfo::refund(refund_count)).into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Refund up to `max_count` contributors of the ended crowdloan of `index`.
+	///
+	/// Returns the number of contributors refunded and whether all of them have been refunded.
+	fn do_refund(index: ParaId, max_count: u32) -> Result<(u32, bool), DispatchError> {
+		let mut fund = Self::funds(index).ok_or(Error::<T>::InvalidParaId)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let fund_account = Self::fund_account_id(fund.fund_index);
+		Self::ensure_crowdloan_ended(now, &fund_account, &fund)?;
+
+		let mut refund_count = 0u32;
+		// Try killing the crowdloan child trie
+		let contributions = Self::contribution_iterator(fund.fund_index);
+		// Assume everyone will be refunded.
+		let mut all_refunded = true;
+		for (who, (balance, _)) in contributions {
+			if refund_count >= max_count {
+				// Not everyone was able to be refunded this time around.
+				all_refunded = false;
+				break
+			}
+			CurrencyOf::<T>::transfer(&fund_account, &who, balance, AllowDeath)?;
+			CurrencyOf::<T>::reactivate(balance);
+			Self::contribution_kill(fund.fund_index, &who);
+			fund.raised = fund.raised.saturating_sub(balance);
+			refund_count += 1;
+		}
+
+		// Save the changes.
+		Funds::<T>::insert(index, &fund);
+
+		if all_refunded {
+			Self::deposit_event(Event::<T>::AllRefunded { para_id: index });
+		} else {
+			Self::deposit_event(Event::<T>::PartiallyRefunded { para_id: index });
+		}
+		Ok((refund_count, all_refunded))
+	}
+
+	/// The authenticated snapshot of the contributions to the crowdloan of `index`, if any.
+	///
+	/// This iterates over all contributions and must not be called on-chain.
+	pub fn contributions_root(index: ParaId) -> Option<ContributionsRoot<T::Hash, BalanceOf<T>>> {
+		let fund = Self::funds(index)?;
+		let leaves: Vec<_> = Self::contribution_iterator(fund.fund_index)
+			.map(|(who, (balance, _))| (who, balance).encode())
+			.collect();
+		Some(ContributionsRoot {
+			root: binary_merkle_tree::merkle_root::<T::Hashing, _>(&leaves),
+			contributors: leaves.len() as u32,
+			raised: fund.raised,
+		})
+	}
+
 	/// The account ID of the fund pot.
 	///
 	/// This actually does computation. If you need to keep using it, then make sure you cache the
@@ -840,6 +902,15 @@ impl<T: Config> crate::traits::OnSwap for Pallet<T> {
 	}
 }
 
+sp_api::decl_runtime_apis! {
+	/// Runtime API to export the contributions to crowdloans, e.g. for off-chain reward
+	/// distributions.
+	pub trait CrowdloanApi<Hash, Balance> where Hash: Codec, Balance: Codec {
+		/// The authenticated snapshot of the contributions to the crowdloan of `para_id`, if any.
+		fn contributions_root(para_id: ParaId) -> Option<ContributionsRoot<Hash, Balance>>;
+	}
+}
+
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod crypto {
 	use sp_core::ed25519;
@@ -1717,6 +1788,79 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn refund_batch_for_works() {
+		new_test_ext().execute_with(|| {
+			let para = new_para();
+			let index = NextFundIndex::<Test>::get();
+			let account_id = Crowdloan::fund_account_id(index);
+
+			// Set up a crowdloan ending on 9
+			assert_ok!(Crowdloan::create(RuntimeOrigin::signed(1), para, 100000, 1, 1, 9, None));
+			for i in 1..=RemoveKeysLimit::get() * 2 {
+				Balances::make_free_balance_be(&i.into(), (1000 * i).into());
+				assert_ok!(Crowdloan::contribute(
+					RuntimeOrigin::signed(i.into()),
+					para,
+					(i * 100).into(),
+					None
+				));
+			}
+			assert_noop!(
+				Crowdloan::refund_batch_for(RuntimeOrigin::signed(1337), para, 2),
+				Error::<Test>::FundNotEnded,
+			);
+
+			// Move to the end of the crowdloan
+			run_to_block(10);
+			assert_ok!(Crowdloan::refund_batch_for(RuntimeOrigin::signed(1337), para, 2));
+			assert_eq!(
+				last_event(),
+				super::Event::<Test>::BatchRefunded { para_id: para, count: 2 }.into()
+			);
+			let remaining = Crowdloan::contribution_iterator(index).count() as u32;
+			assert_eq!(remaining, RemoveKeysLimit::get() * 2 - 2);
+
+			// The batch is bounded by `RemoveKeysLimit`.
+			assert_ok!(Crowdloan::refund_batch_for(RuntimeOrigin::signed(1337), para, u32::MAX));
+			assert_eq!(
+				last_event(),
+				super::Event::<Test>::BatchRefunded { para_id: para, count: RemoveKeysLimit::get() }
+					.into()
+			);
+			assert_ok!(Crowdloan::refund_batch_for(RuntimeOrigin::signed(1337), para, u32::MAX));
+			System::assert_has_event(super::Event::<Test>::AllRefunded { para_id: para }.into());
+
+			// Funds are returned
+			assert_eq!(Balances::free_balance(account_id), 0);
+			for i in 1..=RemoveKeysLimit::get() * 2 {
+				assert_eq!(Balances::free_balance(&i.into()), i as u64 * 1000);
+			}
+		});
+	}
+
+	#[test]
+	fn contributions_root_works() {
+		new_test_ext().execute_with(|| {
+			let para = new_para();
+			assert_eq!(Crowdloan::contributions_root(para), None);
+
+			assert_ok!(Crowdloan::create(RuntimeOrigin::signed(1), para, 1000, 1, 1, 9, None));
+			assert_ok!(Crowdloan::contribute(RuntimeOrigin::signed(2), para, 200, None));
+			assert_ok!(Crowdloan::contribute(RuntimeOrigin::signed(3), para, 300, None));
+
+			let leaves = [(2u64, 200u64).encode(), (3u64, 300u64).encode()];
+			assert_eq!(
+				Crowdloan::contributions_root(para),
+				Some(ContributionsRoot {
+					root: binary_merkle_tree::merkle_root::<BlakeTwo256, _>(&leaves),
+					contributors: 2,
+					raised: 500,
+				})
+			);
+		});
+	}
+
 	#[test]
 	fn refund_and_dissolve_works() {
 		new_test_ext().execute_with(|| {
@@ -24,11 +24,22 @@
 //!
 //! After the migration is complete, the pallet may be removed from both chains' runtimes as well as
 //! the `polkadot-runtime-common` crate.
-
-use frame_support::{dispatch::DispatchResult, traits::Currency, weights::Weight};
+//!
+//! The progress of the migration is tracked in [`Progress`], which can be read with the
+//! [`IdentityMigratorApi`] runtime API, and [`Event::MilestoneReached`] is emitted every
+//! [`Config::MilestoneInterval`] identities reaped or confirmed.
+
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{Currency, ReservableCurrency},
+	weights::Weight,
+};
 pub use pallet::*;
 use pallet_identity;
+use parity_scale_codec::{Codec, Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::Get;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
 
 #[cfg(feature = "runtime-benchmarks")]
 use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, BenchmarkError};
@@ -62,6 +73,27 @@ type BalanceOf<T> = <<T as pallet_identity::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+/// Progress of the migration of identities, as observed by one chain.
+///
+/// The source chain reaps identities and sends the messages migrating them, the destination chain
+/// executes these messages. As neither chain can observe the other, the messages sent by the
+/// source chain stay unconfirmed there, and should be compared with the messages confirmed by the
+/// destination chain.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct MigrationProgress<Balance> {
+	/// The number of identities reaped on this chain.
+	pub reaped: u32,
+	/// The total deposit released by reaping identities on this chain.
+	pub deposits_released: Balance,
+	/// The number of messages sent by this chain to migrate a reaped identity.
+	pub messages_unconfirmed: u32,
+	/// The number of messages executed on this chain, updating the deposit of a migrated
+	/// identity.
+	pub messages_confirmed: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -87,10 +119,19 @@ pub mod pallet {
 		/// A handler for what to do when an identity is reaped.
 		type ReapIdentityHandler: OnReapIdentity<Self::AccountId>;
 
+		/// The number of identities reaped, or confirmed, between two
+		/// [`Event::MilestoneReached`]. Zero disables the event.
+		#[pallet::constant]
+		type MilestoneInterval: Get<u32>;
+
 		/// Weight information for the extrinsics in the pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The progress of the migration on this chain.
+	#[pallet::storage]
+	pub type Progress<T: Config> = StorageValue<_, MigrationProgress<BalanceOf<T>>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -99,6 +140,8 @@ pub mod pallet {
 		/// The deposits held for `who` were updated. `identity` is the new deposit held for
 		/// identity info, and `subs` is the new deposit held for the sub-accounts.
 		DepositUpdated { who: T::AccountId, identity: BalanceOf<T>, subs: BalanceOf<T> },
+		/// Another `MilestoneInterval` identities were reaped or confirmed.
+		MilestoneReached { progress: MigrationProgress<BalanceOf<T>> },
 	}
 
 	#[pallet::call]
@@ -115,12 +158,22 @@ pub mod pallet {
 			who: T::AccountId,
 		) -> DispatchResultWithPostInfo {
 			T::Reaper::ensure_origin(origin)?;
+			let reserved = <T as pallet_identity::Config>::Currency::reserved_balance(&who);
 			// - number of registrars (required to calculate weight)
 			// - byte size of `IdentityInfo` (required to calculate remote deposit)
 			// - number of sub accounts (required to calculate both weight and remote deposit)
 			let (registrars, bytes, subs) = pallet_identity::Pallet::<T>::reap_identity(&who)?;
+			let released = reserved.saturating_sub(
+				<T as pallet_identity::Config>::Currency::reserved_balance(&who),
+			);
 			T::ReapIdentityHandler::on_reap_identity(&who, bytes, subs)?;
 			Self::deposit_event(Event::IdentityReaped { who });
+			Self::note_progress(|progress| {
+				progress.deposits_released.saturating_accrue(released);
+				progress.messages_unconfirmed.saturating_inc();
+				progress.reaped.saturating_inc();
+				progress.reaped
+			});
 			let post = PostDispatchInfo {
 				actual_weight: Some(<T as pallet::Config>::WeightInfo::reap_identity(
 					registrars, subs,
@@ -142,9 +195,33 @@ pub mod pallet {
 				identity: id_deposit,
 				subs: subs_deposit,
 			});
+			Self::note_progress(|progress| {
+				progress.messages_confirmed.saturating_inc();
+				progress.messages_confirmed
+			});
 			Ok(Pays::No.into())
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		/// Update the progress of the migration with `f`, which returns the counter it
+		/// incremented, and emit `MilestoneReached` if that counter reached a milestone.
+		fn note_progress(f: impl FnOnce(&mut MigrationProgress<BalanceOf<T>>) -> u32) {
+			let (counter, progress) = Progress::<T>::mutate(|progress| (f(progress), *progress));
+			let interval = T::MilestoneInterval::get();
+			if interval > 0 && counter % interval == 0 {
+				Self::deposit_event(Event::MilestoneReached { progress });
+			}
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to monitor the migration of identities.
+	pub trait IdentityMigratorApi<Balance> where Balance: Codec {
+		/// The progress of the migration on this chain.
+		fn migration_progress() -> MigrationProgress<Balance>;
+	}
 }
 
 /// Trait to handle reaping identity from state.
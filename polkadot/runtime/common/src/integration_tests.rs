@@ -45,9 +45,9 @@ use sp_io::TestExternalities;
 use sp_keyring::Sr25519Keyring;
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup, One},
+	traits::{BlakeTwo256, IdentityLookup, One, Verify},
 	transaction_validity::TransactionPriority,
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, MultiSignature,
 };
 use sp_std::sync::Arc;
 
@@ -92,7 +92,7 @@ frame_support::construct_runtime!(
 
 		// Migrators
 		Identity: pallet_identity::{Pallet, Call, Storage, Event<T>},
-		IdentityMigrator: identity_migrator::{Pallet, Call, Event<T>},
+		IdentityMigrator: identity_migrator::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -168,6 +168,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = ();
 	type MinimumPeriod = MinimumPeriod;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -224,6 +225,8 @@ impl paras_registrar::Config for Test {
 	type OnSwap = (Crowdloan, Slots);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
+	type DepositRefundTranches = ConstU32<4>;
+	type DepositRefundPeriod = ConstU32<10>;
 	type Currency = Balances;
 	type RuntimeOrigin = RuntimeOrigin;
 	type WeightInfo = crate::paras_registrar::TestWeightInfo;
@@ -292,6 +295,11 @@ impl pallet_identity::Config for Test {
 	type MaxRegistrars = ConstU32<20>;
 	type RegistrarOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
+	type MigratorOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = MultiSignature;
+	type SigningPublicKey = <MultiSignature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = ();
 }
 
@@ -299,6 +307,7 @@ impl identity_migrator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ();
+	type MilestoneInterval = ConstU32<2>;
 	type WeightInfo = crate::identity_migrator::TestWeightInfo;
 }
 
@@ -552,6 +561,9 @@ fn basic_end_to_end_works() {
 				Paras::lifecycle(ParaId::from(para_2)),
 				Some(ParaLifecycle::UpgradingParathread)
 			);
+			// The lease of Para 1 is over, so the refund of its deposit begins.
+			assert!(paras_registrar::DepositRefunds::<Test>::contains_key(ParaId::from(para_1)));
+			assert!(!paras_registrar::DepositRefunds::<Test>::contains_key(ParaId::from(para_2)));
 
 			// Two sessions later, they have transitioned
 			run_to_block(lease_start_block + 220);
@@ -562,6 +574,9 @@ fn basic_end_to_end_works() {
 			run_to_block(lease_start_block + 300);
 			assert_eq!(Paras::lifecycle(ParaId::from(para_1)), Some(ParaLifecycle::Parathread));
 			assert_eq!(Paras::lifecycle(ParaId::from(para_2)), Some(ParaLifecycle::Parachain));
+			// By now the deposit of Para 1 was refunded in full.
+			assert!(!paras_registrar::DepositRefunds::<Test>::contains_key(ParaId::from(para_1)));
+			assert_eq!(Balances::reserved_balance(&account_id(1)), 0);
 
 			// Fifth slot, Para 2 is downgrading
 			run_to_block(lease_start_block + 400);
@@ -575,6 +590,11 @@ fn basic_end_to_end_works() {
 			run_to_block(lease_start_block + 420);
 			assert_eq!(Paras::lifecycle(ParaId::from(para_1)), Some(ParaLifecycle::Parathread));
 			assert_eq!(Paras::lifecycle(ParaId::from(para_2)), Some(ParaLifecycle::Parathread));
+
+			// The funds of the crowdloan of Para 2 were unlocked with its lease, while its deposit
+			// is refunded in tranches.
+			assert!(paras_registrar::DepositRefunds::<Test>::contains_key(ParaId::from(para_2)));
+			assert_ok!(Crowdloan::withdraw(signed(2), account_id(2), ParaId::from(para_2)));
 		});
 	}
 }
@@ -996,7 +1016,8 @@ fn basic_swap_works() {
 		// Dissolve returns the balance of the person who put a deposit for crowdloan
 		assert_ok!(Crowdloan::dissolve(signed(1), ParaId::from(2001)));
 		assert_eq!(Balances::reserved_balance(&account_id(1)), 0);
-		assert_eq!(Balances::reserved_balance(&account_id(2)), 500 + 20 * 2 * 1);
+		// The deposit of the para was refunded once its lease was over.
+		assert_eq!(Balances::reserved_balance(&account_id(2)), 0);
 
 		// Final deregister sets everything back to the start
 		assert_ok!(Registrar::deregister(para_origin(2001).into(), ParaId::from(2001)));
@@ -1726,3 +1747,44 @@ fn cant_bid_on_existing_lease_periods() {
 		));
 	});
 }
+
+#[test]
+fn identity_migration_progress_is_tracked() {
+	new_test_ext().execute_with(|| {
+		let info = IdentityInfo::<ConstU32<2>>::default();
+		let deposit = 100 + 10 * info.encoded_size() as Balance;
+		for i in 1..=3 {
+			Balances::make_free_balance_be(&account_id(i), 1_000_000_000);
+			assert_ok!(Identity::set_identity(signed(i), Box::new(info.clone())));
+		}
+
+		assert_ok!(IdentityMigrator::reap_identity(RuntimeOrigin::root(), account_id(1)));
+		assert_eq!(
+			identity_migrator::Progress::<Test>::get(),
+			identity_migrator::MigrationProgress {
+				reaped: 1,
+				deposits_released: deposit,
+				messages_unconfirmed: 1,
+				messages_confirmed: 0,
+			}
+		);
+
+		// a milestone is reached every two identities reaped.
+		assert_ok!(IdentityMigrator::reap_identity(RuntimeOrigin::root(), account_id(2)));
+		let progress = identity_migrator::MigrationProgress {
+			reaped: 2,
+			deposits_released: 2 * deposit,
+			messages_unconfirmed: 2,
+			messages_confirmed: 0,
+		};
+		assert_eq!(identity_migrator::Progress::<Test>::get(), progress);
+		assert_eq!(
+			last_event(),
+			identity_migrator::Event::<Test>::MilestoneReached { progress }.into()
+		);
+
+		// poking a deposit confirms a message.
+		assert_ok!(IdentityMigrator::poke_deposit(RuntimeOrigin::root(), account_id(3)));
+		assert_eq!(identity_migrator::Progress::<Test>::get().messages_confirmed, 1);
+	});
+}
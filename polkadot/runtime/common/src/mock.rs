@@ -149,6 +149,10 @@ impl<T: frame_system::Config> Registrar for TestRegistrar<T> {
 
 	/// If the ParaId corresponds to a lease holding parachain, then downgrade it to a
 	/// parathread (on-demand parachain)
+	fn convert_lease(_id: ParaId) -> DispatchResult {
+		Ok(())
+	}
+
 	fn make_parathread(id: ParaId) -> DispatchResult {
 		PARACHAINS.with(|x| {
 			let mut parachains = x.borrow_mut();
@@ -0,0 +1,767 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A pallet allowing governance to call into `paras` routines.
+//!
+//! This offers the actions of [`paras_sudo_wrapper`](crate::paras_sudo_wrapper) to governance
+//! origins instead of `Sudo`. Each kind of action requires the origin the runtime configures for
+//! it, e.g. a separate OpenGov track, and is enacted after the delay the runtime configures for
+//! it. Scheduled actions may be cancelled until they are enacted.
+
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::EnsureOriginWithArg,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use parity_scale_codec::{Decode, Encode};
+use primitives::Id as ParaId;
+use runtime_parachains::{
+	configuration, dmp, hrmp,
+	paras::{self, ParaGenesisArgs},
+	ParaLifecycle,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Convert, One, Saturating},
+	RuntimeDebug,
+};
+use sp_std::{boxed::Box, prelude::*};
+
+pub trait WeightInfo {
+	fn schedule() -> Weight;
+	fn cancel() -> Weight;
+	fn enact() -> Weight;
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn schedule() -> Weight {
+		Weight::zero()
+	}
+	fn cancel() -> Weight {
+		Weight::zero()
+	}
+	fn enact() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The kind of a [`ParaAction`], which determines the origin it requires and its delay.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParaActionKind {
+	/// See [`ParaAction::ScheduleParaInitialize`].
+	Initialize,
+	/// See [`ParaAction::ScheduleParaCleanup`].
+	Cleanup,
+	/// See [`ParaAction::ScheduleParathreadUpgrade`].
+	Upgrade,
+	/// See [`ParaAction::ScheduleParachainDowngrade`].
+	Downgrade,
+	/// See [`ParaAction::QueueDownwardXcm`].
+	QueueDownwardXcm,
+	/// See [`ParaAction::EstablishHrmpChannel`].
+	EstablishHrmpChannel,
+}
+
+/// An action managing a para.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ParaAction {
+	/// Schedule a para to be initialized at the start of the next session.
+	ScheduleParaInitialize { id: ParaId, genesis: ParaGenesisArgs },
+	/// Schedule a para to be cleaned up at the start of the next session.
+	ScheduleParaCleanup { id: ParaId },
+	/// Upgrade a parathread (on-demand parachain) to a lease holding parachain.
+	ScheduleParathreadUpgrade { id: ParaId },
+	/// Downgrade a lease holding parachain to an on-demand parachain.
+	ScheduleParachainDowngrade { id: ParaId },
+	/// Send a downward XCM to the given para.
+	///
+	/// The given parachain should exist and the payload should not exceed the preconfigured
+	/// size `config.max_downward_message_size`.
+	QueueDownwardXcm { id: ParaId, xcm: Box<xcm::opaque::VersionedXcm> },
+	/// Forcefully establish a channel from the sender to the recipient.
+	///
+	/// This is equivalent to sending an `Hrmp::hrmp_init_open_channel` extrinsic followed by
+	/// `Hrmp::hrmp_accept_open_channel`.
+	EstablishHrmpChannel {
+		sender: ParaId,
+		recipient: ParaId,
+		max_capacity: u32,
+		max_message_size: u32,
+	},
+}
+
+impl ParaAction {
+	/// The kind of the action.
+	pub fn kind(&self) -> ParaActionKind {
+		match self {
+			Self::ScheduleParaInitialize { .. } => ParaActionKind::Initialize,
+			Self::ScheduleParaCleanup { .. } => ParaActionKind::Cleanup,
+			Self::ScheduleParathreadUpgrade { .. } => ParaActionKind::Upgrade,
+			Self::ScheduleParachainDowngrade { .. } => ParaActionKind::Downgrade,
+			Self::QueueDownwardXcm { .. } => ParaActionKind::QueueDownwardXcm,
+			Self::EstablishHrmpChannel { .. } => ParaActionKind::EstablishHrmpChannel,
+		}
+	}
+}
+
+/// An action which is scheduled to be enacted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScheduledAction<BlockNumber> {
+	/// The action.
+	pub action: ParaAction,
+	/// The block at the start of which the action is enacted.
+	pub when: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	#[pallet::disable_frame_system_supertrait_check]
+	pub trait Config: configuration::Config + paras::Config + dmp::Config + hrmp::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may schedule and cancel actions of a kind.
+		type ManagerOrigin: EnsureOriginWithArg<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			ParaActionKind,
+		>;
+
+		/// The number of blocks between scheduling an action of a kind and its enactment.
+		///
+		/// Actions are enacted at the start of the next block at the earliest.
+		type Delay: Convert<ParaActionKind, BlockNumberFor<Self>>;
+
+		/// The maximum number of actions which may be enacted in a block.
+		#[pallet::constant]
+		type MaxActionsPerBlock: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An action was scheduled to be enacted at the start of the block `when`.
+		Scheduled { index: u32, kind: ParaActionKind, when: BlockNumberFor<T> },
+		/// A scheduled action was cancelled.
+		Cancelled { index: u32 },
+		/// A scheduled action was enacted.
+		Enacted { index: u32, result: DispatchResult },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The specified parachain is not registered.
+		ParaDoesntExist,
+		/// The specified parachain is already registered.
+		ParaAlreadyExists,
+		/// A DMP message couldn't be sent because it exceeds the maximum size allowed for a
+		/// downward message.
+		ExceedsMaxMessageSize,
+		/// Could not schedule para cleanup.
+		CouldntCleanup,
+		/// Not a parathread (on-demand parachain).
+		NotParathread,
+		/// Not a lease holding parachain.
+		NotParachain,
+		/// Cannot upgrade on-demand parachain to lease holding parachain.
+		CannotUpgrade,
+		/// Cannot downgrade lease holding parachain to on-demand.
+		CannotDowngrade,
+		/// Too many actions are already scheduled to be enacted in the same block.
+		TooManyActions,
+		/// There is no scheduled action with the given index.
+		UnknownAction,
+	}
+
+	/// The index of the next action to be scheduled.
+	#[pallet::storage]
+	pub type NextActionIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The actions which are scheduled to be enacted.
+	#[pallet::storage]
+	pub type Actions<T: Config> =
+		StorageMap<_, Twox64Concat, u32, ScheduledAction<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The indices of the actions to be enacted at the start of each block.
+	#[pallet::storage]
+	pub type Agenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxActionsPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let agenda = Agenda::<T>::take(now);
+			for &index in agenda.iter() {
+				let Some(scheduled) = Actions::<T>::take(index) else { continue };
+				// An action failing halfway, e.g. an HRMP channel which was requested but could not
+				// be accepted, must not leave any of its changes behind.
+				let result = with_transaction(|| {
+					let result = Self::enact(scheduled.action);
+					if result.is_ok() {
+						TransactionOutcome::Commit(result)
+					} else {
+						TransactionOutcome::Rollback(result)
+					}
+				});
+				Self::deposit_event(Event::<T>::Enacted { index, result });
+			}
+
+			let enacted = <T as Config>::WeightInfo::enact().saturating_mul(agenda.len() as u64);
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(enacted)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Schedule `action` to be enacted after the delay of its kind.
+		///
+		/// The origin must be accepted by `ManagerOrigin` for the kind of the action.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule())]
+		pub fn schedule(origin: OriginFor<T>, action: Box<ParaAction>) -> DispatchResult {
+			let kind = action.kind();
+			T::ManagerOrigin::ensure_origin(origin, &kind)?;
+
+			let delay = T::Delay::convert(kind).max(One::one());
+			let when = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+			let index = NextActionIndex::<T>::get();
+			Agenda::<T>::try_mutate(when, |agenda| agenda.try_push(index))
+				.map_err(|_| Error::<T>::TooManyActions)?;
+			Actions::<T>::insert(index, ScheduledAction { action: *action, when });
+			NextActionIndex::<T>::put(index.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::Scheduled { index, kind, when });
+			Ok(())
+		}
+
+		/// Cancel the scheduled action `index`.
+		///
+		/// The origin must be accepted by `ManagerOrigin` for the kind of the action.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, index: u32) -> DispatchResult {
+			let scheduled = Actions::<T>::get(index).ok_or(Error::<T>::UnknownAction)?;
+			T::ManagerOrigin::ensure_origin(origin, &scheduled.action.kind())?;
+
+			Agenda::<T>::mutate(scheduled.when, |agenda| agenda.retain(|i| *i != index));
+			Actions::<T>::remove(index);
+
+			Self::deposit_event(Event::<T>::Cancelled { index });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn enact(action: ParaAction) -> DispatchResult {
+		match action {
+			ParaAction::ScheduleParaInitialize { id, genesis } =>
+				runtime_parachains::schedule_para_initialize::<T>(id, genesis)
+					.map_err(|_| Error::<T>::ParaAlreadyExists)?,
+			ParaAction::ScheduleParaCleanup { id } =>
+				runtime_parachains::schedule_para_cleanup::<T>(id)
+					.map_err(|_| Error::<T>::CouldntCleanup)?,
+			ParaAction::ScheduleParathreadUpgrade { id } => {
+				// Para backend should think this is a parathread (on-demand parachain)...
+				ensure!(
+					paras::Pallet::<T>::lifecycle(id) == Some(ParaLifecycle::Parathread),
+					Error::<T>::NotParathread,
+				);
+				runtime_parachains::schedule_parathread_upgrade::<T>(id)
+					.map_err(|_| Error::<T>::CannotUpgrade)?
+			},
+			ParaAction::ScheduleParachainDowngrade { id } => {
+				// Para backend should think this is a parachain...
+				ensure!(
+					paras::Pallet::<T>::lifecycle(id) == Some(ParaLifecycle::Parachain),
+					Error::<T>::NotParachain,
+				);
+				runtime_parachains::schedule_parachain_downgrade::<T>(id)
+					.map_err(|_| Error::<T>::CannotDowngrade)?
+			},
+			ParaAction::QueueDownwardXcm { id, xcm } => {
+				ensure!(<paras::Pallet<T>>::is_valid_para(id), Error::<T>::ParaDoesntExist);
+				let config = <configuration::Pallet<T>>::config();
+				<dmp::Pallet<T>>::queue_downward_message(&config, id, xcm.encode()).map_err(
+					|e| match e {
+						dmp::QueueDownwardMessageError::ExceedsMaxMessageSize =>
+							Error::<T>::ExceedsMaxMessageSize,
+					},
+				)?
+			},
+			ParaAction::EstablishHrmpChannel {
+				sender,
+				recipient,
+				max_capacity,
+				max_message_size,
+			} => {
+				<hrmp::Pallet<T>>::init_open_channel(
+					sender,
+					recipient,
+					max_capacity,
+					max_message_size,
+				)?;
+				<hrmp::Pallet<T>>::accept_open_channel(recipient, sender)?
+			},
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::paras_governance;
+	use frame_support::{
+		assert_noop, assert_ok, derive_impl, error::BadOrigin, parameter_types,
+		traits::{ConstU32, OnInitialize},
+	};
+	use frame_system::RawOrigin;
+	use primitives::{BlockNumber, HeadData, HrmpChannelId, ValidationCode};
+	use runtime_parachains::{origin, paras::ParaKind, shared};
+	use sp_core::H256;
+	use sp_io::TestExternalities;
+	use sp_runtime::{
+		traits::{BlakeTwo256, IdentityLookup},
+		transaction_validity::TransactionPriority,
+		BuildStorage, DispatchError,
+	};
+
+	type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+	type Block = frame_system::mocking::MockBlockU32<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test
+		{
+			System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+			Configuration: configuration::{Pallet, Call, Storage, Config<T>},
+			Paras: paras::{Pallet, Call, Storage, Config<T>, Event},
+			ParasShared: shared::{Pallet, Call, Storage},
+			Dmp: dmp::{Pallet, Storage},
+			Hrmp: hrmp::{Pallet, Call, Storage, Event<T>},
+			ParachainsOrigin: origin::{Pallet, Origin},
+			ParasGovernance: paras_governance::{Pallet, Call, Storage, Event<T>},
+		}
+	);
+
+	impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+	where
+		RuntimeCall: From<C>,
+	{
+		type Extrinsic = UncheckedExtrinsic;
+		type OverarchingCall = RuntimeCall;
+	}
+
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Nonce = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<u64>;
+		type Block = Block;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = ConstU32<250>;
+		type DbWeight = ();
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u128>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	parameter_types! {
+		pub const ExistentialDeposit: u128 = 1;
+	}
+
+	impl pallet_balances::Config for Test {
+		type Balance = u128;
+		type DustRemoval = ();
+		type RuntimeEvent = RuntimeEvent;
+		type ExistentialDeposit = ExistentialDeposit;
+		type AccountStore = System;
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type WeightInfo = ();
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type RuntimeFreezeReason = RuntimeFreezeReason;
+		type FreezeIdentifier = ();
+		type MaxHolds = ConstU32<1>;
+		type MaxFreezes = ConstU32<1>;
+	}
+
+	impl shared::Config for Test {}
+
+	impl origin::Config for Test {}
+
+	parameter_types! {
+		pub const ParasUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+	}
+
+	impl paras::Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type WeightInfo = paras::TestWeightInfo;
+		type UnsignedPriority = ParasUnsignedPriority;
+		type QueueFootprinter = ();
+		type NextSessionRotation = crate::mock::TestNextSessionRotation;
+		type OnNewHead = ();
+	}
+
+	impl configuration::Config for Test {
+		type WeightInfo = configuration::TestWeightInfo;
+	}
+
+	impl dmp::Config for Test {}
+
+	impl hrmp::Config for Test {
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeEvent = RuntimeEvent;
+		type ChannelManager = frame_system::EnsureRoot<u64>;
+		type Currency = Balances;
+		type WeightInfo = hrmp::TestWeightInfo;
+	}
+
+	/// The account which may clean up paras, but take no other action.
+	const CLEANUP_ADMIN: u64 = 1;
+
+	pub struct TestManagerOrigin;
+	impl EnsureOriginWithArg<RuntimeOrigin, ParaActionKind> for TestManagerOrigin {
+		type Success = ();
+
+		fn try_origin(o: RuntimeOrigin, kind: &ParaActionKind) -> Result<(), RuntimeOrigin> {
+			let raw: Result<RawOrigin<u64>, RuntimeOrigin> = o.clone().into();
+			match raw {
+				Ok(RawOrigin::Root) => Ok(()),
+				Ok(RawOrigin::Signed(CLEANUP_ADMIN)) if *kind == ParaActionKind::Cleanup => Ok(()),
+				_ => Err(o),
+			}
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin(_: &ParaActionKind) -> Result<RuntimeOrigin, ()> {
+			Ok(RuntimeOrigin::root())
+		}
+	}
+
+	pub struct TestDelay;
+	impl Convert<ParaActionKind, BlockNumber> for TestDelay {
+		fn convert(kind: ParaActionKind) -> BlockNumber {
+			match kind {
+				ParaActionKind::Cleanup => 10,
+				_ => 0,
+			}
+		}
+	}
+
+	impl Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type ManagerOrigin = TestManagerOrigin;
+		type Delay = TestDelay;
+		type MaxActionsPerBlock = ConstU32<2>;
+		type WeightInfo = TestWeightInfo;
+	}
+
+	fn new_test_ext() -> TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		configuration::GenesisConfig::<Test> {
+			config: configuration::HostConfiguration {
+				max_code_size: 2 * 1024 * 1024,      // 2 MB
+				max_head_data_size: 1 * 1024 * 1024, // 1 MB
+				max_downward_message_size: 1024,
+				hrmp_channel_max_capacity: 8,
+				hrmp_channel_max_message_size: 1024,
+				hrmp_max_parachain_outbound_channels: 4,
+				// No para may accept a channel, so that establishing one fails halfway.
+				hrmp_max_parachain_inbound_channels: 0,
+				..Default::default()
+			},
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext: TestExternalities = t.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn run_to_block(n: BlockNumber) {
+		while System::block_number() < n {
+			let block_number = System::block_number() + 1;
+			System::set_block_number(block_number);
+			ParasGovernance::on_initialize(block_number);
+		}
+	}
+
+	fn initialize(id: u32) -> Box<ParaAction> {
+		Box::new(ParaAction::ScheduleParaInitialize {
+			id: id.into(),
+			genesis: ParaGenesisArgs {
+				genesis_head: HeadData(vec![1, 2, 3]),
+				validation_code: ValidationCode(vec![1, 2, 3]),
+				para_kind: ParaKind::Parathread,
+			},
+		})
+	}
+
+	fn cleanup(id: u32) -> Box<ParaAction> {
+		Box::new(ParaAction::ScheduleParaCleanup { id: id.into() })
+	}
+
+	fn onboard(id: u32) {
+		let ParaAction::ScheduleParaInitialize { id, genesis } = *initialize(id) else {
+			unreachable!()
+		};
+		// Known code skips the PVF pre-checking.
+		assert_ok!(Paras::add_trusted_validation_code(
+			RuntimeOrigin::root(),
+			ValidationCode(vec![1, 2, 3])
+		));
+		assert_ok!(runtime_parachains::schedule_para_initialize::<Test>(id, genesis));
+		for _ in 0..2 {
+			shared::Pallet::<Test>::set_session_index(shared::Pallet::<Test>::session_index() + 1);
+			Paras::test_on_new_session();
+		}
+		assert!(Paras::is_valid_para(id));
+	}
+
+	fn last_event() -> Event<Test> {
+		System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				RuntimeEvent::ParasGovernance(event) => Some(event),
+				_ => None,
+			})
+			.last()
+			.expect("an event was deposited")
+	}
+
+	#[test]
+	fn actions_require_the_origin_of_their_kind() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				ParasGovernance::schedule(RuntimeOrigin::signed(CLEANUP_ADMIN), initialize(1000)),
+				BadOrigin,
+			);
+			assert_noop!(
+				ParasGovernance::schedule(RuntimeOrigin::signed(2), cleanup(1000)),
+				BadOrigin,
+			);
+			assert_ok!(ParasGovernance::schedule(
+				RuntimeOrigin::signed(CLEANUP_ADMIN),
+				cleanup(1000)
+			));
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1000)));
+
+			// Cancelling requires the origin of the kind of the action, too.
+			assert_noop!(
+				ParasGovernance::cancel(RuntimeOrigin::signed(CLEANUP_ADMIN), 1),
+				BadOrigin
+			);
+			assert_ok!(ParasGovernance::cancel(RuntimeOrigin::signed(CLEANUP_ADMIN), 0));
+			assert_eq!(last_event(), Event::Cancelled { index: 0 });
+			assert_noop!(
+				ParasGovernance::cancel(RuntimeOrigin::root(), 0),
+				Error::<Test>::UnknownAction,
+			);
+		});
+	}
+
+	#[test]
+	fn actions_are_enacted_after_their_delay() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1000)));
+			assert_eq!(
+				last_event(),
+				Event::Scheduled { index: 0, kind: ParaActionKind::Initialize, when: 2 }
+			);
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), cleanup(1000)));
+			assert_eq!(
+				last_event(),
+				Event::Scheduled { index: 1, kind: ParaActionKind::Cleanup, when: 11 }
+			);
+			assert_eq!(Paras::lifecycle(ParaId::from(1000)), None);
+
+			// Actions without delay are enacted at the start of the next block.
+			run_to_block(2);
+			assert_eq!(last_event(), Event::Enacted { index: 0, result: Ok(()) });
+			assert_eq!(Paras::lifecycle(ParaId::from(1000)), Some(ParaLifecycle::Onboarding));
+
+			// The cleanup fails, as the para is still onboarding.
+			run_to_block(10);
+			assert!(Actions::<Test>::contains_key(1));
+			run_to_block(11);
+			assert_eq!(
+				last_event(),
+				Event::Enacted {
+					index: 1,
+					result: Err(DispatchError::from(Error::<Test>::CouldntCleanup)),
+				}
+			);
+			assert!(!Actions::<Test>::contains_key(1));
+		});
+	}
+
+	#[test]
+	fn failed_actions_are_rolled_back() {
+		new_test_ext().execute_with(|| {
+			onboard(2000);
+			onboard(2001);
+
+			let establish = ParaAction::EstablishHrmpChannel {
+				sender: 2000.into(),
+				recipient: 2001.into(),
+				max_capacity: 8,
+				max_message_size: 1024,
+			};
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), Box::new(establish)));
+			run_to_block(2);
+			assert_eq!(
+				last_event(),
+				Event::Enacted {
+					index: 0,
+					result: Err(DispatchError::from(
+						hrmp::Error::<Test>::AcceptHrmpChannelLimitExceeded
+					)),
+				}
+			);
+
+			// The channel was requested before accepting it failed, but the request is gone.
+			let channel = HrmpChannelId { sender: 2000.into(), recipient: 2001.into() };
+			assert!(hrmp::HrmpOpenChannelRequests::<Test>::get(&channel).is_none());
+			assert_eq!(hrmp::HrmpOpenChannelRequestCount::<Test>::get(ParaId::from(2000)), 0);
+		});
+	}
+
+	#[test]
+	fn actions_per_block_are_limited() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1000)));
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1001)));
+			assert_noop!(
+				ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1002)),
+				Error::<Test>::TooManyActions,
+			);
+
+			// Cancelling an action frees its slot.
+			assert_ok!(ParasGovernance::cancel(RuntimeOrigin::root(), 1));
+			assert_ok!(ParasGovernance::schedule(RuntimeOrigin::root(), initialize(1002)));
+
+			run_to_block(2);
+			assert_eq!(Paras::lifecycle(ParaId::from(1000)), Some(ParaLifecycle::Onboarding));
+			assert_eq!(Paras::lifecycle(ParaId::from(1001)), None);
+			assert_eq!(Paras::lifecycle(ParaId::from(1002)), Some(ParaLifecycle::Onboarding));
+		});
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::{Pallet as ParasGovernance, *};
+	use frame_benchmarking::{benchmarks, BenchmarkError};
+	use frame_support::{assert_ok, traits::Hooks};
+	use primitives::{HeadData, ValidationCode, MAX_HEAD_DATA_SIZE};
+	use runtime_parachains::paras::ParaKind;
+
+	fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+		let events = frame_system::Pallet::<T>::events();
+		let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+		// compare to the last event record
+		let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+		assert_eq!(event, &system_event);
+	}
+
+	fn worst_initialize<T: Config>(id: u32) -> Box<ParaAction> {
+		let max_code_size = configuration::Pallet::<T>::config().max_code_size;
+		Box::new(ParaAction::ScheduleParaInitialize {
+			id: id.into(),
+			genesis: ParaGenesisArgs {
+				genesis_head: HeadData(vec![0u8; MAX_HEAD_DATA_SIZE as usize]),
+				validation_code: ValidationCode(vec![0u8; max_code_size as usize]),
+				para_kind: ParaKind::Parathread,
+			},
+		})
+	}
+
+	fn schedule_action<T: Config>(action: Box<ParaAction>) -> BlockNumberFor<T> {
+		let origin = T::ManagerOrigin::try_successful_origin(&action.kind())
+			.expect("ManagerOrigin has no successful origin required for the benchmark");
+		assert_ok!(ParasGovernance::<T>::schedule(origin, action));
+		let index = NextActionIndex::<T>::get() - 1;
+		Actions::<T>::get(index).expect("the action was scheduled").when
+	}
+
+	benchmarks! {
+		schedule {
+			let action = worst_initialize::<T>(1000);
+			let origin = T::ManagerOrigin::try_successful_origin(&action.kind())
+				.map_err(|_| BenchmarkError::Weightless)?;
+		}: {
+			assert_ok!(ParasGovernance::<T>::schedule(origin, action));
+		}
+		verify {
+			assert!(Actions::<T>::contains_key(NextActionIndex::<T>::get() - 1));
+		}
+
+		cancel {
+			let when = schedule_action::<T>(worst_initialize::<T>(1000));
+			let index = NextActionIndex::<T>::get() - 1;
+			let origin = T::ManagerOrigin::try_successful_origin(&ParaActionKind::Initialize)
+				.map_err(|_| BenchmarkError::Weightless)?;
+		}: {
+			assert_ok!(ParasGovernance::<T>::cancel(origin, index));
+		}
+		verify {
+			assert_last_event::<T>(Event::<T>::Cancelled { index }.into());
+			assert!(Agenda::<T>::get(when).is_empty());
+		}
+
+		enact {
+			let when = schedule_action::<T>(worst_initialize::<T>(1000));
+		}: {
+			ParasGovernance::<T>::on_initialize(when);
+		}
+		verify {
+			assert_eq!(paras::Pallet::<T>::lifecycle(1000.into()), Some(ParaLifecycle::Onboarding));
+		}
+	}
+}
@@ -25,7 +25,7 @@ use frame_support::{
 	pallet_prelude::Weight,
 	traits::{Currency, Get, ReservableCurrency},
 };
-use frame_system::{self, ensure_root, ensure_signed};
+use frame_system::{self, ensure_root, ensure_signed, pallet_prelude::BlockNumberFor};
 use primitives::{HeadData, Id as ParaId, ValidationCode, LOWEST_PUBLIC_ID};
 use runtime_parachains::{
 	configuration, ensure_parachain,
@@ -40,7 +40,7 @@ use parity_scale_codec::{Decode, Encode};
 use runtime_parachains::paras::{OnNewHead, ParaKind};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedSub, Saturating},
+	traits::{CheckedSub, One, Saturating, Zero},
 	RuntimeDebug,
 };
 
@@ -62,6 +62,16 @@ impl<Account, Balance> ParaInfo<Account, Balance> {
 	}
 }
 
+/// The refund of the deposit of a para whose lease was converted to coretime, which is unreserved
+/// in tranches.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DepositRefund<Balance> {
+	/// The amount unreserved with each tranche. The last tranche unreserves whatever remains.
+	tranche: Balance,
+	/// The number of tranches which are yet to be unreserved.
+	remaining_tranches: u32,
+}
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -73,6 +83,7 @@ pub trait WeightInfo {
 	fn swap() -> Weight;
 	fn schedule_code_upgrade(b: u32) -> Weight;
 	fn set_current_head(b: u32) -> Weight;
+	fn refund_deposit_tranche() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -98,6 +109,9 @@ impl WeightInfo for TestWeightInfo {
 	fn set_current_head(_b: u32) -> Weight {
 		Weight::zero()
 	}
+	fn refund_deposit_tranche() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[frame_support::pallet]
@@ -142,6 +156,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type DataDepositPerByte: Get<BalanceOf<Self>>;
 
+		/// The number of tranches in which the deposit of a para is refunded, once its lease was
+		/// converted to coretime.
+		#[pallet::constant]
+		type DepositRefundTranches: Get<u32>;
+
+		/// The number of blocks between two tranches of a deposit refund.
+		#[pallet::constant]
+		type DepositRefundPeriod: Get<BlockNumberFor<Self>>;
+
 		/// Weight Information for the Extrinsics in the Pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -153,6 +176,16 @@ pub mod pallet {
 		Deregistered { para_id: ParaId },
 		Reserved { para_id: ParaId, who: T::AccountId },
 		Swapped { para_id: ParaId, other_id: ParaId },
+		/// The lease of a para was converted to coretime and the refund of its deposit was
+		/// scheduled.
+		LeaseConverted { para_id: ParaId, deposit: BalanceOf<T> },
+		/// A tranche of the deposit of a para whose lease was converted was refunded.
+		DepositTrancheRefunded {
+			para_id: ParaId,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			remaining_tranches: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -187,6 +220,10 @@ pub mod pallet {
 		/// Cannot perform a parachain slot / lifecycle swap. Check that the state of both paras
 		/// are correct for the swap to work.
 		CannotSwap,
+		/// The refund of the deposit of this para has already been scheduled.
+		RefundAlreadyScheduled,
+		/// There is no deposit to be refunded for this para.
+		NoDeposit,
 	}
 
 	/// Pending swap operations.
@@ -205,6 +242,16 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextFreeParaId<T> = StorageValue<_, ParaId, ValueQuery>;
 
+	/// The pending refunds of the deposits of paras whose lease was converted to coretime.
+	#[pallet::storage]
+	pub type DepositRefunds<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, DepositRefund<BalanceOf<T>>>;
+
+	/// The paras for which a tranche of their deposit is to be refunded at a given block.
+	#[pallet::storage]
+	pub type DepositRefundAgenda<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<ParaId>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -226,7 +273,19 @@ pub mod pallet {
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let due = DepositRefundAgenda::<T>::take(now);
+			let count = due.len() as u64;
+			for para in due {
+				Self::refund_deposit_tranche(para, now);
+			}
+			let tranche_weight = <T as Config>::WeightInfo::refund_deposit_tranche();
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(tranche_weight.saturating_mul(count))
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -499,6 +558,11 @@ impl<T: Config> Registrar for Pallet<T> {
 		Ok(())
 	}
 
+	// Schedule the refund of the deposit of a para whose lease was converted to coretime.
+	fn convert_lease(id: ParaId) -> DispatchResult {
+		Self::do_convert_lease(id)
+	}
+
 	// Downgrade a registered para into a parathread (on-demand parachain).
 	fn make_parathread(id: ParaId) -> DispatchResult {
 		// Para backend should think this is a parachain...
@@ -638,10 +702,66 @@ impl<T: Config> Pallet<T> {
 		}
 
 		PendingSwap::<T>::remove(id);
+		DepositRefunds::<T>::remove(id);
 		Self::deposit_event(Event::<T>::Deregistered { para_id: id });
 		Ok(())
 	}
 
+	/// Schedule the refund of the deposit of the para `id`, whose lease was converted to coretime.
+	///
+	/// The deposit is unreserved in `DepositRefundTranches` tranches, the first one after
+	/// `DepositRefundPeriod` blocks and each following one `DepositRefundPeriod` blocks after the
+	/// previous.
+	fn do_convert_lease(id: ParaId) -> DispatchResult {
+		let info = Paras::<T>::get(id).ok_or(Error::<T>::NotRegistered)?;
+		ensure!(!DepositRefunds::<T>::contains_key(id), Error::<T>::RefundAlreadyScheduled);
+		ensure!(!info.deposit.is_zero(), Error::<T>::NoDeposit);
+
+		let tranches = T::DepositRefundTranches::get().max(1);
+		let tranche = info.deposit / tranches.into();
+		DepositRefunds::<T>::insert(id, DepositRefund { tranche, remaining_tranches: tranches });
+		let when = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::DepositRefundPeriod::get().max(One::one()));
+		DepositRefundAgenda::<T>::append(when, id);
+
+		Self::deposit_event(Event::<T>::LeaseConverted { para_id: id, deposit: info.deposit });
+		Ok(())
+	}
+
+	/// Refund the next tranche of the deposit of the para `id` and schedule the one after it, if
+	/// any.
+	fn refund_deposit_tranche(id: ParaId, now: BlockNumberFor<T>) {
+		let Some(mut refund) = DepositRefunds::<T>::get(id) else { return };
+		let Some(mut info) = Paras::<T>::get(id) else {
+			DepositRefunds::<T>::remove(id);
+			return
+		};
+
+		refund.remaining_tranches.saturating_dec();
+		let amount = if refund.remaining_tranches == 0 {
+			info.deposit
+		} else {
+			refund.tranche.min(info.deposit)
+		};
+		<T as Config>::Currency::unreserve(&info.manager, amount);
+		info.deposit.saturating_reduce(amount);
+
+		if refund.remaining_tranches == 0 {
+			DepositRefunds::<T>::remove(id);
+		} else {
+			let when = now.saturating_add(T::DepositRefundPeriod::get().max(One::one()));
+			DepositRefundAgenda::<T>::append(when, id);
+			DepositRefunds::<T>::insert(id, &refund);
+		}
+		Self::deposit_event(Event::<T>::DepositTrancheRefunded {
+			para_id: id,
+			who: info.manager.clone(),
+			amount,
+			remaining_tranches: refund.remaining_tranches,
+		});
+		Paras::<T>::insert(id, info);
+	}
+
 	/// Verifies the onboarding data is valid for a para.
 	///
 	/// Returns `ParaGenesisArgs` and the deposit needed for the data.
@@ -824,6 +944,8 @@ mod tests {
 		pub const ParaDeposit: Balance = 10;
 		pub const DataDepositPerByte: Balance = 1;
 		pub const MaxRetries: u32 = 3;
+		pub const DepositRefundTranches: u32 = 3;
+		pub const DepositRefundPeriod: BlockNumber = 10;
 	}
 
 	impl Config for Test {
@@ -833,6 +955,8 @@ mod tests {
 		type OnSwap = MockSwap;
 		type ParaDeposit = ParaDeposit;
 		type DataDepositPerByte = DataDepositPerByte;
+		type DepositRefundTranches = DepositRefundTranches;
+		type DepositRefundPeriod = DepositRefundPeriod;
 		type WeightInfo = TestWeightInfo;
 	}
 
@@ -906,6 +1030,7 @@ mod tests {
 			}
 			System::set_block_number(b + 1);
 			System::on_initialize(System::block_number());
+			Registrar::on_initialize(System::block_number());
 		}
 	}
 
@@ -988,6 +1113,59 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn convert_lease_refunds_deposit_in_tranches() {
+		new_test_ext().execute_with(|| {
+			let para_id = LOWEST_PUBLIC_ID;
+
+			const START_SESSION_INDEX: SessionIndex = 1;
+			run_to_session(START_SESSION_INDEX);
+
+			let validation_code = test_validation_code(32);
+			assert_ok!(Registrar::reserve(RuntimeOrigin::signed(1)));
+			assert_ok!(Registrar::register(
+				RuntimeOrigin::signed(1),
+				para_id,
+				test_genesis_head(32),
+				validation_code.clone(),
+			));
+			conclude_pvf_checking::<Test>(&validation_code, VALIDATORS, START_SESSION_INDEX);
+			run_to_session(START_SESSION_INDEX + 2);
+
+			assert_noop!(Registrar::convert_lease(para_id + 1), Error::<Test>::NotRegistered);
+			assert_ok!(Registrar::convert_lease(para_id));
+			assert_noop!(Registrar::convert_lease(para_id), Error::<Test>::RefundAlreadyScheduled);
+			let deposit = <Test as Config>::ParaDeposit::get() +
+				64 * <Test as Config>::DataDepositPerByte::get();
+			System::assert_last_event(Event::LeaseConverted { para_id, deposit }.into());
+			assert_eq!(Balances::reserved_balance(&1), deposit);
+
+			// The deposit is refunded in three tranches, the last one refunding the remainder.
+			let now = System::block_number();
+			let mut reserved = deposit;
+			for (tranche, amount) in [(1, 24), (2, 24), (3, deposit - 48)] {
+				run_to_block(now + tranche * 10 - 1);
+				assert_eq!(Balances::reserved_balance(&1), reserved);
+				run_to_block(now + tranche * 10);
+				reserved -= amount;
+				assert_eq!(Balances::reserved_balance(&1), reserved);
+				System::assert_has_event(
+					Event::DepositTrancheRefunded {
+						para_id,
+						who: 1,
+						amount,
+						remaining_tranches: 3 - tranche,
+					}
+					.into(),
+				);
+			}
+			assert_eq!(reserved, 0);
+			assert!(DepositRefunds::<Test>::get(para_id).is_none());
+			assert_eq!(Paras::<Test>::get(para_id).unwrap().deposit, 0);
+			assert_noop!(Registrar::convert_lease(para_id), Error::<Test>::NoDeposit);
+		});
+	}
+
 	#[test]
 	fn register_works() {
 		new_test_ext().execute_with(|| {
@@ -1601,6 +1779,22 @@ mod benchmarking {
 			let para_id = ParaId::from(1000);
 		}: _(RawOrigin::Root, para_id, new_head)
 
+		refund_deposit_tranche {
+			let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+			next_scheduled_session::<T>();
+			Registrar::<T>::do_convert_lease(para)?;
+			let now = frame_system::Pallet::<T>::block_number();
+		}: {
+			Registrar::<T>::refund_deposit_tranche(para, now);
+		}
+		verify {
+			let remaining_tranches = T::DepositRefundTranches::get().max(1) - 1;
+			assert_eq!(
+				DepositRefunds::<T>::get(para).map_or(0, |r| r.remaining_tranches),
+				remaining_tranches,
+			);
+		}
+
 		impl_benchmark_test_suite!(
 			Registrar,
 			crate::integration_tests::new_test_ext(),
@@ -247,13 +247,18 @@ impl<T: Config> Pallet<T> {
 				//
 				// `para` is now just an on-demand parachain.
 				//
-				// Unreserve whatever is left.
+				// Unreserve whatever is left. This returns the funds of a crowdloan to its fund
+				// account, from which the contributors withdraw them.
 				if let Some((who, value)) = &lease_periods[0] {
 					T::Currency::unreserve(&who, *value);
 				}
 
 				// Remove the now-empty lease list.
 				Leases::<T>::remove(para);
+
+				// The lease is over, so the para continues on coretime. Failing only means that
+				// there is nothing to refund, e.g. the refund of its deposit is under way.
+				let _ = T::Registrar::convert_lease(para);
 			} else {
 				// The parachain entry has leased future periods.
 
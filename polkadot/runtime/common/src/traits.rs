@@ -74,6 +74,11 @@ pub trait Registrar {
 	/// Downgrade lease holding parachain into parathread (on-demand parachain)
 	fn make_parathread(id: ParaId) -> DispatchResult;
 
+	/// Convert the lease of a para to coretime, refunding the deposit of its registration.
+	///
+	/// Must only be called once the para has no slot lease left.
+	fn convert_lease(id: ParaId) -> DispatchResult;
+
 	#[cfg(any(feature = "runtime-benchmarks", test))]
 	fn worst_head_data() -> HeadData;
 
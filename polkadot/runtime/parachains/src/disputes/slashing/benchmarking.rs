@@ -21,7 +21,7 @@ use frame_benchmarking::{benchmarks, whitelist_account};
 use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_staking::testing_utils::create_validators;
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 use primitives::{Hash, PARACHAIN_KEY_TYPE_ID};
 use sp_runtime::traits::{One, OpaqueKeys, StaticLookup};
 use sp_session::MembershipProof;
@@ -53,7 +53,8 @@ where
 		let validator = T::Lookup::lookup(who).unwrap();
 		let controller = pallet_staking::Pallet::<T>::bonded(&validator).unwrap();
 
-		let keys = {
+		let generated = T::Keys::generate_with_ownership_proof(&controller.encode(), None);
+		let (keys, proof) = generated.unwrap_or_else(|| {
 			const SESSION_KEY_LEN: usize = 32;
 			let key_ids = T::Keys::key_ids();
 			let mut keys_len = key_ids.len() * SESSION_KEY_LEN;
@@ -64,11 +65,11 @@ where
 			let mut keys = vec![0u8; keys_len];
 			let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(n as u64);
 			rng.fill_bytes(&mut keys);
-			keys
-		};
 
-		let keys: T::Keys = Decode::decode(&mut &keys[..]).expect("wrong number of session keys?");
-		let proof: Vec<u8> = vec![];
+			let keys: T::Keys =
+				Decode::decode(&mut &keys[..]).expect("wrong number of session keys?");
+			(keys, Vec::new())
+		});
 
 		whitelist_account!(controller);
 		pallet_session::Pallet::<T>::set_keys(RawOrigin::Signed(controller).into(), keys, proof)
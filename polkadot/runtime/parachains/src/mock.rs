@@ -171,6 +171,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = ();
 	type MinimumPeriod = MinimumPeriod;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -340,4 +340,6 @@ impl pallet_ranked_collective::Config<FellowshipCollectiveInstance> for Runtime
 	type Polls = FellowshipReferenda;
 	type MinRankOfClass = sp_runtime::traits::Identity;
 	type VoteWeight = pallet_ranked_collective::Geometric;
+	type InactivityPeriod = ();
+	type ExemptOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
@@ -70,6 +70,7 @@ impl pallet_whitelist::Config for Runtime {
 		EitherOf<EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>, Fellows>;
 	type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<Self::AccountId>, WhitelistedCaller>;
 	type Preimages = Preimage;
+	type MaxExpiringPerBlock = ConstU32<16>;
 }
 
 impl pallet_referenda::Config for Runtime {
@@ -31,12 +31,13 @@ use primitives::{
 	ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
 };
 use runtime_common::{
-	assigned_slots, auctions, claims, crowdloan, identity_migrator, impl_runtime_weights,
+	assigned_slots, auctions, claims, collator_offences, crowdloan, identity_migrator,
+	impl_runtime_weights,
 	impls::{
 		LocatableAssetConverter, ToAuthor, VersionedLocatableAsset, VersionedMultiLocationConverter,
 	},
-	paras_registrar, paras_sudo_wrapper, prod_or_fast, slots, BlockHashCount, BlockLength,
-	SlowAdjustingFeeUpdate,
+	paras_governance::{self, ParaActionKind},
+	paras_registrar, prod_or_fast, slots, BlockHashCount, BlockLength, SlowAdjustingFeeUpdate,
 };
 use scale_info::TypeInfo;
 use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, prelude::*};
@@ -68,9 +69,9 @@ use frame_support::{
 	genesis_builder_helper::{build_config, create_default_config},
 	parameter_types,
 	traits::{
-		fungible::HoldConsideration, Contains, EitherOf, EitherOfDiverse, EverythingBut,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage,
-		ProcessMessageError, StorageMapShim, WithdrawReasons,
+		fungible::HoldConsideration, Contains, EitherOf, EitherOfDiverse, EnsureOrigin,
+		EnsureOriginWithArg, EverythingBut, InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice,
+		PrivilegeCmp, ProcessMessage, ProcessMessageError, StorageMapShim, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, WeightMeter},
 	PalletId,
@@ -84,7 +85,7 @@ use sp_core::{ConstU128, OpaqueMetadata, H256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, ConstU32, ConvertInto,
+		AccountIdLookup, BlakeTwo256, Block as BlockT, ConstU32, Convert, ConvertInto,
 		Extrinsic as ExtrinsicT, IdentityLookup, Keccak256, OpaqueKeys, SaturatedConversion,
 		Verify,
 	},
@@ -122,7 +123,7 @@ use impls::ToParachainIdentityReaper;
 pub mod governance;
 use governance::{
 	pallet_custom_origins, AuctionAdmin, Fellows, GeneralAdmin, LeaseAdmin, Treasurer,
-	TreasurySpender,
+	TreasurySpender, WhitelistedCaller,
 };
 
 #[cfg(test)]
@@ -210,6 +211,8 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 parameter_types! {
@@ -293,6 +296,7 @@ impl pallet_babe::Config for Runtime {
 
 parameter_types! {
 	pub const IndexDeposit: Balance = 100 * CENTS;
+	pub const IndexLeasePeriod: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_indices::Config for Runtime {
@@ -300,6 +304,8 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type LeasePeriod = IndexLeasePeriod;
+	type MaxLeasesExpiringPerBlock = ConstU32<16>;
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
 
@@ -349,6 +355,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type DriftCheck = ();
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
@@ -551,6 +558,7 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type CuratorFeeAccrualPeriod = ConstU32<{ 30 * DAYS }>;
 	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
 }
 
@@ -558,6 +566,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = ();
+	type DeferPeriod = ConstU32<0>;
+	type VetoOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -674,6 +684,11 @@ impl pallet_identity::Config for Runtime {
 	type Slashed = Treasury;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type MigratorOrigin = EnsureRoot<Self::AccountId>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -689,6 +704,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigExpiry: BlockNumber = 30 * DAYS;
 	pub const MaxSignatories: u32 = 100;
 }
 
@@ -699,6 +715,9 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type Preimages = Preimage;
+	type MaxNotedCallLen = ConstU32<{ 16 * 1024 }>;
+	type MultisigExpiry = MultisigExpiry;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -738,6 +757,7 @@ impl pallet_society::Config for Runtime {
 	type MaxPayouts = ConstU32<8>;
 	type MaxBids = ConstU32<512>;
 	type PalletId = SocietyPalletId;
+	type CandidateJudgement = ();
 	type WeightInfo = ();
 }
 
@@ -765,6 +785,7 @@ parameter_types! {
 	pub const MaxProxies: u16 = 32;
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const PureRecoveryDelay: BlockNumber = 28 * DAYS;
 	pub const MaxPending: u16 = 32;
 }
 
@@ -903,6 +924,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type RecoveryDelay = PureRecoveryDelay;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1062,6 +1085,8 @@ impl parachains_slashing::Config for Runtime {
 
 parameter_types! {
 	pub const ParaDeposit: Balance = 40 * UNITS;
+	pub const DepositRefundTranches: u32 = 4;
+	pub const DepositRefundPeriod: BlockNumber = 1 * DAYS;
 }
 
 impl paras_registrar::Config for Runtime {
@@ -1071,6 +1096,8 @@ impl paras_registrar::Config for Runtime {
 	type OnSwap = (Crowdloan, Slots);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
+	type DepositRefundTranches = DepositRefundTranches;
+	type DepositRefundPeriod = DepositRefundPeriod;
 	type WeightInfo = weights::runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
@@ -1133,6 +1160,7 @@ impl identity_migrator::Config for Runtime {
 	// To be changed to `EnsureSigned` once there is a People Chain to migrate to.
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type MilestoneInterval = ConstU32<1_000>;
 	type WeightInfo = weights::runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1259,14 +1287,85 @@ impl BeefyDataProvider<H256> for ParaHeadsRootProvider {
 	}
 }
 
+parameter_types! {
+	pub const MmrLeafRetention: u64 = 28 * DAYS as u64;
+}
+
 impl pallet_beefy_mmr::Config for Runtime {
 	type LeafVersion = LeafVersion;
 	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 	type LeafExtra = H256;
 	type BeefyDataProvider = ParaHeadsRootProvider;
+	type RuntimeEvent = RuntimeEvent;
+	type PruneOrigin = EnsureRoot<AccountId>;
+	type LeafRetention = MmrLeafRetention;
+	type WeightInfo = ();
 }
 
-impl paras_sudo_wrapper::Config for Runtime {}
+/// The origins which may take each kind of para management action.
+///
+/// Onboarding, offboarding and HRMP channels are general administration, changes of the lease
+/// type go through the lease admin track, and arbitrary downward messages need to be whitelisted.
+pub struct ParasManagerOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, ParaActionKind> for ParasManagerOrigin {
+	type Success = ();
+
+	fn try_origin(o: RuntimeOrigin, kind: &ParaActionKind) -> Result<(), RuntimeOrigin> {
+		match kind {
+			ParaActionKind::Initialize |
+			ParaActionKind::Cleanup |
+			ParaActionKind::EstablishHrmpChannel =>
+				EitherOf::<EnsureRoot<AccountId>, GeneralAdmin>::try_origin(o),
+			ParaActionKind::Upgrade | ParaActionKind::Downgrade =>
+				EitherOf::<EnsureRoot<AccountId>, LeaseAdmin>::try_origin(o),
+			ParaActionKind::QueueDownwardXcm =>
+				EitherOf::<EnsureRoot<AccountId>, WhitelistedCaller>::try_origin(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_kind: &ParaActionKind) -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+/// The delay between the approval of para management actions and their enactment.
+///
+/// Actions which take a para off the relay chain, or cut its cores, leave a day for the para to
+/// react, the others are enacted as soon as possible.
+pub struct ParasActionDelay;
+impl Convert<ParaActionKind, BlockNumber> for ParasActionDelay {
+	fn convert(kind: ParaActionKind) -> BlockNumber {
+		match kind {
+			ParaActionKind::Cleanup | ParaActionKind::Downgrade => prod_or_fast!(1 * DAYS, 1),
+			_ => 0,
+		}
+	}
+}
+
+impl paras_governance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = ParasManagerOrigin;
+	type Delay = ParasActionDelay;
+	type MaxActionsPerBlock = ConstU32<8>;
+	type WeightInfo = weights::runtime_common_paras_governance::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	/// The weight paras may use to handle the notification of a reported collator.
+	pub CollatorOffenceNotificationWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+}
+
+impl collator_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type ValidatorSet = Session;
+	type XcmSender = xcm_config::XcmRouter;
+	type ReportThreshold = ConstU32<10>;
+	type MaxCollatorsPerPara = ConstU32<100>;
+	type NotificationWeight = CollatorOffenceNotificationWeight;
+	type WeightInfo = weights::runtime_common_collator_offences::WeightInfo<Runtime>;
+}
 
 parameter_types! {
 	pub const PermanentSlotLeasePeriodLength: u32 = 365;
@@ -1292,6 +1391,7 @@ impl validator_manager::Config for Runtime {
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	type AnnouncementDelay = ();
 	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
 }
 
@@ -1307,6 +1407,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type MaxRateHistory = ConstU32<50>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -1328,7 +1429,7 @@ construct_runtime! {
 		// Consensus support.
 		// Authorship must be before session in order to note author in the correct session and era.
 		Authorship: pallet_authorship::{Pallet, Storage} = 5,
-		Offences: pallet_offences::{Pallet, Storage, Event} = 7,
+		Offences: pallet_offences::{Pallet, Call, Storage, Event} = 7,
 		Historical: session_historical::{Pallet} = 34,
 
 		// BEEFY Bridges support.
@@ -1336,7 +1437,7 @@ construct_runtime! {
 		// MMR leaf construction must be before session in order to have leaf contents
 		// refer to block<N-1> consistently. see substrate issue #11797 for details.
 		Mmr: pallet_mmr::{Pallet, Storage} = 241,
-		MmrLeaf: pallet_beefy_mmr::{Pallet, Storage} = 242,
+		MmrLeaf: pallet_beefy_mmr::{Pallet, Call, Storage, Event<T>} = 242,
 
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 8,
 		Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config<T>, Event, ValidateUnsigned} = 10,
@@ -1422,14 +1523,15 @@ construct_runtime! {
 		Slots: slots::{Pallet, Call, Storage, Event<T>} = 71,
 		Auctions: auctions::{Pallet, Call, Storage, Event<T>} = 72,
 		Crowdloan: crowdloan::{Pallet, Call, Storage, Event<T>} = 73,
+		ParasGovernance: paras_governance::{Pallet, Call, Storage, Event<T>} = 74,
+		CollatorOffences: collator_offences::{Pallet, Call, Storage, Event<T>} = 75,
 
 		// Pallet for sending XCM.
 		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>} = 99,
 
 		// Pallet for migrating Identity to a parachain. To be removed post-migration.
-		IdentityMigrator: identity_migrator::{Pallet, Call, Event<T>} = 248,
+		IdentityMigrator: identity_migrator::{Pallet, Call, Storage, Event<T>} = 248,
 
-		ParasSudoWrapper: paras_sudo_wrapper::{Pallet, Call} = 250,
 		AssignedSlots: assigned_slots::{Pallet, Call, Storage, Event<T>, Config<T>} = 251,
 
 		// Validator Manager pallet.
@@ -1616,6 +1718,11 @@ pub mod migrations {
 		paras_registrar::migration::MigrateToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, ()>,
 		pallet_referenda::migration::v1::MigrateV0ToV1<Runtime, pallet_referenda::Instance2>,
+		pallet_nis::migration::v1::MigrateToV1<Runtime>,
+		pallet_identity::migration::v1::MigrateToV1<Runtime>,
+		pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+		pallet_xcm::migration::InitMigratedXcmVersion<Runtime>,
+		pallet_preimage::migration::tickets::KeepTickets<Runtime>,
 
 		// Unlock & unreserve Gov1 funds
 
@@ -1690,9 +1797,11 @@ mod benches {
 		[runtime_common::auctions, Auctions]
 		[runtime_common::crowdloan, Crowdloan]
 		[runtime_common::claims, Claims]
+		[runtime_common::collator_offences, CollatorOffences]
 		[runtime_common::identity_migrator, IdentityMigrator]
 		[runtime_common::slots, Slots]
 		[runtime_common::paras_registrar, Registrar]
+		[runtime_common::paras_governance, ParasGovernance]
 		[runtime_parachains::configuration, Configuration]
 		[runtime_parachains::hrmp, Hrmp]
 		[runtime_parachains::disputes, ParasDisputes]
@@ -1954,7 +2063,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(3)]
+	#[api_version(4)]
 	impl beefy_primitives::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
 			Beefy::genesis_block()
@@ -1990,9 +2099,13 @@ sp_api::impl_runtime_apis! {
 				.map(|p| p.encode())
 				.map(beefy_primitives::OpaqueKeyOwnershipProof::new)
 		}
+
+		fn signature_scheme() -> beefy_primitives::SignatureScheme {
+			Beefy::signature_scheme()
+		}
 	}
 
-	#[api_version(2)]
+	#[api_version(3)]
 	impl mmr::MmrApi<Block, mmr::Hash, BlockNumber> for Runtime {
 		fn mmr_root() -> Result<mmr::Hash, mmr::Error> {
 			Ok(Mmr::mmr_root())
@@ -2019,6 +2132,23 @@ sp_api::impl_runtime_apis! {
 			)
 		}
 
+		fn generate_leaf_proof(
+			leaf_indices: Vec<mmr::LeafIndex>,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_leaf_proof(leaf_indices, best_known_block_number).map(
+				|(leaves, proof)| {
+					(
+						leaves
+							.into_iter()
+							.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+							.collect(),
+						proof,
+					)
+				},
+			)
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::Proof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -2130,6 +2260,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -2140,6 +2271,15 @@ sp_api::impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: keys.encode(), proof }
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -2148,6 +2288,73 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_message_queue::MessageQueueApi<Block, AggregateMessageOrigin, xcm::VersionedXcm<()>>
+		for Runtime
+	{
+		fn queue_heads(
+			max_messages: u32,
+		) -> Vec<pallet_message_queue::QueueHead<AggregateMessageOrigin, xcm::VersionedXcm<()>>> {
+			MessageQueue::queue_heads::<
+				xcm_builder::DescribeXcmMessage<frame_support::traits::ConstU32<8>>,
+			>(max_messages)
+		}
+	}
+
+	impl identity_migrator::IdentityMigratorApi<Block, Balance> for Runtime {
+		fn migration_progress() -> identity_migrator::MigrationProgress<Balance> {
+			identity_migrator::Progress::<Runtime>::get()
+		}
+	}
+
+	impl pallet_xcm::XcmVersionApi<Block> for Runtime {
+		fn version_subscriptions() -> Vec<(VersionedMultiLocation, Option<xcm::Version>)> {
+			XcmPallet::version_subscriptions()
+		}
+
+		fn version_subscribers() -> Vec<(VersionedMultiLocation, xcm::Version)> {
+			XcmPallet::version_subscribers()
+		}
+
+		fn version_discovery_queue() -> Vec<(VersionedMultiLocation, u32)> {
+			XcmPallet::version_discovery_queue()
+		}
+	}
+
+	impl crowdloan::CrowdloanApi<Block, Hash, Balance> for Runtime {
+		fn contributions_root(
+			para_id: ParaId,
+		) -> Option<crowdloan::ContributionsRoot<Hash, Balance>> {
+			Crowdloan::contributions_root(para_id)
+		}
+	}
+
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId, BlockNumber> for Runtime {
+		fn export_identity(who: AccountId) -> Option<Vec<u8>> {
+			Identity::export_identity(&who).map(|bundle| bundle.encode())
+		}
+
+		fn pending_judgement_requests(
+			registrar: u32,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, BlockNumber)> {
+			Identity::pending_requests(registrar, start_after, limit)
+		}
+	}
+
+	impl pallet_asset_rate_runtime_api::AssetRateApi<
+		Block,
+		<Runtime as pallet_treasury::Config>::AssetKind,
+		BlockNumber,
+	> for Runtime {
+		fn rate_at(
+			asset_kind: <Runtime as pallet_treasury::Config>::AssetKind,
+			block: BlockNumber,
+		) -> Option<FixedU128> {
+			AssetRate::rate_at(&asset_kind, block)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2166,6 +2373,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_beefy_mmr::BeefyMmrApi<Block, Hash> for RuntimeApi {
 		fn authority_set_proof() -> beefy_primitives::mmr::BeefyAuthoritySet<Hash> {
 			MmrLeaf::authority_set_proof()
@@ -2174,6 +2382,10 @@ sp_api::impl_runtime_apis! {
 		fn next_authority_set_proof() -> beefy_primitives::mmr::BeefyNextAuthoritySet<Hash> {
 			MmrLeaf::next_authority_set_proof()
 		}
+
+		fn earliest_provable_leaf() -> u64 {
+			MmrLeaf::earliest_provable_leaf()
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
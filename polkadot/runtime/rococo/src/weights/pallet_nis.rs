@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `bm5`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("rococo-dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since `split` was added and communal receipts may be thawed in tranches.
+//! The weights of `split` and `thaw_communal` are PLACEHOLDERS. Re-run the benchmarks to replace
+//! them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -196,10 +200,7 @@ impl<T: frame_system::Config> pallet_nis::WeightInfo for WeightInfo<T> {
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn thaw_communal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `604`
-		//  Estimated: `3593`
-		// Minimum execution time: 90_972_000 picoseconds.
+		// PLACEHOLDER: not benchmarked since receipts may be thawed in tranches.
 		Weight::from_parts(92_074_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(6))
@@ -246,4 +247,15 @@ impl<T: frame_system::Config> pallet_nis::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Nis Receipts (r:1 w:2)
+	/// Proof: Nis Receipts (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nis Summary (r:1 w:1)
+	/// Proof: Nis Summary (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn split() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(18_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 3554))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
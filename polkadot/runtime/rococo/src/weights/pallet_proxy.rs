@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `bm5`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("rococo-dev"), DB CACHE: 1024
+//!
+//! NOTE: The pure proxy restoration calls were added after these weights were generated for rococo.
+//! Their weights are PLACEHOLDERS until the `pallet_proxy` benchmarks are re-run.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -216,4 +219,37 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Proxy PureRestorations (r:1 w:1)
+	/// Proof: Proxy PureRestorations (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn restore_pure_access() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(17_897_000, 0)
+			.saturating_add(Weight::from_parts(0, 3574))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Proxy PureRestorations (r:1 w:1)
+	/// Proof: Proxy PureRestorations (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Proxy Proxies (r:1 w:1)
+	/// Proof: Proxy Proxies (max_values: None, max_size: Some(1241), added: 3716, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 31]`.
+	fn enact_pure_restoration(p: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(37_806_214, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(45_319, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Proxy PureRestorations (r:1 w:1)
+	/// Proof: Proxy PureRestorations (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn cancel_pure_restoration() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(16_395_000, 0)
+			.saturating_add(Weight::from_parts(0, 3574))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -47,7 +47,9 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	// Storage: Session NextKeys (r:1 w:1)
 	// Storage: Session KeyOwner (r:6 w:6)
 	fn set_keys() -> Weight {
-		Weight::from_parts(36_115_000 as u64, 0)
+		// PLACEHOLDER: includes six sr25519 proof of possession checks, one per Rococo session key,
+		// priced with `frame_benchmarking`'s `sr25519_verification`. Not benchmarked.
+		Weight::from_parts(368_855_230 as u64, 0)
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
@@ -21,6 +21,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-aahe6cbd-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since whitelisted calls may expire. The storage accesses of the existing
+//! weights were adjusted by hand, and `whitelist_call_with_expiry` and `expire_whitelisted_calls`
+//! are PLACEHOLDERS derived from `whitelist_call` and `remove_whitelisted_call`. Re-run the
+//! benchmarks to replace them.
 
 // Executed Command:
 // target/production/polkadot
@@ -66,15 +71,38 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 	/// Proof: `Whitelist::WhitelistedCall` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `Preimage::StatusFor` (r:1 w:1)
 	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::Expiring` (r:1 w:1)
+	/// Proof: `Whitelist::Expiring` (`max_values`: None, `max_size`: Some(525), added: 3000, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::CallExpiry` (r:0 w:1)
+	/// Proof: `Whitelist::CallExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn whitelist_call_with_expiry() -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `whitelist_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `3990`
+		// Minimum execution time: 20_035_000 picoseconds.
+		Weight::from_parts(20_452_000, 0)
+			.saturating_add(Weight::from_parts(0, 3990))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Whitelist::WhitelistedCall` (r:1 w:1)
+	/// Proof: `Whitelist::WhitelistedCall` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:1)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::CallExpiry` (r:1 w:1)
+	/// Proof: `Whitelist::CallExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::Expiring` (r:1 w:1)
+	/// Proof: `Whitelist::Expiring` (`max_values`: None, `max_size`: Some(525), added: 3000, mode: `MaxEncodedLen`)
 	fn remove_whitelisted_call() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `352`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 20_247_000 picoseconds.
 		Weight::from_parts(20_808_000, 0)
-			.saturating_add(Weight::from_parts(0, 3556))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 3990))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Whitelist::WhitelistedCall` (r:1 w:1)
 	/// Proof: `Whitelist::WhitelistedCall` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
@@ -82,6 +110,10 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
 	/// Storage: `Preimage::StatusFor` (r:1 w:1)
 	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::CallExpiry` (r:1 w:1)
+	/// Proof: `Whitelist::CallExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::Expiring` (r:1 w:1)
+	/// Proof: `Whitelist::Expiring` (`max_values`: None, `max_size`: Some(525), added: 3000, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 4194294]`.
 	fn dispatch_whitelisted_call(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -92,25 +124,54 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3892))
 			// Standard Error: 1
 			.saturating_add(Weight::from_parts(1_223, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
 	}
 	/// Storage: `Whitelist::WhitelistedCall` (r:1 w:1)
 	/// Proof: `Whitelist::WhitelistedCall` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	/// Storage: `Preimage::StatusFor` (r:1 w:1)
 	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::CallExpiry` (r:1 w:1)
+	/// Proof: `Whitelist::CallExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::Expiring` (r:1 w:1)
+	/// Proof: `Whitelist::Expiring` (`max_values`: None, `max_size`: Some(525), added: 3000, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10000]`.
 	fn dispatch_whitelisted_call_with_preimage(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `352`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 23_833_000 picoseconds.
 		Weight::from_parts(24_698_994, 0)
-			.saturating_add(Weight::from_parts(0, 3556))
+			.saturating_add(Weight::from_parts(0, 3990))
 			// Standard Error: 4
 			.saturating_add(Weight::from_parts(1_454, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Whitelist::Expiring` (r:1 w:1)
+	/// Proof: `Whitelist::Expiring` (`max_values`: None, `max_size`: Some(525), added: 3000, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::CallExpiry` (r:0 w:n)
+	/// Proof: `Whitelist::CallExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Whitelist::WhitelistedCall` (r:n w:n)
+	/// Proof: `Whitelist::WhitelistedCall` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:n w:n)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_whitelisted_calls(n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `remove_whitelisted_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `183 + n * (112 ±0)`
+		//  Estimated: `3990 + n * (2566 ±0)`
+		// Minimum execution time: 3_208_000 picoseconds.
+		Weight::from_parts(3_614_000, 0)
+			.saturating_add(Weight::from_parts(0, 3990))
+			// Standard Error: 9_250
+			.saturating_add(Weight::from_parts(17_985_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2566).saturating_mul(n.into()))
 	}
 }
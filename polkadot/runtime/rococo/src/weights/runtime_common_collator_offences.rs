@@ -0,0 +1,97 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! PLACEHOLDER weights for `runtime_common::collator_offences`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. They are estimated by hand from the storage accesses of
+//! each call and from the weights of similar calls, until the file is generated with the command
+//! below.
+
+// Command to generate this file:
+// ./target/production/polkadot
+// benchmark
+// pallet
+// --chain=rococo-dev
+// --steps=50
+// --repeat=20
+// --pallet=runtime_common::collator_offences
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./file_header.txt
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_common::collator_offences`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_common::collator_offences::WeightInfo for WeightInfo<T> {
+	/// Storage: CollatorOffences Collators (r:1 w:1)
+	/// Proof: CollatorOffences Collators (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: CollatorOffences CollatorCount (r:1 w:1)
+	/// Proof: CollatorOffences CollatorCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	fn register_collator() -> Weight {
+		Weight::from_parts(17_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 3557))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: CollatorOffences Collators (r:1 w:1)
+	/// Proof: CollatorOffences Collators (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: CollatorOffences CollatorCount (r:1 w:1)
+	/// Proof: CollatorOffences CollatorCount (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: CollatorOffences Reports (r:0 w:1)
+	/// Proof: CollatorOffences Reports (max_values: None, max_size: Some(705), added: 3180, mode: MaxEncodedLen)
+	fn deregister_collator() -> Weight {
+		Weight::from_parts(21_783_000, 0)
+			.saturating_add(Weight::from_parts(0, 3557))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: CollatorOffences NotificationCalls (r:0 w:1)
+	/// Proof: CollatorOffences NotificationCalls (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	fn set_notification_call() -> Weight {
+		Weight::from_parts(10_241_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Session Validators (r:1 w:0)
+	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Session CurrentIndex (r:1 w:0)
+	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: CollatorOffences Collators (r:1 w:0)
+	/// Proof: CollatorOffences Collators (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: CollatorOffences Reports (r:1 w:1)
+	/// Proof: CollatorOffences Reports (max_values: None, max_size: Some(705), added: 3180, mode: MaxEncodedLen)
+	/// Storage: CollatorOffences NotificationCalls (r:1 w:0)
+	/// Proof: CollatorOffences NotificationCalls (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	fn report_invalid_candidate() -> Weight {
+		Weight::from_parts(51_112_000, 0)
+			.saturating_add(Weight::from_parts(0, 4871))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
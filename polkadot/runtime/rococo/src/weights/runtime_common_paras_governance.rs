@@ -0,0 +1,98 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! PLACEHOLDER weights for `runtime_common::paras_governance`
+//!
+//! THESE WEIGHTS WERE NOT BENCHMARKED. They are estimated by hand from the storage accesses of
+//! each call and from the weights of `runtime_common::paras_registrar::register`, which handles
+//! validation code of the same size, until the file is generated with the command below.
+
+// Command to generate this file:
+// ./target/production/polkadot
+// benchmark
+// pallet
+// --chain=rococo-dev
+// --steps=50
+// --repeat=20
+// --pallet=runtime_common::paras_governance
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --header=./file_header.txt
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_common::paras_governance`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_common::paras_governance::WeightInfo for WeightInfo<T> {
+	/// Storage: ParasGovernance NextActionIndex (r:1 w:1)
+	/// Proof Skipped: ParasGovernance NextActionIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParasGovernance Agenda (r:1 w:1)
+	/// Proof Skipped: ParasGovernance Agenda (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParasGovernance Actions (r:0 w:1)
+	/// Proof Skipped: ParasGovernance Actions (max_values: None, max_size: None, mode: Measured)
+	fn schedule() -> Weight {
+		Weight::from_parts(1_437_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: ParasGovernance Actions (r:1 w:1)
+	/// Proof Skipped: ParasGovernance Actions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParasGovernance Agenda (r:1 w:1)
+	/// Proof Skipped: ParasGovernance Agenda (max_values: None, max_size: None, mode: Measured)
+	fn cancel() -> Weight {
+		Weight::from_parts(25_119_000, 0)
+			.saturating_add(Weight::from_parts(0, 3149416))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: ParasGovernance Actions (r:1 w:1)
+	/// Proof Skipped: ParasGovernance Actions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras FutureCodeHash (r:1 w:0)
+	/// Proof Skipped: Paras FutureCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras ParaLifecycles (r:1 w:1)
+	/// Proof Skipped: Paras ParaLifecycles (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras ActionsQueue (r:1 w:1)
+	/// Proof Skipped: Paras ActionsQueue (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Paras PvfActiveVoteMap (r:1 w:1)
+	/// Proof Skipped: Paras PvfActiveVoteMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CodeByHash (r:1 w:1)
+	/// Proof Skipped: Paras CodeByHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParasShared ActiveValidatorKeys (r:1 w:0)
+	/// Proof Skipped: ParasShared ActiveValidatorKeys (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Paras PvfActiveVoteList (r:1 w:1)
+	/// Proof Skipped: Paras PvfActiveVoteList (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Paras CodeByHashRefs (r:1 w:1)
+	/// Proof Skipped: Paras CodeByHashRefs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras CurrentCodeHash (r:0 w:1)
+	/// Proof Skipped: Paras CurrentCodeHash (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Paras UpcomingParasGenesis (r:0 w:1)
+	/// Proof Skipped: Paras UpcomingParasGenesis (max_values: None, max_size: None, mode: Measured)
+	fn enact() -> Weight {
+		Weight::from_parts(6_352_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 3149484))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+}
@@ -125,6 +125,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = super::RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Nothing;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 impl pallet_xcm::Config for crate::Runtime {
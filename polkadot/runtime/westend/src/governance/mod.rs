@@ -74,6 +74,7 @@ impl pallet_whitelist::Config for Runtime {
 	>;
 	type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<Self::AccountId>, WhitelistedCaller>;
 	type Preimages = Preimage;
+	type MaxExpiringPerBlock = ConstU32<16>;
 }
 
 impl pallet_referenda::Config for Runtime {
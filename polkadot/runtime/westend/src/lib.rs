@@ -205,6 +205,8 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 parameter_types! {
@@ -281,6 +283,7 @@ impl pallet_babe::Config for Runtime {
 
 parameter_types! {
 	pub const IndexDeposit: Balance = 100 * CENTS;
+	pub const IndexLeasePeriod: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_indices::Config for Runtime {
@@ -288,6 +291,8 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type LeasePeriod = IndexLeasePeriod;
+	type MaxLeasesExpiringPerBlock = ConstU32<16>;
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
 
@@ -382,11 +387,19 @@ impl BeefyDataProvider<H256> for ParaHeadsRootProvider {
 	}
 }
 
+parameter_types! {
+	pub const MmrLeafRetention: u64 = 28 * DAYS as u64;
+}
+
 impl pallet_beefy_mmr::Config for Runtime {
 	type LeafVersion = LeafVersion;
 	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 	type LeafExtra = H256;
 	type BeefyDataProvider = ParaHeadsRootProvider;
+	type RuntimeEvent = RuntimeEvent;
+	type PruneOrigin = EnsureRoot<AccountId>;
+	type LeafRetention = MmrLeafRetention;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -412,6 +425,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type DriftCheck = ();
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
@@ -707,6 +721,7 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = UseValidatorsMap<Self>;
 	type NominationsQuota = pallet_staking::FixedNominationsQuota<{ MaxNominations::get() }>;
 	type MaxUnlockingChunks = frame_support::traits::ConstU32<32>;
+	type NominationExpiry = ();
 	type HistoryDepth = frame_support::traits::ConstU32<84>;
 	type BenchmarkingConfig = runtime_common::StakingBenchmarkingConfig;
 	type EventListeners = NominationPools;
@@ -787,6 +802,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type DeferPeriod = ConstU32<0>;
+	type VetoOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -898,6 +915,11 @@ impl pallet_identity::Config for Runtime {
 	type MaxRegistrars = MaxRegistrars;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type MigratorOrigin = EnsureRoot<Self::AccountId>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -913,6 +935,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigExpiry: BlockNumber = 30 * DAYS;
 	pub const MaxSignatories: u32 = 100;
 }
 
@@ -923,6 +946,9 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type Preimages = Preimage;
+	type MaxNotedCallLen = ConstU32<{ 16 * 1024 }>;
+	type MultisigExpiry = MultisigExpiry;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -963,6 +989,7 @@ impl pallet_vesting::Config for Runtime {
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	type AnnouncementDelay = ();
 	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
 }
 
@@ -974,6 +1001,7 @@ parameter_types! {
 	pub const MaxProxies: u16 = 32;
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const PureRecoveryDelay: BlockNumber = 28 * DAYS;
 	pub const MaxPending: u16 = 32;
 }
 
@@ -1122,6 +1150,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type RecoveryDelay = PureRecoveryDelay;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1277,6 +1307,8 @@ impl parachains_slashing::Config for Runtime {
 parameter_types! {
 	pub const ParaDeposit: Balance = 2000 * CENTS;
 	pub const RegistrarDataDepositPerByte: Balance = deposit(0, 1);
+	pub const DepositRefundTranches: u32 = 4;
+	pub const DepositRefundPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl paras_registrar::Config for Runtime {
@@ -1286,6 +1318,8 @@ impl paras_registrar::Config for Runtime {
 	type OnSwap = (Crowdloan, Slots);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = RegistrarDataDepositPerByte;
+	type DepositRefundTranches = DepositRefundTranches;
+	type DepositRefundPeriod = DepositRefundPeriod;
 	type WeightInfo = weights::runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
@@ -1348,6 +1382,7 @@ impl identity_migrator::Config for Runtime {
 	// To be changed to `EnsureSigned` once there is a People Chain to migrate to.
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type MilestoneInterval = ConstU32<1_000>;
 	type WeightInfo = weights::runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1392,6 +1427,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type MaxRateHistory = ConstU32<50>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -1414,7 +1450,7 @@ construct_runtime! {
 		// Authorship must be before session in order to note author in the correct session and era.
 		Authorship: pallet_authorship::{Pallet, Storage} = 5,
 		Staking: pallet_staking::{Pallet, Call, Storage, Config<T>, Event<T>} = 6,
-		Offences: pallet_offences::{Pallet, Storage, Event} = 7,
+		Offences: pallet_offences::{Pallet, Call, Storage, Event} = 7,
 		Historical: session_historical::{Pallet} = 27,
 
 		// BEEFY Bridges support.
@@ -1422,7 +1458,7 @@ construct_runtime! {
 		// MMR leaf construction must be before session in order to have leaf contents
 		// refer to block<N-1> consistently. see substrate issue #11797 for details.
 		Mmr: pallet_mmr::{Pallet, Storage} = 201,
-		BeefyMmrLeaf: pallet_beefy_mmr::{Pallet, Storage} = 202,
+		BeefyMmrLeaf: pallet_beefy_mmr::{Pallet, Call, Storage, Event<T>} = 202,
 
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 8,
 		Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config<T>, Event, ValidateUnsigned} = 10,
@@ -1514,7 +1550,7 @@ construct_runtime! {
 		RootTesting: pallet_root_testing::{Pallet, Call, Storage, Event<T>} = 102,
 
 		// Pallet for migrating Identity to a parachain. To be removed post-migration.
-		IdentityMigrator: identity_migrator::{Pallet, Call, Event<T>} = 248,
+		IdentityMigrator: identity_migrator::{Pallet, Call, Storage, Event<T>} = 248,
 	}
 }
 
@@ -1650,6 +1686,10 @@ pub mod migrations {
 		pallet_grandpa::migrations::MigrateV4ToV5<Runtime>,
 		parachains_configuration::migration::v10::MigrateToV10<Runtime>,
 		pallet_nomination_pools::migration::versioned::V7ToV8<Runtime>,
+		pallet_identity::migration::v1::MigrateToV1<Runtime>,
+		pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+		pallet_xcm::migration::InitMigratedXcmVersion<Runtime>,
+		pallet_preimage::migration::tickets::KeepTickets<Runtime>,
 		UpgradeSessionKeys,
 		frame_support::migrations::RemovePallet<
 			ImOnlinePalletName,
@@ -1983,6 +2023,10 @@ sp_api::impl_runtime_apis! {
 				.map(|p| p.encode())
 				.map(beefy_primitives::OpaqueKeyOwnershipProof::new)
 		}
+
+		fn signature_scheme() -> beefy_primitives::SignatureScheme {
+			Beefy::signature_scheme()
+		}
 	}
 
 	impl mmr::MmrApi<Block, Hash, BlockNumber> for Runtime {
@@ -2011,6 +2055,23 @@ sp_api::impl_runtime_apis! {
 			)
 		}
 
+		fn generate_leaf_proof(
+			leaf_indices: Vec<mmr::LeafIndex>,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_leaf_proof(leaf_indices, best_known_block_number).map(
+				|(leaves, proof)| {
+					(
+						leaves
+							.into_iter()
+							.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+							.collect(),
+						proof,
+					)
+				},
+			)
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::Proof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -2031,6 +2092,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_beefy_mmr::BeefyMmrApi<Block, Hash> for RuntimeApi {
 		fn authority_set_proof() -> beefy_primitives::mmr::BeefyAuthoritySet<Hash> {
 			BeefyMmrLeaf::authority_set_proof()
@@ -2039,6 +2101,10 @@ sp_api::impl_runtime_apis! {
 		fn next_authority_set_proof() -> beefy_primitives::mmr::BeefyNextAuthoritySet<Hash> {
 			BeefyMmrLeaf::next_authority_set_proof()
 		}
+
+		fn earliest_provable_leaf() -> u64 {
+			BeefyMmrLeaf::earliest_provable_leaf()
+		}
 	}
 
 	impl fg_primitives::GrandpaApi<Block> for Runtime {
@@ -2132,6 +2198,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -2142,6 +2209,15 @@ sp_api::impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: keys.encode(), proof }
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -2150,6 +2226,73 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_message_queue::MessageQueueApi<Block, AggregateMessageOrigin, xcm::VersionedXcm<()>>
+		for Runtime
+	{
+		fn queue_heads(
+			max_messages: u32,
+		) -> Vec<pallet_message_queue::QueueHead<AggregateMessageOrigin, xcm::VersionedXcm<()>>> {
+			MessageQueue::queue_heads::<
+				xcm_builder::DescribeXcmMessage<frame_support::traits::ConstU32<8>>,
+			>(max_messages)
+		}
+	}
+
+	impl identity_migrator::IdentityMigratorApi<Block, Balance> for Runtime {
+		fn migration_progress() -> identity_migrator::MigrationProgress<Balance> {
+			identity_migrator::Progress::<Runtime>::get()
+		}
+	}
+
+	impl pallet_xcm::XcmVersionApi<Block> for Runtime {
+		fn version_subscriptions() -> Vec<(VersionedMultiLocation, Option<xcm::Version>)> {
+			XcmPallet::version_subscriptions()
+		}
+
+		fn version_subscribers() -> Vec<(VersionedMultiLocation, xcm::Version)> {
+			XcmPallet::version_subscribers()
+		}
+
+		fn version_discovery_queue() -> Vec<(VersionedMultiLocation, u32)> {
+			XcmPallet::version_discovery_queue()
+		}
+	}
+
+	impl crowdloan::CrowdloanApi<Block, Hash, Balance> for Runtime {
+		fn contributions_root(
+			para_id: ParaId,
+		) -> Option<crowdloan::ContributionsRoot<Hash, Balance>> {
+			Crowdloan::contributions_root(para_id)
+		}
+	}
+
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId, BlockNumber> for Runtime {
+		fn export_identity(who: AccountId) -> Option<Vec<u8>> {
+			Identity::export_identity(&who).map(|bundle| bundle.encode())
+		}
+
+		fn pending_judgement_requests(
+			registrar: u32,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, BlockNumber)> {
+			Identity::pending_requests(registrar, start_after, limit)
+		}
+	}
+
+	impl pallet_asset_rate_runtime_api::AssetRateApi<
+		Block,
+		<Runtime as pallet_treasury::Config>::AssetKind,
+		BlockNumber,
+	> for Runtime {
+		fn rate_at(
+			asset_kind: <Runtime as pallet_treasury::Config>::AssetKind,
+			block: BlockNumber,
+		) -> Option<FixedU128> {
+			AssetRate::rate_at(&asset_kind, block)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -2203,6 +2346,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2211,6 +2355,10 @@ sp_api::impl_runtime_apis! {
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page {
 			Staking::api_eras_stakers_page_count(era, account)
 		}
+
+		fn era_payout_forecast() -> Option<sp_staking::EraPayoutForecast<AccountId, Balance>> {
+			Staking::api_era_payout_forecast()
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
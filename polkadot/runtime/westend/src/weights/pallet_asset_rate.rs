@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("polkadot-dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated for Westend since each rate change is also recorded in `RateHistory`. The
+//! weights are PLACEHOLDERS estimated by hand and should be replaced by a fresh benchmark run.
 
 // Executed Command:
 // ./target/debug/polkadot
@@ -49,38 +52,35 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_asset_rate::WeightInfo for WeightInfo<T> {
 	/// Storage: AssetRate ConversionRateToNative (r:1 w:1)
 	/// Proof: AssetRate ConversionRateToNative (max_values: None, max_size: Some(1237), added: 3712, mode: MaxEncodedLen)
+	/// Storage: AssetRate RateHistory (r:1 w:1)
+	/// Proof: AssetRate RateHistory (max_values: None, max_size: Some(2272), added: 4747, mode: MaxEncodedLen)
 	fn create() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `4702`
-		// Minimum execution time: 67_000_000 picoseconds.
+		// PLACEHOLDER: not benchmarked, adjusted by hand for `RateHistory`.
 		Weight::from_parts(69_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 4702))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 9449))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: AssetRate ConversionRateToNative (r:1 w:1)
 	/// Proof: AssetRate ConversionRateToNative (max_values: None, max_size: Some(1237), added: 3712, mode: MaxEncodedLen)
+	/// Storage: AssetRate RateHistory (r:1 w:1)
+	/// Proof: AssetRate RateHistory (max_values: None, max_size: Some(2272), added: 4747, mode: MaxEncodedLen)
 	fn update() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `110`
-		//  Estimated: `4702`
-		// Minimum execution time: 69_000_000 picoseconds.
+		// PLACEHOLDER: not benchmarked, adjusted by hand for `RateHistory`.
 		Weight::from_parts(71_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 4702))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 9449))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: AssetRate ConversionRateToNative (r:1 w:1)
 	/// Proof: AssetRate ConversionRateToNative (max_values: None, max_size: Some(1237), added: 3712, mode: MaxEncodedLen)
+	/// Storage: AssetRate RateHistory (r:1 w:1)
+	/// Proof: AssetRate RateHistory (max_values: None, max_size: Some(2272), added: 4747, mode: MaxEncodedLen)
 	fn remove() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `110`
-		//  Estimated: `4702`
-		// Minimum execution time: 70_000_000 picoseconds.
+		// PLACEHOLDER: not benchmarked, adjusted by hand for `RateHistory`.
 		Weight::from_parts(90_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 4702))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 9449))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
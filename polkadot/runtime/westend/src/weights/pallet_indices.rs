@@ -78,20 +78,28 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: `Indices::Leases` (r:1 w:1)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::LeaseExpiries` (r:1 w:1)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn free() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `238`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 25_574_000 picoseconds.
 		Weight::from_parts(26_123_000, 0)
-			.saturating_add(Weight::from_parts(0, 3534))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Indices::Leases` (r:1 w:1)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::LeaseExpiries` (r:1 w:1)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `341`
@@ -99,19 +107,79 @@ impl<T: frame_system::Config> pallet_indices::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_605_000 picoseconds.
 		Weight::from_parts(28_569_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: `Indices::Leases` (r:1 w:1)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::LeaseExpiries` (r:1 w:1)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `238`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 27_447_000 picoseconds.
 		Weight::from_parts(28_136_000, 0)
-			.saturating_add(Weight::from_parts(0, 3534))
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Indices::Accounts` (r:1 w:1)
+	/// Proof: `Indices::Accounts` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::Leases` (r:1 w:1)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::LeaseExpiries` (r:1 w:1)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn claim_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3542`
+		// Minimum execution time: 27_902_000 picoseconds.
+		Weight::from_parts(28_614_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Indices::Accounts` (r:1 w:0)
+	/// Proof: `Indices::Accounts` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::Leases` (r:1 w:1)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::LeaseExpiries` (r:2 w:2)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn renew_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `6094`
+		// Minimum execution time: 19_375_000 picoseconds.
+		Weight::from_parts(19_930_000, 0)
+			.saturating_add(Weight::from_parts(0, 6094))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Indices::LeaseExpiries` (r:1 w:1)
+	/// Proof: `Indices::LeaseExpiries` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::Leases` (r:0 w:n)
+	/// Proof: `Indices::Leases` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Indices::Accounts` (r:n w:n)
+	/// Proof: `Indices::Accounts` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:n w:n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_leases(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (137 ±0)`
+		//  Estimated: `3542 + n * (2603 ±0)`
+		// Minimum execution time: 2_981_000 picoseconds.
+		Weight::from_parts(3_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 3542))
+			// Standard Error: 11_406
+			.saturating_add(Weight::from_parts(20_713_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 }
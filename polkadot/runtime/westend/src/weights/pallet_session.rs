@@ -57,11 +57,9 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 	/// Storage: Session KeyOwner (r:6 w:6)
 	/// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
 	fn set_keys() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1954`
-		//  Estimated: `17794`
-		// Minimum execution time: 62_746_000 picoseconds.
-		Weight::from_parts(70_413_000, 0)
+		// PLACEHOLDER: the base was raised by hand to cover verifying the ownership proofs of the
+		// six Westend session keys (sr25519, about 55us each).
+		Weight::from_parts(403_153_230, 0)
 			.saturating_add(Weight::from_parts(0, 17794))
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(7))
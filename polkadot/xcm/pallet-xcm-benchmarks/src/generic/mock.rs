@@ -137,6 +137,8 @@ impl xcm_executor::Config for XcmConfig {
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = Aliasers;
+	type Tracer = ();
+	type ProcessedTopics = ();
 }
 
 parameter_types! {
@@ -21,6 +21,7 @@ use frame_support::{traits::Currency, weights::Weight};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 use xcm::{latest::prelude::*, v2};
+use xcm_builder::ProvideWeightHints;
 
 type RuntimeOrigin<T> = <T as frame_system::Config>::RuntimeOrigin;
 
@@ -296,6 +297,87 @@ benchmarks! {
 		<crate::Pallet::<T> as QueryHandler>::take_response(query_id);
 	}
 
+	claim_trapped_assets {
+		let (asset, _) = T::teleportable_asset_and_dest()
+			.or_else(T::reserve_transferable_asset_and_dest)
+			.ok_or(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let caller: T::AccountId = whitelisted_caller();
+		let claim_origin = RawOrigin::Signed(caller);
+		let origin_location = T::ExecuteXcmOrigin::try_origin(claim_origin.clone().into())
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let context = XcmContext { origin: Some(origin_location), message_id: [0; 32], topic: None };
+		<crate::Pallet<T> as DropAssets>::drop_assets(&origin_location, asset.into(), &context);
+		let versioned_beneficiary: VersionedMultiLocation =
+			AccountId32 { network: None, id: [0u8; 32] }.into();
+	}: _<RuntimeOrigin<T>>(claim_origin.into(), None, Box::new(versioned_beneficiary))
+	verify {
+		assert!(crate::Pallet::<T>::trapped_assets(origin_location).is_empty());
+	}
+
+	note_processed_topic {
+		// A full ring buffer, so that the oldest topic is evicted.
+		let origin: MultiLocation = Parent.into();
+		crate::RecentTopicsWindow::<T, ConstU32<1>>::note(&origin, [0; 32]);
+	}: {
+		crate::RecentTopicsWindow::<T, ConstU32<1>>::note(&origin, [1; 32]);
+	}
+	verify {
+		assert!(!crate::RecentTopicsWindow::<T, ConstU32<1>>::contains(&origin, &[0; 32]));
+		assert!(crate::RecentTopicsWindow::<T, ConstU32<1>>::contains(&origin, &[1; 32]));
+	}
+
+	teleport_assets_multi {
+		let b in 1 .. MAX_BENEFICIARIES_FOR_TRANSFER as u32;
+		let (asset, destination) = T::teleportable_asset_and_dest().ok_or(
+			BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)),
+		)?;
+
+		let transferred_amount = match &asset.fun {
+			Fungible(amount) => *amount,
+			_ => return Err(BenchmarkError::Stop("Benchmark asset not fungible")),
+		}.into();
+		let existential_deposit = T::ExistentialDeposit::get();
+		let caller = whitelisted_caller();
+
+		// Give some multiple of the existential deposit on top of the fees and transfers.
+		let withdrawn = transferred_amount.saturating_mul((b + 1).into());
+		let balance = existential_deposit.saturating_mul(ED_MULTIPLIER.into()) + withdrawn;
+		let _ = <pallet_balances::Pallet<T> as Currency<_>>::make_free_balance_be(&caller, balance);
+
+		let send_origin = RawOrigin::Signed(caller.clone());
+		let origin_location = T::ExecuteXcmOrigin::try_origin(send_origin.clone().into())
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		if !T::XcmTeleportFilter::contains(&(origin_location, vec![asset.clone()])) {
+			return Err(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))
+		}
+
+		let transfers = (0..b)
+			.map(|i| {
+				let beneficiary: VersionedMultiLocation =
+					AccountId32 { network: None, id: [i as u8; 32] }.into();
+				(beneficiary, VersionedMultiAssets::from(MultiAssets::from(asset.clone())))
+			})
+			.collect::<Vec<_>>();
+		let versioned_dest: VersionedMultiLocation = destination.into();
+		let versioned_fees: VersionedMultiAsset = asset.into();
+	}: _<RuntimeOrigin<T>>(send_origin.into(), Box::new(versioned_dest), transfers, Box::new(versioned_fees), Unlimited)
+	verify {
+		// verify balance after transfer, decreased by withdrawn amount (+ maybe XCM delivery fees)
+		assert!(pallet_balances::Pallet::<T>::free_balance(&caller) <= balance - withdrawn);
+	}
+
+	set_weight_hints {
+		let location: MultiLocation = Parent.into();
+		let hints = WeightHints {
+			calls: vec![((0, 0), Weight::from_parts(1_000, 100))],
+			..Default::default()
+		};
+		let versioned_location: VersionedMultiLocation = location.into();
+	}: _(RawOrigin::Root, Box::new(versioned_location), Some(hints.clone()))
+	verify {
+		assert_eq!(StorageWeightHints::weight_hints(&location), Some(hints));
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext_with_balances(Vec::new()),
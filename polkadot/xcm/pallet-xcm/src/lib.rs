@@ -35,6 +35,7 @@ use frame_support::{
 		Contains, ContainsPair, Currency, Defensive, EnsureOrigin, Get, LockableCurrency,
 		OriginTrait, WithdrawReasons,
 	},
+	weights::constants::RocksDbWeight,
 	PalletId,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, *};
@@ -51,13 +52,14 @@ use sp_std::{boxed::Box, marker::PhantomData, prelude::*, result::Result, vec};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::{
 	ExecuteController, ExecuteControllerWeightInfo, QueryController, QueryControllerWeightInfo,
-	SendController, SendControllerWeightInfo,
+	SendController, SendControllerWeightInfo, StorageWeightHints, WeightHints,
 };
 use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
-		DropAssets, MatchesFungible, OnResponse, Properties, QueryHandler, QueryResponseStatus,
-		TransactAsset, TransferType, VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
+		DropAssets, MatchesFungible, OnResponse, ProcessedTopics, Properties, QueryHandler,
+		QueryResponseStatus, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
+		XcmAssetTransfers,
 	},
 	Assets,
 };
@@ -81,6 +83,10 @@ pub trait WeightInfo {
 	fn migrate_and_notify_old_targets() -> Weight;
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
+	fn claim_trapped_assets() -> Weight;
+	fn note_processed_topic() -> Weight;
+	fn teleport_assets_multi(b: u32) -> Weight;
+	fn set_weight_hints() -> Weight;
 }
 
 /// fallback implementation
@@ -157,6 +163,24 @@ impl WeightInfo for TestWeightInfo {
 	fn take_response() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
+
+	fn claim_trapped_assets() -> Weight {
+		// Not benchmarked yet, estimated from the storage accesses like the runtime weights.
+		Weight::from_parts(8_933_000, 3607).saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+	}
+
+	fn note_processed_topic() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
+	fn teleport_assets_multi(b: u32) -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(b.into()))
+	}
+
+	fn set_weight_hints() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
 }
 
 #[frame_support::pallet]
@@ -478,6 +502,8 @@ pub mod pallet {
 		FeesPaid { paying: MultiLocation, fees: MultiAssets },
 		/// Some assets have been claimed from an asset trap
 		AssetsClaimed { hash: H256, origin: MultiLocation, assets: VersionedMultiAssets },
+		/// The weight hints of a destination were set, or removed if `hints` is `None`.
+		WeightHintsSet { location: MultiLocation, hints: Option<WeightHints> },
 	}
 
 	#[pallet::origin]
@@ -549,6 +575,10 @@ pub mod pallet {
 		TooManyReserves,
 		/// Local XCM execution incomplete.
 		LocalExecutionIncomplete,
+		/// No assets are trapped for the origin.
+		NoTrappedAssets,
+		/// Too many beneficiaries have been attempted for transfer.
+		TooManyBeneficiaries,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -590,6 +620,31 @@ pub mod pallet {
 		Ready { response: VersionedResponse, at: BlockNumber },
 	}
 
+	impl<BlockNumber: Clone + PartialEq> QueryStatus<BlockNumber> {
+		/// Returns `self` with all XCM values expressed in the latest XCM version, or `None` if
+		/// they already are.
+		pub(crate) fn upgraded(&self) -> Option<Self> {
+			use QueryStatus::*;
+			let upgraded = match self {
+				Pending { responder, maybe_match_querier, maybe_notify, timeout } => Pending {
+					responder: to_latest(responder),
+					maybe_match_querier: maybe_match_querier.as_ref().map(to_latest),
+					maybe_notify: *maybe_notify,
+					timeout: timeout.clone(),
+				},
+				VersionNotifier { origin, is_active } =>
+					VersionNotifier { origin: to_latest(origin), is_active: *is_active },
+				Ready { response, at } => Ready { response: to_latest(response), at: at.clone() },
+			};
+			(upgraded != *self).then_some(upgraded)
+		}
+	}
+
+	/// Express `value` in the latest XCM version, keeping it as it is if that is not possible.
+	pub(crate) fn to_latest<V: IntoVersion + Clone>(value: &V) -> V {
+		value.clone().into_latest().unwrap_or_else(|_| value.clone())
+	}
+
 	#[derive(Copy, Clone)]
 	pub(crate) struct LatestVersionedMultiLocation<'a>(pub(crate) &'a MultiLocation);
 	impl<'a> EncodeLike<VersionedMultiLocation> for LatestVersionedMultiLocation<'a> {}
@@ -608,6 +663,9 @@ pub mod pallet {
 		MigrateVersionNotifiers,
 		NotifyCurrentTargets(Option<Vec<u8>>),
 		MigrateAndNotifyOldTargets,
+		MigrateQueries(Option<Vec<u8>>),
+		MigrateLockedFungibles(Option<Vec<u8>>),
+		MigrateRemoteLockedFungibles(Option<Vec<u8>>),
 	}
 
 	impl Default for VersionMigrationStage {
@@ -634,6 +692,22 @@ pub mod pallet {
 	#[pallet::getter(fn asset_trap)]
 	pub(super) type AssetTraps<T: Config> = StorageMap<_, Identity, H256, u32, ValueQuery>;
 
+	/// The assets of the existing asset traps, by the origin they were trapped for.
+	///
+	/// The second key is the key of the trap in [`AssetTraps`]. Only traps recorded since this
+	/// index was introduced are in it: the key of a trap is a hash, from which the origin of the
+	/// traps recorded before cannot be recovered.
+	#[pallet::storage]
+	pub(super) type TrappedAssets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedMultiLocation,
+		Identity,
+		H256,
+		VersionedMultiAssets,
+		OptionQuery,
+	>;
+
 	/// Default version to encode XCM when latest version of destination is unknown. If `None`,
 	/// then the destinations whose XCM version is unknown are considered unreachable.
 	#[pallet::storage]
@@ -700,6 +774,13 @@ pub mod pallet {
 	pub(super) type CurrentMigration<T: Config> =
 		StorageValue<_, VersionMigrationStage, OptionQuery>;
 
+	/// The XCM version to which the versioned XCM values in storage were last migrated.
+	///
+	/// A new migration is started on runtime upgrade whenever this differs from the XCM version of
+	/// the runtime.
+	#[pallet::storage]
+	pub(super) type MigratedXcmVersion<T: Config> = StorageValue<_, XcmVersion, OptionQuery>;
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(MaxConsumers))]
 	pub struct RemoteLockedFungibleRecord<ConsumerIdentifier, MaxConsumers: Get<u32>> {
@@ -723,6 +804,20 @@ pub mod pallet {
 		}
 	}
 
+	impl<LockId: Clone, MaxConsumers: Get<u32>> RemoteLockedFungibleRecord<LockId, MaxConsumers> {
+		/// Returns `self` with its locations expressed in the latest XCM version, or `None` if they
+		/// already are.
+		pub(crate) fn upgraded(&self) -> Option<Self> {
+			let (owner, locker) = (to_latest(&self.owner), to_latest(&self.locker));
+			(owner != self.owner || locker != self.locker).then(|| Self {
+				amount: self.amount,
+				owner,
+				locker,
+				consumers: self.consumers.clone(),
+			})
+		}
+	}
+
 	/// Fungible assets which we know are locked on a remote chain.
 	#[pallet::storage]
 	pub(super) type RemoteLockedFungibles<T: Config> = StorageNMap<
@@ -750,6 +845,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type XcmExecutionSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The ring buffer of the recently processed messages, by their position. Each message is
+	/// recorded as the hash of its origin and topic.
+	///
+	/// See [`RecentTopicsWindow`].
+	#[pallet::storage]
+	pub(super) type RecentTopics<T: Config> =
+		StorageMap<_, Twox64Concat, u32, XcmHash, OptionQuery>;
+
+	/// The positions in [`RecentTopics`] of the hashes of recently processed origins and topics.
+	#[pallet::storage]
+	pub(super) type RecentTopicPositions<T: Config> =
+		StorageMap<_, Blake2_128Concat, XcmHash, u32, OptionQuery>;
+
+	/// The position in [`RecentTopics`] of the next topic to be noted.
+	#[pallet::storage]
+	pub(super) type NextTopicPosition<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -768,11 +880,30 @@ pub mod pallet {
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
 			SafeXcmVersion::<T>::set(self.safe_xcm_version);
+			MigratedXcmVersion::<T>::put(XCM_VERSION);
 		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			// Upgrade all versioned XCM values in storage in the background whenever the XCM
+			// version changes, so that no manual migration is required.
+			match MigratedXcmVersion::<T>::get() {
+				// Not initialised yet, see `migration::InitMigratedXcmVersion`.
+				None => T::DbWeight::get().reads(1),
+				Some(version) if version == XCM_VERSION => T::DbWeight::get().reads(1),
+				// An ongoing migration is left to complete. The version is left as is, so that the
+				// values it already went over are upgraded by a migration on a later upgrade.
+				Some(_) if CurrentMigration::<T>::exists() => T::DbWeight::get().reads(2),
+				Some(_) => {
+					CurrentMigration::<T>::put(VersionMigrationStage::default());
+					MigratedXcmVersion::<T>::put(XCM_VERSION);
+					T::DbWeight::get().reads_writes(2, 2)
+				},
+			}
+		}
+
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
 			let mut weight_used = Weight::zero();
 			if let Some(migration) = CurrentMigration::<T>::get() {
@@ -1225,12 +1356,175 @@ pub mod pallet {
 			XcmExecutionSuspended::<T>::set(suspended);
 			Ok(())
 		}
+
+		/// Claim assets trapped for the origin, i.e. dropped by an XCM executed on its behalf, and
+		/// deposit them to `beneficiary`.
+		///
+		/// A single trap is claimed per call, with an XCM `ClaimAsset` program built from the
+		/// recorded trapped assets. The traps of an origin can be listed with the
+		/// [`TrappedAssetsApi`].
+		///
+		/// Assets trapped before [`TrappedAssets`] was introduced are not indexed and can't be
+		/// claimed with this call. They must be claimed with `execute` and an XCM `ClaimAsset`
+		/// instruction, using the assets of the `AssetsTrapped` event which recorded them.
+		///
+		/// - `origin`: Must be capable of executing XCM.
+		/// - `origin_location_hint`: The location the assets were trapped for, if it is not the
+		///   location of `origin`. The executor must allow `origin` to alias it.
+		/// - `beneficiary`: The location receiving the claimed assets.
+		#[pallet::call_index(11)]
+		#[pallet::weight({
+			let hint: Option<Result<MultiLocation, ()>> =
+				origin_location_hint.clone().map(|hint| (*hint).try_into());
+			let maybe_beneficiary: Result<MultiLocation, ()> = (*beneficiary.clone()).try_into();
+			match (hint.transpose(), maybe_beneficiary) {
+				(Ok(hint), Ok(beneficiary)) => {
+					let assets = (0..MAX_ASSETS_FOR_CLAIM)
+						.map(|i| (GeneralIndex(i.into()), 1u128).into())
+						.collect::<Vec<MultiAsset>>();
+					let mut message =
+						Pallet::<T>::claim_program(hint, assets.into(), beneficiary);
+					T::Weigher::weight(&mut message).map_or(Weight::MAX, |w| T::WeightInfo::claim_trapped_assets().saturating_add(w))
+				},
+				_ => Weight::MAX,
+			}
+		})]
+		pub fn claim_trapped_assets(
+			origin: OriginFor<T>,
+			origin_location_hint: Option<Box<VersionedMultiLocation>>,
+			beneficiary: Box<VersionedMultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let trapped_location: Option<MultiLocation> = origin_location_hint
+				.map(|hint| (*hint).try_into())
+				.transpose()
+				.map_err(|()| Error::<T>::BadVersion)?;
+			let beneficiary: MultiLocation =
+				(*beneficiary).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			let trapped_for = trapped_location.unwrap_or(origin_location);
+			let assets = TrappedAssets::<T>::iter_prefix_values(VersionedMultiLocation::from(
+				trapped_for,
+			))
+			.find_map(|assets| MultiAssets::try_from(assets).ok())
+			.ok_or(Error::<T>::NoTrappedAssets)?;
+			ensure!(assets.len() <= MAX_ASSETS_FOR_CLAIM as usize, Error::<T>::TooManyAssets);
+
+			let alias = trapped_location.filter(|location| *location != origin_location);
+			let mut message = Self::claim_program(alias, assets, beneficiary);
+			let weight =
+				T::Weigher::weight(&mut message).map_err(|()| Error::<T>::UnweighableMessage)?;
+			let hash = message.using_encoded(sp_io::hashing::blake2_256);
+			let outcome = T::XcmExecutor::execute_xcm_in_credit(
+				origin_location,
+				message,
+				hash,
+				weight,
+				weight,
+			);
+			Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
+			let weight_used = outcome.weight_used();
+			outcome.ensure_complete().map_err(|error| {
+				log::error!(target: "xcm::pallet_xcm::claim_trapped_assets", "XCM execution failed with error {:?}", error);
+				Error::<T>::LocalExecutionIncomplete
+			})?;
+			Ok(Some(weight_used.saturating_add(T::WeightInfo::claim_trapped_assets())).into())
+		}
+
+		/// Teleport some assets from the local chain to several beneficiaries on the destination
+		/// chain, with a single XCM.
+		///
+		/// The assets of all transfers and `fees` are withdrawn from the origin and teleported to
+		/// `dest` in one message, so that the delivery fees are paid once for the whole batch. On
+		/// the `dest` side, `fees` pay for the execution of the message and the assets of each
+		/// transfer are deposited to its beneficiary. Whatever is left of `fees` is deposited to
+		/// the beneficiary of the last transfer.
+		///
+		/// - `origin`: Must be capable of withdrawing the assets and executing XCM.
+		/// - `dest`: Destination context for the assets. Will typically be `X2(Parent,
+		///   Parachain(..))` to send from parachain to parachain, or `X1(Parachain(..))` to send
+		///   from relay to parachain.
+		/// - `transfers`: The beneficiaries, in the context of `dest`, and the assets deposited to
+		///   each of them. May not be empty.
+		/// - `fees`: The asset withdrawn in addition to the transferred assets to pay the fees on
+		///   the `dest` side.
+		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
+		#[pallet::call_index(12)]
+		#[pallet::weight({
+			let maybe_dest: Result<MultiLocation, ()> = (*dest.clone()).try_into();
+			let maybe_assets = Pallet::<T>::multi_transfer_assets(transfers.clone(), *fees.clone());
+			match (maybe_assets, maybe_dest) {
+				(Ok((_, _, assets)), Ok(dest)) => {
+					use sp_std::vec;
+					let count = assets.len() as u32;
+					let mut message = Xcm(vec![
+						WithdrawAsset(assets),
+						SetFeesMode { jit_withdraw: true },
+						InitiateTeleport { assets: Wild(AllCounted(count)), dest, xcm: Xcm(vec![]) },
+					]);
+					let transfers = transfers.len() as u32;
+					T::Weigher::weight(&mut message).map_or(Weight::MAX, |w| T::WeightInfo::teleport_assets_multi(transfers).saturating_add(w))
+				}
+				_ => Weight::MAX,
+			}
+		})]
+		pub fn teleport_assets_multi(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			transfers: Vec<(VersionedMultiLocation, VersionedMultiAssets)>,
+			fees: Box<VersionedMultiAsset>,
+			weight_limit: WeightLimit,
+		) -> DispatchResult {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let dest = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let (transfers, fees, assets) = Self::multi_transfer_assets(transfers, *fees)?;
+
+			let value = (origin_location, assets.into_inner());
+			ensure!(T::XcmTeleportFilter::contains(&value), Error::<T>::Filtered);
+			let (origin_location, assets) = value;
+			for asset in assets.iter() {
+				let transfer_type =
+					T::XcmExecutor::determine_for(asset, &dest).map_err(Error::<T>::from)?;
+				ensure!(matches!(transfer_type, TransferType::Teleport), Error::<T>::Filtered);
+			}
+
+			let local_xcm =
+				Self::teleport_assets_multi_program(dest, transfers, assets, fees, weight_limit)?;
+			Self::execute_xcm_transfer(origin_location, dest, local_xcm, None)
+		}
+
+		/// Set the weight hints published by a destination, which are used to weigh the
+		/// `Transact` instructions sent to it, see [`xcm_builder::HintedTransactWeights`].
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `location`: The destination which published the hints.
+		/// - `hints`: The published hints, or `None` to remove the hints of `location`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_weight_hints())]
+		pub fn set_weight_hints(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			hints: Option<WeightHints>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let location: MultiLocation =
+				(*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			StorageWeightHints::set(&location, hints.as_ref());
+			Self::deposit_event(Event::WeightHintsSet { location, hints });
+			Ok(())
+		}
 	}
 }
 
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
 const MAX_ASSETS_FOR_TRANSFER: usize = 2;
 
+/// The maximum number of beneficiaries of a single `teleport_assets_multi`.
+const MAX_BENEFICIARIES_FOR_TRANSFER: usize = 64;
+
+/// The maximum number of distinct assets of a trap claimed with `claim_trapped_assets`.
+const MAX_ASSETS_FOR_CLAIM: u32 = 8;
+
 impl<T: Config> QueryHandler for Pallet<T> {
 	type QueryId = u64;
 	type BlockNumber = BlockNumberFor<T>;
@@ -1266,7 +1560,7 @@ impl<T: Config> QueryHandler for Pallet<T> {
 
 	/// Removes response when ready and emits [Event::ResponseTaken] event.
 	fn take_response(query_id: Self::QueryId) -> QueryResponseStatus<Self::BlockNumber> {
-		match Queries::<T>::get(query_id) {
+		match Self::upgraded_query(query_id) {
 			Some(QueryStatus::Ready { response, at }) => match response.try_into() {
 				Ok(response) => {
 					Queries::<T>::remove(query_id);
@@ -1456,7 +1750,7 @@ impl<T: Config> Pallet<T> {
 			fees {:?}, fees_xcm: {:?}, weight_limit: {:?}",
 			origin, dest, beneficiary, assets, transfer_type, fees, separate_fees_instructions, weight_limit,
 		);
-		let (mut local_xcm, remote_xcm) = match transfer_type {
+		let (local_xcm, remote_xcm) = match transfer_type {
 			TransferType::LocalReserve => {
 				let (local, remote) = Self::local_reserve_transfer_programs(
 					dest,
@@ -1495,6 +1789,16 @@ impl<T: Config> Pallet<T> {
 				None,
 			),
 		};
+		Self::execute_xcm_transfer(origin, dest, local_xcm, remote_xcm)
+	}
+
+	/// Execute `local_xcm` on behalf of `origin`, then send `remote_xcm`, if any, to `dest`.
+	fn execute_xcm_transfer(
+		origin: MultiLocation,
+		dest: MultiLocation,
+		mut local_xcm: Xcm<<T as Config>::RuntimeCall>,
+		remote_xcm: Option<Xcm<()>>,
+	) -> DispatchResult {
 		let weight =
 			T::Weigher::weight(&mut local_xcm).map_err(|()| Error::<T>::UnweighableMessage)?;
 		let hash = local_xcm.using_encoded(sp_io::hashing::blake2_256);
@@ -1503,7 +1807,7 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Attempted { outcome: outcome.clone() });
 		outcome.ensure_complete().map_err(|error| {
 			log::error!(
-				target: "xcm::pallet_xcm::build_and_execute_xcm_transfer_type",
+				target: "xcm::pallet_xcm::execute_xcm_transfer",
 				"XCM execution failed with error {:?}", error
 			);
 			Error::<T>::LocalExecutionIncomplete
@@ -1515,7 +1819,7 @@ impl<T: Config> Pallet<T> {
 			if origin != Here.into_location() {
 				Self::charge_fees(origin, price).map_err(|error| {
 					log::error!(
-						target: "xcm::pallet_xcm::build_and_execute_xcm_transfer_type",
+						target: "xcm::pallet_xcm::execute_xcm_transfer",
 						"Unable to charge fee with error {:?}", error
 					);
 					Error::<T>::FeesNotMet
@@ -1803,6 +2107,66 @@ impl<T: Config> Pallet<T> {
 		]))
 	}
 
+	/// Convert the `transfers` and `fees` of `teleport_assets_multi`, and return them along with
+	/// all the assets to be withdrawn for them.
+	fn multi_transfer_assets(
+		transfers: Vec<(VersionedMultiLocation, VersionedMultiAssets)>,
+		fees: VersionedMultiAsset,
+	) -> Result<(Vec<(MultiLocation, MultiAssets)>, MultiAsset, MultiAssets), Error<T>> {
+		ensure!(!transfers.is_empty(), Error::<T>::Empty);
+		ensure!(
+			transfers.len() <= MAX_BENEFICIARIES_FOR_TRANSFER,
+			Error::<T>::TooManyBeneficiaries
+		);
+		let fees: MultiAsset = fees.try_into().map_err(|()| Error::<T>::BadVersion)?;
+		let mut all_assets = MultiAssets::from(fees.clone());
+		let transfers = transfers
+			.into_iter()
+			.map(|(beneficiary, assets)| -> Result<_, Error<T>> {
+				let beneficiary: MultiLocation =
+					beneficiary.try_into().map_err(|()| Error::<T>::BadVersion)?;
+				let assets: MultiAssets = assets.try_into().map_err(|()| Error::<T>::BadVersion)?;
+				ensure!(!assets.is_none(), Error::<T>::Empty);
+				for asset in assets.inner() {
+					ensure!(asset.fun != Fungible(0), Error::<T>::Empty);
+					all_assets.push(asset.clone());
+				}
+				Ok((beneficiary, assets))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		ensure!(all_assets.len() <= MAX_ASSETS_FOR_TRANSFER, Error::<T>::TooManyAssets);
+		Ok((transfers, fees, all_assets))
+	}
+
+	fn teleport_assets_multi_program(
+		dest: MultiLocation,
+		transfers: Vec<(MultiLocation, MultiAssets)>,
+		assets: Vec<MultiAsset>,
+		mut fees: MultiAsset,
+		weight_limit: WeightLimit,
+	) -> Result<Xcm<<T as Config>::RuntimeCall>, Error<T>> {
+		let context = T::UniversalLocation::get();
+		fees.reanchor(&dest, context).map_err(|_| Error::<T>::CannotReanchor)?;
+		let max_assets = assets.len() as u32;
+		let last = transfers.len().saturating_sub(1);
+		let mut xcm_on_dest = vec![BuyExecution { fees, weight_limit }];
+		for (index, (beneficiary, mut assets)) in transfers.into_iter().enumerate() {
+			let assets = if index == last {
+				// The last beneficiary also receives what is left of the fees.
+				Wild(AllCounted(max_assets))
+			} else {
+				assets.reanchor(&dest, context).map_err(|_| Error::<T>::CannotReanchor)?;
+				Definite(assets)
+			};
+			xcm_on_dest.push(DepositAsset { assets, beneficiary });
+		}
+		Ok(Xcm(vec![
+			WithdrawAsset(assets.into()),
+			SetFeesMode { jit_withdraw: true },
+			InitiateTeleport { assets: Wild(AllCounted(max_assets)), dest, xcm: Xcm(xcm_on_dest) },
+		]))
+	}
+
 	/// Halve `fees` fungible amount.
 	pub(crate) fn halve_fees(fees: MultiAsset) -> Result<(MultiAsset, MultiAsset), Error<T>> {
 		match fees.fun {
@@ -1972,10 +2336,168 @@ impl<T: Config> Pallet<T> {
 					}
 				}
 			}
+			stage = MigrateQueries(None);
+		}
+
+		// PLACEHOLDER: the migration of the remaining items is not benchmarked. Each is weighed as
+		// the migration of a supported version, which also reads and rewrites one versioned value.
+		let item_migrate_weight = sv_migrate_weight;
+
+		if let MigrateQueries(maybe_last_raw_key) = stage {
+			let mut iter = match maybe_last_raw_key {
+				Some(k) => Queries::<T>::iter_from(k),
+				None => Queries::<T>::iter(),
+			};
+			while let Some((query_id, query)) = iter.next() {
+				if let Some(upgraded) = query.upgraded() {
+					Queries::<T>::insert(query_id, upgraded);
+				}
+				weight_used.saturating_accrue(item_migrate_weight);
+				if weight_used.any_gte(weight_cutoff) {
+					let last = Some(iter.last_raw_key().into());
+					return (weight_used, Some(MigrateQueries(last)))
+				}
+			}
+			stage = MigrateLockedFungibles(None);
+		}
+		if let MigrateLockedFungibles(maybe_last_raw_key) = stage {
+			let mut iter = match maybe_last_raw_key {
+				Some(k) => LockedFungibles::<T>::iter_from(k),
+				None => LockedFungibles::<T>::iter(),
+			};
+			while let Some((account, locks)) = iter.next() {
+				if let Some(upgraded) = Self::upgraded_locks(&locks) {
+					LockedFungibles::<T>::insert(account, upgraded);
+				}
+				weight_used.saturating_accrue(item_migrate_weight);
+				if weight_used.any_gte(weight_cutoff) {
+					let last = Some(iter.last_raw_key().into());
+					return (weight_used, Some(MigrateLockedFungibles(last)))
+				}
+			}
+			stage = MigrateRemoteLockedFungibles(None);
+		}
+		if let MigrateRemoteLockedFungibles(maybe_last_raw_key) = stage {
+			let mut iter = match maybe_last_raw_key {
+				Some(k) => RemoteLockedFungibles::<T>::iter_from(k),
+				None => RemoteLockedFungibles::<T>::iter(),
+			};
+			while let Some(((version, account, id), record)) = iter.next() {
+				if version < XCM_VERSION {
+					Self::migrate_remote_locked_fungible(version, account, id, record);
+				}
+				weight_used.saturating_accrue(item_migrate_weight);
+				if weight_used.any_gte(weight_cutoff) {
+					let last = Some(iter.last_raw_key().into());
+					return (weight_used, Some(MigrateRemoteLockedFungibles(last)))
+				}
+			}
 		}
 		(weight_used, None)
 	}
 
+	/// Moves the remote lock record of `account` on `id`, stored under the older XCM `version`,
+	/// over to the key of the latest XCM version, upgrading its locations.
+	///
+	/// The record is kept under its old key if `id` cannot be expressed in the latest XCM version,
+	/// or if a record is already stored under the new key.
+	fn migrate_remote_locked_fungible(
+		version: XcmVersion,
+		account: T::AccountId,
+		id: VersionedAssetId,
+		record: RemoteLockedFungibleRecord<
+			T::RemoteLockConsumerIdentifier,
+			T::MaxRemoteLockConsumers,
+		>,
+	) {
+		match id.clone().into_latest() {
+			Ok(new_id)
+				if !RemoteLockedFungibles::<T>::contains_key((XCM_VERSION, &account, &new_id)) =>
+			{
+				RemoteLockedFungibles::<T>::remove((version, &account, &id));
+				let record = record.upgraded().unwrap_or(record);
+				RemoteLockedFungibles::<T>::insert((XCM_VERSION, account, new_id), record);
+			},
+			_ => log::warn!(
+				target: "xcm::pallet_xcm::migration",
+				"Keeping the remote lock of {:?} on {:?} at XCM version {}",
+				account,
+				id,
+				version,
+			),
+		}
+	}
+
+	/// Returns `locks` with all locations expressed in the latest XCM version, or `None` if they
+	/// already are.
+	fn upgraded_locks(
+		locks: &BoundedVec<(BalanceOf<T>, VersionedMultiLocation), T::MaxLockers>,
+	) -> Option<BoundedVec<(BalanceOf<T>, VersionedMultiLocation), T::MaxLockers>> {
+		let mut upgraded = locks.clone();
+		upgraded.iter_mut().for_each(|(_, unlocker)| *unlocker = to_latest(unlocker));
+		(upgraded != *locks).then_some(upgraded)
+	}
+
+	/// Returns the query `query_id`, upgrading its XCM values in storage to the latest XCM version
+	/// if necessary.
+	fn upgraded_query(query_id: QueryId) -> Option<QueryStatus<BlockNumberFor<T>>> {
+		let query = Queries::<T>::get(query_id)?;
+		match query.upgraded() {
+			Some(upgraded) => {
+				Queries::<T>::insert(query_id, &upgraded);
+				Some(upgraded)
+			},
+			None => Some(query),
+		}
+	}
+
+	/// Returns the locks held on behalf of `account`, upgrading its locations in storage to the
+	/// latest XCM version if necessary.
+	fn upgraded_locked_fungibles(
+		account: &T::AccountId,
+	) -> Option<BoundedVec<(BalanceOf<T>, VersionedMultiLocation), T::MaxLockers>> {
+		let locks = LockedFungibles::<T>::get(account)?;
+		match Self::upgraded_locks(&locks) {
+			Some(upgraded) => {
+				LockedFungibles::<T>::insert(account, &upgraded);
+				Some(upgraded)
+			},
+			None => Some(locks),
+		}
+	}
+
+	/// Returns the remote lock record of `key`, moving it over from the key of an older XCM
+	/// version and upgrading its locations in storage to the latest XCM version if necessary.
+	fn upgraded_remote_locked_fungible(
+		key: &(u32, T::AccountId, VersionedAssetId),
+	) -> Option<
+		RemoteLockedFungibleRecord<T::RemoteLockConsumerIdentifier, T::MaxRemoteLockConsumers>,
+	> {
+		let (record, moved) = match RemoteLockedFungibles::<T>::get(key) {
+			Some(record) => (record, false),
+			None => {
+				let (_, account, id) = key;
+				let record = (0..XCM_VERSION).find_map(|v| {
+					let old_id = id.clone().into_version(v).ok()?;
+					RemoteLockedFungibles::<T>::take((v, account.clone(), old_id))
+				})?;
+				(record, true)
+			},
+		};
+		match record.upgraded() {
+			Some(upgraded) => {
+				RemoteLockedFungibles::<T>::insert(key, &upgraded);
+				Some(upgraded)
+			},
+			None => {
+				if moved {
+					RemoteLockedFungibles::<T>::insert(key, &record);
+				}
+				Some(record)
+			},
+		}
+	}
+
 	/// Request that `dest` informs us of its version.
 	pub fn request_version_notify(dest: impl Into<MultiLocation>) -> XcmResult {
 		let dest = dest.into();
@@ -2038,6 +2560,54 @@ impl<T: Config> Pallet<T> {
 		T::XcmRouter::deliver(ticket)
 	}
 
+	/// The assets trapped for `origin`, each with the number of times it was trapped.
+	///
+	/// Only assets trapped since [`TrappedAssets`] was introduced are listed.
+	pub fn trapped_assets(origin: MultiLocation) -> Vec<(VersionedMultiAssets, u32)> {
+		TrappedAssets::<T>::iter_prefix(VersionedMultiLocation::from(origin))
+			.map(|(hash, assets)| (assets, AssetTraps::<T>::get(hash)))
+			.collect()
+	}
+
+	/// The locations we requested version notifications from, each with the XCM version it last
+	/// advertised, if any.
+	pub fn version_subscriptions() -> Vec<(VersionedMultiLocation, Option<XcmVersion>)> {
+		VersionNotifiers::<T>::iter_key_prefix(XCM_VERSION)
+			.map(|location| {
+				let version = SupportedVersion::<T>::get(XCM_VERSION, &location);
+				(location, version)
+			})
+			.collect()
+	}
+
+	/// The locations subscribed to our version notifications, each with the XCM version we last
+	/// informed it of.
+	pub fn version_subscribers() -> Vec<(VersionedMultiLocation, XcmVersion)> {
+		VersionNotifyTargets::<T>::iter_prefix(XCM_VERSION)
+			.map(|(location, (_, _, version))| (location, version))
+			.collect()
+	}
+
+	/// The destinations whose XCM version is yet to be discovered, each with the number of sends
+	/// attempted to it, in the order of the queue.
+	pub fn version_discovery_queue() -> Vec<(VersionedMultiLocation, u32)> {
+		VersionDiscoveryQueue::<T>::get().into_inner()
+	}
+
+	/// The XCM claiming the trapped `assets` and depositing them to `beneficiary`, after aliasing
+	/// `alias` if there is one.
+	fn claim_program(
+		alias: Option<MultiLocation>,
+		assets: MultiAssets,
+		beneficiary: MultiLocation,
+	) -> Xcm<<T as Config>::RuntimeCall> {
+		let count = assets.len() as u32;
+		let mut instructions: Vec<_> = alias.map(AliasOrigin).into_iter().collect();
+		instructions.push(ClaimAsset { assets, ticket: Here.into() });
+		instructions.push(DepositAsset { assets: Wild(AllCounted(count)), beneficiary });
+		Xcm(instructions)
+	}
+
 	pub fn check_account() -> T::AccountId {
 		const ID: PalletId = PalletId(*b"py/xcmch");
 		AccountIdConversion::<T::AccountId>::into_account_truncating(&ID)
@@ -2163,7 +2733,8 @@ pub struct LockTicket<T: Config> {
 impl<T: Config> xcm_executor::traits::Enact for LockTicket<T> {
 	fn enact(self) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::UnexpectedState;
-		let mut locks = LockedFungibles::<T>::get(&self.sovereign_account).unwrap_or_default();
+		let mut locks =
+			Pallet::<T>::upgraded_locked_fungibles(&self.sovereign_account).unwrap_or_default();
 		match self.item_index {
 			Some(index) => {
 				ensure!(locks.len() > index, UnexpectedState);
@@ -2196,8 +2767,8 @@ pub struct UnlockTicket<T: Config> {
 impl<T: Config> xcm_executor::traits::Enact for UnlockTicket<T> {
 	fn enact(self) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::UnexpectedState;
-		let mut locks =
-			LockedFungibles::<T>::get(&self.sovereign_account).ok_or(UnexpectedState)?;
+		let mut locks = Pallet::<T>::upgraded_locked_fungibles(&self.sovereign_account)
+			.ok_or(UnexpectedState)?;
 		let mut maybe_remove_index = None;
 		let mut locked = BalanceOf::<T>::zero();
 		let mut found = false;
@@ -2234,7 +2805,8 @@ pub struct ReduceTicket<T: Config> {
 impl<T: Config> xcm_executor::traits::Enact for ReduceTicket<T> {
 	fn enact(self) -> Result<(), xcm_executor::traits::LockError> {
 		use xcm_executor::traits::LockError::UnexpectedState;
-		let mut record = RemoteLockedFungibles::<T>::get(&self.key).ok_or(UnexpectedState)?;
+		let mut record =
+			Pallet::<T>::upgraded_remote_locked_fungible(&self.key).ok_or(UnexpectedState)?;
 		ensure!(self.locker == record.locker && self.owner == record.owner, UnexpectedState);
 		let new_amount = record.amount.checked_sub(self.amount).ok_or(UnexpectedState)?;
 		ensure!(record.amount_held().map_or(true, |h| new_amount >= h), UnexpectedState);
@@ -2262,7 +2834,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		let sovereign_account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
 		let amount = T::CurrencyMatcher::matches_fungible(&asset).ok_or(UnknownAsset)?;
 		ensure!(T::Currency::free_balance(&sovereign_account) >= amount, AssetNotOwned);
-		let locks = LockedFungibles::<T>::get(&sovereign_account).unwrap_or_default();
+		let locks = Self::upgraded_locked_fungibles(&sovereign_account).unwrap_or_default();
 		let item_index = locks.iter().position(|x| x.1.try_as::<_>() == Ok(&unlocker));
 		ensure!(item_index.is_some() || locks.len() < T::MaxLockers::get() as usize, NoResources);
 		Ok(LockTicket { sovereign_account, amount, unlocker, item_index })
@@ -2277,7 +2849,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		let sovereign_account = T::SovereignAccountOf::convert_location(&owner).ok_or(BadOwner)?;
 		let amount = T::CurrencyMatcher::matches_fungible(&asset).ok_or(UnknownAsset)?;
 		ensure!(T::Currency::free_balance(&sovereign_account) >= amount, AssetNotOwned);
-		let locks = LockedFungibles::<T>::get(&sovereign_account).unwrap_or_default();
+		let locks = Self::upgraded_locked_fungibles(&sovereign_account).unwrap_or_default();
 		let item_index =
 			locks.iter().position(|x| x.1.try_as::<_>() == Ok(&unlocker)).ok_or(NotLocked)?;
 		ensure!(locks[item_index].0 >= amount, NotLocked);
@@ -2303,7 +2875,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		let key = (XCM_VERSION, account, id);
 		let mut record =
 			RemoteLockedFungibleRecord { amount, owner, locker, consumers: BoundedVec::default() };
-		if let Some(old) = RemoteLockedFungibles::<T>::get(&key) {
+		if let Some(old) = Self::upgraded_remote_locked_fungible(&key) {
 			// Make sure that the new record wouldn't clobber any old data.
 			ensure!(old.locker == record.locker && old.owner == record.owner, WouldClobber);
 			record.consumers = old.consumers;
@@ -2330,7 +2902,7 @@ impl<T: Config> xcm_executor::traits::AssetLock for Pallet<T> {
 		let id: VersionedAssetId = asset.id.into();
 		let key = (XCM_VERSION, sovereign_account, id);
 
-		let record = RemoteLockedFungibles::<T>::get(&key).ok_or(NotLocked)?;
+		let record = Self::upgraded_remote_locked_fungible(&key).ok_or(NotLocked)?;
 		// Make sure that the record contains what we expect and there's enough to unlock.
 		ensure!(locker == record.locker && owner == record.owner, WouldClobber);
 		ensure!(record.amount >= amount, NotEnoughLocked);
@@ -2420,6 +2992,7 @@ impl<T: Config> DropAssets for Pallet<T> {
 		let versioned = VersionedMultiAssets::from(MultiAssets::from(assets));
 		let hash = BlakeTwo256::hash_of(&(&origin, &versioned));
 		AssetTraps::<T>::mutate(hash, |n| *n += 1);
+		TrappedAssets::<T>::insert(VersionedMultiLocation::from(*origin), hash, &versioned);
 		Self::deposit_event(Event::AssetsTrapped { hash, origin: *origin, assets: versioned });
 		// TODO #3735: Put the real weight in there.
 		Weight::zero()
@@ -2446,7 +3019,10 @@ impl<T: Config> ClaimAssets for Pallet<T> {
 		let hash = BlakeTwo256::hash_of(&(origin, versioned.clone()));
 		match AssetTraps::<T>::get(hash) {
 			0 => return false,
-			1 => AssetTraps::<T>::remove(hash),
+			1 => {
+				AssetTraps::<T>::remove(hash);
+				TrappedAssets::<T>::remove(VersionedMultiLocation::from(*origin), hash);
+			},
 			n => AssetTraps::<T>::insert(hash, n - 1),
 		}
 		Self::deposit_event(Event::AssetsClaimed { hash, origin: *origin, assets: versioned });
@@ -2483,7 +3059,7 @@ impl<T: Config> OnResponse for Pallet<T> {
 		_context: &XcmContext,
 	) -> Weight {
 		let origin = *origin;
-		match (response, Queries::<T>::get(query_id)) {
+		match (response, Self::upgraded_query(query_id)) {
 			(
 				Response::Version(v),
 				Some(QueryStatus::VersionNotifier { origin: expected_origin, is_active }),
@@ -2643,6 +3219,51 @@ impl<T: Config> CheckSuspension for Pallet<T> {
 	}
 }
 
+/// The topics of the last `Window` messages noted by the executor, e.g. for
+/// [`xcm_builder::DenyDuplicateTopics`], kept in a ring buffer in the storage of the pallet.
+///
+/// A topic is noted along with the origin of its message, and it is only found again for the same
+/// origin.
+///
+/// Noting a topic when the buffer is full evicts the oldest one. If `Window` shrinks, the topics
+/// noted beyond the new window are only evicted once `Window` grows back; a `Window` of zero notes
+/// nothing.
+///
+/// Noting a topic weighs [`WeightInfo::note_processed_topic`], which the executor adds to the
+/// weight of every message with a topic.
+pub struct RecentTopicsWindow<T, Window>(PhantomData<(T, Window)>);
+impl<T, Window> RecentTopicsWindow<T, Window> {
+	/// The key under which `topic` is recorded for `origin`.
+	fn key(origin: &MultiLocation, topic: &XcmHash) -> XcmHash {
+		(origin, topic).using_encoded(sp_io::hashing::blake2_256)
+	}
+}
+
+impl<T: Config, Window: Get<u32>> ProcessedTopics for RecentTopicsWindow<T, Window> {
+	fn contains(origin: &MultiLocation, topic: &XcmHash) -> bool {
+		RecentTopicPositions::<T>::contains_key(Self::key(origin, topic))
+	}
+
+	fn note(origin: &MultiLocation, topic: XcmHash) {
+		let window = Window::get();
+		let key = Self::key(origin, &topic);
+		if window.is_zero() || RecentTopicPositions::<T>::contains_key(key) {
+			return
+		}
+		let position = NextTopicPosition::<T>::get() % window;
+		if let Some(evicted) = RecentTopics::<T>::get(position) {
+			RecentTopicPositions::<T>::remove(evicted);
+		}
+		RecentTopics::<T>::insert(position, key);
+		RecentTopicPositions::<T>::insert(key, position);
+		NextTopicPosition::<T>::put((position + 1) % window);
+	}
+
+	fn note_weight() -> Weight {
+		T::WeightInfo::note_processed_topic()
+	}
+}
+
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
 ///
 /// Returns `Ok` with the location of the XCM sender or an `Err` otherwise.
@@ -2763,3 +3384,27 @@ impl<RuntimeOrigin: From<crate::Origin>> ConvertOrigin<RuntimeOrigin>
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API listing the assets trapped by the XCM executor, which can be claimed with
+	/// [`Call::claim_trapped_assets`].
+	pub trait TrappedAssetsApi {
+		/// The assets trapped for `origin`, each with the number of times it was trapped.
+		///
+		/// Assets trapped before the trapped assets were indexed by origin are not listed.
+		fn trapped_assets(origin: VersionedMultiLocation) -> Vec<(VersionedMultiAssets, u32)>;
+	}
+
+	/// API exposing the XCM version negotiation with other locations, e.g. to diagnose messages
+	/// sent with an unexpected XCM version.
+	pub trait XcmVersionApi {
+		/// The locations we subscribed to for version notifications, with their advertised version.
+		fn version_subscriptions() -> Vec<(VersionedMultiLocation, Option<XcmVersion>)>;
+		/// The locations subscribed to our version notifications, with the version they were last
+		/// informed of.
+		fn version_subscribers() -> Vec<(VersionedMultiLocation, XcmVersion)>;
+		/// The destinations whose version is yet to be discovered, with the number of sends
+		/// attempted to them.
+		fn version_discovery_queue() -> Vec<(VersionedMultiLocation, u32)>;
+	}
+}
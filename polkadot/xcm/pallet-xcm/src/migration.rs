@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Config, Pallet, VersionNotifyTargets};
+use crate::{Config, MigratedXcmVersion, Pallet, VersionNotifyTargets};
 use frame_support::{
 	pallet_prelude::*,
 	traits::{OnRuntimeUpgrade, StorageVersion},
@@ -73,3 +73,28 @@ pub mod v1 {
 		<T as frame_system::Config>::DbWeight,
 	>;
 }
+
+/// Initialises the XCM version the versioned XCM values in storage were last migrated to, on
+/// chains which had this pallet before it was tracked.
+///
+/// The storage of these chains is expected to be at the XCM version of the runtime, as its
+/// migration was started by the previous upgrades. Does nothing once the version is initialised,
+/// so it never restarts an ongoing migration.
+pub struct InitMigratedXcmVersion<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for InitMigratedXcmVersion<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if MigratedXcmVersion::<T>::exists() {
+			log::warn!("skipping the migrated XCM version initialisation, should be removed");
+			return T::DbWeight::get().reads(1)
+		}
+
+		MigratedXcmVersion::<T>::put(xcm::latest::VERSION);
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		ensure!(MigratedXcmVersion::<T>::exists(), "the migrated XCM version must be initialised");
+		Ok(())
+	}
+}
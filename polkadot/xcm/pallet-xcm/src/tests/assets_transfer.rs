@@ -117,6 +117,86 @@ fn limited_teleport_assets_works() {
 	);
 }
 
+/// Test `teleport_assets_multi`
+///
+/// Asserts that the assets of all transfers and the fees are teleported with a single message,
+/// which deposits the assets of each transfer to its beneficiary.
+#[test]
+fn teleport_assets_multi_works() {
+	let bob: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+	let charlie: MultiLocation = AccountId32 { network: None, id: [2u8; 32] }.into();
+	let balances = vec![
+		(ALICE, INITIAL_BALANCE),
+		(ParaId::from(OTHER_PARA_ID).into_account_truncating(), INITIAL_BALANCE),
+	];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let weight = BaseXcmWeight::get() * 3;
+		assert_ok!(XcmPallet::teleport_assets_multi(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(RelayLocation::get().into()),
+			vec![
+				(bob.into(), (Here, SEND_AMOUNT).into()),
+				(charlie.into(), (Here, 2 * SEND_AMOUNT).into()),
+			],
+			Box::new((Here, FEE_AMOUNT).into()),
+			Unlimited,
+		));
+		let withdrawn = 3 * SEND_AMOUNT + FEE_AMOUNT;
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - withdrawn);
+		assert_eq!(
+			sent_xcm(),
+			vec![(
+				RelayLocation::get().into(),
+				Xcm(vec![
+					ReceiveTeleportedAsset((Here, withdrawn).into()),
+					ClearOrigin,
+					buy_limited_execution((Here, FEE_AMOUNT), Unlimited),
+					DepositAsset {
+						assets: Definite((Here, SEND_AMOUNT).into()),
+						beneficiary: bob
+					},
+					DepositAsset { assets: AllCounted(1).into(), beneficiary: charlie },
+				]),
+			)]
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::Attempted { outcome: Outcome::Complete(weight) })
+		);
+	});
+}
+
+/// `teleport_assets_multi` should fail without transfers or for too many distinct assets
+#[test]
+fn teleport_assets_multi_checks_transfers() {
+	let bob: MultiLocation = AccountId32 { network: None, id: BOB.into() }.into();
+	new_test_ext_with_balances(vec![(ALICE, INITIAL_BALANCE)]).execute_with(|| {
+		let teleport = |transfers: Vec<(VersionedMultiLocation, VersionedMultiAssets)>| {
+			XcmPallet::teleport_assets_multi(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(RelayLocation::get().into()),
+				transfers,
+				Box::new((Here, FEE_AMOUNT).into()),
+				Unlimited,
+			)
+		};
+		assert_eq!(teleport(vec![]), Err(crate::Error::<Test>::Empty.into()));
+		assert_eq!(
+			teleport(vec![(bob.into(), (Here, 0).into())]),
+			Err(crate::Error::<Test>::Empty.into())
+		);
+		let other_assets: MultiAssets =
+			vec![(Parent, SEND_AMOUNT).into(), ((Parent, GeneralIndex(1)), SEND_AMOUNT).into()]
+				.into();
+		assert_eq!(
+			teleport(vec![(bob.into(), other_assets.into())]),
+			Err(crate::Error::<Test>::TooManyAssets.into())
+		);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
 /// `limited_teleport_assets` should fail for filtered assets
 #[test]
 fn limited_teleport_filtered_assets_disallowed() {
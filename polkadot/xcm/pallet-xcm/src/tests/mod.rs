@@ -19,21 +19,23 @@
 mod assets_transfer;
 
 use crate::{
-	mock::*, AssetTraps, CurrentMigration, Error, LatestVersionedMultiLocation, Queries,
-	QueryStatus, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
+	mock::*, AssetTraps, CurrentMigration, Error, LatestVersionedMultiLocation, LockedFungibles,
+	MigratedXcmVersion, Queries, QueryStatus, RecentTopicsWindow, RemoteLockedFungibleRecord,
+	RemoteLockedFungibles, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
 	VersionNotifyTargets,
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{Currency, Hooks},
+	traits::{ConstU32, Currency, Hooks},
 	weights::Weight,
+	BoundedVec,
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
 use xcm::{latest::QueryResponseInfo, prelude::*};
-use xcm_builder::AllowKnownQueryResponses;
+use xcm_builder::{AllowKnownQueryResponses, ProvideWeightHints, StorageWeightHints, WeightHints};
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{ProcessedTopics, Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
 	XcmExecutor,
 };
 
@@ -459,6 +461,94 @@ fn trapped_assets_can_be_claimed() {
 	});
 }
 
+#[test]
+fn trapped_assets_can_be_claimed_with_helper() {
+	let balances = vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let source: MultiLocation =
+			Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let dest: MultiLocation = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+		let beneficiary = Box::new(VersionedMultiLocation::from(dest));
+
+		assert_noop!(
+			XcmPallet::claim_trapped_assets(
+				RuntimeOrigin::signed(ALICE),
+				None,
+				beneficiary.clone()
+			),
+			Error::<Test>::NoTrappedAssets
+		);
+
+		assert_ok!(XcmPallet::execute(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(VersionedXcm::from(Xcm(vec![
+				WithdrawAsset((Here, SEND_AMOUNT).into()),
+				buy_execution((Here, SEND_AMOUNT)),
+				SetErrorHandler(Xcm(vec![ClearError])),
+				Trap(0),
+			]))),
+			BaseXcmWeight::get() * 4
+		));
+		let vma = VersionedMultiAssets::from(MultiAssets::from((Here, SEND_AMOUNT)));
+		assert_eq!(XcmPallet::trapped_assets(source), vec![(vma, 1)]);
+		// Nothing is trapped for other origins.
+		assert!(XcmPallet::trapped_assets(dest).is_empty());
+
+		assert_ok!(XcmPallet::claim_trapped_assets(
+			RuntimeOrigin::signed(ALICE),
+			None,
+			beneficiary.clone()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::Attempted {
+				outcome: Outcome::Complete(BaseXcmWeight::get() * 2)
+			})
+		);
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+		assert_eq!(Balances::total_balance(&BOB), INITIAL_BALANCE + SEND_AMOUNT);
+		assert!(XcmPallet::trapped_assets(source).is_empty());
+		assert_eq!(AssetTraps::<Test>::iter().collect::<Vec<_>>(), vec![]);
+
+		assert_noop!(
+			XcmPallet::claim_trapped_assets(RuntimeOrigin::signed(ALICE), None, beneficiary),
+			Error::<Test>::NoTrappedAssets
+		);
+	});
+}
+
+/// Test that the recent topics window only remembers the last topics noted, per origin.
+#[test]
+fn recent_topics_window_evicts_oldest_topics() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		type Topics = RecentTopicsWindow<Test, ConstU32<2>>;
+		let origin: MultiLocation = Parent.into();
+		Topics::note(&origin, [1; 32]);
+		Topics::note(&origin, [2; 32]);
+		assert!(Topics::contains(&origin, &[1; 32]));
+		assert!(Topics::contains(&origin, &[2; 32]));
+
+		// Topics are only found again for the same origin.
+		assert!(!Topics::contains(&Parachain(1000).into(), &[1; 32]));
+
+		// Noting a topic again doesn't refresh it.
+		Topics::note(&origin, [1; 32]);
+		Topics::note(&origin, [3; 32]);
+		assert!(!Topics::contains(&origin, &[1; 32]));
+		assert!(Topics::contains(&origin, &[2; 32]));
+		assert!(Topics::contains(&origin, &[3; 32]));
+
+		Topics::note(&Parachain(1000).into(), [2; 32]);
+		assert!(!Topics::contains(&origin, &[2; 32]));
+		assert!(Topics::contains(&origin, &[3; 32]));
+		assert!(Topics::contains(&Parachain(1000).into(), &[2; 32]));
+
+		// An empty window notes nothing.
+		RecentTopicsWindow::<Test, ConstU32<0>>::note(&origin, [5; 32]);
+		assert!(!Topics::contains(&origin, &[5; 32]));
+	});
+}
+
 /// Test failure to complete execution reverts intermediate side-effects.
 ///
 /// XCM program will withdraw and deposit some assets, then fail execution of a further withdraw.
@@ -924,6 +1014,184 @@ fn auto_subscription_works() {
 	})
 }
 
+#[test]
+fn version_negotiation_state_is_exposed() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		AdvertisedXcmVersion::set(3);
+		let notifier: MultiLocation = Parachain(1000).into();
+		let subscriber: MultiLocation = Parachain(1001).into();
+		let unknown: MultiLocation = Parachain(1002).into();
+
+		// We subscribe to `notifier`, whose version is unknown until it responds.
+		assert_ok!(XcmPallet::force_subscribe_version_notify(
+			RuntimeOrigin::root(),
+			Box::new(notifier.into()),
+		));
+		assert_eq!(XcmPallet::version_subscriptions(), vec![(notifier.into(), None)]);
+		let weight = BaseXcmWeight::get();
+		let message = Xcm(vec![QueryResponse {
+			query_id: 0,
+			max_weight: Weight::zero(),
+			response: Response::Version(2),
+			querier: None,
+		}]);
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<XcmConfig>::execute_xcm(notifier, message, hash, weight);
+		assert_eq!(r, Outcome::Complete(weight));
+		assert_eq!(XcmPallet::version_subscriptions(), vec![(notifier.into(), Some(2))]);
+
+		// `subscriber` subscribes to us.
+		assert!(XcmPallet::version_subscribers().is_empty());
+		let message =
+			Xcm(vec![SubscribeVersion { query_id: 0, max_response_weight: Weight::zero() }]);
+		let hash = fake_message_hash(&message);
+		let r = XcmExecutor::<XcmConfig>::execute_xcm(subscriber, message, hash, weight);
+		assert_eq!(r, Outcome::Complete(weight));
+		assert_eq!(XcmPallet::version_subscribers(), vec![(subscriber.into(), 3)]);
+
+		// Sending to `unknown` queues the discovery of its version.
+		assert_ok!(XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), Some(2)));
+		let msg = xcm::v2::Xcm::<()>(vec![xcm::v2::Instruction::Trap(0)]);
+		assert_ok!(XcmPallet::wrap_version(&unknown, msg.clone()));
+		assert_ok!(XcmPallet::wrap_version(&unknown, msg));
+		assert_eq!(XcmPallet::version_discovery_queue(), vec![(unknown.into(), 2)]);
+	})
+}
+
+#[test]
+fn versioned_values_are_upgraded_on_access_and_in_background() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let v2_location = VersionedMultiLocation::V2(xcm::v2::Junction::Parachain(1000).into());
+		let v3_location = Parachain(1000).into_versioned();
+		let pending = |responder| QueryStatus::Pending {
+			responder,
+			maybe_match_querier: None,
+			maybe_notify: None,
+			timeout: 100,
+		};
+		let remote_lock = |location: &VersionedMultiLocation| RemoteLockedFungibleRecord {
+			amount: 10,
+			owner: location.clone(),
+			locker: location.clone(),
+			consumers: BoundedVec::default(),
+		};
+		let asset_id: VersionedAssetId = AssetId::Concrete(Here.into()).into();
+
+		// Entries from a previous runtime with v2 XCM.
+		Queries::<Test>::insert(0, pending(v2_location.clone()));
+		Queries::<Test>::insert(1, pending(v2_location.clone()));
+		let locks = BoundedVec::truncate_from(vec![(10, v2_location.clone())]);
+		LockedFungibles::<Test>::insert(ALICE, locks);
+		let v2_remote_lock = remote_lock(&v2_location);
+		RemoteLockedFungibles::<Test>::insert((2, ALICE, asset_id.clone()), v2_remote_lock);
+		// A record clashing with one at the latest XCM version is kept as is.
+		let v2_remote_lock = remote_lock(&v2_location);
+		RemoteLockedFungibles::<Test>::insert((2, BOB, asset_id.clone()), v2_remote_lock);
+		let v3_remote_lock = remote_lock(&v3_location);
+		RemoteLockedFungibles::<Test>::insert((XCM_VERSION, BOB, asset_id.clone()), v3_remote_lock);
+
+		// Queries are upgraded on access.
+		assert_eq!(XcmPallet::take_response(0), QueryResponseStatus::Pending { timeout: 100 });
+		assert_eq!(Queries::<Test>::get(0), Some(pending(v3_location.clone())));
+		assert_eq!(Queries::<Test>::get(1), Some(pending(v2_location.clone())));
+
+		// A runtime upgrade to a runtime with the same XCM version does not start a migration.
+		assert_eq!(MigratedXcmVersion::<Test>::get(), Some(XCM_VERSION));
+		<XcmPallet as Hooks<_>>::on_runtime_upgrade();
+		assert!(CurrentMigration::<Test>::get().is_none());
+
+		// Neither a chain with an uninitialised version nor an ongoing migration are migrated.
+		MigratedXcmVersion::<Test>::kill();
+		<XcmPallet as Hooks<_>>::on_runtime_upgrade();
+		assert!(CurrentMigration::<Test>::get().is_none());
+		MigratedXcmVersion::<Test>::put(2);
+		let ongoing = VersionMigrationStage::MigrateLockedFungibles(None);
+		CurrentMigration::<Test>::put(ongoing.clone());
+		<XcmPallet as Hooks<_>>::on_runtime_upgrade();
+		assert!(CurrentMigration::<Test>::get() == Some(ongoing));
+		assert_eq!(MigratedXcmVersion::<Test>::get(), Some(2));
+		CurrentMigration::<Test>::kill();
+
+		// A runtime upgrade to a new XCM version migrates the remaining entries in the background.
+		<XcmPallet as Hooks<_>>::on_runtime_upgrade();
+		assert_eq!(MigratedXcmVersion::<Test>::get(), Some(XCM_VERSION));
+		assert!(CurrentMigration::<Test>::get().is_some());
+		XcmPallet::on_initialize(2);
+		assert!(CurrentMigration::<Test>::get().is_none());
+
+		assert_eq!(Queries::<Test>::get(1), Some(pending(v3_location.clone())));
+		assert_eq!(
+			LockedFungibles::<Test>::get(ALICE).unwrap().into_inner(),
+			vec![(10, v3_location.clone())]
+		);
+		let mut remote_locks = RemoteLockedFungibles::<Test>::iter().collect::<Vec<_>>();
+		remote_locks.sort_by_key(|((version, account, _), _)| (*version, account.clone()));
+		assert_eq!(
+			remote_locks,
+			vec![
+				((2, BOB, asset_id.clone()), remote_lock(&v2_location)),
+				((XCM_VERSION, ALICE, asset_id.clone()), remote_lock(&v3_location)),
+				((XCM_VERSION, BOB, asset_id), remote_lock(&v3_location)),
+			]
+		);
+	});
+}
+
+#[test]
+fn migrated_xcm_version_is_initialised_once() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+		type InitMigratedXcmVersion = crate::migration::InitMigratedXcmVersion<Test>;
+
+		MigratedXcmVersion::<Test>::kill();
+		InitMigratedXcmVersion::on_runtime_upgrade();
+		assert_eq!(MigratedXcmVersion::<Test>::get(), Some(XCM_VERSION));
+
+		// An initialised version is left as is.
+		MigratedXcmVersion::<Test>::put(2);
+		InitMigratedXcmVersion::on_runtime_upgrade();
+		assert_eq!(MigratedXcmVersion::<Test>::get(), Some(2));
+		assert!(CurrentMigration::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn set_weight_hints_works() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let location: MultiLocation = Parachain(OTHER_PARA_ID).into();
+		let hints = WeightHints {
+			calls: vec![((4, 2), Weight::from_parts(1000, 100))],
+			..Default::default()
+		};
+
+		assert_noop!(
+			XcmPallet::set_weight_hints(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(location.into()),
+				Some(hints.clone())
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(XcmPallet::set_weight_hints(
+			RuntimeOrigin::root(),
+			Box::new(location.into()),
+			Some(hints.clone())
+		));
+		assert_eq!(StorageWeightHints::weight_hints(&location), Some(hints.clone()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::XcmPallet(crate::Event::WeightHintsSet { location, hints: Some(hints) })
+		);
+
+		assert_ok!(XcmPallet::set_weight_hints(
+			RuntimeOrigin::root(),
+			Box::new(location.into()),
+			None
+		));
+		assert_eq!(StorageWeightHints::weight_hints(&location), None);
+	});
+}
+
 #[test]
 fn subscription_side_upgrades_work_with_multistage_notify() {
 	new_test_ext_with_balances(vec![]).execute_with(|| {
@@ -24,7 +24,7 @@ use frame_support::{
 use polkadot_parachain_primitives::primitives::IsSystem;
 use sp_std::{cell::Cell, marker::PhantomData, ops::ControlFlow, result::Result};
 use xcm::prelude::*;
-use xcm_executor::traits::{CheckSuspension, OnResponse, Properties, ShouldExecute};
+use xcm_executor::traits::{CheckSuspension, OnResponse, ProcessedTopics, Properties, ShouldExecute};
 
 /// Execution barrier that just takes `max_weight` from `properties.weight_credit`.
 ///
@@ -263,6 +263,45 @@ where
 	}
 }
 
+/// Barrier condition that rejects messages whose topic was already processed for the same origin
+/// according to `Topics`, e.g. because a buggy sender sent them twice.
+///
+/// The topic of a message is its trailing `SetTopic`, or its `message_id` if that was already
+/// taken from the message, e.g. by [`TrailingSetTopicAsId`]. Messages without a topic are left to
+/// `Inner`.
+///
+/// This barrier doesn't note topics itself: `Topics` should be the executor's `ProcessedTopics`,
+/// which notes the topic once the message executed.
+pub struct DenyDuplicateTopics<Inner, Topics>(PhantomData<(Inner, Topics)>);
+impl<Inner, Topics> ShouldExecute for DenyDuplicateTopics<Inner, Topics>
+where
+	Inner: ShouldExecute,
+	Topics: ProcessedTopics,
+{
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<Call>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let topic = match instructions.last() {
+			Some(SetTopic(topic)) => Some(*topic),
+			_ => properties.message_id,
+		};
+		if let Some(topic) = topic {
+			if Topics::contains(origin, &topic) {
+				log::debug!(
+					target: "xcm::barriers",
+					"DenyDuplicateTopics rejected message from {:?} with processed topic {:?}",
+					origin, topic,
+				);
+				return Err(ProcessMessageError::Unsupported)
+			}
+		}
+		Inner::should_execute(origin, instructions, max_weight, properties)
+	}
+}
+
 /// Allows execution from any origin that is contained in `T` (i.e. `T::Contains(origin)`).
 ///
 /// Use only for executions from completely trusted origins, from which no permissionless messages
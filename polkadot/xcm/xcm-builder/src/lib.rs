@@ -56,13 +56,13 @@ pub use asset_conversion::{ConvertedAbstractAssetId, ConvertedConcreteAssetId};
 mod barriers;
 pub use barriers::{
 	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, DenyReserveTransferToRelayChain,
-	DenyThenTry, IsChildSystemParachain, RespectSuspension, TakeWeightCredit, TrailingSetTopicAsId,
-	WithComputedOrigin,
+	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, DenyDuplicateTopics,
+	DenyReserveTransferToRelayChain, DenyThenTry, IsChildSystemParachain, RespectSuspension,
+	TakeWeightCredit, TrailingSetTopicAsId, WithComputedOrigin,
 };
 
 mod process_xcm_message;
-pub use process_xcm_message::ProcessXcmMessage;
+pub use process_xcm_message::{DescribeXcmMessage, ProcessXcmMessage};
 
 mod currency_adapter;
 pub use currency_adapter::CurrencyAdapter;
@@ -88,6 +88,12 @@ pub use weight::{
 	FixedRateOfFungible, FixedWeightBounds, TakeRevenue, UsingComponents, WeightInfoBounds,
 };
 
+mod weight_hints;
+pub use weight_hints::{
+	HintedTransactWeights, HintedWeigher, ProvideWeightHints, StorageWeightHints, WeightHints,
+	WEIGHT_HINTS_PREFIX,
+};
+
 mod matches_location;
 pub use matches_location::{StartsWith, StartsWithExplicitGlobalConsensus};
 
@@ -18,9 +18,9 @@
 
 use frame_support::{
 	ensure,
-	traits::{ProcessMessage, ProcessMessageError},
+	traits::{DescribeMessage, Get, ProcessMessage, ProcessMessageError},
 };
-use parity_scale_codec::{Decode, FullCodec, MaxEncodedLen};
+use parity_scale_codec::{Decode, DecodeLimit, FullCodec, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::{fmt::Debug, marker::PhantomData};
 use sp_weights::{Weight, WeightMeter};
@@ -66,6 +66,23 @@ impl<
 	}
 }
 
+/// Describes an XCM message by its first `MaxInstructions` instructions.
+///
+/// The `Transact` calls within the summary are kept in their encoded form.
+pub struct DescribeXcmMessage<MaxInstructions>(PhantomData<MaxInstructions>);
+impl<MaxInstructions: Get<u32>> DescribeMessage for DescribeXcmMessage<MaxInstructions> {
+	type Summary = VersionedXcm<()>;
+
+	fn describe(message: &[u8]) -> Option<Self::Summary> {
+		let versioned_message =
+			VersionedXcm::<()>::decode_with_depth_limit(xcm::MAX_XCM_DECODE_DEPTH, &mut &message[..])
+				.ok()?;
+		let mut message = Xcm::<()>::try_from(versioned_message).ok()?;
+		message.inner_mut().truncate(MaxInstructions::get() as usize);
+		Some(message.into())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -128,6 +145,26 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn describe_message_works() {
+		type Describer = DescribeXcmMessage<frame_support::traits::ConstU32<1>>;
+
+		let msg = VersionedXcm::V3(v3::Xcm::<RuntimeCall>(vec![
+			v3::Instruction::ClearOrigin,
+			v3::Instruction::Trap(1),
+		]));
+		assert_eq!(
+			Describer::describe(&msg.encode()),
+			Some(VersionedXcm::V3(v3::Xcm::<()>(vec![v3::Instruction::ClearOrigin])))
+		);
+		// Older versions are described in the latest version.
+		assert_eq!(
+			Describer::describe(&v3_xcm(true).encode()),
+			Some(VersionedXcm::V3(v3::Xcm::<()>(vec![v3::Instruction::ClearOrigin])))
+		);
+		assert_eq!(Describer::describe(&[55, 66]), None);
+	}
+
 	fn v2_xcm(success: bool) -> VersionedXcm<RuntimeCall> {
 		let instr = if success {
 			v3::Instruction::<RuntimeCall>::ClearOrigin
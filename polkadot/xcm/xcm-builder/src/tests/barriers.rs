@@ -309,3 +309,93 @@ fn suspension_should_work() {
 	);
 	assert_eq!(r, Ok(()));
 }
+
+#[test]
+fn deny_duplicate_topics_should_work() {
+	type Barrier = DenyDuplicateTopics<
+		AllowUnpaidExecutionFrom<IsInVec<AllowUnpaidFrom>>,
+		TestProcessedTopics,
+	>;
+
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	Processed::set(vec![(Parent.into(), [1; 32])]);
+	let message = |topic: Option<XcmHash>| {
+		let mut instructions =
+			vec![TransferAsset { assets: (Parent, 100).into(), beneficiary: Here.into() }];
+		instructions.extend(topic.map(SetTopic));
+		Xcm::<()>(instructions)
+	};
+	let should_execute = |origin: MultiLocation, mut message: Xcm<()>| {
+		Barrier::should_execute(
+			&origin,
+			message.inner_mut(),
+			Weight::from_parts(10, 10),
+			&mut props(Weight::zero()),
+		)
+	};
+
+	assert_eq!(
+		should_execute(Parent.into(), message(Some([1; 32]))),
+		Err(ProcessMessageError::Unsupported)
+	);
+	assert_eq!(should_execute(Parent.into(), message(Some([2; 32]))), Ok(()));
+	// The barrier leaves noting the topic to the executor.
+	assert_eq!(should_execute(Parent.into(), message(Some([2; 32]))), Ok(()));
+	assert_eq!(Processed::get(), vec![(Parent.into(), [1; 32])]);
+
+	// Messages without a topic can't be told apart.
+	assert_eq!(should_execute(Parent.into(), message(None)), Ok(()));
+
+	// Topics are processed per origin.
+	AllowUnpaidFrom::set(vec![Parent.into(), Parachain(1).into()]);
+	assert_eq!(should_execute(Parachain(1).into(), message(Some([1; 32]))), Ok(()));
+
+	// The topic may have been taken from the message already.
+	let mut message = message(None);
+	let mut properties = Properties { weight_credit: Weight::zero(), message_id: Some([1; 32]) };
+	assert_eq!(
+		Barrier::should_execute(
+			&Parent.into(),
+			message.inner_mut(),
+			Weight::from_parts(10, 10),
+			&mut properties,
+		),
+		Err(ProcessMessageError::Unsupported)
+	);
+}
+
+#[test]
+fn executor_notes_topics_of_executed_messages() {
+	AllowUnpaidFrom::set(vec![Parent.into()]);
+	NoteTopicWeight::set(Weight::from_parts(5, 5));
+	add_asset(Parent, (Parent, 100u128));
+	let message = |amount: u128, topic: XcmHash| {
+		Xcm::<TestCall>(vec![
+			TransferAsset { assets: (Parent, amount).into(), beneficiary: Parachain(1).into() },
+			SetTopic(topic),
+		])
+	};
+
+	// Noting the topic is part of the weight of the message.
+	let mut hash = [0; 32];
+	let r = XcmExecutor::<TestConfig>::prepare_and_execute(
+		Parent,
+		message(10, [1; 32]),
+		&mut hash,
+		Weight::from_parts(50, 50),
+		Weight::zero(),
+	);
+	assert_eq!(r, Outcome::Complete(Weight::from_parts(25, 25)));
+	assert_eq!(Processed::get(), vec![(Parent.into(), [1; 32])]);
+
+	// The topic of a message that failed is not noted.
+	let r = XcmExecutor::<TestConfig>::prepare_and_execute(
+		Parent,
+		message(1_000, [2; 32]),
+		&mut hash,
+		Weight::from_parts(50, 50),
+		Weight::zero(),
+	);
+	assert_eq!(r, Outcome::Incomplete(Weight::from_parts(25, 25), XcmError::NotWithdrawable));
+	assert_eq!(Processed::get(), vec![(Parent.into(), [1; 32])]);
+}
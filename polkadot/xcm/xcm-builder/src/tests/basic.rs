@@ -104,3 +104,46 @@ fn code_registers_should_work() {
 	assert_eq!(asset_list(Here), vec![(Here, 1u128).into()]);
 	assert_eq!(sent_xcm(), vec![]);
 }
+
+#[test]
+fn executed_instructions_are_traced() {
+	AllowUnpaidFrom::set(vec![Here.into()]);
+	add_asset(Here, (Here, 10u128));
+	let message = Xcm(vec![
+		WithdrawAsset((Here, 5u128).into()),
+		ClearOrigin,
+		// Fails since the origin was cleared.
+		WithdrawAsset((Here, 1u128).into()),
+	]);
+	let hash = fake_message_hash(&message);
+	let limit = Weight::from_parts(30, 30);
+	RecordXcmTrace::take();
+
+	let r = XcmExecutor::<TestConfig>::execute_xcm(Here, message, hash, limit);
+	assert_eq!(r, Outcome::Incomplete(limit, XcmError::BadOrigin));
+
+	let withdrawn: Assets = MultiAsset::from((Here, 5u128)).into();
+	let trace = |index, instruction, holding_added, origin, result| InstructionTrace {
+		origin,
+		index,
+		instruction,
+		holding_added,
+		holding_removed: Assets::new(),
+		weight_used: Weight::from_parts(10, 10),
+		result,
+	};
+	assert_eq!(
+		RecordXcmTrace::take(),
+		vec![
+			trace(0, WithdrawAsset((Here, 5u128).into()), withdrawn, Some(Here.into()), Ok(())),
+			trace(1, ClearOrigin, Assets::new(), Some(Here.into()), Ok(())),
+			trace(
+				2,
+				WithdrawAsset((Here, 1u128).into()),
+				Assets::new(),
+				None,
+				Err(XcmError::BadOrigin)
+			),
+		]
+	);
+}
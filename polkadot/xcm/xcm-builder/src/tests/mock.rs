@@ -22,8 +22,8 @@ use crate::{
 };
 pub use crate::{
 	AliasForeignAccountId32, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
-	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, FixedRateOfFungible,
-	FixedWeightBounds, TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, DenyDuplicateTopics,
+	FixedRateOfFungible, FixedWeightBounds, TakeWeightCredit,
 };
 use frame_support::traits::{ContainsPair, Everything};
 pub use frame_support::{
@@ -47,7 +47,8 @@ use xcm_executor::traits::{Properties, QueryHandler, QueryResponseStatus};
 pub use xcm_executor::{
 	traits::{
 		AssetExchange, AssetLock, CheckSuspension, ConvertOrigin, Enact, ExportXcm, FeeManager,
-		FeeReason, LockError, OnResponse, TransactAsset,
+		FeeReason, InstructionTrace, LockError, OnResponse, ProcessedTopics, RecordXcmTrace,
+		TransactAsset,
 	},
 	Assets, Config,
 };
@@ -504,6 +505,24 @@ impl TestSuspender {
 	}
 }
 
+parameter_types! {
+	pub static Processed: Vec<(MultiLocation, XcmHash)> = vec![];
+	pub static NoteTopicWeight: Weight = Weight::zero();
+}
+
+pub struct TestProcessedTopics;
+impl ProcessedTopics for TestProcessedTopics {
+	fn contains(origin: &MultiLocation, topic: &XcmHash) -> bool {
+		Processed::get().contains(&(*origin, *topic))
+	}
+	fn note(origin: &MultiLocation, topic: XcmHash) {
+		Processed::mutate(|p| p.push((*origin, topic)));
+	}
+	fn note_weight() -> Weight {
+		NoteTopicWeight::get()
+	}
+}
+
 pub type TestBarrier = (
 	TakeWeightCredit,
 	AllowKnownQueryResponses<TestResponseHandler>,
@@ -744,6 +763,8 @@ impl Config for TestConfig {
 	type CallDispatcher = TestCall;
 	type SafeCallFilter = Everything;
 	type Aliasers = AliasForeignAccountId32<SiblingPrefix>;
+	type Tracer = RecordXcmTrace;
+	type ProcessedTopics = TestProcessedTopics;
 }
 
 pub fn fungible_multi_asset(location: MultiLocation, amount: u128) -> MultiAsset {
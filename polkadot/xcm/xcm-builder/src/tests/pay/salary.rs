@@ -93,6 +93,7 @@ fn set_rank(who: AccountId, rank: u128) {
 parameter_types! {
 	pub const RegistrationPeriod: BlockNumber = 2;
 	pub const PayoutPeriod: BlockNumber = 2;
+	pub const ExtensionPeriod: BlockNumber = 1;
 	pub const FixedSalaryAmount: Balance = 10 * UNITS;
 	pub static Budget: Balance = FixedSalaryAmount::get();
 }
@@ -107,11 +108,15 @@ impl GetSalary<Rank, AccountId, Balance> for FixedSalary {
 impl pallet_salary::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = ();
 	type Paymaster = SalaryPayOverXcm;
+	type DefaultAssetKind = ();
+	type PayoutAssetOrigin = frame_system::EnsureRoot<AccountId>;
 	type Members = TestClub;
 	type Salary = FixedSalary;
 	type RegistrationPeriod = RegistrationPeriod;
 	type PayoutPeriod = PayoutPeriod;
+	type ExtensionPeriod = ExtensionPeriod;
 	type Budget = Budget;
 }
 
@@ -204,3 +204,120 @@ fn weight_trader_tuple_should_work() {
 	// and no refund
 	assert_eq!(traders.refund_weight(Weight::from_parts(2, 2), &ctx), None);
 }
+
+#[test]
+fn hinted_weigher_uses_published_weight_hints() {
+	use xcm_executor::traits::UniversalWeigher;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let dest: MultiLocation = Parachain(1000).into();
+		let other: MultiLocation = Parachain(1001).into();
+		let hints = WeightHints {
+			default: Weight::from_parts(10, 1),
+			instructions: vec![(
+				Instruction::<()>::ClearOrigin.using_encoded(|e| e[0]),
+				Weight::from_parts(5, 0),
+			)],
+			calls: vec![((4, 2), Weight::from_parts(1000, 100))],
+		};
+		StorageWeightHints::set(&dest, Some(&hints));
+		assert_eq!(StorageWeightHints::weight_hints(&dest), Some(hints));
+		assert_eq!(StorageWeightHints::weight_hints(&other), None);
+
+		let transact = |call: Vec<u8>| Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most: Weight::from_parts(1, 1),
+			call: call.into(),
+		};
+		let mut message = Xcm(vec![
+			ClearOrigin,
+			transact(vec![4, 2, 0]),
+			SetAppendix(Xcm(vec![transact(vec![4, 3])])),
+		]);
+		type Weigher = HintedWeigher<StorageWeightHints, ()>;
+		// 5 for `ClearOrigin`, 10 + 1000 for the hinted `Transact`, 10 for `SetAppendix` and 10 + 1
+		// for the unhinted `Transact`.
+		assert_eq!(Weigher::weigh(dest, message.clone()), Ok(Weight::from_parts(1036, 104)));
+		// Destinations without hints are weighed by the fallback.
+		assert_eq!(Weigher::weigh(other, message.clone()), Err(()));
+
+		assert_eq!(Weigher::hint_transact_weights(&other, &mut message), 0);
+		assert_eq!(Weigher::hint_transact_weights(&dest, &mut message), 1);
+		assert_eq!(
+			message.0[1],
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::from_parts(1000, 100),
+				call: vec![4, 2, 0].into(),
+			}
+		);
+
+		StorageWeightHints::set(&dest, None);
+		assert_eq!(StorageWeightHints::weight_hints(&dest), None);
+	});
+}
+
+#[test]
+fn hinted_transact_weights_hint_sent_messages() {
+	parameter_types! {
+		pub const LookupWeight: Weight = Weight::from_parts(7, 7);
+	}
+	type Weigher = HintedTransactWeights<
+		StorageWeightHints,
+		FixedWeightBounds<UnitWeightCost, (), MaxInstructions>,
+		LookupWeight,
+	>;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let dest: MultiLocation = (Parent, Parachain(1000)).into();
+		let hints = WeightHints {
+			calls: vec![((4, 2), Weight::from_parts(1000, 100))],
+			..Default::default()
+		};
+		StorageWeightHints::set(&dest, Some(&hints));
+
+		let transact = |require_weight_at_most| Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most,
+			call: vec![4, 2, 0].into(),
+		};
+		let mut message = Xcm::<()>(vec![
+			WithdrawAsset((Parent, 100u128).into()),
+			SetAppendix(Xcm(vec![InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: dest,
+				xcm: Xcm(vec![transact(Weight::from_parts(1, 1))]),
+			}])),
+			InitiateTeleport {
+				assets: All.into(),
+				dest: Parachain(1001).into(),
+				xcm: Xcm(vec![transact(Weight::from_parts(1, 1))]),
+			},
+			DepositReserveAsset {
+				assets: All.into(),
+				dest: Parachain(1002).into(),
+				xcm: Xcm(vec![ClearOrigin]),
+			},
+		]);
+
+		// 5 instructions and 2 lookups, one for each destination which is sent a `Transact`.
+		assert_eq!(Weigher::weight(&mut message), Ok(Weight::from_parts(64, 64)));
+		assert_eq!(
+			message.0[1],
+			SetAppendix(Xcm(vec![InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: dest,
+				xcm: Xcm(vec![transact(Weight::from_parts(1000, 100))]),
+			}]))
+		);
+		// Parachain 1001 didn't publish hints.
+		assert_eq!(
+			message.0[2],
+			InitiateTeleport {
+				assets: All.into(),
+				dest: Parachain(1001).into(),
+				xcm: Xcm(vec![transact(Weight::from_parts(1, 1))]),
+			}
+		);
+	});
+}
@@ -0,0 +1,229 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weighing of messages sent to destinations which publish weight hints for their instructions.
+
+use frame_support::{storage::unhashed, traits::Get};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::{marker::PhantomData, vec::Vec};
+use xcm::latest::{prelude::*, Weight};
+use xcm_executor::traits::{UniversalWeigher, WeightBounds};
+
+/// The prefix of the well-known storage keys under which the weight hints of destinations are
+/// kept, e.g. by a relayer or subscription which bridges the hints published by the destination.
+pub const WEIGHT_HINTS_PREFIX: &[u8] = b":xcm_weight_hints:";
+
+/// The weights a destination published for executing its instructions.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, Default, TypeInfo)]
+pub struct WeightHints {
+	/// The weight of an instruction without a more specific hint.
+	pub default: Weight,
+	/// The weight of instructions, keyed by the index of their variant in the encoding of the
+	/// latest `Instruction`.
+	pub instructions: Vec<(u8, Weight)>,
+	/// The weight of the calls dispatched by `Transact`, keyed by their pallet and call index.
+	pub calls: Vec<((u8, u8), Weight)>,
+}
+
+impl WeightHints {
+	/// The hinted weight of executing `instruction`, excluding any call it dispatches or message it
+	/// contains.
+	pub fn instruction_weight(&self, instruction: &Instruction<()>) -> Weight {
+		let index = instruction.using_encoded(|encoded| encoded[0]);
+		self.instructions
+			.iter()
+			.find_map(|(i, weight)| (*i == index).then_some(*weight))
+			.unwrap_or(self.default)
+	}
+
+	/// The hinted weight of dispatching the encoded `call`, if any.
+	pub fn call_weight(&self, call: &[u8]) -> Option<Weight> {
+		let index = (*call.get(0)?, *call.get(1)?);
+		self.calls.iter().find_map(|(i, weight)| (*i == index).then_some(*weight))
+	}
+}
+
+/// A source of the weight hints of destinations.
+pub trait ProvideWeightHints {
+	/// The weight hints `dest` published, if any are known.
+	fn weight_hints(dest: &MultiLocation) -> Option<WeightHints>;
+}
+
+impl ProvideWeightHints for () {
+	fn weight_hints(_: &MultiLocation) -> Option<WeightHints> {
+		None
+	}
+}
+
+/// Weight hints kept in storage under the well-known key of each destination.
+pub struct StorageWeightHints;
+impl StorageWeightHints {
+	/// The well-known storage key of the weight hints of `dest`.
+	pub fn key(dest: &MultiLocation) -> Vec<u8> {
+		let mut key = WEIGHT_HINTS_PREFIX.to_vec();
+		key.extend(sp_io::hashing::blake2_256(&VersionedMultiLocation::from(*dest).encode()));
+		key
+	}
+
+	/// Set the weight hints of `dest`, or remove them if `None`.
+	pub fn set(dest: &MultiLocation, hints: Option<&WeightHints>) {
+		match hints {
+			Some(hints) => unhashed::put(&Self::key(dest), hints),
+			None => unhashed::kill(&Self::key(dest)),
+		}
+	}
+}
+
+impl ProvideWeightHints for StorageWeightHints {
+	fn weight_hints(dest: &MultiLocation) -> Option<WeightHints> {
+		unhashed::get(&Self::key(dest))
+	}
+}
+
+/// A [`UniversalWeigher`] which weighs messages to destinations which published weight hints
+/// according to these, and falls back to `Fallback` for all other destinations.
+///
+/// With hints, a `Transact` weighs as its hinted instruction weight plus the hinted weight of its
+/// call, or its `require_weight_at_most` if the call has no hint.
+pub struct HintedWeigher<Hints, Fallback>(PhantomData<(Hints, Fallback)>);
+
+impl<Hints: ProvideWeightHints, Fallback: UniversalWeigher> HintedWeigher<Hints, Fallback> {
+	/// Set the `require_weight_at_most` of each `Transact` in `message` to the hinted weight of its
+	/// call, where `dest` published one.
+	///
+	/// Returns the number of `Transact` instructions which were updated.
+	pub fn hint_transact_weights(dest: &MultiLocation, message: &mut Xcm<()>) -> u32 {
+		match Hints::weight_hints(dest) {
+			Some(hints) => Self::set_transact_weights(&hints, message),
+			None => 0,
+		}
+	}
+
+	fn set_transact_weights(hints: &WeightHints, message: &mut Xcm<()>) -> u32 {
+		let mut updated = 0;
+		for instruction in message.0.iter_mut() {
+			match instruction {
+				Transact { require_weight_at_most, call, .. } => {
+					if let Some(weight) = hints.call_weight(&call.clone().into_encoded()) {
+						*require_weight_at_most = weight;
+						updated += 1;
+					}
+				},
+				SetErrorHandler(xcm) | SetAppendix(xcm) =>
+					updated += Self::set_transact_weights(hints, xcm),
+				_ => {},
+			}
+		}
+		updated
+	}
+
+	fn weigh_with_hints(hints: &WeightHints, message: &Xcm<()>) -> Result<Weight, ()> {
+		let mut weight = Weight::zero();
+		for instruction in message.0.iter() {
+			let inner = match instruction {
+				Transact { require_weight_at_most, call, .. } => hints
+					.call_weight(&call.clone().into_encoded())
+					.unwrap_or(*require_weight_at_most),
+				SetErrorHandler(xcm) | SetAppendix(xcm) => Self::weigh_with_hints(hints, xcm)?,
+				_ => Weight::zero(),
+			};
+			weight = weight
+				.checked_add(&hints.instruction_weight(instruction))
+				.and_then(|w| w.checked_add(&inner))
+				.ok_or(())?;
+		}
+		Ok(weight)
+	}
+}
+
+impl<Hints: ProvideWeightHints, Fallback: UniversalWeigher> UniversalWeigher
+	for HintedWeigher<Hints, Fallback>
+{
+	fn weigh(dest: impl Into<MultiLocation>, message: Xcm<()>) -> Result<Weight, ()> {
+		let dest = dest.into();
+		match Hints::weight_hints(&dest) {
+			Some(hints) => Self::weigh_with_hints(&hints, &message),
+			None => Fallback::weigh(dest, message),
+		}
+	}
+}
+
+/// A [`WeightBounds`] which weighs messages with `Inner`, after setting the
+/// `require_weight_at_most` of each `Transact` in the messages they send to destinations which
+/// published weight hints, see [`HintedWeigher::hint_transact_weights`].
+///
+/// Looking up the hints of a destination adds `LookupWeight` to the weight of the message.
+pub struct HintedTransactWeights<Hints, Inner, LookupWeight>(
+	PhantomData<(Hints, Inner, LookupWeight)>,
+);
+
+impl<Hints: ProvideWeightHints, Inner, LookupWeight>
+	HintedTransactWeights<Hints, Inner, LookupWeight>
+{
+	/// Whether `message` contains a `Transact`.
+	fn sends_transact(message: &Xcm<()>) -> bool {
+		message.0.iter().any(|instruction| match instruction {
+			Transact { .. } => true,
+			SetErrorHandler(xcm) | SetAppendix(xcm) => Self::sends_transact(xcm),
+			_ => false,
+		})
+	}
+
+	/// Hint the `Transact` weights of the messages sent by `instructions`.
+	///
+	/// Returns the number of destinations whose hints were looked up.
+	fn hint_sent_messages<Call>(instructions: &mut [Instruction<Call>]) -> u64 {
+		let mut lookups = 0u64;
+		for instruction in instructions.iter_mut() {
+			match instruction {
+				DepositReserveAsset { dest, xcm, .. } |
+				InitiateReserveWithdraw { reserve: dest, xcm, .. } |
+				InitiateTeleport { dest, xcm, .. } |
+				TransferReserveAsset { dest, xcm, .. }
+					if Self::sends_transact(xcm) =>
+				{
+					HintedWeigher::<Hints, ()>::hint_transact_weights(dest, xcm);
+					lookups = lookups.saturating_add(1);
+				},
+				SetErrorHandler(xcm) | SetAppendix(xcm) =>
+					lookups = lookups.saturating_add(Self::hint_sent_messages(xcm.inner_mut())),
+				_ => {},
+			}
+		}
+		lookups
+	}
+}
+
+impl<Hints, Inner, LookupWeight, Call> WeightBounds<Call>
+	for HintedTransactWeights<Hints, Inner, LookupWeight>
+where
+	Hints: ProvideWeightHints,
+	Inner: WeightBounds<Call>,
+	LookupWeight: Get<Weight>,
+{
+	fn weight(message: &mut Xcm<Call>) -> Result<Weight, ()> {
+		let lookups = Self::hint_sent_messages(message.inner_mut());
+		Inner::weight(message)?
+			.checked_add(&LookupWeight::get().saturating_mul(lookups))
+			.ok_or(())
+	}
+
+	fn instr_weight(instruction: &Instruction<Call>) -> Result<Weight, ()> {
+		Inner::instr_weight(instruction)
+	}
+}
+
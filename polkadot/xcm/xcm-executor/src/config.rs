@@ -16,8 +16,8 @@
 
 use crate::traits::{
 	AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin, DropAssets, ExportXcm,
-	FeeManager, OnResponse, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds,
-	WeightTrader,
+	FeeManager, OnResponse, ProcessedTopics, ShouldExecute, TraceXcm, TransactAsset,
+	VersionChangeNotifier, WeightBounds, WeightTrader,
 };
 use frame_support::{
 	dispatch::{GetDispatchInfo, Parameter, PostDispatchInfo},
@@ -111,4 +111,16 @@ pub trait Config {
 	/// Use this type to explicitly whitelist calls that cannot undergo recursion. This is a
 	/// temporary measure until we properly account for proof size weights for XCM instructions.
 	type SafeCallFilter: Contains<Self::RuntimeCall>;
+
+	/// The tracer which records every executed instruction.
+	///
+	/// Use `()` unless instructions should be traced, e.g. for debugging.
+	type Tracer: TraceXcm;
+
+	/// The record of processed topics.
+	///
+	/// The topic of a message is noted once the message executed without error, so that e.g.
+	/// `DenyDuplicateTopics` can reject it if it is sent again. Use `()` if topics should not be
+	/// recorded.
+	type ProcessedTopics: ProcessedTopics;
 }
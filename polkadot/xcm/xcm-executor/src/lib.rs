@@ -31,8 +31,9 @@ use xcm::latest::prelude::*;
 pub mod traits;
 use traits::{
 	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
-	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, OnResponse, Properties, ShouldExecute,
-	TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader, XcmAssetTransfers,
+	DropAssets, Enact, ExportXcm, FeeManager, FeeReason, InstructionTrace, OnResponse, Properties,
+	ShouldExecute, TraceXcm, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
+	XcmAssetTransfers,
 };
 
 mod assets;
@@ -186,7 +187,15 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 		mut message: Xcm<Config::RuntimeCall>,
 	) -> Result<Self::Prepared, Xcm<Config::RuntimeCall>> {
 		match Config::Weigher::weight(&mut message) {
-			Ok(weight) => Ok(WeighedMessage(weight, message)),
+			Ok(weight) => {
+				// The topic is noted once the message executed, see `execute`.
+				let weight = match message.0.last() {
+					Some(SetTopic(_)) =>
+						weight.saturating_add(Config::ProcessedTopics::note_weight()),
+					_ => weight,
+				};
+				Ok(WeighedMessage(weight, message))
+			},
 			Err(_) => Err(message),
 		}
 	}
@@ -204,6 +213,10 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			message,
 			weight_credit,
 		);
+		let topic = match message.0.last() {
+			Some(SetTopic(topic)) => Some(*topic),
+			_ => None,
+		};
 		let mut properties = Properties { weight_credit, message_id: None };
 		if let Err(e) = Config::Barrier::should_execute(
 			&origin,
@@ -222,6 +235,7 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			return Outcome::Error(XcmError::Barrier)
 		}
 
+		let topic = topic.or(properties.message_id);
 		*id = properties.message_id.unwrap_or(*id);
 
 		let mut vm = Self::new(origin, *id);
@@ -239,7 +253,11 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			}
 		}
 
-		vm.post_process(xcm_weight)
+		let outcome = vm.post_process(xcm_weight);
+		if let (Outcome::Complete(_), Some(topic)) = (&outcome, topic) {
+			Config::ProcessedTopics::note(&origin, topic);
+		}
+		outcome
 	}
 
 	fn charge_fees(origin: impl Into<MultiLocation>, fees: MultiAssets) -> XcmResult {
@@ -320,6 +338,15 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		for (i, instr) in xcm.0.into_iter().enumerate() {
 			match &mut result {
 				r @ Ok(()) => {
+					// Only capture the state needed for the trace if somebody is listening.
+					let trace = Config::Tracer::is_active().then(|| {
+						let weight =
+							Config::Weigher::instr_weight(&instr).unwrap_or(Weight::zero());
+						let instruction = Instruction::<()>::from(instr.clone());
+						(instruction, self.origin_ref().cloned(), self.holding.clone(), weight)
+					});
+					let surplus_before = self.total_surplus;
+
 					// Initialize the recursion count only the first time we hit this code in our
 					// potential recursive execution.
 					let inst_res = recursion_count::using_once(&mut 1, || {
@@ -343,6 +370,19 @@ impl<Config: config::Config> XcmExecutor<Config> {
 
 						self.process_instruction(instr)
 					});
+					if let Some((instruction, origin, holding, weight)) = trace {
+						let surplus = self.total_surplus.saturating_sub(surplus_before);
+						let trace = InstructionTrace {
+							origin,
+							index: i as u32,
+							instruction,
+							holding_added: Assets::new(),
+							holding_removed: Assets::new(),
+							weight_used: weight.saturating_sub(surplus),
+							result: inst_res.clone(),
+						};
+						Config::Tracer::trace(trace.with_holding_delta(&holding, &self.holding));
+					}
 					if let Err(e) = inst_res {
 						log::trace!(target: "xcm::execute", "!!! ERROR: {:?}", e);
 						*r = Err(ExecutorError {
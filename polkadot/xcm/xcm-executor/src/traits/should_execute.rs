@@ -111,3 +111,31 @@ impl CheckSuspension for Tuple {
 		false
 	}
 }
+
+/// A record of the topics of the messages that were processed, e.g. to detect messages that were
+/// sent twice.
+///
+/// Topics are recorded per origin, so that an origin can't get the messages of another origin
+/// rejected by using their topics first.
+pub trait ProcessedTopics {
+	/// Whether a message from `origin` with `topic` was processed recently.
+	fn contains(origin: &MultiLocation, topic: &XcmHash) -> bool;
+
+	/// Note that a message from `origin` with `topic` was processed.
+	fn note(origin: &MultiLocation, topic: XcmHash);
+
+	/// The weight of [`Self::note`].
+	fn note_weight() -> Weight;
+}
+
+impl ProcessedTopics for () {
+	fn contains(_origin: &MultiLocation, _topic: &XcmHash) -> bool {
+		false
+	}
+
+	fn note(_origin: &MultiLocation, _topic: XcmHash) {}
+
+	fn note_weight() -> Weight {
+		Weight::zero()
+	}
+}
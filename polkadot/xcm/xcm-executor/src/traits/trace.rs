@@ -0,0 +1,122 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::Assets;
+use xcm::latest::{Error as XcmError, Instruction, MultiAsset, MultiLocation, Weight};
+
+/// The record of a single instruction executed by the `XcmExecutor`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct InstructionTrace {
+	/// The origin of the message at the time the instruction was executed.
+	pub origin: Option<MultiLocation>,
+	/// The index of the instruction within the message.
+	pub index: u32,
+	/// The executed instruction, with any call to `Transact` left encoded.
+	pub instruction: Instruction<()>,
+	/// The assets added to the Holding Register by the instruction.
+	pub holding_added: Assets,
+	/// The assets removed from the Holding Register by the instruction.
+	pub holding_removed: Assets,
+	/// The weight consumed by the instruction, i.e. its weight less any surplus it reported.
+	pub weight_used: Weight,
+	/// The result of executing the instruction.
+	pub result: Result<(), XcmError>,
+}
+
+impl InstructionTrace {
+	/// Set `holding_added` and `holding_removed` to the difference between `before` and `after`.
+	pub fn with_holding_delta(mut self, before: &Assets, after: &Assets) -> Self {
+		let (added, removed) = (&mut self.holding_added, &mut self.holding_removed);
+		for (id, &amount) in after.fungible.iter() {
+			let previous = before.fungible.get(id).copied().unwrap_or_default();
+			if amount > previous {
+				added.subsume(MultiAsset { id: *id, fun: (amount - previous).into() });
+			}
+		}
+		for (id, &previous) in before.fungible.iter() {
+			let amount = after.fungible.get(id).copied().unwrap_or_default();
+			if previous > amount {
+				removed.subsume(MultiAsset { id: *id, fun: (previous - amount).into() });
+			}
+		}
+		for (id, instance) in after.non_fungible.difference(&before.non_fungible) {
+			added.subsume(MultiAsset { id: *id, fun: (*instance).into() });
+		}
+		for (id, instance) in before.non_fungible.difference(&after.non_fungible) {
+			removed.subsume(MultiAsset { id: *id, fun: (*instance).into() });
+		}
+		self
+	}
+}
+
+/// Receives a trace of every instruction executed by the `XcmExecutor`.
+pub trait TraceXcm {
+	/// Whether instructions should be traced at all.
+	///
+	/// Tracing is skipped entirely while this returns `false`, so that it does not cost anything.
+	fn is_active() -> bool;
+
+	/// Record the execution of an instruction.
+	fn trace(trace: InstructionTrace);
+}
+
+impl TraceXcm for () {
+	fn is_active() -> bool {
+		false
+	}
+
+	fn trace(_trace: InstructionTrace) {}
+}
+
+/// Keeps the most recently executed instructions in memory, so that they can be inspected with
+/// [`RecordXcmTrace::take`], e.g. by tests.
+///
+/// Only records when compiled with `std`; it does nothing in a Wasm runtime.
+pub struct RecordXcmTrace;
+
+impl RecordXcmTrace {
+	/// The maximum number of instructions kept; older instructions are dropped first.
+	pub const MAX_INSTRUCTIONS: usize = 1024;
+
+	/// Returns and clears the recorded instructions, the oldest first.
+	#[cfg(feature = "std")]
+	pub fn take() -> Vec<InstructionTrace> {
+		RECORDED_TRACE.with(|t| t.take().into())
+	}
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+	static RECORDED_TRACE: core::cell::RefCell<std::collections::VecDeque<InstructionTrace>> =
+		Default::default();
+}
+
+impl TraceXcm for RecordXcmTrace {
+	fn is_active() -> bool {
+		cfg!(feature = "std")
+	}
+
+	fn trace(_trace: InstructionTrace) {
+		#[cfg(feature = "std")]
+		RECORDED_TRACE.with(|t| {
+			let mut t = t.borrow_mut();
+			if t.len() >= Self::MAX_INSTRUCTIONS {
+				t.pop_front();
+			}
+			t.push_back(_trace);
+		});
+	}
+}
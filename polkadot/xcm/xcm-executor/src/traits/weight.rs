@@ -36,6 +36,13 @@ pub trait UniversalWeigher {
 	fn weigh(dest: impl Into<MultiLocation>, message: Xcm<()>) -> Result<Weight, ()>;
 }
 
+/// Weighing of no message is supported.
+impl UniversalWeigher for () {
+	fn weigh(_: impl Into<MultiLocation>, _: Xcm<()>) -> Result<Weight, ()> {
+		Err(())
+	}
+}
+
 /// Charge for weight in order to execute XCM.
 ///
 /// A `WeightTrader` may also be put into a tuple, in which case the default behavior of
@@ -214,6 +214,8 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
 		task_manager: &mut task_manager,
 		transaction_pool: transaction_pool.clone(),
 		rpc_builder: rpc_extensions_builder,
+		transaction_estimator: None,
+		health_checks: Vec::new(),
 		backend,
 		system_rpc_tx,
 		tx_handler_controller,
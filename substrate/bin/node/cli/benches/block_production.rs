@@ -93,6 +93,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		tracing_targets: None,
 		tracing_receiver: Default::default(),
 		max_runtime_instances: 8,
+		min_runtime_instances: 1,
 		runtime_cache_size: 2,
 		announce_block: true,
 		data_path: base_path.path().into(),
@@ -101,7 +102,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		wasm_runtime_overrides: None,
 	};
 
-	node_cli::service::new_full_base(config, None, false, |_, _| ())
+	node_cli::service::new_full_base(config, None, Default::default(), false, |_, _| ())
 		.expect("creating a full node doesn't fail")
 }
 
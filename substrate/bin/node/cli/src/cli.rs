@@ -44,6 +44,10 @@ pub struct Cli {
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub statement_store: sc_statement_store::StatementStoreParams,
 }
 
 /// Possible subcommands of the main binary.
@@ -79,12 +83,19 @@ pub enum Subcommand {
 	/// Sign a message, with a given (secret) key.
 	Sign(sc_cli::SignCmd),
 
+	/// Check the feasibility of an election solution before submitting it.
+	CheckSolution(substrate_frame_cli::CheckSolutionCmd),
+
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
+	/// Debugging utilities.
+	#[command(subcommand)]
+	Debug(sc_cli::DebugSubcommand),
+
 	/// Export blocks.
 	ExportBlocks(sc_cli::ExportBlocksCmd),
 
@@ -26,7 +26,8 @@ use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
 use frame_system_rpc_runtime_api::AccountNonceApi;
 use futures::prelude::*;
 use kitchensink_runtime::RuntimeApi;
-use node_primitives::Block;
+use node_primitives::{Balance, Block};
+use pallet_transaction_payment_rpc::TransactionPaymentEstimator;
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 use sc_network::{event::Event, NetworkEventStream, NetworkService};
@@ -71,6 +72,24 @@ pub type TransactionPool = sc_transaction_pool::FullPool<Block, FullClient>;
 /// imported and generated.
 const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
 
+/// The size quotas of the persistent offchain storage used by the offchain workers, so that no
+/// single offchain worker can exhaust the offchain database.
+fn offchain_storage_quotas() -> Vec<sc_offchain::StorageQuota> {
+	const MIB: u64 = 1024 * 1024;
+	vec![
+		sc_offchain::StorageQuota {
+			namespace: b"parity/im-online-heartbeat/".to_vec(),
+			max_bytes: MIB,
+		},
+		sc_offchain::StorageQuota {
+			namespace: b"parity/multi-phase-unsigned-election".to_vec(),
+			max_bytes: 16 * MIB,
+		},
+		// Everything else.
+		sc_offchain::StorageQuota { namespace: Vec::new(), max_bytes: 256 * MIB },
+	]
+}
+
 /// Fetch the nonce of the given `account` from the chain state.
 ///
 /// Note: Should only be used for tests.
@@ -152,6 +171,7 @@ pub fn create_extrinsic(
 pub fn new_partial(
 	config: &Configuration,
 	mixnet_config: Option<&sc_mixnet::Config>,
+	statement_store_options: sc_statement_store::Options,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -257,7 +277,7 @@ pub fn new_partial(
 
 	let statement_store = sc_statement_store::Store::new_shared(
 		&config.data_path,
-		Default::default(),
+		statement_store_options,
 		client.clone(),
 		keystore_container.local_keystore(),
 		config.prometheus_registry(),
@@ -356,6 +376,7 @@ pub struct NewFullBase {
 pub fn new_full_base(
 	config: Configuration,
 	mixnet_config: Option<sc_mixnet::Config>,
+	statement_store_options: sc_statement_store::Options,
 	disable_hardware_benchmarks: bool,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
@@ -379,7 +400,7 @@ pub fn new_full_base(
 		transaction_pool,
 		other:
 			(rpc_builder, import_setup, rpc_setup, mut telemetry, statement_store, mixnet_api_backend),
-	} = new_partial(&config, mixnet_config.as_ref())?;
+	} = new_partial(&config, mixnet_config.as_ref(), statement_store_options)?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
@@ -458,6 +479,10 @@ pub fn new_full_base(
 		keystore: keystore_container.keystore(),
 		network: network.clone(),
 		rpc_builder: Box::new(rpc_builder),
+		transaction_estimator: Some(Arc::new(TransactionPaymentEstimator::<_, Balance>::new(
+			client.clone(),
+		))),
+		health_checks: Vec::new(),
 		transaction_pool: transaction_pool.clone(),
 		task_manager: &mut task_manager,
 		system_rpc_tx,
@@ -650,7 +675,9 @@ pub fn new_full_base(
 			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
 				runtime_api_provider: client.clone(),
 				keystore: Some(keystore_container.keystore()),
-				offchain_db: backend.offchain_storage(),
+				offchain_db: backend
+					.offchain_storage()
+					.map(|db| sc_offchain::QuotaStorage::new(db, offchain_storage_quotas())),
 				transaction_pool: Some(OffchainTransactionPoolFactory::new(
 					transaction_pool.clone(),
 				)),
@@ -681,8 +708,14 @@ pub fn new_full_base(
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let mixnet_config = cli.mixnet_params.config(config.role.is_authority());
 	let database_source = config.database.clone();
-	let task_manager = new_full_base(config, mixnet_config, cli.no_hardware_benchmarks, |_, _| ())
-		.map(|NewFullBase { task_manager, .. }| task_manager)?;
+	let task_manager = new_full_base(
+		config,
+		mixnet_config,
+		cli.statement_store.options(),
+		cli.no_hardware_benchmarks,
+		|_, _| (),
+	)
+	.map(|NewFullBase { task_manager, .. }| task_manager)?;
 
 	sc_storage_monitor::StorageMonitorService::try_spawn(
 		cli.storage_monitor,
@@ -760,6 +793,7 @@ mod tests {
 					new_full_base(
 						config,
 						None,
+						Default::default(),
 						false,
 						|block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						 babe_link: &sc_consensus_babe::BabeLink<Block>| {
@@ -935,7 +969,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, sync, transaction_pool, .. } =
-					new_full_base(config, None, false, |_, _| ())?;
+					new_full_base(config, None, Default::default(), false, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(
 					task_manager,
 					client,
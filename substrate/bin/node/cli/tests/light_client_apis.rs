@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conformance of the runtime APIs used by light clients.
+//!
+//! Light clients, like smoldot, execute runtime APIs against a storage proof fetched from a full
+//! node, and only provide the host functions of [`sp_io::SubstrateHostFunctions`]. Each test
+//! executes a runtime API like a full node and like a light client, and checks that both agree.
+//! A runtime API which reads storage that is not part of the proof of its execution, or calls a
+//! host function that light clients do not provide, fails on the light client.
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::DispatchClass;
+use kitchensink_runtime::{CheckedExtrinsic, Runtime, RuntimeCall};
+use node_primitives::{Balance, Nonce};
+use node_testing::keyring::*;
+use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use sc_executor::WasmExecutor;
+use sp_core::{
+	storage::well_known_keys,
+	traits::{RuntimeCode, WrappedRuntimeCode},
+	OpaqueMetadata,
+};
+use sp_runtime::traits::{BlakeTwo256, Zero};
+use sp_state_machine::{execution_proof_check, prove_execution, Backend};
+
+pub mod common;
+use self::common::{sign, *};
+
+/// Executes `method` of the runtime in the state of `t`, once like a full node and once like a
+/// light client, and returns the result after checking that both agree.
+fn call_as_light_client(t: &TestExternalities<BlakeTwo256>, method: &str, data: &[u8]) -> Vec<u8> {
+	let mut backend = t.as_backend();
+	let root = *backend.root();
+	let code = backend.storage(well_known_keys::CODE).unwrap().expect("genesis sets the code");
+	let heap_pages = backend.storage(well_known_keys::HEAP_PAGES).unwrap();
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.as_slice().into()),
+		hash: sp_core::blake2_256(&code).to_vec(),
+		heap_pages: heap_pages.and_then(|hp| Decode::decode(&mut &hp[..]).ok()),
+	};
+	sp_tracing::try_init_simple();
+
+	let (full, proof) = prove_execution(
+		&mut backend,
+		&mut Default::default(),
+		&executor(),
+		method,
+		data,
+		&runtime_code,
+	)
+	.unwrap_or_else(|e| panic!("`{method}` failed on the full node: {e}"));
+
+	// Missing host functions are only an error once they are called, as with light clients.
+	let light_client_executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+		.with_allow_missing_host_functions(true)
+		.build();
+	let light = execution_proof_check::<BlakeTwo256, _>(
+		root,
+		proof,
+		&mut Default::default(),
+		&light_client_executor,
+		method,
+		data,
+		&runtime_code,
+	)
+	.unwrap_or_else(|e| panic!("`{method}` failed on the light client: {e}"));
+
+	assert_eq!(full, light, "`{method}` returned different results on the light client");
+	full
+}
+
+fn new_account_info(nonce: Nonce, free: Balance) -> Vec<u8> {
+	frame_system::AccountInfo {
+		nonce,
+		consumers: 0,
+		providers: 1,
+		sufficients: 0,
+		data: (free, 0 as Balance, 0 as Balance, 1u128 << 127),
+	}
+	.encode()
+}
+
+#[test]
+fn metadata_is_light_client_compatible() {
+	let t = new_test_ext(compact_code_unwrap());
+
+	let metadata = call_as_light_client(&t, "Metadata_metadata", &[]);
+	let metadata = OpaqueMetadata::decode(&mut &metadata[..]).unwrap();
+	assert!(!metadata.is_empty());
+
+	let versions = call_as_light_client(&t, "Metadata_metadata_versions", &[]);
+	assert!(Vec::<u32>::decode(&mut &versions[..]).unwrap().contains(&15));
+}
+
+#[test]
+fn account_nonce_is_light_client_compatible() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.insert(<frame_system::Account<Runtime>>::hashed_key_for(alice()), new_account_info(5, 100));
+
+	let nonce = call_as_light_client(&t, "AccountNonceApi_account_nonce", &alice().encode());
+	assert_eq!(Nonce::decode(&mut &nonce[..]).unwrap(), 5);
+
+	let nonce = call_as_light_client(&t, "AccountNonceApi_account_nonce", &bob().encode());
+	assert_eq!(Nonce::decode(&mut &nonce[..]).unwrap(), 0);
+}
+
+#[test]
+fn transaction_payment_is_light_client_compatible() {
+	let t = new_test_ext(compact_code_unwrap());
+	let xt = sign(CheckedExtrinsic {
+		signed: Some((alice(), signed_extra(0, 0))),
+		function: RuntimeCall::Balances(default_transfer_call()),
+	});
+	let len = xt.encoded_size() as u32;
+
+	let info = call_as_light_client(&t, "TransactionPaymentApi_query_info", &(&xt, len).encode());
+	let info = RuntimeDispatchInfo::<Balance>::decode(&mut &info[..]).unwrap();
+	assert_eq!(info.class, DispatchClass::Normal);
+	assert!(!info.partial_fee.is_zero());
+
+	let details =
+		call_as_light_client(&t, "TransactionPaymentApi_query_fee_details", &(&xt, len).encode());
+	let details = FeeDetails::<Balance>::decode(&mut &details[..]).unwrap();
+	assert_eq!(details.final_fee(), info.partial_fee);
+}
@@ -27,6 +27,7 @@ use frame_support::{
 use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
 use pallet_asset_tx_payment::HandleCredit;
 use pallet_identity::legacy::IdentityField;
+use pallet_society::IdentityJudgement;
 use sp_std::prelude::*;
 
 use crate::{
@@ -77,6 +78,30 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 }
 
+/// Only lets accounts whose identity a registrar judged `KnownGood` or `Reasonable` bid for, or be
+/// vouched into, the society.
+pub struct SocietyIdentityJudgement;
+impl IdentityJudgement<AccountId> for SocietyIdentityJudgement {
+	fn has_minimum_judgement(who: &AccountId) -> bool {
+		use pallet_identity::Judgement;
+		crate::Identity::identity(who).map_or(false, |registration| {
+			registration
+				.judgements
+				.iter()
+				.any(|(_, j)| matches!(j, Judgement::KnownGood | Judgement::Reasonable))
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_minimum_judgement(who: &AccountId) {
+		use pallet_identity::{IdentityInformationProvider, Judgement};
+		let info =
+			<Runtime as pallet_identity::Config>::IdentityInformation::create_identity_info();
+		crate::Identity::set_judged_identity_no_deposit(who, info, Judgement::Reasonable)
+			.expect("a single judgement is within `MaxRegistrars`; qed");
+	}
+}
+
 pub struct AllianceProposalProvider;
 impl ProposalProvider<AccountId, Hash, RuntimeCall> for AllianceProposalProvider {
 	fn propose_proposal(
@@ -36,12 +36,12 @@ use frame_support::{
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
-		fungible::{Balanced, Credit, HoldConsideration, ItemOf},
+		dynamic_params::Dynamic,
+		fungible::{Balanced, Credit, ItemOf},
 		tokens::{nonfungibles_v2::Inspect, pay::PayAssetFromAccount, GetSalary, PayFromAccount},
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
-		EitherOfDiverse, EqualPrivilegeOnly, Imbalance, InsideBoth, InstanceFilter,
-		KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, Nothing, OnUnbalanced,
-		WithdrawReasons,
+		EitherOfDiverse, EqualPrivilegeOnly, Footprint, Imbalance, InsideBoth, InstanceFilter,
+		KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -59,7 +59,9 @@ pub use node_primitives::{AccountId, Signature};
 use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Moment, Nonce};
 use pallet_asset_conversion::{NativeOrAssetId, NativeOrAssetIdConverter};
 use pallet_broker::{CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600};
-use pallet_election_provider_multi_phase::{GeometricDepositBase, SolutionAccuracyOf};
+use pallet_election_provider_multi_phase::{
+	DecayingDepositBase, GeometricDepositBase, SolutionAccuracyOf,
+};
 use pallet_identity::legacy::IdentityInfo;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_nfts::PalletFeatures;
@@ -106,7 +108,7 @@ pub use sp_runtime::BuildStorage;
 pub mod impls;
 #[cfg(not(feature = "runtime-benchmarks"))]
 use impls::AllianceIdentityVerifier;
-use impls::{AllianceProposalProvider, Author, CreditToBlockAuthor};
+use impls::{AllianceProposalProvider, Author, CreditToBlockAuthor, SocietyIdentityJudgement};
 
 /// Constant values used within the runtime.
 pub mod constants;
@@ -307,6 +309,8 @@ impl frame_system::Config for Runtime {
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxIndexedEvents = ConstU32<0>;
+	type NestedCallLimits = ();
 }
 
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
@@ -323,6 +327,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MultisigExpiry: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_multisig::Config for Runtime {
@@ -332,6 +337,9 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type Preimages = Preimage;
+	type MaxNotedCallLen = ConstU32<{ 16 * 1024 }>;
+	type MultisigExpiry = MultisigExpiry;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
@@ -342,6 +350,7 @@ parameter_types! {
 	pub const ProxyDepositFactor: Balance = deposit(0, 33);
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const PureRecoveryDelay: BlockNumber = 28 * DAYS;
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -420,6 +429,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type RecoveryDelay = PureRecoveryDelay;
 }
 
 parameter_types! {
@@ -461,12 +472,56 @@ impl pallet_preimage::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<AccountId>;
-	type Consideration = HoldConsideration<
-		AccountId,
-		Balances,
-		PreimageHoldReason,
-		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
-	>;
+	type Consideration = PreimageTicket;
+}
+
+/// The ticket of a preimage deposit, tracked by the deposit tracker.
+pub type PreimageTicket = pallet_deposit_tracker::TrackedHold<Runtime, PreimageHoldReason>;
+
+/// Prices the deposits tracked by the deposit tracker, which are those of the preimages.
+pub struct DepositPrices;
+impl pallet_deposit_tracker::DepositPrices<RuntimeHoldReason, Balance> for DepositPrices {
+	fn deposit(reason: &RuntimeHoldReason, footprint: Footprint) -> Balance {
+		match reason {
+			RuntimeHoldReason::Preimage(_) => PreimageBaseDeposit::get()
+				.saturating_mul(footprint.count.into())
+				.saturating_add(PreimageByteDeposit::get().saturating_mul(footprint.size.into())),
+			// No other deposits are tracked.
+			_ => 0,
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DepositTrackerBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deposit_tracker::BenchmarkHelper<RuntimeHoldReason> for DepositTrackerBenchmarkHelper {
+	fn reason(_: u32) -> RuntimeHoldReason {
+		PreimageHoldReason::get()
+	}
+}
+
+impl pallet_deposit_tracker::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Prices = DepositPrices;
+	type MaxReasons = ConstU32<1>;
+	type WeightInfo = pallet_deposit_tracker::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DepositTrackerBenchmarkHelper;
+}
+
+/// Moves the preimage deposits, which were held by a `HoldConsideration`, to the deposit tracker.
+///
+/// A `HoldConsideration` ticket is encoded as the amount it holds.
+pub struct AdoptPreimageDeposits;
+impl pallet_preimage::migration::tickets::ConvertTicket<AccountId, Balance, PreimageTicket>
+	for AdoptPreimageDeposits
+{
+	fn convert_ticket(who: &AccountId, amount: Balance, footprint: Footprint) -> PreimageTicket {
+		PreimageTicket::adopt(who, footprint, amount)
+	}
 }
 
 parameter_types! {
@@ -494,6 +549,7 @@ impl pallet_babe::Config for Runtime {
 
 parameter_types! {
 	pub const IndexDeposit: Balance = 1 * DOLLARS;
+	pub const IndexLeasePeriod: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_indices::Config for Runtime {
@@ -501,6 +557,8 @@ impl pallet_indices::Config for Runtime {
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
 	type RuntimeEvent = RuntimeEvent;
+	type LeasePeriod = IndexLeasePeriod;
+	type MaxLeasesExpiringPerBlock = ConstU32<16>;
 	type WeightInfo = pallet_indices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -581,6 +639,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = Moment;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type DriftCheck = ();
 	type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
@@ -676,6 +735,7 @@ impl pallet_staking::Config for Runtime {
 	// This a placeholder, to be introduced in the next PR as an instance of bags-list
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
+	type NominationExpiry = ();
 	type HistoryDepth = HistoryDepth;
 	type EventListeners = NominationPools;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
@@ -702,6 +762,8 @@ parameter_types! {
 	pub const SignedRewardBase: Balance = 1 * DOLLARS;
 	pub const SignedFixedDeposit: Balance = 1 * DOLLARS;
 	pub const SignedDepositIncreaseFactor: Percent = Percent::from_percent(10);
+	pub const SignedDepositDecay: Percent = Percent::from_percent(5);
+	pub const SignedMinDeposit: Balance = 20 * CENTS;
 	pub const SignedDepositByte: Balance = 1 * CENTS;
 
 	pub BetterUnsignedThreshold: Perbill = Perbill::from_rational(1u32, 10_000);
@@ -829,8 +891,12 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	type MinerConfig = Self;
 	type SignedMaxSubmissions = ConstU32<10>;
 	type SignedRewardBase = SignedRewardBase;
-	type SignedDepositBase =
-		GeometricDepositBase<Balance, SignedFixedDeposit, SignedDepositIncreaseFactor>;
+	type SignedDepositBase = DecayingDepositBase<
+		Self,
+		GeometricDepositBase<Balance, SignedFixedDeposit, SignedDepositIncreaseFactor>,
+		SignedDepositDecay,
+		SignedMinDeposit,
+	>;
 	type SignedDepositByte = SignedDepositByte;
 	type SignedMaxRefunds = ConstU32<3>;
 	type SignedDepositWeight = ();
@@ -1009,6 +1075,8 @@ impl pallet_ranked_collective::Config for Runtime {
 	type Polls = RankedPolls;
 	type MinRankOfClass = traits::Identity;
 	type VoteWeight = pallet_ranked_collective::Geometric;
+	type InactivityPeriod = ();
+	type ExemptOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -1097,6 +1165,7 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -1149,6 +1218,21 @@ parameter_types! {
 	pub const TechnicalMaxMembers: u32 = 100;
 }
 
+/// Technical committee members cast one vote for each rank they hold in the ranked collective,
+/// and members without a rank cast a single vote.
+pub struct TechnicalCommitteeVotes;
+impl pallet_collective::MemberRank<AccountId> for TechnicalCommitteeVotes {
+	fn vote_weight(who: &AccountId) -> u32 {
+		<RankedVotes as pallet_collective::MemberRank<_>>::vote_weight(who).max(1)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_single_vote(who: &AccountId) {
+		<RankedVotes as pallet_collective::MemberRank<_>>::set_single_vote(who)
+	}
+}
+type RankedVotes = pallet_collective::RankVoteWeight<RankedCollective>;
+
 type TechnicalCollective = pallet_collective::Instance2;
 impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -1158,6 +1242,7 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberRank = TechnicalCommitteeVotes;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -1174,6 +1259,8 @@ impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 	type SwapOrigin = EnsureRootOrHalfCouncil;
 	type ResetOrigin = EnsureRootOrHalfCouncil;
 	type PrimeOrigin = EnsureRootOrHalfCouncil;
+	type RenewOrigin = EnsureRootOrHalfCouncil;
+	type MembershipPeriod = ();
 	type MembershipInitialized = TechnicalCommittee;
 	type MembershipChanged = TechnicalCommittee;
 	type MaxMembers = TechnicalMaxMembers;
@@ -1235,6 +1322,7 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = u32;
+	type MaxRateHistory = ConstU32<50>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1295,6 +1383,7 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<5>;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type CuratorFeeAccrualPeriod = ConstU32<{ 30 * DAYS }>;
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1359,6 +1448,7 @@ impl pallet_contracts::Config for Runtime {
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	type AnnouncementDelay = ();
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1446,6 +1536,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type DeferPeriod = ConstU32<0>;
+	type VetoOrigin = EnsureRoot<AccountId>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -1467,6 +1559,15 @@ impl pallet_grandpa::Config for Runtime {
 		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type MaxValueLen = ConstU32<128>;
+	type MaxBatchLen = ConstU32<32>;
+	type Validator = ();
+	type WeightInfo = pallet_parameters::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	// difference of 26 bytes on-chain for the registration and 9 bytes on-chain for the identity
 	// information, already accounted for by the byte deposit
@@ -1481,8 +1582,10 @@ parameter_types! {
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type BasicDeposit = BasicDeposit;
-	type ByteDeposit = ByteDeposit;
+	type BasicDeposit =
+		Dynamic<Parameters, pallet_identity::dynamic_params::BasicDeposit<Runtime>, BasicDeposit>;
+	type ByteDeposit =
+		Dynamic<Parameters, pallet_identity::dynamic_params::ByteDeposit<Runtime>, ByteDeposit>;
 	type SubAccountDeposit = SubAccountDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
@@ -1490,6 +1593,11 @@ impl pallet_identity::Config for Runtime {
 	type Slashed = Treasury;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
+	type MigratorOrigin = EnsureRoot<Self::AccountId>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1538,6 +1646,7 @@ impl pallet_society::Config for Runtime {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type CandidateJudgement = SocietyIdentityJudgement;
 	type WeightInfo = pallet_society::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1604,6 +1713,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type AssetDeposit = AssetDeposit;
 	type AssetAccountDeposit = ConstU128<DOLLARS>;
+	type MaxSponsoredAccounts = ConstU32<1_000>;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
@@ -1611,6 +1721,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type OnAssetTransfer = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1631,6 +1742,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type AssetDeposit = AssetDeposit;
 	type AssetAccountDeposit = ConstU128<DOLLARS>;
+	type MaxSponsoredAccounts = ConstU32<1_000>;
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ApprovalDeposit;
@@ -1640,6 +1752,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type CallbackHandle = ();
+	type OnAssetTransfer = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1760,11 +1873,15 @@ impl GetSalary<u16, AccountId, Balance> for SalaryForRank {
 impl pallet_salary::Config for Runtime {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = ();
 	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+	type DefaultAssetKind = ();
+	type PayoutAssetOrigin = EnsureRoot<AccountId>;
 	type Members = RankedCollective;
 	type Salary = SalaryForRank;
 	type RegistrationPeriod = ConstU32<200>;
 	type PayoutPeriod = ConstU32<200>;
+	type ExtensionPeriod = ConstU32<100>;
 	type Budget = Budget;
 }
 
@@ -1778,6 +1895,7 @@ impl pallet_core_fellowship::Config for Runtime {
 	type ApproveOrigin = EnsureRootWithSuccess<AccountId, ConstU16<9>>;
 	type PromoteOrigin = EnsureRootWithSuccess<AccountId, ConstU16<9>>;
 	type EvidenceSize = ConstU32<16_384>;
+	type EvidenceRetentionPeriod = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -1816,6 +1934,7 @@ impl pallet_nfts::Config for Runtime {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RoyaltyAsset = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type CollectionDeposit = CollectionDeposit;
 	type ItemDeposit = ItemDeposit;
@@ -1859,6 +1978,7 @@ impl pallet_whitelist::Config for Runtime {
 	type WhitelistOrigin = EnsureRoot<AccountId>;
 	type DispatchWhitelistedOrigin = EnsureRoot<AccountId>;
 	type Preimages = Preimage;
+	type MaxExpiringPerBlock = ConstU32<16>;
 	type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1900,6 +2020,7 @@ impl pallet_collective::Config<AllianceCollective> for Runtime {
 	type MaxProposals = AllianceMaxProposals;
 	type MaxMembers = AllianceMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxCollectivesProposalWeight;
@@ -1928,6 +2049,7 @@ impl pallet_alliance::Config for Runtime {
 		pallet_collective::EnsureProportionMoreThan<AccountId, AllianceCollective, 2, 3>,
 	>;
 	type Currency = Balances;
+	type Preimages = Preimage;
 	type Slashed = Treasury;
 	type InitializeMembers = AllianceMotion;
 	type MembershipChanged = AllianceMotion;
@@ -2144,6 +2266,8 @@ construct_runtime!(
 		Broker: pallet_broker,
 		Mixnet: pallet_mixnet,
 		SkipFeelessPayment: pallet_skip_feeless_payment,
+		Parameters: pallet_parameters,
+		DepositTracker: pallet_deposit_tracker,
 	}
 );
 
@@ -2191,6 +2315,7 @@ pub type Executive = frame_executive::Executive<
 	Runtime,
 	AllPalletsWithSystem,
 	Migrations,
+	ConstBool<true>,
 >;
 
 // All migrations executed on runtime upgrade as a nested tuple of types implementing
@@ -2200,6 +2325,12 @@ type Migrations = (
 	pallet_nomination_pools::migration::versioned::V6ToV7<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_nis::migration::v1::MigrateToV1<Runtime>,
+	pallet_identity::migration::v1::MigrateToV1<Runtime>,
+	pallet_core_fellowship::migration::v1::MigrateToV1<Runtime>,
+	pallet_im_online::migration::v2::Migration<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+	pallet_preimage::migration::tickets::MigrateTickets<Runtime, Balance, AdoptPreimageDeposits>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2217,10 +2348,26 @@ mod mmr {
 	pub type Hashing = <Runtime as pallet_mmr::Config>::Hashing;
 }
 
+/// Snapshot of the members of the membership pallet at `pallet_index`, if it is one.
+fn membership_snapshot(
+	pallet_index: u8,
+) -> Option<frame_support::traits::MembershipSnapshot<AccountId, Hash>> {
+	use frame_support::traits::{PalletInfoAccess, SnapshotMembers};
+	Some(match pallet_index as usize {
+		i if i == Council::index() => Council::membership_snapshot(),
+		i if i == TechnicalCommittee::index() => TechnicalCommittee::membership_snapshot(),
+		i if i == TechnicalMembership::index() => TechnicalMembership::membership_snapshot(),
+		i if i == AllianceMotion::index() => AllianceMotion::membership_snapshot(),
+		i if i == RankedCollective::index() => RankedCollective::membership_snapshot(),
+		_ => return None,
+	})
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
 	frame_benchmarking::define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
+		[frame_benchmarking::host_functions, HostFunctionsBench::<Runtime>]
 		[frame_benchmarking_pallet_pov, Pov]
 		[pallet_alliance, Alliance]
 		[pallet_assets, Assets]
@@ -2230,11 +2377,12 @@ mod benches {
 		[pallet_bounties, Bounties]
 		[pallet_broker, Broker]
 		[pallet_child_bounties, ChildBounties]
-		[pallet_collective, Council]
+		[pallet_collective, TechnicalCommittee]
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_contracts, Contracts]
 		[pallet_core_fellowship, CoreFellowship]
 		[pallet_democracy, Democracy]
+		[pallet_deposit_tracker, DepositTracker]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[pallet_election_provider_support_benchmarking, EPSBench::<Runtime>]
@@ -2252,6 +2400,7 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, RankedCollective]
@@ -2349,6 +2498,15 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl sp_transaction_storage_proof::TransactionStorageApi<Block, BlockNumber> for Runtime {
+		fn transaction_commitment(
+			block: BlockNumber,
+			index: u32,
+		) -> Option<sp_transaction_storage_proof::TransactionCommitment> {
+			TransactionStorage::transaction_commitment(block, index)
+		}
+	}
+
 	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
 		fn offchain_worker(header: &<Block as BlockT>::Header) {
 			Executive::offchain_worker(header)
@@ -2391,6 +2549,51 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId, BlockNumber> for Runtime {
+		fn export_identity(who: AccountId) -> Option<Vec<u8>> {
+			Identity::export_identity(&who).map(|bundle| bundle.encode())
+		}
+
+		fn pending_judgement_requests(
+			registrar: u32,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, BlockNumber)> {
+			Identity::pending_requests(registrar, start_after, limit)
+		}
+	}
+
+	impl pallet_asset_rate_runtime_api::AssetRateApi<Block, u32, BlockNumber> for Runtime {
+		fn rate_at(asset_kind: u32, block: BlockNumber) -> Option<FixedU128> {
+			AssetRate::rate_at(&asset_kind, block)
+		}
+	}
+
+	impl pallet_core_fellowship_runtime_api::CoreFellowshipApi<Block, AccountId, BlockNumber, Hash>
+		for Runtime
+	{
+		fn evidence_metadata(
+			who: AccountId,
+		) -> Option<pallet_core_fellowship::EvidenceMetadata<BlockNumber, Hash>> {
+			CoreFellowship::evidence_metadata(&who)
+		}
+	}
+
+	impl pallet_membership_runtime_api::MembershipSnapshotApi<Block, AccountId, Hash> for Runtime {
+		fn membership_snapshot(
+			pallet_index: u8,
+		) -> Option<pallet_membership_runtime_api::VersionedMembershipSnapshot<AccountId, Hash>> {
+			membership_snapshot(pallet_index).map(Into::into)
+		}
+
+		fn member_proof(
+			pallet_index: u8,
+			who: AccountId,
+		) -> Option<pallet_membership_runtime_api::MemberProof<AccountId, Hash>> {
+			membership_snapshot(pallet_index)?.prove::<BlakeTwo256>(&who)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
 		fn pending_rewards(who: AccountId) -> Balance {
 			NominationPools::api_pending_rewards(who).unwrap_or_default()
@@ -2405,6 +2608,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
 		fn nominations_quota(balance: Balance) -> u32 {
 			Staking::api_nominations_quota(balance)
@@ -2413,6 +2617,10 @@ impl_runtime_apis! {
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page {
 			Staking::api_eras_stakers_page_count(era, account)
 		}
+
+		fn era_payout_forecast() -> Option<sp_staking::EraPayoutForecast<AccountId, Balance>> {
+			Staking::api_era_payout_forecast()
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -2476,6 +2684,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::WeightReconciliationApi<
+		Block,
+		frame_support::dispatch::PerDispatchClass<frame_system::DispatchWeightReport>,
+	> for Runtime {
+		fn weight_reconciliation(
+		) -> frame_support::dispatch::PerDispatchClass<frame_system::DispatchWeightReport> {
+			System::weight_reconciliation()
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -2560,6 +2778,12 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn storage_info(
+			address: AccountId,
+		) -> pallet_contracts_primitives::ContractStorageInfoResult<AccountId, Balance> {
+			Contracts::storage_info(address)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -2691,6 +2915,23 @@ impl_runtime_apis! {
 			)
 		}
 
+		fn generate_leaf_proof(
+			leaf_indices: Vec<mmr::LeafIndex>,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_leaf_proof(leaf_indices, best_known_block_number).map(
+				|(leaves, proof)| {
+					(
+						leaves
+							.into_iter()
+							.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+							.collect(),
+						proof,
+					)
+				},
+			)
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::Proof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -2729,6 +2970,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -2739,6 +2981,15 @@ impl_runtime_apis! {
 		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
 			SessionKeys::decode_into_raw_public_keys(&encoded)
 		}
+
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> sp_session::OpaqueGeneratedSessionKeys {
+			let (keys, proof) = SessionKeys::generate_with_ownership_proof(&owner, seed)
+				.expect("the private keys were just generated in the keystore; qed");
+			sp_session::OpaqueGeneratedSessionKeys { keys: keys.encode(), proof }
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -2780,6 +3031,7 @@ impl_runtime_apis! {
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use frame_benchmarking::host_functions::Pallet as HostFunctionsBench;
 			use pallet_nomination_pools_benchmarking::Pallet as NominationPoolsBench;
 
 			let mut list = Vec::<BenchmarkList>::new();
@@ -2804,6 +3056,7 @@ impl_runtime_apis! {
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use frame_benchmarking::host_functions::Pallet as HostFunctionsBench;
 			use pallet_nomination_pools_benchmarking::Pallet as NominationPoolsBench;
 
 			impl pallet_session_benchmarking::Config for Runtime {}
@@ -2811,6 +3064,7 @@ impl_runtime_apis! {
 			impl pallet_election_provider_support_benchmarking::Config for Runtime {}
 			impl frame_system_benchmarking::Config for Runtime {}
 			impl baseline::Config for Runtime {}
+			impl frame_benchmarking::host_functions::Config for Runtime {}
 			impl pallet_nomination_pools_benchmarking::Config for Runtime {}
 
 			use frame_support::traits::WhitelistedStorageKeys;
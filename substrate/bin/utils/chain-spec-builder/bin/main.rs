@@ -17,13 +17,26 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use chain_spec_builder::{
-	generate_chain_spec_for_runtime, ChainSpecBuilder, ChainSpecBuilderCmd, ConvertToRawCmd,
-	UpdateCodeCmd, VerifyCmd,
+	fetch_light_sync_state, generate_chain_spec_for_runtime, verify_light_sync_state,
+	ChainSpecBuilder, ChainSpecBuilderCmd, ConvertToRawCmd, UpdateCodeCmd,
+	UpdateLightSyncStateCmd, VerifyCmd,
 };
 use clap::Parser;
-use sc_chain_spec::{update_code_in_json_chain_spec, GenericChainSpec};
+use sc_chain_spec::{
+	update_code_in_json_chain_spec, update_light_sync_state_in_json_chain_spec, GenericChainSpec,
+};
 use staging_chain_spec_builder as chain_spec_builder;
-use std::fs;
+use std::{fs, future::Future, path::Path};
+
+fn block_on<F: Future>(future: F) -> Result<F::Output, String> {
+	let runtime = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
+	Ok(runtime.block_on(future))
+}
+
+fn read_json(path: &Path) -> Result<serde_json::Value, String> {
+	let chain_spec = fs::read(path).map_err(|e| format!("Chain spec could not be read: {e}"))?;
+	serde_json::from_slice(&chain_spec).map_err(|e| format!("Chain spec is not valid json: {e}"))
+}
 
 fn main() -> Result<(), String> {
 	sp_tracing::try_init_simple();
@@ -71,6 +84,25 @@ fn main() -> Result<(), String> {
 			let _ = serde_json::from_str::<serde_json::Value>(&chain_spec.as_json(true)?)
 				.map_err(|e| format!("Conversion to json failed: {e}"))?;
 		},
+		ChainSpecBuilderCmd::UpdateLightSyncState(UpdateLightSyncStateCmd {
+			ref input_chain_spec,
+			ref rpc_url,
+		}) => {
+			let mut chain_spec_json = read_json(input_chain_spec)?;
+			let light_sync_state = block_on(fetch_light_sync_state(rpc_url))??;
+			if !update_light_sync_state_in_json_chain_spec(&mut chain_spec_json, light_sync_state) {
+				return Err("Chain spec does not declare the light sync state extension".into())
+			}
+
+			let chain_spec_json = serde_json::to_string_pretty(&chain_spec_json)
+				.map_err(|e| format!("to pretty failed: {e}"))?;
+			fs::write(chain_spec_path, chain_spec_json).map_err(|err| err.to_string())?;
+		},
+		ChainSpecBuilderCmd::VerifyLightSyncState(ref cmd) => {
+			let chain_spec_json = read_json(&cmd.input_chain_spec)?;
+			let (number, hash) = block_on(verify_light_sync_state(cmd, &chain_spec_json))??;
+			println!("Light sync state verification: OK (block #{number}, {hash:?})");
+		},
 	};
 	Ok(())
 }
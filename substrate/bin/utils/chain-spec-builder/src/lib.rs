@@ -68,8 +68,17 @@
 //! chain-spec-builder create -r runtime.wasm full full-genesis-config.json
 //! ```
 //! 
+//! ##### Embed a fresh light sync state.
+//!
+//! Fetch the light sync state at the latest finalized block of a running node and embed it into
+//! `chain_spec.json`, then verify that it is finalized by the node and at most 1000 blocks old:
+//! ```text
+//! chain-spec-builder -c chain_spec.json update-light-sync-state chain_spec.json --rpc-url http://localhost:9944
+//! chain-spec-builder verify-light-sync-state chain_spec.json --rpc-url http://localhost:9944 --max-age 1000
+//! ```
+//!
 //! ##### Extra tools.
-//! The `chain-spec-builder` provides also some extra utilities: [`VerifyCmd`], [`ConvertToRawCmd`], [`UpdateCodeCmd`].
+//! The `chain-spec-builder` provides also some extra utilities: [`VerifyCmd`], [`ConvertToRawCmd`], [`UpdateCodeCmd`], [`UpdateLightSyncStateCmd`], [`VerifyLightSyncStateCmd`].
 //!
 //! [`sc-chain-spec`]: ../sc_chain_spec/index.html
 //! [`node-cli`]: ../node_cli/index.html
@@ -80,8 +89,13 @@
 use std::{fs, path::PathBuf};
 
 use clap::{Parser, Subcommand};
-use sc_chain_spec::{GenericChainSpec, GenesisConfigBuilderRuntimeCaller};
+use codec::{Compact, Decode};
+use jsonrpsee::{core::client::ClientT, http_client::HttpClientBuilder, rpc_params};
+use sc_chain_spec::{
+	light_sync_state_in_json_chain_spec, GenericChainSpec, GenesisConfigBuilderRuntimeCaller,
+};
 use serde_json::Value;
+use sp_core::{bytes, hashing::blake2_256, H256};
 
 /// A utility to easily create a chain spec definition.
 #[derive(Debug, Parser)]
@@ -101,6 +115,8 @@ pub enum ChainSpecBuilderCmd {
 	Verify(VerifyCmd),
 	UpdateCode(UpdateCodeCmd),
 	ConvertToRaw(ConvertToRawCmd),
+	UpdateLightSyncState(UpdateLightSyncStateCmd),
+	VerifyLightSyncState(VerifyLightSyncStateCmd),
 }
 
 /// Create a new chain spec by interacting with the provided runtime wasm blob.
@@ -187,6 +203,119 @@ pub struct VerifyCmd {
 	pub input_chain_spec: PathBuf,
 }
 
+/// Updates the light sync state in the provided input chain spec.
+///
+/// The light sync state at the latest finalized block is fetched from a running node, which must
+/// expose the `sync_state_genSyncSpec` RPC of `sc-sync-state-rpc`. The input chain spec must
+/// declare the light sync state extension. This operation supports both plain and raw formats.
+#[derive(Parser, Debug, Clone)]
+pub struct UpdateLightSyncStateCmd {
+	/// Chain spec to be updated.
+	pub input_chain_spec: PathBuf,
+	/// The HTTP RPC endpoint of the node to fetch the light sync state from.
+	#[arg(long, default_value = "http://localhost:9944")]
+	pub rpc_url: String,
+}
+
+/// Verifies the light sync state of the provided input chain spec.
+///
+/// Checks that the light sync state is present and well formed. If an RPC endpoint is given, also
+/// checks that the block of the light sync state is finalized by the node, and optionally that it
+/// is recent enough.
+///
+/// The block hash is computed assuming that the chain hashes its headers with BLAKE2-256.
+#[derive(Parser, Debug, Clone)]
+pub struct VerifyLightSyncStateCmd {
+	/// Chain spec to be verified.
+	pub input_chain_spec: PathBuf,
+	/// The HTTP RPC endpoint of a node of the chain to verify the light sync state against.
+	#[arg(long)]
+	pub rpc_url: Option<String>,
+	/// The maximum number of blocks the light sync state may lag behind the latest finalized
+	/// block of the node.
+	#[arg(long, requires = "rpc_url")]
+	pub max_age: Option<u64>,
+}
+
+/// Fetches the light sync state at the latest finalized block of the node at `rpc_url`.
+pub async fn fetch_light_sync_state(rpc_url: &str) -> Result<Value, String> {
+	let client = HttpClientBuilder::default()
+		.max_request_body_size(u32::MAX)
+		.build(rpc_url)
+		.map_err(|e| format!("failed to connect to {rpc_url}: {e}"))?;
+	let chain_spec: Value = client
+		.request("sync_state_genSyncSpec", rpc_params![false])
+		.await
+		.map_err(|e| format!("failed to fetch the light sync state: {e}"))?;
+	light_sync_state_in_json_chain_spec(&chain_spec)
+		.cloned()
+		.ok_or_else(|| "the node did not return a light sync state".into())
+}
+
+/// Returns the number and hash of the finalized block of the light sync state of `chain_spec`.
+pub fn light_sync_state_checkpoint(chain_spec: &Value) -> Result<(u64, H256), String> {
+	let state = light_sync_state_in_json_chain_spec(chain_spec)
+		.ok_or("chain spec shall contain a light sync state")?;
+	let field = |name: &str| {
+		let value = state[name]
+			.as_str()
+			.ok_or_else(|| format!("light sync state shall contain `{name}` as a hex string"))?;
+		bytes::from_hex(value).map_err(|e| format!("`{name}` shall be valid hex: {e}"))
+	};
+	field("babeEpochChanges")?;
+	field("grandpaAuthoritySet")?;
+	state["babeFinalizedBlockWeight"]
+		.as_u64()
+		.ok_or("light sync state shall contain `babeFinalizedBlockWeight` as a number")?;
+
+	let header = field("finalizedBlockHeader")?;
+	let (_parent_hash, Compact(number)) = <([u8; 32], Compact<u64>)>::decode(&mut &header[..])
+		.map_err(|e| format!("`finalizedBlockHeader` shall be a valid header: {e}"))?;
+	Ok((number, H256(blake2_256(&header))))
+}
+
+/// Processes `VerifyLightSyncStateCmd` for the already loaded `chain_spec`, returning the number
+/// and hash of the finalized block of its light sync state.
+pub async fn verify_light_sync_state(
+	cmd: &VerifyLightSyncStateCmd,
+	chain_spec: &Value,
+) -> Result<(u64, H256), String> {
+	let (number, hash) = light_sync_state_checkpoint(chain_spec)?;
+	let Some(ref rpc_url) = cmd.rpc_url else { return Ok((number, hash)) };
+
+	let client = HttpClientBuilder::default()
+		.build(rpc_url)
+		.map_err(|e| format!("failed to connect to {rpc_url}: {e}"))?;
+	let rpc_err = |e: jsonrpsee::core::Error| format!("RPC request to {rpc_url} failed: {e}");
+	let canonical: Option<H256> =
+		client.request("chain_getBlockHash", rpc_params![number]).await.map_err(rpc_err)?;
+	if canonical != Some(hash) {
+		return Err(format!("block #{number} of the light sync state is not canonical: {hash:?}"))
+	}
+
+	let finalized: H256 =
+		client.request("chain_getFinalizedHead", rpc_params![]).await.map_err(rpc_err)?;
+	let finalized_header: Value =
+		client.request("chain_getHeader", rpc_params![finalized]).await.map_err(rpc_err)?;
+	let finalized_number = finalized_header["number"]
+		.as_str()
+		.and_then(|n| u64::from_str_radix(n.trim_start_matches("0x"), 16).ok())
+		.ok_or("the node returned an invalid finalized header")?;
+	if number > finalized_number {
+		return Err(format!("block #{number} of the light sync state is not finalized yet"))
+	}
+	if let Some(max_age) = cmd.max_age {
+		let age = finalized_number - number;
+		if age > max_age {
+			return Err(format!(
+				"light sync state is {age} blocks behind the finalized block, at most {max_age} \
+				are allowed"
+			))
+		}
+	}
+	Ok((number, hash))
+}
+
 /// Processes `CreateCmd` and returns JSON version of `ChainSpec`.
 pub fn generate_chain_spec_for_runtime(cmd: &CreateCmd) -> Result<String, String> {
 	let code = fs::read(cmd.runtime_wasm_path.as_path())
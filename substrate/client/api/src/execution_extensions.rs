@@ -23,8 +23,10 @@
 //! extensions to support APIs for particular execution context & capabilities.
 
 use parking_lot::RwLock;
+use prometheus_endpoint::{register, CounterVec, Opts, PrometheusError, Registry, U64};
 use sp_core::traits::{ReadRuntimeVersion, ReadRuntimeVersionExt};
 use sp_externalities::{Extension, Extensions};
+use sp_io::{StorageMetricsExt, StorageMetricsSink};
 use sp_runtime::traits::{Block as BlockT, NumberFor};
 use std::{marker::PhantomData, sync::Arc};
 
@@ -87,6 +89,87 @@ impl<Block: BlockT, Ext: Default + Extension> ExtensionsFactory<Block>
 	}
 }
 
+/// An [`ExtensionsFactory`] that registers the [`StorageMetricsExt`] for every runtime call.
+///
+/// Storage access counters reported by a runtime built with the `storage-metrics` feature of
+/// `frame-support` are forwarded to the given [`StorageMetricsSink`]. The extension can be
+/// registered in the following way:
+///
+/// ```nocompile
+/// let metrics = Arc::new(PrometheusStorageMetrics::register(&prometheus_registry)?);
+/// client.execution_extensions().set_extensions_factory(
+/// 	StorageMetricsExtensionFactory::<Block>::new(metrics),
+/// );
+/// ```
+pub struct StorageMetricsExtensionFactory<Block: BlockT> {
+	sink: Arc<dyn StorageMetricsSink>,
+	_marker: PhantomData<fn(Block) -> Block>,
+}
+
+impl<Block: BlockT> StorageMetricsExtensionFactory<Block> {
+	/// Create the extension factory forwarding all reports to `sink`.
+	pub fn new(sink: Arc<dyn StorageMetricsSink>) -> Self {
+		Self { sink, _marker: PhantomData }
+	}
+}
+
+impl<Block: BlockT> ExtensionsFactory<Block> for StorageMetricsExtensionFactory<Block> {
+	fn extensions_for(&self, _: Block::Hash, _: NumberFor<Block>) -> Extensions {
+		let mut exts = Extensions::new();
+		exts.register(StorageMetricsExt(self.sink.clone()));
+		exts
+	}
+}
+
+/// A [`StorageMetricsSink`] exporting the reported storage accesses to Prometheus.
+///
+/// All counters are labeled by pallet and call name.
+#[derive(Clone)]
+pub struct PrometheusStorageMetrics {
+	calls: CounterVec<U64>,
+	reads: CounterVec<U64>,
+	writes: CounterVec<U64>,
+	rollbacks: CounterVec<U64>,
+}
+
+impl PrometheusStorageMetrics {
+	/// Register the metrics at the given Prometheus registry.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		let counter = |name: &str, help: &str| {
+			register(CounterVec::new(Opts::new(name, help), &["pallet", "call"])?, registry)
+		};
+
+		Ok(Self {
+			calls: counter(
+				"substrate_runtime_storage_metrics_calls_total",
+				"Number of dispatched calls that reported storage metrics",
+			)?,
+			reads: counter(
+				"substrate_runtime_storage_reads_total",
+				"Number of storage reads performed by dispatched calls",
+			)?,
+			writes: counter(
+				"substrate_runtime_storage_writes_total",
+				"Number of storage writes performed by dispatched calls",
+			)?,
+			rollbacks: counter(
+				"substrate_runtime_storage_rollbacks_total",
+				"Number of transactional layers rolled back by dispatched calls",
+			)?,
+		})
+	}
+}
+
+impl StorageMetricsSink for PrometheusStorageMetrics {
+	fn report(&self, pallet: &str, call: &str, reads: u32, writes: u32, rollbacks: u32) {
+		let labels = [pallet, call];
+		self.calls.with_label_values(&labels).inc();
+		self.reads.with_label_values(&labels).inc_by(reads.into());
+		self.writes.with_label_values(&labels).inc_by(writes.into());
+		self.rollbacks.with_label_values(&labels).inc_by(rollbacks.into());
+	}
+}
+
 /// A producer of execution extensions for offchain calls.
 ///
 /// This crate aggregates extensions available for the offchain calls
@@ -840,6 +840,36 @@ pub fn update_code_in_json_chain_spec(chain_spec: &mut json::Value, code: &[u8])
 	}
 }
 
+/// The key of the light sync state in a JSON chain spec.
+///
+/// Nodes serialize the `LightSyncStateExtension` of `sc-sync-state-rpc` under this key.
+const LIGHT_SYNC_STATE_KEY: &str = "lightSyncState";
+
+/// This function returns the light sync state of given chain spec, if it has one.
+pub fn light_sync_state_in_json_chain_spec(chain_spec: &json::Value) -> Option<&json::Value> {
+	chain_spec.get(LIGHT_SYNC_STATE_KEY).filter(|state| !state.is_null())
+}
+
+/// This function updates the light sync state in given chain spec.
+///
+/// Both the plain and raw formats are supported, but the chain spec must declare the light sync
+/// state extension, i.e. contain the `lightSyncState` key, even if its value is `null`.
+///
+/// If update was successful `true` is returned, otherwise `false`. Chain spec JSON is modified in
+/// place.
+pub fn update_light_sync_state_in_json_chain_spec(
+	chain_spec: &mut json::Value,
+	light_sync_state: json::Value,
+) -> bool {
+	match chain_spec.get_mut(LIGHT_SYNC_STATE_KEY) {
+		Some(state) => {
+			*state = light_sync_state;
+			true
+		},
+		None => false,
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1210,6 +1240,21 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn update_light_sync_state_works() {
+		let mut chain_spec_json = json!({"name": "TestName", "lightSyncState": null});
+		assert_eq!(light_sync_state_in_json_chain_spec(&chain_spec_json), None);
+
+		let state = json!({"finalizedBlockHeader": "0x00", "babeFinalizedBlockWeight": 1});
+		assert!(update_light_sync_state_in_json_chain_spec(&mut chain_spec_json, state.clone()));
+		assert_eq!(light_sync_state_in_json_chain_spec(&chain_spec_json), Some(&state));
+		assert_eq!(chain_spec_json["name"], "TestName");
+
+		let mut chain_spec_json = json!({"name": "TestName"});
+		assert!(!update_light_sync_state_in_json_chain_spec(&mut chain_spec_json, state));
+		assert_eq!(chain_spec_json, json!({"name": "TestName"}));
+	}
+
 	#[test]
 	fn update_code_works_with_runtime_genesis_patch() {
 		let chain_spec = ChainSpec::<()>::builder(
@@ -329,8 +329,9 @@ mod json_patch;
 
 pub use self::{
 	chain_spec::{
-		update_code_in_json_chain_spec, ChainSpec as GenericChainSpec, ChainSpecBuilder,
-		NoExtension,
+		light_sync_state_in_json_chain_spec, update_code_in_json_chain_spec,
+		update_light_sync_state_in_json_chain_spec, ChainSpec as GenericChainSpec,
+		ChainSpecBuilder, NoExtension,
 	},
 	extension::{get_extension, get_extension_mut, Extension, Fork, Forks, GetExtension, Group},
 	genesis_block::{
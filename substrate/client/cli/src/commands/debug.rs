@@ -0,0 +1,78 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Debugging related CLI utilities
+
+use super::execute_block_cmd::ExecuteBlockCmd;
+use crate::{
+	error,
+	params::{ImportParams, SharedParams},
+	CliConfiguration,
+};
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sp_api::{Metadata, ProvideRuntimeApi};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+/// Debugging utilities for the cli.
+#[derive(Debug, clap::Subcommand)]
+pub enum DebugSubcommand {
+	/// Re-execute a block, recording its storage accesses and logs, and write a JSON report.
+	ExecuteBlock(ExecuteBlockCmd),
+}
+
+impl DebugSubcommand {
+	/// run the debug subcommands
+	pub fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT + 'static,
+		C: HeaderBackend<B>
+			+ BlockBackend<B>
+			+ ProvideRuntimeApi<B>
+			+ Send
+			+ Sync
+			+ 'static,
+		C::Api: Metadata<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		NumberFor<B>: FromStr,
+		<NumberFor<B> as FromStr>::Err: Debug,
+	{
+		match self {
+			DebugSubcommand::ExecuteBlock(cmd) => cmd.run(client),
+		}
+	}
+}
+
+impl CliConfiguration for DebugSubcommand {
+	fn shared_params(&self) -> &SharedParams {
+		match self {
+			DebugSubcommand::ExecuteBlock(cmd) => cmd.shared_params(),
+		}
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		match self {
+			DebugSubcommand::ExecuteBlock(cmd) => cmd.import_params(),
+		}
+	}
+
+	fn log_filters(&self) -> error::Result<String> {
+		match self {
+			DebugSubcommand::ExecuteBlock(cmd) => cmd.log_filters(),
+		}
+	}
+}
@@ -0,0 +1,186 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Implementation of the `execute-block` subcommand
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sc_tracing::{block::BlockExecutor, TraceEvent};
+use serde::Serialize;
+use sp_api::{Metadata, ProvideRuntimeApi};
+use sp_rpc::tracing::{BlockTrace, TraceBlockResponse};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+use std::{collections::BTreeMap, fmt::Debug, fs, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `execute-block` command used to re-execute a block while tracing it.
+#[derive(Debug, Clone, Parser)]
+pub struct ExecuteBlockCmd {
+	/// Block hash or number.
+	#[arg(value_name = "HASH or NUMBER")]
+	pub input: BlockNumberOrHash,
+
+	/// Comma separated list of the targets of the spans, storage accesses and logs to record,
+	/// each optionally followed by the maximum level to record, e.g. `pallet,runtime=debug`.
+	///
+	/// Spans of the runtime are only recorded if it was built with the `with-tracing` feature.
+	#[arg(long, value_name = "TARGETS", default_value = "pallet,frame,state,runtime")]
+	pub targets: String,
+
+	/// Comma separated list of hex encoded prefixes of the keys of the storage accesses to record.
+	///
+	/// All storage accesses are recorded by default.
+	#[arg(long, value_name = "PREFIXES", default_value = "")]
+	pub storage_keys: String,
+
+	/// Comma separated list of the methods of the storage accesses to record, e.g. `Get,Put`.
+	///
+	/// All storage accesses are recorded by default.
+	#[arg(long, value_name = "METHODS", default_value = "")]
+	pub methods: String,
+
+	/// Write the report to the given file instead of stdout.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+/// The report of the re-execution of a block, written as JSON by [`ExecuteBlockCmd`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteBlockReport {
+	/// How long the traced execution of the block took, in milliseconds.
+	pub duration_ms: u128,
+	/// The number of recorded storage accesses, by method.
+	pub storage_accesses: BTreeMap<String, usize>,
+	/// The logs emitted while executing the block.
+	pub logs: Vec<ExecuteBlockLog>,
+	/// The recorded spans and storage accesses.
+	pub trace: BlockTrace,
+}
+
+/// A log emitted while re-executing a block.
+#[derive(Debug, Serialize)]
+pub struct ExecuteBlockLog {
+	/// The level of the log.
+	pub level: String,
+	/// The target of the log.
+	pub target: String,
+	/// The message of the log.
+	pub message: String,
+}
+
+impl From<TraceEvent> for ExecuteBlockLog {
+	fn from(event: TraceEvent) -> Self {
+		Self {
+			level: event.level.to_string(),
+			target: event.target,
+			message: event.values.string_values.get("message").cloned().unwrap_or_default(),
+		}
+	}
+}
+
+impl ExecuteBlockCmd {
+	/// Run the execute-block command
+	pub fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT + 'static,
+		C: HeaderBackend<B>
+			+ BlockBackend<B>
+			+ ProvideRuntimeApi<B>
+			+ Send
+			+ Sync
+			+ 'static,
+		C::Api: Metadata<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		NumberFor<B>: FromStr,
+		<NumberFor<B> as FromStr>::Err: Debug,
+	{
+		let hash = match self.input.parse::<B>()? {
+			BlockId::Hash(hash) => hash,
+			BlockId::Number(number) =>
+				client.hash(number)?.ok_or_else(|| format!("Unknown block number {:?}", number))?,
+		};
+
+		let start = std::time::Instant::now();
+		let (response, logs) = BlockExecutor::<B, C>::new(
+			client,
+			hash,
+			Some(self.targets.clone()),
+			Some(self.storage_keys.clone()),
+			Some(self.methods.clone()),
+		)
+		.trace_block_with_logs()
+		.map_err(|e| format!("Failed to execute block: {}", e))?;
+		let duration_ms = start.elapsed().as_millis();
+
+		let trace = match response {
+			TraceBlockResponse::BlockTrace(trace) => trace,
+			TraceBlockResponse::TraceError(e) =>
+				return Err(format!("Failed to trace block: {}", e.error).into()),
+		};
+		let mut storage_accesses = BTreeMap::<String, usize>::new();
+		for method in trace.events.iter().filter_map(|e| e.data.string_values.get("method")) {
+			*storage_accesses.entry(method.clone()).or_default() += 1;
+		}
+		let report = ExecuteBlockReport {
+			duration_ms,
+			storage_accesses,
+			logs: logs.into_iter().map(Into::into).collect(),
+			trace,
+		};
+
+		let report = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+		match &self.output {
+			Some(path) => fs::write(path, report)?,
+			None => println!("{}", report),
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ExecuteBlockCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn log_filters(&self) -> error::Result<String> {
+		// The runtime only emits the logs enabled on the node.
+		let mut filters = vec!["runtime=trace".to_string()];
+		filters.extend_from_slice(self.shared_params.log_filters());
+		Ok(filters.join(","))
+	}
+}
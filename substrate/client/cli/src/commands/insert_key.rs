@@ -66,7 +66,8 @@ impl InsertKeyCmd {
 		let config_dir = base_path.config_dir(chain_spec.id());
 
 		let (keystore, public) = match self.keystore_params.keystore_config(&config_dir)? {
-			KeystoreConfig::Path { path, password } => {
+			KeystoreConfig::Path { path, password } |
+			KeystoreConfig::Remote { path, password, .. } => {
 				let public = with_crypto_scheme!(self.scheme, to_vec(&suri, password.clone()))?;
 				let keystore: KeystorePtr = LocalKeystore::open(path, password)?.into();
 				(keystore, public)
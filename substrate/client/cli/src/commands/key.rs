@@ -20,6 +20,7 @@
 use super::{
 	generate::GenerateCmd, generate_node_key::GenerateNodeKeyCmd, insert_key::InsertKeyCmd,
 	inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
+	rotate_session::RotateSessionCmd,
 };
 use crate::{Error, SubstrateCli};
 
@@ -41,6 +42,9 @@ pub enum KeySubcommand {
 
 	/// Insert a key to the keystore of a node.
 	Insert(InsertKeyCmd),
+
+	/// Generate new session keys in the keystore of a node and optionally set them on-chain.
+	RotateSession(RotateSessionCmd),
 }
 
 impl KeySubcommand {
@@ -52,6 +56,7 @@ impl KeySubcommand {
 			KeySubcommand::Inspect(cmd) => cmd.run(),
 			KeySubcommand::Insert(cmd) => cmd.run(cli),
 			KeySubcommand::InspectNodeKey(cmd) => cmd.run(),
+			KeySubcommand::RotateSession(cmd) => cmd.run(),
 		}
 	}
 }
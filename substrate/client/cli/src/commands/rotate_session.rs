@@ -0,0 +1,348 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `rotate-session` subcommand
+
+use crate::{utils, with_crypto_scheme, CryptoSchemeFlag, Error, KeystoreParams};
+use array_bytes::bytes2hex;
+use clap::Parser;
+use frame_metadata::{v14::RuntimeMetadataV14, RuntimeMetadata, RuntimeMetadataPrefixed};
+use parity_scale_codec::{Compact, Decode, Encode};
+use scale_info::{PortableRegistry, TypeDef};
+use serde::Deserialize;
+use sp_core::{
+	crypto::{Pair, SecretString, Ss58Codec},
+	hashing::{blake2_256, twox_128},
+	storage::{StorageData, StorageKey},
+	Bytes, H256,
+};
+use sp_runtime::{
+	generic::Era, traits::IdentifyAccount, AccountId32, MultiAddress, MultiSignature, MultiSigner,
+};
+use sp_version::RuntimeVersion;
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+/// The `rotate-session` command
+#[derive(Debug, Clone, Parser)]
+#[command(
+	name = "rotate-session",
+	about = "Generate new session keys in the keystore of a node and optionally set them on-chain."
+)]
+pub struct RotateSessionCmd {
+	/// The URI of the RPC server of the node whose session keys are rotated.
+	#[arg(long, value_name = "URI", default_value = "ws://127.0.0.1:9944")]
+	uri: String,
+
+	/// Submit the `set_keys` extrinsic of the session pallet, signed with `--suri`.
+	///
+	/// Otherwise only the encoded call is printed, see `--owner`.
+	#[arg(long)]
+	submit: bool,
+
+	/// The SS58 address of the account which will set the session keys, when not submitting
+	/// the extrinsic.
+	///
+	/// `set_keys` requires a proof that the keys are owned by this account, so the call is only
+	/// printed if the account is known.
+	#[arg(long, conflicts_with = "submit")]
+	owner: Option<String>,
+
+	/// The secret key URI of the account setting the session keys.
+	/// If the value is a file, the file content is used as URI.
+	/// If not given and `--submit` is set, you will be prompted for the URI.
+	#[arg(long)]
+	suri: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub keystore_params: KeystoreParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub crypto_scheme: CryptoSchemeFlag,
+}
+
+impl RotateSessionCmd {
+	/// Run the command
+	pub fn run(&self) -> Result<(), Error> {
+		crate::build_runtime()?.block_on(self.rotate())
+	}
+
+	async fn rotate(&self) -> Result<(), Error> {
+		let client = ws_client(&self.uri).await?;
+
+		let signer = if self.submit {
+			let suri = utils::read_uri(self.suri.as_ref())?;
+			let password = self.keystore_params.read_password()?;
+			Some(with_crypto_scheme!(self.crypto_scheme.scheme, signer(&suri, password))?)
+		} else {
+			None
+		};
+		let owner = match (&signer, &self.owner) {
+			(Some((account, _)), _) => account.clone(),
+			(None, Some(owner)) => AccountId32::from_ss58check(owner)
+				.map_err(|e| format!("Invalid owner address: {:?}", e))?,
+			(None, None) => {
+				let keys: Bytes =
+					client.request("author_rotateKeys", rpc_params![]).await.map_err(rpc_error)?;
+				println!("Session keys: {}", bytes2hex("0x", &keys.0));
+				println!("Pass `--owner` to print the `set_keys` call for these keys.");
+				return Ok(())
+			},
+		};
+
+		let generated: GeneratedSessionKeys = client
+			.request("author_rotateKeysWithOwner", rpc_params![Bytes(owner.encode())])
+			.await
+			.map_err(rpc_error)?;
+		println!("Session keys: {}", bytes2hex("0x", &generated.keys.0));
+
+		let metadata: Bytes =
+			client.request("state_getMetadata", rpc_params![]).await.map_err(rpc_error)?;
+		let metadata = decode_metadata(&metadata.0)?;
+		let call = set_keys_call(&metadata, generated.keys.0, &generated.proof.0)?;
+		let Some((account, sign)) = signer else {
+			println!("Call: {}", bytes2hex("0x", &call));
+			return Ok(())
+		};
+
+		let version: RuntimeVersion =
+			client.request("state_getRuntimeVersion", rpc_params![]).await.map_err(rpc_error)?;
+		let genesis: H256 =
+			client.request("chain_getBlockHash", rpc_params![0]).await.map_err(rpc_error)?;
+		let nonce: u64 = client
+			.request("system_accountNextIndex", rpc_params![account.to_ss58check()])
+			.await
+			.map_err(rpc_error)?;
+
+		let (extra, additional) = signed_extensions(&metadata, &version, genesis, nonce)?;
+		let payload = [&call[..], &extra, &additional].concat();
+		let signature =
+			if payload.len() > 256 { sign(&blake2_256(&payload)) } else { sign(&payload) };
+		let extrinsic = signed_extrinsic(account, signature, &extra, &call);
+		let hash: H256 = client
+			.request("author_submitExtrinsic", rpc_params![Bytes(extrinsic)])
+			.await
+			.map_err(rpc_error)?;
+		println!("Submitted extrinsic: {:?}", hash);
+
+		let key = StorageKey([twox_128(b"Session"), twox_128(b"CurrentIndex")].concat());
+		let session: Option<StorageData> =
+			client.request("state_getStorage", rpc_params![key]).await.map_err(rpc_error)?;
+		let session = session.map(|data| u32::decode(&mut &data.0[..])).transpose()?;
+		let session = session.unwrap_or_default();
+		// Keys set during a session are queued at its end and used from the session after.
+		println!(
+			"If included in session {}, the new session keys are active from session {}.",
+			session,
+			session.saturating_add(2),
+		);
+
+		Ok(())
+	}
+}
+
+/// Session keys generated by `author_rotateKeysWithOwner`, with the proof of their ownership.
+#[derive(Deserialize)]
+struct GeneratedSessionKeys {
+	keys: Bytes,
+	proof: Bytes,
+}
+
+fn rpc_error(error: substrate_rpc_client::Error) -> Error {
+	Error::Application(error.into())
+}
+
+/// The account of the pair of `suri`, with a function signing payloads with it.
+fn signer<P>(
+	suri: &str,
+	password: Option<SecretString>,
+) -> Result<(AccountId32, Box<dyn Fn(&[u8]) -> MultiSignature>), Error>
+where
+	P: Pair + 'static,
+	P::Public: Into<MultiSigner>,
+	P::Signature: Into<MultiSignature>,
+{
+	let pair = utils::pair_from_suri::<P>(suri, password)?;
+	let signer: MultiSigner = pair.public().into();
+	Ok((signer.into_account(), Box::new(move |payload: &[u8]| pair.sign(payload).into())))
+}
+
+fn decode_metadata(encoded: &[u8]) -> Result<RuntimeMetadataV14, Error> {
+	match RuntimeMetadataPrefixed::decode(&mut &encoded[..])?.1 {
+		RuntimeMetadata::V14(metadata) => Ok(metadata),
+		_ => Err("Unsupported metadata version, expected V14".into()),
+	}
+}
+
+/// Encode the call to `set_keys` of the session pallet, setting the encoded session `keys` with
+/// the `proof` of their ownership.
+fn set_keys_call(
+	metadata: &RuntimeMetadataV14,
+	keys: Vec<u8>,
+	proof: &[u8],
+) -> Result<Vec<u8>, Error> {
+	let pallet = metadata
+		.pallets
+		.iter()
+		.find(|pallet| pallet.name == "Session")
+		.ok_or("The runtime has no `Session` pallet")?;
+	let call_index = pallet
+		.calls
+		.as_ref()
+		.and_then(|calls| match &metadata.types.resolve(calls.ty.id)?.type_def {
+			TypeDef::Variant(calls) =>
+				calls.variants.iter().find(|call| call.name == "set_keys").map(|call| call.index),
+			_ => None,
+		})
+		.ok_or("The `Session` pallet has no `set_keys` call")?;
+
+	let mut call = vec![pallet.index, call_index];
+	call.extend(keys);
+	proof.encode_to(&mut call);
+	Ok(call)
+}
+
+/// Encode the signed extensions of an immortal transaction without tip, returning the data
+/// included in the transaction and the additional signed data.
+fn signed_extensions(
+	metadata: &RuntimeMetadataV14,
+	version: &RuntimeVersion,
+	genesis: H256,
+	nonce: u64,
+) -> Result<(Vec<u8>, Vec<u8>), Error> {
+	let (mut extra, mut additional) = (Vec::new(), Vec::new());
+	for extension in &metadata.extrinsic.signed_extensions {
+		match extension.identifier.as_str() {
+			"CheckSpecVersion" => version.spec_version.encode_to(&mut additional),
+			"CheckTxVersion" => version.transaction_version.encode_to(&mut additional),
+			"CheckGenesis" => genesis.encode_to(&mut additional),
+			"CheckMortality" | "CheckEra" => {
+				Era::Immortal.encode_to(&mut extra);
+				genesis.encode_to(&mut additional);
+			},
+			"CheckNonce" => Compact(nonce).encode_to(&mut extra),
+			"ChargeTransactionPayment" => Compact(0u128).encode_to(&mut extra),
+			"ChargeAssetTxPayment" => (Compact(0u128), None::<()>).encode_to(&mut extra),
+			_ if is_empty(&metadata.types, extension.ty.id) &&
+				is_empty(&metadata.types, extension.additional_signed.id) => {},
+			identifier =>
+				return Err(format!("Unsupported signed extension `{}`", identifier).into()),
+		}
+	}
+	Ok((extra, additional))
+}
+
+/// Whether values of the type `id` are always encoded as no bytes.
+fn is_empty(types: &PortableRegistry, id: u32) -> bool {
+	match types.resolve(id).map(|ty| &ty.type_def) {
+		Some(TypeDef::Composite(composite)) =>
+			composite.fields.iter().all(|field| is_empty(types, field.ty.id)),
+		Some(TypeDef::Tuple(tuple)) => tuple.fields.iter().all(|field| is_empty(types, field.id)),
+		_ => false,
+	}
+}
+
+/// Encode a signed extrinsic in version 4 of the format, from an account of a runtime using
+/// `MultiAddress` and `MultiSignature`.
+fn signed_extrinsic(
+	account: AccountId32,
+	signature: MultiSignature,
+	extra: &[u8],
+	call: &[u8],
+) -> Vec<u8> {
+	let mut extrinsic = vec![0b1000_0100];
+	MultiAddress::<AccountId32, u32>::Id(account).encode_to(&mut extrinsic);
+	signature.encode_to(&mut extrinsic);
+	extrinsic.extend(extra);
+	extrinsic.extend(call);
+	extrinsic.encode()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_metadata::v14::{ExtrinsicMetadata, PalletMetadata, SignedExtensionMetadata};
+	use scale_info::{meta_type, TypeInfo};
+	use std::marker::PhantomData;
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code, non_camel_case_types)]
+	enum SessionCall {
+		#[codec(index = 3)]
+		set_keys { keys: [u8; 32], proof: Vec<u8> },
+		#[codec(index = 4)]
+		purge_keys,
+	}
+
+	#[derive(TypeInfo)]
+	struct CheckWeight(PhantomData<u32>);
+
+	fn runtime_metadata(signed_extensions: Vec<SignedExtensionMetadata>) -> RuntimeMetadataV14 {
+		let pallets = vec![PalletMetadata {
+			name: "Session",
+			storage: None,
+			calls: Some(meta_type::<SessionCall>().into()),
+			event: None,
+			constants: vec![],
+			error: None,
+			index: 9,
+		}];
+		let extrinsic = ExtrinsicMetadata { ty: meta_type::<()>(), version: 4, signed_extensions };
+		RuntimeMetadataV14::new(pallets, extrinsic, meta_type::<()>())
+	}
+
+	fn extension<Ty: TypeInfo + 'static, Additional: TypeInfo + 'static>(
+		identifier: &'static str,
+	) -> SignedExtensionMetadata {
+		SignedExtensionMetadata {
+			identifier,
+			ty: meta_type::<Ty>(),
+			additional_signed: meta_type::<Additional>(),
+		}
+	}
+
+	#[test]
+	fn set_keys_call_is_encoded() {
+		let metadata = runtime_metadata(vec![]);
+		let call = set_keys_call(&metadata, vec![1; 32], &[2; 64]).unwrap();
+		assert_eq!(call, [&[9, 3][..], &[1; 32], &[64 << 2], &[2; 64]].concat());
+	}
+
+	#[test]
+	fn signed_extensions_are_encoded() {
+		let metadata = runtime_metadata(vec![
+			extension::<CheckWeight, ()>("CheckNonZeroSender"),
+			extension::<CheckWeight, u32>("CheckSpecVersion"),
+			extension::<CheckWeight, H256>("CheckGenesis"),
+			extension::<u8, H256>("CheckMortality"),
+			extension::<Compact<u32>, ()>("CheckNonce"),
+			extension::<CheckWeight, ()>("CheckWeight"),
+			extension::<Compact<u128>, ()>("ChargeTransactionPayment"),
+		]);
+		let version = RuntimeVersion { spec_version: 7, ..Default::default() };
+		let genesis = H256::repeat_byte(1);
+
+		let (extra, additional) = signed_extensions(&metadata, &version, genesis, 5).unwrap();
+		assert_eq!(extra, vec![0, 5 << 2, 0]);
+		assert_eq!(additional, (7u32, genesis, genesis).encode());
+
+		let metadata = runtime_metadata(vec![extension::<u32, ()>("CheckUnknown")]);
+		assert!(signed_extensions(&metadata, &version, genesis, 5).is_err());
+	}
+}
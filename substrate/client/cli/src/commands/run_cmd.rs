@@ -396,6 +396,10 @@ impl CliConfiguration for RunCmd {
 		Ok(Some(self.runtime_params.max_runtime_instances))
 	}
 
+	fn min_runtime_instances(&self) -> Result<Option<usize>> {
+		Ok(Some(self.runtime_params.min_runtime_instances))
+	}
+
 	fn runtime_cache_size(&self) -> Result<u8> {
 		Ok(self.runtime_params.runtime_cache_size)
 	}
@@ -421,6 +421,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(Default::default())
 	}
 
+	/// Get minimum runtime instances
+	///
+	/// By default this is `None`.
+	fn min_runtime_instances(&self) -> Result<Option<usize>> {
+		Ok(Default::default())
+	}
+
 	/// Get maximum different runtimes in cache
 	///
 	/// By default this is `2`.
@@ -464,6 +471,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let node_key = self.node_key(&net_config_dir)?;
 		let role = self.role(is_dev)?;
 		let max_runtime_instances = self.max_runtime_instances()?.unwrap_or(8);
+		let min_runtime_instances = self.min_runtime_instances()?.unwrap_or(1);
 		let is_validator = role.is_authority();
 		let keystore = self.keystore_config(&config_dir)?;
 		let telemetry_endpoints = self.telemetry_endpoints(&chain_spec)?;
@@ -513,6 +521,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			tracing_receiver: self.tracing_receiver()?,
 			chain_spec,
 			max_runtime_instances,
+			min_runtime_instances,
 			announce_block: self.announce_block()?,
 			role,
 			base_path,
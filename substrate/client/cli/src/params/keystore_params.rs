@@ -23,6 +23,7 @@ use sp_core::crypto::SecretString;
 use std::{
 	fs,
 	path::{Path, PathBuf},
+	time::Duration,
 };
 
 /// default sub directory for the key store
@@ -56,6 +57,17 @@ pub struct KeystoreParams {
 		conflicts_with_all = &["password_interactive", "password"]
 	)]
 	pub password_filename: Option<PathBuf>,
+
+	/// Sign with the keys of a remote signer listening on the Unix domain socket at `PATH`.
+	///
+	/// The remote signer, e.g. a bridge to a Hardware Security Module, is sent JSON-RPC signing
+	/// requests. Keys it does not hold are kept in the keystore at `--keystore-path`.
+	#[arg(long, value_name = "PATH")]
+	pub keystore_remote_signer: Option<PathBuf>,
+
+	/// Timeout in milliseconds of requests to the remote signer.
+	#[arg(long, value_name = "MS", default_value_t = 2000, requires = "keystore_remote_signer")]
+	pub keystore_remote_signer_timeout: u64,
 }
 
 /// Parse a secret string, returning a displayable error.
@@ -80,7 +92,15 @@ impl KeystoreParams {
 			.clone()
 			.unwrap_or_else(|| config_dir.join(DEFAULT_KEYSTORE_CONFIG_PATH));
 
-		Ok(KeystoreConfig::Path { path, password })
+		Ok(match self.keystore_remote_signer.clone() {
+			Some(socket) => KeystoreConfig::Remote {
+				socket,
+				timeout: Duration::from_millis(self.keystore_remote_signer_timeout),
+				path,
+				password,
+			},
+			None => KeystoreConfig::Path { path, password },
+		})
 	}
 
 	/// helper method to fetch password from `KeyParams` or read from stdin
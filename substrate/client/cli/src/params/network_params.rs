@@ -20,7 +20,8 @@ use crate::{arg_enums::SyncMode, params::node_key_params::NodeKeyParams};
 use clap::Args;
 use sc_network::{
 	config::{
-		NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, SetConfig, TransportConfig,
+		BandwidthLimit, NetworkConfiguration, NodeKeyConfig, NonReservedPeerMode, SetConfig,
+		TransportConfig,
 	},
 	multiaddr::Protocol,
 };
@@ -166,6 +167,14 @@ pub struct NetworkParams {
 	/// and observe block requests timing out.
 	#[arg(long, value_name = "COUNT", default_value_t = 64)]
 	pub max_blocks_per_request: u32,
+
+	/// Limit the bandwidth used to send data to each peer on a protocol.
+	///
+	/// Given as `<PROTOCOL>=<BYTES_PER_SECOND>`, where `<PROTOCOL>` is a suffix of the protocol
+	/// names the limit applies to. E.g. `/sync/2=1048576` limits the block responses sent to each
+	/// peer to 1 MiB/s. May be given multiple times.
+	#[arg(long, value_name = "PROTOCOL=BYTES_PER_SECOND")]
+	pub bandwidth_limit: Vec<BandwidthLimit>,
 }
 
 impl NetworkParams {
@@ -261,6 +270,7 @@ impl NetworkParams {
 			yamux_window_size: None,
 			ipfs_server: self.ipfs_server,
 			sync_mode: self.sync.into(),
+			bandwidth_limits: self.bandwidth_limit.clone(),
 		}
 	}
 }
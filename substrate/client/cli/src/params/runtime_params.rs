@@ -28,6 +28,14 @@ pub struct RuntimeParams {
 	#[arg(long, default_value_t = 8, value_parser = parse_max_runtime_instances)]
 	pub max_runtime_instances: usize,
 
+	/// The number of instances each runtime's cache shrinks back to when idle.
+	///
+	/// The instances cache grows up to `--max-runtime-instances` while all of its instances are
+	/// busy, e.g. under RPC load. Instances unused for a minute are then released again, down to
+	/// this number.
+	#[arg(long, default_value_t = 1)]
+	pub min_runtime_instances: usize,
+
 	/// Maximum number of different runtimes that can be cached.
 	#[arg(long, default_value_t = 2)]
 	pub runtime_cache_size: u8,
@@ -280,6 +280,7 @@ mod tests {
 				tracing_targets: None,
 				tracing_receiver: Default::default(),
 				max_runtime_instances: 8,
+				min_runtime_instances: 1,
 				announce_block: true,
 				base_path: sc_service::BasePath::new(root.clone()),
 				data_path: root,
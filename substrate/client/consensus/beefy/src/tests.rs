@@ -56,8 +56,8 @@ use sp_consensus_beefy::{
 	known_payloads,
 	mmr::{find_mmr_root_digest, MmrRootProvider},
 	BeefyApi, Commitment, ConsensusLog, EquivocationProof, Keyring as BeefyKeyring, MmrRootHash,
-	OpaqueKeyOwnershipProof, Payload, SignedCommitment, ValidatorSet, ValidatorSetId,
-	VersionedFinalityProof, VoteMessage, BEEFY_ENGINE_ID,
+	OpaqueKeyOwnershipProof, Payload, SignatureScheme, SignedCommitment, ValidatorSet,
+	ValidatorSetId, VersionedFinalityProof, VoteMessage, BEEFY_ENGINE_ID,
 };
 use sp_core::H256;
 use sp_keystore::{testing::MemoryKeystore, Keystore, KeystorePtr};
@@ -250,6 +250,7 @@ pub(crate) struct TestApi {
 	pub beefy_genesis: u64,
 	pub validator_set: Option<BeefyValidatorSet>,
 	pub mmr_root_hash: MmrRootHash,
+	pub signature_scheme: SignatureScheme,
 	pub reported_equivocations:
 		Option<Arc<Mutex<Vec<EquivocationProof<NumberFor<Block>, AuthorityId, Signature>>>>>,
 }
@@ -264,6 +265,7 @@ impl TestApi {
 			beefy_genesis,
 			validator_set: Some(validator_set.clone()),
 			mmr_root_hash,
+			signature_scheme: SignatureScheme::Ecdsa,
 			reported_equivocations: None,
 		}
 	}
@@ -273,6 +275,7 @@ impl TestApi {
 			beefy_genesis: 1,
 			validator_set: Some(validator_set.clone()),
 			mmr_root_hash: GOOD_MMR_ROOT,
+			signature_scheme: SignatureScheme::Ecdsa,
 			reported_equivocations: None,
 		}
 	}
@@ -320,6 +323,10 @@ sp_api::mock_impl_runtime_apis! {
 			_dummy1: ValidatorSetId,
 			_dummy2: AuthorityId,
 		) -> Option<OpaqueKeyOwnershipProof> { Some(OpaqueKeyOwnershipProof::new(vec![])) }
+
+		fn signature_scheme() -> SignatureScheme {
+			self.inner.signature_scheme
+		}
 	}
 
 	impl MmrApi<Block, MmrRootHash, NumberFor<Block>> for RuntimeApi {
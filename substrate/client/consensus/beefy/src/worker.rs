@@ -36,14 +36,14 @@ use log::{debug, error, info, log_enabled, trace, warn};
 use sc_client_api::{Backend, FinalityNotification, FinalityNotifications, HeaderBackend};
 use sc_network_gossip::GossipEngine;
 use sc_utils::{mpsc::TracingUnboundedReceiver, notification::NotificationReceiver};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_arithmetic::traits::{AtLeast32Bit, Saturating};
 use sp_consensus::SyncOracle;
 use sp_consensus_beefy::{
 	check_equivocation_proof,
 	ecdsa_crypto::{AuthorityId, Signature},
-	BeefyApi, Commitment, ConsensusLog, EquivocationProof, PayloadProvider, ValidatorSet,
-	VersionedFinalityProof, VoteMessage, BEEFY_ENGINE_ID,
+	BeefyApi, Commitment, ConsensusLog, EquivocationProof, PayloadProvider, SignatureScheme,
+	ValidatorSet, ValidatorSetId, VersionedFinalityProof, VoteMessage, BEEFY_ENGINE_ID,
 };
 use sp_runtime::{
 	generic::{BlockId, OpaqueDigestItemId},
@@ -478,6 +478,12 @@ where
 					self.init_session_at(new_validator_set, *header.number());
 					new_session_added = true;
 				}
+				if let Some((id, scheme)) = find_signature_scheme_change::<B>(&header) {
+					info!(
+						target: LOG_TARGET,
+						"🥩 Validator set {} commits with {:?} signatures", id, scheme
+					);
+				}
 			}
 
 			if new_session_added {
@@ -697,6 +703,21 @@ where
 		Ok(())
 	}
 
+	/// Return the signature scheme the validator set active at `hash` commits with.
+	///
+	/// Runtimes which don't expose the signature scheme commit with ECDSA.
+	fn signature_scheme_at(&self, hash: B::Hash) -> SignatureScheme {
+		let runtime_api = self.runtime.runtime_api();
+		match runtime_api.api_version::<dyn BeefyApi<B, AuthorityId>>(hash) {
+			Ok(Some(version)) if version >= 4 =>
+				runtime_api.signature_scheme(hash).unwrap_or_else(|err| {
+					debug!(target: LOG_TARGET, "🥩 Couldn't get signature scheme: {:?}", err);
+					SignatureScheme::Ecdsa
+				}),
+			_ => SignatureScheme::Ecdsa,
+		}
+	}
+
 	/// Create and gossip Signed Commitment for block number `target_number`.
 	///
 	/// Also handle this self vote by calling `self.handle_vote()` for it.
@@ -730,6 +751,18 @@ where
 		};
 		let target_hash = target_header.hash();
 
+		// The voter only signs with ECDSA keys.
+		let signature_scheme = self.signature_scheme_at(target_hash);
+		if signature_scheme != SignatureScheme::Ecdsa {
+			warn!(
+				target: LOG_TARGET,
+				"🥩 Can't sign {:?} commitments, skipping vote on: {:?}",
+				signature_scheme,
+				target_hash
+			);
+			return Ok(())
+		}
+
 		let payload = if let Some(hash) = self.payload_provider.payload(&target_header) {
 			hash
 		} else {
@@ -1022,6 +1055,24 @@ where
 	header.digest().convert_first(|l| l.try_to(id).and_then(filter))
 }
 
+/// Scan the `header` digest log for a BEEFY signature scheme change. Return the id of the
+/// validator set and the signature scheme it commits with, or `None` in case no signature scheme
+/// change has been signaled.
+pub(crate) fn find_signature_scheme_change<B>(
+	header: &B::Header,
+) -> Option<(ValidatorSetId, SignatureScheme)>
+where
+	B: Block,
+{
+	let id = OpaqueDigestItemId::Consensus(&BEEFY_ENGINE_ID);
+
+	let filter = |log: ConsensusLog<AuthorityId>| match log {
+		ConsensusLog::SignatureSchemeChange(set_id, scheme) => Some((set_id, scheme)),
+		_ => None,
+	};
+	header.digest().convert_first(|l| l.try_to(id).and_then(filter))
+}
+
 /// Calculate next block number to vote on.
 ///
 /// Return `None` if there is no voteable target yet.
@@ -1462,6 +1513,46 @@ pub(crate) mod tests {
 		assert_eq!(extracted, Some(validator_set));
 	}
 
+	#[test]
+	fn extract_signature_scheme_change_digest() {
+		let mut header = Header::new(
+			1u32.into(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Digest::default(),
+		);
+
+		// verify empty digest shows nothing
+		assert!(find_signature_scheme_change::<Block>(&header).is_none());
+
+		header.digest_mut().push(DigestItem::Consensus(
+			BEEFY_ENGINE_ID,
+			ConsensusLog::<AuthorityId>::SignatureSchemeChange(42, SignatureScheme::EcdsaBls)
+				.encode(),
+		));
+
+		// verify signature scheme is correctly extracted from digest
+		let extracted = find_signature_scheme_change::<Block>(&header);
+		assert_eq!(extracted, Some((42, SignatureScheme::EcdsaBls)));
+	}
+
+	#[tokio::test]
+	async fn should_get_signature_scheme_from_runtime() {
+		let keys = &[Keyring::Alice];
+		let validator_set = ValidatorSet::new(make_beefy_ids(keys), 0).unwrap();
+		let mut net = BeefyTestNet::new(1);
+		let mut worker = create_beefy_worker(net.peer(0), &keys[0], 1, validator_set.clone());
+		let hash = net.peer(0).client().info().genesis_hash;
+
+		assert_eq!(worker.signature_scheme_at(hash), SignatureScheme::Ecdsa);
+
+		let mut api = TestApi::with_validator_set(&validator_set);
+		api.signature_scheme = SignatureScheme::EcdsaBls;
+		worker.runtime = Arc::new(api);
+		assert_eq!(worker.signature_scheme_at(hash), SignatureScheme::EcdsaBls);
+	}
+
 	#[tokio::test]
 	async fn keystore_vs_validator_set() {
 		let keys = &[Keyring::Alice];
@@ -18,6 +18,7 @@
 
 use crate::{
 	error::{Error, Result},
+	metrics::Metrics,
 	wasm_runtime::{RuntimeCache, WasmExecutionMethod},
 	RuntimeVersionOf,
 };
@@ -30,6 +31,7 @@ use std::{
 };
 
 use codec::Encode;
+use prometheus_endpoint::Registry;
 use sc_executor_common::{
 	runtime_blob::RuntimeBlob,
 	wasm_runtime::{
@@ -90,9 +92,11 @@ pub struct WasmExecutorBuilder<H> {
 	offchain_heap_alloc_strategy: Option<HeapAllocStrategy>,
 	ignore_onchain_heap_pages: bool,
 	max_runtime_instances: usize,
+	min_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
 	allow_missing_host_functions: bool,
 	runtime_cache_size: u8,
+	prometheus_registry: Option<Registry>,
 }
 
 impl<H> WasmExecutorBuilder<H> {
@@ -107,9 +111,11 @@ impl<H> WasmExecutorBuilder<H> {
 			offchain_heap_alloc_strategy: None,
 			ignore_onchain_heap_pages: false,
 			max_runtime_instances: 2,
+			min_runtime_instances: 1,
 			runtime_cache_size: 4,
 			allow_missing_host_functions: false,
 			cache_path: None,
+			prometheus_registry: None,
 		}
 	}
 
@@ -158,6 +164,17 @@ impl<H> WasmExecutorBuilder<H> {
 		self
 	}
 
+	/// Create the wasm executor with the given minimum number of `instances`.
+	///
+	/// The instances cache of a runtime grows up to the maximum number of instances when all of
+	/// them are busy, and releases instances that were idle for a while down to this minimum.
+	///
+	/// By default the minimum number of `instances` is `1`.
+	pub fn with_min_runtime_instances(mut self, instances: usize) -> Self {
+		self.min_runtime_instances = instances;
+		self
+	}
+
 	/// Create the wasm executor with the given `cache_path`.
 	///
 	/// The `cache_path` is A path to a directory where the executor can place its files for
@@ -193,8 +210,30 @@ impl<H> WasmExecutorBuilder<H> {
 		self
 	}
 
+	/// Create the wasm executor with the given Prometheus `registry`.
+	///
+	/// The metrics of the instances caches are registered with it.
+	///
+	/// By default no metrics are registered.
+	pub fn with_prometheus_registry(mut self, registry: Registry) -> Self {
+		self.prometheus_registry = Some(registry);
+		self
+	}
+
 	/// Build the configured [`WasmExecutor`].
 	pub fn build(self) -> WasmExecutor<H> {
+		let metrics = self.prometheus_registry.and_then(|registry| {
+			Metrics::register(&registry)
+				.map_err(|e| {
+					tracing::warn!(
+						target: "wasm-runtime",
+						error = %e,
+						"Failed to register the metrics of the executor",
+					)
+				})
+				.ok()
+		});
+
 		WasmExecutor {
 			method: self.method,
 			default_offchain_heap_alloc_strategy: unwrap_heap_pages(
@@ -206,8 +245,10 @@ impl<H> WasmExecutorBuilder<H> {
 			ignore_onchain_heap_pages: self.ignore_onchain_heap_pages,
 			cache: Arc::new(RuntimeCache::new(
 				self.max_runtime_instances,
+				self.min_runtime_instances,
 				self.cache_path.clone(),
 				self.runtime_cache_size,
+				metrics,
 			)),
 			cache_path: self.cache_path,
 			allow_missing_host_functions: self.allow_missing_host_functions,
@@ -292,9 +333,11 @@ where
 			),
 			ignore_onchain_heap_pages: false,
 			cache: Arc::new(RuntimeCache::new(
+				max_runtime_instances,
 				max_runtime_instances,
 				cache_path.clone(),
 				runtime_cache_size,
+				None,
 			)),
 			cache_path,
 			allow_missing_host_functions: false,
@@ -35,6 +35,7 @@
 mod executor;
 #[cfg(test)]
 mod integration_tests;
+mod metrics;
 mod wasm_runtime;
 
 pub use self::{
@@ -71,6 +72,7 @@ mod tests {
 	use super::*;
 	use sc_executor_common::runtime_blob::RuntimeBlob;
 	use sc_runtime_test::wasm_binary_unwrap;
+	use sp_core::traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode};
 	use sp_io::TestExternalities;
 
 	#[test]
@@ -90,4 +92,45 @@ mod tests {
 			.unwrap();
 		assert_eq!(res, vec![0u8; 0]);
 	}
+
+	#[test]
+	fn instance_pool_metrics_work() {
+		let mut ext = TestExternalities::default();
+		let mut ext = ext.ext();
+
+		let registry = prometheus_endpoint::Registry::new();
+		let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+			.with_prometheus_registry(registry.clone())
+			.build();
+		let code_fetcher = WrappedRuntimeCode(wasm_binary_unwrap().into());
+		let runtime_code =
+			RuntimeCode { code_fetcher: &code_fetcher, heap_pages: None, hash: vec![1] };
+
+		for _ in 0..3 {
+			let (res, _) = executor.call(
+				&mut ext,
+				&runtime_code,
+				"test_empty_return",
+				&[],
+				false,
+				CallContext::Offchain,
+			);
+			assert_eq!(res.unwrap(), vec![0u8; 0]);
+		}
+
+		let value = |name: &str| {
+			let family = registry.gather().into_iter().find(|f| f.get_name() == name).unwrap();
+			let metric = &family.get_metric()[0];
+			if metric.has_counter() {
+				metric.get_counter().get_value()
+			} else {
+				metric.get_gauge().get_value()
+			}
+		};
+		// the first call allocates the instance that the others reuse.
+		assert_eq!(value("substrate_wasm_instance_allocations_total"), 1.0);
+		assert_eq!(value("substrate_wasm_instance_reuses_total"), 2.0);
+		assert_eq!(value("substrate_wasm_instance_misses_total"), 0.0);
+		assert_eq!(value("substrate_wasm_pooled_instances"), 1.0);
+	}
 }
@@ -0,0 +1,69 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Metrics of the runtime instance pools of the executor.
+
+use prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+/// Prometheus metrics of the runtime instance pools.
+#[derive(Clone)]
+pub(crate) struct Metrics {
+	/// Calls served by an instance that was already in the pool.
+	pub instance_reuses: Counter<U64>,
+	/// Instances created to be kept in the pool.
+	pub instance_allocations: Counter<U64>,
+	/// Calls served by a throwaway instance, because the pool was exhausted.
+	pub instance_misses: Counter<U64>,
+	/// Instances currently kept in the pools.
+	pub pooled_instances: Gauge<U64>,
+}
+
+impl Metrics {
+	pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			instance_reuses: register(
+				Counter::new(
+					"substrate_wasm_instance_reuses_total",
+					"Number of runtime calls served by a pooled wasm instance",
+				)?,
+				registry,
+			)?,
+			instance_allocations: register(
+				Counter::new(
+					"substrate_wasm_instance_allocations_total",
+					"Number of wasm instances created for the instance pool",
+				)?,
+				registry,
+			)?,
+			instance_misses: register(
+				Counter::new(
+					"substrate_wasm_instance_misses_total",
+					"Number of runtime calls served by a throwaway wasm instance",
+				)?,
+				registry,
+			)?,
+			pooled_instances: register(
+				Gauge::new(
+					"substrate_wasm_pooled_instances",
+					"Number of wasm instances kept in the instance pools",
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
@@ -21,10 +21,13 @@
 //! The primary means of accessing the runtimes is through a cache which saves the reusable
 //! components of the runtime that are expensive to initialize.
 
-use crate::error::{Error, WasmError};
+use crate::{
+	error::{Error, WasmError},
+	metrics::Metrics,
+};
 
 use codec::Decode;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, MutexGuard};
 use sc_executor_common::{
 	runtime_blob::RuntimeBlob,
 	wasm_runtime::{HeapAllocStrategy, WasmInstance, WasmModule},
@@ -37,9 +40,16 @@ use sp_wasm_interface::HostFunctions;
 use std::{
 	panic::AssertUnwindSafe,
 	path::{Path, PathBuf},
-	sync::Arc,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
 };
 
+/// How long a pooled instance has to stay unused before the pool shrinks.
+const INSTANCE_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Specification of different methods of executing the runtime Wasm code.
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum WasmExecutionMethod {
@@ -77,8 +87,23 @@ struct VersionedRuntime {
 
 	// TODO: Remove this once the legacy instance reuse instantiation strategy
 	//       for `wasmtime` is gone, as this only makes sense with that particular strategy.
-	/// Cached instance pool.
-	instances: Vec<Mutex<Option<Box<dyn WasmInstance>>>>,
+	/// Cached instance pool, with one slot per instance up to the maximum size of the pool.
+	instances: Vec<Mutex<Option<PooledInstance>>>,
+	/// The number of slots of `instances` currently in use.
+	///
+	/// Grows when all the instances of the pool are busy and shrinks down to `min_instances`
+	/// when the last instance has been idle for [`INSTANCE_IDLE_TIMEOUT`].
+	pool_size: AtomicUsize,
+	/// The minimum size of the pool.
+	min_instances: usize,
+	metrics: Option<Metrics>,
+}
+
+/// An instance kept in the pool of a [`VersionedRuntime`].
+struct PooledInstance {
+	instance: Box<dyn WasmInstance>,
+	/// When the instance was last returned to the pool.
+	last_used: Instant,
 }
 
 impl VersionedRuntime {
@@ -92,19 +117,26 @@ impl VersionedRuntime {
 			&mut dyn Externalities,
 		) -> Result<R, Error>,
 	{
-		// Find a free instance
-		let instance = self
-			.instances
+		// Find a free instance, growing the pool if all of them are busy.
+		let pool_size = self.pool_size.load(Ordering::Relaxed);
+		let instance = self.instances[..pool_size]
 			.iter()
 			.enumerate()
-			.find_map(|(index, i)| i.try_lock().map(|i| (index, i)));
+			.find_map(|(index, i)| i.try_lock().map(|i| (index, i)))
+			.or_else(|| self.grow(pool_size));
 
-		match instance {
+		let result = match instance {
 			Some((index, mut locked)) => {
-				let (mut instance, new_inst) = locked
-					.take()
-					.map(|r| Ok((r, false)))
-					.unwrap_or_else(|| self.module.new_instance().map(|i| (i, true)))?;
+				let (mut instance, new_inst) = match locked.take() {
+					Some(pooled) => {
+						if let Some(metrics) = &self.metrics {
+							metrics.instance_reuses.inc();
+							metrics.pooled_instances.dec();
+						}
+						(pooled.instance, false)
+					},
+					None => (self.module.new_instance()?, true),
+				};
 
 				let result = f(&*self.module, &mut *instance, self.version.as_ref(), ext);
 				if let Err(e) = &result {
@@ -122,9 +154,15 @@ impl VersionedRuntime {
 						);
 					}
 				} else {
-					*locked = Some(instance);
+					*locked = Some(PooledInstance { instance, last_used: Instant::now() });
+					if let Some(metrics) = &self.metrics {
+						metrics.pooled_instances.inc();
+					}
 
 					if new_inst {
+						if let Some(metrics) = &self.metrics {
+							metrics.instance_allocations.inc();
+						}
 						tracing::debug!(
 							target: "wasm-runtime",
 							"Allocated WASM instance {}/{}",
@@ -138,12 +176,69 @@ impl VersionedRuntime {
 			},
 			None => {
 				tracing::warn!(target: "wasm-runtime", "Ran out of free WASM instances");
+				if let Some(metrics) = &self.metrics {
+					metrics.instance_misses.inc();
+				}
 
 				// Allocate a new instance
 				let mut instance = self.module.new_instance()?;
 
 				f(&*self.module, &mut *instance, self.version.as_ref(), ext)
 			},
+		};
+
+		self.shrink_if_idle();
+		result
+	}
+
+	/// Add a slot to the pool of `pool_size` slots, unless it has reached its maximum size.
+	fn grow(&self, pool_size: usize) -> Option<(usize, MutexGuard<Option<PooledInstance>>)> {
+		if pool_size >= self.instances.len() {
+			return None
+		}
+		self.pool_size
+			.compare_exchange(pool_size, pool_size + 1, Ordering::Relaxed, Ordering::Relaxed)
+			.ok()?;
+		self.instances[pool_size].try_lock().map(|locked| (pool_size, locked))
+	}
+
+	/// Release the last slot of the pool if its instance has been idle for
+	/// [`INSTANCE_IDLE_TIMEOUT`], unless the pool has reached its minimum size.
+	fn shrink_if_idle(&self) {
+		let pool_size = self.pool_size.load(Ordering::Relaxed);
+		if pool_size <= self.min_instances {
+			return
+		}
+		let Some(mut last) = self.instances[pool_size - 1].try_lock() else { return };
+		let idle = last.as_ref().map_or(true, |i| i.last_used.elapsed() >= INSTANCE_IDLE_TIMEOUT);
+		if !idle {
+			return
+		}
+		let shrunk = self.pool_size.compare_exchange(
+			pool_size,
+			pool_size - 1,
+			Ordering::Relaxed,
+			Ordering::Relaxed,
+		);
+		if shrunk.is_ok() && last.take().is_some() {
+			if let Some(metrics) = &self.metrics {
+				metrics.pooled_instances.dec();
+			}
+			tracing::debug!(
+				target: "wasm-runtime",
+				"Released idle WASM instance {}/{}",
+				pool_size,
+				self.instances.len(),
+			);
+		}
+	}
+}
+
+impl Drop for VersionedRuntime {
+	fn drop(&mut self) {
+		if let Some(metrics) = &self.metrics {
+			let pooled = self.instances.iter_mut().filter(|i| i.get_mut().is_some()).count();
+			metrics.pooled_instances.sub(pooled as u64);
 		}
 	}
 }
@@ -164,29 +259,46 @@ pub struct RuntimeCache {
 	///
 	/// Runtimes sorted by recent usage. The most recently used is at the front.
 	runtimes: Mutex<LruMap<VersionedRuntimeId, Arc<VersionedRuntime>>>,
-	/// The size of the instances cache for each runtime.
+	/// The maximum size of the instances cache for each runtime.
 	max_runtime_instances: usize,
+	/// The size the instances cache of each runtime shrinks down to when idle.
+	min_runtime_instances: usize,
 	cache_path: Option<PathBuf>,
+	metrics: Option<Metrics>,
 }
 
 impl RuntimeCache {
 	/// Creates a new instance of a runtimes cache.
 	///
-	/// `max_runtime_instances` specifies the number of instances per runtime preserved in an
-	/// in-memory cache.
+	/// `max_runtime_instances` specifies the maximum number of instances per runtime preserved in
+	/// an in-memory cache. The cache of a runtime grows up to this number when all its instances
+	/// are busy.
+	///
+	/// `min_runtime_instances` specifies the number of instances per runtime the cache shrinks
+	/// down to when its instances are idle, capped by `max_runtime_instances`.
 	///
 	/// `cache_path` allows to specify an optional directory where the executor can store files
 	/// for caching.
 	///
 	/// `runtime_cache_size` specifies the number of different runtimes versions preserved in an
 	/// in-memory cache, must always be at least 1.
-	pub fn new(
+	///
+	/// `metrics` are updated with the usage of the instances caches, if given.
+	pub(crate) fn new(
 		max_runtime_instances: usize,
+		min_runtime_instances: usize,
 		cache_path: Option<PathBuf>,
 		runtime_cache_size: u8,
+		metrics: Option<Metrics>,
 	) -> RuntimeCache {
 		let cap = ByLength::new(runtime_cache_size.max(1) as u32);
-		RuntimeCache { runtimes: Mutex::new(LruMap::new(cap)), max_runtime_instances, cache_path }
+		RuntimeCache {
+			runtimes: Mutex::new(LruMap::new(cap)),
+			max_runtime_instances,
+			min_runtime_instances: min_runtime_instances.min(max_runtime_instances),
+			cache_path,
+			metrics,
+		}
 	}
 
 	/// Prepares a WASM module instance and executes given function for it.
@@ -254,7 +366,9 @@ impl RuntimeCache {
 				heap_alloc_strategy,
 				allow_missing_func_imports,
 				self.max_runtime_instances,
+				self.min_runtime_instances,
 				self.cache_path.as_deref(),
+				self.metrics.clone(),
 			);
 
 			match result {
@@ -384,7 +498,9 @@ fn create_versioned_wasm_runtime<H>(
 	heap_alloc_strategy: HeapAllocStrategy,
 	allow_missing_func_imports: bool,
 	max_instances: usize,
+	min_instances: usize,
 	cache_path: Option<&Path>,
+	metrics: Option<Metrics>,
 ) -> Result<VersionedRuntime, WasmError>
 where
 	H: HostFunctions,
@@ -432,7 +548,14 @@ where
 	let mut instances = Vec::with_capacity(max_instances);
 	instances.resize_with(max_instances, || Mutex::new(None));
 
-	Ok(VersionedRuntime { module: runtime, version, instances })
+	Ok(VersionedRuntime {
+		module: runtime,
+		version,
+		instances,
+		pool_size: AtomicUsize::new(min_instances),
+		min_instances,
+		metrics,
+	})
 }
 
 #[cfg(test)]
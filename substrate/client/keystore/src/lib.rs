@@ -26,6 +26,11 @@ use std::io;
 /// Local keystore implementation
 mod local;
 pub use local::LocalKeystore;
+/// Keystore delegating signing to a remote signer
+#[cfg(unix)]
+mod remote;
+#[cfg(unix)]
+pub use remote::{RemoteKeystore, PUBLIC_KEYS_CACHE_TTL};
 pub use sp_keystore::Keystore;
 
 /// Keystore error.
@@ -55,6 +60,9 @@ pub enum Error {
 	/// Keystore unavailable
 	#[error("Keystore unavailable")]
 	Unavailable,
+	/// Remote signer error
+	#[error("Remote signer error: {0}")]
+	RemoteSigner(String),
 }
 
 /// Keystore Result
@@ -69,6 +77,7 @@ impl From<Error> for TraitError {
 			Error::Unavailable => TraitError::Unavailable,
 			Error::Io(e) => TraitError::Other(e.to_string()),
 			Error::Json(e) => TraitError::Other(e.to_string()),
+			Error::RemoteSigner(e) => TraitError::Other(e),
 		}
 	}
 }
@@ -0,0 +1,740 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keystore delegating signing to a remote signer.
+//!
+//! The remote signer, e.g. a bridge to a Hardware Security Module or to a Key Management Service,
+//! is reached over a Unix domain socket and speaks JSON-RPC 2.0, one request or response per line.
+//! Key types, public keys, messages and signatures are hex encoded and schemes are one of
+//! `"sr25519"`, `"ed25519"` or `"ecdsa"`. The following methods must be supported:
+//!
+//! - `signer_publicKeys { keyType, scheme }`: the list of the public keys held by the signer.
+//! - `signer_sign { keyType, scheme, public, message }`: the signature of `message`, or `null` if
+//!   the signer does not hold `public`.
+//! - `signer_signPrehashed { keyType, scheme, public, message }`: as `signer_sign`, for an
+//!   `ecdsa` signature of a 32 bytes prehashed `message`.
+//! - `signer_generate { keyType, scheme }`: the public key of a newly generated key.
+//!
+//! Anything the remote signer is not able to do, e.g. VRF signing or storing keys given by their
+//! secret URI, is done by a [`LocalKeystore`].
+
+use parking_lot::{Mutex, RwLock};
+use serde_json::{json, Value};
+use sp_core::{
+	crypto::{ByteArray, CryptoTypeId, KeyTypeId},
+	ecdsa, ed25519, sr25519, Decode,
+};
+use sp_keystore::{Error as TraitError, Keystore};
+use std::{
+	collections::HashMap,
+	io::{self, BufRead, BufReader, Write},
+	os::unix::net::UnixStream,
+	path::PathBuf,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+sp_keystore::bandersnatch_experimental_enabled! {
+use sp_core::bandersnatch;
+}
+
+sp_keystore::bls_experimental_enabled! {
+use sp_core::{bls377, bls381, ecdsa_bls377};
+}
+
+use crate::{Error, LocalKeystore, Result};
+
+const LOG_TARGET: &str = "keystore::remote";
+
+/// For how long the public keys held by the remote signer are cached.
+pub const PUBLIC_KEYS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// The signature schemes supported by remote signers.
+const REMOTE_SCHEMES: [CryptoTypeId; 3] =
+	[sr25519::CRYPTO_ID, ed25519::CRYPTO_ID, ecdsa::CRYPTO_ID];
+
+/// A keystore signing with the keys of a remote signer, see the [module](self) documentation.
+///
+/// The public keys of the remote signer are cached for [`PUBLIC_KEYS_CACHE_TTL`], and requests
+/// which are not answered within the configured timeout fail.
+pub struct RemoteKeystore {
+	socket: PathBuf,
+	timeout: Duration,
+	connection: Mutex<Option<BufReader<UnixStream>>>,
+	next_id: AtomicU64,
+	public_keys: RwLock<HashMap<(KeyTypeId, CryptoTypeId), (Instant, Vec<Vec<u8>>)>>,
+	local: Arc<LocalKeystore>,
+}
+
+impl RemoteKeystore {
+	/// Connect to the remote signer listening on `socket`, falling back to `local` for anything
+	/// the remote signer can not do.
+	pub fn open<T: Into<PathBuf>>(
+		socket: T,
+		timeout: Duration,
+		local: Arc<LocalKeystore>,
+	) -> Result<Self> {
+		let keystore = Self {
+			socket: socket.into(),
+			timeout,
+			connection: Mutex::new(None),
+			next_id: AtomicU64::new(0),
+			public_keys: RwLock::new(HashMap::new()),
+			local,
+		};
+		*keystore.connection.lock() = Some(keystore.connect()?);
+		Ok(keystore)
+	}
+
+	fn connect(&self) -> Result<BufReader<UnixStream>> {
+		let stream = UnixStream::connect(&self.socket)?;
+		stream.set_read_timeout(Some(self.timeout))?;
+		stream.set_write_timeout(Some(self.timeout))?;
+		Ok(BufReader::new(stream))
+	}
+
+	/// Send a request to the remote signer and wait for its result.
+	fn request(&self, method: &str, params: Value) -> Result<Value> {
+		let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+		let mut request =
+			json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }).to_string();
+		request.push('\n');
+
+		let mut connection = self.connection.lock();
+		if connection.is_none() {
+			*connection = Some(self.connect()?);
+		}
+		let stream = connection.as_mut().expect("Connected above; qed");
+		let mut exchange = || -> Result<Value> {
+			stream.get_mut().write_all(request.as_bytes())?;
+			let mut line = String::new();
+			if stream.read_line(&mut line)? == 0 {
+				return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+			}
+			Ok(serde_json::from_str(&line)?)
+		};
+		let mut response = match exchange() {
+			Ok(response) if response.get("id") == Some(&json!(id)) => response,
+			Ok(_) => {
+				*connection = None;
+				return Err(Error::RemoteSigner("Unexpected response".into()))
+			},
+			Err(e) => {
+				// A late response would be taken for the response to the next request.
+				*connection = None;
+				return Err(e)
+			},
+		};
+
+		if let Some(error) = response.get("error") {
+			return Err(Error::RemoteSigner(error.to_string()))
+		}
+		Ok(response.get_mut("result").map(Value::take).unwrap_or_default())
+	}
+
+	/// The raw public keys of `scheme` and `key_type` held by the remote signer.
+	///
+	/// If the remote signer can not be reached, the keys it was last known to hold are returned.
+	fn remote_keys(&self, key_type: KeyTypeId, scheme: CryptoTypeId) -> Vec<Vec<u8>> {
+		if let Some((fetched, keys)) = self.public_keys.read().get(&(key_type, scheme)) {
+			if fetched.elapsed() < PUBLIC_KEYS_CACHE_TTL {
+				return keys.clone()
+			}
+		}
+
+		let keys = self.request("signer_publicKeys", params(key_type, scheme)).and_then(|keys| {
+			keys.as_array()
+				.ok_or_else(|| Error::RemoteSigner(format!("Invalid public keys: {}", keys)))?
+				.iter()
+				.map(from_hex)
+				.collect::<Result<Vec<_>>>()
+		});
+		match keys {
+			Ok(keys) => {
+				self.public_keys.write().insert((key_type, scheme), (Instant::now(), keys.clone()));
+				keys
+			},
+			Err(e) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to fetch the public keys from the remote signer: {}",
+					e,
+				);
+				self.public_keys
+					.read()
+					.get(&(key_type, scheme))
+					.map(|(_, keys)| keys.clone())
+					.unwrap_or_default()
+			},
+		}
+	}
+
+	/// The public keys of `key_type` held by the remote signer or by the local keystore.
+	fn public_keys<P: ByteArray + PartialEq>(
+		&self,
+		key_type: KeyTypeId,
+		scheme: CryptoTypeId,
+		mut keys: Vec<P>,
+	) -> Vec<P> {
+		for key in self.remote_keys(key_type, scheme) {
+			match P::from_slice(&key) {
+				Ok(key) if !keys.contains(&key) => keys.push(key),
+				Ok(_) => {},
+				Err(_) => log::warn!(
+					target: LOG_TARGET,
+					"Ignoring invalid public key {} of the remote signer",
+					to_hex(&key),
+				),
+			}
+		}
+		keys
+	}
+
+	/// Generate a new key of `scheme` and `key_type` on the remote signer.
+	fn generate_new<P: ByteArray>(
+		&self,
+		key_type: KeyTypeId,
+		scheme: CryptoTypeId,
+	) -> std::result::Result<P, TraitError> {
+		let public = from_hex(&self.request("signer_generate", params(key_type, scheme))?)?;
+		self.public_keys.write().remove(&(key_type, scheme));
+		P::from_slice(&public).map_err(|_| {
+			Error::RemoteSigner(format!("Invalid public key: {}", to_hex(&public))).into()
+		})
+	}
+
+	/// Sign `msg` with the key `public` of the remote signer.
+	///
+	/// Returns `None` if the remote signer does not hold `public`.
+	fn sign<S: Decode>(
+		&self,
+		method: &str,
+		key_type: KeyTypeId,
+		scheme: CryptoTypeId,
+		public: &[u8],
+		msg: &[u8],
+	) -> std::result::Result<Option<S>, TraitError> {
+		if !self.remote_keys(key_type, scheme).iter().any(|key| key == public) {
+			return Ok(None)
+		}
+
+		let mut params = params(key_type, scheme);
+		params["public"] = to_hex(public).into();
+		params["message"] = to_hex(msg).into();
+		let signature = self.request(method, params)?;
+		if signature.is_null() {
+			return Ok(None)
+		}
+
+		let signature = from_hex(&signature)?;
+		let mut input = &signature[..];
+		match S::decode(&mut input) {
+			Ok(signature) if input.is_empty() => Ok(Some(signature)),
+			_ => Err(Error::RemoteSigner(format!("Invalid signature: {}", to_hex(&signature)))
+				.into()),
+		}
+	}
+}
+
+fn params(key_type: KeyTypeId, scheme: CryptoTypeId) -> Value {
+	let scheme = match scheme {
+		sr25519::CRYPTO_ID => "sr25519",
+		ed25519::CRYPTO_ID => "ed25519",
+		_ => "ecdsa",
+	};
+	json!({ "keyType": to_hex(&key_type.0), "scheme": scheme })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	array_bytes::bytes2hex("0x", bytes)
+}
+
+fn from_hex(value: &Value) -> Result<Vec<u8>> {
+	value
+		.as_str()
+		.and_then(|hex| array_bytes::hex2bytes(hex).ok())
+		.ok_or_else(|| Error::RemoteSigner(format!("Invalid hex string: {}", value)))
+}
+
+impl Keystore for RemoteKeystore {
+	/// Insert a key into the local keystore, remote signers do not accept secret keys.
+	fn insert(
+		&self,
+		key_type: KeyTypeId,
+		suri: &str,
+		public: &[u8],
+	) -> std::result::Result<(), ()> {
+		self.local.insert(key_type, suri, public)
+	}
+
+	fn keys(&self, key_type: KeyTypeId) -> std::result::Result<Vec<Vec<u8>>, TraitError> {
+		let mut keys = self.local.keys(key_type)?;
+		for scheme in REMOTE_SCHEMES {
+			for key in self.remote_keys(key_type, scheme) {
+				if !keys.contains(&key) {
+					keys.push(key);
+				}
+			}
+		}
+		Ok(keys)
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		public_keys.iter().all(|(p, t)| {
+			REMOTE_SCHEMES.iter().any(|scheme| self.remote_keys(*t, *scheme).contains(p)) ||
+				self.local.has_keys(&[(p.clone(), *t)])
+		})
+	}
+
+	fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+		self.public_keys(key_type, sr25519::CRYPTO_ID, self.local.sr25519_public_keys(key_type))
+	}
+
+	/// Generate a new pair compatible with the 'sr25519' signature scheme.
+	///
+	/// If `[seed]` is `Some` then the key will be ephemeral and stored in the local keystore,
+	/// otherwise it is generated by the remote signer.
+	fn sr25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<sr25519::Public, TraitError> {
+		match seed {
+			Some(_) => self.local.sr25519_generate_new(key_type, seed),
+			None => self.generate_new(key_type, sr25519::CRYPTO_ID),
+		}
+	}
+
+	fn sr25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<sr25519::Signature>, TraitError> {
+		match self.sign("signer_sign", key_type, sr25519::CRYPTO_ID, public.as_slice(), msg)? {
+			Some(signature) => Ok(Some(signature)),
+			None => self.local.sr25519_sign(key_type, public, msg),
+		}
+	}
+
+	/// VRF signing is done by the local keystore, the data to sign can not be sent to remote
+	/// signers.
+	fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		data: &sr25519::vrf::VrfSignData,
+	) -> std::result::Result<Option<sr25519::vrf::VrfSignature>, TraitError> {
+		self.local.sr25519_vrf_sign(key_type, public, data)
+	}
+
+	fn sr25519_vrf_output(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		input: &sr25519::vrf::VrfInput,
+	) -> std::result::Result<Option<sr25519::vrf::VrfOutput>, TraitError> {
+		self.local.sr25519_vrf_output(key_type, public, input)
+	}
+
+	fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+		self.public_keys(key_type, ed25519::CRYPTO_ID, self.local.ed25519_public_keys(key_type))
+	}
+
+	/// Generate a new pair compatible with the 'ed25519' signature scheme.
+	///
+	/// If `[seed]` is `Some` then the key will be ephemeral and stored in the local keystore,
+	/// otherwise it is generated by the remote signer.
+	fn ed25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ed25519::Public, TraitError> {
+		match seed {
+			Some(_) => self.local.ed25519_generate_new(key_type, seed),
+			None => self.generate_new(key_type, ed25519::CRYPTO_ID),
+		}
+	}
+
+	fn ed25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ed25519::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ed25519::Signature>, TraitError> {
+		match self.sign("signer_sign", key_type, ed25519::CRYPTO_ID, public.as_slice(), msg)? {
+			Some(signature) => Ok(Some(signature)),
+			None => self.local.ed25519_sign(key_type, public, msg),
+		}
+	}
+
+	fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+		self.public_keys(key_type, ecdsa::CRYPTO_ID, self.local.ecdsa_public_keys(key_type))
+	}
+
+	/// Generate a new pair compatible with the 'ecdsa' signature scheme.
+	///
+	/// If `[seed]` is `Some` then the key will be ephemeral and stored in the local keystore,
+	/// otherwise it is generated by the remote signer.
+	fn ecdsa_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> std::result::Result<ecdsa::Public, TraitError> {
+		match seed {
+			Some(_) => self.local.ecdsa_generate_new(key_type, seed),
+			None => self.generate_new(key_type, ecdsa::CRYPTO_ID),
+		}
+	}
+
+	fn ecdsa_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		match self.sign("signer_sign", key_type, ecdsa::CRYPTO_ID, public.as_slice(), msg)? {
+			Some(signature) => Ok(Some(signature)),
+			None => self.local.ecdsa_sign(key_type, public, msg),
+		}
+	}
+
+	fn ecdsa_sign_prehashed(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> std::result::Result<Option<ecdsa::Signature>, TraitError> {
+		let method = "signer_signPrehashed";
+		match self.sign(method, key_type, ecdsa::CRYPTO_ID, public.as_slice(), msg)? {
+			Some(signature) => Ok(Some(signature)),
+			None => self.local.ecdsa_sign_prehashed(key_type, public, msg),
+		}
+	}
+
+	sp_keystore::bandersnatch_experimental_enabled! {
+		fn bandersnatch_public_keys(&self, key_type: KeyTypeId) -> Vec<bandersnatch::Public> {
+			self.local.bandersnatch_public_keys(key_type)
+		}
+
+		fn bandersnatch_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bandersnatch::Public, TraitError> {
+			self.local.bandersnatch_generate_new(key_type, seed)
+		}
+
+		fn bandersnatch_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bandersnatch::Signature>, TraitError> {
+			self.local.bandersnatch_sign(key_type, public, msg)
+		}
+
+		fn bandersnatch_vrf_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			data: &bandersnatch::vrf::VrfSignData,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfSignature>, TraitError> {
+			self.local.bandersnatch_vrf_sign(key_type, public, data)
+		}
+
+		fn bandersnatch_vrf_output(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			input: &bandersnatch::vrf::VrfInput,
+		) -> std::result::Result<Option<bandersnatch::vrf::VrfOutput>, TraitError> {
+			self.local.bandersnatch_vrf_output(key_type, public, input)
+		}
+
+		fn bandersnatch_ring_vrf_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bandersnatch::Public,
+			data: &bandersnatch::vrf::VrfSignData,
+			prover: &bandersnatch::ring_vrf::RingProver,
+		) -> std::result::Result<Option<bandersnatch::ring_vrf::RingVrfSignature>, TraitError> {
+			self.local.bandersnatch_ring_vrf_sign(key_type, public, data, prover)
+		}
+	}
+
+	sp_keystore::bls_experimental_enabled! {
+		fn bls381_public_keys(&self, key_type: KeyTypeId) -> Vec<bls381::Public> {
+			self.local.bls381_public_keys(key_type)
+		}
+
+		fn bls381_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bls381::Public, TraitError> {
+			self.local.bls381_generate_new(key_type, seed)
+		}
+
+		fn bls381_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls381::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bls381::Signature>, TraitError> {
+			self.local.bls381_sign(key_type, public, msg)
+		}
+
+		fn bls377_public_keys(&self, key_type: KeyTypeId) -> Vec<bls377::Public> {
+			self.local.bls377_public_keys(key_type)
+		}
+
+		fn bls377_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<bls377::Public, TraitError> {
+			self.local.bls377_generate_new(key_type, seed)
+		}
+
+		fn bls377_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &bls377::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<bls377::Signature>, TraitError> {
+			self.local.bls377_sign(key_type, public, msg)
+		}
+
+		fn ecdsa_bls377_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa_bls377::Public> {
+			self.local.ecdsa_bls377_public_keys(key_type)
+		}
+
+		fn ecdsa_bls377_generate_new(
+			&self,
+			key_type: KeyTypeId,
+			seed: Option<&str>,
+		) -> std::result::Result<ecdsa_bls377::Public, TraitError> {
+			self.local.ecdsa_bls377_generate_new(key_type, seed)
+		}
+
+		fn ecdsa_bls377_sign(
+			&self,
+			key_type: KeyTypeId,
+			public: &ecdsa_bls377::Public,
+			msg: &[u8],
+		) -> std::result::Result<Option<ecdsa_bls377::Signature>, TraitError> {
+			self.local.ecdsa_bls377_sign(key_type, public, msg)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{
+		crypto::Pair,
+		testing::{ECDSA, ED25519, SR25519},
+		Encode,
+	};
+	use std::{os::unix::net::UnixListener, thread};
+	use tempfile::TempDir;
+
+	/// A remote signer holding its keys in a [`LocalKeystore`].
+	struct MockSigner {
+		socket: PathBuf,
+		keystore: Arc<LocalKeystore>,
+		requests: Arc<AtomicU64>,
+		_dir: TempDir,
+	}
+
+	impl MockSigner {
+		/// Spawn a signer which answers requests if `respond` is true and ignores them otherwise.
+		fn spawn(respond: bool) -> Self {
+			let dir = TempDir::new().unwrap();
+			let socket = dir.path().join("signer.sock");
+			let listener = UnixListener::bind(&socket).unwrap();
+			let keystore = Arc::new(LocalKeystore::in_memory());
+			let requests = Arc::new(AtomicU64::new(0));
+
+			let (signer, counter) = (keystore.clone(), requests.clone());
+			thread::spawn(move || {
+				let mut idle = Vec::new();
+				for stream in listener.incoming() {
+					let stream = stream.unwrap();
+					if !respond {
+						idle.push(stream);
+						continue
+					}
+					let (signer, counter) = (signer.clone(), counter.clone());
+					thread::spawn(move || {
+						let mut writer = stream.try_clone().unwrap();
+						for line in BufReader::new(stream).lines() {
+							let request: Value = serde_json::from_str(&line.unwrap()).unwrap();
+							counter.fetch_add(1, Ordering::Relaxed);
+							let result = Self::handle(&signer, &request);
+							let response =
+								json!({ "jsonrpc": "2.0", "id": request["id"], "result": result });
+							writeln!(writer, "{}", response).unwrap();
+						}
+					});
+				}
+			});
+
+			Self { socket, keystore, requests, _dir: dir }
+		}
+
+		fn handle(keystore: &LocalKeystore, request: &Value) -> Value {
+			let params = &request["params"];
+			let key_type = KeyTypeId(from_hex(&params["keyType"]).unwrap().try_into().unwrap());
+			let public = from_hex(&params["public"]).unwrap_or_default();
+			let message = from_hex(&params["message"]).unwrap_or_default();
+
+			let result = match (request["method"].as_str().unwrap(), params["scheme"].as_str()) {
+				("signer_publicKeys", Some("sr25519")) => {
+					let keys = keystore.sr25519_public_keys(key_type);
+					return keys.iter().map(|key| to_hex(key.as_slice())).collect()
+				},
+				("signer_publicKeys", Some("ed25519")) => {
+					let keys = keystore.ed25519_public_keys(key_type);
+					return keys.iter().map(|key| to_hex(key.as_slice())).collect()
+				},
+				("signer_publicKeys", _) => {
+					let keys = keystore.ecdsa_public_keys(key_type);
+					return keys.iter().map(|key| to_hex(key.as_slice())).collect()
+				},
+				("signer_generate", Some("sr25519")) =>
+					Some(keystore.sr25519_generate_new(key_type, None).unwrap().to_vec()),
+				("signer_sign", Some("sr25519")) => {
+					let public = sr25519::Public::from_slice(&public).unwrap();
+					keystore.sr25519_sign(key_type, &public, &message).unwrap().map(|s| s.encode())
+				},
+				("signer_sign", Some("ed25519")) => {
+					let public = ed25519::Public::from_slice(&public).unwrap();
+					keystore.ed25519_sign(key_type, &public, &message).unwrap().map(|s| s.encode())
+				},
+				("signer_signPrehashed", Some("ecdsa")) => {
+					let public = ecdsa::Public::from_slice(&public).unwrap();
+					let message = message.try_into().unwrap();
+					keystore
+						.ecdsa_sign_prehashed(key_type, &public, &message)
+						.unwrap()
+						.map(|s| s.encode())
+				},
+				_ => None,
+			};
+			result.map(|bytes| to_hex(&bytes).into()).unwrap_or_default()
+		}
+
+		fn requests(&self) -> u64 {
+			self.requests.load(Ordering::Relaxed)
+		}
+	}
+
+	#[test]
+	fn signs_with_remote_and_local_keys() {
+		let signer = MockSigner::spawn(true);
+		let remote = signer.keystore.sr25519_generate_new(SR25519, None).unwrap();
+		let local = Arc::new(LocalKeystore::in_memory());
+		let fallback = local.sr25519_generate_new(SR25519, None).unwrap();
+		let keystore = RemoteKeystore::open(&signer.socket, Duration::from_secs(5), local).unwrap();
+
+		let mut keys = keystore.sr25519_public_keys(SR25519);
+		keys.sort();
+		let mut expected = vec![remote, fallback];
+		expected.sort();
+		assert_eq!(keys, expected);
+		assert!(keystore.has_keys(&[(remote.to_vec(), SR25519), (fallback.to_vec(), SR25519)]));
+		assert_eq!(keystore.keys(SR25519).unwrap().len(), 2);
+
+		let msg = b"hello";
+		let signature = keystore.sr25519_sign(SR25519, &remote, msg).unwrap().unwrap();
+		assert!(sr25519::Pair::verify(&signature, msg, &remote));
+		let signature = keystore.sr25519_sign(SR25519, &fallback, msg).unwrap().unwrap();
+		assert!(sr25519::Pair::verify(&signature, msg, &fallback));
+
+		let unknown = sr25519::Pair::generate().0.public();
+		assert_eq!(keystore.sr25519_sign(SR25519, &unknown, msg).unwrap(), None);
+	}
+
+	#[test]
+	fn signs_prehashed_messages_with_remote_keys() {
+		let signer = MockSigner::spawn(true);
+		let remote = signer.keystore.ecdsa_generate_new(ECDSA, None).unwrap();
+		let local = Arc::new(LocalKeystore::in_memory());
+		let keystore = RemoteKeystore::open(&signer.socket, Duration::from_secs(5), local).unwrap();
+
+		let msg = sp_core::keccak_256(b"hello");
+		let signature = keystore.ecdsa_sign_prehashed(ECDSA, &remote, &msg).unwrap().unwrap();
+		assert!(ecdsa::Pair::verify_prehashed(&signature, &msg, &remote));
+	}
+
+	#[test]
+	fn vrf_signing_uses_local_keystore() {
+		let signer = MockSigner::spawn(true);
+		let remote = signer.keystore.sr25519_generate_new(SR25519, None).unwrap();
+		let local = Arc::new(LocalKeystore::in_memory());
+		let fallback = local.sr25519_generate_new(SR25519, None).unwrap();
+		let keystore = RemoteKeystore::open(&signer.socket, Duration::from_secs(5), local).unwrap();
+
+		let data = sr25519::vrf::VrfInput::new(b"Test", &[(b"one", &1_u64.to_le_bytes())])
+			.into_sign_data();
+		assert!(keystore.sr25519_vrf_sign(SR25519, &fallback, &data).unwrap().is_some());
+		assert!(keystore.sr25519_vrf_sign(SR25519, &remote, &data).unwrap().is_none());
+		assert_eq!(signer.requests(), 0);
+	}
+
+	#[test]
+	fn public_keys_are_cached() {
+		let signer = MockSigner::spawn(true);
+		let remote = signer.keystore.ed25519_generate_new(ED25519, None).unwrap();
+		let local = Arc::new(LocalKeystore::in_memory());
+		let keystore = RemoteKeystore::open(&signer.socket, Duration::from_secs(5), local).unwrap();
+
+		assert_eq!(keystore.ed25519_public_keys(ED25519), vec![remote]);
+		assert_eq!(keystore.ed25519_public_keys(ED25519), vec![remote]);
+		let signature = keystore.ed25519_sign(ED25519, &remote, b"hello").unwrap().unwrap();
+		assert!(ed25519::Pair::verify(&signature, b"hello", &remote));
+		// One request to fetch the public keys and one to sign.
+		assert_eq!(signer.requests(), 2);
+
+		// Generating a key invalidates the cached keys.
+		let generated = keystore.sr25519_generate_new(SR25519, None).unwrap();
+		assert_eq!(signer.keystore.sr25519_public_keys(SR25519), vec![generated]);
+		assert_eq!(keystore.sr25519_public_keys(SR25519), vec![generated]);
+		assert_eq!(signer.requests(), 4);
+	}
+
+	#[test]
+	fn unresponsive_signer_times_out() {
+		let signer = MockSigner::spawn(false);
+		let local = Arc::new(LocalKeystore::in_memory());
+		let fallback = local.sr25519_generate_new(SR25519, None).unwrap();
+		let keystore =
+			RemoteKeystore::open(&signer.socket, Duration::from_millis(100), local).unwrap();
+
+		let start = Instant::now();
+		assert_eq!(keystore.sr25519_public_keys(SR25519), vec![fallback]);
+		assert!(keystore.sr25519_sign(SR25519, &fallback, b"hello").unwrap().is_some());
+		assert!(keystore.ed25519_generate_new(ED25519, None).is_err());
+		assert!(start.elapsed() < Duration::from_secs(5));
+	}
+}
@@ -0,0 +1,211 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Bandwidth accounting and limits of individual protocols.
+//!
+//! The transport only knows the total bandwidth of the node. This module accounts for the bytes
+//! exchanged on each request-response protocol, and enforces the
+//! [`BandwidthLimit`](crate::config::BandwidthLimit)s on the bytes sent to each peer.
+
+use crate::types::ProtocolName;
+
+use libp2p::PeerId;
+use parking_lot::Mutex;
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+
+/// Bytes received and sent on each request-response protocol.
+#[derive(Debug, Default)]
+pub struct ProtocolBandwidth {
+	totals: Mutex<HashMap<ProtocolName, (u64, u64)>>,
+}
+
+impl ProtocolBandwidth {
+	/// Record `bytes` received on `protocol`.
+	pub(crate) fn record_inbound(&self, protocol: &ProtocolName, bytes: usize) {
+		let mut totals = self.totals.lock();
+		let (inbound, _) = totals.entry(protocol.clone()).or_default();
+		*inbound = inbound.saturating_add(bytes as u64);
+	}
+
+	/// Record `bytes` sent on `protocol`.
+	pub(crate) fn record_outbound(&self, protocol: &ProtocolName, bytes: usize) {
+		let mut totals = self.totals.lock();
+		let (_, outbound) = totals.entry(protocol.clone()).or_default();
+		*outbound = outbound.saturating_add(bytes as u64);
+	}
+
+	/// The total number of bytes received and sent on each protocol so far.
+	pub fn totals(&self) -> Vec<(ProtocolName, u64, u64)> {
+		self.totals
+			.lock()
+			.iter()
+			.map(|(protocol, (inbound, outbound))| (protocol.clone(), *inbound, *outbound))
+			.collect()
+	}
+}
+
+/// A token bucket limiting the bandwidth to `max_bytes_per_second`.
+///
+/// The bucket holds up to one second worth of bytes. Sending may take the bucket into debt, e.g.
+/// for a single message larger than the bucket, in which case nothing more may be sent until the
+/// debt is paid back.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+	max_bytes_per_second: u64,
+	available: i128,
+	last_update: Instant,
+}
+
+impl RateLimiter {
+	/// Create a new limiter with a full bucket.
+	pub(crate) fn new(max_bytes_per_second: u64, now: Instant) -> Self {
+		Self { max_bytes_per_second, available: max_bytes_per_second.into(), last_update: now }
+	}
+
+	fn refill(&mut self, now: Instant) {
+		let elapsed = now.saturating_duration_since(self.last_update);
+		let refill = elapsed.as_micros() * u128::from(self.max_bytes_per_second) / 1_000_000;
+		self.available = self
+			.available
+			.saturating_add(refill.min(i128::MAX as u128) as i128)
+			.min(self.max_bytes_per_second.into());
+		self.last_update = now;
+	}
+
+	/// Returns `true` if nothing may be sent at the moment.
+	pub(crate) fn is_exhausted(&mut self, now: Instant) -> bool {
+		self.refill(now);
+		self.available <= 0
+	}
+
+	/// Returns `true` if the bucket is full, i.e. the limiter is in its initial state.
+	pub(crate) fn is_full(&mut self, now: Instant) -> bool {
+		self.refill(now);
+		self.available >= self.max_bytes_per_second.into()
+	}
+
+	/// Record `bytes` sent.
+	pub(crate) fn consume(&mut self, bytes: usize, now: Instant) {
+		self.refill(now);
+		self.available = self.available.saturating_sub(bytes as i128);
+	}
+
+	/// The time until something may be sent again.
+	pub(crate) fn time_until_available(&mut self, now: Instant) -> Duration {
+		self.refill(now);
+		if self.available > 0 || self.max_bytes_per_second == 0 {
+			return Duration::ZERO
+		}
+		let debt = (1 - self.available) as u128;
+		let rate = u128::from(self.max_bytes_per_second);
+		let micros = (debt * 1_000_000 + rate - 1) / rate;
+		Duration::from_micros(micros.min(u64::MAX as u128) as u64)
+	}
+}
+
+/// [`RateLimiter`]s of all peers of a protocol.
+#[derive(Debug)]
+pub(crate) struct PeerRateLimiters {
+	max_bytes_per_second: u64,
+	peers: HashMap<PeerId, RateLimiter>,
+}
+
+impl PeerRateLimiters {
+	/// Limit each peer to `max_bytes_per_second`.
+	pub(crate) fn new(max_bytes_per_second: u64) -> Self {
+		Self { max_bytes_per_second, peers: HashMap::new() }
+	}
+
+	/// Returns `true` if nothing may be sent to `peer` at the moment.
+	pub(crate) fn is_exhausted(&mut self, peer: &PeerId, now: Instant) -> bool {
+		self.peers.get_mut(peer).map_or(false, |limiter| limiter.is_exhausted(now))
+	}
+
+	/// Record `bytes` sent to `peer`.
+	pub(crate) fn consume(&mut self, peer: PeerId, bytes: usize, now: Instant) {
+		let max_bytes_per_second = self.max_bytes_per_second;
+		self.peers
+			.entry(peer)
+			.or_insert_with(|| RateLimiter::new(max_bytes_per_second, now))
+			.consume(bytes, now);
+	}
+
+	/// Forget the peers whose bucket is full again, as they are back to their initial state.
+	pub(crate) fn prune(&mut self, now: Instant) {
+		self.peers.retain(|_, limiter| !limiter.is_full(now));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rate_limiter_refills_over_time() {
+		let start = Instant::now();
+		let mut limiter = RateLimiter::new(1000, start);
+		assert!(!limiter.is_exhausted(start));
+
+		// Sending more than the bucket holds takes it into debt.
+		limiter.consume(1500, start);
+		assert!(limiter.is_exhausted(start));
+		assert_eq!(limiter.time_until_available(start), Duration::from_millis(501));
+
+		let later = start + Duration::from_millis(501);
+		assert!(!limiter.is_exhausted(later));
+		assert!(!limiter.is_full(later));
+
+		// The bucket never holds more than a second worth of bytes.
+		let much_later = start + Duration::from_secs(60);
+		assert!(limiter.is_full(much_later));
+		limiter.consume(1000, much_later);
+		assert!(limiter.is_exhausted(much_later));
+	}
+
+	#[test]
+	fn peer_rate_limiters_are_independent() {
+		let start = Instant::now();
+		let (alice, bob) = (PeerId::random(), PeerId::random());
+		let mut limiters = PeerRateLimiters::new(100);
+		limiters.consume(alice, 100, start);
+		assert!(limiters.is_exhausted(&alice, start));
+		assert!(!limiters.is_exhausted(&bob, start));
+
+		limiters.prune(start);
+		assert_eq!(limiters.peers.len(), 1);
+		limiters.prune(start + Duration::from_secs(1));
+		assert!(limiters.peers.is_empty());
+	}
+
+	#[test]
+	fn protocol_bandwidth_is_recorded_per_protocol() {
+		let bandwidth = ProtocolBandwidth::default();
+		let (sync, state): (ProtocolName, ProtocolName) = ("/sync/2".into(), "/state/2".into());
+		bandwidth.record_inbound(&sync, 10);
+		bandwidth.record_outbound(&sync, 100);
+		bandwidth.record_outbound(&state, 5);
+		bandwidth.record_outbound(&sync, 1);
+
+		let mut totals = bandwidth.totals();
+		totals.sort_by_key(|(protocol, ..)| protocol.to_string());
+		assert_eq!(totals, vec![(state, 0, 5), (sync, 10, 101)]);
+	}
+}
@@ -569,6 +569,51 @@ impl NonDefaultSetConfig {
 	}
 }
 
+/// Limit on the bandwidth used to send data to each peer on a protocol.
+///
+/// For request-response protocols, incoming requests of a peer are refused while the responses sent
+/// to it exceed the limit. For notification protocols, notifications to a peer are queued as if the
+/// link to the peer was slower.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BandwidthLimit {
+	/// Suffix of the names of the protocols the limit applies to, e.g. `/sync/2` for the block
+	/// request protocol, as protocol names are usually prefixed with the genesis hash.
+	pub protocol: String,
+
+	/// Maximum number of bytes sent to each peer per second.
+	pub max_bytes_per_second: u64,
+}
+
+impl BandwidthLimit {
+	/// Returns `true` if the limit applies to `protocol`.
+	pub fn applies_to(&self, protocol: &str) -> bool {
+		protocol.ends_with(&self.protocol)
+	}
+
+	/// The limit which applies to `protocol`, if any, preferring the most specific one.
+	pub fn find<'a>(limits: &'a [BandwidthLimit], protocol: &str) -> Option<&'a BandwidthLimit> {
+		limits
+			.iter()
+			.filter(|limit| limit.applies_to(protocol))
+			.max_by_key(|limit| limit.protocol.len())
+	}
+}
+
+impl FromStr for BandwidthLimit {
+	type Err = String;
+
+	/// Parses `<protocol>=<max bytes per second>`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (protocol, max_bytes_per_second) = s
+			.rsplit_once('=')
+			.ok_or_else(|| format!("Expected `<protocol>=<max bytes per second>`, got `{}`", s))?;
+		let max_bytes_per_second = max_bytes_per_second
+			.parse()
+			.map_err(|e| format!("Invalid bandwidth `{}`: {}", max_bytes_per_second, e))?;
+		Ok(Self { protocol: protocol.into(), max_bytes_per_second })
+	}
+}
+
 /// Network service configuration.
 #[derive(Clone, Debug)]
 pub struct NetworkConfiguration {
@@ -655,6 +700,9 @@ pub struct NetworkConfiguration {
 	/// a modification of the way the implementation works. Different nodes with different
 	/// configured values remain compatible with each other.
 	pub yamux_window_size: Option<u32>,
+
+	/// Limits on the bandwidth used to send data to each peer on individual protocols.
+	pub bandwidth_limits: Vec<BandwidthLimit>,
 }
 
 impl NetworkConfiguration {
@@ -687,6 +735,7 @@ impl NetworkConfiguration {
 				.expect("value is a constant; constant is non-zero; qed."),
 			yamux_window_size: None,
 			ipfs_server: false,
+			bandwidth_limits: Vec::new(),
 		}
 	}
 
@@ -833,4 +882,21 @@ mod tests {
 		let kp2 = NodeKeyConfig::Ed25519(Secret::New).into_keypair().unwrap();
 		assert!(secret_bytes(kp1) != secret_bytes(kp2));
 	}
+
+	#[test]
+	fn bandwidth_limits_are_parsed_and_matched() {
+		let sync: BandwidthLimit = "/sync/2=1048576".parse().unwrap();
+		assert_eq!(
+			sync,
+			BandwidthLimit { protocol: "/sync/2".into(), max_bytes_per_second: 1024 * 1024 }
+		);
+		assert!("/sync/2".parse::<BandwidthLimit>().is_err());
+		assert!("/sync/2=fast".parse::<BandwidthLimit>().is_err());
+
+		let all: BandwidthLimit = "=100".parse().unwrap();
+		let limits = vec![all.clone(), sync.clone()];
+		assert_eq!(BandwidthLimit::find(&limits, "/0x1234/sync/2"), Some(&sync));
+		assert_eq!(BandwidthLimit::find(&limits, "/0x1234/state/2"), Some(&all));
+		assert_eq!(BandwidthLimit::find(&limits[1..], "/0x1234/state/2"), None);
+	}
 }
@@ -86,7 +86,13 @@ impl<B: BlockT> Protocol<B> {
 		peer_store_handle: PeerStoreHandle,
 		protocol_controller_handles: Vec<protocol_controller::ProtocolHandle>,
 		from_protocol_controllers: TracingUnboundedReceiver<protocol_controller::Message>,
+		bandwidth_limits: &[config::BandwidthLimit],
 	) -> error::Result<(Self, Vec<ProtocolHandle>)> {
+		let max_bytes_per_second = |protocol: &ProtocolName| {
+			config::BandwidthLimit::find(bandwidth_limits, protocol)
+				.map(|limit| limit.max_bytes_per_second)
+		};
+
 		let (behaviour, notification_protocols, handles) = {
 			let installed_protocols = iter::once(block_announces_protocol.protocol_name().clone())
 				.chain(notification_protocols.iter().map(|p| p.protocol_name().clone()))
@@ -101,6 +107,9 @@ impl<B: BlockT> Protocol<B> {
 					fallback_names: block_announces_protocol.fallback_names().cloned().collect(),
 					handshake: block_announces_protocol.handshake().as_ref().unwrap().to_vec(),
 					max_notification_size: block_announces_protocol.max_notification_size(),
+					max_bytes_per_second: max_bytes_per_second(
+						block_announces_protocol.protocol_name(),
+					),
 				};
 
 				let (handle, command_stream) =
@@ -114,6 +123,7 @@ impl<B: BlockT> Protocol<B> {
 					fallback_names: s.fallback_names().cloned().collect(),
 					handshake: s.handshake().as_ref().map_or(roles.encode(), |h| (*h).to_vec()),
 					max_notification_size: s.max_notification_size(),
+					max_bytes_per_second: max_bytes_per_second(s.protocol_name()),
 				};
 
 				let (handle, command_stream) = s.take_protocol_handle().split();
@@ -182,6 +182,8 @@ pub struct ProtocolConfig {
 	pub handshake: Vec<u8>,
 	/// Maximum allowed size for a notification.
 	pub max_notification_size: u64,
+	/// Maximum number of bytes of notifications sent to each peer per second, if limited.
+	pub max_bytes_per_second: Option<u64>,
 }
 
 /// Identifier for a delay firing.
@@ -422,6 +424,7 @@ impl Notifications {
 						fallback_names: cfg.fallback_names,
 						handshake: Arc::new(RwLock::new(cfg.handshake)),
 						max_notification_size: cfg.max_notification_size,
+						max_bytes_per_second: cfg.max_bytes_per_second,
 					},
 					(protocol_handle, command_stream),
 				)
@@ -2459,6 +2462,7 @@ mod tests {
 						fallback_names: Vec::new(),
 						handshake: vec![1, 2, 3, 4],
 						max_notification_size: u64::MAX,
+						max_bytes_per_second: None,
 					},
 					notif_handle,
 					command_stream,
@@ -58,6 +58,7 @@
 //! [`NotifsHandlerIn::Open`] has gotten an answer.
 
 use crate::{
+	bandwidth::RateLimiter,
 	protocol::notifications::{
 		service::metrics,
 		upgrade::{
@@ -82,6 +83,7 @@ use libp2p::{
 	},
 	PeerId,
 };
+use futures_timer::Delay;
 use log::error;
 use parking_lot::{Mutex, RwLock};
 use std::{
@@ -142,6 +144,7 @@ impl NotifsHandler {
 		protocols: Vec<ProtocolConfig>,
 		metrics: Option<metrics::Metrics>,
 	) -> Self {
+		let now = Instant::now();
 		Self {
 			protocols: protocols
 				.into_iter()
@@ -152,12 +155,22 @@ impl NotifsHandler {
 						config.max_notification_size,
 					);
 
-					Protocol { config, in_upgrade, state: State::Closed { pending_opening: false } }
+					let rate_limiter = config
+						.max_bytes_per_second
+						.map(|max_bytes_per_second| RateLimiter::new(max_bytes_per_second, now));
+
+					Protocol {
+						config,
+						in_upgrade,
+						state: State::Closed { pending_opening: false },
+						rate_limiter,
+						rate_limit_delay: None,
+					}
 				})
 				.collect(),
 			peer_id,
 			endpoint,
-			when_connection_open: Instant::now(),
+			when_connection_open: now,
 			events_queue: VecDeque::with_capacity(16),
 			metrics: metrics.map_or(None, |metrics| Some(Arc::new(metrics))),
 		}
@@ -175,6 +188,8 @@ pub struct ProtocolConfig {
 	pub handshake: Arc<RwLock<Vec<u8>>>,
 	/// Maximum allowed size for a notification.
 	pub max_notification_size: u64,
+	/// Maximum number of bytes of notifications sent to the peer per second, if limited.
+	pub max_bytes_per_second: Option<u64>,
 }
 
 /// Fields specific for each individual protocol.
@@ -187,6 +202,15 @@ struct Protocol {
 
 	/// Current state of the substreams for this protocol.
 	state: State,
+
+	/// Limits the bandwidth of the notifications sent, if configured.
+	///
+	/// Notifications exceeding the limit stay queued in the [`NotificationsSink`], as if the link
+	/// to the peer was slower.
+	rate_limiter: Option<RateLimiter>,
+
+	/// Fires when notifications may be sent again after the rate limit was exceeded.
+	rate_limit_delay: Option<Delay>,
 }
 
 /// See the module-level documentation to learn about the meaning of these variants.
@@ -749,9 +773,11 @@ impl ConnectionHandler for NotifsHandler {
 		// For each open substream, try send messages from `notifications_sink_rx` to the
 		// substream.
 		for protocol_index in 0..self.protocols.len() {
+			let Protocol { state, rate_limiter, rate_limit_delay, .. } =
+				&mut self.protocols[protocol_index];
 			if let State::Open {
 				notifications_sink_rx, out_substream: Some(out_substream), ..
-			} = &mut self.protocols[protocol_index].state
+			} = state
 			{
 				loop {
 					// Only proceed with `out_substream.poll_ready_unpin` if there is an element
@@ -766,6 +792,24 @@ impl ConnectionHandler for NotifsHandler {
 						Poll::Ready(None) | Poll::Pending => break,
 					}
 
+					// Hold back the message while the bandwidth limit is exceeded, and wake up
+					// once it may be sent.
+					if let Some(rate_limiter) = rate_limiter.as_mut() {
+						let now = Instant::now();
+						if rate_limiter.is_exhausted(now) {
+							let delay = rate_limit_delay.get_or_insert_with(|| {
+								Delay::new(rate_limiter.time_until_available(now))
+							});
+							match delay.poll_unpin(cx) {
+								Poll::Ready(()) => {
+									*rate_limit_delay = None;
+									continue
+								},
+								Poll::Pending => break,
+							}
+						}
+					}
+
 					// Before we extract the element from `notifications_sink_rx`, check that the
 					// substream is ready to accept a message.
 					match out_substream.poll_ready_unpin(cx) {
@@ -786,6 +830,9 @@ impl ConnectionHandler for NotifsHandler {
 						},
 					};
 
+					if let Some(rate_limiter) = rate_limiter.as_mut() {
+						rate_limiter.consume(message.len(), Instant::now());
+					}
 					let _ = out_substream.start_send_unpin(message);
 					// Note that flushing is performed later down this function.
 				}
@@ -1075,9 +1122,12 @@ pub mod tests {
 				fallback_names: vec![],
 				handshake: Arc::new(RwLock::new(b"hello, world".to_vec())),
 				max_notification_size: u64::MAX,
+				max_bytes_per_second: None,
 			},
 			in_upgrade: NotificationsIn::new("/foo", Vec::new(), u64::MAX),
 			state: State::Closed { pending_opening: false },
+			rate_limiter: None,
+			rate_limit_delay: None,
 		};
 
 		NotifsHandler {
@@ -106,6 +106,7 @@ fn build_nodes() -> (Swarm<CustomProtoWithAddr>, Swarm<CustomProtoWithAddr>) {
 						fallback_names: Vec::new(),
 						handshake: Vec::new(),
 						max_notification_size: 1024 * 1024,
+						max_bytes_per_second: None,
 					},
 					notif_handle,
 					command_stream,
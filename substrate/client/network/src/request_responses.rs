@@ -33,8 +33,13 @@
 //!
 //! - If provided, a ["requests processing"](ProtocolConfig::inbound_queue) channel
 //! is used to handle incoming requests.
+//!
+//! - If a [`BandwidthLimit`] applies to a protocol, incoming requests of a peer are refused while
+//! the responses sent to that peer exceed the limit.
 
 use crate::{
+	bandwidth::{PeerRateLimiters, ProtocolBandwidth},
+	config::BandwidthLimit,
 	peer_store::{PeerStoreProvider, BANNED_THRESHOLD},
 	types::ProtocolName,
 	ReputationChange,
@@ -57,6 +62,7 @@ use std::{
 	collections::{hash_map::Entry, HashMap},
 	io, iter,
 	pin::Pin,
+	sync::Arc,
 	task::{Context, Poll},
 	time::{Duration, Instant},
 };
@@ -282,6 +288,12 @@ pub struct RequestResponsesBehaviour {
 
 	/// Primarily used to get a reputation of a node.
 	peer_store: Box<dyn PeerStoreProvider>,
+
+	/// Bytes received and sent on each protocol.
+	bandwidth: Arc<ProtocolBandwidth>,
+
+	/// Limits on the bandwidth of the responses sent to each peer, by protocol.
+	rate_limiters: HashMap<ProtocolName, PeerRateLimiters>,
 }
 
 /// Generated by the response builder and waiting to be processed.
@@ -296,12 +308,24 @@ struct RequestProcessingOutcome {
 impl RequestResponsesBehaviour {
 	/// Creates a new behaviour. Must be passed a list of supported protocols. Returns an error if
 	/// the same protocol is passed twice.
+	///
+	/// The bytes exchanged on each protocol are recorded in `bandwidth`.
 	pub fn new(
 		list: impl Iterator<Item = ProtocolConfig>,
 		peer_store: Box<dyn PeerStoreProvider>,
+		bandwidth_limits: &[BandwidthLimit],
+		bandwidth: Arc<ProtocolBandwidth>,
 	) -> Result<Self, RegisterError> {
 		let mut protocols = HashMap::new();
+		let mut rate_limiters = HashMap::new();
 		for protocol in list {
+			if let Some(limit) = BandwidthLimit::find(bandwidth_limits, &protocol.name) {
+				rate_limiters.insert(
+					protocol.name.clone(),
+					PeerRateLimiters::new(limit.max_bytes_per_second),
+				);
+			}
+
 			let mut cfg = Config::default();
 			cfg.set_connection_keep_alive(Duration::from_secs(10));
 			cfg.set_request_timeout(protocol.request_timeout);
@@ -336,6 +360,8 @@ impl RequestResponsesBehaviour {
 			pending_responses_arrival_time: Default::default(),
 			send_feedback: Default::default(),
 			peer_store,
+			bandwidth,
+			rate_limiters,
 		})
 	}
 
@@ -357,9 +383,11 @@ impl RequestResponsesBehaviour {
 
 		if let Some((protocol, _)) = self.protocols.get_mut(protocol_name) {
 			if protocol.is_connected(target) || connect.should_connect() {
+				let protocol_name: ProtocolName = protocol_name.to_string().into();
+				self.bandwidth.record_outbound(&protocol_name, request.len());
 				let request_id = protocol.send_request(target, request);
 				let prev_req_id = self.pending_requests.insert(
-					(protocol_name.to_string().into(), request_id).into(),
+					(protocol_name, request_id).into(),
 					(Instant::now(), pending_response),
 				);
 				debug_assert!(prev_req_id.is_none(), "Expect request id to be unique.");
@@ -467,7 +495,11 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 				endpoint,
 				handler,
 				remaining_established,
-			}) =>
+			}) => {
+				if remaining_established == 0 {
+					let now = Instant::now();
+					self.rate_limiters.values_mut().for_each(|limiters| limiters.prune(now));
+				}
 				for (p_name, p_handler) in handler.into_iter() {
 					if let Some((proto, _)) = self.protocols.get_mut(p_name.as_str()) {
 						proto.on_swarm_event(FromSwarm::ConnectionClosed(ConnectionClosed {
@@ -484,7 +516,8 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 						  p_name,
 						)
 					}
-				},
+				}
+			},
 			FromSwarm::DialFailure(e) =>
 				for (p, _) in self.protocols.values_mut() {
 					NetworkBehaviour::on_swarm_event(p, FromSwarm::DialFailure(e));
@@ -571,6 +604,11 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 					if let Some((protocol, _)) = self.protocols.get_mut(&*protocol_name) {
 						log::trace!(target: "sub-libp2p", "send response to {peer} ({protocol_name:?}), {} bytes", payload.len());
 
+						self.bandwidth.record_outbound(&protocol_name, payload.len());
+						if let Some(limiters) = self.rate_limiters.get_mut(&protocol_name) {
+							limiters.consume(peer, payload.len(), Instant::now());
+						}
+
 						if protocol.send_response(inner_channel, Ok(payload)).is_err() {
 							// Note: Failure is handled further below when receiving
 							// `InboundFailure` event from request-response [`Behaviour`].
@@ -631,8 +669,10 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							peer,
 							message: Message::Request { request_id, request, channel, .. },
 						} => {
+							let now = Instant::now();
 							self.pending_responses_arrival_time
-								.insert((protocol.clone(), request_id).into(), Instant::now());
+								.insert((protocol.clone(), request_id).into(), now);
+							self.bandwidth.record_inbound(protocol, request.len());
 
 							let reputation = self.peer_store.peer_reputation(&peer);
 
@@ -646,6 +686,20 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 								continue 'poll_protocol
 							}
 
+							if self
+								.rate_limiters
+								.get_mut(protocol)
+								.map_or(false, |limiters| limiters.is_exhausted(&peer, now))
+							{
+								log::debug!(
+									target: "sub-libp2p",
+									"Refusing request from {} on {:?}: bandwidth limit exceeded",
+									peer,
+									protocol,
+								);
+								continue 'poll_protocol
+							}
+
 							let (tx, rx) = oneshot::channel();
 
 							// Submit the request to the "response builder" passed by the user at
@@ -699,11 +753,13 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 								.remove(&(protocol.clone(), request_id).into())
 							{
 								Some((started, pending_response)) => {
+									let size =
+										response.as_ref().map_or(0usize, |response| response.len());
 									log::trace!(
 										target: "sub-libp2p",
-										"received response from {peer} ({protocol:?}), {} bytes",
-										response.as_ref().map_or(0usize, |response| response.len()),
+										"received response from {peer} ({protocol:?}), {size} bytes",
 									);
+									self.bandwidth.record_inbound(protocol, size);
 
 									let delivered = pending_response
 										.send(response.map_err(|()| RequestFailure::Refused))
@@ -998,6 +1054,13 @@ mod tests {
 
 	fn build_swarm(
 		list: impl Iterator<Item = ProtocolConfig>,
+	) -> (Swarm<RequestResponsesBehaviour>, Multiaddr) {
+		build_swarm_with_limits(list, &[])
+	}
+
+	fn build_swarm_with_limits(
+		list: impl Iterator<Item = ProtocolConfig>,
+		bandwidth_limits: &[BandwidthLimit],
 	) -> (Swarm<RequestResponsesBehaviour>, Multiaddr) {
 		let keypair = Keypair::generate_ed25519();
 
@@ -1007,7 +1070,13 @@ mod tests {
 			.multiplex(libp2p::yamux::Config::default())
 			.boxed();
 
-		let behaviour = RequestResponsesBehaviour::new(list, Box::new(MockPeerStore {})).unwrap();
+		let behaviour = RequestResponsesBehaviour::new(
+			list,
+			Box::new(MockPeerStore {}),
+			bandwidth_limits,
+			Default::default(),
+		)
+		.unwrap();
 
 		let runtime = tokio::runtime::Runtime::new().unwrap();
 		let mut swarm = SwarmBuilder::with_executor(
@@ -1122,6 +1191,111 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn requests_are_refused_beyond_bandwidth_limit() {
+		let protocol_name = "/test/req-resp/1";
+		let mut pool = LocalPool::new();
+
+		// Build swarms whose behaviour is [`RequestResponsesBehaviour`]. Only `swarm[1]` answers
+		// requests, and limits the bandwidth of the responses to far less than one response per
+		// second.
+		let mut swarms = (0..2)
+			.map(|i| {
+				let (tx, mut rx) = async_channel::bounded::<IncomingRequest>(64);
+
+				pool.spawner()
+					.spawn_obj(
+						async move {
+							while let Some(rq) = rx.next().await {
+								let _ = rq.pending_response.send(super::OutgoingResponse {
+									result: Ok(b"this is a response".to_vec()),
+									reputation_changes: Vec::new(),
+									sent_feedback: None,
+								});
+							}
+						}
+						.boxed()
+						.into(),
+					)
+					.unwrap();
+
+				let protocol_config = ProtocolConfig {
+					name: From::from(protocol_name),
+					fallback_names: Vec::new(),
+					max_request_size: 1024,
+					max_response_size: 1024 * 1024,
+					request_timeout: Duration::from_secs(30),
+					inbound_queue: Some(tx),
+				};
+				let limits = vec![BandwidthLimit {
+					protocol: "/req-resp/1".into(),
+					max_bytes_per_second: 1,
+				}];
+
+				build_swarm_with_limits(iter::once(protocol_config), &limits[..i])
+			})
+			.collect::<Vec<_>>();
+
+		{
+			let dial_addr = swarms[1].1.clone();
+			Swarm::dial(&mut swarms[0].0, dial_addr).unwrap();
+		}
+
+		let (mut swarm, _) = swarms.remove(1);
+		// Running `swarm[1]` in the background.
+		pool.spawner()
+			.spawn_obj({
+				async move {
+					loop {
+						let _ = swarm.select_next_some().await;
+					}
+				}
+				.boxed()
+				.into()
+			})
+			.unwrap();
+
+		let (mut swarm, _) = swarms.remove(0);
+		pool.run_until(async move {
+			let mut responses = Vec::new();
+
+			loop {
+				let peer = match swarm.select_next_some().await {
+					SwarmEvent::ConnectionEstablished { peer_id, .. } => peer_id,
+					SwarmEvent::Behaviour(Event::RequestFinished { peer, result, .. }) => {
+						result.unwrap();
+						if responses.len() == 2 {
+							break
+						}
+						peer
+					},
+					_ => continue,
+				};
+				let (sender, receiver) = oneshot::channel();
+				swarm.behaviour_mut().send_request(
+					&peer,
+					protocol_name,
+					b"this is a request".to_vec(),
+					sender,
+					IfDisconnected::ImmediateError,
+				);
+				responses.push(receiver);
+			}
+
+			// The first response exhausts the bandwidth of the peer, so the second request is
+			// refused.
+			let mut responses = responses.into_iter();
+			assert_eq!(
+				responses.next().unwrap().await.unwrap().unwrap(),
+				b"this is a response"
+			);
+			assert!(matches!(
+				responses.next().unwrap().await.unwrap(),
+				Err(RequestFailure::Refused)
+			));
+		});
+	}
+
 	#[test]
 	fn max_response_size_exceeded() {
 		let protocol_name = "/test/req-resp/1";
@@ -28,6 +28,7 @@
 //! which is then processed by [`NetworkWorker::next_action`].
 
 use crate::{
+	bandwidth::ProtocolBandwidth,
 	behaviour::{self, Behaviour, BehaviourOut},
 	config::{parse_addr, FullNetworkConfiguration, MultiaddrWithPeerId, Params, TransportConfig},
 	discovery::DiscoveryConfig,
@@ -376,6 +377,7 @@ where
 
 		let num_connected = Arc::new(AtomicUsize::new(0));
 		let external_addresses = Arc::new(Mutex::new(HashSet::new()));
+		let protocol_bandwidth = Arc::new(ProtocolBandwidth::default());
 
 		let (protocol, notif_protocol_handles) = Protocol::new(
 			From::from(&params.role),
@@ -385,6 +387,7 @@ where
 			params.peer_store.clone(),
 			protocol_handles.clone(),
 			from_protocol_controllers,
+			&network_config.bandwidth_limits,
 		)?;
 
 		// Build the swarm.
@@ -435,6 +438,8 @@ where
 					request_response_protocols,
 					params.peer_store.clone(),
 					external_addresses.clone(),
+					&network_config.bandwidth_limits,
+					protocol_bandwidth.clone(),
 				);
 
 				match result {
@@ -484,6 +489,7 @@ where
 				MetricSources {
 					bandwidth: bandwidth.clone(),
 					connected_peers: num_connected.clone(),
+					protocol_bandwidth,
 				},
 			)?),
 			None => None,
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::transport::BandwidthSinks;
+use crate::{bandwidth::ProtocolBandwidth, transport::BandwidthSinks};
 use prometheus_endpoint::{
 	self as prometheus, Counter, CounterVec, Gauge, GaugeVec, HistogramOpts, MetricSource, Opts,
 	PrometheusError, Registry, SourcedCounter, SourcedGauge, U64,
@@ -34,6 +34,7 @@ pub use prometheus_endpoint::{Histogram, HistogramVec};
 /// Registers all networking metrics with the given registry.
 pub fn register(registry: &Registry, sources: MetricSources) -> Result<Metrics, PrometheusError> {
 	BandwidthCounters::register(registry, sources.bandwidth)?;
+	ProtocolBandwidthCounters::register(registry, sources.protocol_bandwidth)?;
 	NumConnectedGauge::register(registry, sources.connected_peers)?;
 	Metrics::register(registry)
 }
@@ -42,6 +43,7 @@ pub fn register(registry: &Registry, sources: MetricSources) -> Result<Metrics,
 pub struct MetricSources {
 	pub bandwidth: Arc<BandwidthSinks>,
 	pub connected_peers: Arc<AtomicUsize>,
+	pub protocol_bandwidth: Arc<ProtocolBandwidth>,
 }
 
 /// Dedicated metrics.
@@ -236,6 +238,45 @@ impl MetricSource for BandwidthCounters {
 	}
 }
 
+/// The bandwidth counter metric of request-response protocols.
+#[derive(Clone)]
+pub struct ProtocolBandwidthCounters(Arc<ProtocolBandwidth>);
+
+impl ProtocolBandwidthCounters {
+	/// Registers the `ProtocolBandwidthCounters` metric whose values are
+	/// obtained from the given `ProtocolBandwidth`.
+	fn register(
+		registry: &Registry,
+		bandwidth: Arc<ProtocolBandwidth>,
+	) -> Result<(), PrometheusError> {
+		prometheus::register(
+			SourcedCounter::new(
+				&Opts::new(
+					"substrate_sub_libp2p_requests_bytes_total",
+					"Total bandwidth usage of request-response protocols",
+				)
+				.variable_label("protocol")
+				.variable_label("direction"),
+				ProtocolBandwidthCounters(bandwidth),
+			)?,
+			registry,
+		)?;
+
+		Ok(())
+	}
+}
+
+impl MetricSource for ProtocolBandwidthCounters {
+	type N = u64;
+
+	fn collect(&self, mut set: impl FnMut(&[&str], Self::N)) {
+		for (protocol, inbound, outbound) in self.0.totals() {
+			set(&[&protocol[..], "in"], inbound);
+			set(&[&protocol[..], "out"], outbound);
+		}
+	}
+}
+
 /// The connected peers metric.
 #[derive(Clone)]
 pub struct NumConnectedGauge(Arc<AtomicUsize>);
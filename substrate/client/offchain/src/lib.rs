@@ -53,9 +53,11 @@ use sp_runtime::traits::{self, Header};
 use threadpool::ThreadPool;
 
 mod api;
+mod quota;
 
+pub use quota::{QuotaStorage, StorageQuota};
 pub use sp_core::offchain::storage::OffchainDb;
-pub use sp_offchain::{OffchainWorkerApi, STORAGE_PREFIX};
+pub use sp_offchain::{OffchainWorkerApi, StorageUsage, STORAGE_PREFIX};
 
 const LOG_TARGET: &str = "offchain-worker";
 
@@ -0,0 +1,240 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Size quotas for namespaces of the persistent offchain storage.
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use parking_lot::Mutex;
+use sp_core::offchain::OffchainStorage;
+use sp_offchain::{StorageUsage, STORAGE_PREFIX, USAGE_PREFIX};
+
+const LOG_TARGET: &str = "offchain-worker::quota";
+
+/// The size quota of a namespace of the persistent offchain storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageQuota {
+	/// The key prefix of the namespace.
+	///
+	/// The empty prefix contains all keys which are not part of a more specific namespace.
+	pub namespace: Vec<u8>,
+	/// The maximum number of bytes of keys and values which may be stored in the namespace.
+	pub max_bytes: u64,
+}
+
+/// An [`OffchainStorage`] which enforces [`StorageQuota`]s on the persistent offchain storage.
+///
+/// Each key belongs to the namespace with the longest prefix of the key, if any. Writes which
+/// would take a namespace beyond its quota are rejected: `set` ignores them and
+/// `compare_and_set` fails.
+///
+/// The [`StorageUsage`] of each namespace is recorded under [`USAGE_PREFIX`], so it survives
+/// restarts and may be inspected over RPC. Only writes made through this storage are accounted
+/// for, so data stored before the quotas were enabled does not count towards them.
+#[derive(Clone)]
+pub struct QuotaStorage<Storage> {
+	inner: Storage,
+	quotas: Arc<Vec<StorageQuota>>,
+	/// Serializes the accounting of writes across all clones.
+	lock: Arc<Mutex<()>>,
+}
+
+impl<Storage: OffchainStorage> QuotaStorage<Storage> {
+	/// Enforce `quotas` on the persistent offchain storage of `inner`.
+	pub fn new(mut inner: Storage, quotas: Vec<StorageQuota>) -> Self {
+		for quota in &quotas {
+			let mut usage = read_usage(&inner, &quota.namespace);
+			usage.quota = Some(quota.max_bytes);
+			inner.set(USAGE_PREFIX, &quota.namespace, &usage.encode());
+		}
+		Self { inner, quotas: Arc::new(quotas), lock: Default::default() }
+	}
+
+	/// The usage of `namespace`.
+	pub fn usage(&self, namespace: &[u8]) -> StorageUsage {
+		read_usage(&self.inner, namespace)
+	}
+
+	/// The quota of the namespace of `key`, if any.
+	fn quota(&self, prefix: &[u8], key: &[u8]) -> Option<StorageQuota> {
+		if prefix != STORAGE_PREFIX {
+			return None
+		}
+		self.quotas
+			.iter()
+			.filter(|quota| key.starts_with(&quota.namespace))
+			.max_by_key(|quota| quota.namespace.len())
+			.cloned()
+	}
+
+	/// The usage of the namespace of `quota` after replacing `old` by `new` under `key`, or
+	/// `None` if it would exceed the quota.
+	fn updated_usage(
+		&self,
+		quota: &StorageQuota,
+		key: &[u8],
+		old: Option<&[u8]>,
+		new: Option<&[u8]>,
+	) -> Option<StorageUsage> {
+		let size = |value: Option<&[u8]>| value.map_or(0, |v| (key.len() + v.len()) as u64);
+		let (old, new) = (size(old), size(new));
+		let mut usage = self.usage(&quota.namespace);
+		usage.used = usage.used.saturating_sub(old).saturating_add(new);
+		if new > old && usage.used > quota.max_bytes {
+			log::warn!(
+				target: LOG_TARGET,
+				"Rejecting write of {} bytes to offchain storage namespace {}: quota of {} bytes \
+				exceeded",
+				new,
+				array_bytes::bytes2hex("0x", &quota.namespace),
+				quota.max_bytes,
+			);
+			return None
+		}
+		Some(usage)
+	}
+}
+
+impl<Storage: OffchainStorage> OffchainStorage for QuotaStorage<Storage> {
+	fn set(&mut self, prefix: &[u8], key: &[u8], value: &[u8]) {
+		let Some(quota) = self.quota(prefix, key) else {
+			return self.inner.set(prefix, key, value)
+		};
+		let lock = self.lock.clone();
+		let _guard = lock.lock();
+		let old = self.inner.get(prefix, key);
+		if let Some(usage) = self.updated_usage(&quota, key, old.as_deref(), Some(value)) {
+			self.inner.set(prefix, key, value);
+			self.inner.set(USAGE_PREFIX, &quota.namespace, &usage.encode());
+		}
+	}
+
+	fn remove(&mut self, prefix: &[u8], key: &[u8]) {
+		let Some(quota) = self.quota(prefix, key) else {
+			return self.inner.remove(prefix, key)
+		};
+		let lock = self.lock.clone();
+		let _guard = lock.lock();
+		let Some(old) = self.inner.get(prefix, key) else { return };
+		if let Some(usage) = self.updated_usage(&quota, key, Some(&old), None) {
+			self.inner.remove(prefix, key);
+			self.inner.set(USAGE_PREFIX, &quota.namespace, &usage.encode());
+		}
+	}
+
+	fn get(&self, prefix: &[u8], key: &[u8]) -> Option<Vec<u8>> {
+		self.inner.get(prefix, key)
+	}
+
+	fn compare_and_set(
+		&mut self,
+		prefix: &[u8],
+		key: &[u8],
+		old_value: Option<&[u8]>,
+		new_value: &[u8],
+	) -> bool {
+		let Some(quota) = self.quota(prefix, key) else {
+			return self.inner.compare_and_set(prefix, key, old_value, new_value)
+		};
+		let lock = self.lock.clone();
+		let _guard = lock.lock();
+		let old = self.inner.get(prefix, key);
+		if old.as_deref() != old_value {
+			return false
+		}
+		let Some(usage) = self.updated_usage(&quota, key, old_value, Some(new_value)) else {
+			return false
+		};
+		let set = self.inner.compare_and_set(prefix, key, old_value, new_value);
+		if set {
+			self.inner.set(USAGE_PREFIX, &quota.namespace, &usage.encode());
+		}
+		set
+	}
+}
+
+fn read_usage<Storage: OffchainStorage>(storage: &Storage, namespace: &[u8]) -> StorageUsage {
+	storage
+		.get(USAGE_PREFIX, namespace)
+		.and_then(|usage| StorageUsage::decode(&mut &usage[..]).ok())
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	fn storage() -> QuotaStorage<InMemOffchainStorage> {
+		QuotaStorage::new(
+			InMemOffchainStorage::default(),
+			vec![
+				StorageQuota { namespace: b"pallet/".to_vec(), max_bytes: 20 },
+				StorageQuota { namespace: Vec::new(), max_bytes: 100 },
+			],
+		)
+	}
+
+	#[test]
+	fn writes_are_accounted_to_the_longest_namespace() {
+		let mut storage = storage();
+		storage.set(STORAGE_PREFIX, b"pallet/a", b"1234");
+		storage.set(STORAGE_PREFIX, b"other", b"12345");
+		assert_eq!(storage.usage(b"pallet/"), StorageUsage { used: 12, quota: Some(20) });
+		assert_eq!(storage.usage(b""), StorageUsage { used: 10, quota: Some(100) });
+
+		// Overwriting only accounts for the difference.
+		storage.set(STORAGE_PREFIX, b"pallet/a", b"12");
+		assert_eq!(storage.usage(b"pallet/").used, 10);
+		storage.remove(STORAGE_PREFIX, b"pallet/a");
+		assert_eq!(storage.usage(b"pallet/").used, 0);
+
+		// Other prefixes are not accounted for.
+		storage.set(b"other-prefix", b"pallet/a", &[0; 64]);
+		assert_eq!(storage.usage(b"pallet/").used, 0);
+	}
+
+	#[test]
+	fn writes_beyond_quota_are_rejected() {
+		let mut storage = storage();
+		storage.set(STORAGE_PREFIX, b"pallet/a", &[0; 12]);
+		assert_eq!(storage.get(STORAGE_PREFIX, b"pallet/a"), Some(vec![0; 12]));
+
+		storage.set(STORAGE_PREFIX, b"pallet/b", &[0; 4]);
+		assert_eq!(storage.get(STORAGE_PREFIX, b"pallet/b"), None);
+		assert!(!storage.compare_and_set(STORAGE_PREFIX, b"pallet/b", None, &[0; 4]));
+		assert_eq!(storage.usage(b"pallet/").used, 20);
+
+		// Shrinking is always possible, and frees space for other keys.
+		assert!(storage.compare_and_set(STORAGE_PREFIX, b"pallet/a", Some(&[0; 12][..]), &[]));
+		storage.set(STORAGE_PREFIX, b"pallet/b", &[0; 4]);
+		assert_eq!(storage.get(STORAGE_PREFIX, b"pallet/b"), Some(vec![0; 4]));
+	}
+
+	#[test]
+	fn usage_is_persisted() {
+		let mut storage = storage();
+		storage.set(STORAGE_PREFIX, b"pallet/a", b"1234");
+		let storage = QuotaStorage::new(
+			storage.inner,
+			vec![StorageQuota { namespace: b"pallet/".to_vec(), max_bytes: 50 }],
+		);
+		assert_eq!(storage.usage(b"pallet/"), StorageUsage { used: 12, quota: Some(50) });
+	}
+}
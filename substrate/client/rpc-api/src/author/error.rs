@@ -51,6 +51,9 @@ pub enum Error {
 	/// Invalid session keys encoding.
 	#[error("Session keys are not encoded correctly")]
 	InvalidSessionKeys,
+	/// The runtime cannot generate proofs of ownership of session keys.
+	#[error("The runtime does not support proofs of ownership of session keys")]
+	UnsupportedSessionKeysProof,
 	/// Call to an unsafe RPC was denied.
 	#[error(transparent)]
 	UnsafeRpcCalled(#[from] crate::policy::UnsafeRpcError),
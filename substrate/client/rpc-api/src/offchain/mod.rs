@@ -19,10 +19,21 @@
 //! Substrate offchain API.
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
 use sp_core::{offchain::StorageKind, Bytes};
 
 pub mod error;
 
+/// The usage of a namespace of the offchain local storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUsage {
+	/// The number of bytes of the keys and values stored in the namespace.
+	pub used: u64,
+	/// The maximum number of bytes which may be stored in the namespace, if limited.
+	pub quota: Option<u64>,
+}
+
 /// Substrate offchain RPC API
 #[rpc(client, server)]
 pub trait OffchainApi {
@@ -33,4 +44,14 @@ pub trait OffchainApi {
 	/// Get offchain local storage under given key and prefix.
 	#[method(name = "offchain_localStorageGet")]
 	fn get_local_storage(&self, kind: StorageKind, key: Bytes) -> RpcResult<Option<Bytes>>;
+
+	/// Get the usage of the offchain local storage namespace with the given key prefix.
+	///
+	/// Returns `None` if the node does not account for the usage of the namespace.
+	#[method(name = "offchain_localStorageUsage")]
+	fn local_storage_usage(
+		&self,
+		kind: StorageKind,
+		namespace: Bytes,
+	) -> RpcResult<Option<StorageUsage>>;
 }
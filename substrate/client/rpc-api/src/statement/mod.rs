@@ -19,10 +19,25 @@
 //! Substrate Statement Store RPC API.
 
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
 use sp_core::Bytes;
 
 pub mod error;
 
+/// Usage of the statement store by the statements of an account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountUsage {
+	/// Number of statements stored for the account.
+	pub statements: u32,
+	/// Total data size of the statements stored for the account.
+	pub size: u32,
+	/// Max statement count allowed for the account.
+	pub max_count: u32,
+	/// Max total data size allowed for the account.
+	pub max_size: u32,
+}
+
 /// Substrate statement RPC API
 #[rpc(client, server)]
 pub trait StatementApi {
@@ -57,4 +72,9 @@ pub trait StatementApi {
 	/// Remove a statement from the store.
 	#[method(name = "statement_remove")]
 	fn remove(&self, statement_hash: [u8; 32]) -> RpcResult<()>;
+
+	/// Return the usage of the store by the statements of `account`, or `None` if it has no
+	/// statements stored.
+	#[method(name = "statement_accountUsage")]
+	fn account_usage(&self, account: [u8; 32]) -> RpcResult<Option<AccountUsage>>;
 }
@@ -18,7 +18,7 @@
 
 //! System RPC module errors.
 
-use crate::system::helpers::Health;
+use crate::system::helpers::{Health, HealthReport};
 use jsonrpsee::{
 	core::Error as JsonRpseeError,
 	types::error::{CallError, ErrorObject},
@@ -33,6 +33,9 @@ pub enum Error {
 	/// Provided block range couldn't be resolved to a list of blocks.
 	#[error("Node is not fully functional: {}", .0)]
 	NotHealthy(Health),
+	/// Some subsystems of the node are not healthy.
+	#[error("Node is not ready: {}", .0)]
+	NotReady(HealthReport),
 	/// Peer argument is malformatted.
 	#[error("{0}")]
 	MalformattedPeerArg(String),
@@ -44,12 +47,16 @@ const BASE_ERROR: i32 = crate::error::base::SYSTEM;
 const NOT_HEALTHY_ERROR: i32 = BASE_ERROR + 1;
 // Peer argument is malformatted.
 const MALFORMATTED_PEER_ARG_ERROR: i32 = BASE_ERROR + 2;
+// Some subsystems of the node are not healthy.
+const NOT_READY_ERROR: i32 = BASE_ERROR + 3;
 
 impl From<Error> for JsonRpseeError {
 	fn from(e: Error) -> Self {
 		match e {
 			Error::NotHealthy(ref h) =>
 				CallError::Custom(ErrorObject::owned(NOT_HEALTHY_ERROR, e.to_string(), Some(h))),
+			Error::NotReady(ref r) =>
+				CallError::Custom(ErrorObject::owned(NOT_READY_ERROR, e.to_string(), Some(r))),
 			Error::MalformattedPeerArg(e) => CallError::Custom(ErrorObject::owned(
 				MALFORMATTED_PEER_ARG_ERROR + 2,
 				e,
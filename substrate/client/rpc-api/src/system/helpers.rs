@@ -92,6 +92,71 @@ pub struct SyncState<Number> {
 	pub highest_block: Number,
 }
 
+/// The health status of a subsystem of the node, or of the node as a whole.
+///
+/// The statuses are ordered from the best to the worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthStatus {
+	/// The subsystem is fully functional.
+	Healthy,
+	/// The subsystem is functional, but not ready to serve, e.g. because it is still syncing.
+	Degraded,
+	/// The subsystem is not functional.
+	Unhealthy,
+}
+
+/// The health of a subsystem of the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsystemHealth {
+	/// Name of the subsystem, e.g. `sync`.
+	pub name: String,
+	/// Health status of the subsystem.
+	pub status: HealthStatus,
+	/// Subsystem specific details about its status.
+	pub details: serde_json::Value,
+}
+
+/// The health of the node, aggregated from the health of its subsystems.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+	/// The worst health status of the subsystems.
+	pub status: HealthStatus,
+	/// The health of each of the subsystems.
+	pub subsystems: Vec<SubsystemHealth>,
+}
+
+impl HealthReport {
+	/// Aggregate the health of the given subsystems.
+	pub fn new(subsystems: Vec<SubsystemHealth>) -> Self {
+		let status = subsystems.iter().map(|s| s.status).max().unwrap_or(HealthStatus::Healthy);
+		HealthReport { status, subsystems }
+	}
+
+	/// Whether the node is ready to serve, i.e. all of its subsystems are healthy.
+	pub fn is_ready(&self) -> bool {
+		self.status == HealthStatus::Healthy
+	}
+}
+
+impl fmt::Display for HealthReport {
+	fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+		let not_healthy = self
+			.subsystems
+			.iter()
+			.filter(|s| s.status != HealthStatus::Healthy)
+			.map(|s| s.name.as_str())
+			.collect::<Vec<_>>();
+		if not_healthy.is_empty() {
+			write!(fmt, "all subsystems healthy")
+		} else {
+			write!(fmt, "{:?} ({})", self.status, not_healthy.join(", "))
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -123,6 +188,28 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_serialize_health_report() {
+		let report = HealthReport::new(vec![
+			SubsystemHealth {
+				name: "network".into(),
+				status: HealthStatus::Healthy,
+				details: serde_json::json!({ "peers": 3 }),
+			},
+			SubsystemHealth {
+				name: "sync".into(),
+				status: HealthStatus::Degraded,
+				details: serde_json::Value::Null,
+			},
+		]);
+		assert!(!report.is_ready());
+		assert_eq!(
+			::serde_json::to_string(&report).unwrap(),
+			r#"{"status":"degraded","subsystems":[{"name":"network","status":"healthy","details":{"peers":3}},{"name":"sync","status":"degraded","details":null}]}"#,
+		);
+		assert!(HealthReport::new(vec![]).is_ready());
+	}
+
 	#[test]
 	fn should_serialize_sync_state() {
 		assert_eq!(
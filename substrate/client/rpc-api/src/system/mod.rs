@@ -23,7 +23,9 @@ use jsonrpsee::{
 	proc_macros::rpc,
 };
 
-pub use self::helpers::{Health, NodeRole, PeerInfo, SyncState, SystemInfo};
+pub use self::helpers::{
+	Health, HealthReport, HealthStatus, NodeRole, PeerInfo, SubsystemHealth, SyncState, SystemInfo,
+};
 
 pub mod error;
 pub mod helpers;
@@ -59,6 +61,16 @@ pub trait SystemApi<Hash, Number> {
 	#[method(name = "system_health")]
 	async fn system_health(&self) -> RpcResult<Health>;
 
+	/// Return the health of each of the major subsystems of the node, e.g. network, sync,
+	/// transaction pool and consensus, along with subsystem specific details.
+	#[method(name = "system_healthReport")]
+	async fn system_health_report(&self) -> RpcResult<HealthReport>;
+
+	/// Return the health report of the node if it is ready to serve, i.e. all of its subsystems
+	/// are healthy, and an error carrying the report otherwise.
+	#[method(name = "system_readiness")]
+	async fn system_readiness(&self) -> RpcResult<HealthReport>;
+
 	/// Returns the base58-encoded PeerId of the node.
 	#[method(name = "system_localPeerId")]
 	async fn system_local_peer_id(&self) -> RpcResult<String>;
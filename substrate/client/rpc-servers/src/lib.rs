@@ -91,6 +91,11 @@ pub async fn start_server<M: Send + Sync + 'static>(
 	let middleware = tower::ServiceBuilder::new()
 		// Proxy `GET /health` requests to internal `system_health` method.
 		.layer(ProxyGetRequestLayer::new("/health", "system_health")?)
+		// Proxy `GET /health/report` requests to internal `system_healthReport` method.
+		.layer(ProxyGetRequestLayer::new("/health/report", "system_healthReport")?)
+		// Proxy `GET /health/readiness` requests to internal `system_readiness` method, which
+		// fails unless all subsystems are healthy, e.g. for readiness probes.
+		.layer(ProxyGetRequestLayer::new("/health/readiness", "system_readiness")?)
 		.layer(try_into_cors(cors)?);
 
 	let mut builder = ServerBuilder::new()
@@ -20,6 +20,29 @@
 
 use serde::{Deserialize, Serialize};
 
+/// The weight of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionWeight {
+	/// The computational time used to execute the transaction, in picoseconds.
+	pub ref_time: u64,
+	/// The size of the proof needed to execute the transaction, in bytes.
+	pub proof_size: u64,
+}
+
+/// The estimated cost of a transaction, computed when it was validated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEstimates {
+	/// The estimated fee of the transaction, excluding the tip, as a decimal string.
+	pub fee: String,
+	/// The estimated weight of the transaction.
+	pub weight: TransactionWeight,
+	/// The number of blocks the transaction is valid for, counting from the block
+	/// it was validated at.
+	pub longevity: u64,
+}
+
 /// The transaction was broadcasted to a number of peers.
 ///
 /// # Note
@@ -35,6 +58,11 @@ use serde::{Deserialize, Serialize};
 pub struct TransactionBroadcasted {
 	/// The number of peers the transaction was broadcasted to.
 	pub num_peers: usize,
+	/// The estimated cost of the transaction.
+	///
+	/// This is `None` if the node is not configured to estimate transactions.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub estimates: Option<TransactionEstimates>,
 }
 
 /// The transaction was included in a block of the chain.
@@ -242,7 +270,7 @@ mod tests {
 	#[test]
 	fn broadcasted_event() {
 		let event: TransactionEvent<()> =
-			TransactionEvent::Broadcasted(TransactionBroadcasted { num_peers: 2 });
+			TransactionEvent::Broadcasted(TransactionBroadcasted { num_peers: 2, estimates: None });
 		let ser = serde_json::to_string(&event).unwrap();
 
 		let exp = r#"{"event":"broadcasted","numPeers":2}"#;
@@ -252,6 +280,25 @@ mod tests {
 		assert_eq!(event_dec, event);
 	}
 
+	#[test]
+	fn broadcasted_event_with_estimates() {
+		let event: TransactionEvent<()> = TransactionEvent::Broadcasted(TransactionBroadcasted {
+			num_peers: 2,
+			estimates: Some(TransactionEstimates {
+				fee: "340282366920938463463374607431768211455".into(),
+				weight: TransactionWeight { ref_time: 1_000, proof_size: 64 },
+				longevity: 64,
+			}),
+		});
+		let ser = serde_json::to_string(&event).unwrap();
+
+		let exp = r#"{"event":"broadcasted","numPeers":2,"estimates":{"fee":"340282366920938463463374607431768211455","weight":{"refTime":1000,"proofSize":64},"longevity":64}}"#;
+		assert_eq!(ser, exp);
+
+		let event_dec: TransactionEvent<()> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+	}
+
 	#[test]
 	fn best_chain_event() {
 		let event: TransactionEvent<()> = TransactionEvent::BestChainBlockIncluded(None);
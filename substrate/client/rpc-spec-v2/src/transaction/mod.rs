@@ -25,6 +25,9 @@
 //!
 //! Methods are prefixed by `transaction`.
 
+#[cfg(test)]
+mod tests;
+
 pub mod api;
 pub mod error;
 pub mod event;
@@ -33,6 +36,6 @@ pub mod transaction;
 pub use api::TransactionApiServer;
 pub use event::{
 	TransactionBlock, TransactionBroadcasted, TransactionDropped, TransactionError,
-	TransactionEvent,
+	TransactionEstimates, TransactionEvent, TransactionWeight,
 };
-pub use transaction::Transaction;
+pub use transaction::{FeeEstimate, Transaction, TransactionEstimator};
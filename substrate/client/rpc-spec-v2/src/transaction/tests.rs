@@ -0,0 +1,94 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use codec::Encode;
+use jsonrpsee::{core::server::rpc_module::Subscription as RpcSubscription, rpc_params};
+use sc_transaction_pool::{BasicPool, FullChainApi};
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sp_core::{blake2_256, bytes::to_hex, testing::TaskExecutor, H256};
+use std::{collections::HashMap, sync::Arc};
+use substrate_test_runtime_client::{
+	runtime::{Block, Extrinsic, ExtrinsicBuilder, Transfer},
+	AccountKeyring, Backend, Client,
+};
+
+type FullTransactionPool = BasicPool<FullChainApi<Client<Backend>, Block>, Block>;
+
+async fn get_next_event<T: serde::de::DeserializeOwned>(sub: &mut RpcSubscription) -> T {
+	let (event, _sub_id) = tokio::time::timeout(std::time::Duration::from_secs(60), sub.next())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	event
+}
+
+/// Estimates every transaction to cost its length.
+struct TestEstimator;
+
+impl TransactionEstimator<Block> for TestEstimator {
+	fn estimate(&self, _at: H256, _xt: &Extrinsic, len: u32) -> Option<FeeEstimate> {
+		Some(FeeEstimate {
+			fee: len as u128,
+			weight: TransactionWeight { ref_time: 1_000, proof_size: 64 },
+		})
+	}
+}
+
+#[tokio::test]
+async fn submit_and_watch_reports_estimates() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let spawner = TaskExecutor::new();
+	let pool: Arc<FullTransactionPool> =
+		BasicPool::new_full(Default::default(), true.into(), None, spawner, client.clone());
+	let api = Transaction::new(client, pool.clone(), Arc::new(TaskExecutor::default()))
+		.with_estimator(TestEstimator)
+		.into_rpc();
+
+	let xt = ExtrinsicBuilder::new_transfer(Transfer {
+		amount: Default::default(),
+		nonce: 0,
+		from: AccountKeyring::Alice.into(),
+		to: AccountKeyring::Bob.into(),
+	})
+	.build()
+	.encode();
+	let xt_hash: H256 = blake2_256(&xt).into();
+	let mut sub = api
+		.subscribe("transaction_unstable_submitAndWatch", rpc_params![to_hex(&xt, true)])
+		.await
+		.unwrap();
+
+	let event: TransactionEvent<H256> = get_next_event(&mut sub).await;
+	assert_eq!(event, TransactionEvent::Validated);
+
+	pool.on_broadcasted(HashMap::from([(xt_hash, vec!["peer".into()])]));
+	let event: TransactionEvent<H256> = get_next_event(&mut sub).await;
+	let longevity = *pool.ready_transaction(&xt_hash).unwrap().longevity();
+	assert_eq!(
+		event,
+		TransactionEvent::Broadcasted(TransactionBroadcasted {
+			num_peers: 1,
+			estimates: Some(TransactionEstimates {
+				fee: xt.len().to_string(),
+				weight: TransactionWeight { ref_time: 1_000, proof_size: 64 },
+				longevity,
+			}),
+		})
+	);
+}
@@ -24,7 +24,7 @@ use crate::{
 		error::Error,
 		event::{
 			TransactionBlock, TransactionBroadcasted, TransactionDropped, TransactionError,
-			TransactionEvent,
+			TransactionEstimates, TransactionEvent, TransactionWeight,
 		},
 	},
 	SubscriptionTaskExecutor,
@@ -38,8 +38,8 @@ use jsonrpsee::{
 	SubscriptionSink,
 };
 use sc_transaction_pool_api::{
-	error::IntoPoolError, BlockHash, TransactionFor, TransactionPool, TransactionSource,
-	TransactionStatus,
+	error::IntoPoolError, BlockHash, InPoolTransaction, TransactionFor, TransactionPool,
+	TransactionSource, TransactionStatus, TxHash,
 };
 use std::sync::Arc;
 
@@ -49,22 +49,74 @@ use sp_core::Bytes;
 use sp_runtime::traits::Block as BlockT;
 
 use codec::Decode;
-use futures::{FutureExt, StreamExt, TryFutureExt};
+use futures::{channel::oneshot, FutureExt, StreamExt, TryFutureExt};
+
+/// The fee and weight of a transaction, as estimated by a [`TransactionEstimator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeEstimate {
+	/// The fee of the transaction, excluding the tip.
+	pub fee: u128,
+	/// The weight of the transaction.
+	pub weight: TransactionWeight,
+}
+
+/// Estimates the fee and weight of the submitted transactions, which are then reported
+/// in the [`TransactionBroadcasted`] events.
+///
+/// This is usually implemented with the `TransactionPaymentApi::query_info` runtime API, see
+/// `TransactionPaymentEstimator` in `pallet-transaction-payment-rpc`.
+pub trait TransactionEstimator<Block: BlockT>: Send + Sync {
+	/// Estimate the fee and weight of the extrinsic `xt`, encoded in `len` bytes, at the
+	/// block `at`.
+	///
+	/// Returns `None` if the extrinsic cannot be estimated.
+	fn estimate(&self, at: Block::Hash, xt: &Block::Extrinsic, len: u32) -> Option<FeeEstimate>;
+}
+
+/// Transactions are not estimated.
+impl<Block: BlockT> TransactionEstimator<Block> for () {
+	fn estimate(&self, _: Block::Hash, _: &Block::Extrinsic, _: u32) -> Option<FeeEstimate> {
+		None
+	}
+}
+
+impl<Block, E> TransactionEstimator<Block> for Arc<E>
+where
+	Block: BlockT,
+	E: TransactionEstimator<Block> + ?Sized,
+{
+	fn estimate(&self, at: Block::Hash, xt: &Block::Extrinsic, len: u32) -> Option<FeeEstimate> {
+		(**self).estimate(at, xt, len)
+	}
+}
 
 /// An API for transaction RPC calls.
-pub struct Transaction<Pool, Client> {
+pub struct Transaction<Pool, Client, Estimator = ()> {
 	/// Substrate client.
 	client: Arc<Client>,
 	/// Transactions pool.
 	pool: Arc<Pool>,
 	/// Executor to spawn subscriptions.
 	executor: SubscriptionTaskExecutor,
+	/// Estimator of the fee and weight of the submitted transactions.
+	estimator: Arc<Estimator>,
 }
 
 impl<Pool, Client> Transaction<Pool, Client> {
 	/// Creates a new [`Transaction`].
+	///
+	/// The submitted transactions are not estimated, see [`Transaction::with_estimator`].
 	pub fn new(client: Arc<Client>, pool: Arc<Pool>, executor: SubscriptionTaskExecutor) -> Self {
-		Transaction { client, pool, executor }
+		Transaction { client, pool, executor, estimator: Arc::new(()) }
+	}
+}
+
+impl<Pool, Client, Estimator> Transaction<Pool, Client, Estimator> {
+	/// Estimate the submitted transactions with `estimator`, reporting the estimates in the
+	/// [`TransactionBroadcasted`] events.
+	pub fn with_estimator<E>(self, estimator: E) -> Transaction<Pool, Client, E> {
+		let Transaction { client, pool, executor, .. } = self;
+		Transaction { client, pool, executor, estimator: Arc::new(estimator) }
 	}
 }
 
@@ -83,12 +135,14 @@ const TX_SOURCE: TransactionSource = TransactionSource::External;
 const BAD_FORMAT: i32 = 1001;
 
 #[async_trait]
-impl<Pool, Client> TransactionApiServer<BlockHash<Pool>> for Transaction<Pool, Client>
+impl<Pool, Client, Estimator> TransactionApiServer<BlockHash<Pool>>
+	for Transaction<Pool, Client, Estimator>
 where
 	Pool: TransactionPool + Sync + Send + 'static,
 	Pool::Hash: Unpin,
 	<Pool::Block as BlockT>::Hash: Unpin,
 	Client: HeaderBackend<Pool::Block> + ProvideRuntimeApi<Pool::Block> + Send + Sync + 'static,
+	Estimator: TransactionEstimator<Pool::Block> + 'static,
 {
 	fn submit_and_watch(&self, mut sink: SubscriptionSink, xt: Bytes) -> SubscriptionResult {
 		// This is the only place where the RPC server can return an error for this
@@ -108,6 +162,22 @@ where
 
 		let best_block_hash = self.client.info().best_hash;
 
+		// The fee and weight are estimated at the block the transaction is validated at. This
+		// calls into the runtime, so it is done in a blocking task.
+		let (estimate_tx, estimate_rx) = oneshot::channel();
+		let estimator = self.estimator.clone();
+		let (estimated_xt, len) = (decoded_extrinsic.clone(), xt.len() as u32);
+		self.executor.spawn_blocking(
+			"substrate-rpc-transaction-estimate",
+			Some("rpc"),
+			async move {
+				let _ = estimate_tx.send(estimator.estimate(best_block_hash, &estimated_xt, len));
+			}
+			.boxed(),
+		);
+		let tx_hash = self.pool.hash_of(&decoded_extrinsic);
+		let pool = self.pool.clone();
+
 		let submit = self
 			.pool
 			.submit_and_watch(best_block_hash, TX_SOURCE, decoded_extrinsic)
@@ -120,9 +190,18 @@ where
 		let fut = async move {
 			match submit.await {
 				Ok(stream) => {
-					let mut state = TransactionState::new();
-					let stream =
-						stream.filter_map(|event| async move { state.handle_event(event) });
+					let fee_estimate = estimate_rx.await.ok().flatten();
+					let estimates = fee_estimate.and_then(|estimate| {
+						let longevity = longevity(&*pool, &tx_hash)?;
+						Some(TransactionEstimates {
+							fee: estimate.fee.to_string(),
+							weight: estimate.weight,
+							longevity,
+						})
+					});
+					let mut state = TransactionState::new(estimates);
+					let stream = stream
+						.filter_map(move |event| futures::future::ready(state.handle_event(event)));
 					sink.pipe_from_stream(stream.boxed()).await;
 				},
 				Err(err) => {
@@ -139,6 +218,14 @@ where
 	}
 }
 
+/// The longevity the transaction `hash` was given by its validation, if it is in the pool.
+fn longevity<Pool: TransactionPool>(pool: &Pool, hash: &TxHash<Pool>) -> Option<u64> {
+	match pool.ready_transaction(hash) {
+		Some(tx) => Some(*tx.longevity()),
+		None => pool.futures().iter().find(|tx| tx.hash() == hash).map(|tx| *tx.longevity()),
+	}
+}
+
 /// The transaction's state that needs to be preserved between
 /// multiple events generated by the transaction-pool.
 ///
@@ -146,16 +233,18 @@ where
 ///
 /// In the future, the RPC server can submit only the last event when multiple
 /// identical events happen in a row.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct TransactionState {
 	/// True if the transaction was previously broadcasted.
 	broadcasted: bool,
+	/// The estimated cost of the transaction, reported when it is broadcasted.
+	estimates: Option<TransactionEstimates>,
 }
 
 impl TransactionState {
 	/// Construct a new [`TransactionState`].
-	pub fn new() -> Self {
-		TransactionState { broadcasted: false }
+	pub fn new(estimates: Option<TransactionEstimates>) -> Self {
+		TransactionState { broadcasted: false, estimates }
 	}
 
 	/// Handle events generated by the transaction-pool and convert them
@@ -175,6 +264,7 @@ impl TransactionState {
 
 				Some(TransactionEvent::Broadcasted(TransactionBroadcasted {
 					num_peers: peers.len(),
+					estimates: self.estimates.clone(),
 				}))
 			},
 			TransactionStatus::InBlock((hash, index)) =>
@@ -246,6 +246,26 @@ async fn author_should_rotate_keys() {
 	assert!(sr25519_pubkeys.contains(&session_keys.sr25519.to_raw_vec()));
 }
 
+#[tokio::test]
+async fn author_should_rotate_keys_with_owner() {
+	use sp_runtime::traits::OpaqueKeys;
+
+	let setup = TestSetup::default();
+	let api = setup.author().into_rpc();
+
+	let owner = AccountKeyring::Alice.to_account_id().encode();
+	let generated: GeneratedSessionKeys =
+		api.call("author_rotateKeysWithOwner", [Bytes(owner.clone())]).await.unwrap();
+	let session_keys =
+		SessionKeys::decode(&mut &generated.keys[..]).expect("SessionKeys decode successfully");
+	let ed25519_pubkeys = setup.keystore.keys(ED25519).unwrap();
+	assert!(ed25519_pubkeys.contains(&session_keys.ed25519.to_raw_vec()));
+	assert!(session_keys.ownership_proof_is_valid(&owner, &generated.proof));
+
+	let other = AccountKeyring::Bob.to_account_id().encode();
+	assert!(!session_keys.ownership_proof_is_valid(&other, &generated.proof));
+}
+
 #[tokio::test]
 async fn author_has_session_keys() {
 	// Setup
@@ -22,6 +22,7 @@
 mod tests;
 
 use self::error::Error;
+use codec::Decode;
 use jsonrpsee::core::{async_trait, Error as JsonRpseeError, RpcResult};
 use parking_lot::RwLock;
 /// Re-export the API for backward compatibility.
@@ -71,4 +72,24 @@ impl<T: OffchainStorage + 'static> OffchainApiServer for Offchain<T> {
 
 		Ok(self.storage.read().get(prefix, &key).map(Into::into))
 	}
+
+	fn local_storage_usage(
+		&self,
+		kind: StorageKind,
+		namespace: Bytes,
+	) -> RpcResult<Option<StorageUsage>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let prefix = match kind {
+			StorageKind::PERSISTENT => sp_offchain::USAGE_PREFIX,
+			StorageKind::LOCAL => return Err(JsonRpseeError::from(Error::UnavailableStorageKind)),
+		};
+
+		Ok(self
+			.storage
+			.read()
+			.get(prefix, &namespace)
+			.and_then(|usage| sp_offchain::StorageUsage::decode(&mut &usage[..]).ok())
+			.map(|usage| StorageUsage { used: usage.used, quota: usage.quota }))
+	}
 }
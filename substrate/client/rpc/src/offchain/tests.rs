@@ -37,6 +37,26 @@ fn local_storage_should_work() {
 	);
 }
 
+#[test]
+fn local_storage_usage_should_work() {
+	use codec::Encode;
+	use sp_core::offchain::OffchainStorage;
+
+	let mut storage = InMemOffchainStorage::default();
+	let usage = sp_offchain::StorageUsage { used: 42, quota: Some(100) };
+	storage.set(sp_offchain::USAGE_PREFIX, b"pallet/", &usage.encode());
+	let offchain = Offchain::new(storage, DenyUnsafe::No);
+
+	assert_matches!(
+		offchain.local_storage_usage(StorageKind::PERSISTENT, Bytes(b"pallet/".to_vec())),
+		Ok(Some(StorageUsage { used: 42, quota: Some(100) }))
+	);
+	assert_matches!(
+		offchain.local_storage_usage(StorageKind::PERSISTENT, Bytes(b"other/".to_vec())),
+		Ok(None)
+	);
+}
+
 #[test]
 fn offchain_calls_considered_unsafe() {
 	use jsonrpsee::types::error::CallError;
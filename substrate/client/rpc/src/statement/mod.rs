@@ -21,7 +21,7 @@
 use codec::{Decode, Encode};
 use jsonrpsee::core::{async_trait, RpcResult};
 /// Re-export the API for backward compatibility.
-pub use sc_rpc_api::statement::{error::Error, StatementApiServer};
+pub use sc_rpc_api::statement::{error::Error, AccountUsage, StatementApiServer};
 use sc_rpc_api::DenyUnsafe;
 use sp_core::Bytes;
 use sp_statement_store::{StatementSource, SubmitResult};
@@ -105,4 +105,15 @@ impl StatementApiServer for StatementStore {
 	fn remove(&self, hash: [u8; 32]) -> RpcResult<()> {
 		Ok(self.store.remove(&hash).map_err(|e| Error::StatementStore(e.to_string()))?)
 	}
+
+	fn account_usage(&self, account: [u8; 32]) -> RpcResult<Option<AccountUsage>> {
+		let usage =
+			self.store.account_usage(&account).map_err(|e| Error::StatementStore(e.to_string()))?;
+		Ok(usage.map(|usage| AccountUsage {
+			statements: usage.statements,
+			size: usage.size,
+			max_count: usage.max_count,
+			max_size: usage.max_size,
+		}))
+	}
 }
@@ -30,10 +30,13 @@ use sc_rpc_api::DenyUnsafe;
 use sc_tracing::logging;
 use sc_utils::mpsc::TracingUnboundedSender;
 use sp_runtime::traits::{self, Header as HeaderT};
+use std::sync::Arc;
 
 use self::error::Result;
 
-pub use self::helpers::{Health, NodeRole, PeerInfo, SyncState, SystemInfo};
+pub use self::helpers::{
+	Health, HealthReport, HealthStatus, NodeRole, PeerInfo, SubsystemHealth, SyncState, SystemInfo,
+};
 pub use sc_rpc_api::system::*;
 
 /// System API implementation
@@ -41,6 +44,14 @@ pub struct System<B: traits::Block> {
 	info: SystemInfo,
 	send_back: TracingUnboundedSender<Request<B>>,
 	deny_unsafe: DenyUnsafe,
+	health_checks: Vec<Arc<dyn HealthCheck>>,
+}
+
+/// A check of the health of a subsystem of the node, reported by `system_healthReport` along with
+/// the health of the network and of the syncing.
+pub trait HealthCheck: Send + Sync {
+	/// Check the health of the subsystem.
+	fn check(&self) -> SubsystemHealth;
 }
 
 /// Request to be processed.
@@ -78,7 +89,50 @@ impl<B: traits::Block> System<B> {
 		send_back: TracingUnboundedSender<Request<B>>,
 		deny_unsafe: DenyUnsafe,
 	) -> Self {
-		System { info, send_back, deny_unsafe }
+		System { info, send_back, deny_unsafe, health_checks: Vec::new() }
+	}
+
+	/// Include the health of the subsystems checked by `health_checks` in the health report.
+	pub fn with_health_checks(mut self, health_checks: Vec<Arc<dyn HealthCheck>>) -> Self {
+		self.health_checks.extend(health_checks);
+		self
+	}
+
+	/// The health of the network and of the syncing, followed by the health of the subsystems
+	/// checked by the health checks.
+	async fn health_report(&self) -> RpcResult<HealthReport> {
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::Health(tx));
+		let health = rx.await.map_err(|e| JsonRpseeError::to_call_error(e))?;
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::SyncState(tx));
+		let sync_state = rx.await.map_err(|e| JsonRpseeError::to_call_error(e))?;
+
+		let network = SubsystemHealth {
+			name: "network".into(),
+			status: if health.should_have_peers && health.peers == 0 {
+				HealthStatus::Unhealthy
+			} else {
+				HealthStatus::Healthy
+			},
+			details: serde_json::json!({
+				"peers": health.peers,
+				"shouldHavePeers": health.should_have_peers,
+			}),
+		};
+		let sync = SubsystemHealth {
+			name: "sync".into(),
+			status: if health.is_syncing { HealthStatus::Degraded } else { HealthStatus::Healthy },
+			details: serde_json::json!({
+				"isSyncing": health.is_syncing,
+				"currentBlock": sync_state.current_block,
+				"highestBlock": sync_state.highest_block,
+			}),
+		};
+
+		let mut subsystems = vec![network, sync];
+		subsystems.extend(self.health_checks.iter().map(|check| check.check()));
+		Ok(HealthReport::new(subsystems))
 	}
 }
 
@@ -110,6 +164,19 @@ impl<B: traits::Block> SystemApiServer<B::Hash, <B::Header as HeaderT>::Number>
 		rx.await.map_err(|e| JsonRpseeError::to_call_error(e))
 	}
 
+	async fn system_health_report(&self) -> RpcResult<HealthReport> {
+		self.health_report().await
+	}
+
+	async fn system_readiness(&self) -> RpcResult<HealthReport> {
+		let report = self.health_report().await?;
+		if report.is_ready() {
+			Ok(report)
+		} else {
+			Err(error::Error::NotReady(report).into())
+		}
+	}
+
 	async fn system_local_peer_id(&self) -> RpcResult<String> {
 		let (tx, rx) = oneshot::channel();
 		let _ = self.send_back.unbounded_send(Request::LocalPeerId(tx));
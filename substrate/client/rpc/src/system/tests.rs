@@ -222,6 +222,42 @@ async fn system_health() {
 	);
 }
 
+#[tokio::test]
+async fn system_health_report() {
+	let report = api(None)
+		.call::<_, HealthReport>("system_healthReport", EmptyParams::new())
+		.await
+		.unwrap();
+	assert_eq!(report.status, HealthStatus::Unhealthy);
+	assert_eq!(
+		report.subsystems.iter().map(|s| (s.name.as_str(), s.status)).collect::<Vec<_>>(),
+		vec![("network", HealthStatus::Unhealthy), ("sync", HealthStatus::Healthy)],
+	);
+	assert_eq!(report.subsystems[1].details["highestBlock"], 3);
+
+	let syncing = Status { peer_id: PeerId::random(), peers: 5, is_syncing: true, is_dev: false };
+	let report = api(syncing)
+		.call::<_, HealthReport>("system_healthReport", EmptyParams::new())
+		.await
+		.unwrap();
+	assert_eq!(report.status, HealthStatus::Degraded);
+}
+
+#[tokio::test]
+async fn system_readiness() {
+	let ready = Status { peer_id: PeerId::random(), peers: 5, is_syncing: false, is_dev: false };
+	assert!(api(ready)
+		.call::<_, HealthReport>("system_readiness", EmptyParams::new())
+		.await
+		.unwrap()
+		.is_ready());
+
+	assert_matches!(
+		api(None).call::<_, HealthReport>("system_readiness", EmptyParams::new()).await,
+		Err(RpcError::Call(CallError::Custom(err))) if err.message().contains("Node is not ready")
+	);
+}
+
 #[tokio::test]
 async fn system_local_peer_id_works() {
 	assert_eq!(
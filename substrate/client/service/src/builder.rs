@@ -21,6 +21,7 @@ use crate::{
 	client::{Client, ClientConfig},
 	config::{Configuration, KeystoreConfig, PrometheusConfig},
 	error::Error,
+	health::{FinalityHealth, TransactionPoolHealth},
 	metrics::MetricsService,
 	start_rpc_servers, BuildGenesisBlock, GenesisBlockBuilder, RpcHandlers, SpawnTaskHandle,
 	TaskManager, TransactionPoolAdapter,
@@ -41,6 +42,8 @@ use sc_executor::{
 	NativeExecutionDispatch, RuntimeVersionOf, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY,
 };
 use sc_keystore::LocalKeystore;
+#[cfg(unix)]
+use sc_keystore::RemoteKeystore;
 use sc_network::{
 	config::{FullNetworkConfiguration, SyncMode},
 	peer_store::PeerStore,
@@ -60,10 +63,13 @@ use sc_rpc::{
 	chain::ChainApiServer,
 	offchain::OffchainApiServer,
 	state::{ChildStateApiServer, StateApiServer},
-	system::SystemApiServer,
+	system::{HealthCheck, SystemApiServer},
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
-use sc_rpc_spec_v2::{chain_head::ChainHeadApiServer, transaction::TransactionApiServer};
+use sc_rpc_spec_v2::{
+	chain_head::ChainHeadApiServer,
+	transaction::{TransactionApiServer, TransactionEstimator},
+};
 use sc_telemetry::{telemetry, ConnectionMessage, Telemetry, TelemetryHandle, SUBSTRATE_INFO};
 use sc_transaction_pool_api::{MaintainedTransactionPool, TransactionPool};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
@@ -91,28 +97,45 @@ type TFullParts<TBl, TRtApi, TExec> =
 	(TFullClient<TBl, TRtApi, TExec>, Arc<TFullBackend<TBl>>, KeystoreContainer, TaskManager);
 
 /// Construct a local keystore shareable container
-pub struct KeystoreContainer(Arc<LocalKeystore>);
+pub struct KeystoreContainer {
+	keystore: KeystorePtr,
+	local: Arc<LocalKeystore>,
+}
 
 impl KeystoreContainer {
 	/// Construct KeystoreContainer
 	pub fn new(config: &KeystoreConfig) -> Result<Self, Error> {
-		let keystore = Arc::new(match config {
-			KeystoreConfig::Path { path, password } =>
+		let local = Arc::new(match config {
+			KeystoreConfig::Path { path, password } |
+			KeystoreConfig::Remote { path, password, .. } =>
 				LocalKeystore::open(path.clone(), password.clone())?,
 			KeystoreConfig::InMemory => LocalKeystore::in_memory(),
 		});
 
-		Ok(Self(keystore))
+		let keystore: KeystorePtr = match config {
+			#[cfg(unix)]
+			KeystoreConfig::Remote { socket, timeout, .. } =>
+				Arc::new(RemoteKeystore::open(socket.clone(), *timeout, local.clone())?),
+			#[cfg(not(unix))]
+			KeystoreConfig::Remote { .. } =>
+				return Err(Error::Other("Remote signers are only supported on Unix".into())),
+			KeystoreConfig::Path { .. } | KeystoreConfig::InMemory => local.clone(),
+		};
+
+		Ok(Self { keystore, local })
 	}
 
 	/// Returns a shared reference to a dynamic `Keystore` trait implementation.
 	pub fn keystore(&self) -> KeystorePtr {
-		self.0.clone()
+		self.keystore.clone()
 	}
 
 	/// Returns a shared reference to the local keystore .
+	///
+	/// When using a remote signer, this is the keystore holding the keys the remote signer does
+	/// not hold, the keys of the remote signer are not accessible through it.
 	pub fn local_keystore(&self) -> Arc<LocalKeystore> {
-		self.0.clone()
+		self.local.clone()
 	}
 }
 
@@ -135,6 +158,23 @@ pub fn new_full_parts<TBl, TRtApi, TExec>(
 	telemetry: Option<TelemetryHandle>,
 	executor: TExec,
 ) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
+where
+	TBl: BlockT,
+	TExec: CodeExecutor + RuntimeVersionOf + Clone,
+{
+	new_full_parts_record_import(config, telemetry, executor, false)
+}
+
+/// Create the initial parts of a full node with the default genesis block builder.
+///
+/// With `enable_import_proof_recording`, storage proofs are also recorded while importing blocks,
+/// which is required by runtimes querying the proof size (e.g. parachain runtimes).
+pub fn new_full_parts_record_import<TBl, TRtApi, TExec>(
+	config: &Configuration,
+	telemetry: Option<TelemetryHandle>,
+	executor: TExec,
+	enable_import_proof_recording: bool,
+) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
 where
 	TBl: BlockT,
 	TExec: CodeExecutor + RuntimeVersionOf + Clone,
@@ -148,7 +188,14 @@ where
 		executor.clone(),
 	)?;
 
-	new_full_parts_with_genesis_builder(config, telemetry, executor, backend, genesis_block_builder)
+	new_full_parts_with_genesis_builder(
+		config,
+		telemetry,
+		executor,
+		backend,
+		genesis_block_builder,
+		enable_import_proof_recording,
+	)
 }
 
 /// Create the initial parts of a full node.
@@ -158,6 +205,7 @@ pub fn new_full_parts_with_genesis_builder<TBl, TRtApi, TExec, TBuildGenesisBloc
 	executor: TExec,
 	backend: Arc<TFullBackend<TBl>>,
 	genesis_block_builder: TBuildGenesisBlock,
+	enable_import_proof_recording: bool,
 ) -> Result<TFullParts<TBl, TRtApi, TExec>, Error>
 where
 	TBl: BlockT,
@@ -225,6 +273,7 @@ where
 					SyncMode::LightState { .. } | SyncMode::Warp { .. }
 				),
 				wasm_runtime_substitutes,
+				enable_import_proof_recording,
 			},
 		)?;
 
@@ -246,13 +295,18 @@ pub fn new_wasm_executor<H: HostFunctions>(config: &Configuration) -> WasmExecut
 	let strategy = config
 		.default_heap_pages
 		.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |p| HeapAllocStrategy::Static { extra_pages: p as _ });
-	WasmExecutor::<H>::builder()
+	let builder = WasmExecutor::<H>::builder()
 		.with_execution_method(config.wasm_method)
 		.with_onchain_heap_alloc_strategy(strategy)
 		.with_offchain_heap_alloc_strategy(strategy)
 		.with_max_runtime_instances(config.max_runtime_instances)
-		.with_runtime_cache_size(config.runtime_cache_size)
-		.build()
+		.with_min_runtime_instances(config.min_runtime_instances)
+		.with_runtime_cache_size(config.runtime_cache_size);
+	let builder = match config.prometheus_registry() {
+		Some(registry) => builder.with_prometheus_registry(registry.clone()),
+		None => builder,
+	};
+	builder.build()
 }
 
 /// Create an instance of default DB-backend backend.
@@ -346,6 +400,13 @@ pub struct SpawnTasksParams<'a, TBl: BlockT, TCl, TExPool, TRpc, Backend> {
 	/// Builds additional [`RpcModule`]s that should be added to the server
 	pub rpc_builder:
 		Box<dyn Fn(DenyUnsafe, SubscriptionTaskExecutor) -> Result<RpcModule<TRpc>, Error>>,
+	/// Estimates the transactions submitted with `transaction_unstable_submitAndWatch`.
+	///
+	/// The estimates are not reported if this is `None`.
+	pub transaction_estimator: Option<Arc<dyn TransactionEstimator<TBl>>>,
+	/// Additional health checks reported by `system_healthReport`, after the ones of the
+	/// transaction pool and of the finality.
+	pub health_checks: Vec<Arc<dyn HealthCheck>>,
 	/// A shared network instance.
 	pub network: Arc<dyn SpawnTaskNetwork<TBl>>,
 	/// A Sender for RPC requests.
@@ -396,6 +457,8 @@ where
 		keystore,
 		transaction_pool,
 		rpc_builder,
+		transaction_estimator,
+		health_checks,
 		network,
 		system_rpc_tx,
 		tx_handler_controller,
@@ -485,6 +548,8 @@ where
 			&config,
 			backend.clone(),
 			&*rpc_builder,
+			transaction_estimator.clone(),
+			health_checks.clone(),
 		)
 	};
 
@@ -584,6 +649,8 @@ fn gen_rpc_module<TBl, TBackend, TCl, TRpc, TExPool>(
 	config: &Configuration,
 	backend: Arc<TBackend>,
 	rpc_builder: &(dyn Fn(DenyUnsafe, SubscriptionTaskExecutor) -> Result<RpcModule<TRpc>, Error>),
+	transaction_estimator: Option<Arc<dyn TransactionEstimator<TBl>>>,
+	extra_health_checks: Vec<Arc<dyn HealthCheck>>,
 ) -> Result<RpcModule<()>, Error>
 where
 	TBl: BlockT,
@@ -626,11 +693,14 @@ where
 		(chain, state, child_state)
 	};
 
+	let transaction_estimator: Arc<dyn TransactionEstimator<TBl>> =
+		transaction_estimator.unwrap_or_else(|| Arc::new(()));
 	let transaction_v2 = sc_rpc_spec_v2::transaction::Transaction::new(
 		client.clone(),
 		transaction_pool.clone(),
 		task_executor.clone(),
 	)
+	.with_estimator(transaction_estimator)
 	.into_rpc();
 
 	let chain_head_v2 = sc_rpc_spec_v2::chain_head::ChainHead::new(
@@ -642,6 +712,12 @@ where
 	)
 	.into_rpc();
 
+	let mut health_checks: Vec<Arc<dyn HealthCheck>> = vec![
+		Arc::new(TransactionPoolHealth(transaction_pool.clone())),
+		Arc::new(FinalityHealth::new(client.clone())),
+	];
+	health_checks.extend(extra_health_checks);
+
 	let author = sc_rpc::author::Author::new(
 		client.clone(),
 		transaction_pool,
@@ -651,7 +727,9 @@ where
 	)
 	.into_rpc();
 
-	let system = sc_rpc::system::System::new(system_info, system_rpc_tx, deny_unsafe).into_rpc();
+	let system = sc_rpc::system::System::new(system_info, system_rpc_tx, deny_unsafe)
+		.with_health_checks(health_checks)
+		.into_rpc();
 
 	if let Some(storage) = backend.offchain_storage() {
 		let offchain = sc_rpc::offchain::Offchain::new(storage, deny_unsafe).into_rpc();
@@ -40,6 +40,7 @@ use std::{
 	io, iter,
 	net::SocketAddr,
 	path::{Path, PathBuf},
+	time::Duration,
 };
 use tempfile::TempDir;
 
@@ -127,6 +128,10 @@ pub struct Configuration {
 	///
 	/// The default value is 8.
 	pub max_runtime_instances: usize,
+	/// The size the instances cache shrinks back to when idle.
+	///
+	/// The default value is 1.
+	pub min_runtime_instances: usize,
 	/// Announce block automatically after they have been imported
 	pub announce_block: bool,
 	/// Data path root for the configured chain.
@@ -160,13 +165,26 @@ pub enum KeystoreConfig {
 	},
 	/// In-memory keystore. Recommended for in-browser nodes.
 	InMemory,
+	/// Keystore signing with the keys of a remote signer listening on a Unix domain socket, e.g. a
+	/// bridge to a Hardware Security Module. Keys the remote signer does not hold are kept in a
+	/// keystore at a path on-disk.
+	Remote {
+		/// The path of the socket of the remote signer.
+		socket: PathBuf,
+		/// How long to wait for the remote signer to answer a request.
+		timeout: Duration,
+		/// The path of the keystore.
+		path: PathBuf,
+		/// Node keystore's password.
+		password: Option<SecretString>,
+	},
 }
 
 impl KeystoreConfig {
 	/// Returns the path for the keystore.
 	pub fn path(&self) -> Option<&Path> {
 		match self {
-			Self::Path { path, .. } => Some(path),
+			Self::Path { path, .. } | Self::Remote { path, .. } => Some(path),
 			Self::InMemory => None,
 		}
 	}
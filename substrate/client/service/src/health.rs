@@ -0,0 +1,78 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Health checks of the subsystems of the node, reported by the `system_healthReport` RPC.
+
+use sc_client_api::blockchain::HeaderBackend;
+use sc_rpc::system::{HealthCheck, HealthStatus, SubsystemHealth};
+use sc_transaction_pool_api::TransactionPool;
+use sp_runtime::traits::{Block as BlockT, Saturating, UniqueSaturatedInto};
+use std::{marker::PhantomData, sync::Arc};
+
+/// The number of blocks the finalized block may lag behind the best block before the consensus
+/// is reported as degraded.
+const MAX_FINALITY_LAG: u64 = 512;
+
+/// Reports the number of transactions in the transaction pool.
+pub(crate) struct TransactionPoolHealth<P>(pub(crate) Arc<P>);
+
+impl<P: TransactionPool> HealthCheck for TransactionPoolHealth<P> {
+	fn check(&self) -> SubsystemHealth {
+		let status = self.0.status();
+		SubsystemHealth {
+			name: "txPool".into(),
+			status: HealthStatus::Healthy,
+			details: serde_json::json!({
+				"ready": status.ready,
+				"future": status.future,
+			}),
+		}
+	}
+}
+
+/// Reports the consensus as degraded if the finalized block lags too far behind the best block.
+pub(crate) struct FinalityHealth<B, C> {
+	client: Arc<C>,
+	_phantom: PhantomData<B>,
+}
+
+impl<B, C> FinalityHealth<B, C> {
+	pub(crate) fn new(client: Arc<C>) -> Self {
+		FinalityHealth { client, _phantom: PhantomData }
+	}
+}
+
+impl<B: BlockT, C: HeaderBackend<B>> HealthCheck for FinalityHealth<B, C> {
+	fn check(&self) -> SubsystemHealth {
+		let info = self.client.info();
+		let lag: u64 =
+			info.best_number.saturating_sub(info.finalized_number).unique_saturated_into();
+		SubsystemHealth {
+			name: "consensus".into(),
+			status: if lag > MAX_FINALITY_LAG {
+				HealthStatus::Degraded
+			} else {
+				HealthStatus::Healthy
+			},
+			details: serde_json::json!({
+				"bestBlock": info.best_number,
+				"finalizedBlock": info.finalized_number,
+			}),
+		}
+	}
+}
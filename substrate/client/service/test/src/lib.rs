@@ -263,6 +263,7 @@ fn node_config<
 		tracing_targets: None,
 		tracing_receiver: Default::default(),
 		max_runtime_instances: 8,
+		min_runtime_instances: 1,
 		announce_block: true,
 		base_path: BasePath::new(root.clone()),
 		data_path: root,
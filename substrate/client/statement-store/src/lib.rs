@@ -28,7 +28,8 @@
 //! The following constraints are then checked:
 //! * For a given account id, there may be at most `max_count` statements with `max_size` total data
 //!   size. To satisfy this, statements for this account ID are removed from the store starting with
-//!   the lowest priority until a constraint is satisfied.
+//!   the lowest priority until a constraint is satisfied. `max_size` is capped by
+//!   `Options::max_account_size`, if set.
 //! * There may not be more than `MAX_TOTAL_STATEMENTS` total statements with `MAX_TOTAL_SIZE` size.
 //!   To satisfy this, statements are removed from the store starting with the lowest
 //!   `global_priority` until a constraint is satisfied.
@@ -43,12 +44,21 @@
 //! explicitly with the `remove` function) the statement is marked as expired. Expired statements
 //! can't be added to the store for `Options::purge_after_sec` seconds. This is to prevent old
 //! statements from being propagated on the network.
+//!
+//! Statement retention.
+//!
+//! Topics may be configured with a retention period with `Options::with_topic_retention`. A
+//! statement with such a topic is removed from the store once the shortest retention period of its
+//! topics has passed since it was inserted, and is then marked as expired as above. Statements
+//! loaded from the database on startup are considered to be inserted at startup.
 
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
 
 mod metrics;
+mod params;
 
+pub use params::StatementStoreParams;
 pub use sp_statement_store::{Error, StatementStore, MAX_TOPICS};
 
 use metrics::MetricsLink as PrometheusMetrics;
@@ -63,8 +73,8 @@ use sp_statement_store::{
 	runtime_api::{
 		InvalidStatement, StatementSource, StatementStoreExt, ValidStatement, ValidateStatement,
 	},
-	AccountId, BlockHash, Channel, DecryptionKey, Hash, NetworkPriority, Proof, Result, Statement,
-	SubmitResult, Topic,
+	AccountId, AccountUsage, BlockHash, Channel, DecryptionKey, Hash, NetworkPriority, Proof,
+	Result, Statement, SubmitResult, Topic,
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
@@ -125,6 +135,10 @@ struct StatementsForAccount {
 	channels: HashMap<Channel, ChannelEntry>,
 	// Sum of all `Data` field sizes.
 	data_size: usize,
+	// Max statement count, as of the latest validation.
+	max_count: u32,
+	// Max total data size, as of the latest validation.
+	max_size: u32,
 }
 
 /// Store configuration
@@ -137,6 +151,11 @@ pub struct Options {
 	max_total_size: usize,
 	/// Number of seconds for which removed statements won't be allowed to be added back in.
 	purge_after_sec: u64,
+	/// Maximum total data size allowed for the statements of an account, regardless of the size
+	/// allowed by the runtime.
+	max_account_size: Option<u32>,
+	/// Number of seconds for which statements with a topic are retained, by topic.
+	topic_retention: HashMap<Topic, u64>,
 }
 
 impl Default for Options {
@@ -145,10 +164,27 @@ impl Default for Options {
 			max_total_statements: DEFAULT_MAX_TOTAL_STATEMENTS,
 			max_total_size: DEFAULT_MAX_TOTAL_SIZE,
 			purge_after_sec: DEFAULT_PURGE_AFTER_SEC,
+			max_account_size: None,
+			topic_retention: HashMap::new(),
 		}
 	}
 }
 
+impl Options {
+	/// Allow the statements of an account at most `max_size` total data size, even if the runtime
+	/// allows more.
+	pub fn with_max_account_size(mut self, max_size: u32) -> Self {
+		self.max_account_size = Some(max_size);
+		self
+	}
+
+	/// Retain statements with `topic` for `retention_sec` seconds after they were inserted.
+	pub fn with_topic_retention(mut self, topic: Topic, retention_sec: u64) -> Self {
+		self.topic_retention.insert(topic, retention_sec);
+		self
+	}
+}
+
 #[derive(Default)]
 struct Index {
 	by_topic: HashMap<Topic, HashSet<Hash>>,
@@ -156,6 +192,7 @@ struct Index {
 	topics_and_keys: HashMap<Hash, ([Option<Topic>; MAX_TOPICS], Option<DecryptionKey>)>,
 	entries: HashMap<Hash, (AccountId, Priority, usize)>,
 	expired: HashMap<Hash, u64>, // Value is expiration timestamp.
+	retained: HashMap<Hash, u64>, // Value is the timestamp until which the statement is retained.
 	accounts: HashMap<AccountId, StatementsForAccount>,
 	options: Options,
 	total_size: usize,
@@ -224,7 +261,13 @@ impl Index {
 		Index { options, ..Default::default() }
 	}
 
-	fn insert_new(&mut self, hash: Hash, account: AccountId, statement: &Statement) {
+	fn insert_new(
+		&mut self,
+		hash: Hash,
+		account: AccountId,
+		statement: &Statement,
+		current_time: u64,
+	) {
 		let mut all_topics = [None; MAX_TOPICS];
 		let mut nt = 0;
 		while let Some(t) = statement.topic(nt) {
@@ -232,6 +275,14 @@ impl Index {
 			all_topics[nt] = Some(t);
 			nt += 1;
 		}
+		let retention = all_topics
+			.iter()
+			.flatten()
+			.filter_map(|t| self.options.topic_retention.get(t))
+			.min();
+		if let Some(retention) = retention {
+			self.retained.insert(hash, current_time.saturating_add(*retention));
+		}
 		let key = statement.decryption_key();
 		self.by_dec_key.entry(key).or_default().insert(hash);
 		if nt > 0 || key.is_some() {
@@ -320,6 +371,25 @@ impl Index {
 		purged
 	}
 
+	fn expire_retained(&mut self, current_time: u64) -> Vec<Hash> {
+		let retired: Vec<_> = self
+			.retained
+			.iter()
+			.filter_map(|(hash, until)| (*until <= current_time).then_some(*hash))
+			.collect();
+		for hash in &retired {
+			self.make_expired(hash, current_time);
+			log::trace!(target: LOG_TARGET, "Retired statement {:?}", HexDisplay::from(hash));
+		}
+		retired
+	}
+
+	fn max_account_size(&self, validation: &ValidStatement) -> u32 {
+		self.options
+			.max_account_size
+			.map_or(validation.max_size, |max_size| max_size.min(validation.max_size))
+	}
+
 	fn make_expired(&mut self, hash: &Hash, current_time: u64) -> bool {
 		if let Some((account, priority, len)) = self.entries.remove(hash) {
 			self.total_size -= len;
@@ -344,6 +414,7 @@ impl Index {
 				}
 			}
 			self.expired.insert(*hash, current_time);
+			self.retained.remove(hash);
 			if let std::collections::hash_map::Entry::Occupied(mut account_rec) =
 				self.accounts.entry(account)
 			{
@@ -374,7 +445,8 @@ impl Index {
 		current_time: u64,
 	) -> MaybeInserted {
 		let statement_len = statement.data_len();
-		if statement_len > validation.max_size as usize {
+		let max_account_size = self.max_account_size(validation);
+		if statement_len > max_account_size as usize {
 			log::debug!(
 				target: LOG_TARGET,
 				"Ignored oversize message: {:?} ({} bytes)",
@@ -387,7 +459,7 @@ impl Index {
 		let mut evicted = HashSet::new();
 		let mut would_free_size = 0;
 		let priority = Priority(statement.priority().unwrap_or(0));
-		let (max_size, max_count) = (validation.max_size as usize, validation.max_count as usize);
+		let (max_size, max_count) = (max_account_size as usize, validation.max_count as usize);
 		// It may happen that we can't delete enough lower priority messages
 		// to satisfy size constraints. We check for that before deleting anything,
 		// taking into account channel message replacement.
@@ -469,7 +541,11 @@ impl Index {
 		for h in &evicted {
 			self.make_expired(h, current_time);
 		}
-		self.insert_new(hash, *account, statement);
+		self.insert_new(hash, *account, statement, current_time);
+		if let Some(account_rec) = self.accounts.get_mut(account) {
+			account_rec.max_count = validation.max_count;
+			account_rec.max_size = max_account_size;
+		}
 		MaybeInserted::Inserted(evicted)
 	}
 }
@@ -584,6 +660,7 @@ impl Store {
 	// iterating the index.
 	fn populate(&self) -> Result<()> {
 		{
+			let current_time = self.timestamp();
 			let mut index = self.index.write();
 			self.db
 				.iter_column_while(col::STATEMENTS, |item| {
@@ -596,7 +673,7 @@ impl Store {
 							HexDisplay::from(&hash)
 						);
 						if let Some(account_id) = statement.account_id() {
-							index.insert_new(hash, account_id, &statement);
+							index.insert_new(hash, account_id, &statement, current_time);
 						} else {
 							log::debug!(
 								target: LOG_TARGET,
@@ -671,19 +748,28 @@ impl Store {
 	/// Perform periodic store maintenance
 	pub fn maintain(&self) {
 		log::trace!(target: LOG_TARGET, "Started store maintenance");
-		let deleted = self.index.write().maintain(self.timestamp());
-		let deleted: Vec<_> =
+		let current_time = self.timestamp();
+		let (deleted, retired) = {
+			let mut index = self.index.write();
+			(index.maintain(current_time), index.expire_retained(current_time))
+		};
+		let mut commit: Vec<_> =
 			deleted.into_iter().map(|hash| (col::EXPIRED, hash.to_vec(), None)).collect();
-		let count = deleted.len() as u64;
-		if let Err(e) = self.db.commit(deleted) {
+		let count = commit.len() as u64;
+		for hash in &retired {
+			commit.push((col::STATEMENTS, hash.to_vec(), None));
+			commit.push((col::EXPIRED, hash.to_vec(), Some((hash, current_time).encode())));
+		}
+		if let Err(e) = self.db.commit(commit) {
 			log::warn!(target: LOG_TARGET, "Error writing to the statement database: {:?}", e);
 		} else {
 			self.metrics.report(|metrics| metrics.statements_pruned.inc_by(count));
 		}
 		log::trace!(
 			target: LOG_TARGET,
-			"Completed store maintenance. Purged: {}, Active: {}, Expired: {}",
+			"Completed store maintenance. Purged: {}, Retired: {}, Active: {}, Expired: {}",
 			count,
+			retired.len(),
 			self.index.read().entries.len(),
 			self.index.read().expired.len()
 		);
@@ -925,17 +1011,27 @@ impl StatementStore for Store {
 		}
 		Ok(())
 	}
+
+	fn account_usage(&self, account: &AccountId) -> Result<Option<AccountUsage>> {
+		Ok(self.index.read().accounts.get(account).map(|account_rec| AccountUsage {
+			statements: account_rec.by_priority.len() as u32,
+			size: account_rec.data_size as u32,
+			max_count: account_rec.max_count,
+			max_size: account_rec.max_size,
+		}))
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::Store;
+	use crate::{Options, Store};
 	use sc_keystore::Keystore;
 	use sp_core::Pair;
 	use sp_statement_store::{
 		runtime_api::{InvalidStatement, ValidStatement, ValidateStatement},
-		AccountId, Channel, DecryptionKey, NetworkPriority, Proof, SignatureVerificationResult,
-		Statement, StatementSource, StatementStore, SubmitResult, Topic,
+		AccountId, AccountUsage, Channel, DecryptionKey, NetworkPriority, Proof,
+		SignatureVerificationResult, Statement, StatementSource, StatementStore, SubmitResult,
+		Topic,
 	};
 
 	type Extrinsic = sp_runtime::OpaqueExtrinsic;
@@ -1277,6 +1373,58 @@ mod tests {
 		assert_eq!(store.index.read().expired.len(), 0);
 	}
 
+	#[test]
+	fn statements_are_retired_after_topic_retention() {
+		let (mut store, _temp) = test_store();
+		store.index.write().options = Options::default()
+			.with_topic_retention(topic(1), 10)
+			.with_topic_retention(topic(2), 5);
+		store.set_time(0);
+		let mut short = statement(1, 1, None, 100);
+		short.set_topic(0, topic(1));
+		short.set_topic(1, topic(2));
+		let mut long = statement(2, 1, None, 100);
+		long.set_topic(0, topic(1));
+		let forever = statement(3, 1, None, 100);
+		for s in [&short, &long, &forever] {
+			store.submit(s.clone(), StatementSource::Network);
+		}
+		assert_eq!(store.index.read().retained.len(), 2);
+
+		store.set_time(5);
+		store.maintain();
+		assert_eq!(store.statement(&short.hash()).unwrap(), None);
+		assert!(store.index.read().expired.contains_key(&short.hash()));
+		assert_eq!(store.statements().unwrap().len(), 2);
+		// Retired statements may not be submitted again from the network.
+		assert_eq!(store.submit(short, StatementSource::Network), SubmitResult::KnownExpired);
+
+		store.set_time(10);
+		store.maintain();
+		let statements: Vec<_> =
+			store.statements().unwrap().into_iter().map(|(hash, _)| hash).collect();
+		assert_eq!(statements, vec![forever.hash()]);
+		assert!(store.index.read().retained.is_empty());
+	}
+
+	#[test]
+	fn account_size_is_capped_and_reported() {
+		let (store, _temp) = test_store();
+		store.index.write().options = Options::default().with_max_account_size(500);
+		let source = StatementSource::Network;
+		let ok = SubmitResult::New(NetworkPriority::High);
+
+		// The runtime allows account 4 up to 1000 bytes.
+		assert_eq!(store.submit(statement(4, 1, None, 600), source), SubmitResult::Ignored);
+		assert_eq!(store.account_usage(&account(4)).unwrap(), None);
+		assert_eq!(store.submit(statement(4, 1, None, 300), source), ok);
+		assert_eq!(store.submit(statement(4, 2, None, 300), source), ok);
+		assert_eq!(
+			store.account_usage(&account(4)).unwrap(),
+			Some(AccountUsage { statements: 1, size: 300, max_count: 4, max_size: 500 })
+		);
+	}
+
 	#[test]
 	fn posted_clear_decrypts() {
 		let (store, _temp) = test_store();
@@ -0,0 +1,87 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Command line parameters of the statement store.
+
+use crate::Options;
+use clap::Args;
+use sp_statement_store::Topic;
+
+/// Parameters used to configure the statement store.
+#[derive(Default, Debug, Clone, Args)]
+pub struct StatementStoreParams {
+	/// Maximum total data size of the statements of an account in the statement store, in bytes.
+	///
+	/// Caps the size allowed by the runtime. By default only the runtime limit applies.
+	#[arg(long = "statement-store-max-account-size", value_name = "BYTES")]
+	pub max_account_size: Option<u32>,
+
+	/// Retain the statements with a topic in the statement store for a number of seconds after
+	/// they were inserted.
+	///
+	/// Given as `<TOPIC>=<SECONDS>`, with the topic as 32 bytes in hex. May be given once per
+	/// topic.
+	#[arg(
+		long = "statement-store-topic-retention",
+		value_name = "TOPIC=SECONDS",
+		value_parser = parse_topic_retention
+	)]
+	pub topic_retention: Vec<(Topic, u64)>,
+}
+
+impl StatementStoreParams {
+	/// The options of the statement store.
+	pub fn options(&self) -> Options {
+		let options = self
+			.topic_retention
+			.iter()
+			.fold(Options::default(), |options, (topic, retention_sec)| {
+				options.with_topic_retention(*topic, *retention_sec)
+			});
+		match self.max_account_size {
+			Some(max_size) => options.with_max_account_size(max_size),
+			None => options,
+		}
+	}
+}
+
+/// Parse a topic retention given as `<TOPIC>=<SECONDS>`.
+fn parse_topic_retention(s: &str) -> Result<(Topic, u64), String> {
+	let (topic, retention) =
+		s.split_once('=').ok_or_else(|| format!("expected `<TOPIC>=<SECONDS>`, got `{s}`"))?;
+	let topic = sp_core::bytes::from_hex(topic)
+		.ok()
+		.and_then(|bytes| Topic::try_from(bytes).ok())
+		.ok_or_else(|| format!("expected a topic of 32 bytes in hex, got `{topic}`"))?;
+	let retention = retention.parse().map_err(|e| format!("invalid retention `{retention}`: {e}"))?;
+	Ok((topic, retention))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn topic_retentions_are_parsed() {
+		let topic = format!("0x{}", "01".repeat(32));
+		assert_eq!(parse_topic_retention(&format!("{topic}=60")), Ok(([1; 32], 60)));
+		assert!(parse_topic_retention(&topic).is_err());
+		assert!(parse_topic_retention("0x01=60").is_err());
+		assert!(parse_topic_retention(&format!("{topic}=soon")).is_err());
+	}
+}
@@ -50,6 +50,7 @@ const DEFAULT_TARGETS: &str = "pallet,frame,state";
 const TRACE_TARGET: &str = "block_trace";
 // The name of a field required for all events.
 const REQUIRED_EVENT_FIELD: &str = "method";
+const LOG_EVENT_FIELD: &str = "message";
 
 /// Tracing Block Result type alias
 pub type TraceBlockResult<T> = Result<T, Error>;
@@ -69,13 +70,14 @@ pub enum Error {
 
 struct BlockSubscriber {
 	targets: Vec<(String, Level)>,
+	record_logs: bool,
 	next_id: AtomicU64,
 	spans: Mutex<HashMap<Id, SpanDatum>>,
 	events: Mutex<Vec<TraceEvent>>,
 }
 
 impl BlockSubscriber {
-	fn new(targets: &str) -> Self {
+	fn new(targets: &str, record_logs: bool) -> Self {
 		let next_id = AtomicU64::new(1);
 		let mut targets: Vec<_> = targets.split(',').map(crate::parse_target).collect();
 		// Ensure that WASM traces are always enabled
@@ -83,6 +85,7 @@ impl BlockSubscriber {
 		targets.push((WASM_TRACE_IDENTIFIER.to_owned(), Level::TRACE));
 		BlockSubscriber {
 			targets,
+			record_logs,
 			next_id,
 			spans: Mutex::new(HashMap::new()),
 			events: Mutex::new(Vec::new()),
@@ -92,7 +95,10 @@ impl BlockSubscriber {
 
 impl Subscriber for BlockSubscriber {
 	fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
-		if !metadata.is_span() && metadata.fields().field(REQUIRED_EVENT_FIELD).is_none() {
+		if !metadata.is_span() &&
+			metadata.fields().field(REQUIRED_EVENT_FIELD).is_none() &&
+			!(self.record_logs && metadata.fields().field(LOG_EVENT_FIELD).is_some())
+		{
 			return false
 		}
 		for (target, level) in &self.targets {
@@ -194,6 +200,16 @@ where
 	/// and filter out events which do not have keys starting with one of the
 	/// prefixes in `Self::storage_keys`.
 	pub fn trace_block(&self) -> TraceBlockResult<TraceBlockResponse> {
+		self.trace(false).map(|(response, _)| response)
+	}
+
+	/// Like [`Self::trace_block`], but also returns the logs belonging to `Self::targets` which
+	/// were emitted while executing the block, e.g. by the runtime.
+	pub fn trace_block_with_logs(&self) -> TraceBlockResult<(TraceBlockResponse, Vec<TraceEvent>)> {
+		self.trace(true)
+	}
+
+	fn trace(&self, record_logs: bool) -> TraceBlockResult<(TraceBlockResponse, Vec<TraceEvent>)> {
 		tracing::debug!(target: "state_tracing", "Tracing block: {}", self.block);
 		// Prepare the block
 		let mut header = self
@@ -214,7 +230,7 @@ where
 		let block = Block::new(header, extrinsics);
 
 		let targets = if let Some(t) = &self.targets { t } else { DEFAULT_TARGETS };
-		let block_subscriber = BlockSubscriber::new(targets);
+		let block_subscriber = BlockSubscriber::new(targets, record_logs);
 		let dispatch = Dispatch::new(block_subscriber);
 
 		{
@@ -248,10 +264,13 @@ where
 			// Patch wasm identifiers
 			.filter_map(|(_, s)| patch_and_filter(s, targets))
 			.collect();
-		let events: Vec<_> = block_subscriber
+		let (events, logs): (Vec<_>, Vec<_>) = block_subscriber
 			.events
 			.lock()
 			.drain(..)
+			.partition(|e| e.values.string_values.contains_key(REQUIRED_EVENT_FIELD));
+		let events: Vec<_> = events
+			.into_iter()
 			.filter(|e| {
 				self.storage_keys
 					.as_ref()
@@ -268,7 +287,7 @@ where
 			.collect();
 		tracing::debug!(target: "state_tracing", "Captured {} spans and {} events", spans.len(), events.len());
 
-		Ok(TraceBlockResponse::BlockTrace(BlockTrace {
+		let response = TraceBlockResponse::BlockTrace(BlockTrace {
 			block_hash: block_id_as_string(BlockId::<Block>::Hash(self.block)),
 			parent_hash: block_id_as_string(BlockId::<Block>::Hash(parent_hash)),
 			tracing_targets: targets.to_string(),
@@ -276,7 +295,8 @@ where
 			methods: self.methods.clone().unwrap_or_default(),
 			spans,
 			events,
-		}))
+		});
+		Ok((response, logs))
 	}
 }
 
@@ -605,7 +605,8 @@ mod benchmarks {
 			call.dispatch_bypass_filter(origin)?;
 		}
 
-		assert!(Alliance::<T, I>::announcements().contains(&announcement));
+		let hash = T::Hashing::hash_of(&announcement);
+		assert!(Alliance::<T, I>::announcements().contains(&hash));
 		assert_last_event::<T, I>(Event::Announced { announcement }.into());
 		Ok(())
 	}
@@ -615,8 +616,9 @@ mod benchmarks {
 		set_members::<T, I>();
 
 		let announcement = announcement(b"hello world");
+		let hash = T::Preimages::note(announcement.encode().into())?;
 		let announcements: BoundedVec<_, T::MaxAnnouncementsCount> =
-			BoundedVec::try_from(vec![announcement.clone()]).unwrap();
+			BoundedVec::try_from(vec![hash]).unwrap();
 		Announcements::<T, I>::put(announcements);
 
 		let call = Call::<T, I>::remove_announcement { announcement: announcement.clone() };
@@ -628,11 +630,54 @@ mod benchmarks {
 			call.dispatch_bypass_filter(origin)?;
 		}
 
-		assert!(!Alliance::<T, I>::announcements().contains(&announcement));
+		assert!(!Alliance::<T, I>::announcements().contains(&hash));
 		assert_last_event::<T, I>(Event::AnnouncementRemoved { announcement }.into());
 		Ok(())
 	}
 
+	#[benchmark]
+	fn announce_preimage() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		let hash = T::Preimages::note(b"hello world".as_slice().into())?;
+
+		let call = Call::<T, I>::announce_preimage { hash };
+		let origin = T::AnnouncementOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(Alliance::<T, I>::announcements().contains(&hash));
+		assert_last_event::<T, I>(Event::PreimageAnnounced { hash }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retract_announcement() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		let hash = T::Preimages::note(b"hello world".as_slice().into())?;
+		let announcements: BoundedVec<_, T::MaxAnnouncementsCount> =
+			BoundedVec::try_from(vec![hash]).unwrap();
+		Announcements::<T, I>::put(announcements);
+
+		let call = Call::<T, I>::retract_announcement { hash };
+		let origin = T::AnnouncementOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(!Alliance::<T, I>::announcements().contains(&hash));
+		assert_last_event::<T, I>(Event::AnnouncementRetracted { hash }.into());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn join_alliance() -> Result<(), BenchmarkError> {
 		set_members::<T, I>();
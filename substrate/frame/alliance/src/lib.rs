@@ -37,7 +37,8 @@
 //!
 //! - Rule: The IPFS CID (hash) of the Alliance rules for the community to read and the Alliance
 //!   members to enforce. Similar to a Charter or Code of Conduct.
-//! - Announcement: An IPFS CID of some content that the Alliance want to announce.
+//! - Announcement: Some content that the Alliance want to announce, e.g. an IPFS CID. It is
+//!   addressed by its hash and its content is held as a preimage by `Preimages`.
 //! - Member: An account that is already in the group of the Alliance, including two types: Fellow,
 //!   or Ally. A member can also be kicked by the `MembershipManager` origin or retire by itself.
 //! - Fellow: An account who is elevated from Ally by other Fellows.
@@ -68,6 +69,8 @@
 //! - `close` - Close a motion with enough votes or that has expired.
 //! - `set_rule` - Initialize or update the Alliance's rule by IPFS CID.
 //! - `announce` - Make announcement by IPFS CID.
+//! - `announce_preimage` - Make announcement of some content noted as a preimage.
+//! - `retract_announcement` - Retract an announcement, releasing its preimage.
 //! - `nominate_ally` - Nominate a non-member to become an Ally, without deposit.
 //! - `elevate_ally` - Approve an ally to become a Fellow.
 //! - `kick_member` - Kick a member and slash its deposit.
@@ -98,7 +101,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
-	traits::{Dispatchable, Saturating, StaticLookup, Zero},
+	traits::{Dispatchable, Hash, Saturating, StaticLookup, Zero},
 	DispatchError, RuntimeDebug,
 };
 use sp_std::{convert::TryInto, prelude::*};
@@ -107,8 +110,8 @@ use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
 	ensure,
 	traits::{
-		ChangeMembers, Currency, Get, InitializeMembers, IsSubType, OnUnbalanced,
-		ReservableCurrency,
+		ChangeMembers, Currency, Get, InitializeMembers, IsSubType, OnUnbalanced, QueryPreimage,
+		ReservableCurrency, StorePreimage,
 	},
 	weights::Weight,
 };
@@ -248,6 +251,9 @@ pub mod pallet {
 		/// The currency used for deposits.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The preimage provider holding the content of announcements.
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
 		/// What to do with slashed funds.
 		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
 
@@ -345,6 +351,10 @@ pub mod pallet {
 		MissingProposalHash,
 		/// The announcement is not found.
 		MissingAnnouncement,
+		/// The content has already been announced.
+		AlreadyAnnounced,
+		/// The preimage of the announced content is not available.
+		MissingPreimage,
 		/// Number of members exceeds `MaxMembersCount`.
 		TooManyMembers,
 		/// Number of announcements exceeds `MaxAnnouncementsCount`.
@@ -370,6 +380,10 @@ pub mod pallet {
 		Announced { announcement: Cid },
 		/// An on-chain announcement has been removed.
 		AnnouncementRemoved { announcement: Cid },
+		/// The content noted as the preimage of `hash` has been announced.
+		PreimageAnnounced { hash: T::Hash },
+		/// The announcement of the content with the given `hash` has been retracted.
+		AnnouncementRetracted { hash: T::Hash },
 		/// Some accounts have been initialized as members (fellows/allies).
 		MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> },
 		/// An account has been added as an Ally and reserved its deposit.
@@ -445,11 +459,13 @@ pub mod pallet {
 	#[pallet::getter(fn rule)]
 	pub type Rule<T: Config<I>, I: 'static = ()> = StorageValue<_, Cid, OptionQuery>;
 
-	/// The current IPFS CIDs of any announcements.
+	/// The hashes of the content of any announcements.
+	///
+	/// The content itself is requested from, and held by, `T::Preimages`.
 	#[pallet::storage]
 	#[pallet::getter(fn announcements)]
 	pub type Announcements<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, BoundedVec<Cid, T::MaxAnnouncementsCount>, ValueQuery>;
+		StorageValue<_, BoundedVec<T::Hash, T::MaxAnnouncementsCount>, ValueQuery>;
 
 	/// Maps members to their candidacy deposit.
 	#[pallet::storage]
@@ -650,15 +666,19 @@ pub mod pallet {
 		}
 
 		/// Make an announcement of a new IPFS CID about alliance issues.
+		///
+		/// The encoded CID is requested and noted as a preimage without a deposit, so that it is
+		/// retained for as long as it is announced.
 		#[pallet::call_index(6)]
 		pub fn announce(origin: OriginFor<T>, announcement: Cid) -> DispatchResult {
 			T::AnnouncementOrigin::ensure_origin(origin)?;
 
-			let mut announcements = <Announcements<T, I>>::get();
-			announcements
-				.try_push(announcement.clone())
-				.map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
-			<Announcements<T, I>>::put(announcements);
+			let hash = T::Hashing::hash_of(&announcement);
+			Self::do_announce(hash)?;
+			// Request before noting: noting an already requested preimage doesn't count another
+			// request, which `do_retract` would then release.
+			T::Preimages::request(&hash);
+			T::Preimages::note(announcement.encode().into())?;
 
 			Self::deposit_event(Event::Announced { announcement });
 			Ok(())
@@ -669,13 +689,7 @@ pub mod pallet {
 		pub fn remove_announcement(origin: OriginFor<T>, announcement: Cid) -> DispatchResult {
 			T::AnnouncementOrigin::ensure_origin(origin)?;
 
-			let mut announcements = <Announcements<T, I>>::get();
-			let pos = announcements
-				.binary_search(&announcement)
-				.ok()
-				.ok_or(Error::<T, I>::MissingAnnouncement)?;
-			announcements.remove(pos);
-			<Announcements<T, I>>::put(announcements);
+			Self::do_retract(T::Hashing::hash_of(&announcement))?;
 
 			Self::deposit_event(Event::AnnouncementRemoved { announcement });
 			Ok(())
@@ -916,6 +930,36 @@ pub mod pallet {
 			Self::deposit_event(Event::FellowAbdicated { fellow: who });
 			Ok(())
 		}
+
+		/// Make an announcement of the content noted as the preimage of `hash`.
+		///
+		/// The preimage is requested, so that it is retained for as long as it is announced. Any
+		/// deposit paid for noting it stays reserved until its noter unnotes it.
+		#[pallet::call_index(18)]
+		pub fn announce_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			T::AnnouncementOrigin::ensure_origin(origin)?;
+			ensure!(T::Preimages::len(&hash).is_some(), Error::<T, I>::MissingPreimage);
+
+			Self::do_announce(hash)?;
+			T::Preimages::request(&hash);
+
+			Self::deposit_event(Event::PreimageAnnounced { hash });
+			Ok(())
+		}
+
+		/// Retract the announcement of the content with the given `hash`.
+		///
+		/// The preimage of the content is no longer requested, so that it is removed if it was
+		/// noted without a deposit, or can be unnoted by whoever noted it otherwise.
+		#[pallet::call_index(19)]
+		pub fn retract_announcement(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			T::AnnouncementOrigin::ensure_origin(origin)?;
+
+			Self::do_retract(hash)?;
+
+			Self::deposit_event(Event::AnnouncementRetracted { hash });
+			Ok(())
+		}
 	}
 }
 
@@ -1116,4 +1160,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)?;
 		Ok(info.into())
 	}
+
+	/// Add the content with the given `hash` to the announcements.
+	fn do_announce(hash: T::Hash) -> DispatchResult {
+		<Announcements<T, I>>::try_mutate(|announcements| -> DispatchResult {
+			ensure!(!announcements.contains(&hash), Error::<T, I>::AlreadyAnnounced);
+			announcements.try_push(hash).map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
+			Ok(())
+		})
+	}
+
+	/// Remove the content with the given `hash` from the announcements and release its preimage.
+	fn do_retract(hash: T::Hash) -> DispatchResult {
+		<Announcements<T, I>>::try_mutate(|announcements| -> DispatchResult {
+			let pos = announcements
+				.iter()
+				.position(|h| h == &hash)
+				.ok_or(Error::<T, I>::MissingAnnouncement)?;
+			announcements.remove(pos);
+			Ok(())
+		})?;
+		T::Preimages::unrequest(&hash);
+		Ok(())
+	}
 }
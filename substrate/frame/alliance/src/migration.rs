@@ -20,7 +20,7 @@ use frame_support::{pallet_prelude::*, storage::migration, traits::OnRuntimeUpgr
 use log;
 
 /// The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 /// Wrapper for all migrations of this pallet.
 pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
@@ -35,6 +35,10 @@ pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
 		weight = weight.saturating_add(v1_to_v2::migrate::<T, I>());
 	}
 
+	if onchain_version < 3 {
+		weight = weight.saturating_add(v2_to_v3::migrate::<T, I>());
+	}
+
 	STORAGE_VERSION.put::<Pallet<T, I>>();
 	weight = weight.saturating_add(T::DbWeight::get().writes(1));
 
@@ -159,10 +163,65 @@ pub(crate) mod v1_to_v2 {
 	}
 }
 
+/// v2_to_v3: `Announcements` holds the hashes of the announced CIDs instead of the CIDs, which
+/// are noted as preimages.
+pub(crate) mod v2_to_v3 {
+	use super::*;
+	use crate::{Announcements, Cid};
+	use frame_support::traits::StorePreimage;
+	use sp_runtime::traits::Hash;
+
+	pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
+		log::info!(
+			target: LOG_TARGET,
+			"Running migration v2_to_v3: `Announcements` are noted as preimages."
+		);
+		let Some(cids) = migration::take_storage_value::<BoundedVec<Cid, T::MaxAnnouncementsCount>>(
+			<Pallet<T, I>>::name().as_bytes(),
+			b"Announcements",
+			b"",
+		) else {
+			return T::DbWeight::get().reads(1)
+		};
+
+		let mut hashes = BoundedVec::<T::Hash, T::MaxAnnouncementsCount>::default();
+		for cid in cids.iter() {
+			if hashes.contains(&T::Hashing::hash_of(cid)) {
+				continue
+			}
+			match T::Preimages::note(cid.encode().into()) {
+				// Cannot fail, there are no more hashes than CIDs.
+				Ok(hash) => {
+					let _ = hashes.try_push(hash);
+				},
+				Err(e) => log::error!(
+					target: LOG_TARGET,
+					"Failed to note the announcement {:?} as a preimage: {:?}",
+					cid,
+					e,
+				),
+			}
+		}
+		log::info!(
+			target: LOG_TARGET,
+			"Noted '{}' of '{}' announcements as preimages.",
+			hashes.len(),
+			cids.len(),
+		);
+		Announcements::<T, I>::put(hashes);
+
+		// Noting a preimage reads its request status and writes it along with the preimage.
+		let noted = cids.len() as u64;
+		T::DbWeight::get().reads_writes(1 + noted, 1 + 2 * noted)
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::{mock::*, MemberRole};
+	use crate::{mock::*, Cid, MemberRole};
+	use frame_support::traits::QueryPreimage;
+	use sp_runtime::traits::Hash;
 
 	#[test]
 	fn migration_v1_to_v2_works() {
@@ -176,4 +235,31 @@ mod test {
 			assert_eq!(Alliance::members(MemberRole::Retiring), vec![]);
 		});
 	}
+
+	#[test]
+	fn migration_v2_to_v3_works() {
+		new_test_ext().execute_with(|| {
+			let first = Cid::new_v0([1u8; 32]);
+			let second = Cid::new_v0([2u8; 32]);
+			migration::put_storage_value(
+				<Pallet<Test, ()>>::name().as_bytes(),
+				b"Announcements",
+				b"",
+				vec![first.clone(), second.clone(), first.clone()],
+			);
+
+			v2_to_v3::migrate::<Test, ()>();
+
+			let hashes = vec![BlakeTwo256::hash_of(&first), BlakeTwo256::hash_of(&second)];
+			assert_eq!(Alliance::announcements(), hashes);
+			for hash in hashes.iter() {
+				let len = <Preimage as QueryPreimage>::len(hash);
+				assert_eq!(len, Some(first.encoded_size() as u32));
+				assert!(Preimage::is_requested(hash));
+			}
+
+			assert_ok!(Alliance::remove_announcement(RuntimeOrigin::signed(3), first));
+			assert_eq!(<Preimage as QueryPreimage>::len(&hashes[0]), None);
+		});
+	}
 }
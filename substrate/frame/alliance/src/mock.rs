@@ -18,7 +18,10 @@
 //! Test utilities
 
 pub use sp_core::H256;
-use sp_runtime::traits::Hash;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::Hash,
+};
 pub use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
@@ -76,6 +79,14 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = ();
+	type WeightInfo = ();
+}
+
 const MOTION_DURATION_IN_BLOCKS: BlockNumber = 3;
 
 parameter_types! {
@@ -93,6 +104,7 @@ impl pallet_collective::Config<AllianceCollective> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -128,9 +140,27 @@ impl pallet_identity::Config for Test {
 	type Slashed = ();
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type MigratorOrigin = EnsureRoot<AccountId>;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
 	type WeightInfo = ();
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_identity::BenchmarkHelper<UintAuthorityId, TestSignature> for TestBenchmarkHelper {
+	fn generate_key() -> UintAuthorityId {
+		UintAuthorityId(1)
+	}
+
+	fn sign(public: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(public.0, message.to_vec())
+	}
+}
+
 pub struct AllianceIdentityVerifier;
 impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	fn has_required_identities(who: &AccountId) -> bool {
@@ -201,6 +231,7 @@ impl Config for Test {
 	type MembershipManager = EnsureSignedBy<Two, AccountId>;
 	type AnnouncementOrigin = EnsureSignedBy<Three, AccountId>;
 	type Currency = Balances;
+	type Preimages = Preimage;
 	type Slashed = ();
 	type InitializeMembers = AllianceMotion;
 	type MembershipChanged = AllianceMotion;
@@ -228,6 +259,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Preimage: pallet_preimage,
 		Identity: pallet_identity,
 		AllianceMotion: pallet_collective::<Instance1>,
 		Alliance: pallet_alliance,
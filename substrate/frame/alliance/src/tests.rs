@@ -326,15 +326,40 @@ fn set_rule_works() {
 fn announce_works() {
 	new_test_ext().execute_with(|| {
 		let cid = test_cid();
+		let hash = BlakeTwo256::hash_of(&cid);
 
 		assert_noop!(Alliance::announce(RuntimeOrigin::signed(2), cid.clone()), BadOrigin);
 
 		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid.clone()));
-		assert_eq!(Alliance::announcements(), vec![cid.clone()]);
+		assert_eq!(Alliance::announcements(), vec![hash]);
+		let len = <Preimage as QueryPreimage>::len(&hash);
+		assert_eq!(len, Some(cid.encoded_size() as u32));
+		assert!(Preimage::is_requested(&hash));
 
 		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::Announced {
-			announcement: cid,
+			announcement: cid.clone(),
 		}));
+
+		assert_noop!(
+			Alliance::announce(RuntimeOrigin::signed(3), cid),
+			Error::<Test, ()>::AlreadyAnnounced
+		);
+	});
+}
+
+#[test]
+fn announce_keeps_preimage_requested_elsewhere() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		let hash = BlakeTwo256::hash_of(&cid);
+		<Preimage as QueryPreimage>::request(&hash);
+
+		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid.clone()));
+		assert_ok!(Alliance::remove_announcement(RuntimeOrigin::signed(3), cid.clone()));
+
+		// The other request still holds the preimage.
+		assert!(Preimage::is_requested(&hash));
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), Some(cid.encoded_size() as u32));
 	});
 }
 
@@ -342,8 +367,9 @@ fn announce_works() {
 fn remove_announcement_works() {
 	new_test_ext().execute_with(|| {
 		let cid = test_cid();
+		let hash = BlakeTwo256::hash_of(&cid);
 		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid.clone()));
-		assert_eq!(Alliance::announcements(), vec![cid.clone()]);
+		assert_eq!(Alliance::announcements(), vec![hash]);
 		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::Announced {
 			announcement: cid.clone(),
 		}));
@@ -352,9 +378,70 @@ fn remove_announcement_works() {
 
 		assert_ok!(Alliance::remove_announcement(RuntimeOrigin::signed(3), cid.clone()));
 		assert_eq!(Alliance::announcements(), vec![]);
+		// The preimage was noted by the pallet and is removed with the announcement.
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), None);
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::AnnouncementRemoved { announcement: cid.clone() },
+		));
+
+		assert_noop!(
+			Alliance::remove_announcement(RuntimeOrigin::signed(3), cid),
+			Error::<Test, ()>::MissingAnnouncement
+		);
+	});
+}
+
+#[test]
+fn announce_preimage_works() {
+	new_test_ext().execute_with(|| {
+		let content = b"the alliance announces".to_vec();
+		let hash = BlakeTwo256::hash(&content);
+
+		assert_noop!(
+			Alliance::announce_preimage(RuntimeOrigin::signed(3), hash),
+			Error::<Test, ()>::MissingPreimage
+		);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), content));
+
+		assert_noop!(Alliance::announce_preimage(RuntimeOrigin::signed(2), hash), BadOrigin);
+		assert_ok!(Alliance::announce_preimage(RuntimeOrigin::signed(3), hash));
+		assert_eq!(Alliance::announcements(), vec![hash]);
+		assert!(Preimage::is_requested(&hash));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::PreimageAnnounced { hash },
+		));
+
+		assert_noop!(
+			Alliance::announce_preimage(RuntimeOrigin::signed(3), hash),
+			Error::<Test, ()>::AlreadyAnnounced
+		);
+	});
+}
+
+#[test]
+fn retract_announcement_works() {
+	new_test_ext().execute_with(|| {
+		let content = b"the alliance announces".to_vec();
+		let hash = BlakeTwo256::hash(&content);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), content));
+		assert_ok!(Alliance::announce_preimage(RuntimeOrigin::signed(3), hash));
+
+		assert_noop!(Alliance::retract_announcement(RuntimeOrigin::signed(2), hash), BadOrigin);
+		assert_ok!(Alliance::retract_announcement(RuntimeOrigin::signed(3), hash));
+		assert_eq!(Alliance::announcements(), vec![]);
 		System::assert_last_event(mock::RuntimeEvent::Alliance(
-			crate::Event::AnnouncementRemoved { announcement: cid },
+			crate::Event::AnnouncementRetracted { hash },
 		));
+
+		// The preimage is no longer requested and can be cleaned up by whoever noted it.
+		assert!(!Preimage::is_requested(&hash));
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(1), hash));
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), None);
+
+		assert_noop!(
+			Alliance::retract_announcement(RuntimeOrigin::signed(3), hash),
+			Error::<Test, ()>::MissingAnnouncement
+		);
 	});
 }
 
@@ -0,0 +1,37 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for asset rate pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::FixedU128;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for auditing the conversion rates of assets.
+	pub trait AssetRateApi<AssetKind, BlockNumber>
+		where
+			AssetKind: Codec,
+			BlockNumber: Codec,
+	{
+		/// Returns the conversion rate to native balance of `asset_kind` that was in force at
+		/// block `block`, or `None` if it had no rate then or the block is older than the kept
+		/// history of its rate.
+		fn rate_at(asset_kind: AssetKind, block: BlockNumber) -> Option<FixedU128>;
+	}
+}
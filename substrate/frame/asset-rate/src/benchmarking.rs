@@ -22,9 +22,10 @@ use crate::{pallet as pallet_asset_rate, Pallet as AssetRate};
 
 use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
 use sp_core::crypto::FromEntropy;
+use sp_std::vec::Vec;
 
 /// Trait describing the factory function for the `AssetKind` parameter.
 pub trait AssetKindFactory<AssetKind> {
@@ -45,6 +46,18 @@ fn default_conversion_rate() -> FixedU128 {
 	FixedU128::from_u32(1u32)
 }
 
+/// Fill the rate history of `asset_kind` with changes of past blocks, so that the next change
+/// drops the oldest one.
+fn fill_rate_history<T: Config>(asset_kind: &T::AssetKind) {
+	let max = T::MaxRateHistory::get();
+	let history = (0..max)
+		.map(|block| (BlockNumberFor::<T>::from(block), Some(default_conversion_rate())))
+		.collect::<Vec<_>>();
+	let history = BoundedVec::<_, T::MaxRateHistory>::truncate_from(history);
+	pallet_asset_rate::RateHistory::<T>::insert(asset_kind, history);
+	frame_system::Pallet::<T>::set_block_number(max.into());
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -52,6 +65,8 @@ mod benchmarks {
 	#[benchmark]
 	fn create() -> Result<(), BenchmarkError> {
 		let asset_kind: T::AssetKind = T::BenchmarkHelper::create_asset_kind(SEED);
+		fill_rate_history::<T>(&asset_kind);
+
 		#[extrinsic_call]
 		_(RawOrigin::Root, Box::new(asset_kind.clone()), default_conversion_rate());
 
@@ -70,6 +85,7 @@ mod benchmarks {
 			Box::new(asset_kind.clone()),
 			default_conversion_rate()
 		));
+		fill_rate_history::<T>(&asset_kind);
 
 		#[extrinsic_call]
 		_(RawOrigin::Root, Box::new(asset_kind.clone()), FixedU128::from_u32(2));
@@ -89,6 +105,7 @@ mod benchmarks {
 			Box::new(asset_kind.clone()),
 			default_conversion_rate()
 		));
+		fill_rate_history::<T>(&asset_kind);
 
 		#[extrinsic_call]
 		_(RawOrigin::Root, Box::new(asset_kind.clone()));
@@ -39,6 +39,8 @@
 //!
 //! * Providing a soft conversion for the balance of supported assets to a default asset class.
 //! * Updating existing conversion rates.
+//! * Auditing the conversion rate that was in force at some past block, see
+//!   [`Pallet::rate_at`].
 //!
 //! ## Interface
 //!
@@ -60,6 +62,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::traits::{fungible::Inspect, tokens::ConversionFromAssetBalance};
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128};
 use sp_std::boxed::Box;
 
@@ -115,6 +118,10 @@ pub mod pallet {
 		/// The type for asset kinds for which the conversion rate to native balance is set.
 		type AssetKind: Parameter + MaxEncodedLen;
 
+		/// The maximum number of changes kept in the rate history of an asset.
+		#[pallet::constant]
+		type MaxRateHistory: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::AssetKindFactory<Self::AssetKind>;
@@ -127,6 +134,19 @@ pub mod pallet {
 	pub type ConversionRateToNative<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetKind, FixedU128, OptionQuery>;
 
+	/// The latest changes of the conversion rate of an asset, oldest first.
+	///
+	/// Each change is the block it was made at and the new rate, `None` if the rate was removed.
+	/// Only the last [`Config::MaxRateHistory`] changes are kept.
+	#[pallet::storage]
+	pub type RateHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetKind,
+		BoundedVec<(BlockNumberFor<T>, Option<FixedU128>), T::MaxRateHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -166,6 +186,7 @@ pub mod pallet {
 				Error::<T>::AlreadyExists
 			);
 			ConversionRateToNative::<T>::set(asset_kind.as_ref(), Some(rate));
+			Self::note_rate(asset_kind.as_ref(), Some(rate));
 
 			Self::deposit_event(Event::AssetRateCreated { asset_kind: *asset_kind, rate });
 			Ok(())
@@ -195,6 +216,7 @@ pub mod pallet {
 					Err(Error::<T>::UnknownAssetKind)
 				}
 			})?;
+			Self::note_rate(asset_kind.as_ref(), Some(rate));
 
 			Self::deposit_event(Event::AssetRateUpdated {
 				asset_kind: *asset_kind,
@@ -218,6 +240,7 @@ pub mod pallet {
 				Error::<T>::UnknownAssetKind
 			);
 			ConversionRateToNative::<T>::remove(asset_kind.as_ref());
+			Self::note_rate(asset_kind.as_ref(), None);
 
 			Self::deposit_event(Event::AssetRateRemoved { asset_kind: *asset_kind });
 			Ok(())
@@ -225,6 +248,33 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// The conversion rate of `asset_kind` that was in force at block `block`.
+	///
+	/// Returns `None` if the asset had no rate at that block, or if the block precedes the kept
+	/// history of its rate.
+	pub fn rate_at(asset_kind: &T::AssetKind, block: BlockNumberFor<T>) -> Option<FixedU128> {
+		pallet::RateHistory::<T>::get(asset_kind)
+			.iter()
+			.rev()
+			.find(|(changed_at, _)| *changed_at <= block)
+			.and_then(|(_, rate)| *rate)
+	}
+
+	/// Record the new `rate` of `asset_kind` in its history.
+	fn note_rate(asset_kind: &T::AssetKind, rate: Option<FixedU128>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		pallet::RateHistory::<T>::mutate(asset_kind, |history| {
+			// Only the last change of a block is ever in force.
+			if history.last().map_or(false, |(changed_at, _)| *changed_at == now) {
+				history.pop();
+			}
+			// The oldest change is dropped if the history is full.
+			let _ = history.force_insert_keep_right(history.len(), (now, rate));
+		});
+	}
+}
+
 /// Exposes conversion of an arbitrary balance of an asset to native balance.
 impl<T> ConversionFromAssetBalance<BalanceOf<T>, AssetKindOf<T>, BalanceOf<T>> for Pallet<T>
 where
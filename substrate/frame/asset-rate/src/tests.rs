@@ -20,7 +20,7 @@
 use super::*;
 use crate::pallet as pallet_asset_rate;
 use frame_support::{assert_noop, assert_ok};
-use mock::{new_test_ext, AssetRate, RuntimeOrigin, Test};
+use mock::{new_test_ext, AssetRate, RuntimeOrigin, System, Test};
 use sp_runtime::FixedU128;
 
 const ASSET_ID: u32 = 42;
@@ -151,3 +151,64 @@ fn convert_unknown_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+#[test]
+fn rate_history_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(0.1)
+		));
+		System::set_block_number(5);
+		assert_ok!(AssetRate::update(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(0.2)
+		));
+		System::set_block_number(10);
+		assert_ok!(AssetRate::remove(RuntimeOrigin::root(), Box::new(ASSET_ID)));
+
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 0), None);
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 1), Some(FixedU128::from_float(0.1)));
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 4), Some(FixedU128::from_float(0.1)));
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 5), Some(FixedU128::from_float(0.2)));
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 10), None);
+	});
+}
+
+#[test]
+fn rate_history_is_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(0.1)
+		));
+		// only the last change of a block is kept.
+		assert_ok!(AssetRate::update(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_float(0.2)
+		));
+		assert_eq!(pallet_asset_rate::RateHistory::<Test>::get(ASSET_ID).len(), 1);
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 1), Some(FixedU128::from_float(0.2)));
+
+		for block in 2..5 {
+			System::set_block_number(block);
+			assert_ok!(AssetRate::update(
+				RuntimeOrigin::root(),
+				Box::new(ASSET_ID),
+				FixedU128::from_u32(block as u32)
+			));
+		}
+
+		// the oldest change was dropped.
+		assert_eq!(pallet_asset_rate::RateHistory::<Test>::get(ASSET_ID).len(), 3);
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 1), None);
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 2), Some(FixedU128::from_u32(2)));
+		assert_eq!(AssetRate::rate_at(&ASSET_ID, 100), Some(FixedU128::from_u32(4)));
+	});
+}
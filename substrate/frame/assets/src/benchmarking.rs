@@ -548,5 +548,53 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	sponsor {
+		let (asset_id, issuer, _) = create_default_asset::<T, I>(false);
+		let new_account: T::AccountId = account("newaccount", 1, SEED);
+		let new_account_lookup = T::Lookup::unlookup(new_account.clone());
+		T::Currency::make_free_balance_be(&issuer, DepositBalanceOf::<T, I>::max_value());
+		assert_ne!(issuer, new_account);
+		assert!(!Account::<T, I>::contains_key(asset_id.into(), &new_account));
+	}: _(SystemOrigin::Signed(issuer.clone()), asset_id, new_account_lookup)
+	verify {
+		assert_last_event::<T, I>(
+			Event::Sponsored { asset_id: asset_id.into(), who: new_account, sponsor: issuer }.into()
+		);
+	}
+
+	unsponsor {
+		let (asset_id, issuer, _) = create_default_asset::<T, I>(false);
+		let new_account: T::AccountId = account("newaccount", 1, SEED);
+		let new_account_lookup = T::Lookup::unlookup(new_account.clone());
+		T::Currency::make_free_balance_be(&issuer, DepositBalanceOf::<T, I>::max_value());
+		assert!(Assets::<T, I>::sponsor(
+			SystemOrigin::Signed(issuer.clone()).into(),
+			asset_id,
+			new_account_lookup.clone()
+		).is_ok());
+		assert!(Assets::<T, I>::mint(
+			SystemOrigin::Signed(issuer.clone()).into(),
+			asset_id,
+			new_account_lookup.clone(),
+			100u32.into()
+		).is_ok());
+		// worst case: the account keeps its balance and must take a consumer reference
+		T::Currency::make_free_balance_be(&new_account, T::Currency::minimum_balance());
+	}: _(SystemOrigin::Signed(issuer.clone()), asset_id, new_account_lookup)
+	verify {
+		assert!(T::Currency::reserved_balance(&issuer).is_zero());
+		assert_last_event::<T, I>(
+			Event::Unsponsored { asset_id: asset_id.into(), who: new_account, sponsor: issuer }.into()
+		);
+	}
+
+	on_asset_transfer {
+		let amount = T::Balance::from(100u32);
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, amount);
+		let target: T::AccountId = account("target", 0, SEED);
+	}: {
+		assert!(T::OnAssetTransfer::on_transfer(&asset_id.into(), &caller, &target, amount).is_ok());
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -75,10 +75,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			} else {
 				ExistenceReason::DepositFrom(depositor.clone(), deposit)
 			}
-		} else if d.is_sufficient {
+		} else {
+			Self::reference_account(who, d)?
+		};
+		d.accounts = accounts;
+		Ok(reason)
+	}
+
+	/// Takes a sufficient reference (if the asset is sufficient) or a consumer reference on `who`
+	/// for it to hold an asset account without a deposit.
+	fn reference_account(
+		who: &T::AccountId,
+		d: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+	) -> Result<ExistenceReasonOf<T, I>, DispatchError> {
+		if d.is_sufficient {
 			frame_system::Pallet::<T>::inc_sufficients(who);
 			d.sufficients += 1;
-			ExistenceReason::Sufficient
+			Ok(ExistenceReason::Sufficient)
 		} else {
 			frame_system::Pallet::<T>::inc_consumers(who)
 				.map_err(|_| Error::<T, I>::UnavailableConsumer)?;
@@ -88,10 +101,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				frame_system::Pallet::<T>::dec_consumers(who);
 				return Err(Error::<T, I>::UnavailableConsumer.into())
 			}
-			ExistenceReason::Consumer
-		};
-		d.accounts = accounts;
-		Ok(reason)
+			Ok(ExistenceReason::Consumer)
+		}
 	}
 
 	pub(super) fn dead_account(
@@ -387,6 +398,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Remove = Self::dead_account(&who, &mut details, &account.reason, false) {
 			Account::<T, I>::remove(&id, &who);
+			Self::end_sponsorship(&id, &who);
 		} else {
 			debug_assert!(false, "refund did not result in dead account?!");
 			// deposit may have been refunded, need to update `Account`
@@ -399,6 +411,69 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		return Ok(())
 	}
 
+	/// Creates an asset account for `who` with a deposit from `sponsor`, which must be the Issuer
+	/// of the asset `id`.
+	pub(super) fn do_sponsor(
+		id: T::AssetId,
+		who: T::AccountId,
+		sponsor: T::AccountId,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(sponsor == details.issuer, Error::<T, I>::NoPermission);
+		let sponsored = SponsoredAccounts::<T, I>::get(&id, &sponsor);
+		ensure!(
+			sponsored < T::MaxSponsoredAccounts::get(),
+			Error::<T, I>::TooManySponsoredAccounts
+		);
+
+		Self::do_touch(id.clone(), who.clone(), sponsor.clone(), false)?;
+		Sponsorships::<T, I>::insert(&id, &who, &sponsor);
+		SponsoredAccounts::<T, I>::insert(&id, &sponsor, sponsored.saturating_add(1));
+		Self::deposit_event(Event::Sponsored { asset_id: id, who, sponsor });
+		Ok(())
+	}
+
+	/// Ends the sponsorship of the asset account of `who` by `caller`, returning the deposit.
+	///
+	/// An account with a zero balance is destroyed, an account with a non-zero balance takes a
+	/// reference on `who` instead of the deposit.
+	pub(super) fn do_unsponsor(
+		id: T::AssetId,
+		who: T::AccountId,
+		caller: &T::AccountId,
+	) -> DispatchResult {
+		let sponsor = Sponsorships::<T, I>::get(&id, &who).ok_or(Error::<T, I>::NotSponsored)?;
+		ensure!(caller == &sponsor, Error::<T, I>::NoPermission);
+		let mut account = Account::<T, I>::get(&id, &who).ok_or(Error::<T, I>::NoAccount)?;
+
+		if account.balance.is_zero() {
+			Self::do_refund_other(id.clone(), &who, caller)?;
+		} else {
+			let mut details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
+			let (depositor, deposit) =
+				account.reason.take_deposit_from().ok_or(Error::<T, I>::NoDeposit)?;
+			account.reason = Self::reference_account(&who, &mut details)?;
+			T::Currency::unreserve(&depositor, deposit);
+			Account::<T, I>::insert(&id, &who, account);
+			Asset::<T, I>::insert(&id, details);
+			Self::end_sponsorship(&id, &who);
+		}
+
+		Self::deposit_event(Event::Unsponsored { asset_id: id, who, sponsor });
+		Ok(())
+	}
+
+	/// Forgets the sponsorship (if any) of the asset account of `who`.
+	fn end_sponsorship(id: &T::AssetId, who: &T::AccountId) {
+		if let Some(sponsor) = Sponsorships::<T, I>::take(id, who) {
+			SponsoredAccounts::<T, I>::mutate_exists(id, &sponsor, |sponsored| {
+				*sponsored = sponsored.and_then(|s| s.checked_sub(1)).filter(|s| *s > 0);
+			});
+		}
+	}
+
 	/// Increases the asset `id` balance of `beneficiary` by `amount`.
 	///
 	/// This alters the registered supply of the asset and emits an event.
@@ -609,6 +684,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
+		// Give the hook the chance to veto the transfer.
+		T::OnAssetTransfer::on_transfer(&id, source, dest, amount).map_err(Into::into)?;
+
 		// Figure out the debit and credit, together with side-effects.
 		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
@@ -769,6 +847,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					}
 					if let Remove = Self::dead_account(&who, &mut details, &v.reason, false) {
 						Account::<T, I>::remove(&id, &who);
+						Self::end_sponsorship(&id, &who);
 						dead_accounts.push(who);
 					} else {
 						// deposit may have been released, need to update `Account`
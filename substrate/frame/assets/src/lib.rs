@@ -58,6 +58,9 @@
 //!   from the approving account into some third-party destination account.
 //! * **Sufficiency**: The idea of a minimum-balance of an asset being sufficient to allow the
 //!   account's existence on the system without requiring any other existential-deposit.
+//! * **Sponsorship**: The Issuer of an asset class placing the deposit of an asset account on
+//!   behalf of its holder, so that the holder can receive the asset without holding any native
+//!   token.
 //!
 //! ### Goals
 //!
@@ -112,6 +115,9 @@
 //!   called by the asset class's Freezer or Admin.
 //! * `block`: Disallows further `transfer`s to and from an account; called by the asset class's
 //!   Freezer.
+//! * `sponsor`: Create an asset account for specified account, which then does not need to hold
+//!   any native token. Caller must place a deposit; called by the asset class's Issuer.
+//! * `unsponsor`: Stop sponsoring an asset account, returning the deposit; called by the sponsor.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -199,6 +205,39 @@ pub trait AssetsCallback<AssetId, AccountId> {
 /// Empty implementation in case no callbacks are required.
 impl<AssetId, AccountId> AssetsCallback<AssetId, AccountId> for () {}
 
+/// Trait with a hook that is executed before assets are transferred between accounts, e.g. to
+/// enforce the compliance rules of an asset.
+///
+/// The weight of the hook is accounted for by the `on_asset_transfer` benchmark, which is added
+/// to the weight of the transfer dispatchables.
+pub trait OnAssetTransfer<AssetId, AccountId, Balance> {
+	/// The error returned when a transfer is vetoed.
+	type Error: Into<DispatchError>;
+
+	/// Indicates that `amount` of the asset with `id` is about to be transferred from `source` to
+	/// `dest`. Returning an error vetoes the transfer.
+	fn on_transfer(
+		id: &AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> Result<(), Self::Error>;
+}
+
+/// Empty implementation allowing all transfers.
+impl<AssetId, AccountId, Balance> OnAssetTransfer<AssetId, AccountId, Balance> for () {
+	type Error = DispatchError;
+
+	fn on_transfer(
+		_id: &AssetId,
+		_source: &AccountId,
+		_dest: &AccountId,
+		_amount: Balance,
+	) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -289,6 +328,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type AssetAccountDeposit: Get<DepositBalanceOf<Self, I>>;
 
+		/// The maximum number of asset accounts a single account can sponsor for an asset class.
+		#[pallet::constant]
+		type MaxSponsoredAccounts: Get<u32>;
+
 		/// The basic amount of funds that must be reserved when adding metadata to your asset.
 		#[pallet::constant]
 		type MetadataDepositBase: Get<DepositBalanceOf<Self, I>>;
@@ -316,6 +359,9 @@ pub mod pallet {
 		/// Callback methods for asset state change (e.g. asset created or destroyed)
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// Hook executed before assets are transferred, able to veto the transfer.
+		type OnAssetTransfer: OnAssetTransfer<Self::AssetId, Self::AccountId, Self::Balance>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -368,6 +414,31 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// The sponsor of a sponsored asset account.
+	/// First key is the asset ID, second key is the account holder.
+	pub(super) type Sponsorships<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::AccountId,
+	>;
+
+	#[pallet::storage]
+	/// The number of asset accounts sponsored by an account.
+	/// First key is the asset ID, second key is the sponsor.
+	pub(super) type SponsoredAccounts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -524,6 +595,10 @@ pub mod pallet {
 		Touched { asset_id: T::AssetId, who: T::AccountId, depositor: T::AccountId },
 		/// Some account `who` was blocked.
 		Blocked { asset_id: T::AssetId, who: T::AccountId },
+		/// The asset account of `who` was created with a deposit from its sponsor `sponsor`.
+		Sponsored { asset_id: T::AssetId, who: T::AccountId, sponsor: T::AccountId },
+		/// The sponsorship of the asset account of `who` by `sponsor` ended.
+		Unsponsored { asset_id: T::AssetId, who: T::AccountId, sponsor: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -571,6 +646,10 @@ pub mod pallet {
 		NotFrozen,
 		/// Callback action resulted in error
 		CallbackFailed,
+		/// The sponsor already sponsors the maximum number of asset accounts.
+		TooManySponsoredAccounts,
+		/// The asset-account is not sponsored.
+		NotSponsored,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -833,6 +912,9 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(T::WeightInfo::on_asset_transfer())
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -866,6 +948,9 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive().saturating_add(T::WeightInfo::on_asset_transfer())
+		)]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -900,6 +985,9 @@ pub mod pallet {
 		/// Modes: Pre-existence of `dest`; Post-existence of `source`; Account pre-existence of
 		/// `dest`.
 		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::force_transfer().saturating_add(T::WeightInfo::on_asset_transfer())
+		)]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1456,6 +1544,9 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_approved().saturating_add(T::WeightInfo::on_asset_transfer())
+		)]
 		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1636,6 +1727,55 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Sponsor an asset account for `who`, so that it can receive the asset `id` without
+		/// holding any native token.
+		///
+		/// A deposit will be taken from the signer account. The number of asset accounts the
+		/// signer sponsors for the asset `id` is limited by `MaxSponsoredAccounts`.
+		///
+		/// - `origin`: Must be Signed by the `Issuer` of the asset `id`; the signer account must
+		///   have sufficient funds for a deposit to be taken.
+		/// - `id`: The identifier of the asset for the account to be created.
+		/// - `who`: The account to be created.
+		///
+		/// Emits `Touched` and `Sponsored` events when successful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::sponsor())]
+		pub fn sponsor(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::do_sponsor(id, who, origin)
+		}
+
+		/// Stop sponsoring the asset account of `who`, returning the deposit to the sponsor.
+		///
+		/// An account with a zero balance is destroyed. An account with a non-zero balance must
+		/// be able to exist without the deposit: it takes a sufficient reference if the asset `id`
+		/// is sufficient, and a consumer reference otherwise.
+		///
+		/// - `origin`: Must be Signed by the sponsor of the asset account.
+		/// - `id`: The identifier of the asset for the sponsored account.
+		/// - `who`: The sponsored account.
+		///
+		/// Emits `Unsponsored` event when successful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::unsponsor())]
+		pub fn unsponsor(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::do_unsponsor(id, who, &origin)
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
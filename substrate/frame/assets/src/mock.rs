@@ -143,6 +143,7 @@ impl Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type AssetDeposit = ConstU64<1>;
 	type AssetAccountDeposit = ConstU64<10>;
+	type MaxSponsoredAccounts = ConstU32<2>;
 	type MetadataDepositBase = ConstU64<1>;
 	type MetadataDepositPerByte = ConstU64<1>;
 	type ApprovalDeposit = ConstU64<1>;
@@ -150,6 +151,7 @@ impl Config for Test {
 	type Freezer = TestFreezer;
 	type WeightInfo = ();
 	type CallbackHandle = AssetsCallbackHandle;
+	type OnAssetTransfer = TestTransferHook;
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -193,6 +195,26 @@ pub(crate) fn clear_frozen_balance(asset: u32, who: u64) {
 	});
 }
 
+parameter_types! {
+	static TransferBlocked: Vec<(u32, u64)> = Default::default();
+}
+
+pub struct TestTransferHook;
+impl OnAssetTransfer<u32, u64, u64> for TestTransferHook {
+	type Error = TokenError;
+
+	fn on_transfer(asset: &u32, _source: &u64, dest: &u64, _amount: u64) -> Result<(), TokenError> {
+		if TransferBlocked::get().contains(&(*asset, *dest)) {
+			return Err(TokenError::Blocked)
+		}
+		Ok(())
+	}
+}
+
+pub(crate) fn block_transfers_to(asset: u32, who: u64) {
+	TransferBlocked::mutate(|v| v.push((asset, who)));
+}
+
 pub(crate) fn hooks() -> Vec<Hook> {
 	Hooks::get().clone()
 }
@@ -729,6 +729,44 @@ fn transferring_frozen_user_should_not_work() {
 	});
 }
 
+#[test]
+fn transfer_hook_can_veto_transfers() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 100));
+		block_transfers_to(0, 2);
+
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50), TokenError::Blocked);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 2, 50),
+			TokenError::Blocked
+		);
+		assert_noop!(
+			Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 2, 50),
+			TokenError::Blocked
+		);
+		assert_noop!(
+			<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 50, Protect),
+			TokenError::Blocked
+		);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 3, 50));
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(3), 0, 1, 2, 50),
+			TokenError::Blocked
+		);
+
+		// transfers of other assets and to other accounts are not affected
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 50));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 50));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::balance(1, 2), 50);
+		assert_eq!(Assets::balance(0, 3), 50);
+	});
+}
+
 #[test]
 fn transferring_frozen_asset_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -1103,6 +1141,114 @@ fn refund_other_works() {
 	})
 }
 
+#[test]
+fn sponsor_and_unsponsor_works() {
+	new_test_ext().execute_with(|| {
+		// 1 will be the asset owner and issuer
+		// 3 will be sponsored by 1 and holds no native token
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+
+		// only the issuer can sponsor
+		assert_noop!(Assets::sponsor(RuntimeOrigin::signed(2), 0, 3), Error::<Test>::NoPermission);
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Sponsored {
+			asset_id: 0,
+			who: 3,
+			sponsor: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_eq!(SponsoredAccounts::<Test>::get(0, 1), 1);
+		assert_noop!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 3), Error::<Test>::AlreadyExists);
+
+		// the sponsored account can receive the asset without any native token
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 100));
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert_eq!(System::consumers(&3), 0);
+
+		// only the sponsor can unsponsor
+		assert_noop!(
+			Assets::unsponsor(RuntimeOrigin::signed(2), 0, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::unsponsor(RuntimeOrigin::signed(1), 0, 4),
+			Error::<Test>::NotSponsored
+		);
+		// the account can not exist on its own yet
+		assert_noop!(
+			Assets::unsponsor(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::UnavailableConsumer
+		);
+
+		// once it holds native tokens, the account keeps its balance with a consumer reference
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Assets::unsponsor(RuntimeOrigin::signed(1), 0, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::Unsponsored {
+			asset_id: 0,
+			who: 3,
+			sponsor: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(SponsoredAccounts::<Test>::get(0, 1), 0);
+		assert!(!Sponsorships::<Test>::contains_key(0, 3));
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert_eq!(System::consumers(&3), 1);
+		assert_eq!(asset_account_counts(0), (1, 0));
+		assert_noop!(
+			Assets::unsponsor(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::NotSponsored
+		);
+	})
+}
+
+#[test]
+fn unsponsor_of_sufficient_asset_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 100));
+		assert_eq!(asset_account_counts(0), (1, 0));
+
+		// the account is kept alive by the asset instead of the deposit
+		assert_ok!(Assets::unsponsor(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert_eq!(System::sufficients(&3), 1);
+		assert_eq!(asset_account_counts(0), (1, 1));
+	})
+}
+
+#[test]
+fn sponsored_accounts_are_limited() {
+	new_test_ext().execute_with(|| {
+		// 1 will be the asset owner, issuer and admin
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 4));
+		assert_noop!(
+			Assets::sponsor(RuntimeOrigin::signed(1), 0, 5),
+			Error::<Test>::TooManySponsoredAccounts
+		);
+
+		// an account with a zero balance is destroyed when unsponsored
+		assert_ok!(Assets::unsponsor(RuntimeOrigin::signed(1), 0, 3));
+		assert!(!Account::<Test>::contains_key(0, &3));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 5));
+
+		// refunding a sponsored account ends its sponsorship as well
+		assert_ok!(Assets::refund_other(RuntimeOrigin::signed(1), 0, 4));
+		assert!(!Sponsorships::<Test>::contains_key(0, 4));
+		assert_eq!(SponsoredAccounts::<Test>::get(0, 1), 1);
+		assert_ok!(Assets::sponsor(RuntimeOrigin::signed(1), 0, 6));
+		assert_eq!(SponsoredAccounts::<Test>::get(0, 1), 2);
+	})
+}
+
 #[test]
 fn transferring_amount_more_than_available_balance_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -44,7 +44,7 @@ use frame_support::{
 	BoundedSlice, BoundedVec, ConsensusEngineId, Parameter,
 };
 use log;
-use sp_consensus_aura::{AuthorityIndex, ConsensusLog, Slot, AURA_ENGINE_ID};
+use sp_consensus_aura::{AuthorityIndex, ConsensusLog, Slot, SlotPerformance, AURA_ENGINE_ID};
 use sp_runtime::{
 	generic::DigestItem,
 	traits::{IsMember, Member, SaturatedConversion, Saturating, Zero},
@@ -149,12 +149,14 @@ pub mod pallet {
 							authority_index,
 						);
 					}
+
+					Self::note_authored_slot(current_slot, new_slot, n_authorities);
 				}
 
 				// TODO [#3398] Generate offence report for all authorities that skipped their
 				// slots.
 
-				T::DbWeight::get().reads_writes(2, 1)
+				T::DbWeight::get().reads_writes(3, 2)
 			} else {
 				T::DbWeight::get().reads(1)
 			}
@@ -179,6 +181,14 @@ pub mod pallet {
 	#[pallet::getter(fn current_slot)]
 	pub(super) type CurrentSlot<T: Config> = StorageValue<_, Slot, ValueQuery>;
 
+	/// The authoring performance of each authority in the current session, in the order of
+	/// [`Authorities`].
+	///
+	/// This is cleared at the start of every session.
+	#[pallet::storage]
+	pub(super) type AuthoringPerformance<T: Config> =
+		StorageValue<_, BoundedVec<SlotPerformance, T::MaxAuthorities>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -235,6 +245,48 @@ impl<T: Config> Pallet<T> {
 		Authorities::<T>::decode_len().unwrap_or(0)
 	}
 
+	/// The authoring performance of each current authority in the current session.
+	pub fn authoring_performance() -> Vec<(T::AuthorityId, SlotPerformance)> {
+		let performance = AuthoringPerformance::<T>::get();
+		Self::authorities()
+			.into_iter()
+			.enumerate()
+			.map(|(i, authority)| (authority, performance.get(i).copied().unwrap_or_default()))
+			.collect()
+	}
+
+	/// Account for the slots after `previous` up to `new`, of which only `new` was authored.
+	///
+	/// Nothing is accounted for if the slot did not change.
+	fn note_authored_slot(previous: Slot, new: Slot, n_authorities: usize) {
+		if new <= previous || n_authorities == 0 {
+			return
+		}
+
+		AuthoringPerformance::<T>::mutate(|performance| {
+			// The counters are indexed like the authorities, which changed.
+			if performance.len() != n_authorities {
+				*performance =
+					BoundedVec::truncate_from(sp_std::vec![Default::default(); n_authorities]);
+			}
+
+			let n = n_authorities as u64;
+			// Nobody was expected to author the slots before the first block.
+			let first = if *previous == 0 { *new } else { *previous + 1 };
+			let slots = *new - first + 1;
+			// Every authority was expected to author `slots / n` of the slots, and the first
+			// `slots % n` authorities starting with the one of slot `first` one slot more.
+			for (index, p) in performance.iter_mut().enumerate() {
+				let offset = (index as u64 + n - first % n) % n;
+				let expected = slots / n + u64::from(offset < slots % n);
+				p.expected = p.expected.saturating_add(expected.saturated_into());
+			}
+			if let Some(author) = performance.get_mut((*new % n) as usize) {
+				author.produced.saturating_inc();
+			}
+		});
+	}
+
 	/// Get the current slot from the pre-runtime digests.
 	fn current_slot_from_digests() -> Option<Slot> {
 		let digest = frame_system::Pallet::<T>::digest();
@@ -332,6 +384,9 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 	where
 		I: Iterator<Item = (&'a T::AccountId, T::AuthorityId)>,
 	{
+		// The weight of the session rotation is accounted for by `pallet-session`.
+		AuthoringPerformance::<T>::kill();
+
 		// instant changes
 		if changed {
 			let next_authorities = validators.map(|(_, k)| k).collect::<Vec<_>>();
@@ -420,3 +475,36 @@ impl<T: Config> OnTimestampSet<T::Moment> for Pallet<T> {
 		);
 	}
 }
+
+/// A [`pallet_timestamp::DriftCheck`] expecting the timestamp of a block to be the start of its
+/// slot, rejecting blocks whose timestamp drifts from it by more than `MaxDrift`.
+///
+/// `OnDrift` is notified of every block whose timestamp is not the start of its slot. Note that
+/// with [`Config::AllowMultipleBlocksPerSlot`] only the first block of a slot can start it.
+pub struct SlotDriftCheck<T, MaxDrift, OnDrift = ()>(
+	sp_std::marker::PhantomData<(T, MaxDrift, OnDrift)>,
+);
+
+impl<T, MaxDrift, OnDrift> pallet_timestamp::DriftCheck<T::Moment>
+	for SlotDriftCheck<T, MaxDrift, OnDrift>
+where
+	T: Config,
+	MaxDrift: Get<T::Moment>,
+	OnDrift: pallet_timestamp::OnTimestampDrift<T::Moment>,
+{
+	fn expected_time() -> Option<T::Moment> {
+		let slot = CurrentSlot::<T>::get();
+		if *slot == 0 {
+			return None
+		}
+		Some((*slot).saturated_into::<T::Moment>().saturating_mul(Pallet::<T>::slot_duration()))
+	}
+
+	fn max_drift() -> T::Moment {
+		MaxDrift::get()
+	}
+
+	fn on_drift(now: T::Moment, expected: T::Moment) {
+		OnDrift::on_timestamp_drift(now, expected)
+	}
+}
@@ -21,7 +21,7 @@
 
 use crate::mock::{build_ext_and_execute_test, Aura, MockDisabledValidators, System};
 use codec::Encode;
-use frame_support::traits::OnInitialize;
+use frame_support::traits::{OnInitialize, OneSessionHandler};
 use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
 use sp_runtime::{Digest, DigestItem};
 
@@ -116,3 +116,47 @@ fn pallet_always_rejects_decreasing_slot() {
 		Aura::on_initialize(43);
 	});
 }
+
+#[test]
+fn authoring_performance_is_tracked_per_session() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		let author_block = |number: u64, slot: u64| {
+			let pre_digest = Digest {
+				logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, Slot::from(slot).encode())],
+			};
+			System::initialize(&number, &System::parent_hash(), &pre_digest);
+			Aura::on_initialize(number);
+			System::finalize();
+		};
+		let performance = || {
+			Aura::authoring_performance()
+				.into_iter()
+				.map(|(_, p)| (p.expected, p.produced))
+				.collect::<Vec<_>>()
+		};
+
+		// Only the slot of the first block was expected to be authored.
+		author_block(1, 1);
+		assert_eq!(performance(), vec![(0, 0), (1, 1), (0, 0), (0, 0)]);
+
+		// Additional blocks in the same slot are not counted.
+		crate::mock::AllowMultipleBlocksPerSlot::set(true);
+		author_block(2, 1);
+		author_block(3, 2);
+		assert_eq!(performance(), vec![(0, 0), (1, 1), (1, 1), (0, 0)]);
+
+		// The slots 3 to 6 were skipped.
+		author_block(4, 7);
+		assert_eq!(performance(), vec![(1, 0), (2, 1), (2, 1), (2, 1)]);
+
+		<Aura as OneSessionHandler<u64>>::on_new_session(
+			false,
+			sp_std::iter::empty(),
+			sp_std::iter::empty(),
+		);
+		assert_eq!(performance(), vec![(0, 0); 4]);
+
+		author_block(5, 8);
+		assert_eq!(performance(), vec![(1, 1), (0, 0), (0, 0), (0, 0)]);
+	});
+}
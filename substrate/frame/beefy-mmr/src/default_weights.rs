@@ -0,0 +1,34 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Default weights for the BEEFY-MMR Pallet
+//! This file was not auto-generated.
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+impl crate::WeightInfo for () {
+	fn prune_leaves(leaves: u32) -> Weight {
+		// Reading the earliest provable leaf and the number of leaves, writing the earliest
+		// provable leaf and the event.
+		let base_weight = DbWeight::get().reads_writes(2, 2);
+		// Pruning a leaf clears two nodes from the Offchain DB on average, which is accounted
+		// for as two writes.
+		let leaf_weight = DbWeight::get().writes(2);
+
+		base_weight.saturating_add(leaf_weight.saturating_mul(leaves.into()))
+	}
+}
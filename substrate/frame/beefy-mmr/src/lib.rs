@@ -32,22 +32,31 @@
 //! 3. Arbitrary extra leaf data to be used by downstream pallets to include custom data.
 //!
 //! and thanks to versioning can be easily updated in the future.
+//!
+//! To bound the growth of the Offchain DB, the MMR nodes that are only needed to prove old
+//! leaves can be pruned with [`Call::prune_leaves`], keeping the most recent
+//! [`Config::LeafRetention`] leaves provable. The first leaf that can still be proven is returned
+//! by [`Pallet::earliest_provable_leaf`].
 
-use sp_runtime::traits::{Convert, Member};
+use sp_runtime::traits::{Convert, Member, SaturatedConversion};
 use sp_std::prelude::*;
 
 use codec::Decode;
-use pallet_mmr::{LeafDataProvider, ParentNumberAndHash};
+use pallet_mmr::{
+	primitives::{utils::NodesUtils, LeafIndex},
+	LeafDataProvider, ParentNumberAndHash,
+};
 use sp_consensus_beefy::{
 	mmr::{BeefyAuthoritySet, BeefyDataProvider, BeefyNextAuthoritySet, MmrLeaf, MmrLeafVersion},
 	ValidatorSet as BeefyValidatorSet,
 };
 
-use frame_support::{crypto::ecdsa::ECDSAExt, traits::Get};
+use frame_support::{crypto::ecdsa::ECDSAExt, traits::Get, weights::Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use pallet::*;
 
+mod default_weights;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -88,12 +97,19 @@ impl Convert<sp_consensus_beefy::ecdsa_crypto::AuthorityId, Vec<u8>> for BeefyEc
 
 type MerkleRootOf<T> = <<T as pallet_mmr::Config>::Hashing as sp_runtime::traits::Hash>::Output;
 
+/// Weight information for the extrinsics of this pallet.
+pub trait WeightInfo {
+	/// The weight of [`Call::prune_leaves`] pruning `leaves` leaves.
+	fn prune_leaves(leaves: u32) -> Weight;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	#![allow(missing_docs)]
 
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 
 	/// BEEFY-MMR pallet.
 	#[pallet::pallet]
@@ -122,6 +138,20 @@ pub mod pallet {
 
 		/// Retrieve arbitrary data that should be added to the mmr leaf
 		type BeefyDataProvider: BeefyDataProvider<Self::LeafExtra>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to prune the MMR nodes of old leaves from the Offchain DB.
+		type PruneOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The number of most recent leaves that can not be pruned.
+		#[pallet::constant]
+		type LeafRetention: Get<LeafIndex>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Details of current BEEFY authority set.
@@ -137,6 +167,55 @@ pub mod pallet {
 	#[pallet::getter(fn beefy_next_authorities)]
 	pub type BeefyNextAuthorities<T: Config> =
 		StorageValue<_, BeefyNextAuthoritySet<MerkleRootOf<T>>, ValueQuery>;
+
+	/// The first leaf of the MMR whose nodes have not been pruned from the Offchain DB.
+	#[pallet::storage]
+	#[pallet::getter(fn earliest_provable_leaf)]
+	pub type EarliestProvableLeaf<T: Config> = StorageValue<_, LeafIndex, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The MMR nodes of the leaves before `earliest_provable_leaf` have been pruned.
+		LeavesPruned { earliest_provable_leaf: LeafIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// All the leaves outside of the retention window are already pruned.
+		NothingToPrune,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Prune the MMR nodes of up to `count` leaves from the Offchain DB of the nodes that index
+		/// it, starting at the earliest provable leaf.
+		///
+		/// Only the nodes that are not needed to prove later leaves are removed, and the most
+		/// recent [`Config::LeafRetention`] leaves are never pruned.
+		///
+		/// The dispatch origin for this call must be [`Config::PruneOrigin`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::prune_leaves(*count))]
+		pub fn prune_leaves(origin: OriginFor<T>, count: u32) -> DispatchResultWithPostInfo {
+			T::PruneOrigin::ensure_origin(origin)?;
+
+			let earliest = EarliestProvableLeaf::<T>::get();
+			let prunable =
+				pallet_mmr::Pallet::<T>::mmr_leaves().saturating_sub(T::LeafRetention::get());
+			let new_earliest = earliest.saturating_add(count.into()).min(prunable);
+			ensure!(new_earliest > earliest, Error::<T>::NothingToPrune);
+
+			for leaf in earliest..new_earliest {
+				Self::prune_leaf(leaf);
+			}
+			EarliestProvableLeaf::<T>::put(new_earliest);
+			Self::deposit_event(Event::LeavesPruned { earliest_provable_leaf: new_earliest });
+
+			let pruned = (new_earliest - earliest).saturated_into();
+			Ok(Some(T::WeightInfo::prune_leaves(pruned)).into())
+		}
+	}
 }
 
 impl<T: Config> LeafDataProvider for Pallet<T> {
@@ -185,6 +264,23 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::beefy_next_authorities()
 	}
 
+	/// Remove the MMR nodes that are no longer needed once `leaf` is pruned from the Offchain DB.
+	///
+	/// Proofs of later leaves still need the roots of the subtrees made only of pruned leaves,
+	/// so a node is only removed once its parent is made only of pruned leaves, i.e. once the
+	/// rightmost leaf below its parent is pruned. The nodes added with `leaf` are the parents
+	/// whose rightmost leaf it is.
+	fn prune_leaf(leaf: LeafIndex) {
+		let prefix = <T as pallet_mmr::Config>::INDEXING_PREFIX;
+		let branch = NodesUtils::right_branch_ending_in_leaf(leaf);
+		for (height, pos) in branch.into_iter().enumerate().skip(1) {
+			let (left_child, right_child) = (pos - (1 << height), pos - 1);
+			for child in [left_child, right_child] {
+				sp_io::offchain_index::clear(&NodesUtils::node_canon_offchain_key(prefix, child));
+			}
+		}
+	}
+
 	/// Returns details of a BEEFY authority set.
 	///
 	/// Details contain authority set id, authority set length and a merkle root,
@@ -225,6 +321,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// API useful for BEEFY light clients.
+	#[api_version(2)]
 	pub trait BeefyMmrApi<H>
 	where
 		BeefyAuthoritySet<H>: Decode,
@@ -234,5 +331,9 @@ sp_api::decl_runtime_apis! {
 
 		/// Return the next/queued BEEFY authority set proof.
 		fn next_authority_set_proof() -> BeefyNextAuthoritySet<H>;
+
+		/// Return the first leaf of the MMR that can still be proven.
+		#[api_version(2)]
+		fn earliest_provable_leaf() -> LeafIndex;
 	}
 }
@@ -53,7 +53,7 @@ construct_runtime!(
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>},
 		Mmr: pallet_mmr::{Pallet, Storage},
 		Beefy: pallet_beefy::{Pallet, Config<T>, Storage},
-		BeefyMmr: pallet_beefy_mmr::{Pallet, Storage},
+		BeefyMmr: pallet_beefy_mmr::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -116,6 +116,14 @@ impl pallet_beefy_mmr::Config for Test {
 	type LeafExtra = Vec<u8>;
 
 	type BeefyDataProvider = DummyDataProvider;
+
+	type RuntimeEvent = RuntimeEvent;
+
+	type PruneOrigin = frame_system::EnsureRoot<u64>;
+
+	type LeafRetention = ConstU64<2>;
+
+	type WeightInfo = ();
 }
 
 pub struct DummyDataProvider;
@@ -23,13 +23,13 @@ use sp_consensus_beefy::{
 	ValidatorSet,
 };
 
-use sp_core::H256;
+use sp_core::{offchain::OffchainStorage, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{traits::Keccak256, DigestItem};
 
-use frame_support::traits::OnInitialize;
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
 
-use crate::mock::*;
+use crate::{mock::*, Error, Event};
 
 fn init_block(block: u64) {
 	System::set_block_number(block);
@@ -207,3 +207,41 @@ fn should_update_authorities() {
 		assert_eq!(want, next_auth_set.keyset_commitment);
 	});
 }
+
+#[test]
+fn should_prune_nodes_of_old_leaves() {
+	let canon_key = |pos: u64| (<Test as pallet_mmr::Config>::INDEXING_PREFIX, pos).encode();
+	let stored_nodes = |ext: &mut TestExternalities| {
+		ext.persist_offchain_overlay();
+		let offchain_db = ext.offchain_db();
+		(0..11).filter(|pos| offchain_db.get(&canon_key(*pos)).is_some()).collect::<Vec<_>>()
+	};
+
+	// 7 leaves add the nodes 0 to 10, which are canonicalized.
+	let mut ext = new_test_ext(vec![1, 2, 3, 4]);
+	ext.execute_with(|| (1..=7).for_each(init_block));
+	let mut offchain_db = ext.offchain_db();
+	for pos in 0..11 {
+		offchain_db.set(b"", &canon_key(pos), b"node");
+	}
+
+	ext.execute_with(|| {
+		assert_ok!(BeefyMmr::prune_leaves(RuntimeOrigin::root(), 3));
+		assert_eq!(BeefyMmr::earliest_provable_leaf(), 3);
+		System::assert_last_event(Event::LeavesPruned { earliest_provable_leaf: 3 }.into());
+	});
+	// Node 2, the parent of the first two leaves, and node 3, the third leaf, are needed to prove
+	// the fourth leaf.
+	assert_eq!(stored_nodes(&mut ext), vec![2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+	ext.execute_with(|| {
+		// The last two leaves are retained.
+		assert_ok!(BeefyMmr::prune_leaves(RuntimeOrigin::root(), 10));
+		assert_eq!(BeefyMmr::earliest_provable_leaf(), 5);
+		assert_noop!(
+			BeefyMmr::prune_leaves(RuntimeOrigin::root(), 1),
+			Error::<Test>::NothingToPrune
+		);
+	});
+	assert_eq!(stored_nodes(&mut ext), vec![6, 7, 8, 9, 10]);
+}
@@ -53,4 +53,8 @@ impl crate::WeightInfo for () {
 	fn set_new_genesis() -> Weight {
 		DbWeight::get().writes(1)
 	}
+
+	fn set_signature_scheme() -> Weight {
+		DbWeight::get().writes(1)
+	}
 }
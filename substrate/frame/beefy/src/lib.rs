@@ -42,7 +42,7 @@ use sp_std::prelude::*;
 
 use sp_consensus_beefy::{
 	AuthorityIndex, BeefyAuthorityId, ConsensusLog, EquivocationProof, OnNewValidatorSet,
-	ValidatorSet, BEEFY_ENGINE_ID, GENESIS_AUTHORITY_SET_ID,
+	SignatureScheme, ValidatorSet, BEEFY_ENGINE_ID, GENESIS_AUTHORITY_SET_ID,
 };
 
 mod default_weights;
@@ -159,6 +159,17 @@ pub mod pallet {
 	pub(super) type GenesisBlock<T: Config> =
 		StorageValue<_, Option<BlockNumberFor<T>>, ValueQuery>;
 
+	/// The signature scheme the current validator set commits with.
+	#[pallet::storage]
+	#[pallet::getter(fn signature_scheme)]
+	pub(super) type CurrentSignatureScheme<T: Config> =
+		StorageValue<_, SignatureScheme, ValueQuery>;
+
+	/// Signature scheme scheduled to be used, starting with the next validator set.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_signature_scheme)]
+	pub(super) type PendingSignatureScheme<T: Config> = StorageValue<_, SignatureScheme>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Initial set of BEEFY authorities.
@@ -200,6 +211,8 @@ pub mod pallet {
 		DuplicateOffenceReport,
 		/// Submitted configuration is invalid.
 		InvalidConfiguration,
+		/// The signature scheme is not supported by the runtime.
+		UnsupportedSignatureScheme,
 	}
 
 	#[pallet::call]
@@ -284,6 +297,23 @@ pub mod pallet {
 			GenesisBlock::<T>::put(Some(genesis_block));
 			Ok(())
 		}
+
+		/// Schedule the validator sets to commit with the signature scheme `scheme`, starting
+		/// with the validator set of the next session.
+		///
+		/// Note: Only schemes which the BEEFY voter can sign with are supported, see
+		/// [`SignatureScheme::is_supported`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_signature_scheme())]
+		pub fn set_signature_scheme(
+			origin: OriginFor<T>,
+			scheme: SignatureScheme,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(scheme.is_supported(), Error::<T>::UnsupportedSignatureScheme);
+			PendingSignatureScheme::<T>::put(scheme);
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -340,6 +370,18 @@ impl<T: Config> Pallet<T> {
 			);
 			<frame_system::Pallet<T>>::deposit_log(log);
 
+			// Signature schemes only change together with the validator set.
+			if let Some(scheme) = <PendingSignatureScheme<T>>::take() {
+				if scheme != Self::signature_scheme() {
+					<CurrentSignatureScheme<T>>::put(scheme);
+					let log = DigestItem::Consensus(
+						BEEFY_ENGINE_ID,
+						ConsensusLog::<T::BeefyId>::SignatureSchemeChange(new_id, scheme).encode(),
+					);
+					<frame_system::Pallet<T>>::deposit_log(log);
+				}
+			}
+
 			let next_id = new_id + 1;
 			if let Some(next_validator_set) = ValidatorSet::<T::BeefyId>::new(queued, next_id) {
 				<T::OnNewValidatorSet as OnNewValidatorSet<_>>::on_new_validator_set(
@@ -472,4 +514,5 @@ impl<T: Config> IsMember<T::BeefyId> for Pallet<T> {
 pub trait WeightInfo {
 	fn report_equivocation(validator_count: u32, max_nominators_per_validator: u32) -> Weight;
 	fn set_new_genesis() -> Weight;
+	fn set_signature_scheme() -> Weight;
 }
@@ -145,6 +145,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<3>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -201,6 +202,7 @@ impl pallet_staking::Config for Test {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
 	type MaxUnlockingChunks = ConstU32<32>;
+	type NominationExpiry = ();
 	type HistoryDepth = ConstU32<84>;
 	type EventListeners = ();
 	type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
@@ -211,6 +213,8 @@ impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type DeferPeriod = ConstU64<0>;
+	type VetoOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 // Note, that we can't use `UintAuthorityId` here. Reason is that the implementation
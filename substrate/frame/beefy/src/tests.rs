@@ -20,18 +20,18 @@ use std::vec;
 use codec::Encode;
 use sp_consensus_beefy::{
 	check_equivocation_proof, generate_equivocation_proof, known_payloads::MMR_ROOT_ID,
-	Keyring as BeefyKeyring, Payload, ValidatorSet, KEY_TYPE as BEEFY_KEY_TYPE,
+	Keyring as BeefyKeyring, Payload, SignatureScheme, ValidatorSet, KEY_TYPE as BEEFY_KEY_TYPE,
 };
 
-use sp_runtime::DigestItem;
+use sp_runtime::{traits::BadOrigin, DigestItem};
 
 use frame_support::{
-	assert_err, assert_ok,
+	assert_err, assert_noop, assert_ok,
 	dispatch::{GetDispatchInfo, Pays},
 	traits::{Currency, KeyOwnerProofSystem, OnInitialize},
 };
 
-use crate::{mock::*, Call, Config, Error, Weight, WeightInfo};
+use crate::{mock::*, Call, Config, Error, PendingSignatureScheme, Weight, WeightInfo};
 
 fn init_block(block: u64) {
 	System::set_block_number(block);
@@ -813,3 +813,38 @@ fn set_new_genesis_works() {
 		);
 	});
 }
+
+#[test]
+fn set_signature_scheme_works() {
+	new_test_ext(vec![1, 2, 3, 4]).execute_with(|| {
+		assert_eq!(Beefy::signature_scheme(), SignatureScheme::Ecdsa);
+
+		// only root can schedule a signature scheme
+		assert_noop!(
+			Beefy::set_signature_scheme(RuntimeOrigin::signed(1), SignatureScheme::Ecdsa),
+			BadOrigin,
+		);
+
+		// scheduling the current signature scheme doesn't signal a change
+		assert_ok!(Beefy::set_signature_scheme(RuntimeOrigin::root(), SignatureScheme::Ecdsa));
+		init_block(1);
+		assert_eq!(Beefy::pending_signature_scheme(), None);
+		assert_eq!(Beefy::signature_scheme(), SignatureScheme::Ecdsa);
+
+		// the voter can't sign BLS commitments yet
+		assert_noop!(
+			Beefy::set_signature_scheme(RuntimeOrigin::root(), SignatureScheme::EcdsaBls),
+			Error::<Test>::UnsupportedSignatureScheme,
+		);
+
+		// the signature scheme only changes with the next validator set
+		PendingSignatureScheme::<Test>::put(SignatureScheme::EcdsaBls);
+		assert_eq!(Beefy::signature_scheme(), SignatureScheme::Ecdsa);
+
+		init_block(2);
+		assert_eq!(Beefy::signature_scheme(), SignatureScheme::EcdsaBls);
+
+		let want = beefy_log(ConsensusLog::SignatureSchemeChange(2, SignatureScheme::EcdsaBls));
+		assert!(System::digest().logs.contains(&want));
+	});
+}
@@ -21,6 +21,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::benchmarks;
+use frame_support::storage::unhashed;
 use frame_system::Pallet as System;
 use sp_runtime::{
 	traits::{AppVerify, Hash},
@@ -101,6 +102,26 @@ benchmarks! {
 		});
 	}
 
+	storage_read {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+		let mut found = 0;
+	}: {
+		keys.iter().for_each(|key| found += unhashed::get_raw(key).map_or(0, |_| 1));
+	} verify {
+		assert_eq!(found, i);
+	}
+
+	storage_batch_read {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+		let mut found = 0;
+	}: {
+		found += unhashed::get_raw_many(&keys).into_iter().flatten().count() as u32;
+	} verify {
+		assert_eq!(found, i);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		mock::new_test_ext(),
@@ -108,6 +129,17 @@ benchmarks! {
 	);
 }
 
+/// Put `n` values in storage, returning their keys.
+fn storage_keys(n: u32) -> Vec<Vec<u8>> {
+	(0..n)
+		.map(|j| {
+			let key = [b"baseline:".as_slice(), &j.to_be_bytes()].concat();
+			unhashed::put_raw(&key, &[0u8; 32]);
+			key
+		})
+		.collect()
+}
+
 #[cfg(test)]
 pub mod mock {
 	use frame_support::derive_impl;
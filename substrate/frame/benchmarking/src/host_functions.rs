@@ -0,0 +1,198 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks of the overhead of the host functions most commonly called by runtimes. These
+//! benchmarks do not require a pallet to be deployed.
+//!
+//! Each benchmark calls a host function `i` times, so that the slope of its weight in `i` is the
+//! cost of a single call on the machine running the benchmarks. Rendering the results with the
+//! `.maintain/host-function-weights-template.hbs` template of the benchmarking CLI emits these
+//! costs as weight constants, in the style of the `extrinsic_weights.rs` of a runtime.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::benchmarks;
+use frame_system::Pallet as System;
+use sp_core::crypto::KeyTypeId;
+use sp_io::{crypto, hashing, storage};
+use sp_std::vec::Vec;
+
+/// The key type of the keys generated to benchmark signature verification.
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"host");
+
+/// The message which is signed to benchmark signature verification.
+const MESSAGE: [u8; 32] = [1; 32];
+
+pub struct Pallet<T: Config>(System<T>);
+pub trait Config: frame_system::Config {}
+
+benchmarks! {
+	blake2_128 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 16];
+	}: {
+		(0..i).for_each(|_| hash = hashing::blake2_128(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 16]);
+	}
+
+	blake2_256 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 32];
+	}: {
+		(0..i).for_each(|_| hash = hashing::blake2_256(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 32]);
+	}
+
+	twox_64 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 8];
+	}: {
+		(0..i).for_each(|_| hash = hashing::twox_64(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 8]);
+	}
+
+	twox_128 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 16];
+	}: {
+		(0..i).for_each(|_| hash = hashing::twox_128(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 16]);
+	}
+
+	keccak_256 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 32];
+	}: {
+		(0..i).for_each(|_| hash = hashing::keccak_256(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 32]);
+	}
+
+	sha2_256 {
+		let i in 0 .. 1_000;
+		let mut hash = [0u8; 32];
+	}: {
+		(0..i).for_each(|_| hash = hashing::sha2_256(&hash));
+	} verify {
+		assert!(i == 0 || hash != [0; 32]);
+	}
+
+	storage_get {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+		keys.iter().for_each(|key| storage::set(key, &[0u8; 32]));
+		let mut found = 0;
+	}: {
+		keys.iter().for_each(|key| found += storage::get(key).map_or(0, |_| 1));
+	} verify {
+		assert_eq!(found, i);
+	}
+
+	storage_exists {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+		keys.iter().for_each(|key| storage::set(key, &[0u8; 32]));
+		let mut found = 0;
+	}: {
+		keys.iter().for_each(|key| found += storage::exists(key) as u32);
+	} verify {
+		assert_eq!(found, i);
+	}
+
+	storage_set {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+	}: {
+		keys.iter().for_each(|key| storage::set(key, &[0u8; 32]));
+	} verify {
+		assert!(keys.iter().all(|key| storage::exists(key)));
+	}
+
+	storage_clear {
+		let i in 0 .. 1_000;
+		let keys = storage_keys(i);
+		keys.iter().for_each(|key| storage::set(key, &[0u8; 32]));
+	}: {
+		keys.iter().for_each(|key| storage::clear(key));
+	} verify {
+		assert!(keys.iter().all(|key| !storage::exists(key)));
+	}
+
+	sr25519_verify {
+		let i in 0 .. 100;
+		let public = crypto::sr25519_generate(KEY_TYPE, None);
+		let signature = crypto::sr25519_sign(KEY_TYPE, &public, &MESSAGE).unwrap();
+		let mut valid = 0;
+	}: {
+		(0..i).for_each(|_| valid += crypto::sr25519_verify(&signature, &MESSAGE, &public) as u32);
+	} verify {
+		assert_eq!(valid, i);
+	}
+
+	ed25519_verify {
+		let i in 0 .. 100;
+		let public = crypto::ed25519_generate(KEY_TYPE, None);
+		let signature = crypto::ed25519_sign(KEY_TYPE, &public, &MESSAGE).unwrap();
+		let mut valid = 0;
+	}: {
+		(0..i).for_each(|_| valid += crypto::ed25519_verify(&signature, &MESSAGE, &public) as u32);
+	} verify {
+		assert_eq!(valid, i);
+	}
+
+	ecdsa_verify {
+		let i in 0 .. 100;
+		let public = crypto::ecdsa_generate(KEY_TYPE, None);
+		let signature = crypto::ecdsa_sign(KEY_TYPE, &public, &MESSAGE).unwrap();
+		let mut valid = 0;
+	}: {
+		(0..i).for_each(|_| valid += crypto::ecdsa_verify(&signature, &MESSAGE, &public) as u32);
+	} verify {
+		assert_eq!(valid, i);
+	}
+
+	secp256k1_ecdsa_recover {
+		let i in 0 .. 100;
+		let public = crypto::ecdsa_generate(KEY_TYPE, None);
+		let signature = crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &MESSAGE).unwrap();
+		let mut recovered = 0;
+	}: {
+		(0..i).for_each(|_| {
+			recovered += crypto::secp256k1_ecdsa_recover(&signature.0, &MESSAGE).is_ok() as u32
+		});
+	} verify {
+		assert_eq!(recovered, i);
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::baseline::mock::new_test_ext(),
+		crate::baseline::mock::Test,
+	);
+}
+
+/// The keys of `n` storage items.
+fn storage_keys(n: u32) -> Vec<Vec<u8>> {
+	(0..n).map(|j| [b"host_functions:".as_slice(), &j.to_be_bytes()].concat()).collect()
+}
+
+#[cfg(test)]
+impl Config for crate::baseline::mock::Test {}
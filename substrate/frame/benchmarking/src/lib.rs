@@ -26,8 +26,10 @@ mod tests;
 #[cfg(test)]
 mod tests_instance;
 mod utils;
+mod worst_case;
 
 pub mod baseline;
+pub mod host_functions;
 pub mod v1;
 
 /// Private exports that are being used by macros.
@@ -116,12 +118,18 @@ pub use v1::*;
 /// return type compatible with `Result<(), BenchmarkError>`, as discussed below) and zero or
 /// more arguments whose names are valid [BenchmarkParameter](`crate::BenchmarkParameter`)
 /// parameters, such as `x`, `y`, `a`, `b`, etc., and whose param types must implement
-/// [ParamRange](`v2::ParamRange`). At the moment the only valid type that implements
-/// [ParamRange](`v2::ParamRange`) is [Linear](`v2::Linear`).
+/// [ParamRange](`v2::ParamRange`). At the moment the valid types that implement
+/// [ParamRange](`v2::ParamRange`) are [Linear](`v2::Linear`) and
+/// [StorageBound](`v2::StorageBound`).
 ///
 /// The valid syntax for defining a [Linear](`v2::Linear`) is `Linear<A, B>` where `A`, and `B`
 /// are valid integer literals (that fit in a `u32`), such that `B` >= `A`.
 ///
+/// The valid syntax for defining a [StorageBound](`v2::StorageBound`) is `StorageBound<Item, C>`
+/// where `Item` is a storage item of the pallet, i.e. `Members<T>`, and `C` is a valid integer
+/// literal. The param then ranges from `0` to the `max_values` declared for `Item`, or `C` if
+/// that is smaller or `Item` has no such bound.
+///
 /// Anywhere within a benchmark function you may use the generic `T: Config` parameter as well
 /// as `I` in the case of an `#[instance_benchmarks]` module. You should not add these to the
 /// function signature as this will be handled automatically for you based on whether this is a
@@ -183,10 +191,11 @@ pub use v1::*;
 ///
 /// ### Optional Attributes
 ///
-/// The keywords `extra` and `skip_meta` can be provided as optional arguments to the
-/// `#[benchmark]` attribute, i.e. `#[benchmark(extra, skip_meta)]`. Including either of these
-/// will enable the `extra` or `skip_meta` option, respectively. These options enable the same
-/// behavior they did in the old benchmarking syntax in `frame_benchmarking`, namely:
+/// The keywords `extra`, `skip_meta` and `worst_case_storage` can be provided as optional
+/// arguments to the `#[benchmark]` attribute, i.e. `#[benchmark(extra, skip_meta)]`. Including
+/// any of these will enable the `extra`, `skip_meta` or `worst_case_storage` option,
+/// respectively. The first two options enable the same behavior they did in the old benchmarking
+/// syntax in `frame_benchmarking`, namely:
 ///
 /// #### `extra`
 ///
@@ -200,6 +209,15 @@ pub use v1::*;
 /// 0x… in case a storage key that does not have metadata. Note that this skips the analysis of
 /// all accesses, not just ones without metadata.
 ///
+/// #### `worst_case_storage`
+///
+/// Takes the storage maps of the pallet to fill before the setup code of the benchmark runs,
+/// i.e. `#[benchmark(worst_case_storage(Members<T>, Proposals<T>))]`. Each map is filled up to
+/// its `max_values` with the [`WorstCase`](v2::WorstCase) of its value type, using
+/// [`FillToBounds`](v2::FillToBounds). Maps without a bound on the number of values are filled
+/// with [`WORST_CASE_STORAGE_CAP`] entries. This gives simple pallets a credible worst case
+/// without hand-written setup code.
+///
 /// ## Where Clause
 ///
 /// Some pallets require a where clause specifying constraints on their generics to make
@@ -312,7 +330,11 @@ pub use v1::*;
 pub mod v2 {
 	pub use super::*;
 	pub use frame_support_procedural::{
-		benchmark, benchmarks, block, extrinsic_call, instance_benchmarks,
+		benchmark, benchmarks, block, extrinsic_call, instance_benchmarks, WorstCase,
+	};
+
+	pub use super::worst_case::{
+		assert_worst_case, full_bounded_vec, Distinct, FillToBounds, WorstCase,
 	};
 
 	// Used in #[benchmark] implementation to ensure that benchmark function arguments
@@ -350,4 +372,24 @@ pub mod v2 {
 			B
 		}
 	}
+
+	/// Used by the new benchmarking code to specify that a benchmarking variable ranges from `0`
+	/// to the maximum number of values the storage `Item` can hold, capped at `CAP`, i.e.
+	/// `StorageBound<Members<T>, 1_000>`.
+	///
+	/// The bound is derived from the [`StorageInfo`](frame_support::traits::StorageInfo) of
+	/// `Item`. See [`v2`] for more info.
+	pub struct StorageBound<Item, const CAP: u32>(sp_std::marker::PhantomData<Item>);
+
+	impl<Item: frame_support::traits::StorageInfoTrait, const CAP: u32> ParamRange
+		for StorageBound<Item, CAP>
+	{
+		fn start(&self) -> u32 {
+			0
+		}
+
+		fn end(&self) -> u32 {
+			storage_bound::<Item>(CAP)
+		}
+	}
 }
@@ -442,3 +442,44 @@ mod benchmarks {
 		});
 	}
 }
+
+#[test]
+fn storage_bound_works() {
+	assert_eq!(crate::storage_bound::<pallet_test::Value<Test>>(10), 1);
+	assert_eq!(crate::storage_bound::<frame_system::Account<Test>>(10), 10);
+}
+
+#[frame_support::storage_alias]
+type BoundedMap = StorageMap<
+	TestPallet,
+	frame_support::Twox64Concat,
+	u64,
+	frame_support::BoundedVec<u8, ConstU32<10>>,
+	frame_support::storage::types::OptionQuery,
+	frame_support::traits::GetDefault,
+	ConstU32<3>,
+>;
+
+#[frame_support::storage_alias]
+type UnboundedDoubleMap = StorageDoubleMap<
+	TestPallet,
+	frame_support::Twox64Concat,
+	u64,
+	frame_support::Twox64Concat,
+	u32,
+	Option<u16>,
+>;
+
+#[test]
+fn fill_to_bounds_works() {
+	use crate::v2::FillToBounds;
+
+	new_test_ext().execute_with(|| {
+		BoundedMap::fill_to_bounds(7);
+		UnboundedDoubleMap::fill_to_bounds(7);
+
+		let full = frame_support::BoundedVec::truncate_from(vec![u8::MAX; 10]);
+		assert_eq!(BoundedMap::iter_values().collect::<Vec<_>>(), vec![full; 3]);
+		assert_eq!(UnboundedDoubleMap::iter_values().collect::<Vec<_>>(), vec![Some(u16::MAX); 7]);
+	});
+}
@@ -17,7 +17,11 @@
 
 //! Interfaces, types and utils for benchmarking a FRAME runtime.
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchErrorWithPostInfo, pallet_prelude::*, traits::StorageInfo};
+use frame_support::{
+	dispatch::DispatchErrorWithPostInfo,
+	pallet_prelude::*,
+	traits::{StorageInfo, StorageInfoTrait},
+};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -370,6 +374,21 @@ pub fn whitelisted_caller<AccountId: Decode>() -> AccountId {
 	account::<AccountId>("whitelisted_caller", 0, 0)
 }
 
+/// The maximum number of entries `#[benchmark(worst_case_storage(..))]` writes into a single map.
+pub const WORST_CASE_STORAGE_CAP: u32 = 1_000;
+
+/// The maximum number of values the storage `Item` can hold, capped at `cap`.
+///
+/// Storage items without a bound on the number of values are assumed to hold `cap` values. This
+/// backs the `StorageBound<Item, CAP>` benchmark component.
+pub fn storage_bound<Item: StorageInfoTrait>(cap: u32) -> u32 {
+	Item::storage_info()
+		.iter()
+		.filter_map(|info| info.max_values)
+		.min()
+		.map_or(cap, |max_values| max_values.min(cap))
+}
+
 #[macro_export]
 macro_rules! whitelist_account {
 	($acc:ident) => {
@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since the `storage_read` and `storage_batch_read` benchmarks were added.
+//! Their weights are PLACEHOLDERS; re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -58,6 +61,8 @@ pub trait WeightInfo {
 	fn division(i: u32, ) -> Weight;
 	fn hashing() -> Weight;
 	fn sr25519_verification(i: u32, ) -> Weight;
+	fn storage_read(i: u32, ) -> Weight;
+	fn storage_batch_read(i: u32, ) -> Weight;
 }
 
 /// Weights for frame_benchmarking using the Substrate node and recommended hardware.
@@ -112,6 +117,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 6_256
 			.saturating_add(Weight::from_parts(55_456_705, 0).saturating_mul(i.into()))
 	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `i` is `[0, 1000]`.
+	fn storage_read(i: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from one read per key.
+		Weight::from_parts(331_000, 0)
+			.saturating_add(Weight::from_parts(2_118_644, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `i` is `[0, 1000]`.
+	fn storage_batch_read(i: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from one read per key.
+		Weight::from_parts(356_000, 0)
+			.saturating_add(Weight::from_parts(1_472_309, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -165,4 +190,24 @@ impl WeightInfo for () {
 			// Standard Error: 6_256
 			.saturating_add(Weight::from_parts(55_456_705, 0).saturating_mul(i.into()))
 	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `i` is `[0, 1000]`.
+	fn storage_read(i: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from one read per key.
+		Weight::from_parts(331_000, 0)
+			.saturating_add(Weight::from_parts(2_118_644, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `i` is `[0, 1000]`.
+	fn storage_batch_read(i: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from one read per key.
+		Weight::from_parts(356_000, 0)
+			.saturating_add(Weight::from_parts(1_472_309, 0).saturating_mul(i.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 2520).saturating_mul(i.into()))
+	}
 }
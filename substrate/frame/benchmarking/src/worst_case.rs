@@ -0,0 +1,340 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Worst case values of bounded types, to be used as the inputs and storage of benchmarks.
+
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use frame_support::{
+	storage::types::{
+		CountedStorageMap, CountedStorageMapInstance, QueryKindTrait, StorageDoubleMap,
+		StorageMap,
+	},
+	traits::{Get, StorageInstance},
+	BoundedBTreeSet, BoundedVec, StorageHasher, WeakBoundedVec,
+};
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*, vec};
+
+/// A type with a worst case value for benchmarking, i.e. a value that is as large as the type
+/// allows.
+///
+/// Integers are at their maximum, options are `Some` and bounded collections are at capacity,
+/// with every element at its own worst case. Types whose encoding never reaches
+/// [`MaxEncodedLen::max_encoded_len`] are not a good fit.
+///
+/// The trait can be derived for structs, building every field with its own worst case, so that
+/// new fields are covered without touching the benchmarks. Deriving it for an enum requires
+/// marking the variant to build with `#[worst_case]`:
+///
+/// ```ignore
+/// #[derive(Encode, MaxEncodedLen, frame_benchmarking::v2::WorstCase)]
+/// pub struct Info<Limit: Get<u32>> {
+/// 	pub fields: BoundedVec<(u32, u64), Limit>,
+/// 	pub nickname: Option<[u8; 32]>,
+/// }
+///
+/// #[derive(Encode, MaxEncodedLen, frame_benchmarking::v2::WorstCase)]
+/// pub enum Judgement {
+/// 	Unknown,
+/// 	#[worst_case]
+/// 	FeePaid(u128),
+/// }
+/// ```
+pub trait WorstCase {
+	/// The worst case value of the type.
+	fn worst_case() -> Self;
+}
+
+macro_rules! impl_worst_case_for_integers {
+	( $( $int:ty ),* ) => {
+		$(
+			impl WorstCase for $int {
+				fn worst_case() -> Self {
+					<$int>::MAX
+				}
+			}
+		)*
+	};
+}
+
+impl_worst_case_for_integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl WorstCase for bool {
+	fn worst_case() -> Self {
+		true
+	}
+}
+
+impl WorstCase for () {
+	fn worst_case() -> Self {}
+}
+
+impl<T: WorstCase> WorstCase for Option<T> {
+	fn worst_case() -> Self {
+		Some(T::worst_case())
+	}
+}
+
+impl<T: WorstCase, const N: usize> WorstCase for [T; N] {
+	fn worst_case() -> Self {
+		core::array::from_fn(|_| T::worst_case())
+	}
+}
+
+impl<T: WorstCase> WorstCase for Box<T> {
+	fn worst_case() -> Self {
+		Box::new(T::worst_case())
+	}
+}
+
+macro_rules! impl_worst_case_for_tuples {
+	( $( ( $( $t:ident ),+ ) ),* ) => {
+		$(
+			impl<$( $t: WorstCase ),+> WorstCase for ( $( $t, )+ ) {
+				fn worst_case() -> Self {
+					( $( $t::worst_case(), )+ )
+				}
+			}
+		)*
+	};
+}
+
+impl_worst_case_for_tuples!(
+	(A),
+	(A, B),
+	(A, B, C),
+	(A, B, C, D),
+	(A, B, C, D, E),
+	(A, B, C, D, E, F)
+);
+
+impl<T: WorstCase, S: Get<u32>> WorstCase for BoundedVec<T, S> {
+	fn worst_case() -> Self {
+		BoundedVec::truncate_from(full_vec::<T, S>())
+	}
+}
+
+impl<T: WorstCase, S: Get<u32>> WorstCase for WeakBoundedVec<T, S> {
+	fn worst_case() -> Self {
+		WeakBoundedVec::force_from(full_vec::<T, S>(), None)
+	}
+}
+
+/// The elements of a set must be distinct, so they are built with [`Distinct`] rather than
+/// [`WorstCase`].
+impl<T: Distinct + Ord, S: Get<u32>> WorstCase for BoundedBTreeSet<T, S> {
+	fn worst_case() -> Self {
+		let set = (0..S::get()).map(T::distinct).collect::<BTreeSet<_>>();
+		set.try_into().expect("the set has at most `S` elements; qed")
+	}
+}
+
+/// A type with many distinct values of its maximum encoded length, e.g. to fill sets.
+pub trait Distinct {
+	/// The `i`th distinct value.
+	fn distinct(i: u32) -> Self;
+}
+
+macro_rules! impl_distinct_for_integers {
+	( $( $int:ty ),* ) => {
+		$(
+			impl Distinct for $int {
+				fn distinct(i: u32) -> Self {
+					<$int>::MAX.saturating_sub(i.try_into().unwrap_or(<$int>::MAX))
+				}
+			}
+		)*
+	};
+}
+
+impl_distinct_for_integers!(u8, u16, u32, u64, u128);
+
+impl<const N: usize> Distinct for [u8; N] {
+	fn distinct(i: u32) -> Self {
+		let mut value = [u8::MAX; N];
+		let index = i.to_le_bytes();
+		let len = N.min(index.len());
+		value[..len].copy_from_slice(&index[..len]);
+		value
+	}
+}
+
+/// A vector of `S` worst case values of `T`.
+fn full_vec<T: WorstCase, S: Get<u32>>() -> Vec<T> {
+	(0..S::get()).map(|_| T::worst_case()).collect()
+}
+
+/// A bounded vector at capacity, filled with clones of `item`, e.g. for element types without
+/// a [`WorstCase`].
+pub fn full_bounded_vec<T: Clone, S: Get<u32>>(item: T) -> BoundedVec<T, S> {
+	BoundedVec::truncate_from(vec![item; S::get() as usize])
+}
+
+/// A storage map which can be filled with worst case values, up to the number of values it can
+/// hold.
+///
+/// Backs the `worst_case_storage` option of `#[benchmark]`.
+pub trait FillToBounds {
+	/// Insert [`WorstCase`] values into the map until it holds its `max_values`, but at most
+	/// `cap` of them.
+	///
+	/// The keys are decoded from hashes of their index, so that they spread over the map like
+	/// real keys would. Indices which do not decode to a key of the map are skipped, and small
+	/// key types may collide, so the map may end up with fewer values.
+	fn fill_to_bounds(cap: u32);
+}
+
+/// The number of values to fill a map with `MaxValues` with, at most `cap`.
+fn fill_count<MaxValues: Get<Option<u32>>>(cap: u32) -> u32 {
+	MaxValues::get().map_or(cap, |max_values| max_values.min(cap))
+}
+
+/// The `part` of the `index`th key to fill the map with `Prefix` with.
+fn fill_key<Prefix: StorageInstance, Key: Decode>(index: u32, part: u8) -> Option<Key> {
+	let entropy = (Prefix::pallet_prefix(), Prefix::STORAGE_PREFIX, index, part)
+		.using_encoded(blake2_256);
+	Key::decode(&mut TrailingZeroInput::new(entropy.as_ref())).ok()
+}
+
+impl<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues> FillToBounds
+	for StorageMap<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec + WorstCase,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn fill_to_bounds(cap: u32) {
+		for index in 0..fill_count::<MaxValues>(cap) {
+			if let Some(key) = fill_key::<Prefix, Key>(index, 0) {
+				Self::insert(key, Value::worst_case());
+			}
+		}
+	}
+}
+
+impl<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues> FillToBounds
+	for CountedStorageMap<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: CountedStorageMapInstance,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec + WorstCase,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn fill_to_bounds(cap: u32) {
+		for index in 0..fill_count::<MaxValues>(cap) {
+			if let Some(key) = fill_key::<Prefix, Key>(index, 0) {
+				Self::insert(key, Value::worst_case());
+			}
+		}
+	}
+}
+
+impl<Prefix, Hasher1, Key1, Hasher2, Key2, Value, QueryKind, OnEmpty, MaxValues> FillToBounds
+	for StorageDoubleMap<Prefix, Hasher1, Key1, Hasher2, Key2, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	Hasher1: StorageHasher,
+	Hasher2: StorageHasher,
+	Key1: FullCodec,
+	Key2: FullCodec,
+	Value: FullCodec + WorstCase,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn fill_to_bounds(cap: u32) {
+		for index in 0..fill_count::<MaxValues>(cap) {
+			let key1 = fill_key::<Prefix, Key1>(index, 0);
+			let key2 = fill_key::<Prefix, Key2>(index, 1);
+			if let (Some(key1), Some(key2)) = (key1, key2) {
+				Self::insert(key1, key2, Value::worst_case());
+			}
+		}
+	}
+}
+
+/// Panics if the [`WorstCase`] of `T` encodes to less than [`MaxEncodedLen::max_encoded_len`].
+///
+/// Meant to be called from the tests of a pallet, to catch worst cases that fall behind their
+/// type.
+pub fn assert_worst_case<T: WorstCase + Encode + MaxEncodedLen>() {
+	let (encoded_len, max_encoded_len) = (T::worst_case().encoded_size(), T::max_encoded_len());
+	assert!(
+		encoded_len >= max_encoded_len,
+		"The worst case of {} encodes to {} bytes, less than its maximum of {} bytes",
+		sp_std::any::type_name::<T>(),
+		encoded_len,
+		max_encoded_len,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::traits::ConstU32;
+
+	#[test]
+	fn worst_cases_are_at_capacity() {
+		assert_eq!(<Option<u32>>::worst_case(), Some(u32::MAX));
+		assert_eq!(<[u8; 4]>::worst_case(), [u8::MAX; 4]);
+		assert_eq!(
+			<BoundedVec<(u8, bool), ConstU32<3>>>::worst_case().into_inner(),
+			vec![(u8::MAX, true); 3]
+		);
+		let set = <BoundedBTreeSet<u64, ConstU32<3>>>::worst_case();
+		let set = set.into_iter().collect::<Vec<_>>();
+		assert_eq!(set, vec![u64::MAX - 2, u64::MAX - 1, u64::MAX]);
+		assert_eq!(full_bounded_vec::<_, ConstU32<2>>(7u8).into_inner(), vec![7, 7]);
+	}
+
+	#[test]
+	fn worst_cases_encode_at_max_len() {
+		assert_worst_case::<(u32, Option<[u8; 20]>)>();
+		assert_worst_case::<BoundedVec<BoundedVec<u8, ConstU32<32>>, ConstU32<100>>>();
+		assert_worst_case::<BoundedBTreeSet<[u8; 32], ConstU32<1000>>>();
+	}
+
+	#[test]
+	#[should_panic(expected = "encodes to 1 bytes, less than its maximum of 5 bytes")]
+	fn assert_worst_case_catches_short_encodings() {
+		struct Short(Option<u32>);
+		impl Encode for Short {
+			fn encode_to<O: codec::Output + ?Sized>(&self, dest: &mut O) {
+				self.0.encode_to(dest)
+			}
+		}
+		impl MaxEncodedLen for Short {
+			fn max_encoded_len() -> usize {
+				<Option<u32>>::max_encoded_len()
+			}
+		}
+		impl WorstCase for Short {
+			fn worst_case() -> Self {
+				Short(None)
+			}
+		}
+		assert_worst_case::<Short>();
+	}
+}
@@ -0,0 +1,181 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline simulation of the price adapters of `pallet-broker`.
+//!
+//! Replays a history of Bulk Coretime sales against each candidate [`AdaptPrice`]
+//! implementation, starting from the price of the first sale, and prints the price each adapter
+//! would have set for every subsequent sale next to the historical one.
+//!
+//! The history is a CSV file with the header
+//! `price,sellout_price,ideal_cores_sold,cores_offered,cores_sold`, one sale per line and in
+//! chronological order. An empty `sellout_price` means that no core was sold at or beyond the
+//! ideal number of cores.
+//!
+//! Since the simulated price differs from the historical one, the sell-out price of each sale is
+//! taken relative to its regular price and applied to the simulated price. The number of cores
+//! sold is taken as is; the simulation does not model the demand response to a different price.
+
+use clap::{Parser, ValueEnum};
+use pallet_broker::{AdaptPrice, CoreIndex, Linear, SalePerformance};
+use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+use std::{fmt, path::PathBuf};
+
+/// The balance type of the simulated sales.
+type Balance = u128;
+
+/// The price adapters which may be simulated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum Adapter {
+	/// Keep the price of the previous sale (`()`).
+	Fixed,
+	/// The `Linear` adapter.
+	Linear,
+}
+
+impl Adapter {
+	fn adapt_price(self, performance: SalePerformance<Balance>) -> Balance {
+		match self {
+			Adapter::Fixed => <() as AdaptPrice<Balance>>::adapt_price(performance),
+			Adapter::Linear => <Linear as AdaptPrice<Balance>>::adapt_price(performance),
+		}
+	}
+}
+
+impl fmt::Display for Adapter {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Adapter::Fixed => write!(f, "fixed"),
+			Adapter::Linear => write!(f, "linear"),
+		}
+	}
+}
+
+/// Compare the price adapters of `pallet-broker` on historical sales data.
+#[derive(Debug, Parser)]
+#[command(name = "broker-price-simulator")]
+struct Cli {
+	/// Path to the CSV file of historical sales.
+	#[arg(value_name = "PATH")]
+	history: PathBuf,
+
+	/// The adapters to simulate. Defaults to all of them.
+	#[arg(long, value_enum, value_delimiter = ',')]
+	adapters: Vec<Adapter>,
+}
+
+/// Parse the CSV history of sales.
+fn parse_history(content: &str) -> Result<Vec<SalePerformance<Balance>>, String> {
+	const HEADER: &str = "price,sellout_price,ideal_cores_sold,cores_offered,cores_sold";
+	let mut lines = content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+	if lines.next().map(|(_, header)| header.trim()) != Some(HEADER) {
+		return Err("missing or unexpected CSV header".into())
+	}
+	lines
+		.map(|(index, line)| {
+			let err = |field: &str| format!("line {}: invalid {}", index + 1, field);
+			let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+			let &[price, sellout, ideal, offered, sold] = &fields[..] else {
+				return Err(format!("line {}: expected 5 fields", index + 1))
+			};
+			let cores = |field: &str, name| field.parse::<CoreIndex>().map_err(|_| err(name));
+			Ok(SalePerformance {
+				price: price.parse().map_err(|_| err("price"))?,
+				sellout_price: match sellout {
+					"" => None,
+					p => Some(p.parse().map_err(|_| err("sellout_price"))?),
+				},
+				ideal_cores_sold: cores(ideal, "ideal_cores_sold")?,
+				cores_offered: cores(offered, "cores_offered")?,
+				cores_sold: cores(sold, "cores_sold")?,
+			})
+		})
+		.collect()
+}
+
+/// Replay `history` with `adapter`, returning the simulated regular price of each sale.
+fn simulate(adapter: Adapter, history: &[SalePerformance<Balance>]) -> Vec<Balance> {
+	let Some(first) = history.first() else { return Vec::new() };
+	let mut prices = vec![first.price];
+	for sale in &history[..history.len() - 1] {
+		let price = *prices.last().expect("prices is never empty; qed");
+		let sellout_price = sale.sellout_price.map(|sellout_price| {
+			multiply_by_rational_with_rounding(sellout_price, price, sale.price, Rounding::Down)
+				.unwrap_or(sellout_price)
+		});
+		prices.push(adapter.adapt_price(SalePerformance { price, sellout_price, ..*sale }));
+	}
+	prices
+}
+
+fn main() -> Result<(), String> {
+	let cli = Cli::parse();
+	let content = std::fs::read_to_string(&cli.history)
+		.map_err(|e| format!("failed to read {}: {}", cli.history.display(), e))?;
+	let history = parse_history(&content)?;
+	let adapters = match cli.adapters.is_empty() {
+		true => vec![Adapter::Fixed, Adapter::Linear],
+		false => cli.adapters,
+	};
+	let simulations: Vec<_> = adapters.iter().map(|a| simulate(*a, &history)).collect();
+
+	print!("{:>6} {:>8} {:>24}", "sale", "sold", "historical");
+	adapters.iter().for_each(|adapter| print!(" {:>24}", adapter.to_string()));
+	println!();
+	for (index, sale) in history.iter().enumerate() {
+		let sold = format!("{}/{}", sale.cores_sold, sale.ideal_cores_sold);
+		print!("{:>6} {:>8} {:>24}", index, sold, sale.price);
+		simulations.iter().for_each(|prices| print!(" {:>24}", prices[index]));
+		println!();
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const HISTORY: &str = "price,sellout_price,ideal_cores_sold,cores_offered,cores_sold
+		100,,8,10,4
+		50,55,8,10,10
+		69,,8,10,8";
+
+	#[test]
+	fn parse_history_works() {
+		let history = parse_history(HISTORY).unwrap();
+		assert_eq!(history.len(), 3);
+		assert_eq!(
+			history[1],
+			SalePerformance {
+				price: 50,
+				sellout_price: Some(55),
+				ideal_cores_sold: 8,
+				cores_offered: 10,
+				cores_sold: 10,
+			}
+		);
+		assert!(parse_history("price,cores_sold\n1,2").is_err());
+		assert!(parse_history(&format!("{}\n1,2,3", HISTORY)).is_err());
+	}
+
+	#[test]
+	fn simulate_works() {
+		let history = parse_history(HISTORY).unwrap();
+		assert_eq!(simulate(Adapter::Fixed, &history), vec![100, 100, 110]);
+		assert_eq!(simulate(Adapter::Linear, &history), vec![100, 50, 110]);
+	}
+}
@@ -17,41 +17,90 @@
 
 #![deny(missing_docs)]
 
-use crate::CoreIndex;
-use sp_arithmetic::{traits::One, FixedU64};
+use crate::{CoreIndex, SaleInfoRecord};
+use sp_arithmetic::{traits::One, FixedPointNumber, FixedPointOperand, FixedU64};
+
+/// The performance of a Bulk Coretime Sale, from which the price of the next sale is determined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SalePerformance<Balance> {
+	/// The price of Bulk Coretime after the Leadin Period.
+	pub price: Balance,
+	/// The latest price at which Bulk Coretime was purchased until surpassing the ideal number of
+	/// cores were sold.
+	pub sellout_price: Option<Balance>,
+	/// The number of cores which were to be sold, ideally.
+	pub ideal_cores_sold: CoreIndex,
+	/// Number of cores which were offered for sale.
+	pub cores_offered: CoreIndex,
+	/// Number of cores which were sold.
+	pub cores_sold: CoreIndex,
+}
+
+impl<Balance: Copy> SalePerformance<Balance> {
+	/// The performance of the given `sale`.
+	pub fn from_sale<BlockNumber>(sale: &SaleInfoRecord<Balance, BlockNumber>) -> Self {
+		Self {
+			price: sale.price,
+			sellout_price: sale.sellout_price,
+			ideal_cores_sold: sale.ideal_cores_sold,
+			cores_offered: sale.cores_offered,
+			cores_sold: sale.cores_sold,
+		}
+	}
+
+	/// The price which the market paid for Bulk Coretime, and which should be corrected based on
+	/// the number of cores sold. `None` if the price should not be altered.
+	pub fn purchase_price(&self) -> Option<Balance> {
+		if self.cores_offered == 0 {
+			// No cores offered for sale - no purchase price.
+			None
+		} else if self.cores_sold >= self.ideal_cores_sold {
+			// Sold more than the ideal amount. We should look for the last purchase price before
+			// the sell-out. If there was no purchase at all, then we avoid having a price here so
+			// that we make no alterations to it (since otherwise we would increase it).
+			self.sellout_price
+		} else {
+			// Sold less than the ideal - we fall back to the regular price.
+			Some(self.price)
+		}
+	}
+}
 
 /// Type for determining how to set price.
-pub trait AdaptPrice {
+pub trait AdaptPrice<Balance> {
 	/// Return the factor by which the regular price must be multiplied during the leadin period.
 	///
 	/// - `when`: The amount through the leadin period; between zero and one.
 	fn leadin_factor_at(when: FixedU64) -> FixedU64;
-	/// Return the correction factor by which the regular price must be multiplied based on market
-	/// performance.
+	/// Return the regular price of the next sale based on the market performance of the previous
+	/// sale.
 	///
-	/// - `sold`: The number of cores sold.
-	/// - `target`: The target number of cores to be sold (must be larger than zero).
-	/// - `limit`: The maximum number of cores to be sold.
-	fn adapt_price(sold: CoreIndex, target: CoreIndex, limit: CoreIndex) -> FixedU64;
+	/// - `performance`: The performance of the previous sale.
+	fn adapt_price(performance: SalePerformance<Balance>) -> Balance;
 }
 
-impl AdaptPrice for () {
+/// Implementation of `AdaptPrice` which keeps the regular price at the purchase price of the
+/// previous sale, without any leadin.
+impl<Balance: Copy> AdaptPrice<Balance> for () {
 	fn leadin_factor_at(_: FixedU64) -> FixedU64 {
 		FixedU64::one()
 	}
-	fn adapt_price(_: CoreIndex, _: CoreIndex, _: CoreIndex) -> FixedU64 {
-		FixedU64::one()
+	fn adapt_price(performance: SalePerformance<Balance>) -> Balance {
+		performance.purchase_price().unwrap_or(performance.price)
 	}
 }
 
 /// Simple implementation of `AdaptPrice` giving a monotonic leadin and a linear price change based
 /// on cores sold.
 pub struct Linear;
-impl AdaptPrice for Linear {
-	fn leadin_factor_at(when: FixedU64) -> FixedU64 {
-		FixedU64::from(2) - when
-	}
-	fn adapt_price(sold: CoreIndex, target: CoreIndex, limit: CoreIndex) -> FixedU64 {
+impl Linear {
+	/// Return the correction factor by which the purchase price must be multiplied based on market
+	/// performance.
+	///
+	/// - `sold`: The number of cores sold.
+	/// - `target`: The target number of cores to be sold (must be larger than zero).
+	/// - `limit`: The maximum number of cores to be sold.
+	pub fn correction_factor(sold: CoreIndex, target: CoreIndex, limit: CoreIndex) -> FixedU64 {
 		if sold <= target {
 			FixedU64::from_rational(sold.into(), target.into())
 		} else {
@@ -60,6 +109,19 @@ impl AdaptPrice for Linear {
 		}
 	}
 }
+impl<Balance: FixedPointOperand> AdaptPrice<Balance> for Linear {
+	fn leadin_factor_at(when: FixedU64) -> FixedU64 {
+		FixedU64::from(2) - when
+	}
+	fn adapt_price(performance: SalePerformance<Balance>) -> Balance {
+		let Some(purchase_price) = performance.purchase_price() else {
+			return performance.price
+		};
+		let SalePerformance { cores_sold, ideal_cores_sold, cores_offered, .. } = performance;
+		Self::correction_factor(cores_sold.min(cores_offered), ideal_cores_sold, cores_offered)
+			.saturating_mul_int(purchase_price)
+	}
+}
 
 #[cfg(test)]
 mod tests {
@@ -70,7 +132,7 @@ mod tests {
 		for limit in 0..10 {
 			for target in 1..10 {
 				for sold in 0..=limit {
-					let price = Linear::adapt_price(sold, target, limit);
+					let price = Linear::correction_factor(sold, target, limit);
 
 					if sold > target {
 						assert!(price > FixedU64::one());
@@ -81,4 +143,23 @@ mod tests {
 			}
 		}
 	}
+
+	#[test]
+	fn adapt_price_uses_purchase_price() {
+		let performance = |sellout_price, cores_sold| SalePerformance {
+			price: 100u64,
+			sellout_price,
+			ideal_cores_sold: 4,
+			cores_offered: 8,
+			cores_sold,
+		};
+		// Sold less than the ideal: the regular price is corrected.
+		assert_eq!(<() as AdaptPrice<u64>>::adapt_price(performance(None, 2)), 100);
+		assert_eq!(<Linear as AdaptPrice<u64>>::adapt_price(performance(None, 2)), 50);
+		// Sold out: the sellout price is corrected.
+		assert_eq!(<() as AdaptPrice<u64>>::adapt_price(performance(Some(120), 6)), 120);
+		assert_eq!(<Linear as AdaptPrice<u64>>::adapt_price(performance(Some(120), 6)), 180);
+		// Sold out without a sellout price: the price is left unaltered.
+		assert_eq!(<Linear as AdaptPrice<u64>>::adapt_price(performance(None, 8)), 100);
+	}
 }
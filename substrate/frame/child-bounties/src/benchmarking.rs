@@ -312,5 +312,25 @@ benchmarks! {
 		}.into())
 	}
 
+	claim_curator_fee {
+		let period = T::CuratorFeeAccrualPeriod::get();
+		if period.is_zero() {
+			return Err(BenchmarkError::Skip)
+		}
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + period);
+	}: _(RawOrigin::Signed(bounty_setup.child_curator.clone()), bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id)
+	verify {
+		assert_last_event::<T>(Event::CuratorFeePaid {
+			index: bounty_setup.bounty_id,
+			child_index: bounty_setup.child_bounty_id,
+			curator: bounty_setup.child_curator,
+			amount: bounty_setup.child_bounty_fee,
+		}.into())
+	}
+
 	impl_benchmark_test_suite!(ChildBounties, crate::tests::new_test_ext(), crate::tests::Test)
 }
@@ -40,6 +40,7 @@
 //!   curator deposit.
 //! - `award_child_bounty` - Close and pay out the specified amount for the completed work.
 //! - `claim_child_bounty` - Claim a specific child bounty amount from the payout address.
+//! - `claim_curator_fee` - Claim the part of the child bounty curator fee which has accrued so far.
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
@@ -66,7 +67,7 @@ use frame_support::traits::{
 
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, CheckedSub, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Perbill, RuntimeDebug,
 };
 
 use frame_support::pallet_prelude::*;
@@ -124,6 +125,15 @@ pub enum ChildBountyStatus<AccountId, BlockNumber> {
 	},
 }
 
+/// The streaming of the fee of a child-bounty curator.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CuratorFeeStream<Balance, BlockNumber> {
+	/// The block from which the fee accrues, i.e. when the curator accepted their role.
+	pub since: BlockNumber,
+	/// The part of the fee which has already been paid to the curator.
+	pub paid: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -144,6 +154,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// The number of blocks over which the fee of a child-bounty curator accrues, starting
+		/// when they accept their role. The accrued part of the fee can be claimed at any time,
+		/// the rest of it is paid when the child-bounty is claimed.
+		///
+		/// If zero, the whole fee is paid when the child-bounty is claimed.
+		#[pallet::constant]
+		type CuratorFeeAccrualPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -159,6 +177,8 @@ pub mod pallet {
 		InsufficientBountyBalance,
 		/// Number of child bounties exceeds limit `MaxActiveChildBountyCount`.
 		TooManyChildBounties,
+		/// No curator fee has accrued since it was last claimed.
+		NothingAccrued,
 	}
 
 	#[pallet::event]
@@ -177,6 +197,13 @@ pub mod pallet {
 		},
 		/// A child-bounty is cancelled.
 		Canceled { index: BountyIndex, child_index: BountyIndex },
+		/// The accrued part of a child-bounty curator fee is paid to the curator.
+		CuratorFeePaid {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			curator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	/// Number of total child bounties.
@@ -215,6 +242,19 @@ pub mod pallet {
 	pub type ChildrenCuratorFees<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The streaming of the fee of each active child-bounty curator, if the fee accrues over
+	/// `CuratorFeeAccrualPeriod`.
+	#[pallet::storage]
+	#[pallet::getter(fn curator_fee_streams)]
+	pub type CuratorFeeStreams<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		CuratorFeeStream<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add a new child-bounty.
@@ -370,7 +410,8 @@ pub mod pallet {
 		/// A deposit will be reserved from the curator and refund upon
 		/// successful payout or cancellation.
 		///
-		/// Fee for curator is deducted from curator fee of parent bounty.
+		/// Fee for curator is deducted from curator fee of parent bounty. It
+		/// starts to accrue to the curator over `CuratorFeeAccrualPeriod`.
 		///
 		/// Parent bounty must be in active state, for this child-bounty call to
 		/// work.
@@ -416,6 +457,19 @@ pub mod pallet {
 
 						child_bounty.status =
 							ChildBountyStatus::Active { curator: curator.clone() };
+
+						// Start streaming the curator fee.
+						if !T::CuratorFeeAccrualPeriod::get().is_zero() {
+							let stream = CuratorFeeStream {
+								since: frame_system::Pallet::<T>::block_number(),
+								paid: Zero::zero(),
+							};
+							CuratorFeeStreams::<T>::insert(
+								parent_bounty_id,
+								child_bounty_id,
+								stream,
+							);
+						}
 						Ok(())
 					} else {
 						Err(BountiesError::<T>::UnexpectedStatus.into())
@@ -549,7 +603,16 @@ pub mod pallet {
 							// Continue to change child-bounty status below.
 						},
 					};
-					// Move the child-bounty state to Added.
+					// Stop streaming the curator fee, deducting the part already paid from the
+					// fee and from the cumulative sum, and move the child-bounty state to Added.
+					if let Some(stream) =
+						CuratorFeeStreams::<T>::take(parent_bounty_id, child_bounty_id)
+					{
+						child_bounty.fee = child_bounty.fee.saturating_sub(stream.paid);
+						ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
+							*value = value.saturating_sub(stream.paid)
+						});
+					}
 					child_bounty.status = ChildBountyStatus::Added;
 					Ok(())
 				},
@@ -631,8 +694,9 @@ pub mod pallet {
 		/// Call works independent of parent bounty state, No need for parent
 		/// bounty to be in active state.
 		///
-		/// The Beneficiary is paid out with agreed bounty value. Curator fee is
-		/// paid & curator deposit is unreserved.
+		/// The Beneficiary is paid out with agreed bounty value. The part of the
+		/// curator fee which was not yet claimed by the curator is paid &
+		/// curator deposit is unreserved.
 		///
 		/// Child-bounty must be in "PendingPayout" state, for processing the
 		/// call. And instance of child-bounty is removed from the state on
@@ -673,7 +737,10 @@ pub mod pallet {
 						// Make curator fee payment.
 						let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
 						let balance = T::Currency::free_balance(&child_bounty_account);
-						let curator_fee = child_bounty.fee.min(balance);
+						let fee_paid =
+							CuratorFeeStreams::<T>::take(parent_bounty_id, child_bounty_id)
+								.map_or(Zero::zero(), |stream| stream.paid);
+						let curator_fee = child_bounty.fee.saturating_sub(fee_paid).min(balance);
 						let payout = balance.saturating_sub(curator_fee);
 
 						// Unreserve the curator deposit. Should not fail
@@ -770,6 +837,64 @@ pub mod pallet {
 			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
 			Ok(())
 		}
+
+		/// Claim the part of the curator fee of a child-bounty which has accrued
+		/// since it was last claimed.
+		///
+		/// The dispatch origin for this call must be the curator of this
+		/// child-bounty.
+		///
+		/// The curator fee accrues linearly over `CuratorFeeAccrualPeriod`
+		/// blocks, starting with the acceptance of the curator. Whatever is not
+		/// claimed with this call is paid when the child-bounty is claimed.
+		///
+		/// Call works independent of parent bounty state, No need for parent
+		/// bounty to be in active state.
+		///
+		/// Child-bounty must be in "Active" or "PendingPayout" state, for
+		/// processing the call.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_curator_fee())]
+		pub fn claim_curator_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			let child_bounty = ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+			let curator = match child_bounty.status {
+				ChildBountyStatus::Active { curator } |
+				ChildBountyStatus::PendingPayout { curator, .. } => curator,
+				_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+			};
+			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+
+			let mut stream = CuratorFeeStreams::<T>::get(parent_bounty_id, child_bounty_id)
+				.ok_or(Error::<T>::NothingAccrued)?;
+			let amount = Self::accrued_curator_fee(child_bounty.fee, &stream)
+				.saturating_sub(stream.paid);
+			ensure!(!amount.is_zero(), Error::<T>::NothingAccrued);
+
+			// Should not fail because the curator fee is always less than the child-bounty value.
+			let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+			T::Currency::transfer(&child_bounty_account, &curator, amount, AllowDeath)?;
+
+			stream.paid.saturating_accrue(amount);
+			CuratorFeeStreams::<T>::insert(parent_bounty_id, child_bounty_id, stream);
+
+			Self::deposit_event(Event::<T>::CuratorFeePaid {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+				curator,
+				amount,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -788,6 +913,20 @@ impl<T: Config> Pallet<T> {
 		pallet_bounties::Pallet::<T>::calculate_curator_deposit(bounty_fee)
 	}
 
+	/// The part of the curator `fee` which has accrued with `stream` up until now, including what
+	/// has already been paid.
+	fn accrued_curator_fee(
+		fee: BalanceOf<T>,
+		stream: &CuratorFeeStream<BalanceOf<T>, BlockNumberFor<T>>,
+	) -> BalanceOf<T> {
+		let period = T::CuratorFeeAccrualPeriod::get();
+		if period.is_zero() {
+			return Zero::zero()
+		}
+		let elapsed = frame_system::Pallet::<T>::block_number().saturating_sub(stream.since);
+		Perbill::from_rational(elapsed.min(period), period) * fee
+	}
+
 	/// The account ID of a child-bounty account.
 	pub fn child_bounty_account_id(id: BountyIndex) -> T::AccountId {
 		// This function is taken from the parent (bounties) pallet, but the
@@ -857,10 +996,13 @@ impl<T: Config> Pallet<T> {
 					},
 				}
 
-				// Revert the curator fee back to parent bounty curator &
-				// reduce the active child-bounty count.
+				// Revert the part of the curator fee which was not yet paid
+				// back to parent bounty curator & reduce the active
+				// child-bounty count.
+				let fee_paid = CuratorFeeStreams::<T>::take(parent_bounty_id, child_bounty_id)
+					.map_or(Zero::zero(), |stream| stream.paid);
 				ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
-					*value = value.saturating_sub(child_bounty.fee)
+					*value = value.saturating_sub(child_bounty.fee.saturating_sub(fee_paid))
 				});
 				<ParentChildBounties<T>>::mutate(parent_bounty_id, |count| {
 					*count = count.saturating_sub(1)
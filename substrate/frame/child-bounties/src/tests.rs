@@ -164,6 +164,7 @@ impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type CuratorFeeAccrualPeriod = ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -504,6 +505,188 @@ fn award_claim_child_bounty() {
 	});
 }
 
+#[test]
+fn claim_curator_fee_works() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Bounty curator initial balance.
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// Nothing accrues before the curator is accepted.
+		let fee = 8;
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, fee));
+		assert_noop!(
+			ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0),
+			BountiesError::UnexpectedStatus,
+		);
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(
+			ChildBounties::curator_fee_streams(0, 0),
+			Some(CuratorFeeStream { since: 2, paid: 0 })
+		);
+		assert_noop!(
+			ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0),
+			Error::<Test>::NothingAccrued,
+		);
+
+		// Half of the accrual period elapses.
+		System::set_block_number(7);
+		assert_noop!(
+			ChildBounties::claim_curator_fee(RuntimeOrigin::signed(4), 0, 0),
+			BountiesError::RequireCurator,
+		);
+		assert_ok!(ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::CuratorFeePaid { index: 0, child_index: 0, curator: 8, amount: 4 }
+		);
+		let expected_deposit = CuratorDepositMultiplier::get() * fee;
+		assert_eq!(Balances::free_balance(8), 101 - expected_deposit + 4);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 6);
+		assert_noop!(
+			ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0),
+			Error::<Test>::NothingAccrued,
+		);
+
+		// The fee keeps accruing while the payout is pending.
+		assert_ok!(ChildBounties::award_child_bounty(RuntimeOrigin::signed(8), 0, 0, 7));
+		System::set_block_number(9);
+		assert_ok!(ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0));
+		assert!(Balances::free_balance(8) > 101 - expected_deposit + 4);
+
+		// The rest of the fee is paid when the child-bounty is claimed.
+		System::set_block_number(10);
+		assert_ok!(ChildBounties::claim_child_bounty(RuntimeOrigin::signed(7), 0, 0));
+		assert_eq!(Balances::free_balance(8), 101 + fee);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(7), 10 - fee);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+		assert_eq!(ChildBounties::curator_fee_streams(0, 0), None);
+	});
+}
+
+#[test]
+fn close_child_bounty_with_claimed_curator_fee() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Bounty curator initial balance.
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// Unassigning the curator stops the streaming of the fee...
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(ChildBounties::curator_fee_streams(0, 0), None);
+
+		// ...which restarts for the next curator.
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+		System::set_block_number(7);
+		assert_ok!(ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(Balances::free_balance(8), 101 - 3 + 2);
+
+		// Only the part of the fee which was not paid is reverted to the parent bounty curator.
+		assert_eq!(ChildBounties::children_curator_fees(0), 8);
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(4), 0, 0));
+		assert_eq!(ChildBounties::children_curator_fees(0), 6);
+		assert_eq!(ChildBounties::curator_fee_streams(0, 0), None);
+
+		// Ensure child-bounty curator balance is unreserved.
+		assert_eq!(Balances::free_balance(8), 103);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		// The rest of the child-bounty funds are returned to the parent bounty.
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 48);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 0);
+	});
+}
+
+#[test]
+fn unassign_curator_deducts_paid_curator_fee() {
+	new_test_ext().execute_with(|| {
+		// Make the parent bounty.
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		// Bounty curator initial balance.
+		Balances::make_free_balance_be(&4, 101); // Parent-bounty curator.
+		Balances::make_free_balance_be(&8, 101); // Child-bounty curator.
+
+		assert_ok!(Bounties::propose_bounty(RuntimeOrigin::signed(0), 50, b"12345".to_vec()));
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, 4, 6));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(4), 0));
+
+		// Child-bounty.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(4),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(RuntimeOrigin::signed(4), 0, 0, 8, 4));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// Half of the fee is paid before the curator gives up.
+		System::set_block_number(7);
+		assert_ok!(ChildBounties::claim_curator_fee(RuntimeOrigin::signed(8), 0, 0));
+		assert_eq!(ChildBounties::children_curator_fees(0), 4);
+		assert_ok!(ChildBounties::unassign_curator(RuntimeOrigin::signed(8), 0, 0));
+
+		// The paid part is no longer owed on the child-bounty.
+		assert_eq!(ChildBounties::child_bounties(0, 0).unwrap().fee, 2);
+		assert_eq!(ChildBounties::children_curator_fees(0), 2);
+		assert_eq!(ChildBounties::curator_fee_streams(0, 0), None);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0)), 8);
+	});
+}
+
 #[test]
 fn close_child_bounty_added() {
 	new_test_ext().execute_with(|| {
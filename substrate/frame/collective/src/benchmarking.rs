@@ -40,6 +40,13 @@ fn id_to_remark_data(id: u32, length: usize) -> Vec<u8> {
 	id.to_le_bytes().into_iter().cycle().take(length).collect()
 }
 
+// The thresholds of the benchmarks assume that each member casts a single vote.
+fn set_single_votes<T: Config<I>, I: 'static>(members: &[T::AccountId]) {
+	for member in members {
+		T::MemberRank::set_single_vote(member);
+	}
+}
+
 benchmarks_instance_pallet! {
 	set_members {
 		let m in 0 .. T::MaxMembers::get();
@@ -55,6 +62,7 @@ benchmarks_instance_pallet! {
 		}
 		let old_members_count = old_members.len() as u32;
 
+		set_single_votes::<T, I>(&old_members);
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
 			old_members.clone(),
@@ -124,6 +132,7 @@ benchmarks_instance_pallet! {
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
 
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
 
 		let proposal: T::Proposal = SystemCall::<T>::remark { remark: id_to_remark_data(1, b as usize) }.into();
@@ -154,6 +163,7 @@ benchmarks_instance_pallet! {
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
 
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
 
 		let proposal: T::Proposal = SystemCall::<T>::remark { remark: id_to_remark_data(1, b as usize) }.into();
@@ -184,6 +194,7 @@ benchmarks_instance_pallet! {
 		}
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
 
 		let threshold = m;
@@ -229,6 +240,7 @@ benchmarks_instance_pallet! {
 		}
 		let voter: T::AccountId = account::<T::AccountId>("voter", 0, SEED);
 		members.push(voter.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
 
 		// Threshold is 1 less than the number of members so that one person can vote nay
@@ -304,6 +316,7 @@ benchmarks_instance_pallet! {
 		}
 		let voter = account::<T::AccountId>("voter", 0, SEED);
 		members.push(voter.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
 
 		// Threshold is total members so that one nay will disapprove the vote
@@ -381,6 +394,7 @@ benchmarks_instance_pallet! {
 		}
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
 
 		// Threshold is 2 so any two ayes will approve the vote
@@ -462,6 +476,7 @@ benchmarks_instance_pallet! {
 		}
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
 			members.clone(),
@@ -544,6 +559,7 @@ benchmarks_instance_pallet! {
 		}
 		let caller: T::AccountId = whitelisted_caller();
 		members.push(caller.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
 			members.clone(),
@@ -615,6 +631,7 @@ benchmarks_instance_pallet! {
 		}
 		let caller = account::<T::AccountId>("caller", 0, SEED);
 		members.push(caller.clone());
+		set_single_votes::<T, I>(&members);
 		Collective::<T, I>::set_members(
 			SystemOrigin::Root.into(),
 			members.clone(),
@@ -38,6 +38,10 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! By default each member casts a single vote. A `MemberRank` provider can weight the votes of
+//! the members instead, e.g. by their rank in a fellowship with [`RankVoteWeight`]. Thresholds,
+//! tallies and the `Members` origin are then all counted in votes rather than in members.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -46,7 +50,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_io::storage;
 use sp_runtime::{
-	traits::{Dispatchable, Hash},
+	traits::{Dispatchable, Hash, SaturatedConversion, Saturating},
 	DispatchError, RuntimeDebug,
 };
 use sp_std::{marker::PhantomData, prelude::*, result};
@@ -58,7 +62,7 @@ use frame_support::{
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
 	traits::{
 		Backing, ChangeMembers, EnsureOrigin, EnsureOriginWithArg, Get, GetBacking,
-		InitializeMembers, StorageVersion,
+		InitializeMembers, MembershipSnapshot, RankedMembers, SnapshotMembers, StorageVersion,
 	},
 	weights::Weight,
 };
@@ -134,6 +138,44 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
+/// Provides the number of votes cast by each member of a collective.
+pub trait MemberRank<AccountId> {
+	/// The number of votes cast by the member `who`.
+	fn vote_weight(who: &AccountId) -> MemberCount;
+
+	/// Make `who` cast a single vote, as assumed by the benchmarks of the pallet.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_single_vote(_who: &AccountId) {}
+}
+
+/// Each member casts a single vote.
+impl<AccountId> MemberRank<AccountId> for () {
+	fn vote_weight(_who: &AccountId) -> MemberCount {
+		1
+	}
+}
+
+/// Weights the votes of the members by their rank in `Ranks`.
+///
+/// A member of rank `r` casts `r - min_rank + 1` votes, like the `Linear` vote weight of
+/// `pallet-ranked-collective`. Accounts without a rank cast no votes.
+pub struct RankVoteWeight<Ranks>(PhantomData<Ranks>);
+
+impl<Ranks: RankedMembers> MemberRank<Ranks::AccountId> for RankVoteWeight<Ranks> {
+	fn vote_weight(who: &Ranks::AccountId) -> MemberCount {
+		Ranks::rank_of(who).map_or(0, |rank| {
+			rank.saturating_sub(Ranks::min_rank()).saturated_into::<MemberCount>().saturating_add(1)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_single_vote(who: &Ranks::AccountId) {
+		if Ranks::rank_of(who).is_none() {
+			let _ = Ranks::induct(who);
+		}
+	}
+}
+
 /// Origin for the collective module.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(I))]
@@ -218,6 +260,11 @@ pub mod pallet {
 		/// Default vote strategy of this collective.
 		type DefaultVote: DefaultVote;
 
+		/// The number of votes cast by each member, `()` for one vote per member.
+		///
+		/// Thresholds, tallies and the [`RawOrigin::Members`] origin are counted in votes.
+		type MemberRank: MemberRank<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -524,7 +571,8 @@ pub mod pallet {
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
 			if threshold < 2 {
-				let (proposal_len, result) = Self::do_propose_execute(proposal, length_bound)?;
+				let (proposal_len, result) =
+					Self::do_propose_execute(&who, proposal, length_bound)?;
 
 				Ok(get_result_weight(result)
 					.map(|w| {
@@ -673,8 +721,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::members().contains(who)
 	}
 
-	/// Execute immediately when adding a new proposal.
+	/// Execute immediately when adding a new proposal, with the votes of the proposer `who`.
 	pub fn do_propose_execute(
+		who: &T::AccountId,
 		proposal: Box<<T as Config<I>>::Proposal>,
 		length_bound: MemberCount,
 	) -> Result<(u32, DispatchResultWithPostInfo), DispatchError> {
@@ -689,8 +738,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let proposal_hash = T::Hashing::hash_of(&proposal);
 		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
-		let seats = Self::members().len() as MemberCount;
-		let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+		let yes_votes = T::MemberRank::vote_weight(who);
+		let seats = Self::tally(&Self::members());
+		let result = proposal.dispatch(RawOrigin::Members(yes_votes, seats).into());
 		Self::deposit_event(Event::Executed {
 			proposal_hash,
 			result: result.map(|_| ()).map_err(|e| e.error),
@@ -777,8 +827,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 		}
 
-		let yes_votes = voting.ayes.len() as MemberCount;
-		let no_votes = voting.nays.len() as MemberCount;
+		let yes_votes = Self::tally(&voting.ayes);
+		let no_votes = Self::tally(&voting.nays);
 		Self::deposit_event(Event::Voted {
 			account: who,
 			proposal_hash: proposal,
@@ -802,9 +852,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-		let mut no_votes = voting.nays.len() as MemberCount;
-		let mut yes_votes = voting.ayes.len() as MemberCount;
-		let seats = Self::members().len() as MemberCount;
+		let mut no_votes = Self::tally(&voting.nays);
+		let mut yes_votes = Self::tally(&voting.ayes);
+		let members = Self::members();
+		let member_count = members.len() as MemberCount;
+		let seats = Self::tally(&members);
 		let approved = yes_votes >= voting.threshold;
 		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
 		// Allow (dis-)approving the proposal as soon as there are enough votes.
@@ -819,7 +871,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			return Ok((
 				Some(
-					T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_early_approved(len as u32, member_count, proposal_count)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -829,7 +881,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			return Ok((
-				Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+				Some(T::WeightInfo::close_early_disapproved(member_count, proposal_count)),
 				Pays::No,
 			)
 				.into())
@@ -843,7 +895,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// default voting strategy.
 		let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
 
-		let abstentions = seats - (yes_votes + no_votes);
+		let abstentions = seats.saturating_sub(yes_votes.saturating_add(no_votes));
 		match default {
 			true => yes_votes += abstentions,
 			false => no_votes += abstentions,
@@ -861,7 +913,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			Ok((
 				Some(
-					T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_approved(len as u32, member_count, proposal_count)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -870,10 +922,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		} else {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-			Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
+			Ok((Some(T::WeightInfo::close_disapproved(member_count, proposal_count)), Pays::No)
+				.into())
 		}
 	}
 
+	/// The total number of votes cast by `voters`.
+	fn tally(voters: &[T::AccountId]) -> MemberCount {
+		voters
+			.iter()
+			.fold(0, |votes, who| votes.saturating_add(T::MemberRank::vote_weight(who)))
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -1124,6 +1184,13 @@ impl<T: Config<I>, I: 'static> InitializeMembers<T::AccountId> for Pallet<T, I>
 	}
 }
 
+impl<T: Config<I>, I: 'static> SnapshotMembers<T::AccountId, T::Hash> for Pallet<T, I> {
+	fn membership_snapshot() -> MembershipSnapshot<T::AccountId, T::Hash> {
+		let members = Members::<T, I>::get().into_iter().map(|who| (who, None)).collect();
+		MembershipSnapshot::new::<T::Hashing>(members, Prime::<T, I>::get())
+	}
+}
+
 /// Ensure that the origin `o` represents at least `n` members. Returns `Ok` or an `Err`
 /// otherwise.
 pub fn ensure_members<OuterOrigin, AccountId, I>(
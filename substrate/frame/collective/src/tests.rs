@@ -61,6 +61,7 @@ mod mock_democracy {
 			type RuntimeEvent: From<Event<Self>>
 				+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 			type ExternalMajorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+			type ExternalDefaultOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		}
 
 		#[pallet::call]
@@ -70,6 +71,12 @@ mod mock_democracy {
 				Self::deposit_event(Event::<T>::ExternalProposed);
 				Ok(())
 			}
+
+			pub fn external_propose_default(origin: OriginFor<T>) -> DispatchResult {
+				T::ExternalDefaultOrigin::ensure_origin(origin)?;
+				Self::deposit_event(Event::<T>::ExternalProposed);
+				Ok(())
+			}
 		}
 
 		#[pallet::event]
@@ -125,6 +132,7 @@ impl Config<Instance1> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -137,6 +145,7 @@ impl Config<Instance2> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -144,6 +153,14 @@ impl Config<Instance2> for Test {
 impl mock_democracy::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ExternalMajorityOrigin = EnsureProportionAtLeast<u64, Instance1, 3, 4>;
+	type ExternalDefaultOrigin = EnsureMembers<u64, (), 3>;
+}
+/// Members cast as many votes as their account id.
+pub struct AccountIdVotes;
+impl MemberRank<AccountId> for AccountIdVotes {
+	fn vote_weight(who: &AccountId) -> MemberCount {
+		*who as MemberCount
+	}
 }
 impl Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
@@ -153,6 +170,7 @@ impl Config for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type MemberRank = AccountIdVotes;
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -1524,3 +1542,188 @@ fn migration_v4() {
 		crate::migrations::v4::post_migrate::<DefaultCollective, _>(old_pallet);
 	});
 }
+
+#[test]
+fn weighted_votes_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(DefaultCollective::set_members(
+			RuntimeOrigin::root(),
+			vec![1, 2, 3, 4],
+			None,
+			0
+		));
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		// The members cast 10 votes in total.
+		assert_ok!(DefaultCollective::propose(
+			RuntimeOrigin::signed(4),
+			6,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(DefaultCollective::vote(RuntimeOrigin::signed(4), hash, 0, true));
+		assert_ok!(DefaultCollective::vote(RuntimeOrigin::signed(1), hash, 0, false));
+		assert_noop!(
+			DefaultCollective::close(
+				RuntimeOrigin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			),
+			Error::<Test>::TooEarly
+		);
+		assert_ok!(DefaultCollective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+		assert_ok!(DefaultCollective::close(
+			RuntimeOrigin::signed(4),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.filter_map(|e| match e.event {
+					RuntimeEvent::DefaultCollective(e @ CollectiveEvent::Voted { .. }) |
+					RuntimeEvent::DefaultCollective(e @ CollectiveEvent::Closed { .. }) => Some(e),
+					_ => None,
+				})
+				.collect::<Vec<_>>(),
+			vec![
+				CollectiveEvent::Voted {
+					account: 4,
+					proposal_hash: hash,
+					voted: true,
+					yes: 4,
+					no: 0
+				},
+				CollectiveEvent::Voted {
+					account: 1,
+					proposal_hash: hash,
+					voted: false,
+					yes: 4,
+					no: 1
+				},
+				CollectiveEvent::Voted {
+					account: 2,
+					proposal_hash: hash,
+					voted: true,
+					yes: 6,
+					no: 1
+				},
+				CollectiveEvent::Closed { proposal_hash: hash, yes: 6, no: 1 },
+			]
+		);
+		System::assert_has_event(CollectiveEvent::<Test>::Approved { proposal_hash: hash }.into());
+	});
+}
+
+#[test]
+fn weighted_votes_execute_directly() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(DefaultCollective::set_members(
+			RuntimeOrigin::root(),
+			vec![1, 2, 3, 4],
+			None,
+			0
+		));
+		let proposal = RuntimeCall::Democracy(mock_democracy::Call::external_propose_default {});
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		// Member 1 casts a single vote, which is not enough.
+		assert_ok!(DefaultCollective::propose(
+			RuntimeOrigin::signed(1),
+			1,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		System::assert_last_event(
+			CollectiveEvent::<Test>::Executed {
+				proposal_hash: hash,
+				result: Err(DispatchError::BadOrigin),
+			}
+			.into(),
+		);
+
+		// Member 3 casts 3 votes.
+		assert_ok!(DefaultCollective::propose(
+			RuntimeOrigin::signed(3),
+			1,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		System::assert_last_event(
+			CollectiveEvent::<Test>::Executed { proposal_hash: hash, result: Ok(()) }.into(),
+		);
+	});
+}
+
+#[test]
+fn weighted_votes_disapprove_early() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(DefaultCollective::set_members(
+			RuntimeOrigin::root(),
+			vec![1, 2, 3, 4],
+			None,
+			0
+		));
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		assert_ok!(DefaultCollective::propose(
+			RuntimeOrigin::signed(1),
+			7,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		// Without the 4 votes of member 4 only 6 votes are left.
+		assert_ok!(DefaultCollective::vote(RuntimeOrigin::signed(4), hash, 0, false));
+		assert_ok!(DefaultCollective::close(
+			RuntimeOrigin::signed(1),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		System::assert_last_event(
+			CollectiveEvent::<Test>::Disapproved { proposal_hash: hash }.into(),
+		);
+	});
+}
+
+#[test]
+fn rank_vote_weight_works() {
+	struct Ranks;
+	impl frame_support::traits::RankedMembers for Ranks {
+		type AccountId = AccountId;
+		type Rank = u16;
+
+		fn min_rank() -> u16 {
+			1
+		}
+		fn rank_of(who: &AccountId) -> Option<u16> {
+			(*who <= 3).then(|| *who as u16)
+		}
+		fn induct(_: &AccountId) -> DispatchResult {
+			unimplemented!()
+		}
+		fn promote(_: &AccountId) -> DispatchResult {
+			unimplemented!()
+		}
+		fn demote(_: &AccountId) -> DispatchResult {
+			unimplemented!()
+		}
+	}
+
+	assert_eq!(RankVoteWeight::<Ranks>::vote_weight(&1), 1);
+	assert_eq!(RankVoteWeight::<Ranks>::vote_weight(&3), 3);
+	assert_eq!(RankVoteWeight::<Ranks>::vote_weight(&4), 0);
+}
@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since the votes of the members were weighted by `MemberRank`. The
+//! `RankedCollective` member reads of `propose_execute`, `vote` and the `close_*` functions are
+//! PLACEHOLDERS, charged for every member. Re-run the benchmarks, which now run against the
+//! rank-weighted `TechnicalCommittee`, to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -116,20 +121,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:1 w:0)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[1, 100]`.
 	fn propose_execute(b: u32, m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `202 + m * (32 ±0)`
-		//  Estimated: `3668 + m * (32 ±0)`
-		// Minimum execution time: 18_642_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(17_708_609, 3668)
-			// Standard Error: 58
 			.saturating_add(Weight::from_parts(2_285, 0).saturating_mul(b.into()))
-			// Standard Error: 598
 			.saturating_add(Weight::from_parts(30_454, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Members (r:1 w:0)
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
@@ -165,18 +169,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[5, 100]`.
 	fn vote(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `941 + m * (64 ±0)`
-		//  Estimated: `4405 + m * (64 ±0)`
-		// Minimum execution time: 26_055_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(27_251_907, 4405)
-			// Standard Error: 1_008
 			.saturating_add(Weight::from_parts(65_947, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -186,22 +190,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `530 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `3975 + m * (65 ±0) + p * (36 ±0)`
-		// Minimum execution time: 28_363_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(28_733_464, 3975)
-			// Standard Error: 1_275
 			.saturating_add(Weight::from_parts(43_236, 0).saturating_mul(m.into()))
-			// Standard Error: 1_244
 			.saturating_add(Weight::from_parts(180_187, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -211,26 +214,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `832 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `4149 + b * (1 ±0) + m * (66 ±0) + p * (40 ±0)`
-		// Minimum execution time: 40_391_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(42_695_215, 4149)
-			// Standard Error: 167
 			.saturating_add(Weight::from_parts(3_622, 0).saturating_mul(b.into()))
-			// Standard Error: 1_772
 			.saturating_add(Weight::from_parts(33_830, 0).saturating_mul(m.into()))
-			// Standard Error: 1_727
 			.saturating_add(Weight::from_parts(205_374, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -242,22 +243,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `550 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `3995 + m * (65 ±0) + p * (36 ±0)`
-		// Minimum execution time: 31_368_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(32_141_835, 3995)
-			// Standard Error: 1_451
 			.saturating_add(Weight::from_parts(36_372, 0).saturating_mul(m.into()))
-			// Standard Error: 1_415
 			.saturating_add(Weight::from_parts(210_635, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -269,26 +269,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `852 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `4169 + b * (1 ±0) + m * (66 ±0) + p * (40 ±0)`
-		// Minimum execution time: 43_271_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(45_495_648, 4169)
-			// Standard Error: 174
 			.saturating_add(Weight::from_parts(3_034, 0).saturating_mul(b.into()))
-			// Standard Error: 1_840
 			.saturating_add(Weight::from_parts(42_209, 0).saturating_mul(m.into()))
-			// Standard Error: 1_793
 			.saturating_add(Weight::from_parts(207_525, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
@@ -362,20 +360,19 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:1 w:0)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[1, 100]`.
 	fn propose_execute(b: u32, m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `202 + m * (32 ±0)`
-		//  Estimated: `3668 + m * (32 ±0)`
-		// Minimum execution time: 18_642_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(17_708_609, 3668)
-			// Standard Error: 58
 			.saturating_add(Weight::from_parts(2_285, 0).saturating_mul(b.into()))
-			// Standard Error: 598
 			.saturating_add(Weight::from_parts(30_454, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Members (r:1 w:0)
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
@@ -411,18 +408,18 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[5, 100]`.
 	fn vote(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `941 + m * (64 ±0)`
-		//  Estimated: `4405 + m * (64 ±0)`
-		// Minimum execution time: 26_055_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(27_251_907, 4405)
-			// Standard Error: 1_008
 			.saturating_add(Weight::from_parts(65_947, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -432,22 +429,21 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `530 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `3975 + m * (65 ±0) + p * (36 ±0)`
-		// Minimum execution time: 28_363_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(28_733_464, 3975)
-			// Standard Error: 1_275
 			.saturating_add(Weight::from_parts(43_236, 0).saturating_mul(m.into()))
-			// Standard Error: 1_244
 			.saturating_add(Weight::from_parts(180_187, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -457,26 +453,24 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `832 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `4149 + b * (1 ±0) + m * (66 ±0) + p * (40 ±0)`
-		// Minimum execution time: 40_391_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(42_695_215, 4149)
-			// Standard Error: 167
 			.saturating_add(Weight::from_parts(3_622, 0).saturating_mul(b.into()))
-			// Standard Error: 1_772
 			.saturating_add(Weight::from_parts(33_830, 0).saturating_mul(m.into()))
-			// Standard Error: 1_727
 			.saturating_add(Weight::from_parts(205_374, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -488,22 +482,21 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `550 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `3995 + m * (65 ±0) + p * (36 ±0)`
-		// Minimum execution time: 31_368_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(32_141_835, 3995)
-			// Standard Error: 1_451
 			.saturating_add(Weight::from_parts(36_372, 0).saturating_mul(m.into()))
-			// Standard Error: 1_415
 			.saturating_add(Weight::from_parts(210_635, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -515,26 +508,24 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: RankedCollective Members (r:100 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `852 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `4169 + b * (1 ±0) + m * (66 ±0) + p * (40 ±0)`
-		// Minimum execution time: 43_271_000 picoseconds.
+		// PLACEHOLDER: the member rank reads were added by hand.
 		Weight::from_parts(45_495_648, 4169)
-			// Standard Error: 174
 			.saturating_add(Weight::from_parts(3_034, 0).saturating_mul(b.into()))
-			// Standard Error: 1_840
 			.saturating_add(Weight::from_parts(42_209, 0).saturating_mul(m.into()))
-			// Standard Error: 1_793
 			.saturating_add(Weight::from_parts(207_525, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
 	}
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
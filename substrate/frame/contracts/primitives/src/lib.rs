@@ -98,6 +98,44 @@ pub type CodeUploadResult<CodeHash, Balance> =
 /// Result type of a `get_storage` call.
 pub type GetStorageResult = Result<Option<Vec<u8>>, ContractAccessError>;
 
+/// Result type of a `storage_info` call.
+pub type ContractStorageInfoResult<AccountId, Balance> =
+	Result<ContractStorageInfo<AccountId, Balance>, ContractAccessError>;
+
+/// The storage used by a contract and the deposits held for it, by who paid them.
+///
+/// The deposits of a contract are paid by the origins of the calls which created the storage, and
+/// are refunded to the origins of the calls which remove it. The individual payers are not
+/// recorded, hence the deposits are split by the party they are attributed to.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ContractStorageInfo<AccountId, Balance> {
+	/// The number of items in the storage of the contract.
+	pub storage_items: u32,
+	/// The number of bytes used by the storage of the contract.
+	pub storage_bytes: u32,
+	/// The deposit held on the contract for its storage items and bytes, paid by the origins of
+	/// the calls which created them.
+	pub storage_deposit: Balance,
+	/// The deposit held on the contract for the contract itself, paid by its instantiator.
+	pub base_deposit: Balance,
+	/// The deposit held on the contract for its delegate dependencies, paid by the origins of the
+	/// calls which added them.
+	///
+	/// Refunded to the origin of the call which terminates the contract.
+	pub delegate_dependency_deposit: Balance,
+	/// The account which uploaded the code of the contract.
+	pub code_owner: AccountId,
+	/// The deposit held on [`Self::code_owner`] for the code of the contract.
+	///
+	/// Not refunded when the contract is terminated, but when the code is removed.
+	pub code_deposit: Balance,
+	/// The deposit refunded to the beneficiary if the contract is terminated.
+	///
+	/// This is the storage and base deposit, less the existential deposit which remains on the
+	/// contract account until it is reaped.
+	pub termination_refund: Balance,
+}
+
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub enum ContractAccessError {
@@ -464,6 +464,15 @@ benchmarks! {
 		let instance = Contract::<T>::with_caller(
 			whitelisted_caller(), WasmModule::dummy(), vec![],
 		)?;
+		// The storage deposits of sponsored calls are charged from the budget of the sponsor,
+		// which is released from and put back on hold around the call.
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		T::Currency::set_balance(&sponsor, caller_funding::<T>() + T::DefaultDepositLimit::get());
+		<Contracts<T>>::sponsor_deposits(
+			RawOrigin::Signed(sponsor).into(),
+			instance.addr.clone(),
+			T::DefaultDepositLimit::get(),
+		)?;
 		let value = Pallet::<T>::min_balance();
 		let origin = RawOrigin::Signed(instance.caller.clone());
 		let callee = instance.addr.clone();
@@ -534,6 +543,37 @@ benchmarks! {
 		assert_eq!(instance.info()?.code_hash, hash);
 	}
 
+	#[pov_mode = Measured]
+	sponsor_deposits {
+		let instance = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		T::Currency::set_balance(&sponsor, caller_funding::<T>());
+		let amount = Pallet::<T>::min_balance() * 100u32.into();
+		let origin = RawOrigin::Signed(sponsor.clone());
+		let callee = instance.addr.clone();
+	}: _(origin, callee, amount)
+	verify {
+		assert_eq!(T::Currency::total_balance_on_hold(&sponsor), amount);
+	}
+
+	#[pov_mode = Measured]
+	cancel_deposit_sponsorship {
+		let instance = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		T::Currency::set_balance(&sponsor, caller_funding::<T>());
+		let amount = Pallet::<T>::min_balance() * 100u32.into();
+		let origin = RawOrigin::Signed(sponsor.clone());
+		let callee = instance.addr.clone();
+		<Contracts<T>>::sponsor_deposits(origin.clone().into(), callee.clone(), amount)?;
+	}: _(origin, callee)
+	verify {
+		assert_eq!(T::Currency::total_balance_on_hold(&sponsor), 0u32.into());
+	}
+
 	#[pov_mode = Measured]
 	seal_caller {
 		let r in 0 .. API_BENCHMARK_RUNS;
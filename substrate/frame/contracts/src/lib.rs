@@ -77,6 +77,11 @@
 //!   origin.
 //! * [`Pallet::migrate`] - Runs migration steps of current multi-block migration in priority,
 //!   before [`Hooks::on_idle`][frame_support::traits::Hooks::on_idle] activates.
+//! * [`Pallet::sponsor_deposits`] - Pays the storage deposits of the calls to a contract out of a
+//!   budget held on the origin, which can be topped up over time.
+//! * [`Pallet::cancel_deposit_sponsorship`] - Ends the sponsorship of the storage deposits of a
+//!   contract and releases its unused budget. Only allowed to the sponsor and the owner of the
+//!   code of the contract.
 //!
 //! ## Usage
 //!
@@ -107,7 +112,9 @@ use crate::{
 		AccountIdOf, ErrorOrigin, ExecError, Executable, Ext, Key, MomentOf, Stack as ExecStack,
 	},
 	gas::GasMeter,
-	storage::{meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager},
+	storage::{
+		meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager, DepositSponsorship,
+	},
 	wasm::{CodeInfo, WasmBlob},
 };
 use codec::{Codec, Decode, Encode, HasCompact, MaxEncodedLen};
@@ -130,8 +137,8 @@ use frame_system::{
 };
 use pallet_contracts_primitives::{
 	Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError, ContractExecResult,
-	ContractInstantiateResult, ContractResult, ExecReturnValue, GetStorageResult,
-	InstantiateReturnValue, StorageDeposit,
+	ContractInstantiateResult, ContractResult, ContractStorageInfo, ContractStorageInfoResult,
+	ExecReturnValue, GetStorageResult, InstantiateReturnValue, StorageDeposit,
 };
 use scale_info::TypeInfo;
 use smallvec::Array;
@@ -877,6 +884,90 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Sponsor the storage deposits of the calls to a contract, or top up the budget of an
+		/// existing sponsorship of the origin.
+		///
+		/// The `amount` is held on the origin and added to the budget of the sponsorship. The
+		/// storage deposits of the signed calls to `contract` whose storage deposit limit is
+		/// covered by the budget are charged from and refunded to the sponsor instead of the
+		/// caller. Calls without a limit are sponsored if the budget covers the
+		/// [`Config::DefaultDepositLimit`]. All other calls are paid by the caller.
+		///
+		/// A contract has at most one sponsor. The owner of the code of the contract can remove
+		/// an unwanted sponsor with [`Self::cancel_deposit_sponsorship`].
+		///
+		/// # Note
+		///
+		/// Only the storage deposits of calls are sponsored. The existential deposits of any
+		/// contracts instantiated within such calls are still paid by the caller.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::sponsor_deposits())]
+		pub fn sponsor_deposits(
+			origin: OriginFor<T>,
+			contract: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			Migration::<T>::ensure_migrated()?;
+			let sponsor = ensure_signed(origin)?;
+			let contract = T::Lookup::lookup(contract)?;
+			ensure!(<ContractInfoOf<T>>::contains_key(&contract), <Error<T>>::ContractNotFound);
+			<DepositSponsorships<T>>::try_mutate(&contract, |sponsorship| {
+				let sponsorship = sponsorship.get_or_insert_with(|| DepositSponsorship {
+					sponsor: sponsor.clone(),
+					budget: Zero::zero(),
+				});
+				ensure!(sponsorship.sponsor == sponsor, <Error<T>>::DepositsAlreadySponsored);
+				T::Currency::hold(&HoldReason::StorageDepositSponsorship.into(), &sponsor, amount)?;
+				sponsorship.budget = sponsorship.budget.saturating_add(amount);
+				Self::deposit_event(
+					vec![T::Hashing::hash_of(&contract), T::Hashing::hash_of(&sponsor)],
+					Event::DepositSponsorshipToppedUp {
+						contract: contract.clone(),
+						sponsor: sponsor.clone(),
+						budget: sponsorship.budget,
+					},
+				);
+				Ok(())
+			})
+		}
+
+		/// Cancel the sponsorship of the storage deposits of the calls to a contract, and release
+		/// its unused budget to the sponsor.
+		///
+		/// Only allowed to the sponsor and the owner of the code of the contract.
+		///
+		/// The storage deposits already paid by the sponsor remain held on the contract and are
+		/// refunded to the origins of the calls which remove the storage.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cancel_deposit_sponsorship())]
+		pub fn cancel_deposit_sponsorship(
+			origin: OriginFor<T>,
+			contract: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			Migration::<T>::ensure_migrated()?;
+			let origin = ensure_signed(origin)?;
+			let contract = T::Lookup::lookup(contract)?;
+			let sponsorship = <DepositSponsorships<T>>::get(&contract)
+				.ok_or(<Error<T>>::NotDepositSponsorshipAdmin)?;
+			if sponsorship.sponsor != origin {
+				let owner = <ContractInfoOf<T>>::get(&contract)
+					.and_then(|info| <CodeInfoOf<T>>::get(info.code_hash))
+					.map(|code_info| code_info.owner().clone());
+				ensure!(owner == Some(origin), <Error<T>>::NotDepositSponsorshipAdmin);
+			}
+			sponsorship.release_budget()?;
+			<DepositSponsorships<T>>::remove(&contract);
+			Self::deposit_event(
+				vec![T::Hashing::hash_of(&contract), T::Hashing::hash_of(&sponsorship.sponsor)],
+				Event::DepositSponsorshipCancelled {
+					contract,
+					sponsor: sponsorship.sponsor,
+					released: sponsorship.budget,
+				},
+			);
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -964,6 +1055,26 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+
+		/// The budget sponsoring the storage deposits of a contract was topped up.
+		DepositSponsorshipToppedUp {
+			/// The contract whose storage deposits are sponsored.
+			contract: T::AccountId,
+			/// The account paying for the storage deposits.
+			sponsor: T::AccountId,
+			/// The budget left for storage deposits, including the top-up.
+			budget: BalanceOf<T>,
+		},
+
+		/// The sponsorship of the storage deposits of a contract was cancelled.
+		DepositSponsorshipCancelled {
+			/// The contract whose storage deposits were sponsored.
+			contract: T::AccountId,
+			/// The account which paid for the storage deposits.
+			sponsor: T::AccountId,
+			/// The unused budget which was released to the sponsor.
+			released: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -1059,6 +1170,11 @@ pub mod pallet {
 		DelegateDependencyAlreadyExists,
 		/// Can not add a delegate dependency to the code hash of the contract itself.
 		CannotAddSelfAsDelegateDependency,
+		/// The storage deposits of the contract are already sponsored by another account.
+		DepositsAlreadySponsored,
+		/// The origin is neither the sponsor of the storage deposits of the contract nor the owner
+		/// of its code.
+		NotDepositSponsorshipAdmin,
 	}
 
 	/// A reason for the pallet contracts placing a hold on funds.
@@ -1068,6 +1184,8 @@ pub mod pallet {
 		CodeUploadDepositReserve,
 		/// The Pallet has reserved it for storage deposit.
 		StorageDepositReserve,
+		/// The Pallet has reserved it for sponsoring the storage deposits of a contract.
+		StorageDepositSponsorship,
 	}
 
 	/// A mapping from a contract's code hash to its code.
@@ -1110,6 +1228,13 @@ pub mod pallet {
 	pub(crate) type ContractInfoOf<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ContractInfo<T>>;
 
+	/// The sponsorships of the storage deposits of the calls to contracts.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub(crate) type DepositSponsorships<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DepositSponsorship<T>>;
+
 	/// Evicted contracts that await child trie deletion.
 	///
 	/// Child trie deletion is a heavy operation depending on the amount of storage items
@@ -1311,16 +1436,35 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 	) -> InternalOutput<T, Self::Output> {
 		let CallInput { dest, determinism } = self;
 		let CommonInput { origin, value, data, debug_message, .. } = common;
-		let mut storage_meter =
-			match StorageMeter::new(&origin, common.storage_deposit_limit, common.value) {
-				Ok(meter) => meter,
-				Err(err) =>
-					return InternalOutput {
-						result: Err(err.into()),
-						gas_meter,
-						storage_deposit: Default::default(),
-					},
-			};
+		// The storage deposits of signed calls to a sponsored contract are paid by the sponsor, as
+		// long as its budget covers the storage deposit limit of the call. Otherwise they are paid
+		// by the caller, as if the contract wasn't sponsored.
+		let sponsored_limit =
+			common.storage_deposit_limit.unwrap_or_else(T::DefaultDepositLimit::get);
+		let mut sponsorship = match &origin {
+			Origin::Signed(_) => <DepositSponsorships<T>>::get(&dest)
+				.filter(|sponsorship| sponsorship.budget >= sponsored_limit),
+			Origin::Root => None,
+		};
+		let (payer, storage_meter) = match &sponsorship {
+			Some(sponsorship) => (
+				Origin::from_account_id(sponsorship.sponsor.clone()),
+				sponsorship.release_budget().map(|_| StorageMeter::new_sponsored(sponsored_limit)),
+			),
+			None => (
+				origin.clone(),
+				StorageMeter::new(&origin, common.storage_deposit_limit, common.value),
+			),
+		};
+		let mut storage_meter = match storage_meter {
+			Ok(meter) => meter,
+			Err(err) =>
+				return InternalOutput {
+					result: Err(err.into()),
+					gas_meter,
+					storage_deposit: Default::default(),
+				},
+		};
 		let schedule = T::Schedule::get();
 		let result = ExecStack::<T, WasmBlob<T>>::run_call(
 			origin.clone(),
@@ -1334,14 +1478,21 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 			determinism,
 		);
 
-		match storage_meter.try_into_deposit(&origin) {
+		let mut output = match storage_meter.try_into_deposit(&payer) {
 			Ok(storage_deposit) => InternalOutput { gas_meter, storage_deposit, result },
 			Err(err) => InternalOutput {
 				gas_meter,
 				storage_deposit: Default::default(),
 				result: Err(err.into()),
 			},
+		};
+		if let Some(sponsorship) = sponsorship.as_mut() {
+			match sponsorship.hold_budget(&output.storage_deposit) {
+				Ok(()) => <DepositSponsorships<T>>::insert(&dest, &*sponsorship),
+				Err(err) => output.result = Err(err.into()),
+			}
 		}
+		output
 	}
 
 	fn ensure_origin(&self, _origin: Origin<T>) -> Result<(), DispatchError> {
@@ -1621,6 +1772,35 @@ impl<T: Config> Pallet<T> {
 		Ok(maybe_value)
 	}
 
+	/// Query the storage used by a specified contract and the deposits held for it.
+	///
+	/// See [`ContractStorageInfo`] for who the deposits are attributed to.
+	pub fn storage_info(
+		address: T::AccountId,
+	) -> ContractStorageInfoResult<T::AccountId, BalanceOf<T>> {
+		if Migration::<T>::in_progress() {
+			return Err(ContractAccessError::MigrationInProgress)
+		}
+		let contract_info =
+			ContractInfoOf::<T>::get(&address).ok_or(ContractAccessError::DoesntExist)?;
+		let code_info = CodeInfoOf::<T>::get(contract_info.code_hash)
+			.ok_or(ContractAccessError::DoesntExist)?;
+
+		Ok(ContractStorageInfo {
+			storage_items: contract_info.storage_items(),
+			storage_bytes: contract_info.storage_bytes(),
+			storage_deposit: contract_info.extra_deposit(),
+			base_deposit: contract_info.storage_base_deposit(),
+			delegate_dependency_deposit: contract_info
+				.delegate_dependencies()
+				.values()
+				.fold(Zero::zero(), |sum: BalanceOf<T>, deposit| sum.saturating_add(*deposit)),
+			code_owner: code_info.owner().clone(),
+			code_deposit: code_info.deposit(),
+			termination_refund: contract_info.total_deposit(),
+		})
+	}
+
 	/// Determine the address of a contract.
 	///
 	/// This is the address generation function used by contract instantiation. See
@@ -1674,7 +1854,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash, EventRecord> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1726,5 +1906,11 @@ sp_api::decl_runtime_apis! {
 			address: AccountId,
 			key: Vec<u8>,
 		) -> GetStorageResult;
+
+		/// Query the storage used by a given contract and the deposits held for it.
+		///
+		/// See [`crate::Pallet::storage_info`].
+		#[api_version(3)]
+		fn storage_info(address: AccountId) -> ContractStorageInfoResult<AccountId, Balance>;
 	}
 }
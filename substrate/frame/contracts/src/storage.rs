@@ -23,11 +23,12 @@ use crate::{
 	exec::{AccountIdOf, Key},
 	weights::WeightInfo,
 	BalanceOf, CodeHash, CodeInfo, Config, ContractInfoOf, DeletionQueue, DeletionQueueCounter,
-	Error, Pallet, TrieId, SENTINEL,
+	Error, HoldReason, Pallet, StorageDeposit as Deposit, TrieId, SENTINEL,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	storage::child::{self, ChildInfo},
+	traits::{fungible::MutateHold, tokens::Precision},
 	weights::Weight,
 	CloneNoBound, DefaultNoBound,
 };
@@ -40,7 +41,7 @@ use sp_runtime::{
 };
 use sp_std::{marker::PhantomData, prelude::*};
 
-use self::meter::Diff;
+use self::meter::{DepositOf, Diff};
 
 /// Information for managing an account and its sub trie abstraction.
 /// This is the required info to cache for an account.
@@ -130,6 +131,16 @@ impl<T: Config> ContractInfo<T> {
 		self.storage_base_deposit
 	}
 
+	/// Returns the number of items in the storage of the contract.
+	pub fn storage_items(&self) -> u32 {
+		self.storage_items
+	}
+
+	/// Returns the number of bytes used by the storage of the contract.
+	pub fn storage_bytes(&self) -> u32 {
+		self.storage_bytes
+	}
+
 	/// Reads a storage kv pair of a contract.
 	///
 	/// The read is performed from the `trie_id` only. The `address` is not necessary. If the
@@ -336,6 +347,44 @@ impl<T: Config> ContractInfo<T> {
 	}
 }
 
+/// A sponsorship of the storage deposits of the calls to a contract.
+///
+/// The budget is held on the sponsor. It is released for the duration of each signed call to the
+/// contract, during which all storage deposits are charged from and refunded to the sponsor
+/// instead of the origin of the call.
+#[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DepositSponsorship<T: Config> {
+	/// The account paying for the storage deposits.
+	pub sponsor: AccountIdOf<T>,
+	/// The balance held on the sponsor which is left to pay for storage deposits.
+	pub budget: BalanceOf<T>,
+}
+
+impl<T: Config> DepositSponsorship<T> {
+	/// Release the budget from hold, so that it can pay for the storage deposits of a call.
+	pub fn release_budget(&self) -> DispatchResult {
+		T::Currency::release(
+			&HoldReason::StorageDepositSponsorship.into(),
+			&self.sponsor,
+			self.budget,
+			Precision::Exact,
+		)?;
+		Ok(())
+	}
+
+	/// Hold the budget again after a call which resulted in the storage `deposit`.
+	///
+	/// A charge is paid out of the budget, while a refund is added to it.
+	pub fn hold_budget(&mut self, deposit: &DepositOf<T>) -> DispatchResult {
+		self.budget = match deposit {
+			Deposit::Charge(amount) => self.budget.saturating_sub(*amount),
+			Deposit::Refund(amount) => self.budget.saturating_add(*amount),
+		};
+		T::Currency::hold(&HoldReason::StorageDepositSponsorship.into(), &self.sponsor, self.budget)
+	}
+}
+
 /// Information about what happened to the pre-existing value when calling [`ContractInfo::write`].
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum WriteOutcome {
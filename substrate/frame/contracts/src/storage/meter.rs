@@ -379,6 +379,15 @@ where
 		}
 	}
 
+	/// Create new storage meter for a call whose storage deposits are paid out of the budget of
+	/// a [`DepositSponsorship`](crate::storage::DepositSponsorship).
+	///
+	/// Unlike [`Self::new`] this does not [`Ext::check_limit`], as the `limit` is covered by the
+	/// budget, which is released from hold for the call.
+	pub fn new_sponsored(limit: BalanceOf<T>) -> Self {
+		Self { limit, ..Default::default() }
+	}
+
 	/// The total amount of deposit that should change hands as result of the execution
 	/// that this meter was passed into. This will also perform all the charges accumulated
 	/// in the whole contract stack.
@@ -55,7 +55,7 @@ use frame_support::{
 };
 use frame_system::{EventRecord, Phase};
 use pallet_contracts_fixtures::compile_module;
-use pallet_contracts_primitives::CodeUploadReturnValue;
+use pallet_contracts_primitives::{CodeUploadReturnValue, ContractAccessError};
 use pretty_assertions::{assert_eq, assert_ne};
 use sp_core::ByteArray;
 use sp_io::hashing::blake2_256;
@@ -382,6 +382,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<1>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 impl pallet_utility::Config for Test {
@@ -404,6 +405,8 @@ impl pallet_proxy::Config for Test {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type RecoveryOrigin = frame_system::EnsureRoot<AccountId32>;
+	type RecoveryDelay = ConstU64<10>;
 }
 
 impl pallet_dummy::Config for Test {}
@@ -4078,6 +4081,170 @@ fn storage_deposit_works() {
 	});
 }
 
+#[test]
+fn storage_info_works() {
+	let (wasm, code_hash) = compile_module::<Test>("multi_store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+
+		assert_eq!(Contracts::storage_info(BOB), Err(ContractAccessError::DoesntExist));
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		// Create 2 storage items of 1_000 and 5_000 bytes.
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			(1_000u32, 5_000u32).encode(),
+		));
+
+		let info = Contracts::storage_info(addr.clone()).unwrap();
+		let base_deposit = get_contract(&addr).storage_base_deposit();
+		assert_eq!(info.storage_items, 2);
+		assert_eq!(info.storage_bytes, 6_000);
+		assert_eq!(info.storage_deposit, 4 + 6_000);
+		assert_eq!(info.base_deposit, base_deposit);
+		assert_eq!(info.delegate_dependency_deposit, 0);
+		assert_eq!(info.code_owner, ALICE);
+		assert_eq!(info.code_deposit, test_utils::get_code_deposit(&code_hash));
+		assert_eq!(info.termination_refund, base_deposit + 4 + 6_000 - 200);
+		assert_eq!(info.termination_refund, get_contract(&addr).total_deposit());
+	});
+}
+
+#[test]
+fn deposit_sponsorship_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("multi_store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000);
+		let _ = <Test as Config>::Currency::set_balance(&CHARLIE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&DJANGO, 1_000_000);
+		let sponsored = || {
+			test_utils::get_balance_on_hold(&HoldReason::StorageDepositSponsorship.into(), &CHARLIE)
+		};
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let call = |origin: AccountId32, sizes: (u32, u32), limit: Option<u64>| {
+			Contracts::call(
+				RuntimeOrigin::signed(origin),
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				limit.map(codec::Compact),
+				sizes.encode(),
+			)
+		};
+		let budget = || crate::DepositSponsorships::<Test>::get(&addr).unwrap().budget;
+
+		// Bob can't afford the storage deposit on his own.
+		assert_err_ignore_postinfo!(
+			call(BOB, (1_000, 5_000), None),
+			<Error<Test>>::StorageDepositLimitExhausted,
+		);
+
+		// Only existing contracts can be sponsored.
+		assert_noop!(
+			Contracts::sponsor_deposits(RuntimeOrigin::signed(CHARLIE), BOB, 10_000),
+			<Error<Test>>::ContractNotFound,
+		);
+		let sponsor = |origin: AccountId32, amount: u64| {
+			Contracts::sponsor_deposits(RuntimeOrigin::signed(origin), addr.clone(), amount)
+		};
+		assert_ok!(sponsor(CHARLIE, 4_000));
+		assert_ok!(sponsor(CHARLIE, 6_000));
+		assert_eq!(sponsored(), 10_000);
+		System::assert_has_event(
+			crate::Event::DepositSponsorshipToppedUp {
+				contract: addr.clone(),
+				sponsor: CHARLIE,
+				budget: 10_000,
+			}
+			.into(),
+		);
+		assert_noop!(sponsor(DJANGO, 10_000), <Error<Test>>::DepositsAlreadySponsored);
+
+		// The storage deposit of Bob's call is paid out of the budget of Charlie.
+		assert_ok!(call(BOB, (1_000, 5_000), Some(10_000)));
+		assert_eq!(test_utils::get_balance(&BOB), 1_000);
+		let left = 10_000 - 4 - 6_000;
+		assert_eq!(budget(), left);
+		assert_eq!(sponsored(), left);
+
+		// Refunds of the storage deposit are added back to the budget.
+		assert_ok!(call(BOB, (1_000, 1_000), Some(left)));
+		assert_eq!(test_utils::get_balance(&BOB), 1_000);
+		let refunded = budget() - left;
+		assert!(refunded > 0);
+
+		// Calls whose storage deposit limit isn't covered by the budget are paid by the caller.
+		let before = test_utils::get_balance(&ALICE);
+		assert_ok!(call(ALICE, (1_000, 2_000), None));
+		assert!(test_utils::get_balance(&ALICE) < before);
+		assert_eq!(budget(), left + refunded);
+		assert_err_ignore_postinfo!(
+			call(BOB, (1_000, 3_000), Some(left + refunded + 1)),
+			<Error<Test>>::StorageDepositNotEnoughFunds,
+		);
+
+		// Only the sponsor and the owner of the code can cancel the sponsorship.
+		assert_noop!(
+			Contracts::cancel_deposit_sponsorship(RuntimeOrigin::signed(DJANGO), addr.clone()),
+			<Error<Test>>::NotDepositSponsorshipAdmin,
+		);
+		assert_ok!(Contracts::cancel_deposit_sponsorship(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone()
+		));
+		assert_eq!(sponsored(), 0);
+		assert!(crate::DepositSponsorships::<Test>::get(&addr).is_none());
+		System::assert_has_event(
+			crate::Event::DepositSponsorshipCancelled {
+				contract: addr.clone(),
+				sponsor: CHARLIE,
+				released: left + refunded,
+			}
+			.into(),
+		);
+
+		// Once the sponsor is removed, another account can sponsor the contract.
+		assert_ok!(sponsor(DJANGO, 10_000));
+		assert_ok!(Contracts::cancel_deposit_sponsorship(
+			RuntimeOrigin::signed(DJANGO),
+			addr.clone()
+		));
+	});
+}
+
 #[test]
 fn storage_deposit_callee_works() {
 	let (wasm_caller, _code_hash_caller) = compile_module::<Test>("call").unwrap();
@@ -325,6 +325,11 @@ impl<T: Config> CodeInfo<T> {
 	pub fn deposit(&self) -> BalanceOf<T> {
 		self.deposit
 	}
+
+	/// Returns the account which uploaded the module.
+	pub fn owner(&self) -> &AccountIdOf<T> {
+		&self.owner
+	}
 }
 
 impl<T: Config> Executable<T> for WasmBlob<T> {
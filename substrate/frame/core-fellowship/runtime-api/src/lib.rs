@@ -0,0 +1,39 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for core fellowship pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_core_fellowship::EvidenceMetadata;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for retrieving the evidence of the members of a fellowship.
+	pub trait CoreFellowshipApi<AccountId, BlockNumber, Hash>
+		where
+			AccountId: Codec,
+			BlockNumber: Codec,
+			Hash: Codec,
+	{
+		/// Returns the metadata of the latest evidence submitted by `who`, if they have any.
+		///
+		/// Once the evidence was archived, it is no longer in state, but can be retrieved by its
+		/// hash from wherever it was exported to.
+		fn evidence_metadata(who: AccountId) -> Option<EvidenceMetadata<BlockNumber, Hash>>;
+	}
+}
@@ -23,6 +23,7 @@ use super::*;
 use crate::Pallet as CoreFellowship;
 
 use frame_benchmarking::v2::*;
+use frame_support::weights::Weight;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_arithmetic::traits::Bounded;
 
@@ -208,6 +209,23 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn archive_evidence() -> Result<(), BenchmarkError> {
+		let member = make_member::<T, I>(1)?;
+		ensure_evidence::<T, I>(&member)?;
+		let now = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::EvidenceRetentionPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(now);
+
+		#[block]
+		{
+			CoreFellowship::<T, I>::archive_expired_evidence(now, Weight::MAX);
+		}
+
+		assert!(MemberEvidenceMetadata::<T, I>::get(&member).unwrap().archived.is_some());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		CoreFellowship,
 		crate::tests::new_test_ext(),
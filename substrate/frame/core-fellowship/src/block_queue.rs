@@ -0,0 +1,102 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains [`BlockQueue`], for keys which are to be processed once a given block is reached.
+
+use codec::{EncodeLike, FullCodec};
+use frame_support::{
+	storage::{IterableStorageDoubleMap, StorageValue},
+	weights::{RuntimeDbWeight, Weight, WeightMeter},
+};
+use sp_runtime::traits::{One, Saturating};
+use sp_std::marker::PhantomData;
+
+/// A queue of keys by the block from which on they are due, processed in block order with
+/// whatever weight is left over, e.g. in `on_idle`.
+///
+/// The keys live in the double map `Queue`, keyed first by block number and valued `()`. The
+/// value `Cursor` holds the first block which was not fully processed yet; it is never moved past
+/// a block with keys still queued, so no key is skipped.
+pub(crate) struct BlockQueue<BlockNumber, Key, Queue, Cursor>(
+	PhantomData<(BlockNumber, Key, Queue, Cursor)>,
+);
+
+impl<BlockNumber, Key, Queue, Cursor> BlockQueue<BlockNumber, Key, Queue, Cursor>
+where
+	BlockNumber: FullCodec + Copy + Ord + One + Saturating,
+	Key: FullCodec,
+	Queue: IterableStorageDoubleMap<BlockNumber, Key, ()>,
+	Cursor: StorageValue<BlockNumber>,
+{
+	/// Queue `key` to be processed from block `at` on.
+	///
+	/// The cursor is moved back to `at` if needed, so that `key` is processed even if it is due
+	/// before keys which were queued earlier.
+	pub(crate) fn enqueue(at: BlockNumber, key: impl EncodeLike<Key>) {
+		Queue::insert(at, key, ());
+		if Cursor::try_get().map_or(true, |cursor| at < cursor) {
+			Cursor::put(at);
+		}
+	}
+
+	/// Remove `key`, queued at block `at`, from the queue.
+	pub(crate) fn dequeue(at: BlockNumber, key: impl EncodeLike<Key>) {
+		Queue::remove(at, key);
+	}
+
+	/// Dequeue and pass to `f` the keys due by block `now`, for as long as `limit` allows.
+	///
+	/// Each key is accounted for with `weight`, which must cover `f`. Keys which `f` queues again
+	/// must not be due before `now + 1`. Returns the weight consumed.
+	pub(crate) fn service(
+		now: BlockNumber,
+		limit: Weight,
+		db: RuntimeDbWeight,
+		weight: Weight,
+		mut f: impl FnMut(Key),
+	) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		if meter.try_consume(db.reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+		let Ok(mut cursor) = Cursor::try_get() else { return meter.consumed() };
+		let start = cursor;
+
+		while cursor <= now {
+			match Queue::iter_key_prefix(cursor).next() {
+				Some(key) => {
+					if meter.try_consume(weight).is_err() {
+						break
+					}
+					Queue::remove(cursor, &key);
+					f(key);
+				},
+				None => {
+					if meter.try_consume(db.reads(1)).is_err() {
+						break
+					}
+					cursor = cursor.saturating_add(One::one());
+				},
+			}
+		}
+
+		if cursor != start {
+			Cursor::put(cursor);
+		}
+		meter.consumed()
+	}
+}
@@ -54,6 +54,10 @@
 //!
 //! Only tracked/ranked accounts may submit evidence for their proof and promotion. Candidates
 //! cannot be approved - they must proceed only to promotion prior to the offboard timeout elapsing.
+//!
+//! Evidence which is not judged within `EvidenceRetentionPeriod` is archived when there is spare
+//! block weight: it is deposited in an event for export and pruned from state, keeping only its
+//! hash and other [`EvidenceMetadata`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -61,7 +65,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{Saturating, Zero};
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{traits::Hash, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 use frame_support::{
@@ -73,11 +77,15 @@ use frame_support::{
 	BoundedVec,
 };
 
+use block_queue::BlockQueue;
+
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod block_queue;
+pub mod migration;
 pub mod weights;
 
 pub use pallet::*;
@@ -98,6 +106,22 @@ pub enum Wish {
 /// decide how to concretely utilise it. This could be an IPFS hash, a URL or structured data.
 pub type Evidence<T, I> = BoundedVec<u8, <T as Config<I>>::EvidenceSize>;
 
+/// What is known about the evidence of a member, even once the evidence itself was archived.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct EvidenceMetadata<BlockNumber, Hash> {
+	/// The desired outcome for which the evidence was presented.
+	pub wish: Wish,
+	/// The hash of the evidence, by which it can be found wherever it was exported to.
+	pub hash: Hash,
+	/// The size of the evidence in bytes.
+	pub len: u32,
+	/// The block in which the evidence was submitted.
+	pub submitted: BlockNumber,
+	/// The block in which the evidence was archived, if it was. Archived evidence is no longer
+	/// kept in state.
+	pub archived: Option<BlockNumber>,
+}
+
 /// The status of the pallet instance.
 #[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
 pub struct ParamsType<Balance, BlockNumber, const RANKS: usize> {
@@ -151,6 +175,7 @@ pub mod pallet {
 	const RANK_COUNT: usize = 9;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
@@ -192,11 +217,30 @@ pub mod pallet {
 		/// The maximum size in bytes submitted evidence is allowed to be.
 		#[pallet::constant]
 		type EvidenceSize: Get<u32>;
+
+		/// The number of blocks for which submitted evidence is kept in state before being
+		/// archived, if it was not judged by then.
+		///
+		/// Archived evidence is pruned from state, but its hash is kept, and it is deposited in an
+		/// event so that it can be exported.
+		#[pallet::constant]
+		type EvidenceRetentionPeriod: Get<BlockNumberFor<Self>>;
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	pub type ParamsOf<T, I> = ParamsType<<T as Config<I>>::Balance, BlockNumberFor<T>, RANK_COUNT>;
 	pub type MemberStatusOf<T> = MemberStatus<BlockNumberFor<T>>;
 	pub type RankOf<T, I> = <<T as Config<I>>::Members as RankedMembers>::Rank;
+	pub type EvidenceMetadataOf<T> =
+		EvidenceMetadata<BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
+	pub(super) type ArchivalQueueOf<T, I> = BlockQueue<
+		BlockNumberFor<T>,
+		<T as frame_system::Config>::AccountId,
+		ArchivalQueue<T, I>,
+		ArchivalCursor<T, I>,
+	>;
 
 	/// The overall status of the system.
 	#[pallet::storage]
@@ -213,6 +257,28 @@ pub mod pallet {
 	pub(super) type MemberEvidence<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, (Wish, Evidence<T, I>), OptionQuery>;
 
+	/// The metadata of the evidence in `MemberEvidence`.
+	#[pallet::storage]
+	pub(super) type MemberEvidenceMetadata<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, EvidenceMetadataOf<T>, OptionQuery>;
+
+	/// The members whose evidence is to be archived, by the block from which on it is to be.
+	#[pallet::storage]
+	pub(super) type ArchivalQueue<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The block from which on `ArchivalQueue` is still to be archived.
+	#[pallet::storage]
+	pub(super) type ArchivalCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -240,7 +306,7 @@ pub mod pallet {
 			who: T::AccountId,
 			/// The desired outcome for which the evidence was presented.
 			wish: Wish,
-			/// The evidence of efforts.
+			/// The evidence of efforts. Empty if the evidence was archived.
 			evidence: Evidence<T, I>,
 			/// The old rank, prior to this change.
 			old_rank: u16,
@@ -249,6 +315,18 @@ pub mod pallet {
 		},
 		/// Pre-ranked account has been inducted at their current rank.
 		Imported { who: T::AccountId, rank: RankOf<T, I> },
+		/// Some evidence was not judged within the retention period and was pruned from state.
+		/// Only its hash is kept, so it should be exported from this event.
+		EvidenceArchived {
+			/// The member/candidate.
+			who: T::AccountId,
+			/// The desired outcome for which the evidence was presented.
+			wish: Wish,
+			/// The evidence of efforts.
+			evidence: Evidence<T, I>,
+			/// The hash of the evidence.
+			hash: T::Hash,
+		},
 	}
 
 	#[pallet::error]
@@ -275,6 +353,13 @@ pub mod pallet {
 		TooSoon,
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::archive_expired_evidence(now, remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Bump the state of a member.
@@ -471,6 +556,7 @@ pub mod pallet {
 			ensure!(Member::<T, I>::contains_key(&who), Error::<T, I>::NotTracked);
 			Member::<T, I>::remove(&who);
 			MemberEvidence::<T, I>::remove(&who);
+			Self::remove_evidence_metadata(&who);
 			Self::deposit_event(Event::<T, I>::Offboarded { who });
 			Ok(Pays::No.into())
 		}
@@ -478,7 +564,9 @@ pub mod pallet {
 		/// Provide evidence that a rank is deserved.
 		///
 		/// This is free as long as no evidence for the forthcoming judgement is already submitted.
-		/// Evidence is cleared after an outcome (either demotion, promotion of approval).
+		/// Evidence is cleared after an outcome (either demotion, promotion of approval). Evidence
+		/// for which there is no outcome within `EvidenceRetentionPeriod` is archived, keeping only
+		/// its hash in state.
 		///
 		/// - `origin`: A `Signed` origin of an inducted and ranked account.
 		/// - `wish`: The stated desire of the member.
@@ -495,7 +583,21 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			ensure!(Member::<T, I>::contains_key(&who), Error::<T, I>::NotTracked);
 			let replaced = MemberEvidence::<T, I>::contains_key(&who);
+			Self::remove_evidence_metadata(&who);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let archive_at = now.saturating_add(T::EvidenceRetentionPeriod::get());
+			let metadata = EvidenceMetadata {
+				wish,
+				hash: T::Hashing::hash(&evidence[..]),
+				len: evidence.len() as u32,
+				submitted: now,
+				archived: None,
+			};
 			MemberEvidence::<T, I>::insert(&who, (wish, evidence));
+			MemberEvidenceMetadata::<T, I>::insert(&who, metadata);
+			ArchivalQueueOf::<T, I>::enqueue(archive_at, &who);
+
 			Self::deposit_event(Event::<T, I>::Requested { who, wish });
 			Ok(if replaced { Pays::Yes } else { Pays::No }.into())
 		}
@@ -537,7 +639,61 @@ pub mod pallet {
 			}
 		}
 
+		/// The metadata of the latest evidence of `who`, if they have any.
+		pub fn evidence_metadata(who: &T::AccountId) -> Option<EvidenceMetadataOf<T>> {
+			MemberEvidenceMetadata::<T, I>::get(who)
+		}
+
+		/// Remove the evidence metadata of `who`, dequeuing the evidence from archival if it was
+		/// not archived yet.
+		fn remove_evidence_metadata(who: &T::AccountId) {
+			if let Some(metadata) = MemberEvidenceMetadata::<T, I>::take(who) {
+				if metadata.archived.is_none() {
+					ArchivalQueueOf::<T, I>::dequeue(Self::archive_at(&metadata), who);
+				}
+			}
+		}
+
+		/// Archive the queued evidence up to block `now`, for as long as `limit` allows.
+		pub(crate) fn archive_expired_evidence(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let weight = T::WeightInfo::archive_evidence();
+			ArchivalQueueOf::<T, I>::service(now, limit, T::DbWeight::get(), weight, |who| {
+				Self::archive_evidence(who, now)
+			})
+		}
+
+		/// The block from which on the evidence described by `metadata` is to be archived.
+		fn archive_at(metadata: &EvidenceMetadataOf<T>) -> BlockNumberFor<T> {
+			metadata.submitted.saturating_add(T::EvidenceRetentionPeriod::get())
+		}
+
+		/// Prune the evidence of `who` from state if it is due for archival by block `now`,
+		/// keeping only its metadata.
+		fn archive_evidence(who: T::AccountId, now: BlockNumberFor<T>) {
+			let Some(mut metadata) = MemberEvidenceMetadata::<T, I>::get(&who) else {
+				return
+			};
+			if metadata.archived.is_some() {
+				return
+			}
+			let archive_at = Self::archive_at(&metadata);
+			if archive_at > now {
+				// `EvidenceRetentionPeriod` was increased since the evidence was queued, or the
+				// entry was left behind by evidence which has since been replaced.
+				ArchivalQueueOf::<T, I>::enqueue(archive_at, &who);
+				return
+			}
+			if let Some((wish, evidence)) = MemberEvidence::<T, I>::get(&who) {
+				MemberEvidence::<T, I>::insert(&who, (wish, Evidence::<T, I>::default()));
+				metadata.archived = Some(now);
+				let hash = metadata.hash;
+				MemberEvidenceMetadata::<T, I>::insert(&who, metadata);
+				Self::deposit_event(Event::<T, I>::EvidenceArchived { who, wish, evidence, hash });
+			}
+		}
+
 		fn dispose_evidence(who: T::AccountId, old_rank: u16, new_rank: Option<u16>) {
+			Self::remove_evidence_metadata(&who);
 			if let Some((wish, evidence)) = MemberEvidence::<T, I>::take(&who) {
 				let e = Event::<T, I>::EvidenceJudged { who, wish, evidence, old_rank, new_rank };
 				Self::deposit_event(e);
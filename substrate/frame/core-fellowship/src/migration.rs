@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the core-fellowship pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const TARGET: &'static str = "runtime::core-fellowship::migration";
+
+pub mod v1 {
+	use super::*;
+
+	/// Records the [`EvidenceMetadata`] of all submitted evidence and queues it for archival.
+	///
+	/// The block in which the evidence was submitted is unknown, so it is recorded as submitted in
+	/// the block of the migration.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let evidence_count = MemberEvidence::<T, I>::iter_keys().count() as u32;
+			log::info!(target: TARGET, "pre-upgrade state contains '{}' evidence.", evidence_count);
+			Ok(evidence_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T, I>::on_chain_storage_version();
+			let mut weight = T::DbWeight::get().reads(1);
+			if onchain_version != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return weight
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let archive_at: BlockNumberFor<T> =
+				now.saturating_add(T::EvidenceRetentionPeriod::get());
+			let mut migrated = 0u64;
+			for (who, (wish, evidence)) in MemberEvidence::<T, I>::iter() {
+				let metadata = EvidenceMetadata {
+					wish,
+					hash: T::Hashing::hash(&evidence[..]),
+					len: evidence.len() as u32,
+					submitted: now,
+					archived: None,
+				};
+				MemberEvidenceMetadata::<T, I>::insert(&who, metadata);
+				ArchivalQueueOf::<T, I>::enqueue(archive_at, &who);
+				migrated.saturating_inc();
+			}
+			log::info!(target: TARGET, "migrated {} evidence.", migrated);
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			weight.saturating_accrue(
+				T::DbWeight::get().reads_writes(migrated * 2 + 1, migrated * 2 + 2),
+			);
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "must upgrade to version 1.");
+			let pre_evidence_count: u32 = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			let post_metadata_count = MemberEvidenceMetadata::<T, I>::iter_keys().count() as u32;
+			ensure!(post_metadata_count == pre_evidence_count, "must migrate all evidence.");
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+
+	#[test]
+	fn migration_v0_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			frame_system::Pallet::<Test>::set_block_number(7);
+			let evidence: Evidence<Test, ()> = b"evidence".to_vec().try_into().unwrap();
+			MemberEvidence::<Test>::insert(10, (Wish::Promotion, evidence.clone()));
+
+			v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+			let metadata = MemberEvidenceMetadata::<Test>::get(10).unwrap();
+			assert_eq!(metadata.hash, <Test as frame_system::Config>::Hashing::hash(&evidence));
+			assert_eq!((metadata.submitted, metadata.archived), (7, None));
+			assert!(ArchivalQueue::<Test>::contains_key(12, 10));
+			assert_eq!(ArchivalCursor::<Test>::get(), Some(12));
+		});
+	}
+}
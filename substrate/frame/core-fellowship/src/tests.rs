@@ -23,7 +23,7 @@ use frame_support::{
 	assert_noop, assert_ok, derive_impl, ord_parameter_types,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{tokens::GetSalary, ConstU32, ConstU64, Everything, IsInVec, TryMapSuccess},
+	traits::{tokens::GetSalary, ConstU32, ConstU64, Everything, Hooks, IsInVec, TryMapSuccess},
 };
 use frame_system::EnsureSignedBy;
 use sp_core::H256;
@@ -127,6 +127,7 @@ fn unrank(who: u64) {
 
 parameter_types! {
 	pub ZeroToNine: Vec<u64> = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+	pub static EvidenceRetentionPeriod: u64 = 5;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -142,6 +143,7 @@ impl Config for Test {
 	type ApproveOrigin = TryMapSuccess<EnsureSignedBy<IsInVec<ZeroToNine>, u64>, TryMorphInto<u16>>;
 	type PromoteOrigin = TryMapSuccess<EnsureSignedBy<IsInVec<ZeroToNine>, u64>, TryMorphInto<u16>>;
 	type EvidenceSize = ConstU32<1024>;
+	type EvidenceRetentionPeriod = EvidenceRetentionPeriod;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -356,3 +358,131 @@ fn active_changing_get_salary_works() {
 		}
 	});
 }
+
+fn evidence(data: &[u8]) -> Evidence<Test, ()> {
+	data.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn submitted_evidence_is_archived_after_retention_period() {
+	new_test_ext().execute_with(|| {
+		set_rank(10, 1);
+		assert_ok!(CoreFellowship::import(signed(10)));
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Promotion, evidence(b"e")));
+		let hash = BlakeTwo256::hash(b"e");
+		assert_eq!(
+			CoreFellowship::evidence_metadata(&10),
+			Some(EvidenceMetadata {
+				wish: Wish::Promotion,
+				hash,
+				len: 1,
+				submitted: 1,
+				archived: None,
+			})
+		);
+
+		run_to(5);
+		CoreFellowship::on_idle(5, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(10), Some((Wish::Promotion, evidence(b"e"))));
+
+		run_to(6);
+		CoreFellowship::on_idle(6, Weight::MAX);
+		System::assert_last_event(
+			Event::<Test>::EvidenceArchived {
+				who: 10,
+				wish: Wish::Promotion,
+				evidence: evidence(b"e"),
+				hash,
+			}
+			.into(),
+		);
+		// The evidence is pruned, but its hash is kept.
+		assert_eq!(MemberEvidence::<Test>::get(10), Some((Wish::Promotion, evidence(b""))));
+		assert_eq!(CoreFellowship::evidence_metadata(&10).unwrap().hash, hash);
+		assert_eq!(CoreFellowship::evidence_metadata(&10).unwrap().archived, Some(6));
+
+		// Archived evidence can still be judged.
+		assert_ok!(CoreFellowship::promote(signed(2), 10, 2));
+		assert_eq!(CoreFellowship::evidence_metadata(&10), None);
+		assert!(!MemberEvidence::<Test>::contains_key(10));
+	});
+}
+
+#[test]
+fn judged_or_replaced_evidence_is_not_archived() {
+	new_test_ext().execute_with(|| {
+		set_rank(10, 1);
+		set_rank(11, 1);
+		assert_ok!(CoreFellowship::import(signed(10)));
+		assert_ok!(CoreFellowship::import(signed(11)));
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence(b"a")));
+		assert_ok!(CoreFellowship::submit_evidence(signed(11), Wish::Retention, evidence(b"b")));
+
+		run_to(3);
+		assert_ok!(CoreFellowship::approve(signed(1), 10, 1));
+		assert_ok!(CoreFellowship::submit_evidence(signed(11), Wish::Promotion, evidence(b"c")));
+
+		run_to(6);
+		CoreFellowship::on_idle(6, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(11), Some((Wish::Promotion, evidence(b"c"))));
+		assert_eq!(CoreFellowship::evidence_metadata(&11).unwrap().submitted, 3);
+
+		run_to(8);
+		CoreFellowship::on_idle(8, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(11), Some((Wish::Promotion, evidence(b""))));
+		assert_eq!(ArchivalQueue::<Test>::iter().count(), 0);
+		assert_eq!(ArchivalCursor::<Test>::get(), Some(9));
+	});
+}
+
+#[test]
+fn evidence_archival_respects_weight_limit() {
+	new_test_ext().execute_with(|| {
+		for who in [10, 11] {
+			set_rank(who, 1);
+			assert_ok!(CoreFellowship::import(signed(who)));
+			let wish = Wish::Retention;
+			assert_ok!(CoreFellowship::submit_evidence(signed(who), wish, evidence(b"e")));
+		}
+
+		run_to(6);
+		CoreFellowship::on_idle(6, <() as WeightInfo>::archive_evidence());
+		assert_eq!(ArchivalQueue::<Test>::iter().count(), 1);
+		CoreFellowship::on_idle(6, Weight::zero());
+		assert_eq!(ArchivalQueue::<Test>::iter().count(), 1);
+		CoreFellowship::on_idle(6, Weight::MAX);
+		assert_eq!(ArchivalQueue::<Test>::iter().count(), 0);
+		assert!(MemberEvidence::<Test>::iter_values().all(|(_, evidence)| evidence.is_empty()));
+	});
+}
+
+#[test]
+fn evidence_archival_follows_retention_period_changes() {
+	new_test_ext().execute_with(|| {
+		for who in [10, 11] {
+			set_rank(who, 1);
+			assert_ok!(CoreFellowship::import(signed(who)));
+		}
+		assert_ok!(CoreFellowship::submit_evidence(signed(10), Wish::Retention, evidence(b"a")));
+		assert_eq!(ArchivalCursor::<Test>::get(), Some(6));
+
+		// Evidence queued before the cursor after the retention period was lowered is archived.
+		EvidenceRetentionPeriod::set(2);
+		assert_ok!(CoreFellowship::submit_evidence(signed(11), Wish::Retention, evidence(b"b")));
+		assert_eq!(ArchivalCursor::<Test>::get(), Some(3));
+		run_to(3);
+		CoreFellowship::on_idle(3, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(11), Some((Wish::Retention, evidence(b""))));
+		assert_eq!(MemberEvidence::<Test>::get(10), Some((Wish::Retention, evidence(b"a"))));
+
+		// Evidence due later after the retention period was raised is queued again.
+		EvidenceRetentionPeriod::set(10);
+		run_to(6);
+		CoreFellowship::on_idle(6, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(10), Some((Wish::Retention, evidence(b"a"))));
+		assert!(ArchivalQueue::<Test>::contains_key(11, 10));
+		run_to(11);
+		CoreFellowship::on_idle(11, Weight::MAX);
+		assert_eq!(MemberEvidence::<Test>::get(10), Some((Wish::Retention, evidence(b""))));
+	});
+}
@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since unjudged evidence is archived. The weight of `archive_evidence` is a
+//! PLACEHOLDER estimated by hand from its storage accesses. Re-run the benchmarks to replace it.
 
 // Executed Command:
 // ./target/production/substrate
@@ -62,6 +65,7 @@ pub trait WeightInfo {
 	fn import() -> Weight;
 	fn approve() -> Weight;
 	fn submit_evidence() -> Weight;
+	fn archive_evidence() -> Weight;
 }
 
 /// Weights for pallet_core_fellowship using the Substrate node and recommended hardware.
@@ -89,14 +93,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn bump_offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16887`
 		//  Estimated: `19894`
 		// Minimum execution time: 58_489_000 picoseconds.
 		Weight::from_parts(60_202_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -110,14 +118,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn bump_demote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16997`
 		//  Estimated: `19894`
 		// Minimum execution time: 60_605_000 picoseconds.
 		Weight::from_parts(63_957_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -165,14 +177,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: RankedCollective IdToIndex (r:0 w:1)
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn promote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16865`
 		//  Estimated: `19894`
 		// Minimum execution time: 56_642_000 picoseconds.
 		Weight::from_parts(59_353_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -180,14 +196,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:0 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
 		//  Estimated: `3514`
 		// Minimum execution time: 17_459_000 picoseconds.
 		Weight::from_parts(18_033_000, 3514)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -208,27 +228,49 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16843`
 		//  Estimated: `19894`
 		// Minimum execution time: 41_487_000 picoseconds.
 		Weight::from_parts(43_459_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:0)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:2)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalCursor (r:1 w:1)
+	/// Proof: CoreFellowship ArchivalCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	fn submit_evidence() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `79`
 		//  Estimated: `19894`
 		// Minimum execution time: 26_033_000 picoseconds.
 		Weight::from_parts(26_612_000, 19894)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: CoreFellowship ArchivalQueue (r:1 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	fn archive_evidence() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(31_524_000, 19894)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -256,14 +298,18 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn bump_offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16887`
 		//  Estimated: `19894`
 		// Minimum execution time: 58_489_000 picoseconds.
 		Weight::from_parts(60_202_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -277,14 +323,18 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn bump_demote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16997`
 		//  Estimated: `19894`
 		// Minimum execution time: 60_605_000 picoseconds.
 		Weight::from_parts(63_957_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -332,14 +382,18 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
 	/// Storage: RankedCollective IdToIndex (r:0 w:1)
 	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn promote() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16865`
 		//  Estimated: `19894`
 		// Minimum execution time: 56_642_000 picoseconds.
 		Weight::from_parts(59_353_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
@@ -347,14 +401,18 @@ impl WeightInfo for () {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:0 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn offboard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
 		//  Estimated: `3514`
 		// Minimum execution time: 17_459_000 picoseconds.
 		Weight::from_parts(18_033_000, 3514)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:1)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -375,26 +433,48 @@ impl WeightInfo for () {
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `16843`
 		//  Estimated: `19894`
 		// Minimum execution time: 41_487_000 picoseconds.
 		Weight::from_parts(43_459_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: CoreFellowship Member (r:1 w:0)
 	/// Proof: CoreFellowship Member (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
 	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalQueue (r:0 w:2)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship ArchivalCursor (r:1 w:1)
+	/// Proof: CoreFellowship ArchivalCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	fn submit_evidence() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `79`
 		//  Estimated: `19894`
 		// Minimum execution time: 26_033_000 picoseconds.
 		Weight::from_parts(26_612_000, 19894)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: CoreFellowship ArchivalQueue (r:1 w:1)
+	/// Proof: CoreFellowship ArchivalQueue (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidenceMetadata (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidenceMetadata (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: CoreFellowship MemberEvidence (r:1 w:1)
+	/// Proof: CoreFellowship MemberEvidence (max_values: None, max_size: Some(16429), added: 18904, mode: MaxEncodedLen)
+	fn archive_evidence() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(31_524_000, 19894)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
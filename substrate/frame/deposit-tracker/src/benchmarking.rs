@@ -0,0 +1,52 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the deposit tracker pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as DepositTracker;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn poke_all_deposits(n: Linear<1, { T::MaxReasons::get() }>) {
+		let who: T::AccountId = account("who", 0, 0);
+		T::Currency::set_balance(&who, BalanceOf::<T>::max_value() / 2u32.into());
+		for i in 0..n {
+			// Nothing is held yet, so that the whole deposit of each reason is held by the poke.
+			let tracked = TrackedDeposit { count: 1, size: 1024, amount: Zero::zero() };
+			Deposits::<T>::insert(&who, T::BenchmarkHelper::reason(i), tracked);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), who.clone());
+
+		assert_eq!(Deposits::<T>::iter_prefix(&who).count() as u32, n);
+	}
+
+	impl_benchmark_test_suite! { DepositTracker, crate::mock::new_test_ext(), crate::mock::Test }
+}
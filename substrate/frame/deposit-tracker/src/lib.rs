@@ -0,0 +1,440 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Deposit Tracker Pallet
+//!
+//! Tracks the deposits other pallets hold from accounts, so that they can all be re-computed in a
+//! uniform way once the constants pricing them change.
+//!
+//! A participating pallet takes its deposits through [`TrackedHold`], usually as its
+//! [`Consideration`]. The deposits are held with the fungible [`Config::Currency`], and priced by
+//! [`Config::Prices`] for the footprint of all the items an account holds a deposit for under the
+//! same reason. This pallet records that footprint along with the amount held, which is the price
+//! as of when the deposit was last updated.
+//!
+//! When the prices change, e.g. because a deposit constant was changed through governance,
+//! anyone can call [`Pallet::poke_all_deposits`] for an account to hold or release the difference
+//! between the recorded and the current deposit of each of its reasons.
+//!
+//! A pallet already holding deposits with the same reason, e.g. through a `HoldConsideration`,
+//! moves its existing deposits to the tracker in a migration, with [`TrackedHold::adopt`].
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	defensive, ensure,
+	traits::{
+		fungible::{self, MutateHold},
+		tokens::{Fortitude::Force, Precision::BestEffort},
+		Consideration, Footprint, Get,
+	},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The balance of the [`Config::Currency`].
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A [`TrackedDeposit`] of the pallet.
+pub type TrackedDepositOf<T> = TrackedDeposit<BalanceOf<T>>;
+
+/// Prices the deposits tracked by this pallet.
+pub trait DepositPrices<Reason, Balance> {
+	/// The deposit currently required to hold `footprint` for `reason`.
+	///
+	/// The footprint is that of all the items an account holds a deposit for under `reason`, so
+	/// the price should be additive, e.g. a deposit per item and a deposit per byte.
+	fn deposit(reason: &Reason, footprint: Footprint) -> Balance;
+}
+
+/// The deposit held from an account for a reason.
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct TrackedDeposit<Balance> {
+	/// The number of items the deposit is held for.
+	pub count: u64,
+	/// The total size of the items in bytes.
+	pub size: u64,
+	/// The amount held, at the price of when the deposit was last updated.
+	pub amount: Balance,
+}
+
+impl<Balance> TrackedDeposit<Balance> {
+	/// The footprint the deposit is held for.
+	pub fn footprint(&self) -> Footprint {
+		Footprint { count: self.count, size: self.size }
+	}
+}
+
+/// Helper to create the hold reasons in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Reason> {
+	/// The `i`th of the reasons deposits are tracked for, up to [`Config::MaxReasons`].
+	fn reason(i: u32) -> Reason;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The reasons deposits are held for, usually the overarching hold reason.
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen;
+
+		/// The currency the deposits are held with.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The current prices of the deposits.
+		type Prices: DepositPrices<Self::RuntimeHoldReason, BalanceOf<Self>>;
+
+		/// The maximum number of reasons deposits are held for from a single account.
+		///
+		/// This bounds the weight of [`Pallet::poke_all_deposits`]. It should be at least the
+		/// number of reasons of the participating pallets: deposits for any further reason fail
+		/// with [`Error::TooManyReasons`].
+		#[pallet::constant]
+		type MaxReasons: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to create the hold reasons in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::RuntimeHoldReason>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The deposits held from each account, by reason.
+	#[pallet::storage]
+	pub type Deposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::RuntimeHoldReason,
+		TrackedDepositOf<T>,
+		OptionQuery,
+	>;
+
+	/// The number of reasons in `Deposits` for each account, at most `MaxReasons`.
+	#[pallet::storage]
+	pub type ReasonCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A deposit was re-computed at the current price.
+		Poked {
+			/// The account the deposit is held from.
+			who: T::AccountId,
+			/// The reason the deposit is held for.
+			reason: T::RuntimeHoldReason,
+			/// The amount held before.
+			old: BalanceOf<T>,
+			/// The amount held now.
+			new: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account already holds deposits for `MaxReasons` reasons.
+		TooManyReasons,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Re-compute all the deposits held from `who` at the current prices.
+		///
+		/// The difference between the recorded and the current deposit of each reason is held or
+		/// released. Fails without changing any deposit if `who` can not afford an increase.
+		///
+		/// Can be called by any signed origin.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::poke_all_deposits(T::MaxReasons::get()))]
+		pub fn poke_all_deposits(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let deposits: Vec<_> = Deposits::<T>::iter_prefix(&who).collect();
+			let count = deposits.len() as u32;
+			for (reason, tracked) in deposits {
+				let new = T::Prices::deposit(&reason, tracked.footprint());
+				if new == tracked.amount {
+					continue
+				}
+
+				Self::adjust_hold(&who, &reason, tracked.amount, new, false)?;
+				Deposits::<T>::insert(&who, &reason, TrackedDeposit { amount: new, ..tracked });
+				Self::deposit_event(Event::Poked {
+					who: who.clone(),
+					reason,
+					old: tracked.amount,
+					new,
+				});
+			}
+
+			Ok(Some(T::WeightInfo::poke_all_deposits(count)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The deposit held from `who` for `reason`, if any.
+	pub fn deposit(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+	) -> Option<TrackedDepositOf<T>> {
+		Deposits::<T>::get(who, reason)
+	}
+
+	/// Add `footprint` to the footprint `who` holds a deposit for under `reason`.
+	///
+	/// The whole deposit of `reason` is re-computed at the current price.
+	pub fn increase(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		footprint: Footprint,
+	) -> DispatchResult {
+		Self::update(who, reason, |held| add(held, footprint), false)
+	}
+
+	/// Remove `footprint` from the footprint `who` holds a deposit for under `reason`, releasing
+	/// the deposit no longer required.
+	///
+	/// The whole deposit of `reason` is re-computed at the current price.
+	pub fn decrease(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		footprint: Footprint,
+	) -> DispatchResult {
+		Self::update(who, reason, |held| sub(held, footprint), false)
+	}
+
+	/// Like [`Pallet::decrease`], but burns the deposit no longer required instead of releasing
+	/// it.
+	pub fn burn(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		footprint: Footprint,
+	) -> DispatchResult {
+		Self::update(who, reason, |held| sub(held, footprint), true)
+	}
+
+	/// Replace the footprint `who` holds a deposit for under `reason` by `f` of it, and hold the
+	/// deposit of the new footprint at the current price.
+	fn update(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		f: impl FnOnce(Footprint) -> Footprint,
+		burn: bool,
+	) -> DispatchResult {
+		let maybe_tracked = Deposits::<T>::get(who, reason);
+		let is_new = maybe_tracked.is_none();
+		let tracked = maybe_tracked.unwrap_or_default();
+		let footprint = f(tracked.footprint());
+		if footprint == Footprint::default() {
+			Self::adjust_hold(who, reason, tracked.amount, Zero::zero(), burn)?;
+			if !is_new {
+				Self::remove_deposit(who, reason);
+			}
+			return Ok(())
+		}
+
+		let reasons = ReasonCount::<T>::get(who);
+		ensure!(!is_new || reasons < T::MaxReasons::get(), Error::<T>::TooManyReasons);
+		let amount = T::Prices::deposit(reason, footprint);
+		Self::adjust_hold(who, reason, tracked.amount, amount, burn)?;
+		if is_new {
+			ReasonCount::<T>::insert(who, reasons + 1);
+		}
+		let Footprint { count, size } = footprint;
+		Deposits::<T>::insert(who, reason, TrackedDeposit { count, size, amount });
+		Ok(())
+	}
+
+	/// Stop tracking the deposit of `who` for `reason`.
+	fn remove_deposit(who: &T::AccountId, reason: &T::RuntimeHoldReason) {
+		Deposits::<T>::remove(who, reason);
+		ReasonCount::<T>::mutate_exists(who, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	/// Track `amount`, already held from `who` for `reason`, as the deposit for `footprint`.
+	fn adopt(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		footprint: Footprint,
+		amount: BalanceOf<T>,
+	) {
+		let tracked = match Deposits::<T>::get(who, reason) {
+			Some(tracked) => tracked,
+			None => {
+				let count = ReasonCount::<T>::get(who).saturating_add(1);
+				if count > T::MaxReasons::get() {
+					defensive!("`MaxReasons` is lower than the number of participating reasons");
+				}
+				ReasonCount::<T>::insert(who, count);
+				TrackedDeposit::default()
+			},
+		};
+		let Footprint { count, size } = add(tracked.footprint(), footprint);
+		let amount = tracked.amount.saturating_add(amount);
+		Deposits::<T>::insert(who, reason, TrackedDeposit { count, size, amount });
+	}
+
+	/// Hold or release the difference between the `old` and the `new` deposit.
+	fn adjust_hold(
+		who: &T::AccountId,
+		reason: &T::RuntimeHoldReason,
+		old: BalanceOf<T>,
+		new: BalanceOf<T>,
+		burn: bool,
+	) -> DispatchResult {
+		if new > old {
+			T::Currency::hold(reason, who, new - old)?;
+		} else if old > new && burn {
+			T::Currency::burn_held(reason, who, old - new, BestEffort, Force)?;
+		} else if old > new {
+			T::Currency::release(reason, who, old - new, BestEffort)?;
+		}
+		Ok(())
+	}
+}
+
+fn add(a: Footprint, b: Footprint) -> Footprint {
+	Footprint { count: a.count.saturating_add(b.count), size: a.size.saturating_add(b.size) }
+}
+
+fn sub(a: Footprint, b: Footprint) -> Footprint {
+	Footprint { count: a.count.saturating_sub(b.count), size: a.size.saturating_sub(b.size) }
+}
+
+/// A [`Consideration`] holding its deposit through the deposit tracker, for the reason given by
+/// `R`.
+///
+/// The ticket records its own footprint, which is added to the tracked footprint of the account
+/// when it is created and removed from it when it is dropped.
+#[derive(
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T, R))]
+#[codec(mel_bound())]
+pub struct TrackedHold<T, R> {
+	count: u64,
+	size: u64,
+	_phantom: PhantomData<fn() -> (T, R)>,
+}
+
+impl<T, R> TrackedHold<T, R> {
+	fn footprint(&self) -> Footprint {
+		Footprint { count: self.count, size: self.size }
+	}
+}
+
+impl<T, R> From<Footprint> for TrackedHold<T, R> {
+	fn from(footprint: Footprint) -> Self {
+		Self { count: footprint.count, size: footprint.size, _phantom: PhantomData }
+	}
+}
+
+impl<T: Config, R: 'static + Get<T::RuntimeHoldReason>> Consideration<T::AccountId>
+	for TrackedHold<T, R>
+{
+	fn new(who: &T::AccountId, new: Footprint) -> Result<Self, DispatchError> {
+		Pallet::<T>::increase(who, &R::get(), new)?;
+		Ok(new.into())
+	}
+
+	fn update(self, who: &T::AccountId, new: Footprint) -> Result<Self, DispatchError> {
+		let old = self.footprint();
+		Pallet::<T>::update(who, &R::get(), |held| add(sub(held, old), new), false)?;
+		Ok(new.into())
+	}
+
+	fn drop(self, who: &T::AccountId) -> Result<(), DispatchError> {
+		Pallet::<T>::decrease(who, &R::get(), self.footprint())
+	}
+
+	fn burn(self, who: &T::AccountId) {
+		let _ = Pallet::<T>::burn(who, &R::get(), self.footprint());
+	}
+}
+
+impl<T: Config, R: 'static + Get<T::RuntimeHoldReason>> TrackedHold<T, R> {
+	/// Create the ticket for `footprint` of a deposit of `amount` which is already held from `who`
+	/// for the reason given by `R`, without holding anything.
+	///
+	/// This is for the migration of a pallet whose deposits were held for the same reason by
+	/// another [`Consideration`], so that their amounts are tracked. The whole deposit of the
+	/// reason is re-computed at the current price with the next poke.
+	pub fn adopt(who: &T::AccountId, footprint: Footprint, amount: BalanceOf<T>) -> Self {
+		Pallet::<T>::adopt(who, &R::get(), footprint, amount);
+		footprint.into()
+	}
+}
@@ -0,0 +1,168 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for the deposit tracker pallet.
+
+use crate as pallet_deposit_tracker;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, Footprint},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+pub type Block = frame_system::mocking::MockBlock<Test>;
+
+/// A pallet holding deposits through the deposit tracker.
+#[frame_support::pallet]
+pub mod pallet_registry {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A reason for this pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for a registered item.
+		Item,
+		/// The funds are held as deposit for stored data.
+		Blob,
+	}
+}
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		DepositTracker: pallet_deposit_tracker,
+		Registry: pallet_registry,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+	type MaxHolds = ConstU32<2>;
+}
+
+impl pallet_deposit_tracker::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Prices = RegistryPrices;
+	type MaxReasons = MaxReasons;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegistryReasons;
+}
+
+impl pallet_registry::Config for Test {}
+
+parameter_types! {
+	pub static ItemDeposit: u64 = 10;
+	pub static ByteDeposit: u64 = 1;
+	pub static MaxReasons: u32 = 2;
+	pub const ItemReason: RuntimeHoldReason =
+		RuntimeHoldReason::Registry(pallet_registry::HoldReason::Item);
+	pub const BlobReason: RuntimeHoldReason =
+		RuntimeHoldReason::Registry(pallet_registry::HoldReason::Blob);
+}
+
+/// Items cost `ItemDeposit` each plus `ByteDeposit` per byte, blobs twice the byte deposit.
+pub struct RegistryPrices;
+
+impl pallet_deposit_tracker::DepositPrices<RuntimeHoldReason, u64> for RegistryPrices {
+	fn deposit(reason: &RuntimeHoldReason, footprint: Footprint) -> u64 {
+		match reason {
+			RuntimeHoldReason::Registry(pallet_registry::HoldReason::Item) =>
+				footprint.count * ItemDeposit::get() + footprint.size * ByteDeposit::get(),
+			RuntimeHoldReason::Registry(pallet_registry::HoldReason::Blob) =>
+				footprint.size * 2 * ByteDeposit::get(),
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RegistryReasons;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deposit_tracker::BenchmarkHelper<RuntimeHoldReason> for RegistryReasons {
+	fn reason(i: u32) -> RuntimeHoldReason {
+		if i % 2 == 0 {
+			ItemReason::get()
+		} else {
+			BlobReason::get()
+		}
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the deposit tracker pallet.
+
+use crate::{mock::*, Deposits, Error, Event, ReasonCount, TrackedDeposit, TrackedHold};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{InspectHold, MutateHold},
+		Consideration, Footprint,
+	},
+};
+use sp_runtime::TokenError;
+
+type ItemHold = TrackedHold<Test, ItemReason>;
+type BlobHold = TrackedHold<Test, BlobReason>;
+
+fn held(who: u64) -> (u64, u64) {
+	(
+		Balances::balance_on_hold(&ItemReason::get(), &who),
+		Balances::balance_on_hold(&BlobReason::get(), &who),
+	)
+}
+
+#[test]
+fn tracked_holds_aggregate_by_reason() {
+	new_test_ext().execute_with(|| {
+		let first = ItemHold::new(&1, Footprint::from_parts(1, 5)).unwrap();
+		let second = ItemHold::new(&1, Footprint::from_parts(1, 10)).unwrap();
+		let blob = BlobHold::new(&1, Footprint::from_parts(1, 4)).unwrap();
+		assert_eq!(held(1), (35, 8));
+		assert_eq!(
+			Deposits::<Test>::get(1, ItemReason::get()),
+			Some(TrackedDeposit { count: 2, size: 15, amount: 35 }),
+		);
+
+		// updating a ticket only changes its own share of the footprint.
+		let second = second.update(&1, Footprint::from_parts(1, 2)).unwrap();
+		assert_eq!(held(1), (27, 8));
+
+		assert_ok!(first.drop(&1));
+		assert_eq!(held(1), (12, 8));
+		assert_ok!(second.drop(&1));
+		assert_eq!(held(1), (0, 8));
+		assert!(Deposits::<Test>::get(1, ItemReason::get()).is_none());
+
+		// burned deposits are not returned.
+		blob.burn(&1);
+		assert_eq!(held(1), (0, 0));
+		assert_eq!(Balances::free_balance(1), 92);
+		assert!(Deposits::<Test>::get(1, BlobReason::get()).is_none());
+	});
+}
+
+#[test]
+fn tracked_reasons_are_bounded() {
+	new_test_ext().execute_with(|| {
+		MaxReasons::set(1);
+		let item = ItemHold::new(&1, Footprint::from_parts(1, 5)).unwrap();
+		assert_eq!(ReasonCount::<Test>::get(1), 1);
+		assert_noop!(BlobHold::new(&1, Footprint::from_parts(1, 4)), Error::<Test>::TooManyReasons);
+
+		// more deposits for a tracked reason are fine.
+		let other = ItemHold::new(&1, Footprint::from_parts(1, 5)).unwrap();
+		assert_eq!(ReasonCount::<Test>::get(1), 1);
+
+		assert_ok!(item.drop(&1));
+		assert_ok!(other.drop(&1));
+		assert!(!ReasonCount::<Test>::contains_key(1));
+		assert_ok!(BlobHold::new(&1, Footprint::from_parts(1, 4)));
+		assert_eq!(held(1), (0, 8));
+	});
+}
+
+#[test]
+fn adopted_holds_are_tracked() {
+	new_test_ext().execute_with(|| {
+		// held for the same reason before the deposit tracker was used, at an older price.
+		assert_ok!(Balances::hold(&ItemReason::get(), &1, 12));
+		let adopted = ItemHold::adopt(&1, Footprint::from_parts(1, 5), 12);
+		assert_eq!(held(1), (12, 0));
+		assert_eq!(
+			Deposits::<Test>::get(1, ItemReason::get()),
+			Some(TrackedDeposit { count: 1, size: 5, amount: 12 }),
+		);
+		assert_eq!(ReasonCount::<Test>::get(1), 1);
+
+		assert_ok!(DepositTracker::poke_all_deposits(RuntimeOrigin::signed(2), 1));
+		assert_eq!(held(1), (15, 0));
+		assert_ok!(adopted.drop(&1));
+		assert_eq!(held(1), (0, 0));
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn poke_all_deposits_follows_the_prices() {
+	new_test_ext().execute_with(|| {
+		let _item = ItemHold::new(&1, Footprint::from_parts(2, 10)).unwrap();
+		let _blob = BlobHold::new(&1, Footprint::from_parts(1, 5)).unwrap();
+		assert_eq!(held(1), (30, 10));
+
+		// nothing to do at unchanged prices.
+		System::reset_events();
+		assert_ok!(DepositTracker::poke_all_deposits(RuntimeOrigin::signed(2), 1));
+		assert!(System::events().is_empty());
+
+		ItemDeposit::set(20);
+		ByteDeposit::set(0);
+		assert_ok!(DepositTracker::poke_all_deposits(RuntimeOrigin::signed(2), 1));
+		assert_eq!(held(1), (40, 0));
+		assert_eq!(Balances::free_balance(1), 60);
+		System::assert_has_event(
+			Event::Poked { who: 1, reason: ItemReason::get(), old: 30, new: 40 }.into(),
+		);
+		System::assert_has_event(
+			Event::Poked { who: 1, reason: BlobReason::get(), old: 10, new: 0 }.into(),
+		);
+
+		// the tracked footprint is kept, only the amount changes.
+		assert_eq!(
+			Deposits::<Test>::get(1, BlobReason::get()),
+			Some(TrackedDeposit { count: 1, size: 5, amount: 0 }),
+		);
+	});
+}
+
+#[test]
+fn poke_all_deposits_is_atomic() {
+	new_test_ext().execute_with(|| {
+		let _item = ItemHold::new(&1, Footprint::from_parts(1, 10)).unwrap();
+		let _blob = BlobHold::new(&1, Footprint::from_parts(1, 10)).unwrap();
+		assert_eq!(held(1), (20, 20));
+
+		// the blob deposit would be released, but the item deposit can not be afforded.
+		ItemDeposit::set(100);
+		ByteDeposit::set(0);
+		assert_noop!(
+			DepositTracker::poke_all_deposits(RuntimeOrigin::signed(2), 1),
+			TokenError::FundsUnavailable,
+		);
+		assert_eq!(held(1), (20, 20));
+	});
+}
+
+#[test]
+fn poke_all_deposits_requires_a_signed_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DepositTracker::poke_all_deposits(RuntimeOrigin::root(), 1),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+	});
+}
@@ -163,6 +163,11 @@
 //! 2. any assignment is checked to match with [`RoundSnapshot::voters`].
 //! 3. the claimed score is valid, based on the fixed point arithmetic accuracy.
 //!
+//! The check itself is implemented by [`Miner::feasibility_check`], which does not touch storage
+//! and only relies on the [`MinerConfig`]. It can thus be used outside of the runtime, e.g. by
+//! miners to check their solutions against the [`Snapshot`] before submitting them and risking
+//! their deposit. The `check-solution` command of `substrate-frame-cli` wraps it for this purpose.
+//!
 //! ## Accuracy
 //!
 //! The accuracy of the election is configured via [`SolutionAccuracyOf`] which is the accuracy that
@@ -279,8 +284,8 @@ use unsigned::VoterOf;
 pub use weights::WeightInfo;
 
 pub use signed::{
-	BalanceOf, GeometricDepositBase, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission,
-	SignedSubmissionOf, SignedSubmissions, SubmissionIndicesOf,
+	BalanceOf, DecayingDepositBase, GeometricDepositBase, NegativeImbalanceOf, PositiveImbalanceOf,
+	SignedSubmission, SignedSubmissionOf, SignedSubmissions, SubmissionIndicesOf,
 };
 pub use unsigned::{Miner, MinerConfig};
 
@@ -667,6 +672,9 @@ pub mod pallet {
 
 		/// Something that calculates the signed deposit base based on the signed submissions queue
 		/// size.
+		///
+		/// See [`GeometricDepositBase`] and [`DecayingDepositBase`] for the bonding curves provided
+		/// by this pallet.
 		type SignedDepositBase: Convert<usize, BalanceOf<Self>>;
 
 		/// The maximum number of electing voters and electable targets to put in the snapshot.
@@ -1066,7 +1074,8 @@ pub mod pallet {
 			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
 
 			let ejected_a_solution = maybe_removed.is_some();
-			// if we had to remove the weakest solution, unreserve its deposit
+			// if we had to remove the weakest solution, unreserve its deposit. This is the deposit
+			// reserved at the time of its submission, whatever the deposit base is now.
 			if let Some(removed) = maybe_removed {
 				let _remainder = T::Currency::unreserve(&removed.who, removed.deposit);
 				debug_assert!(_remainder.is_zero());
@@ -1335,6 +1344,14 @@ pub mod pallet {
 
 	// `SignedSubmissions` items end here.
 
+	/// The number of consecutive signed phases which ended with an accepted solution, without any
+	/// submission being slashed.
+	///
+	/// Used by [`DecayingDepositBase`] to lower the deposit of signed submissions while miners
+	/// behave.
+	#[pallet::storage]
+	pub type HonestSignedRounds<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// The minimum score that each 'untrusted' solution must attain in order to be considered
 	/// feasible.
 	///
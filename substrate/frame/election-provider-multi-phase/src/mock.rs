@@ -16,7 +16,11 @@
 // limitations under the License.
 
 use super::*;
-use crate::{self as multi_phase, signed::GeometricDepositBase, unsigned::MinerConfig};
+use crate::{
+	self as multi_phase,
+	signed::{DecayingDepositBase, GeometricDepositBase},
+	unsigned::MinerConfig,
+};
 use frame_election_provider_support::{
 	bounds::{DataProviderBounds, ElectionBounds},
 	data_provider, onchain, ElectionDataProvider, NposSolution, SequentialPhragmen,
@@ -290,6 +294,8 @@ parameter_types! {
 	pub static EnableVariableDepositBase: bool = false;
 	pub static SignedFixedDeposit: Balance = 5;
 	pub static SignedDepositIncreaseFactor: Percent = Percent::from_percent(10);
+	pub static SignedDepositDecay: Percent = Percent::from_percent(0);
+	pub static SignedDepositFloor: Balance = 0;
 	pub static SignedDepositByte: Balance = 0;
 	pub static SignedDepositWeight: Balance = 0;
 	pub static SignedRewardBase: Balance = 7;
@@ -421,13 +427,18 @@ impl crate::Config for Runtime {
 }
 
 impl Convert<usize, BalanceOf<Runtime>> for Runtime {
-	/// returns the geometric increase deposit fee if `EnableVariableDepositBase` is set, otherwise
-	/// the fee is `SignedFixedDeposit`.
+	/// returns the geometric increase deposit fee, decaying by `SignedDepositDecay`, if
+	/// `EnableVariableDepositBase` is set, otherwise the fee is `SignedFixedDeposit`.
 	fn convert(queue_len: usize) -> Balance {
 		if !EnableVariableDepositBase::get() {
 			SignedFixedDeposit::get()
 		} else {
-			GeometricDepositBase::<Balance, SignedFixedDeposit, SignedDepositIncreaseFactor>::convert(queue_len)
+			DecayingDepositBase::<
+				Runtime,
+				GeometricDepositBase<Balance, SignedFixedDeposit, SignedDepositIncreaseFactor>,
+				SignedDepositDecay,
+				SignedDepositFloor,
+			>::convert(queue_len)
 		}
 	}
 }
@@ -577,6 +588,11 @@ impl ExtBuilder {
 		<SignedDepositIncreaseFactor>::set(increase);
 		self
 	}
+	pub fn signed_deposit_decay(self, decay: Percent, floor: u64) -> Self {
+		<SignedDepositDecay>::set(decay);
+		<SignedDepositFloor>::set(floor);
+		self
+	}
 	pub fn signed_deposit(self, base: u64, byte: u64, weight: u64) -> Self {
 		<SignedFixedDeposit>::set(base);
 		<SignedDepositByte>::set(byte);
@@ -20,9 +20,9 @@
 use core::marker::PhantomData;
 
 use crate::{
-	unsigned::MinerConfig, Config, ElectionCompute, Pallet, QueuedSolution, RawSolution,
-	ReadySolution, SignedSubmissionIndices, SignedSubmissionNextIndex, SignedSubmissionsMap,
-	SolutionOf, SolutionOrSnapshotSize, Weight, WeightInfo,
+	unsigned::MinerConfig, Config, ElectionCompute, HonestSignedRounds, Pallet, QueuedSolution,
+	RawSolution, ReadySolution, SignedSubmissionIndices, SignedSubmissionNextIndex,
+	SignedSubmissionsMap, SolutionOf, SolutionOrSnapshotSize, Weight, WeightInfo,
 };
 use codec::{Decode, Encode, HasCompact};
 use frame_election_provider_support::NposSolution;
@@ -376,6 +376,38 @@ where
 	}
 }
 
+/// Type that can be used to calculate the deposit base for signed submissions, decaying as miners
+/// behave honestly.
+///
+/// The deposit base given by the `Curve`, e.g. a [`GeometricDepositBase`], is decreased by `Decay`
+/// for each of the [`HonestSignedRounds`], but never below `Floor`. A single slashed submission
+/// restores the full deposit.
+pub struct DecayingDepositBase<T, Curve, Decay, Floor> {
+	_marker: PhantomData<(T, Curve, Decay, Floor)>,
+}
+
+impl<T, Curve, Decay, Floor> Convert<usize, BalanceOf<T>>
+	for DecayingDepositBase<T, Curve, Decay, Floor>
+where
+	T: Config,
+	BalanceOf<T>: FixedPointOperand,
+	Curve: Convert<usize, BalanceOf<T>>,
+	Decay: Get<Percent>,
+	Floor: Get<BalanceOf<T>>,
+{
+	// The deposit of the nth honest round is `curve * (1 - decay)^nth`, but never lower than the
+	// floor, unless the curve itself is.
+	fn convert(queue_len: usize) -> BalanceOf<T> {
+		let deposit = Curve::convert(queue_len);
+		let retained: FixedU128 = FixedU128::from_u32(1) - Decay::get().into();
+		let decayed = retained
+			.saturating_pow(HonestSignedRounds::<T>::get() as usize)
+			.saturating_mul_int(deposit);
+
+		decayed.max(Floor::get().min(deposit))
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	/// `Self` accessor for `SignedSubmission<T>`.
 	pub fn signed_submissions() -> SignedSubmissions<T> {
@@ -402,6 +434,7 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn finalize_signed_phase_internal() -> (Weight, bool) {
 		let mut all_submissions = Self::signed_submissions();
 		let mut found_solution = false;
+		let mut slashed_solution = false;
 		let mut weight = T::DbWeight::get().reads(1);
 
 		let SolutionOrSnapshotSize { voters, targets } =
@@ -442,12 +475,22 @@ impl<T: Config> Pallet<T> {
 				Err(_) => {
 					log!(warn, "finalized_signed: invalid signed submission found, slashing.");
 					Self::finalize_signed_phase_reject_solution(&who, deposit);
+					slashed_solution = true;
 					weight = weight
 						.saturating_add(T::WeightInfo::finalize_signed_phase_reject_solution());
 				},
 			}
 		}
 
+		// A slashed submission resets the decay of the deposit, an honest round continues it.
+		if slashed_solution {
+			HonestSignedRounds::<T>::kill();
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+		} else if found_solution {
+			HonestSignedRounds::<T>::mutate(|rounds| rounds.saturating_inc());
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+		}
+
 		// Any unprocessed solution is pointless to even consider. Feasible or malicious,
 		// they didn't end up being used. Unreserve the bonds.
 		let discarded = all_submissions.len();
@@ -858,6 +901,77 @@ mod tests {
 			});
 	}
 
+	#[test]
+	fn decaying_deposit_base_works() {
+		ExtBuilder::default()
+			.signed_base_deposit(10, true, Percent::from_percent(0))
+			.signed_deposit_decay(Percent::from_percent(50), 3)
+			.build_and_execute(|| {
+				roll_to_signed();
+				assert!(MultiPhase::current_phase().is_signed());
+				let submit =
+					|who| MultiPhase::submit(RuntimeOrigin::signed(who), Box::new(raw_solution()));
+
+				// an honest round halves the deposit.
+				assert_ok!(submit(99));
+				assert_eq!(balances(&99), (90, 10));
+				assert!(MultiPhase::finalize_signed_phase());
+				assert_eq!(HonestSignedRounds::<Runtime>::get(), 1);
+
+				assert_ok!(submit(100));
+				assert_eq!(balances(&100), (95, 5));
+				assert!(MultiPhase::finalize_signed_phase());
+				assert_eq!(HonestSignedRounds::<Runtime>::get(), 2);
+
+				// but never below the floor.
+				assert_ok!(submit(101));
+				assert_eq!(balances(&101), (97, 3));
+
+				// a slashed submission restores the full deposit.
+				let mut solution = raw_solution();
+				solution.score.minimal_stake += 1;
+				assert_ok!(MultiPhase::submit(RuntimeOrigin::signed(102), Box::new(solution)));
+				assert!(MultiPhase::finalize_signed_phase());
+				assert_eq!(balances(&102), (97, 0));
+				assert_eq!(HonestSignedRounds::<Runtime>::get(), 0);
+
+				assert_ok!(submit(103));
+				assert_eq!(balances(&103), (90, 10));
+			});
+	}
+
+	#[test]
+	fn ejected_solution_gets_its_own_deposit_back() {
+		ExtBuilder::default()
+			.signed_base_deposit(10, true, Percent::from_percent(10))
+			.build_and_execute(|| {
+				roll_to_signed();
+				assert!(MultiPhase::current_phase().is_signed());
+
+				for s in 0..SignedMaxSubmissions::get() {
+					let origin = RuntimeOrigin::signed(99 + s as u64);
+					// score is always getting better
+					let minimal_stake = (5 + s).into();
+					let score = ElectionScore { minimal_stake, ..Default::default() };
+					let solution = RawSolution { score, ..Default::default() };
+					assert_ok!(MultiPhase::submit(origin, Box::new(solution)));
+				}
+				assert_eq!(balances(&99), (90, 10));
+				assert_eq!(balances(&103), (86, 14));
+
+				// better, paying the deposit of a full queue.
+				let solution = RawSolution {
+					score: ElectionScore { minimal_stake: 20, ..Default::default() },
+					..Default::default()
+				};
+				assert_ok!(MultiPhase::submit(RuntimeOrigin::signed(999), Box::new(solution)));
+				assert_eq!(balances(&999), (84, 16));
+
+				// the weakest gets back the deposit it paid, not the current one.
+				assert_eq!(balances(&99), (100, 0));
+			});
+	}
+
 	#[test]
 	fn call_fee_refund_is_limited_by_signed_max_refunds() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -733,6 +733,11 @@ impl<T: MinerConfig> Miner<T> {
 	}
 
 	/// Checks the feasibility of a solution.
+	///
+	/// This only depends on its arguments, not on the state of the pallet: `desired_targets`,
+	/// `snapshot`, `current_round` and `minimum_untrusted_score` are the values stored in
+	/// [`crate::DesiredTargets`], [`crate::Snapshot`], [`crate::Round`] and
+	/// [`crate::MinimumUntrustedScore`] when checked on-chain.
 	pub fn feasibility_check(
 		raw_solution: RawSolution<SolutionOf<T>>,
 		compute: ElectionCompute,
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: `submit` was adjusted by hand to read `HonestSignedRounds`, which the
+//! `DecayingDepositBase` of the Substrate node reads to compute the deposit. Re-run the benchmarks
+//! to replace it.
 
 // Executed Command:
 // ./target/production/substrate
@@ -204,13 +208,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: ElectionProviderMultiPhase SignedSubmissionNextIndex (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ElectionProviderMultiPhase SignedSubmissionsMap (r:0 w:1)
 	/// Proof Skipped: ElectionProviderMultiPhase SignedSubmissionsMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ElectionProviderMultiPhase HonestSignedRounds (r:1 w:0)
+	/// Proof Skipped: ElectionProviderMultiPhase HonestSignedRounds (max_values: Some(1), max_size: None, mode: Measured)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `927`
 		//  Estimated: `2412`
 		// Minimum execution time: 52_276_000 picoseconds.
 		Weight::from_parts(53_846_000, 2412)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
@@ -413,13 +419,15 @@ impl WeightInfo for () {
 	/// Proof Skipped: ElectionProviderMultiPhase SignedSubmissionNextIndex (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ElectionProviderMultiPhase SignedSubmissionsMap (r:0 w:1)
 	/// Proof Skipped: ElectionProviderMultiPhase SignedSubmissionsMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ElectionProviderMultiPhase HonestSignedRounds (r:1 w:0)
+	/// Proof Skipped: ElectionProviderMultiPhase HonestSignedRounds (max_values: Some(1), max_size: None, mode: Measured)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `927`
 		//  Estimated: `2412`
 		// Minimum execution time: 52_276_000 picoseconds.
 		Weight::from_parts(53_846_000, 2412)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
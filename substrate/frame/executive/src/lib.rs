@@ -118,11 +118,13 @@
 
 use codec::{Codec, Encode};
 use frame_support::{
-	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+	dispatch::{
+		extract_actual_weight, DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo,
+	},
 	pallet_prelude::InvalidTransaction,
 	traits::{
-		BeforeAllRuntimeMigrations, EnsureInherentsAreFirst, ExecuteBlock, OffchainWorker,
-		OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade,
+		BeforeAllRuntimeMigrations, ConstBool, EnsureInherentsAreFirst, ExecuteBlock, Get,
+		OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade,
 	},
 	weights::Weight,
 };
@@ -167,6 +169,9 @@ pub type OriginOf<E, C> = <CallOf<E, C> as Dispatchable>::RuntimeOrigin;
 ///   used to call hooks e.g. `on_initialize`.
 /// - `OnRuntimeUpgrade`: Custom logic that should be called after a runtime upgrade. Modules are
 ///   already called by `AllPalletsWithSystem`. It will be called before all modules will be called.
+/// - `RecordWeightReconciliation`: Whether to record the declared and actually consumed weight of
+///   the dispatches of each block, see [`frame_system::Pallet::weight_reconciliation`]. Off by
+///   default, as it costs a storage write per dispatch.
 pub struct Executive<
 	System,
 	Block,
@@ -174,6 +179,7 @@ pub struct Executive<
 	UnsignedValidator,
 	AllPalletsWithSystem,
 	OnRuntimeUpgrade = (),
+	RecordWeightReconciliation = ConstBool<false>,
 >(
 	PhantomData<(
 		System,
@@ -182,6 +188,7 @@ pub struct Executive<
 		UnsignedValidator,
 		AllPalletsWithSystem,
 		OnRuntimeUpgrade,
+		RecordWeightReconciliation,
 	)>,
 );
 
@@ -200,8 +207,17 @@ impl<
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
+		RecordWeightReconciliation: Get<bool>,
 	> ExecuteBlock<Block>
-	for Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+	for Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		RecordWeightReconciliation,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -218,6 +234,7 @@ where
 			UnsignedValidator,
 			AllPalletsWithSystem,
 			COnRuntimeUpgrade,
+			RecordWeightReconciliation,
 		>::execute_block(block);
 	}
 }
@@ -240,7 +257,17 @@ impl<
 			+ TryState<BlockNumberFor<System>>
 			+ TryDecodeEntireStorage,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		RecordWeightReconciliation: Get<bool>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		RecordWeightReconciliation,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -293,6 +320,13 @@ where
 			let dispatch_info = xt.get_dispatch_info();
 			let r = Applyable::apply::<UnsignedValidator>(xt, &dispatch_info, encoded_len)?;
 
+			if RecordWeightReconciliation::get() {
+				<frame_system::Pallet<System>>::note_dispatch_weight(
+					dispatch_info.class,
+					dispatch_info.weight,
+					extract_actual_weight(&r, &dispatch_info),
+				);
+			}
 			<frame_system::Pallet<System>>::note_applied_extrinsic(&r, dispatch_info);
 
 			Ok(r.map(|_| ()).map_err(|e| e.error))
@@ -450,7 +484,17 @@ impl<
 			+ OnFinalize<BlockNumberFor<System>>
 			+ OffchainWorker<BlockNumberFor<System>>,
 		COnRuntimeUpgrade: OnRuntimeUpgrade,
-	> Executive<System, Block, Context, UnsignedValidator, AllPalletsWithSystem, COnRuntimeUpgrade>
+		RecordWeightReconciliation: Get<bool>,
+	>
+	Executive<
+		System,
+		Block,
+		Context,
+		UnsignedValidator,
+		AllPalletsWithSystem,
+		COnRuntimeUpgrade,
+		RecordWeightReconciliation,
+	>
 where
 	Block::Extrinsic: Checkable<Context> + Codec,
 	CheckedOf<Block::Extrinsic, Context>: Applyable + GetDispatchInfo,
@@ -648,6 +692,13 @@ where
 			return Err(InvalidTransaction::BadMandatory.into())
 		}
 
+		if RecordWeightReconciliation::get() {
+			<frame_system::Pallet<System>>::note_dispatch_weight(
+				dispatch_info.class,
+				dispatch_info.weight,
+				extract_actual_weight(&r, &dispatch_info),
+			);
+		}
 		<frame_system::Pallet<System>>::note_applied_extrinsic(&r, dispatch_info);
 
 		Ok(r.map(|_| ()).map_err(|e| e.error))
@@ -845,6 +896,11 @@ mod tests {
 				Ok(())
 			}
 
+			pub fn refund_weight(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+				frame_system::ensure_signed(origin)?;
+				Ok(Some(Weight::from_parts(1, 0)).into())
+			}
+
 			pub fn calculate_storage_root(_origin: OriginFor<T>) -> DispatchResult {
 				let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
 				sp_io::storage::set("storage_root".as_bytes(), &root);
@@ -1019,6 +1075,16 @@ mod tests {
 		Runtime,
 		AllPalletsWithSystem,
 		CustomOnRuntimeUpgrade,
+		ConstBool<true>,
+	>;
+
+	type ExecutiveWithoutWeightReconciliation = super::Executive<
+		Runtime,
+		Block<TestXt>,
+		ChainContext<Runtime>,
+		Runtime,
+		AllPalletsWithSystem,
+		CustomOnRuntimeUpgrade,
 	>;
 
 	fn extra(nonce: u64, fee: Balance) -> SignedExtra {
@@ -1292,6 +1358,77 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn weight_reconciliation_is_stored_per_class() {
+		let xt =
+			TestXt::new(RuntimeCall::Custom(custom::Call::refund_weight {}), sign_extra(1, 0, 0));
+		let x1 = TestXt::new(
+			RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 33, value: 0 }),
+			sign_extra(1, 1, 0),
+		);
+		let (len, len1) = (xt.encode().len() as u64, x1.encode().len() as u64);
+		let mut t = new_test_ext(1);
+		t.execute_with(|| {
+			let header = |n| {
+				Header::new(
+					n,
+					H256::default(),
+					H256::default(),
+					[69u8; 32].into(),
+					Digest::default(),
+				)
+			};
+			Executive::initialize_block(&header(1));
+			assert!(Executive::apply_extrinsic(xt).unwrap().is_ok());
+			assert!(Executive::apply_extrinsic(x1).unwrap().is_ok());
+
+			// Declared weight for `TestXt` == encoded length, `refund_weight` consumes 1.
+			let report = <frame_system::Pallet<Runtime>>::weight_reconciliation();
+			assert_eq!(
+				*report.get(DispatchClass::Normal),
+				frame_system::DispatchWeightReport {
+					dispatches: 2,
+					declared: Weight::from_parts(len + len1, 0),
+					actual: Weight::from_parts(1 + len1, 0),
+				}
+			);
+			assert_eq!(
+				report.get(DispatchClass::Normal).overestimation(),
+				Weight::from_parts(len - 1, 0)
+			);
+			assert_eq!(*report.get(DispatchClass::Operational), Default::default());
+
+			// The report is kept after the block is finalized, and cleaned up on the next one.
+			let _ = <frame_system::Pallet<Runtime>>::finalize();
+			assert_eq!(<frame_system::Pallet<Runtime>>::weight_reconciliation(), report);
+			Executive::initialize_block(&header(2));
+			assert_eq!(
+				<frame_system::Pallet<Runtime>>::weight_reconciliation(),
+				Default::default()
+			);
+		});
+	}
+
+	#[test]
+	fn weight_reconciliation_is_not_stored_by_default() {
+		let xt =
+			TestXt::new(RuntimeCall::Custom(custom::Call::refund_weight {}), sign_extra(1, 0, 0));
+		new_test_ext(1).execute_with(|| {
+			ExecutiveWithoutWeightReconciliation::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+			assert!(ExecutiveWithoutWeightReconciliation::apply_extrinsic(xt).unwrap().is_ok());
+			assert_eq!(
+				<frame_system::Pallet<Runtime>>::weight_reconciliation(),
+				Default::default()
+			);
+		});
+	}
+
 	#[test]
 	fn validate_unsigned() {
 		let valid = TestXt::new(RuntimeCall::Custom(custom::Call::allowed_unsigned {}), None);
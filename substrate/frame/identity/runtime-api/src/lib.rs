@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for identity pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for moving identities between chains.
+	pub trait IdentityApi<AccountId, BlockNumber>
+		where
+			AccountId: Codec,
+			BlockNumber: Codec,
+	{
+		/// Returns the SCALE encoded, versioned `IdentityBundle` of the identity of `who` and its
+		/// sub-identities, or `None` if `who` has no identity.
+		///
+		/// The bundle can be imported on another chain with the `import_identity_bundle` call of
+		/// the identity pallet, once `who` signed the `bundle_signing_payload` of that chain.
+		fn export_identity(who: AccountId) -> Option<Vec<u8>>;
+
+		/// Returns at most `limit` pending judgement requests of the registrar `registrar`, with
+		/// the block they were requested in, starting after the request of `start_after`.
+		fn pending_judgement_requests(
+			registrar: u32,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, BlockNumber)>;
+	}
+}
@@ -28,6 +28,7 @@ use frame_benchmarking::{
 use frame_support::{
 	ensure,
 	traits::{EnsureOrigin, Get},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
@@ -64,7 +65,7 @@ fn create_sub_accounts<T: Config>(
 ) -> Result<Vec<(T::AccountId, Data)>, &'static str> {
 	let mut subs = Vec::new();
 	let who_origin = RawOrigin::Signed(who.clone());
-	let data = Data::Raw(vec![0; 32].try_into().unwrap());
+	let data = Data::worst_case();
 
 	for i in 0..s {
 		let sub_account = account("sub", i, SEED);
@@ -460,7 +461,7 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 		let _ = add_sub_accounts::<T>(&caller, s)?;
 		let sub = account("new_sub", 0, SEED);
-		let data = Data::Raw(vec![0; 32].try_into().unwrap());
+		let data = Data::worst_case();
 
 		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == s, "Subs not set.");
 
@@ -511,7 +512,7 @@ mod benchmarks {
 		Identity::<T>::add_sub(
 			sup_origin,
 			T::Lookup::unlookup(caller.clone()),
-			Data::Raw(vec![0; 32].try_into().unwrap()),
+			Data::worst_case(),
 		)?;
 		ensure!(SuperOf::<T>::contains_key(&caller), "Sub doesn't exists");
 
@@ -523,5 +524,111 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn import_identity_bundle(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+		s: Linear<0, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let public = T::BenchmarkHelper::generate_key();
+		let who = public.clone().into_account();
+		let _ = T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value());
+
+		let judgements = (0..r).map(|i| (i, Judgement::Reasonable)).collect::<Vec<_>>();
+		let registration = Registration {
+			judgements: BoundedVec::truncate_from(judgements),
+			deposit: Zero::zero(),
+			info: T::IdentityInformation::create_identity_info(),
+		};
+		let data = Data::worst_case();
+		let subs = (0..s).map(|i| (account("sub", i, SEED), data.clone())).collect::<Vec<_>>();
+		let subs = BoundedVec::truncate_from(subs);
+		let bundle = IdentityBundle::V1 { who: who.clone(), registration, subs };
+		let expiry = frame_system::Pallet::<T>::block_number();
+		let payload = Identity::<T>::bundle_signing_payload(&bundle, 0, expiry);
+		let signature = T::BenchmarkHelper::sign(&public, &payload);
+		let origin =
+			T::MigratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(bundle), 0, expiry, signature);
+
+		ensure!(IdentityOf::<T>::contains_key(&who), "Identity not imported");
+		ensure!(SubsOf::<T>::get(&who).1.len() as u32 == s, "Subs not imported");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_sub() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (sub, _) = add_sub_accounts::<T>(&caller, 1)?.remove(0);
+		let new_parent: T::AccountId = account("new_parent", 0, SEED);
+		let _ = create_sub_accounts::<T>(&new_parent, 0)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(sub.clone()),
+			T::Lookup::unlookup(new_parent),
+		);
+
+		ensure!(PendingSubTransfers::<T>::contains_key(&sub), "Transfer not proposed");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_sub_transfer(
+		s: Linear<1, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let parent: T::AccountId = account("parent", 0, SEED);
+		let (sub, _) = add_sub_accounts::<T>(&parent, s)?.remove(0);
+
+		// The new parent has `s - 1` subs of its own.
+		let _ = create_sub_accounts::<T>(&caller, 0)?;
+		let data = Data::worst_case();
+		let new_subs = (1..s).map(|i| (account("new_sub", i, SEED), data.clone())).collect();
+		Identity::<T>::set_subs(RawOrigin::Signed(caller.clone()).into(), new_subs)?;
+
+		Identity::<T>::transfer_sub(
+			RawOrigin::Signed(parent).into(),
+			T::Lookup::unlookup(sub.clone()),
+			T::Lookup::unlookup(caller.clone()),
+		)?;
+		Identity::<T>::accept_sub_transfer(
+			RawOrigin::Signed(sub.clone()).into(),
+			T::Lookup::unlookup(sub.clone()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(sub.clone()));
+
+		ensure!(SuperOf::<T>::get(&sub).map_or(false, |x| x.0 == caller), "Sub not transferred");
+		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == s, "Subs not updated");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_sub_transfer() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (sub, _) = add_sub_accounts::<T>(&caller, 1)?.remove(0);
+		let new_parent: T::AccountId = account("new_parent", 0, SEED);
+		let _ = create_sub_accounts::<T>(&new_parent, 0)?;
+		Identity::<T>::transfer_sub(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(sub.clone()),
+			T::Lookup::unlookup(new_parent),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(sub.clone()));
+
+		ensure!(!PendingSubTransfers::<T>::contains_key(&sub), "Transfer not cancelled");
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -76,6 +76,7 @@ impl TypeInfo for IdentityField {
 )]
 #[codec(mel_bound())]
 #[cfg_attr(test, derive(frame_support::DefaultNoBound))]
+#[cfg_attr(feature = "runtime-benchmarks", derive(frame_benchmarking::v2::WorstCase))]
 #[scale_info(skip_type_params(FieldLimit))]
 pub struct IdentityInfo<FieldLimit: Get<u32>> {
 	/// Additional fields of the identity that are not catered for with the struct's explicit
@@ -132,21 +133,7 @@ impl<FieldLimit: Get<u32> + 'static> IdentityInformationProvider for IdentityInf
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_identity_info() -> Self {
-		let data = Data::Raw(vec![0; 32].try_into().unwrap());
-
-		IdentityInfo {
-			additional: vec![(data.clone(), data.clone()); FieldLimit::get().try_into().unwrap()]
-				.try_into()
-				.unwrap(),
-			display: data.clone(),
-			legal: data.clone(),
-			web: data.clone(),
-			riot: data.clone(),
-			email: data.clone(),
-			pgp_fingerprint: Some([0; 20]),
-			image: data.clone(),
-			twitter: data,
-		}
+		frame_benchmarking::v2::WorstCase::worst_case()
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -57,6 +57,10 @@
 //! * `remove_sub` - Remove a sub-identity of an identity.
 //! * `rename_sub` - Rename a sub-identity of an identity.
 //! * `quit_sub` - Remove a sub-identity of an identity (called by the sub-identity).
+//! * `transfer_sub` - Propose to transfer a sub-identity to another identity.
+//! * `accept_sub_transfer` - Accept the transfer of a sub-identity (called by the new parent
+//!   identity and by the sub-identity).
+//! * `cancel_sub_transfer` - Cancel the transfer of a sub-identity.
 //!
 //! #### For registrars
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
@@ -67,6 +71,10 @@
 //! * `add_registrar` - Add a new registrar to the system.
 //! * `kill_identity` - Forcibly remove the associated identity; the deposit is lost.
 //!
+//! #### For migrators
+//! * `import_identity_bundle` - Import an identity exported from another chain with
+//!   [`Pallet::export_identity`], as signed by its owner.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
 
@@ -74,6 +82,7 @@
 
 mod benchmarking;
 pub mod legacy;
+pub mod migration;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -84,14 +93,19 @@ use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
 	traits::{BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{
+	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
 };
-use sp_runtime::traits::{AppendZerosInput, Hash, Saturating, StaticLookup, Zero};
-use sp_std::prelude::*;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Data, IdentityBundle, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo,
+	Registration, RequestsMigrationCursor, SubTransfer,
 };
 
 type BalanceOf<T> =
@@ -101,6 +115,45 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// The context of the payload signed to import an [`IdentityBundle`], see
+/// [`Pallet::bundle_signing_payload`].
+pub const BUNDLE_SIGNING_CONTEXT: &[u8] = b"identity-bundle";
+
+/// The [`IdentityBundle`] of the identities of `T`.
+pub type IdentityBundleOf<T> = IdentityBundle<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as Config>::MaxRegistrars,
+	<T as Config>::IdentityInformation,
+	<T as Config>::MaxSubAccounts,
+>;
+
+/// Helper to sign identity bundles in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature> {
+	/// Generate a new key pair, returning its public key.
+	fn generate_key() -> Public;
+
+	/// Sign `message` with the key pair of `public`, as returned by `generate_key`.
+	fn sign(public: &Public, message: &[u8]) -> Signature;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<sp_runtime::MultiSigner, sp_runtime::MultiSignature> for () {
+	fn generate_key() -> sp_runtime::MultiSigner {
+		sp_io::crypto::sr25519_generate(0.into(), None).into()
+	}
+
+	fn sign(public: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+		let sp_runtime::MultiSigner::Sr25519(public) = public else {
+			panic!("only sr25519 keys are generated; qed")
+		};
+		sp_io::crypto::sr25519_sign(0.into(), public, message)
+			.expect("the key was generated in the keystore; qed")
+			.into()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -117,10 +170,12 @@ pub mod pallet {
 
 		/// The amount held on deposit for a registered identity
 		#[pallet::constant]
+		#[pallet::dynamic]
 		type BasicDeposit: Get<BalanceOf<Self>>;
 
 		/// The amount held on deposit per encoded byte for a registered identity.
 		#[pallet::constant]
+		#[pallet::dynamic]
 		type ByteDeposit: Get<BalanceOf<Self>>;
 
 		/// The amount held on deposit for a registered subaccount. This should account for the fact
@@ -150,11 +205,28 @@ pub mod pallet {
 		/// The origin which may add or remove registrars. Root can always do this.
 		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which may import identities exported from another chain.
+		type MigratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Signature type proving that the owner of an identity agreed to import it.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// Public key that corresponds to an on-chain `Self::AccountId`.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Helper to sign identity bundles in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::SigningPublicKey, Self::OffchainSignature>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Information that is pertinent to identify the entity behind an account.
@@ -192,6 +264,42 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The judgement requests that are waiting for a registrar, keyed by the index of the
+	/// registrar and the requesting account, with the block in which the judgement was requested.
+	///
+	/// This mirrors the `FeePaid` judgements of `IdentityOf`, so that registrars can find the
+	/// requests addressed to them with [`Pallet::pending_requests`].
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type PendingRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RegistrarIndex,
+		Twox64Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The identity after which [`migration::v1::MigrateToV1`] continues to fill
+	/// [`PendingRequests`]. Absent once all identities were migrated.
+	#[pallet::storage]
+	pub type RequestsMigration<T: Config> =
+		StorageValue<_, RequestsMigrationCursor<T::AccountId>, OptionQuery>;
+
+	/// The nonce with which the next identity bundle of an account must be signed to be imported,
+	/// i.e. the number of bundles of the account imported so far.
+	#[pallet::storage]
+	pub type BundleNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The transfers of sub-identities to another identity that are waiting to be accepted, keyed
+	/// by the sub-account. A transfer is dropped once its sub-identity is removed.
+	#[pallet::storage]
+	pub type PendingSubTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SubTransfer<T::AccountId>, OptionQuery>;
+
 	/// The set of registrars. Not expected to get very big as can only be added through a
 	/// special origin (likely a council motion).
 	///
@@ -249,6 +357,20 @@ pub mod pallet {
 		JudgementForDifferentIdentity,
 		/// Error that occurs when there is an issue paying for judgement.
 		JudgementPaymentFailed,
+		/// The signature of the identity bundle is invalid.
+		InvalidSignature,
+		/// The identity bundle was signed with another nonce than the next one of its owner.
+		InvalidNonce,
+		/// The identity bundle expired.
+		BundleExpired,
+		/// The identity bundle lists a sub-account more than once.
+		DuplicateSubAccount,
+		/// No transfer of the sub-account is pending.
+		NoPendingTransfer,
+		/// Sender is not a party to the transfer of the sub-account.
+		NotTransferParty,
+		/// The transfer was already accepted by the sender.
+		AlreadyAccepted,
 	}
 
 	#[pallet::event]
@@ -275,6 +397,33 @@ pub mod pallet {
 		/// A sub-identity was cleared, and the given deposit repatriated from the
 		/// main identity account to the sub-identity account.
 		SubIdentityRevoked { sub: T::AccountId, main: T::AccountId, deposit: BalanceOf<T> },
+		/// An identity was imported from another chain and the given deposit reserved.
+		IdentityImported { who: T::AccountId, deposit: BalanceOf<T> },
+		/// The transfer of a sub-identity to a new main identity was proposed.
+		SubTransferProposed { sub: T::AccountId, main: T::AccountId, new_main: T::AccountId },
+		/// The transfer of a sub-identity was accepted by one of its parties.
+		SubTransferAccepted { sub: T::AccountId, who: T::AccountId },
+		/// The transfer of a sub-identity was cancelled.
+		SubTransferCancelled { sub: T::AccountId },
+		/// A sub-identity was transferred to a new main identity, which now pays its deposit.
+		SubIdentityTransferred {
+			sub: T::AccountId,
+			old_main: T::AccountId,
+			new_main: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			migration::v1::migrate_requests::<T>(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -399,8 +548,10 @@ pub mod pallet {
 			let (old_deposit, old_ids) = <SubsOf<T>>::get(&sender);
 			let new_deposit = Self::subs_deposit(subs.len() as u32);
 
-			let not_other_sub =
-				subs.iter().filter_map(|i| SuperOf::<T>::get(&i.0)).all(|i| i.0 == sender);
+			let not_other_sub = SuperOf::<T>::get_many(subs.iter().map(|i| &i.0))
+				.into_iter()
+				.flatten()
+				.all(|i| i.0 == sender);
 			ensure!(not_other_sub, Error::<T>::AlreadyClaimed);
 
 			if old_deposit < new_deposit {
@@ -413,6 +564,7 @@ pub mod pallet {
 
 			for s in old_ids.iter() {
 				<SuperOf<T>>::remove(s);
+				<PendingSubTransfers<T>>::remove(s);
 			}
 			let mut ids = BoundedVec::<T::AccountId, T::MaxSubAccounts>::default();
 			for (id, name) in subs {
@@ -456,7 +608,9 @@ pub mod pallet {
 			let deposit = id.total_deposit().saturating_add(subs_deposit);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
+				<PendingSubTransfers<T>>::remove(sub);
 			}
+			Self::remove_pending_requests(&sender, &id);
 
 			let err_amount = T::Currency::unreserve(&sender, deposit);
 			debug_assert!(err_amount.is_zero());
@@ -519,6 +673,11 @@ pub mod pallet {
 
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, id);
+			<PendingRequests<T>>::insert(
+				reg_index,
+				&sender,
+				frame_system::Pallet::<T>::block_number(),
+			);
 
 			Self::deposit_event(Event::JudgementRequested {
 				who: sender,
@@ -561,6 +720,7 @@ pub mod pallet {
 			debug_assert!(err_amount.is_zero());
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, id);
+			<PendingRequests<T>>::remove(reg_index, &sender);
 
 			Self::deposit_event(Event::JudgementUnrequested {
 				who: sender,
@@ -714,6 +874,7 @@ pub mod pallet {
 							BalanceStatus::Free,
 						)
 						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+						<PendingRequests<T>>::remove(reg_index, &target);
 					}
 					id.judgements[position] = item
 				},
@@ -761,7 +922,9 @@ pub mod pallet {
 			let deposit = id.total_deposit().saturating_add(subs_deposit);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
+				<PendingSubTransfers<T>>::remove(sub);
 			}
+			Self::remove_pending_requests(&target, &id);
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
@@ -846,6 +1009,7 @@ pub mod pallet {
 			let (sup, _) = SuperOf::<T>::get(&sub).ok_or(Error::<T>::NotSub)?;
 			ensure!(sup == sender, Error::<T>::NotOwned);
 			SuperOf::<T>::remove(&sub);
+			PendingSubTransfers::<T>::remove(&sub);
 			SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
 				sub_ids.retain(|x| x != &sub);
 				let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
@@ -872,6 +1036,7 @@ pub mod pallet {
 		pub fn quit_sub(origin: OriginFor<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let (sup, _) = SuperOf::<T>::take(&sender).ok_or(Error::<T>::NotSub)?;
+			PendingSubTransfers::<T>::remove(&sender);
 			SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
 				sub_ids.retain(|x| x != &sender);
 				let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
@@ -886,19 +1051,270 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Import an identity exported from another chain, together with its sub-identities.
+		///
+		/// Payment: The deposits for the identity and the sub-identities are reserved from the
+		/// account owning the identity, as if it had set them itself.
+		///
+		/// The dispatch origin for this call must be `T::MigratorOrigin` and the `bundle` must be
+		/// signed by the account owning the identity.
+		///
+		/// - `bundle`: The identity, as returned by [`Pallet::export_identity`] on the source
+		///   chain.
+		/// - `nonce`: The [`BundleNonce`] of the owner of `bundle` on this chain.
+		/// - `expiry`: The last block of this chain in which `bundle` may be imported.
+		/// - `signature`: The signature of [`Pallet::bundle_signing_payload`] by the owner of
+		///   `bundle`, which binds it to this chain, `nonce` and `expiry` so it can not be
+		///   replayed.
+		///
+		/// Judgements with a pending fee are dropped, as the fee stays on the source chain. Other
+		/// judgements are kept as they are, so the registrars are expected to have the same
+		/// indices on both chains.
+		///
+		/// Emits `IdentityImported` if successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::import_identity_bundle(
+			T::MaxRegistrars::get(),
+			T::MaxSubAccounts::get(),
+		))]
+		pub fn import_identity_bundle(
+			origin: OriginFor<T>,
+			bundle: Box<IdentityBundleOf<T>>,
+			nonce: u32,
+			expiry: BlockNumberFor<T>,
+			signature: T::OffchainSignature,
+		) -> DispatchResultWithPostInfo {
+			T::MigratorOrigin::ensure_origin(origin)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= expiry,
+				Error::<T>::BundleExpired
+			);
+			ensure!(nonce == BundleNonce::<T>::get(bundle.who()), Error::<T>::InvalidNonce);
+			let payload = Self::bundle_signing_payload(&bundle, nonce, expiry);
+			ensure!(signature.verify(&payload[..], bundle.who()), Error::<T>::InvalidSignature);
+
+			let IdentityBundle::V1 { who, registration, subs } = *bundle;
+			ensure!(!IdentityOf::<T>::contains_key(&who), Error::<T>::AlreadyClaimed);
+			ensure!(!SubsOf::<T>::contains_key(&who), Error::<T>::AlreadyClaimed);
+			let distinct_subs = subs.iter().map(|(sub, _)| sub).collect::<BTreeSet<_>>();
+			ensure!(distinct_subs.len() == subs.len(), Error::<T>::DuplicateSubAccount);
+			let subs_claimed = SuperOf::<T>::get_many(subs.iter().map(|(sub, _)| sub))
+				.iter()
+				.any(Option::is_some);
+			ensure!(!subs_claimed, Error::<T>::AlreadyClaimed);
+
+			let mut judgements = registration.judgements;
+			judgements.retain(|(_, judgement)| !judgement.has_deposit());
+			let encoded_byte_size = registration.info.encoded_size() as u32;
+			let byte_deposit =
+				T::ByteDeposit::get().saturating_mul(<BalanceOf<T>>::from(encoded_byte_size));
+			let id_deposit = T::BasicDeposit::get().saturating_add(byte_deposit);
+			let subs_deposit = Self::subs_deposit(subs.len() as u32);
+			let deposit = id_deposit.saturating_add(subs_deposit);
+			T::Currency::reserve(&who, deposit)?;
+
+			let registrars = judgements.len() as u32;
+			<IdentityOf<T>>::insert(
+				&who,
+				Registration { judgements, deposit: id_deposit, info: registration.info },
+			);
+			let mut ids = BoundedVec::<T::AccountId, T::MaxSubAccounts>::default();
+			for (id, name) in subs {
+				<SuperOf<T>>::insert(&id, (who.clone(), name));
+				ids.try_push(id).expect("subs length is bounded by T::MaxSubAccounts; qed");
+			}
+			let new_subs = ids.len() as u32;
+			if !ids.is_empty() {
+				<SubsOf<T>>::insert(&who, (subs_deposit, ids));
+			}
+
+			BundleNonce::<T>::insert(&who, nonce.saturating_add(1));
+
+			Self::deposit_event(Event::IdentityImported { who, deposit });
+
+			Ok(Some(T::WeightInfo::import_identity_bundle(registrars, new_subs)).into())
+		}
+
+		/// Propose to transfer the given sub-account of the sender to `new_parent`.
+		///
+		/// The sub-identity keeps its name. The transfer happens once both `new_parent` and `sub`
+		/// accept it with `accept_sub_transfer`. Proposing another transfer of `sub` replaces the
+		/// pending one.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// sub identity of `sub`.
+		///
+		/// Emits `SubTransferProposed` if successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::transfer_sub())]
+		pub fn transfer_sub(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+			new_parent: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			let sub = T::Lookup::lookup(sub)?;
+			let new_parent = T::Lookup::lookup(new_parent)?;
+			let (sup, _) = SuperOf::<T>::get(&sub).ok_or(Error::<T>::NotSub)?;
+			ensure!(sup == sender, Error::<T>::NotOwned);
+			ensure!(new_parent != sender && new_parent != sub, Error::<T>::InvalidTarget);
+			ensure!(IdentityOf::<T>::contains_key(&new_parent), Error::<T>::NoIdentity);
+
+			let transfer = SubTransfer {
+				parent: sender.clone(),
+				new_parent: new_parent.clone(),
+				new_parent_accepted: false,
+				sub_accepted: false,
+			};
+			PendingSubTransfers::<T>::insert(&sub, transfer);
+
+			Self::deposit_event(Event::SubTransferProposed {
+				sub,
+				main: sender,
+				new_main: new_parent,
+			});
+			Ok(())
+		}
+
+		/// Accept the pending transfer of `sub` to a new parent identity.
+		///
+		/// Once both the new parent and the sub-account accepted, the sub-identity is moved to the
+		/// new parent. The proposing parent must still own the sub-identity at that point.
+		///
+		/// Payment: `SubAccountDeposit` is reserved from the new parent, and the deposit of the
+		/// sub-identity is returned to the old parent.
+		///
+		/// The dispatch origin for this call must be _Signed_ by either the new parent or `sub`.
+		///
+		/// Emits `SubTransferAccepted`, and `SubIdentityTransferred` on the second acceptance.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::accept_sub_transfer(T::MaxSubAccounts::get()))]
+		pub fn accept_sub_transfer(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let sub = T::Lookup::lookup(sub)?;
+			let mut transfer =
+				PendingSubTransfers::<T>::get(&sub).ok_or(Error::<T>::NoPendingTransfer)?;
+			let accepted = if sender == transfer.new_parent {
+				&mut transfer.new_parent_accepted
+			} else if sender == sub {
+				&mut transfer.sub_accepted
+			} else {
+				return Err(Error::<T>::NotTransferParty.into())
+			};
+			ensure!(!*accepted, Error::<T>::AlreadyAccepted);
+			*accepted = true;
+			Self::deposit_event(Event::SubTransferAccepted { sub: sub.clone(), who: sender });
+
+			if transfer.new_parent_accepted && transfer.sub_accepted {
+				PendingSubTransfers::<T>::remove(&sub);
+				Self::do_transfer_sub(sub, transfer)
+			} else {
+				PendingSubTransfers::<T>::insert(&sub, transfer);
+				Ok(())
+			}
+		}
+
+		/// Cancel the pending transfer of `sub` to a new parent identity.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the parent that proposed the
+		/// transfer, the new parent or `sub`.
+		///
+		/// Emits `SubTransferCancelled` if successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::cancel_sub_transfer())]
+		pub fn cancel_sub_transfer(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let sub = T::Lookup::lookup(sub)?;
+			let transfer =
+				PendingSubTransfers::<T>::get(&sub).ok_or(Error::<T>::NoPendingTransfer)?;
+			ensure!(
+				sender == transfer.parent || sender == transfer.new_parent || sender == sub,
+				Error::<T>::NotTransferParty
+			);
+			PendingSubTransfers::<T>::remove(&sub);
+
+			Self::deposit_event(Event::SubTransferCancelled { sub });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 	/// Get the subs of an account.
 	pub fn subs(who: &T::AccountId) -> Vec<(T::AccountId, Data)> {
-		SubsOf::<T>::get(who)
-			.1
-			.into_iter()
-			.filter_map(|a| SuperOf::<T>::get(&a).map(|x| (a, x.1)))
+		let subs = SubsOf::<T>::get(who).1;
+		let supers = SuperOf::<T>::get_many(subs.iter());
+		subs.into_iter()
+			.zip(supers)
+			.filter_map(|(a, maybe_super)| maybe_super.map(|x| (a, x.1)))
 			.collect()
 	}
 
+	/// Export the identity of `who` together with its sub-identities, or `None` if `who` has no
+	/// identity.
+	///
+	/// The bundle can be imported on another chain with `import_identity_bundle`, once signed by
+	/// `who`.
+	pub fn export_identity(who: &T::AccountId) -> Option<IdentityBundleOf<T>> {
+		let registration = IdentityOf::<T>::get(who)?;
+		let subs = BoundedVec::truncate_from(Self::subs(who));
+		Some(IdentityBundle::V1 { who: who.clone(), registration, subs })
+	}
+
+	/// The payload the owner of `bundle` signs so that it may be imported on this chain with
+	/// `import_identity_bundle`, with the given `nonce` and until block `expiry`.
+	///
+	/// The payload is domain separated by [`BUNDLE_SIGNING_CONTEXT`] and the genesis hash of this
+	/// chain, so the signature is valid for this chain only.
+	pub fn bundle_signing_payload(
+		bundle: &IdentityBundleOf<T>,
+		nonce: u32,
+		expiry: BlockNumberFor<T>,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(BUNDLE_SIGNING_CONTEXT, genesis_hash, nonce, expiry, bundle).encode()
+	}
+
+	/// The judgement requests waiting for the registrar `reg_index`, with the block in which they
+	/// were requested.
+	///
+	/// At most `limit` requests are returned, starting after the request of `start_after`, so that
+	/// the requests can be paged through without reading all of them. The requests are in storage
+	/// order, not in the order they were requested in.
+	pub fn pending_requests(
+		reg_index: RegistrarIndex,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, BlockNumberFor<T>)> {
+		let requests = match start_after {
+			Some(who) => PendingRequests::<T>::iter_prefix_from(
+				reg_index,
+				PendingRequests::<T>::hashed_key_for(reg_index, who),
+			),
+			None => PendingRequests::<T>::iter_prefix(reg_index),
+		};
+		requests.take(limit as usize).collect()
+	}
+
+	/// Remove the judgement requests of `who` from `PendingRequests`, for each judgement of `id`
+	/// with a pending fee.
+	fn remove_pending_requests(
+		who: &T::AccountId,
+		id: &Registration<BalanceOf<T>, T::MaxRegistrars, T::IdentityInformation>,
+	) {
+		for (reg_index, _) in id.judgements.iter().filter(|(_, j)| j.has_deposit()) {
+			<PendingRequests<T>>::remove(reg_index, who);
+		}
+	}
+
 	/// Calculate the deposit required for a number of `sub` accounts.
 	fn subs_deposit(subs: u32) -> BalanceOf<T> {
 		T::SubAccountDeposit::get().saturating_mul(<BalanceOf<T>>::from(subs))
@@ -919,6 +1335,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Move `sub` from the parent of `transfer` to its new parent, together with the deposit.
+	fn do_transfer_sub(sub: T::AccountId, transfer: SubTransfer<T::AccountId>) -> DispatchResult {
+		let SubTransfer { parent, new_parent, .. } = transfer;
+		let (sup, data) = SuperOf::<T>::get(&sub).ok_or(Error::<T>::NotSub)?;
+		ensure!(sup == parent, Error::<T>::NotOwned);
+		ensure!(IdentityOf::<T>::contains_key(&new_parent), Error::<T>::NoIdentity);
+
+		let deposit = T::SubAccountDeposit::get();
+		SubsOf::<T>::try_mutate(&new_parent, |(ref mut subs_deposit, ref mut sub_ids)| {
+			ensure!(
+				sub_ids.len() < T::MaxSubAccounts::get() as usize,
+				Error::<T>::TooManySubAccounts
+			);
+			T::Currency::reserve(&new_parent, deposit)?;
+			sub_ids.try_push(sub.clone()).expect("sub ids length checked above; qed");
+			*subs_deposit = subs_deposit.saturating_add(deposit);
+			Ok::<_, DispatchError>(())
+		})?;
+		SubsOf::<T>::mutate(&parent, |(ref mut subs_deposit, ref mut sub_ids)| {
+			sub_ids.retain(|x| x != &sub);
+			let old_deposit = deposit.min(*subs_deposit);
+			*subs_deposit -= old_deposit;
+			let err_amount = T::Currency::unreserve(&parent, old_deposit);
+			debug_assert!(err_amount.is_zero());
+		});
+		SuperOf::<T>::insert(&sub, (new_parent.clone(), data));
+
+		Self::deposit_event(Event::SubIdentityTransferred {
+			sub,
+			old_main: parent,
+			new_main: new_parent,
+			deposit,
+		});
+		Ok(())
+	}
+
 	/// Check if the account has corresponding identity information by the identity field.
 	pub fn has_identity(
 		who: &T::AccountId,
@@ -953,6 +1405,7 @@ impl<T: Config> Pallet<T> {
 		for sub in sub_ids.iter() {
 			<SuperOf<T>>::remove(sub);
 		}
+		Self::remove_pending_requests(who, &id);
 
 		// unreserve any deposits
 		let deposit = id.total_deposit().saturating_add(subs_deposit);
@@ -1022,6 +1475,20 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Set an identity with zero deposit, judged by the registrar of index 0. Only used for
+	/// benchmarking pallets that require their users to have a judged identity.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub fn set_judged_identity_no_deposit(
+		who: &T::AccountId,
+		info: T::IdentityInformation,
+		judgement: Judgement<BalanceOf<T>>,
+	) -> DispatchResult {
+		let judgements = BoundedVec::<_, T::MaxRegistrars>::try_from(vec![(0, judgement)])
+			.map_err(|_| Error::<T>::TooManyRegistrars)?;
+		IdentityOf::<T>::insert(&who, Registration { judgements, deposit: Zero::zero(), info });
+		Ok(())
+	}
+
 	/// Set subs with zero deposit. Only used for benchmarking that involves `rejig_deposit`.
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn set_sub_no_deposit(who: &T::AccountId, sub: T::AccountId) -> DispatchResult {
@@ -1033,4 +1500,43 @@ impl<T: Config> Pallet<T> {
 		>(&who, (Zero::zero(), subs));
 		Ok(())
 	}
+
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// * Each sub-identity in [`SubsOf`] must point back to its identity in [`SuperOf`].
+	/// * Each request in [`PendingRequests`] must match a `FeePaid` judgement of the identity.
+	/// * (warning) The reserved balance of an account should cover the deposits of its identity
+	///   and of its sub-identities. Some chains are known to hold legacy discrepancies.
+	#[cfg(feature = "try-runtime")]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::traits::TryStateSeverity;
+
+		for (who, (_, subs)) in SubsOf::<T>::iter() {
+			for sub in subs {
+				ensure!(
+					SuperOf::<T>::get(&sub).map_or(false, |(main, _)| main == who),
+					"A sub-identity in `SubsOf` does not point back to its identity in `SuperOf`"
+				);
+			}
+		}
+
+		for (who, id) in IdentityOf::<T>::iter() {
+			let deposit = id.total_deposit().saturating_add(SubsOf::<T>::get(&who).0);
+			TryStateSeverity::Warning.ensure(
+				T::Currency::reserved_balance(&who) >= deposit,
+				"The reserved balance of an account does not cover its identity deposits",
+			)?;
+		}
+
+		for (reg_index, who, _) in PendingRequests::<T>::iter() {
+			let fee_paid = IdentityOf::<T>::get(&who).map_or(false, |id| {
+				id.judgements
+					.iter()
+					.any(|(index, judgement)| *index == reg_index && judgement.has_deposit())
+			});
+			ensure!(fee_paid, "A request in `PendingRequests` has no `FeePaid` judgement");
+		}
+
+		Ok(())
+	}
 }
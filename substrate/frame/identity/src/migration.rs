@@ -0,0 +1,141 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the Identity pallet.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const TARGET: &'static str = "runtime::identity::migration";
+
+pub mod v1 {
+	use super::*;
+
+	/// Fills [`PendingRequests`] with the `FeePaid` judgements of all identities.
+	///
+	/// The upgrade only starts the migration. The identities are then migrated in steps with the
+	/// weight left at the end of each block, see [`migrate_requests`].
+	///
+	/// The block in which these judgements were requested is unknown, so they are recorded as
+	/// requested in the block in which they are migrated.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(Pallet::<T>::on_chain_storage_version().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return T::DbWeight::get().reads(1)
+			}
+
+			RequestsMigration::<T>::put(RequestsMigrationCursor::Start);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: TARGET, "started to migrate the pending requests.");
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must upgrade to version 1.");
+			let pre_version: StorageVersion = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			if pre_version == 0 {
+				ensure!(RequestsMigration::<T>::exists(), "must start to migrate the requests.");
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrate the requests of the identities following the [`RequestsMigration`] cursor into
+	/// [`PendingRequests`], as long as the migration of another identity fits in `limit`.
+	///
+	/// Returns the consumed weight.
+	pub fn migrate_requests<T: Config>(limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		// Reading an identity and writing a request for each of its judgements.
+		let step = db.reads_writes(1, T::MaxRegistrars::get().into());
+		// Reading and updating the cursor.
+		let mut weight = db.reads_writes(1, 1);
+		if weight.saturating_add(step).any_gt(limit) {
+			return Weight::zero()
+		}
+
+		let Some(mut cursor) = RequestsMigration::<T>::get() else { return db.reads(1) };
+		let mut identities = match &cursor {
+			RequestsMigrationCursor::Start => IdentityOf::<T>::iter(),
+			RequestsMigrationCursor::After(who) =>
+				IdentityOf::<T>::iter_from(IdentityOf::<T>::hashed_key_for(who)),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		while weight.saturating_add(step).all_lte(limit) {
+			let Some((who, id)) = identities.next() else {
+				RequestsMigration::<T>::kill();
+				log::info!(target: TARGET, "migrated all pending requests.");
+				return weight
+			};
+			for (reg_index, _) in id.judgements.iter().filter(|(_, j)| j.has_deposit()) {
+				PendingRequests::<T>::insert(reg_index, &who, now);
+			}
+			weight.saturating_accrue(step);
+			cursor = RequestsMigrationCursor::After(who);
+		}
+		RequestsMigration::<T>::put(cursor);
+		weight
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::traits::Hooks;
+
+	#[test]
+	fn migration_v0_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			frame_system::Pallet::<Test>::set_block_number(7);
+			let judgements = vec![(0, Judgement::FeePaid(10)), (1, Judgement::Reasonable)];
+			IdentityOf::<Test>::insert(
+				10,
+				Registration {
+					judgements: judgements.try_into().unwrap(),
+					deposit: 0,
+					info: Default::default(),
+				},
+			);
+
+			v1::MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+			assert_eq!(RequestsMigration::<Test>::get(), Some(RequestsMigrationCursor::Start));
+			assert_eq!(PendingRequests::<Test>::iter().count(), 0);
+
+			// the requests are migrated with the weight left in a block.
+			frame_system::Pallet::<Test>::set_block_number(8);
+			Pallet::<Test>::on_idle(8, Weight::MAX);
+			assert_eq!(RequestsMigration::<Test>::get(), None);
+			assert_eq!(PendingRequests::<Test>::iter().collect::<Vec<_>>(), vec![(0, 10, 8)]);
+		});
+	}
+}
@@ -32,6 +32,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
@@ -114,9 +115,27 @@ impl pallet_identity::Config for Test {
 	type MaxRegistrars = MaxRegistrars;
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type MigratorOrigin = EnsureRoot<u64>;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
 	type WeightInfo = ();
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<UintAuthorityId, TestSignature> for TestBenchmarkHelper {
+	fn generate_key() -> UintAuthorityId {
+		UintAuthorityId(30)
+	}
+
+	fn sign(public: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(public.0, message.to_vec())
+	}
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -275,6 +294,134 @@ fn resolving_subaccount_ownership_works() {
 	});
 }
 
+#[test]
+fn transferring_subaccount_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+
+		let ten = ten();
+		let ten_deposit = id_deposit(&ten);
+		let twenty = twenty();
+		let twenty_deposit = id_deposit(&twenty);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten)));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(20), Box::new(twenty)));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(10), 1, data(1)));
+
+		// only the parent of 1 can propose to transfer it, to another identity
+		assert_noop!(
+			Identity::transfer_sub(RuntimeOrigin::signed(20), 1, 20),
+			Error::<Test>::NotOwned
+		);
+		assert_noop!(
+			Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 10),
+			Error::<Test>::InvalidTarget
+		);
+		assert_noop!(
+			Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 30),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+
+		// only 20 and 1 can accept, once each
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::NotTransferParty
+		);
+		assert_ok!(Identity::accept_sub_transfer(RuntimeOrigin::signed(20), 1));
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(20), 1),
+			Error::<Test>::AlreadyAccepted
+		);
+		assert_eq!(SuperOf::<Test>::get(1), Some((10, data(1))));
+
+		// 1 accepts, and takes its name and deposit to 20
+		assert_ok!(Identity::accept_sub_transfer(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(
+			Event::SubIdentityTransferred {
+				sub: 1,
+				old_main: 10,
+				new_main: 20,
+				deposit: sub_deposit,
+			}
+			.into(),
+		);
+		assert_eq!(SuperOf::<Test>::get(1), Some((20, data(1))));
+		assert_eq!(SubsOf::<Test>::get(10), (0, BoundedVec::default()));
+		assert_eq!(SubsOf::<Test>::get(20), (sub_deposit, vec![1].try_into().unwrap()));
+		assert_eq!(Balances::reserved_balance(10), ten_deposit);
+		assert_eq!(Balances::reserved_balance(20), twenty_deposit + sub_deposit);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert!(PendingSubTransfers::<Test>::get(1).is_none());
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+#[test]
+fn cancelled_or_outdated_subaccount_transfers_should_fail() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(20), Box::new(twenty())));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(10), 1, data(1)));
+
+		// any party can cancel the transfer
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+		assert_ok!(Identity::accept_sub_transfer(RuntimeOrigin::signed(1), 1));
+		assert_noop!(
+			Identity::cancel_sub_transfer(RuntimeOrigin::signed(30), 1),
+			Error::<Test>::NotTransferParty
+		);
+		assert_ok!(Identity::cancel_sub_transfer(RuntimeOrigin::signed(20), 1));
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(20), 1),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		// the new parent must have room for the sub-identity
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(20), 2, data(2)));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(20), 3, data(3)));
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+		assert_ok!(Identity::accept_sub_transfer(RuntimeOrigin::signed(20), 1));
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::TooManySubAccounts
+		);
+		assert_ok!(Identity::remove_sub(RuntimeOrigin::signed(20), 3));
+
+		// the transfer is dropped once the sub-identity leaves its parent
+		assert_ok!(Identity::quit_sub(RuntimeOrigin::signed(1)));
+		assert!(PendingSubTransfers::<Test>::get(1).is_none());
+		assert_eq!(SuperOf::<Test>::get(1), None);
+
+		// and does not complete once the sub-identity is added back
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(10), 1, data(1)));
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+		assert_ok!(Identity::accept_sub_transfer(RuntimeOrigin::signed(20), 1));
+		assert_ok!(Identity::remove_sub(RuntimeOrigin::signed(10), 1));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(10), 1, data(1)));
+		assert_noop!(
+			Identity::accept_sub_transfer(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NoPendingTransfer
+		);
+
+		// nor once the parent identity is cleared or killed
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(10)));
+		assert!(PendingSubTransfers::<Test>::get(1).is_none());
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(10), 1, data(1)));
+		assert_ok!(Identity::transfer_sub(RuntimeOrigin::signed(10), 1, 20));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), 10));
+		assert!(PendingSubTransfers::<Test>::get(1).is_none());
+	});
+}
+
 #[test]
 fn trailing_zeros_decodes_into_default_data() {
 	let encoded = Data::Raw(b"Hello".to_vec().try_into().unwrap()).encode();
@@ -780,3 +927,165 @@ fn poke_deposit_works() {
 		assert_eq!(Identity::subs_of(10), (subs_deposit, vec![20].try_into().unwrap()));
 	});
 }
+
+#[test]
+fn export_and_import_identity_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let ten_info = ten();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(3), 1, 10));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten_info.clone())));
+		let sub_name = Data::Raw(vec![40; 1].try_into().unwrap());
+		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(10), vec![(20, sub_name.clone())]));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(3),
+			0,
+			10,
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 1, 10));
+
+		assert_eq!(Identity::export_identity(&20), None);
+		let bundle = Identity::export_identity(&10).unwrap();
+		assert_eq!(bundle.who(), &10);
+
+		// move the identity, as if `bundle` was sent to another chain.
+		assert_ok!(Identity::reap_identity(&10));
+		assert_eq!(Balances::free_balance(10), 1000);
+
+		// the bundle must be signed by its owner, for this chain, nonce and expiry.
+		let import = |bundle: &IdentityBundleOf<Test>, nonce, expiry, signer, payload| {
+			Identity::import_identity_bundle(
+				RuntimeOrigin::root(),
+				Box::new(bundle.clone()),
+				nonce,
+				expiry,
+				TestSignature(signer, payload),
+			)
+		};
+		let payload = Identity::bundle_signing_payload(&bundle, 0, 10);
+		assert_noop!(
+			Identity::import_identity_bundle(
+				RuntimeOrigin::signed(10),
+				Box::new(bundle.clone()),
+				0,
+				10,
+				TestSignature(10, payload.clone())
+			),
+			BadOrigin
+		);
+		assert_noop!(import(&bundle, 0, 10, 20, payload.clone()), Error::<Test>::InvalidSignature);
+		assert_noop!(import(&bundle, 0, 10, 10, bundle.encode()), Error::<Test>::InvalidSignature);
+		assert_noop!(import(&bundle, 0, 11, 10, payload.clone()), Error::<Test>::InvalidSignature);
+		assert_noop!(import(&bundle, 1, 10, 10, payload.clone()), Error::<Test>::InvalidNonce);
+		System::set_block_number(11);
+		assert_noop!(import(&bundle, 0, 10, 10, payload.clone()), Error::<Test>::BundleExpired);
+		System::set_block_number(10);
+
+		// a sub-account may only be listed once.
+		let IdentityBundle::V1 { who, registration, subs } = bundle.clone();
+		let mut duplicate_subs = subs.clone().into_inner();
+		duplicate_subs.extend(subs.clone());
+		let duplicated = IdentityBundle::V1 {
+			who,
+			registration,
+			subs: duplicate_subs.try_into().unwrap(),
+		};
+		let duplicated_payload = Identity::bundle_signing_payload(&duplicated, 0, 10);
+		assert_noop!(
+			import(&duplicated, 0, 10, 10, duplicated_payload),
+			Error::<Test>::DuplicateSubAccount
+		);
+
+		assert_ok!(import(&bundle, 0, 10, 10, payload.clone()));
+		assert_eq!(BundleNonce::<Test>::get(10), 1);
+		// the pending judgement request is dropped.
+		let id_deposit = id_deposit(&ten_info);
+		assert_eq!(
+			Identity::identity(10),
+			Some(Registration {
+				judgements: vec![(0, Judgement::Reasonable)].try_into().unwrap(),
+				deposit: id_deposit,
+				info: ten_info,
+			})
+		);
+		assert_eq!(Identity::subs(&10), vec![(20, sub_name)]);
+		let subs_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		assert_eq!(Balances::free_balance(10), 1000 - id_deposit - subs_deposit);
+		System::assert_last_event(
+			Event::IdentityImported { who: 10, deposit: id_deposit + subs_deposit }.into(),
+		);
+
+		// an identity can not be imported twice, nor the same bundle again once cleared.
+		let next_payload = Identity::bundle_signing_payload(&bundle, 1, 10);
+		assert_noop!(import(&bundle, 1, 10, 10, next_payload), Error::<Test>::AlreadyClaimed);
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(10)));
+		assert_noop!(import(&bundle, 0, 10, 10, payload), Error::<Test>::InvalidNonce);
+	});
+}
+
+#[test]
+fn pending_requests_are_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(3), 0, 10));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(20), Box::new(twenty())));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(30), Box::new(ten())));
+
+		System::set_block_number(2);
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(20), 0, 10));
+		System::set_block_number(3);
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(30), 0, 10));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+
+		let mut requests = Identity::pending_requests(0, None, 10);
+		requests.sort();
+		assert_eq!(requests, vec![(10, 3), (20, 2), (30, 3)]);
+		assert_eq!(Identity::pending_requests(1, None, 10), vec![]);
+
+		// requests can be paged through.
+		let first = Identity::pending_requests(0, None, 2);
+		assert_eq!(first.len(), 2);
+		let rest = Identity::pending_requests(0, Some(first[1].0), 2);
+		assert_eq!(rest.len(), 1);
+		assert!(!first.contains(&rest[0]));
+
+		// giving a judgement, cancelling or clearing an identity removes the request.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(3),
+			0,
+			20,
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&twenty())
+		));
+		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(30), 0));
+		assert_eq!(Identity::pending_requests(0, None, 10), vec![(10, 3)]);
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(10)));
+		assert_eq!(Identity::pending_requests(0, None, 10), vec![]);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_reports_deposit_discrepancies_as_warnings() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::signed(1), 3));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(10), 0, 10));
+		assert_ok!(Identity::do_try_state());
+
+		// a deposit which is not reserved anymore is only a warning.
+		Balances::unreserve(&10, 5);
+		assert_ok!(Identity::do_try_state());
+
+		// a dangling judgement request fails the checks.
+		PendingRequests::<Test>::insert(0, 20, 1);
+		assert!(Identity::do_try_state().is_err());
+	});
+}
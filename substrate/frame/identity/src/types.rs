@@ -19,7 +19,7 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	traits::{ConstU32, Get},
-	BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use scale_info::{
 	build::{Fields, Variants},
@@ -64,6 +64,13 @@ impl Data {
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl frame_benchmarking::v2::WorstCase for Data {
+	fn worst_case() -> Self {
+		Data::Raw(frame_benchmarking::v2::WorstCase::worst_case())
+	}
+}
+
 impl Decode for Data {
 	fn decode<I: codec::Input>(input: &mut I) -> sp_std::result::Result<Self, codec::Error> {
 		let b = input.read_byte()?;
@@ -301,6 +308,52 @@ impl<
 	}
 }
 
+/// A versioned bundle of the identity of an account together with its sub-identities, used to
+/// move the identity from one chain to another.
+///
+/// The bundle is exported with [`Pallet::export_identity`](crate::Pallet::export_identity) on the
+/// source chain, signed by the account and imported with `import_identity_bundle` on the
+/// destination chain.
+#[derive(
+	CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxJudgements, MaxSubs))]
+pub enum IdentityBundle<
+	AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
+	Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq,
+	MaxJudgements: Get<u32>,
+	IdentityInfo: IdentityInformationProvider,
+	MaxSubs: Get<u32>,
+> {
+	/// The first version of the bundle.
+	#[codec(index = 0)]
+	V1 {
+		/// The account owning the identity.
+		who: AccountId,
+		/// The identity of the account, with the judgements it received and the deposit held
+		/// for it on the source chain.
+		registration: Registration<Balance, MaxJudgements, IdentityInfo>,
+		/// The sub-identities of the account, together with their names.
+		subs: BoundedVec<(AccountId, Data), MaxSubs>,
+	},
+}
+
+impl<
+		AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
+		Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq,
+		MaxJudgements: Get<u32>,
+		IdentityInfo: IdentityInformationProvider,
+		MaxSubs: Get<u32>,
+	> IdentityBundle<AccountId, Balance, MaxJudgements, IdentityInfo, MaxSubs>
+{
+	/// The account owning the identity of the bundle.
+	pub fn who(&self) -> &AccountId {
+		match self {
+			Self::V1 { who, .. } => who,
+		}
+	}
+}
+
 /// Information concerning a registrar.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct RegistrarInfo<
@@ -319,6 +372,33 @@ pub struct RegistrarInfo<
 	pub fields: IdField,
 }
 
+/// A transfer of a sub-identity to another identity, waiting for the new parent identity and the
+/// sub-account itself to accept it.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SubTransfer<AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq> {
+	/// The identity the sub-identity belonged to when the transfer was proposed.
+	pub parent: AccountId,
+
+	/// The identity the sub-identity is transferred to.
+	pub new_parent: AccountId,
+
+	/// Whether the new parent accepted the transfer.
+	pub new_parent_accepted: bool,
+
+	/// Whether the sub-account accepted the transfer.
+	pub sub_accepted: bool,
+}
+
+/// The progress of filling `PendingRequests` with the requests of the identities which were
+/// registered before it existed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RequestsMigrationCursor<AccountId> {
+	/// No identity was migrated yet.
+	Start,
+	/// The identities up to and including this one were migrated.
+	After(AccountId),
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -385,3 +465,4 @@ mod tests {
 		}
 	}
 }
+
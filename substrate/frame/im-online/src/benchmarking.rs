@@ -22,7 +22,7 @@
 use super::*;
 
 use frame_benchmarking::v1::benchmarks;
-use frame_support::{traits::UnfilteredDispatchable, WeakBoundedVec};
+use frame_support::traits::UnfilteredDispatchable;
 use frame_system::RawOrigin;
 use sp_runtime::{
 	traits::{ValidateUnsigned, Zero},
@@ -46,9 +46,10 @@ pub fn create_heartbeat<T: Config>(
 	for _ in 0..k {
 		keys.push(T::AuthorityId::generate_pair(None));
 	}
-	let bounded_keys = WeakBoundedVec::<_, T::MaxKeys>::try_from(keys.clone())
-		.map_err(|()| "More than the maximum number of keys provided")?;
-	Keys::<T>::put(bounded_keys);
+	if keys.len() > T::MaxKeys::get() as usize {
+		return Err("More than the maximum number of keys provided")
+	}
+	ImOnline::<T>::put_keys(&keys);
 
 	let input_heartbeat = Heartbeat {
 		block_number: frame_system::pallet_prelude::BlockNumberFor::<T>::zero(),
@@ -89,7 +89,7 @@ use frame_support::{
 		EstimateNextSessionRotation, Get, OneSessionHandler, ValidatorSet,
 		ValidatorSetWithIdentification,
 	},
-	BoundedSlice, WeakBoundedVec,
+	BoundedVec,
 };
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
@@ -247,12 +247,15 @@ pub type IdentificationTuple<T> = (
 
 type OffchainResult<T, A> = Result<A, OffchainErr<BlockNumberFor<T>>>;
 
+/// The number of keys in each page of [`KeyPages`].
+pub const KEYS_PAGE_SIZE: u32 = 100;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -341,11 +344,23 @@ pub mod pallet {
 	#[pallet::getter(fn heartbeat_after)]
 	pub(super) type HeartbeatAfter<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
-	/// The current set of keys that may issue a heartbeat.
+	/// The current set of keys that may issue a heartbeat, in pages of [`KEYS_PAGE_SIZE`] keys.
+	///
+	/// The key of the authority at `index` is kept in page `index / KEYS_PAGE_SIZE`, so that
+	/// checking a heartbeat only reads the page of its authority.
 	#[pallet::storage]
-	#[pallet::getter(fn keys)]
-	pub(super) type Keys<T: Config> =
-		StorageValue<_, WeakBoundedVec<T::AuthorityId, T::MaxKeys>, ValueQuery>;
+	pub(super) type KeyPages<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u32,
+		BoundedVec<T::AuthorityId, ConstU32<KEYS_PAGE_SIZE>>,
+		ValueQuery,
+	>;
+
+	/// The number of keys in [`KeyPages`].
+	#[pallet::storage]
+	#[pallet::getter(fn keys_count)]
+	pub(super) type KeysCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	/// For each session index, we keep a mapping of `SessionIndex` and `AuthIndex`.
 	#[pallet::storage]
@@ -383,8 +398,8 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// ## Complexity:
-		/// - `O(K)` where K is length of `Keys` (heartbeat.validators_len)
-		///   - `O(K)`: decoding of length `K`
+		/// - `O(K)` where K is the number of validators (heartbeat.validators_len)
+		///   - `O(K)`: decoding of the validators of the session
 		// NOTE: the weight includes the cost of validate_unsigned as it is part of the cost to
 		// import block with such an extrinsic.
 		#[pallet::call_index(0)]
@@ -403,10 +418,9 @@ pub mod pallet {
 			let current_session = T::ValidatorSet::session_index();
 			let exists =
 				ReceivedHeartbeats::<T>::contains_key(current_session, heartbeat.authority_index);
-			let keys = Keys::<T>::get();
-			let public = keys.get(heartbeat.authority_index as usize);
+			let public = Self::key(heartbeat.authority_index);
 			if let (false, Some(public)) = (exists, public) {
-				Self::deposit_event(Event::<T>::HeartbeatReceived { authority_id: public.clone() });
+				Self::deposit_event(Event::<T>::HeartbeatReceived { authority_id: public });
 
 				ReceivedHeartbeats::<T>::insert(current_session, heartbeat.authority_index, true);
 
@@ -466,11 +480,10 @@ pub mod pallet {
 				}
 
 				// verify that the incoming (unverified) pubkey is actually an authority id
-				let keys = Keys::<T>::get();
-				if keys.len() as u32 != heartbeat.validators_len {
+				if KeysCount::<T>::get() != heartbeat.validators_len {
 					return InvalidTransaction::Custom(INVALID_VALIDATORS_LEN).into()
 				}
-				let authority_id = match keys.get(heartbeat.authority_index as usize) {
+				let authority_id = match Self::key(heartbeat.authority_index) {
 					Some(id) => id,
 					None => return InvalidTransaction::BadProof.into(),
 				};
@@ -597,7 +610,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let session_index = T::ValidatorSet::session_index();
-		let validators_len = Keys::<T>::decode_len().unwrap_or_default() as u32;
+		let validators_len = KeysCount::<T>::get();
 
 		Ok(Self::local_authority_keys().map(move |(authority_index, key)| {
 			Self::send_single_heartbeat(
@@ -657,7 +670,7 @@ impl<T: Config> Pallet<T> {
 		// At index `idx`:
 		// 1. A (ImOnline) public key to be used by a validator at index `idx` to send im-online
 		//    heartbeats.
-		let authorities = Keys::<T>::get();
+		let authorities = Self::keys();
 
 		// local keystore
 		//
@@ -719,20 +732,56 @@ impl<T: Config> Pallet<T> {
 		res
 	}
 
+	/// Returns the key of the authority at `authority_index`, reading only the page of
+	/// [`KeyPages`] it is in.
+	pub fn key(authority_index: AuthIndex) -> Option<T::AuthorityId> {
+		KeyPages::<T>::get(authority_index / KEYS_PAGE_SIZE)
+			.get((authority_index % KEYS_PAGE_SIZE) as usize)
+			.cloned()
+	}
+
+	/// Returns the current set of keys that may issue a heartbeat, reading all pages of
+	/// [`KeyPages`].
+	pub fn keys() -> Vec<T::AuthorityId> {
+		(0..Self::key_pages(KeysCount::<T>::get())).flat_map(KeyPages::<T>::get).collect()
+	}
+
+	/// The number of pages of [`KeyPages`] which hold `count` keys.
+	fn key_pages(count: u32) -> u32 {
+		count.saturating_add(KEYS_PAGE_SIZE - 1) / KEYS_PAGE_SIZE
+	}
+
+	/// Replace the current set of keys with `keys`, removing the pages which are no longer used.
+	pub(crate) fn put_keys(keys: &[T::AuthorityId]) {
+		let old_pages = Self::key_pages(KeysCount::<T>::get());
+		let new_pages = Self::key_pages(keys.len() as u32);
+		for (page, keys) in keys.chunks(KEYS_PAGE_SIZE as usize).enumerate() {
+			KeyPages::<T>::insert(page as u32, BoundedVec::truncate_from(keys.to_vec()));
+		}
+		for page in new_pages..old_pages {
+			KeyPages::<T>::remove(page);
+		}
+		KeysCount::<T>::put(keys.len() as u32);
+	}
+
 	fn initialize_keys(keys: &[T::AuthorityId]) {
 		if !keys.is_empty() {
-			assert!(Keys::<T>::get().is_empty(), "Keys are already initialized!");
-			let bounded_keys = <BoundedSlice<'_, _, T::MaxKeys>>::try_from(keys)
-				.expect("More than the maximum number of keys provided");
-			Keys::<T>::put(bounded_keys);
+			assert!(KeysCount::<T>::get() == 0, "Keys are already initialized!");
+			assert!(
+				keys.len() <= T::MaxKeys::get() as usize,
+				"More than the maximum number of keys provided"
+			);
+			Self::put_keys(keys);
 		}
 	}
 
 	#[cfg(test)]
 	fn set_keys(keys: Vec<T::AuthorityId>) {
-		let bounded_keys = WeakBoundedVec::<_, T::MaxKeys>::try_from(keys)
-			.expect("More than the maximum number of keys provided");
-		Keys::<T>::put(bounded_keys);
+		assert!(
+			keys.len() <= T::MaxKeys::get() as usize,
+			"More than the maximum number of keys provided"
+		);
+		Self::put_keys(&keys);
 	}
 }
 
@@ -764,19 +813,19 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 
 		// Remember who the authorities are for the new session.
 		let keys = validators.map(|x| x.1).collect::<Vec<_>>();
-		let bounded_keys = WeakBoundedVec::<_, T::MaxKeys>::force_from(
-			keys,
-			Some(
+		if keys.len() > T::MaxKeys::get() as usize {
+			log::warn!(
+				target: "runtime::im-online",
 				"Warning: The session has more keys than expected. \
-  				A runtime configuration adjustment may be needed.",
-			),
-		);
-		Keys::<T>::put(bounded_keys);
+				A runtime configuration adjustment may be needed.",
+			);
+		}
+		Self::put_keys(&keys);
 	}
 
 	fn on_before_session_ending() {
 		let session_index = T::ValidatorSet::session_index();
-		let keys = Keys::<T>::get();
+		let validator_set_count = KeysCount::<T>::get();
 		let current_validators = T::ValidatorSet::validators();
 
 		let offenders = current_validators
@@ -803,7 +852,6 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 		} else {
 			Self::deposit_event(Event::<T>::SomeOffline { offline: offenders.clone() });
 
-			let validator_set_count = keys.len() as u32;
 			let offence = UnresponsivenessOffence { session_index, validator_set_count, offenders };
 			if let Err(e) = T::ReportUnresponsiveness::report_offence(vec![], offence) {
 				sp_runtime::print(e);
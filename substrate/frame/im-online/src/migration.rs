@@ -116,11 +116,75 @@ pub mod v1 {
 	}
 }
 
+pub mod v2 {
+	use super::*;
+	use frame_support::WeakBoundedVec;
+
+	/// The log target.
+	const TARGET: &str = "runtime::im-online::migration::v2";
+
+	/// The keys of the im-online pallet before they were paged (`Keys` storage item).
+	#[storage_alias]
+	pub(super) type Keys<T: Config> = StorageValue<
+		Pallet<T>,
+		WeakBoundedVec<<T as Config>::AuthorityId, <T as Config>::MaxKeys>,
+		ValueQuery,
+	>;
+
+	/// Migration which moves the keys from the single `Keys` value into pages of
+	/// [`KEYS_PAGE_SIZE`] keys of `KeyPages`.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count = Keys::<T>::decode_len().unwrap_or_default() as u32;
+			log::info!(target: TARGET, "Migrating {} keys", count);
+
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::warn!(
+					target: TARGET,
+					"Skipping migration because current storage version is not 1"
+				);
+				return weight
+			}
+
+			let keys = Keys::<T>::take();
+			Pallet::<T>::put_keys(&keys);
+			log::info!(target: TARGET, "Migrated {} keys", keys.len());
+
+			let pages = (keys.len() as u64).saturating_add(KEYS_PAGE_SIZE as u64 - 1) /
+				KEYS_PAGE_SIZE as u64;
+			StorageVersion::new(2).put::<Pallet<T>>();
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, pages.saturating_add(3)));
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> DispatchResult {
+			let old_keys: u32 =
+				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+
+			ensure!(crate::KeysCount::<T>::get() == old_keys, "must migrate all keys");
+			ensure!(!Keys::<T>::exists(), "must remove the old keys");
+			ensure!(StorageVersion::get::<Pallet<T>>() >= 2, "must upgrade");
+
+			Ok(())
+		}
+	}
+}
+
 #[cfg(all(feature = "try-runtime", test))]
 mod test {
 	use super::*;
 	use crate::mock::{new_test_ext, Runtime as T};
-	use frame_support::traits::WrapperOpaque;
+	use frame_support::{traits::WrapperOpaque, WeakBoundedVec};
+	use sp_runtime::testing::UintAuthorityId;
 
 	#[test]
 	fn migration_works() {
@@ -158,4 +222,24 @@ mod test {
 			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
 		});
 	}
+
+	#[test]
+	fn migration_v1_to_v2_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			Pallet::<T>::put_keys(&[]);
+			let keys = (0..150u64).map(UintAuthorityId).collect::<Vec<_>>();
+			v2::Keys::<T>::put(WeakBoundedVec::force_from(keys.clone(), None));
+
+			let state = v2::Migration::<T>::pre_upgrade().unwrap();
+			let _w = v2::Migration::<T>::on_runtime_upgrade();
+			v2::Migration::<T>::post_upgrade(state).unwrap();
+
+			assert!(!v2::Keys::<T>::exists());
+			assert_eq!(crate::KeysCount::<T>::get(), 150);
+			assert_eq!(crate::KeyPages::<T>::get(1).len(), 50);
+			assert_eq!(Pallet::<T>::keys(), keys);
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+		});
+	}
 }
@@ -201,6 +201,29 @@ fn late_heartbeat_and_invalid_keys_len_should_fail() {
 	});
 }
 
+#[test]
+fn keys_are_paged() {
+	new_test_ext().execute_with(|| {
+		let keys = (0..250u64).map(UintAuthorityId).collect::<Vec<_>>();
+		ImOnline::set_keys(keys.clone());
+
+		assert_eq!(ImOnline::keys_count(), 250);
+		assert_eq!(KeyPages::<Runtime>::get(2).len(), 50);
+		assert_eq!(ImOnline::key(0), Some(0.into()));
+		assert_eq!(ImOnline::key(149), Some(149.into()));
+		assert_eq!(ImOnline::key(250), None);
+		assert_eq!(ImOnline::keys(), keys);
+
+		// pages which are no longer used are removed.
+		ImOnline::set_keys(keys[..100].to_vec());
+		assert_eq!(ImOnline::keys_count(), 100);
+		assert!(!KeyPages::<Runtime>::contains_key(1));
+		assert!(!KeyPages::<Runtime>::contains_key(2));
+		assert_eq!(ImOnline::key(100), None);
+		assert_eq!(ImOnline::keys(), keys[..100].to_vec());
+	});
+}
+
 #[test]
 fn should_generate_heartbeats() {
 	let mut ext = new_test_ext();
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since the keys are stored in pages. The weight of
+//! `validate_unsigned_and_then_heartbeat` is a PLACEHOLDER, adjusted by hand to the paged storage
+//! accesses. Re-run the benchmarks to replace it.
 
 // Executed Command:
 // ./target/production/substrate
@@ -62,22 +66,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Session CurrentIndex (r:1 w:0)
 	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: ImOnline Keys (r:1 w:0)
-	/// Proof: ImOnline Keys (max_values: Some(1), max_size: Some(320002), added: 320497, mode: MaxEncodedLen)
+	/// Storage: ImOnline KeysCount (r:1 w:0)
+	/// Proof: ImOnline KeysCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ImOnline KeyPages (r:1 w:0)
+	/// Proof: ImOnline KeyPages (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
 	/// Storage: ImOnline ReceivedHeartbeats (r:1 w:1)
 	/// Proof: ImOnline ReceivedHeartbeats (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
 	/// Storage: ImOnline AuthoredBlocks (r:1 w:0)
 	/// Proof: ImOnline AuthoredBlocks (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	/// The range of component `k` is `[1, 1000]`.
 	fn validate_unsigned_and_then_heartbeat(k: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `295 + k * (32 ±0)`
-		//  Estimated: `321487 + k * (1761 ±0)`
-		// Minimum execution time: 80_568_000 picoseconds.
-		Weight::from_parts(95_175_595, 321487)
-			// Standard Error: 627
+		// PLACEHOLDER: not benchmarked, adjusted by hand to the paged keys.
+		Weight::from_parts(97_410_873, 7178)
 			.saturating_add(Weight::from_parts(39_094, 0).saturating_mul(k.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 1761).saturating_mul(k.into()))
 	}
@@ -89,22 +91,20 @@ impl WeightInfo for () {
 	/// Proof Skipped: Session Validators (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Session CurrentIndex (r:1 w:0)
 	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: ImOnline Keys (r:1 w:0)
-	/// Proof: ImOnline Keys (max_values: Some(1), max_size: Some(320002), added: 320497, mode: MaxEncodedLen)
+	/// Storage: ImOnline KeysCount (r:1 w:0)
+	/// Proof: ImOnline KeysCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ImOnline KeyPages (r:1 w:0)
+	/// Proof: ImOnline KeyPages (max_values: None, max_size: Some(3214), added: 5689, mode: MaxEncodedLen)
 	/// Storage: ImOnline ReceivedHeartbeats (r:1 w:1)
 	/// Proof: ImOnline ReceivedHeartbeats (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
 	/// Storage: ImOnline AuthoredBlocks (r:1 w:0)
 	/// Proof: ImOnline AuthoredBlocks (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	/// The range of component `k` is `[1, 1000]`.
 	fn validate_unsigned_and_then_heartbeat(k: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `295 + k * (32 ±0)`
-		//  Estimated: `321487 + k * (1761 ±0)`
-		// Minimum execution time: 80_568_000 picoseconds.
-		Weight::from_parts(95_175_595, 321487)
-			// Standard Error: 627
+		// PLACEHOLDER: not benchmarked, adjusted by hand to the paged keys.
+		Weight::from_parts(97_410_873, 7178)
 			.saturating_add(Weight::from_parts(39_094, 0).saturating_mul(k.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 1761).saturating_mul(k.into()))
 	}
@@ -21,6 +21,7 @@
 
 use super::*;
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Hooks;
 use frame_system::RawOrigin;
 use sp_runtime::traits::Bounded;
 
@@ -92,6 +93,51 @@ benchmarks! {
 		assert_eq!(Accounts::<T>::get(account_index).unwrap().2, true);
 	}
 
+	claim_lease {
+		let account_index = T::AccountIndex::from(SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller.clone()), account_index)
+	verify {
+		assert_eq!(Accounts::<T>::get(account_index).unwrap().0, caller);
+		assert!(Leases::<T>::contains_key(account_index));
+	}
+
+	renew_lease {
+		let account_index = T::AccountIndex::from(SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Lease the index
+		Indices::<T>::claim_lease(RawOrigin::Signed(caller.clone()).into(), account_index)?;
+		let expiry = Leases::<T>::get(account_index).unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), account_index)
+	verify {
+		assert_eq!(
+			Leases::<T>::get(account_index),
+			Some(expiry.saturating_add(T::LeasePeriod::get())),
+		);
+	}
+
+	expire_leases {
+		let n in 0 .. T::MaxLeasesExpiringPerBlock::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Lease the indices, all expiring at the same block
+		for i in 0 .. n {
+			Indices::<T>::claim_lease(RawOrigin::Signed(caller.clone()).into(), i.into())?;
+		}
+		let expiry =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::LeasePeriod::get());
+	}: {
+		Indices::<T>::on_initialize(expiry);
+	}
+	verify {
+		for i in 0 .. n {
+			assert_eq!(Accounts::<T>::get(T::AccountIndex::from(i)), None);
+		}
+	}
+
 	// TODO in another PR: lookup and unlookup trait weights (not critical)
 
 	impl_benchmark_test_suite!(Indices, crate::mock::new_test_ext(), crate::mock::Test);
@@ -17,6 +17,9 @@
 
 //! An index is a short form of an address. This module handles allocation
 //! of indices for a newly created accounts.
+//!
+//! Indices can also be leased for [`Config::LeasePeriod`], after which they are freed up
+//! automatically unless the lease was renewed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -27,9 +30,10 @@ pub mod weights;
 
 use codec::Codec;
 use frame_support::traits::{BalanceStatus::Reserved, Currency, ReservableCurrency};
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
 	traits::{AtLeast32Bit, LookupError, Saturating, StaticLookup, Zero},
-	MultiAddress,
+	DispatchResult, MultiAddress,
 };
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
@@ -70,6 +74,14 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The term of a lease of an index.
+		#[pallet::constant]
+		type LeasePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of leases expiring at the same block.
+		#[pallet::constant]
+		type MaxLeasesExpiringPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -77,6 +89,22 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = LeaseExpiries::<T>::take(now);
+			let weight = T::WeightInfo::expire_leases(expiring.len() as u32);
+			for index in expiring {
+				Leases::<T>::remove(index);
+				if let Some((who, amount, _)) = Accounts::<T>::take(index) {
+					T::Currency::unreserve(&who, amount);
+					Self::deposit_event(Event::LeaseExpired { index, who });
+				}
+			}
+			weight
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Assign an previously unassigned index.
@@ -164,6 +192,7 @@ pub mod pallet {
 				T::Currency::unreserve(&who, amount);
 				Ok(())
 			})?;
+			Self::end_lease(index);
 			Self::deposit_event(Event::IndexFreed { index });
 			Ok(())
 		}
@@ -192,6 +221,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let new = T::Lookup::lookup(new)?;
 
+			Self::end_lease(index);
 			Accounts::<T>::mutate(index, |maybe_value| {
 				if let Some((account, amount, _)) = maybe_value.take() {
 					T::Currency::unreserve(&account, amount);
@@ -227,9 +257,66 @@ pub mod pallet {
 				*maybe_value = Some((account, Zero::zero(), true));
 				Ok(())
 			})?;
+			Self::end_lease(index);
 			Self::deposit_event(Event::IndexFrozen { index, who });
 			Ok(())
 		}
+
+		/// Lease a previously unassigned index for `LeasePeriod`, after which it is freed up.
+		///
+		/// Payment: `Deposit` is reserved from the sender account until the lease expires or
+		/// the index is freed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `index`: the index to be leased. This must not be in use.
+		///
+		/// Emits `IndexLeased` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::claim_lease())]
+		pub fn claim_lease(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let expiry =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::LeasePeriod::get());
+			Accounts::<T>::try_mutate(index, |maybe_value| {
+				ensure!(maybe_value.is_none(), Error::<T>::InUse);
+				*maybe_value = Some((who.clone(), T::Deposit::get(), false));
+				Self::schedule_expiry(index, expiry)?;
+				T::Currency::reserve(&who, T::Deposit::get())
+			})?;
+			Self::deposit_event(Event::IndexLeased { who, index, expiry });
+			Ok(())
+		}
+
+		/// Renew the lease of an index owned by the sender for another `LeasePeriod`, starting
+		/// from its current expiry.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the index.
+		///
+		/// - `index`: the leased index to be renewed. This must be owned by the sender.
+		///
+		/// Emits `LeaseRenewed` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::renew_lease())]
+		pub fn renew_lease(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (account, _, _) = Accounts::<T>::get(index).ok_or(Error::<T>::NotAssigned)?;
+			ensure!(account == who, Error::<T>::NotOwner);
+			let expiry = Leases::<T>::get(index).ok_or(Error::<T>::NotLeased)?;
+
+			let expiry = expiry.saturating_add(T::LeasePeriod::get());
+			Self::schedule_expiry(index, expiry)?;
+			Self::deposit_event(Event::LeaseRenewed { index, expiry });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -241,6 +328,12 @@ pub mod pallet {
 		IndexFreed { index: T::AccountIndex },
 		/// A account index has been frozen to its current account ID.
 		IndexFrozen { index: T::AccountIndex, who: T::AccountId },
+		/// A account index was leased until the `expiry` block.
+		IndexLeased { who: T::AccountId, index: T::AccountIndex, expiry: BlockNumberFor<T> },
+		/// The lease of an account index was renewed until the `expiry` block.
+		LeaseRenewed { index: T::AccountIndex, expiry: BlockNumberFor<T> },
+		/// The lease of an account index expired and the index was freed up.
+		LeaseExpired { index: T::AccountIndex, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -255,6 +348,10 @@ pub mod pallet {
 		NotTransfer,
 		/// The index is permanent and may not be freed/changed.
 		Permanent,
+		/// The index is not leased.
+		NotLeased,
+		/// Too many leases expire at the same block already.
+		TooManyExpiringLeases,
 	}
 
 	/// The lookup from index to account.
@@ -262,6 +359,21 @@ pub mod pallet {
 	pub type Accounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountIndex, (T::AccountId, BalanceOf<T>, bool)>;
 
+	/// The block at the beginning of which the lease of an index expires.
+	#[pallet::storage]
+	pub type Leases<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountIndex, BlockNumberFor<T>, OptionQuery>;
+
+	/// The leased indices expiring at the beginning of a block.
+	#[pallet::storage]
+	pub type LeaseExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountIndex, T::MaxLeasesExpiringPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -286,6 +398,11 @@ impl<T: Config> Pallet<T> {
 		Accounts::<T>::get(index).map(|x| x.0)
 	}
 
+	/// The block at the beginning of which the lease of `index` expires, if it is leased.
+	pub fn lease_expiry(index: T::AccountIndex) -> Option<BlockNumberFor<T>> {
+		Leases::<T>::get(index)
+	}
+
 	/// Lookup an address to get an Id, if there's one there.
 	pub fn lookup_address(a: MultiAddress<T::AccountId, T::AccountIndex>) -> Option<T::AccountId> {
 		match a {
@@ -296,6 +413,31 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Let the lease of `index` expire at `expiry`, replacing its current expiry if any.
+	fn schedule_expiry(index: T::AccountIndex, expiry: BlockNumberFor<T>) -> DispatchResult {
+		Self::end_lease(index);
+		LeaseExpiries::<T>::try_mutate(expiry, |expiring| expiring.try_push(index))
+			.map_err(|_| Error::<T>::TooManyExpiringLeases)?;
+		Leases::<T>::insert(index, expiry);
+		Ok(())
+	}
+
+	/// End the lease of `index` if any, so that it no longer expires.
+	fn end_lease(index: T::AccountIndex) {
+		if let Some(expiry) = Leases::<T>::take(index) {
+			LeaseExpiries::<T>::mutate_exists(expiry, |expiring| {
+				if let Some(indices) = expiring {
+					indices.retain(|i| i != &index);
+					if indices.is_empty() {
+						*expiring = None;
+					}
+				}
+			});
+		}
+	}
+}
+
 impl<T: Config> StaticLookup for Pallet<T> {
 	type Source = MultiAddress<T::AccountId, T::AccountIndex>;
 	type Target = T::AccountId;
@@ -87,6 +87,8 @@ impl Config for Test {
 	type Currency = Balances;
 	type Deposit = ConstU64<1>;
 	type RuntimeEvent = RuntimeEvent;
+	type LeasePeriod = ConstU64<10>;
+	type MaxLeasesExpiringPerBlock = ConstU32<2>;
 	type WeightInfo = ();
 }
 
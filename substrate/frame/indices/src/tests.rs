@@ -20,7 +20,7 @@
 #![cfg(test)]
 
 use super::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use pallet_balances::Error as BalancesError;
 use sp_runtime::MultiAddress::Id;
 
@@ -119,3 +119,79 @@ fn force_transfer_index_on_free_should_work() {
 		assert_eq!(Indices::lookup_index(0), Some(3));
 	});
 }
+
+#[test]
+fn leasing_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Indices::claim_lease(Some(1).into(), 0));
+		assert_noop!(Indices::claim_lease(Some(2).into(), 0), Error::<Test>::InUse);
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(Indices::lease_expiry(0), Some(11));
+
+		Indices::on_initialize(10);
+		assert_eq!(Indices::lookup_index(0), Some(1));
+
+		Indices::on_initialize(11);
+		assert_eq!(Indices::lookup_index(0), None);
+		assert_eq!(Indices::lease_expiry(0), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::<Test>::LeaseExpired { index: 0, who: 1 }.into());
+	});
+}
+
+#[test]
+fn renewing_lease_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_noop!(Indices::renew_lease(Some(1).into(), 0), Error::<Test>::NotLeased);
+		assert_noop!(Indices::renew_lease(Some(1).into(), 1), Error::<Test>::NotAssigned);
+
+		assert_ok!(Indices::claim_lease(Some(2).into(), 1));
+		assert_noop!(Indices::renew_lease(Some(1).into(), 1), Error::<Test>::NotOwner);
+		assert_ok!(Indices::renew_lease(Some(2).into(), 1));
+		assert_eq!(Indices::lease_expiry(1), Some(21));
+		assert!(!LeaseExpiries::<Test>::contains_key(11));
+
+		Indices::on_initialize(11);
+		assert_eq!(Indices::lookup_index(1), Some(2));
+		Indices::on_initialize(21);
+		assert_eq!(Indices::lookup_index(1), None);
+	});
+}
+
+#[test]
+fn leases_expiring_per_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Indices::claim_lease(Some(1).into(), 0));
+		assert_ok!(Indices::claim_lease(Some(1).into(), 1));
+		assert_noop!(Indices::claim_lease(Some(1).into(), 2), Error::<Test>::TooManyExpiringLeases);
+	});
+}
+
+#[test]
+fn ending_lease_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Indices::claim_lease(Some(1).into(), 0));
+		assert_ok!(Indices::claim_lease(Some(1).into(), 1));
+
+		// Freeing, freezing or forcing an index ends its lease.
+		assert_ok!(Indices::free(Some(1).into(), 0));
+		assert_ok!(Indices::freeze(Some(1).into(), 1));
+		assert_eq!(Indices::lease_expiry(0), None);
+		assert_eq!(Indices::lease_expiry(1), None);
+		assert!(!LeaseExpiries::<Test>::contains_key(11));
+
+		System::set_block_number(2);
+		assert_ok!(Indices::claim_lease(Some(2).into(), 2));
+		assert_ok!(Indices::force_transfer(RuntimeOrigin::root(), Id(3), 2, false));
+		assert_eq!(Indices::lease_expiry(2), None);
+
+		Indices::on_initialize(12);
+		assert_eq!(Indices::lookup_index(1), Some(1));
+		assert_eq!(Indices::lookup_index(2), Some(3));
+	});
+}
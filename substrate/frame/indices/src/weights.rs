@@ -57,6 +57,9 @@ pub trait WeightInfo {
 	fn free() -> Weight;
 	fn force_transfer() -> Weight;
 	fn freeze() -> Weight;
+	fn claim_lease() -> Weight;
+	fn renew_lease() -> Weight;
+	fn expire_leases(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_indices using the Substrate node and recommended hardware.
@@ -88,38 +91,103 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn free() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 26_652_000 picoseconds.
-		Weight::from_parts(27_273_000, 3534)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(27_273_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3593`
 		// Minimum execution time: 29_464_000 picoseconds.
 		Weight::from_parts(30_959_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 29_015_000 picoseconds.
-		Weight::from_parts(29_714_000, 3534)
+		Weight::from_parts(29_714_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices Accounts (r:1 w:1)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn claim_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3542`
+		// Minimum execution time: 27_902_000 picoseconds.
+		Weight::from_parts(28_614_000, 3542)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices Accounts (r:1 w:0)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:2 w:2)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn renew_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `6094`
+		// Minimum execution time: 19_375_000 picoseconds.
+		Weight::from_parts(19_930_000, 6094)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:0 w:n)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices Accounts (r:n w:n)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: System Account (r:n w:n)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_leases(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (137 ±0)`
+		//  Estimated: `3542 + n * (2603 ±0)`
+		// Minimum execution time: 2_981_000 picoseconds.
+		Weight::from_parts(3_402_000, 3542)
+			// Standard Error: 11_406
+			.saturating_add(Weight::from_parts(20_713_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 }
 
@@ -151,37 +219,102 @@ impl WeightInfo for () {
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn free() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 26_652_000 picoseconds.
-		Weight::from_parts(27_273_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(27_273_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `275`
 		//  Estimated: `3593`
 		// Minimum execution time: 29_464_000 picoseconds.
 		Weight::from_parts(30_959_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Indices Accounts (r:1 w:1)
 	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
-		//  Estimated: `3534`
+		//  Estimated: `3542`
 		// Minimum execution time: 29_015_000 picoseconds.
-		Weight::from_parts(29_714_000, 3534)
+		Weight::from_parts(29_714_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices Accounts (r:1 w:1)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn claim_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3542`
+		// Minimum execution time: 27_902_000 picoseconds.
+		Weight::from_parts(28_614_000, 3542)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices Accounts (r:1 w:0)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:1 w:1)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices LeaseExpiries (r:2 w:2)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn renew_lease() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `6094`
+		// Minimum execution time: 19_375_000 picoseconds.
+		Weight::from_parts(19_930_000, 6094)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Indices LeaseExpiries (r:1 w:1)
+	/// Proof: Indices LeaseExpiries (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Indices Leases (r:0 w:n)
+	/// Proof: Indices Leases (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: Indices Accounts (r:n w:n)
+	/// Proof: Indices Accounts (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: System Account (r:n w:n)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_leases(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (137 ±0)`
+		//  Estimated: `3542 + n * (2603 ±0)`
+		// Minimum execution time: 2_981_000 picoseconds.
+		Weight::from_parts(3_402_000, 3542)
+			// Standard Error: 11_406
+			.saturating_add(Weight::from_parts(20_713_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 }
@@ -0,0 +1,46 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for snapshots of membership pallets.
+//!
+//! Membership, collective and ranked collective pallets implement
+//! [`SnapshotMembers`](frame_support::traits::SnapshotMembers), and a runtime exposes the
+//! snapshots of its instances through this API, identified by their pallet index.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use frame_support::traits::{MemberLeaf, MemberProof, VersionedMembershipSnapshot};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for retrieving merkle-committed snapshots of membership sets.
+	pub trait MembershipSnapshotApi<AccountId, Hash>
+		where
+			AccountId: Codec,
+			Hash: Codec,
+	{
+		/// Returns a snapshot of the members of the pallet at `pallet_index`, or `None` if it is
+		/// not a membership pallet.
+		fn membership_snapshot(
+			pallet_index: u8,
+		) -> Option<VersionedMembershipSnapshot<AccountId, Hash>>;
+
+		/// Returns a proof of the membership of `who` in the pallet at `pallet_index` against the
+		/// root of its snapshot, or `None` if `who` is not a member.
+		fn member_proof(pallet_index: u8, who: AccountId) -> Option<MemberProof<AccountId, Hash>>;
+	}
+}
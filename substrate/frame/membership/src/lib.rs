@@ -19,15 +19,24 @@
 //!
 //! Allows control of membership of a set of `AccountId`s, useful for managing membership of a
 //! collective. A prime member may be set
+//!
+//! Memberships may expire: members added through [`Pallet::add_member`] (and the like) expire
+//! after `MembershipPeriod` blocks, unless their membership is renewed with [`Pallet::renew`].
+//! Expired members are removed while the chain is idle.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	traits::{ChangeMembers, Contains, Get, InitializeMembers, SortedMembers},
+	traits::{
+		ChangeMembers, Contains, Get, InitializeMembers, MembershipSnapshot, SnapshotMembers,
+		SortedMembers,
+	},
+	weights::Weight,
 	BoundedVec,
 };
-use sp_runtime::traits::StaticLookup;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Saturating, StaticLookup};
 use sp_std::prelude::*;
 
 pub mod migrations;
@@ -74,6 +83,14 @@ pub mod pallet {
 		/// Required origin for setting or resetting the prime member.
 		type PrimeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Required origin for renewing the membership of a member.
+		type RenewOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks after which the membership of a newly added member expires,
+		/// unless it is renewed. `None` if memberships do not expire by default.
+		#[pallet::constant]
+		type MembershipPeriod: Get<Option<BlockNumberFor<Self>>>;
+
 		/// The receiver of the signal for when the membership has been initialized. This happens
 		/// pre-genesis and will usually be the same as `MembershipChanged`. If you need to do
 		/// something different on initialization, then you can change this accordingly.
@@ -104,6 +121,18 @@ pub mod pallet {
 	#[pallet::getter(fn prime)]
 	pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The block at which the membership of a member expires, if it does.
+	#[pallet::storage]
+	pub type ExpiresAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// A lower bound for the earliest block at which a membership expires, if any does.
+	///
+	/// Renewals do not raise it; it is recomputed whenever expired members are pruned.
+	#[pallet::storage]
+	pub type NextExpiry<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -143,6 +172,10 @@ pub mod pallet {
 		MembersReset,
 		/// One of the members' keys changed.
 		KeyChanged,
+		/// The membership of a member was renewed until `expires_at`, or indefinitely.
+		MemberRenewed { who: T::AccountId, expires_at: Option<BlockNumberFor<T>> },
+		/// The memberships of `members` expired and they were removed.
+		MembersExpired { members: Vec<T::AccountId> },
 		/// Phantom member, never used.
 		Dummy { _phantom_data: PhantomData<(T::AccountId, <T as Config<I>>::RuntimeEvent)> },
 	}
@@ -155,6 +188,15 @@ pub mod pallet {
 		NotMember,
 		/// Too many members.
 		TooManyMembers,
+		/// The expiry of a membership must be in the future.
+		ExpiryInPast,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_expired(now, remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -175,6 +217,7 @@ pub mod pallet {
 				.map_err(|_| Error::<T, I>::TooManyMembers)?;
 
 			<Members<T, I>>::put(&members);
+			Self::set_expiry(&who, Self::default_expiry());
 
 			T::MembershipChanged::change_members_sorted(&[who], &[], &members[..]);
 
@@ -196,6 +239,7 @@ pub mod pallet {
 			members.remove(location);
 
 			<Members<T, I>>::put(&members);
+			<ExpiresAt<T, I>>::remove(&who);
 
 			T::MembershipChanged::change_members_sorted(&[], &[who], &members[..]);
 			Self::rejig_prime(&members);
@@ -231,6 +275,8 @@ pub mod pallet {
 			members.sort();
 
 			<Members<T, I>>::put(&members);
+			<ExpiresAt<T, I>>::remove(&remove);
+			Self::set_expiry(&add, Self::default_expiry());
 
 			T::MembershipChanged::change_members_sorted(&[add], &[remove], &members[..]);
 			Self::rejig_prime(&members);
@@ -254,6 +300,13 @@ pub mod pallet {
 			<Members<T, I>>::mutate(|m| {
 				T::MembershipChanged::set_members_sorted(&members[..], m);
 				Self::rejig_prime(&members);
+				let expiry = Self::default_expiry();
+				for who in m.iter().filter(|&who| members.binary_search(who).is_err()) {
+					<ExpiresAt<T, I>>::remove(who);
+				}
+				for who in members.iter().filter(|&who| m.binary_search(who).is_err()) {
+					Self::set_expiry(who, expiry);
+				}
 				*m = members;
 			});
 
@@ -281,6 +334,9 @@ pub mod pallet {
 				members.sort();
 
 				<Members<T, I>>::put(&members);
+				if let Some(expires_at) = <ExpiresAt<T, I>>::take(&remove) {
+					<ExpiresAt<T, I>>::insert(&new, expires_at);
+				}
 
 				T::MembershipChanged::change_members_sorted(
 					&[new.clone()],
@@ -323,6 +379,30 @@ pub mod pallet {
 			T::MembershipChanged::set_prime(None);
 			Ok(())
 		}
+
+		/// Renew the membership of the member `who` until the block `expires_at`, or indefinitely
+		/// if `None`.
+		///
+		/// May only be called from `T::RenewOrigin`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::renew(T::MaxMembers::get()))]
+		pub fn renew(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::RenewOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::members().binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
+			if let Some(expires_at) = expires_at {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(expires_at > now, Error::<T, I>::ExpiryInPast);
+			}
+
+			Self::set_expiry(&who, expires_at);
+			Self::deposit_event(Event::MemberRenewed { who, expires_at });
+			Ok(())
+		}
 	}
 }
 
@@ -335,6 +415,68 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 		}
 	}
+
+	/// The expiry of a membership starting now, according to `T::MembershipPeriod`.
+	fn default_expiry() -> Option<BlockNumberFor<T>> {
+		T::MembershipPeriod::get()
+			.map(|period| frame_system::Pallet::<T>::block_number().saturating_add(period))
+	}
+
+	fn set_expiry(who: &T::AccountId, expires_at: Option<BlockNumberFor<T>>) {
+		match expires_at {
+			Some(expires_at) => {
+				ExpiresAt::<T, I>::insert(who, expires_at);
+				NextExpiry::<T, I>::mutate(|next| {
+					*next = Some(next.map_or(expires_at, |n| n.min(expires_at)))
+				});
+			},
+			None => ExpiresAt::<T, I>::remove(who),
+		}
+	}
+
+	/// Remove the members whose membership expired by `now`, if it fits in `remaining_weight`.
+	fn prune_expired(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let check_weight = T::DbWeight::get().reads(1);
+		if !remaining_weight.all_gte(check_weight) {
+			return Weight::zero()
+		}
+		match NextExpiry::<T, I>::get() {
+			Some(next) if next <= now => {},
+			_ => return check_weight,
+		}
+
+		let members = Members::<T, I>::get();
+		let weight = T::WeightInfo::prune_expired(members.len() as u32);
+		if !remaining_weight.all_gte(weight) {
+			return check_weight
+		}
+
+		let mut next_expiry: Option<BlockNumberFor<T>> = None;
+		let (expired, remaining): (Vec<_>, Vec<_>) =
+			members.into_iter().partition(|who| match ExpiresAt::<T, I>::get(who) {
+				Some(expires_at) if expires_at <= now => true,
+				Some(expires_at) => {
+					next_expiry = Some(next_expiry.map_or(expires_at, |n| n.min(expires_at)));
+					false
+				},
+				None => false,
+			});
+		NextExpiry::<T, I>::set(next_expiry);
+
+		if !expired.is_empty() {
+			for who in expired.iter() {
+				ExpiresAt::<T, I>::remove(who);
+			}
+			let members = BoundedVec::<_, T::MaxMembers>::truncate_from(remaining);
+			Members::<T, I>::put(&members);
+
+			T::MembershipChanged::change_members_sorted(&[], &expired, &members[..]);
+			Self::rejig_prime(&members);
+
+			Self::deposit_event(Event::MembersExpired { members: expired });
+		}
+		weight
+	}
 }
 
 impl<T: Config<I>, I: 'static> Contains<T::AccountId> for Pallet<T, I> {
@@ -353,6 +495,13 @@ impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
 	}
 }
 
+impl<T: Config<I>, I: 'static> SnapshotMembers<T::AccountId, T::Hash> for Pallet<T, I> {
+	fn membership_snapshot() -> MembershipSnapshot<T::AccountId, T::Hash> {
+		let members = Self::members().into_iter().map(|who| (who, None)).collect();
+		MembershipSnapshot::new::<T::Hashing>(members, Prime::<T, I>::get())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmark {
 	use super::{Pallet as Membership, *};
@@ -514,6 +663,45 @@ mod benchmark {
 			#[cfg(test)] crate::tests::clean();
 		}
 
+		renew {
+			let m in 1 .. T::MaxMembers::get();
+			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
+			let who = members.last().cloned().unwrap();
+			let who_lookup = T::Lookup::unlookup(who.clone());
+			set_members::<T, I>(members, None);
+			let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		}: {
+			assert_ok!(<Membership<T, I>>::renew(
+				T::RenewOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				who_lookup,
+				Some(expires_at),
+			));
+		} verify {
+			assert_eq!(<ExpiresAt<T, I>>::get(&who), Some(expires_at));
+			#[cfg(test)] crate::tests::clean();
+		}
+
+		// all but the prime expire, so that the prime is rejigged and the next expiry is updated.
+		prune_expired {
+			let m in 2 .. T::MaxMembers::get();
+			let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
+			set_members::<T, I>(members.clone(), Some(members.len() - 1));
+			let now = frame_system::Pallet::<T>::block_number();
+			for who in members.iter() {
+				<ExpiresAt<T, I>>::insert(who, now);
+			}
+			let prime = members.last().cloned().unwrap();
+			<ExpiresAt<T, I>>::insert(&prime, now + 1u32.into());
+			<NextExpiry<T, I>>::put(now);
+		}: {
+			<Membership<T, I>>::prune_expired(now, Weight::MAX);
+		} verify {
+			assert_eq!(<Members<T, I>>::get(), vec![prime.clone()]);
+			assert_eq!(<Prime<T, I>>::get(), Some(prime));
+			assert!(T::MembershipChanged::get_prime().is_some());
+			#[cfg(test)] crate::tests::clean();
+		}
+
 		impl_benchmark_test_suite!(Membership, crate::tests::new_bench_ext(), crate::tests::Test);
 	}
 }
@@ -532,7 +720,7 @@ mod tests {
 
 	use frame_support::{
 		assert_noop, assert_ok, derive_impl, ord_parameter_types, parameter_types,
-		traits::{ConstU32, ConstU64, StorageVersion},
+		traits::{ConstU32, ConstU64, Hooks, StorageVersion},
 	};
 	use frame_system::EnsureSignedBy;
 
@@ -549,6 +737,7 @@ mod tests {
 	parameter_types! {
 		pub static Members: Vec<u64> = vec![];
 		pub static Prime: Option<u64> = None;
+		pub static MembershipPeriod: Option<u64> = None;
 	}
 
 	#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -583,6 +772,7 @@ mod tests {
 		pub const Three: u64 = 3;
 		pub const Four: u64 = 4;
 		pub const Five: u64 = 5;
+		pub const Six: u64 = 6;
 	}
 
 	pub struct TestChangeMembers;
@@ -620,6 +810,8 @@ mod tests {
 		type SwapOrigin = EnsureSignedBy<Three, u64>;
 		type ResetOrigin = EnsureSignedBy<Four, u64>;
 		type PrimeOrigin = EnsureSignedBy<Five, u64>;
+		type RenewOrigin = EnsureSignedBy<Six, u64>;
+		type MembershipPeriod = MembershipPeriod;
 		type MembershipInitialized = TestChangeMembers;
 		type MembershipChanged = TestChangeMembers;
 		type MaxMembers = ConstU32<10>;
@@ -675,6 +867,23 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn membership_snapshot_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Membership::set_prime(RuntimeOrigin::signed(5), 20));
+			let snapshot = Membership::membership_snapshot();
+			assert_eq!(snapshot.members, vec![(10, None), (20, None), (30, None)]);
+			assert_eq!(snapshot.prime, Some(20));
+
+			let proof = snapshot.prove::<BlakeTwo256>(&20).unwrap();
+			assert!(proof.leaf.prime);
+			assert!(proof.verify::<BlakeTwo256>(&snapshot.root));
+
+			assert_ok!(Membership::add_member(RuntimeOrigin::signed(1), 15));
+			assert!(!proof.verify::<BlakeTwo256>(&Membership::membership_snapshot().root));
+		});
+	}
+
 	#[test]
 	fn add_member_works() {
 		new_test_ext().execute_with(|| {
@@ -795,6 +1004,96 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn memberships_expire_by_default() {
+		new_test_ext().execute_with(|| {
+			MembershipPeriod::set(Some(10));
+			System::set_block_number(1);
+			assert_ok!(Membership::add_member(RuntimeOrigin::signed(1), 15));
+			assert_eq!(ExpiresAt::<Test>::get(15), Some(11));
+			assert_eq!(NextExpiry::<Test>::get(), Some(11));
+			// Genesis members do not expire.
+			assert_eq!(ExpiresAt::<Test>::get(10), None);
+
+			// Swapping in a member starts a new membership, changing the key keeps it.
+			System::set_block_number(3);
+			assert_ok!(Membership::swap_member(RuntimeOrigin::signed(3), 10, 25));
+			assert_eq!(ExpiresAt::<Test>::get(25), Some(13));
+			assert_ok!(Membership::change_key(RuntimeOrigin::signed(25), 5));
+			assert_eq!(ExpiresAt::<Test>::get(25), None);
+			assert_eq!(ExpiresAt::<Test>::get(5), Some(13));
+
+			assert_ok!(Membership::remove_member(RuntimeOrigin::signed(2), 5));
+			assert_eq!(ExpiresAt::<Test>::get(5), None);
+		});
+	}
+
+	#[test]
+	fn renew_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(5);
+			assert_noop!(Membership::renew(RuntimeOrigin::signed(1), 10, Some(20)), BadOrigin);
+			assert_noop!(
+				Membership::renew(RuntimeOrigin::signed(6), 15, Some(20)),
+				Error::<Test, _>::NotMember
+			);
+			assert_noop!(
+				Membership::renew(RuntimeOrigin::signed(6), 10, Some(5)),
+				Error::<Test, _>::ExpiryInPast
+			);
+
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 10, Some(20)));
+			assert_eq!(ExpiresAt::<Test>::get(10), Some(20));
+			System::assert_last_event(
+				Event::<Test>::MemberRenewed { who: 10, expires_at: Some(20) }.into(),
+			);
+
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 10, None));
+			assert_eq!(ExpiresAt::<Test>::get(10), None);
+		});
+	}
+
+	#[test]
+	fn expired_members_are_pruned_on_idle() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 10, Some(5)));
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 20, Some(5)));
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 30, Some(8)));
+			assert_ok!(Membership::set_prime(RuntimeOrigin::signed(5), 30));
+
+			Membership::on_idle(4, Weight::MAX);
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+
+			// Not enough weight left to prune.
+			Membership::on_idle(5, <() as WeightInfo>::prune_expired(3) / 2);
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+
+			Membership::on_idle(5, Weight::MAX);
+			assert_eq!(Membership::members(), vec![30]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members().to_vec());
+			System::assert_last_event(
+				Event::<Test>::MembersExpired { members: vec![10, 20] }.into(),
+			);
+			assert_eq!(ExpiresAt::<Test>::get(10), None);
+			// The prime is still a member and therefore set again.
+			assert_eq!(Membership::prime(), Some(30));
+			assert_eq!(PRIME.with(|m| *m.borrow()), Membership::prime());
+			assert_eq!(NextExpiry::<Test>::get(), Some(8));
+
+			// Renewal postpones the expiry.
+			assert_ok!(Membership::renew(RuntimeOrigin::signed(6), 30, Some(12)));
+			Membership::on_idle(8, Weight::MAX);
+			assert_eq!(Membership::members(), vec![30]);
+			assert_eq!(NextExpiry::<Test>::get(), Some(12));
+
+			Membership::on_idle(12, Weight::MAX);
+			assert!(Membership::members().is_empty());
+			assert_eq!(Membership::prime(), None);
+			assert_eq!(NextExpiry::<Test>::get(), None);
+		});
+	}
+
 	#[test]
 	#[should_panic(expected = "Members cannot contain duplicate accounts.")]
 	fn genesis_build_panics_with_duplicate_members() {
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since memberships can expire. The `renew` and `prune_expired` weights are
+//! PLACEHOLDERS estimated by hand, and the other calls do not yet account for the `ExpiresAt` and
+//! `NextExpiry` writes. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -59,6 +63,8 @@ pub trait WeightInfo {
 	fn change_key(m: u32, ) -> Weight;
 	fn set_prime(m: u32, ) -> Weight;
 	fn clear_prime(m: u32, ) -> Weight;
+	fn renew(m: u32, ) -> Weight;
+	fn prune_expired(m: u32, ) -> Weight;
 }
 
 /// Weights for pallet_membership using the Substrate node and recommended hardware.
@@ -211,6 +217,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(505, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: TechnicalMembership Members (r:1 w:0)
+	/// Proof: TechnicalMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership NextExpiry (r:1 w:1)
+	/// Proof: TechnicalMembership NextExpiry (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership ExpiresAt (r:0 w:1)
+	/// Proof: TechnicalMembership ExpiresAt (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 100]`.
+	fn renew(m: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(9_724_318, 4687)
+			.saturating_add(Weight::from_parts(19_207, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
+	/// Storage: TechnicalMembership NextExpiry (r:1 w:1)
+	/// Proof: TechnicalMembership NextExpiry (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership Members (r:1 w:1)
+	/// Proof: TechnicalMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership ExpiresAt (r:100 w:99)
+	/// Proof: TechnicalMembership ExpiresAt (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TechnicalCommittee Proposals (r:1 w:0)
+	/// Proof Skipped: TechnicalCommittee Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: TechnicalMembership Prime (r:1 w:0)
+	/// Proof: TechnicalMembership Prime (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TechnicalCommittee Members (r:0 w:1)
+	/// Proof Skipped: TechnicalCommittee Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: TechnicalCommittee Prime (r:0 w:1)
+	/// Proof Skipped: TechnicalCommittee Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `m` is `[2, 100]`.
+	fn prune_expired(m: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(24_813_540, 4687)
+			.saturating_add(Weight::from_parts(3_071_226, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(m.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -362,4 +408,44 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(505, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: TechnicalMembership Members (r:1 w:0)
+	/// Proof: TechnicalMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership NextExpiry (r:1 w:1)
+	/// Proof: TechnicalMembership NextExpiry (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership ExpiresAt (r:0 w:1)
+	/// Proof: TechnicalMembership ExpiresAt (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 100]`.
+	fn renew(m: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(9_724_318, 4687)
+			.saturating_add(Weight::from_parts(19_207, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
+	/// Storage: TechnicalMembership NextExpiry (r:1 w:1)
+	/// Proof: TechnicalMembership NextExpiry (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership Members (r:1 w:1)
+	/// Proof: TechnicalMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: TechnicalMembership ExpiresAt (r:100 w:99)
+	/// Proof: TechnicalMembership ExpiresAt (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: TechnicalCommittee Proposals (r:1 w:0)
+	/// Proof Skipped: TechnicalCommittee Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: TechnicalMembership Prime (r:1 w:0)
+	/// Proof: TechnicalMembership Prime (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: TechnicalCommittee Members (r:0 w:1)
+	/// Proof Skipped: TechnicalCommittee Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: TechnicalCommittee Prime (r:0 w:1)
+	/// Proof Skipped: TechnicalCommittee Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `m` is `[2, 100]`.
+	fn prune_expired(m: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(24_813_540, 4687)
+			.saturating_add(Weight::from_parts(3_071_226, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(m.into()))
+	}
 }
@@ -339,6 +339,41 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		mmr.generate_proof(leaf_indices)
 	}
 
+	/// Generate a single MMR proof for an arbitrary set of `leaf_indices`.
+	///
+	/// The leaf indices don't need to be contiguous: they are sorted and deduplicated, and the
+	/// returned leaves are in the order of the `leaf_indices` of the returned proof.
+	/// If `best_known_block_number = Some(n)`, this generates a historical proof for
+	/// the chain with head at height `n`.
+	/// Else it generates a proof for the MMR at the current block height.
+	///
+	/// Note this method can only be used from an off-chain context
+	/// (Offchain Worker or Runtime API call), since it requires
+	/// all the leaves to be present.
+	pub fn generate_leaf_proof(
+		mut leaf_indices: Vec<LeafIndex>,
+		best_known_block_number: Option<BlockNumberFor<T>>,
+	) -> Result<(Vec<LeafOf<T, I>>, primitives::Proof<HashOf<T, I>>), primitives::Error> {
+		// check whether best_known_block_number provided, else use current best block
+		let best_known_block_number =
+			best_known_block_number.unwrap_or_else(|| <frame_system::Pallet<T>>::block_number());
+
+		let leaves_count =
+			Self::block_num_to_leaf_index(best_known_block_number)?.saturating_add(1);
+
+		leaf_indices.sort_unstable();
+		leaf_indices.dedup();
+		match leaf_indices.last() {
+			Some(last) if *last < leaves_count => (),
+			_ =>
+				return Err(primitives::Error::InvalidLeafIndex
+					.log_debug(("Leaf indices are empty or out of bounds.", leaves_count))),
+		}
+
+		let mmr: ModuleMmr<mmr::storage::OffchainStorage, T, I> = mmr::Mmr::new(leaves_count);
+		mmr.generate_proof(leaf_indices)
+	}
+
 	/// Return the on-chain MMR root hash.
 	pub fn mmr_root() -> HashOf<T, I> {
 		Self::mmr_root_hash()
@@ -27,9 +27,8 @@ use sp_mmr_primitives::{mmr_lib, utils::NodesUtils};
 use sp_std::prelude::*;
 
 /// Stateless verification of the proof for a batch of leaves.
-/// Note, the leaves should be sorted such that corresponding leaves and leaf indices have the
-/// same position in both the `leaves` vector and the `leaf_indices` vector contained in the
-/// [primitives::Proof]
+///
+/// See [`sp_mmr_primitives::utils::verify_leaves_proof`].
 pub fn verify_leaves_proof<H, L>(
 	root: H::Output,
 	leaves: Vec<Node<H, L>>,
@@ -39,25 +38,7 @@ where
 	H: sp_runtime::traits::Hash,
 	L: primitives::FullLeaf,
 {
-	let size = NodesUtils::new(proof.leaf_count).size();
-
-	if leaves.len() != proof.leaf_indices.len() {
-		return Err(Error::Verify.log_debug("Proof leaf_indices not same length with leaves"))
-	}
-
-	let leaves_and_position_data = proof
-		.leaf_indices
-		.into_iter()
-		.map(|index| mmr_lib::leaf_index_to_pos(index))
-		.zip(leaves.into_iter())
-		.collect();
-
-	let p = mmr_lib::MerkleProof::<Node<H, L>, Hasher<H, L>>::new(
-		size,
-		proof.items.into_iter().map(Node::Hash).collect(),
-	);
-	p.verify(Node::Hash(root), leaves_and_position_data)
-		.map_err(|e| Error::Verify.log_debug(e))
+	sp_mmr_primitives::utils::verify_leaves_proof::<H, L>(root, leaves, proof)
 }
 
 /// A wrapper around an MMR library to expose limited functionality.
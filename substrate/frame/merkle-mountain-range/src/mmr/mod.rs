@@ -18,27 +18,13 @@
 mod mmr;
 pub mod storage;
 
-use sp_mmr_primitives::{mmr_lib, DataOrHash, FullLeaf};
-use sp_runtime::traits;
+use sp_mmr_primitives::DataOrHash;
 
 pub use self::mmr::{verify_leaves_proof, Mmr};
+pub use sp_mmr_primitives::utils::Hasher;
 
 /// Node type for runtime `T`.
 pub type NodeOf<T, I, L> = Node<<T as crate::Config<I>>::Hashing, L>;
 
 /// A node stored in the MMR.
 pub type Node<H, L> = DataOrHash<H, L>;
-
-/// Default Merging & Hashing behavior for MMR.
-pub struct Hasher<H, L>(sp_std::marker::PhantomData<(H, L)>);
-
-impl<H: traits::Hash, L: FullLeaf> mmr_lib::Merge for Hasher<H, L> {
-	type Item = Node<H, L>;
-
-	fn merge(left: &Self::Item, right: &Self::Item) -> mmr_lib::Result<Self::Item> {
-		let mut concat = left.hash().as_ref().to_vec();
-		concat.extend_from_slice(right.hash().as_ref());
-
-		Ok(Node::Hash(<H as traits::Hash>::hash(&concat)))
-	}
-}
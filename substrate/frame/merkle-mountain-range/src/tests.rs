@@ -700,6 +700,62 @@ fn should_verify_batch_proof_statelessly() {
 	);
 }
 
+#[test]
+fn should_generate_and_verify_proof_for_scattered_leaves() {
+	let _ = env_logger::try_init();
+
+	let mut ext = new_test_ext();
+	let root_7 = ext.execute_with(|| {
+		add_blocks(7);
+		crate::Pallet::<Test>::mmr_root_hash()
+	});
+	ext.persist_offchain_overlay();
+	register_offchain_ext(&mut ext);
+
+	let (leaves, proof) = ext.execute_with(|| {
+		// when
+		crate::Pallet::<Test>::generate_leaf_proof(vec![4, 0, 3, 0], None).unwrap()
+	});
+
+	// then the leaf indices are sorted and deduplicated
+	assert_eq!(proof.leaf_indices, vec![0, 3, 4]);
+	let by_block_numbers =
+		ext.execute_with(|| crate::Pallet::<Test>::generate_proof(vec![1, 4, 5], None).unwrap());
+	assert_eq!((leaves.clone(), proof.clone()), by_block_numbers);
+
+	// and the proof verifies statelessly, whatever the order of the leaves
+	let mut nodes: Vec<_> =
+		leaves.into_iter().map(|leaf| crate::primitives::DataOrHash::Data(leaf)).collect();
+	let mut reversed = proof.clone();
+	nodes.reverse();
+	reversed.leaf_indices.reverse();
+	assert_eq!(
+		utils::verify_leaves_proof::<<Test as Config>::Hashing, _>(root_7, nodes.clone(), reversed),
+		Ok(true)
+	);
+
+	// but not with duplicate leaves
+	let mut duplicated = proof.clone();
+	duplicated.leaf_indices.push(0);
+	nodes.push(nodes[2].clone());
+	assert_eq!(
+		utils::verify_leaves_proof::<<Test as Config>::Hashing, _>(root_7, nodes, duplicated),
+		Err(Error::Verify)
+	);
+
+	// leaf indices out of bounds or empty are rejected
+	ext.execute_with(|| {
+		assert_eq!(
+			crate::Pallet::<Test>::generate_leaf_proof(vec![2, 7], None),
+			Err(Error::InvalidLeafIndex)
+		);
+		assert_eq!(
+			crate::Pallet::<Test>::generate_leaf_proof(vec![], None),
+			Err(Error::InvalidLeafIndex)
+		);
+	});
+}
+
 #[test]
 fn should_verify_on_the_next_block_since_there_is_no_pruning_yet() {
 	let _ = env_logger::try_init();
@@ -194,8 +194,8 @@ use frame_support::{
 	defensive,
 	pallet_prelude::*,
 	traits::{
-		DefensiveTruncateFrom, EnqueueMessage, ExecuteOverweightError, Footprint, ProcessMessage,
-		ProcessMessageError, QueueFootprint, QueuePausedQuery, ServiceQueues,
+		DefensiveTruncateFrom, DescribeMessage, EnqueueMessage, ExecuteOverweightError, Footprint,
+		ProcessMessage, ProcessMessageError, QueueFootprint, QueuePausedQuery, ServiceQueues,
 	},
 	BoundedSlice, CloneNoBound, DefaultNoBound,
 };
@@ -442,6 +442,43 @@ impl<Id> OnQueueChanged<Id> for () {
 	fn on_queue_changed(_: Id, _: QueueFootprint) {}
 }
 
+/// A message waiting at the head of a queue, as returned by [`Pallet::queue_heads`].
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct QueuedMessage<Summary> {
+	/// The length of the message in bytes.
+	pub size: u32,
+	/// The description of the message, if it could be described.
+	pub summary: Option<Summary>,
+}
+
+/// The head of a queue, as returned by [`Pallet::queue_heads`].
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct QueueHead<MessageOrigin, Summary> {
+	/// The origin of the queue.
+	pub origin: MessageOrigin,
+	/// The number of unprocessed messages in the queue.
+	pub message_count: u64,
+	/// The total size of all unprocessed messages in the queue.
+	pub size: u64,
+	/// The first unprocessed messages of the queue in the order in which they will be processed.
+	pub messages: Vec<QueuedMessage<Summary>>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to inspect the messages that are waiting to be processed.
+	pub trait MessageQueueApi<MessageOrigin, Summary>
+	where
+		MessageOrigin: Codec,
+		Summary: Codec,
+	{
+		/// Returns the heads of all queues that are ready to be serviced.
+		///
+		/// The queues are returned in the order in which they will be serviced, each with at most
+		/// `max_messages` of its first unprocessed messages.
+		fn queue_heads(max_messages: u32) -> Vec<QueueHead<MessageOrigin, Summary>>;
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -1299,6 +1336,60 @@ impl<T: Config> Pallet<T> {
 		info
 	}
 
+	/// Returns the heads of all queues in the ready ring, starting with the one that is serviced
+	/// next.
+	///
+	/// Each queue contains at most `max_messages` of its first unprocessed messages, described by
+	/// `D`. Messages that were skipped as overweight are not included.
+	///
+	/// Intended to back the [`MessageQueueApi`]; this reads a lot of storage and must not be
+	/// called from within a block.
+	pub fn queue_heads<D: DescribeMessage>(
+		max_messages: u32,
+	) -> Vec<QueueHead<MessageOriginOf<T>, D::Summary>> {
+		let mut heads = Vec::new();
+		let Some(first) = ServiceHead::<T>::get() else { return heads };
+		let mut origin = first.clone();
+
+		loop {
+			let book_state = BookStateFor::<T>::get(&origin);
+			let mut messages = Vec::new();
+			for page_index in book_state.begin..book_state.end {
+				if messages.len() >= max_messages as usize {
+					break
+				}
+				let Some(page) = Pages::<T>::get(&origin, page_index) else { continue };
+				let mut index = page.first_index.into() as usize;
+				while messages.len() < max_messages as usize {
+					let Some((pos, processed, message)) = page.peek_index(index) else { break };
+					if pos > page.last.into() as usize {
+						break
+					}
+					if !processed {
+						messages.push(QueuedMessage {
+							size: message.len() as u32,
+							summary: D::describe(message),
+						});
+					}
+					index.saturating_inc();
+				}
+			}
+
+			let next = book_state.ready_neighbours.as_ref().map(|n| n.next.clone());
+			heads.push(QueueHead {
+				origin,
+				message_count: book_state.message_count,
+				size: book_state.size,
+				messages,
+			});
+			match next {
+				Some(next) if next != first => origin = next,
+				_ => break,
+			}
+		}
+		heads
+	}
+
 	/// Process a single message.
 	///
 	/// The base weight of this function needs to be accounted for by the caller. `weight` is the
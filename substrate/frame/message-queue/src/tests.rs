@@ -1662,3 +1662,50 @@ fn integrity_test_checks_service_weight() {
 		}
 	});
 }
+
+#[test]
+fn queue_heads_works() {
+	/// Describes a message by its first byte.
+	struct FirstByte;
+	impl DescribeMessage for FirstByte {
+		type Summary = u8;
+
+		fn describe(message: &[u8]) -> Option<u8> {
+			message.first().copied()
+		}
+	}
+
+	let queued = |m: &str| QueuedMessage { size: m.len() as u32, summary: m.bytes().next() };
+
+	build_and_execute::<Test>(|| {
+		use MessageOrigin::*;
+		assert!(MessageQueue::queue_heads::<FirstByte>(2).is_empty());
+
+		MessageQueue::enqueue_messages([msg("a"), msg("bb"), msg("c")].into_iter(), Here);
+		MessageQueue::enqueue_message(msg("dd"), There);
+
+		assert_eq!(
+			MessageQueue::queue_heads::<FirstByte>(2),
+			vec![
+				QueueHead {
+					origin: Here,
+					message_count: 3,
+					size: 4,
+					messages: vec![queued("a"), queued("bb")],
+				},
+				QueueHead { origin: There, message_count: 1, size: 2, messages: vec![queued("dd")] },
+			]
+		);
+
+		// Processed messages are not reported anymore.
+		assert_eq!(MessageQueue::service_queues(1.into_weight()), 1.into_weight());
+		let heads = MessageQueue::queue_heads::<FirstByte>(5);
+		let here = heads.iter().find(|h| h.origin == Here).unwrap();
+		assert_eq!(here.messages, vec![queued("bb"), queued("c")]);
+		assert_eq!(here.message_count, 2);
+
+		// No description without a describer.
+		let heads = MessageQueue::queue_heads::<()>(1);
+		assert!(heads.iter().all(|h| h.messages.iter().all(|m| m.summary.is_none())));
+	});
+}
@@ -37,8 +37,20 @@
 //!
 //! * `as_multi` - Approve and if possible dispatch a call from a composite origin formed from a
 //!   number of signed origins.
-//! * `approve_as_multi` - Approve a call from a composite origin.
+//! * `approve_as_multi` - Approve a call from a composite origin, and if possible dispatch it if
+//!   its preimage was noted and is no longer than `MaxNotedCallLen`.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//!
+//! ### Preimages and Expiry
+//!
+//! The preimage of the call of a pending multisig operation is requested from `Preimages` for as
+//! long as the operation is pending, so the call may be noted there once instead of being passed
+//! with the final approval. Once the operation ends, its preimage is no longer requested: a
+//! preimage noted while it was requested is cleared, while one noted beforehand is left to its
+//! owner to unnote.
+//!
+//! Pending operations expire `MultisigExpiry` blocks after they were opened. Expired operations
+//! are cancelled in `on_idle`, returning their deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -54,16 +66,17 @@ use frame_support::{
 		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
 		PostDispatchInfo,
 	},
+	dispatch_context::dispatch_nested,
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
-	weights::Weight,
+	traits::{Currency, Get, QueryPreimage, ReservableCurrency, StorePreimage},
+	weights::{Weight, WeightMeter},
 	BoundedVec,
 };
 use frame_system::{self as system, pallet_prelude::BlockNumberFor, RawOrigin};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{Dispatchable, TrailingZeroInput, Zero},
+	traits::{BlakeTwo256, Dispatchable, Hash, Saturating, TrailingZeroInput, Zero},
 	DispatchError, RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -120,6 +133,16 @@ where
 
 type CallHash = [u8; 32];
 
+/// The progress of queueing the multisig operations which were opened before version 2 of the
+/// storage for expiry.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ExpiryMigrationCursor<AccountId> {
+	/// No multisig operation was migrated yet.
+	Start,
+	/// The multisig operations up to and including this one were migrated.
+	After(AccountId, CallHash),
+}
+
 enum CallOrHash<T: Config> {
 	Call(<T as Config>::RuntimeCall),
 	Hash([u8; 32]),
@@ -164,12 +187,27 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The preimage provider from which the calls of multisig operations may be fetched by
+		/// their hash.
+		///
+		/// The calls are hashed with Blake2-256, so the provider must be too.
+		type Preimages: QueryPreimage<H = BlakeTwo256> + StorePreimage;
+
+		/// The maximum encoded length of a call noted with `Preimages` which `approve_as_multi`
+		/// dispatches. Longer calls must be dispatched with `as_multi`.
+		#[pallet::constant]
+		type MaxNotedCallLen: Get<u32>;
+
+		/// The number of blocks after which a pending multisig operation expires and is cancelled.
+		#[pallet::constant]
+		type MultisigExpiry: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -186,6 +224,28 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The open multisig operations by the block in which they expire.
+	#[pallet::storage]
+	pub(super) type ExpiryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, CallHash),
+		(),
+		OptionQuery,
+	>;
+
+	/// The first block of `ExpiryQueue` not yet fully processed, if anything was ever queued.
+	#[pallet::storage]
+	pub(super) type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The multisig operation after which [`migrations::v2::MigrateToV2`] continues to queue the
+	/// operations of [`Multisigs`] for expiry. Absent once all operations were migrated.
+	#[pallet::storage]
+	pub type ExpiryMigration<T: Config> =
+		StorageValue<_, ExpiryMigrationCursor<T::AccountId>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -245,10 +305,23 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// A multisig operation has expired and been cancelled.
+		MultisigExpired {
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let weight = migrations::v2::migrate_expiry::<T>(remaining_weight);
+			weight.saturating_add(
+				Self::expire_multisigs(now, remaining_weight.saturating_sub(weight)),
+			)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -290,7 +363,9 @@ pub mod pallet {
 			let id = Self::multi_account_id(&signatories, 1);
 
 			let call_len = call.using_encoded(|c| c.len());
-			let result = call.dispatch(RawOrigin::Signed(id).into());
+			let result = dispatch_nested::<T::NestedCallLimits, _, _, _>(*call, |call| {
+				call.dispatch(RawOrigin::Signed(id).into())
+			});
 
 			result
 				.map(|post_dispatch_info| {
@@ -398,7 +473,10 @@ pub mod pallet {
 		/// transaction index) of the first approval transaction.
 		/// - `call_hash`: The hash of the call to be executed.
 		///
-		/// NOTE: If this is the final approval, you will want to use `as_multi` instead.
+		/// If this is the final approval and the preimage of `call_hash` was noted with
+		/// `Preimages`, then the call is dispatched, as long as it is no longer than
+		/// `MaxNotedCallLen`. Otherwise, if this is the final approval, you will want to use
+		/// `as_multi` instead.
 		///
 		/// ## Complexity
 		/// - `O(S)`.
@@ -414,9 +492,11 @@ pub mod pallet {
 		#[pallet::call_index(2)]
 		#[pallet::weight({
 			let s = other_signatories.len() as u32;
+			let z = T::MaxNotedCallLen::get();
 
 			T::WeightInfo::approve_as_multi_create(s)
 				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.max(T::WeightInfo::approve_as_multi_complete(s, z))
 				.saturating_add(*max_weight)
 		})]
 		pub fn approve_as_multi(
@@ -484,6 +564,7 @@ pub mod pallet {
 			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
 			debug_assert!(err_amount.is_zero());
 			<Multisigs<T>>::remove(&id, &call_hash);
+			Self::end(&id, call_hash, &m.when);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -525,12 +606,12 @@ impl<T: Config> Pallet<T> {
 		let id = Self::multi_account_id(&signatories, threshold);
 
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
-		let (call_hash, call_len, maybe_call) = match call_or_hash {
+		let (call_hash, mut call_len, mut maybe_call, by_hash) = match call_or_hash {
 			CallOrHash::Call(call) => {
 				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
-				(call_hash, call_len, Some(call))
+				(call_hash, call_len, Some(call), false)
 			},
-			CallOrHash::Hash(h) => (h, 0, None),
+			CallOrHash::Hash(h) => (h, 0, None, true),
 		};
 
 		// Branch on whether the operation has already started or not.
@@ -548,6 +629,13 @@ impl<T: Config> Pallet<T> {
 				approvals += 1;
 			}
 
+			// Without the call, try its noted preimage if we're ready to execute.
+			if maybe_call.is_none() && approvals >= threshold {
+				if let Some((call, len)) = Self::fetch_call(&call_hash) {
+					(maybe_call, call_len) = (Some(call), len);
+				}
+			}
+
 			// We only bother fetching/decoding call if we know that we're ready to execute.
 			if let Some(call) = maybe_call.filter(|_| approvals >= threshold) {
 				// verify weight
@@ -560,8 +648,12 @@ impl<T: Config> Pallet<T> {
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
+				Self::end(&id, call_hash, &m.when);
 
-				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+				let origin = RawOrigin::Signed(id.clone()).into();
+				let result = dispatch_nested::<T::NestedCallLimits, _, _, _>(call, |call| {
+					call.dispatch(origin)
+				});
 				Self::deposit_event(Event::MultisigExecuted {
 					approving: who,
 					timepoint,
@@ -569,14 +661,14 @@ impl<T: Config> Pallet<T> {
 					call_hash,
 					result: result.map(|_| ()).map_err(|e| e.error),
 				});
+				let (s, z) = (other_signatories_len as u32, call_len as u32);
+				let complete_weight = if by_hash {
+					T::WeightInfo::approve_as_multi_complete(s, z)
+				} else {
+					T::WeightInfo::as_multi_complete(s, z)
+				};
 				Ok(get_result_weight(result)
-					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
-					})
+					.map(|actual_weight| complete_weight.saturating_add(actual_weight))
 					.into())
 			} else {
 				// We cannot dispatch the call now; either it isn't available, or it is, but we
@@ -617,16 +709,16 @@ impl<T: Config> Pallet<T> {
 			let initial_approvals =
 				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
 
+			let when = Self::timepoint();
 			<Multisigs<T>>::insert(
 				&id,
 				call_hash,
-				Multisig {
-					when: Self::timepoint(),
-					deposit,
-					depositor: who.clone(),
-					approvals: initial_approvals,
-				},
+				Multisig { when, deposit, depositor: who.clone(), approvals: initial_approvals },
 			);
+			if !Self::awaits_expiry_migration(&id, call_hash) {
+				Self::enqueue_expiry(&id, call_hash, Self::expires_at(&when));
+				T::Preimages::request(&preimage_hash(&call_hash));
+			}
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
 			let final_weight =
@@ -644,6 +736,108 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The block in which the multisig operation opened at `when` expires.
+	pub fn expires_at(when: &Timepoint<BlockNumberFor<T>>) -> BlockNumberFor<T> {
+		when.height.saturating_add(T::MultisigExpiry::get())
+	}
+
+	/// Queue the multisig operation `call_hash` of `id` for expiry at block `at`.
+	pub(crate) fn enqueue_expiry(id: &T::AccountId, call_hash: CallHash, at: BlockNumberFor<T>) {
+		ExpiryQueue::<T>::insert(at, (id, call_hash), ());
+		if ExpiryCursor::<T>::get().map_or(true, |cursor| at < cursor) {
+			ExpiryCursor::<T>::put(at);
+		}
+	}
+
+	/// Clean up after the multisig operation `call_hash` of `id` opened at `when` has been removed
+	/// from storage: dequeue it from expiry and stop requesting the preimage of its call.
+	fn end(id: &T::AccountId, call_hash: CallHash, when: &Timepoint<BlockNumberFor<T>>) {
+		ExpiryQueue::<T>::remove(Self::expires_at(when), (id, call_hash));
+		if !Self::awaits_expiry_migration(id, call_hash) {
+			T::Preimages::unrequest(&preimage_hash(&call_hash));
+		}
+	}
+
+	/// Whether the multisig operation `call_hash` of `id` is yet to be queued for expiry, and its
+	/// call requested, by [`migrations::v2::MigrateToV2`].
+	fn awaits_expiry_migration(id: &T::AccountId, call_hash: CallHash) -> bool {
+		match ExpiryMigration::<T>::get() {
+			None => false,
+			Some(ExpiryMigrationCursor::Start) => true,
+			Some(ExpiryMigrationCursor::After(last, last_hash)) =>
+				Multisigs::<T>::hashed_key_for(id, call_hash) >
+					Multisigs::<T>::hashed_key_for(last, last_hash),
+		}
+	}
+
+	/// Fetch the call with `call_hash` and its encoded length from the noted preimages, if any and
+	/// no longer than `MaxNotedCallLen`.
+	fn fetch_call(call_hash: &CallHash) -> Option<(<T as Config>::RuntimeCall, usize)> {
+		let hash = preimage_hash(call_hash);
+		let len = T::Preimages::len(&hash).filter(|len| *len <= T::MaxNotedCallLen::get())?;
+		let bounded = frame_support::traits::Bounded::Lookup { hash, len };
+		T::Preimages::peek(&bounded).ok().map(|(call, _)| (call, len as usize))
+	}
+
+	/// Cancel the queued multisig operations expired by block `now`, for as long as `limit`
+	/// allows.
+	pub(crate) fn expire_multisigs(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let step = T::DbWeight::get().reads(1);
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return Weight::zero()
+		}
+		let Some(mut cursor) = ExpiryCursor::<T>::get() else { return meter.consumed() };
+		let start = cursor;
+
+		while cursor <= now {
+			match ExpiryQueue::<T>::iter_key_prefix(cursor).next() {
+				Some((id, call_hash)) => {
+					let weight = T::WeightInfo::expire_multisig(T::MaxSignatories::get());
+					if meter.try_consume(weight).is_err() {
+						break
+					}
+					ExpiryQueue::<T>::remove(cursor, (&id, call_hash));
+					Self::expire_multisig(id, call_hash, now);
+				},
+				None => {
+					if meter.try_consume(step).is_err() {
+						break
+					}
+					cursor.saturating_inc();
+				},
+			}
+		}
+
+		if cursor != start {
+			ExpiryCursor::<T>::put(cursor);
+		}
+		meter.consumed()
+	}
+
+	/// Cancel the multisig operation `call_hash` of `id` if it expired by block `now`, returning
+	/// its deposit.
+	fn expire_multisig(id: T::AccountId, call_hash: CallHash, now: BlockNumberFor<T>) {
+		let Some(m) = <Multisigs<T>>::get(&id, call_hash) else { return };
+		let expires_at = Self::expires_at(&m.when);
+		if expires_at > now {
+			// `MultisigExpiry` was increased since the operation was queued.
+			Self::enqueue_expiry(&id, call_hash, expires_at);
+			return
+		}
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, call_hash);
+		Self::end(&id, call_hash, &m.when);
+
+		Self::deposit_event(Event::MultisigExpired {
+			timepoint: m.when,
+			multisig: id,
+			call_hash,
+		});
+	}
+
 	/// Check that signatories is sorted and doesn't contain sender, then insert sender.
 	fn ensure_sorted_and_insert(
 		other_signatories: Vec<T::AccountId>,
@@ -667,6 +861,13 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// The hash of the preimage of the call with `call_hash`.
+///
+/// Both are the Blake2-256 hash of the encoded call.
+fn preimage_hash(call_hash: &CallHash) -> <BlakeTwo256 as Hash>::Output {
+	(*call_hash).into()
+}
+
 /// Return the weight of a dispatch call result as an `Option`.
 ///
 /// Will return the weight regardless of what the state of the result is.
@@ -19,7 +19,7 @@
 
 use super::*;
 use frame_support::{
-	traits::{GetStorageVersion, OnRuntimeUpgrade, WrapperKeepOpaque},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion, WrapperKeepOpaque},
 	Identity,
 };
 
@@ -85,3 +85,84 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+
+	/// Queues all open multisig operations for expiry `MultisigExpiry` blocks after they were
+	/// opened and requests the preimages of their calls.
+	///
+	/// The upgrade only starts the migration. The operations are then migrated in steps with the
+	/// weight left at the end of each block, see [`migrate_expiry`].
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok(Pallet::<T>::on_chain_storage_version().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain != 1 {
+				log!(info, "MigrateToV2 should be removed");
+				return T::DbWeight::get().reads(1)
+			}
+
+			ExpiryMigration::<T>::put(ExpiryMigrationCursor::Start);
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log!(info, "Started to queue the multisig operations for expiry");
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "must upgrade to version 2");
+			let pre_version: StorageVersion = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			if pre_version == 1 {
+				ensure!(
+					ExpiryMigration::<T>::exists(),
+					"must start to queue the multisig operations for expiry"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Queue the multisig operations following the [`ExpiryMigration`] cursor for expiry and
+	/// request the preimages of their calls, as long as the migration of another operation fits
+	/// in `limit`.
+	///
+	/// Returns the consumed weight.
+	pub fn migrate_expiry<T: Config>(limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		// Reading an operation, then reading and writing its queue entry, the expiry cursor and
+		// the request status of its preimage.
+		let step = db.reads_writes(3, 3);
+		// Reading and updating the migration cursor.
+		let mut weight = db.reads_writes(1, 1);
+		if weight.saturating_add(step).any_gt(limit) {
+			return Weight::zero()
+		}
+
+		let Some(mut cursor) = ExpiryMigration::<T>::get() else { return db.reads(1) };
+		let mut multisigs = match &cursor {
+			ExpiryMigrationCursor::Start => Multisigs::<T>::iter(),
+			ExpiryMigrationCursor::After(id, call_hash) =>
+				Multisigs::<T>::iter_from(Multisigs::<T>::hashed_key_for(id, call_hash)),
+		};
+		while weight.saturating_add(step).all_lte(limit) {
+			let Some((id, call_hash, m)) = multisigs.next() else {
+				ExpiryMigration::<T>::kill();
+				log!(info, "Queued all multisig operations for expiry");
+				return weight
+			};
+			Pallet::<T>::enqueue_expiry(&id, call_hash, Pallet::<T>::expires_at(&m.when));
+			T::Preimages::request(&preimage_hash(&call_hash));
+			weight.saturating_accrue(step);
+			cursor = ExpiryMigrationCursor::After(id, call_hash);
+		}
+		ExpiryMigration::<T>::put(cursor);
+		weight
+	}
+}
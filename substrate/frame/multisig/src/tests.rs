@@ -24,8 +24,9 @@ use super::*;
 use crate as pallet_multisig;
 use frame_support::{
 	assert_noop, assert_ok, derive_impl,
-	traits::{ConstU32, ConstU64, Contains},
+	traits::{ConstU32, ConstU64, Contains, Hooks, QueryPreimage, StorePreimage},
 };
+use frame_system::EnsureRoot;
 use sp_runtime::{BuildStorage, TokenError};
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
@@ -35,6 +36,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>},
+		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -71,9 +73,20 @@ impl Config for Test {
 	type DepositBase = ConstU64<1>;
 	type DepositFactor = ConstU64<1>;
 	type MaxSignatories = ConstU32<3>;
+	type Preimages = Preimage;
+	type MaxNotedCallLen = ConstU32<1024>;
+	type MultisigExpiry = ConstU32<100>;
 	type WeightInfo = ();
 }
 
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Consideration = ();
+}
+
 use pallet_balances::Call as BalancesCall;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -317,6 +330,200 @@ fn cancel_multisig_works() {
 			Error::<Test>::NotOwner,
 		);
 		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash),);
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash.into()));
+	});
+}
+
+#[test]
+fn approve_as_multi_dispatches_noted_preimage() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		// The preimage of the call is requested while the operation is open.
+		assert!(<Preimage as QueryPreimage>::is_requested(&hash.into()));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(4), data));
+
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// The preimage noted while requested is cleared once the operation ended.
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash.into()));
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash.into()), None);
+	});
+}
+
+#[test]
+fn approve_as_multi_does_not_dispatch_long_noted_preimage() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call =
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 2000] }));
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(4), data));
+
+		// The noted call is longer than `MaxNotedCallLen`, so it is not dispatched.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			call_weight
+		));
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+
+		// It can still be dispatched by passing it.
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn preimage_noted_before_multisig_is_left_to_its_owner() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15);
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(4), data));
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 2, vec![2, 3], now(), hash));
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash.into()));
+		assert!(<Preimage as QueryPreimage>::len(&hash.into()).is_some());
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(4), hash.into()));
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash.into()), None);
+	});
+}
+
+#[test]
+fn expired_multisig_is_cancelled() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// Opened in block 1, the operation expires in block 101.
+		Multisig::on_idle(100, Weight::MAX);
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+
+		Multisig::on_idle(101, Weight::MAX);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash.into()));
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(102));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExpired { timepoint, multisig: multi, call_hash: hash }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn migration_to_v2_queues_open_multisigs_for_expiry() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		let when = Timepoint { height: 7, index: 0 };
+		Multisigs::<Test>::insert(
+			multi,
+			hash,
+			crate::Multisig {
+				when,
+				deposit: 0,
+				depositor: 1,
+				approvals: vec![1].try_into().unwrap(),
+			},
+		);
+		StorageVersion::new(1).put::<Multisig>();
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Multisig::on_chain_storage_version(), 2);
+		assert_eq!(ExpiryMigration::<Test>::get(), Some(ExpiryMigrationCursor::Start));
+		assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+
+		// Operations ending before they were migrated have no preimage request to end.
+		let other_hash = blake2_256(&call_transfer(6, 10).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			other_hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::cancel_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			now(),
+			other_hash
+		));
+
+		// The operations are migrated with the weight left in a block.
+		Multisig::on_idle(1, Weight::MAX);
+		assert_eq!(ExpiryMigration::<Test>::get(), None);
+		assert!(ExpiryQueue::<Test>::contains_key(107, (multi, hash)));
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(107));
+		assert!(<Preimage as QueryPreimage>::is_requested(&hash.into()));
+		assert!(!<Preimage as QueryPreimage>::is_requested(&other_hash.into()));
 	});
 }
 
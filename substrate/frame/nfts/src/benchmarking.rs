@@ -26,14 +26,17 @@ use frame_benchmarking::v1::{
 };
 use frame_support::{
 	assert_ok,
-	traits::{EnsureOrigin, Get, UnfilteredDispatchable},
+	traits::{
+		fungible::{Inspect as FunInspect, Mutate as FunMutate},
+		EnsureOrigin, Get, UnfilteredDispatchable,
+	},
 	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
 use sp_io::crypto::{sr25519_generate, sr25519_sign};
 use sp_runtime::{
 	traits::{Bounded, IdentifyAccount, One},
-	AccountId32, MultiSignature, MultiSigner,
+	AccountId32, MultiSignature, MultiSigner, Permill,
 };
 use sp_std::prelude::*;
 
@@ -278,9 +281,15 @@ benchmarks_instance_pallet! {
 		for i in 0..a {
 			add_collection_attribute::<T, I>(i as u16);
 		}
+		let royalty = RoyaltyConfig {
+			beneficiary: account("beneficiary", 0, SEED),
+			royalty: Permill::from_percent(10),
+		};
+		CollectionRoyaltyOf::<T, I>::insert(&collection, royalty);
 		let witness = Collection::<T, I>::get(collection).unwrap().destroy_witness();
 	}: _(SystemOrigin::Signed(caller), collection, witness)
 	verify {
+		assert!(!CollectionRoyaltyOf::<T, I>::contains_key(&collection));
 		assert_last_event::<T, I>(Event::Destroyed { collection }.into());
 	}
 
@@ -891,5 +900,43 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let royalty = RoyaltyConfig { beneficiary: beneficiary.clone(), royalty: Permill::one() };
+	}: _(SystemOrigin::Signed(caller.clone()), collection, Some(royalty))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet {
+			collection,
+			beneficiary,
+			royalty: Permill::one(),
+		}.into());
+	}
+
+	transfer_sale {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let royalty = RoyaltyConfig {
+			beneficiary: beneficiary.clone(),
+			royalty: Permill::from_percent(10),
+		};
+		CollectionRoyaltyOf::<T, I>::insert(&collection, royalty);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let price = T::RoyaltyAsset::minimum_balance() * 100u32.into();
+		T::RoyaltyAsset::set_balance(&caller, price);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, target_lookup, price)
+	verify {
+		assert_last_event::<T, I>(Event::ItemSold {
+			collection,
+			item,
+			price,
+			seller: caller,
+			buyer: target,
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
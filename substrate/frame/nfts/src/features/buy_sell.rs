@@ -119,7 +119,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// receive the bid price if it is equal to or higher than the item's set price. If
 	/// `whitelisted_buyer` is specified in the item's price information, only that account is
 	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
-	/// function will return an error.
+	/// function will return an error. If the collection has a royalty, it is paid from the price to
+	/// its beneficiary and the owner receives the rest.
 	///
 	/// - `collection`: The identifier of the collection containing the item to be bought.
 	/// - `item`: The identifier of the item to be bought.
@@ -148,10 +149,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
+		let royalty = Self::do_pay_royalty(
+			collection,
+			item,
+			&details.owner,
+			&buyer,
+			price_info.0,
+			|beneficiary, amount| {
+				T::Currency::transfer(&buyer, beneficiary, amount, ExistenceRequirement::KeepAlive)
+			},
+		)?;
+
 		T::Currency::transfer(
 			&buyer,
 			&details.owner,
-			price_info.0,
+			price_info.0.saturating_sub(royalty),
 			ExistenceRequirement::KeepAlive,
 		)?;
 
@@ -24,5 +24,6 @@ pub mod create_delete_item;
 pub mod lock;
 pub mod metadata;
 pub mod roles;
+pub mod royalties;
 pub mod settings;
 pub mod transfer;
@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to configure and pay the royalties of collections.
+//! Royalties are only paid for items transferred as part of a sale, plain transfers of items are
+//! exempt.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{fungible::Mutate, tokens::Preservation},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set or remove the royalty of the specified collection.
+	///
+	/// - `maybe_check_owner`: An optional account ID used to check if the caller is the owner of
+	///   the collection. If provided, the function will check if the caller is the owner.
+	/// - `collection`: The identifier of the collection to set the royalty for.
+	/// - `royalty`: The royalty to be paid on sales of the collection's items, or `None` to stop
+	///   charging royalties.
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalty: Option<RoyaltyConfig<T::AccountId>>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		match royalty {
+			Some(royalty) => {
+				CollectionRoyaltyOf::<T, I>::insert(&collection, &royalty);
+				Self::deposit_event(Event::CollectionRoyaltySet {
+					collection,
+					beneficiary: royalty.beneficiary,
+					royalty: royalty.royalty,
+				});
+			},
+			None => {
+				CollectionRoyaltyOf::<T, I>::remove(&collection);
+				Self::deposit_event(Event::CollectionRoyaltyRemoved { collection });
+			},
+		}
+		Ok(())
+	}
+
+	/// Transfer an item as part of a sale, paying the royalty of its collection.
+	///
+	/// The royalty is paid by the `sender` in [`Config::RoyaltyAsset`].
+	///
+	/// - `collection`: The identifier of the collection of the item.
+	/// - `item`: The item being sold.
+	/// - `sender`: The owner or the approved delegate of the `item`.
+	/// - `dest`: The buyer of the `item`.
+	/// - `price`: The price the `item` was sold for.
+	pub(crate) fn do_transfer_sale(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		sender: T::AccountId,
+		dest: T::AccountId,
+		price: BalanceOf<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Trading),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let mut seller = None;
+		Self::do_transfer(collection, item, dest.clone(), |_, details| {
+			if details.owner != sender {
+				let deadline =
+					details.approvals.get(&sender).ok_or(Error::<T, I>::NoPermission)?;
+				if let Some(d) = deadline {
+					let block_number = frame_system::Pallet::<T>::block_number();
+					ensure!(block_number <= *d, Error::<T, I>::ApprovalExpired);
+				}
+			}
+			Self::do_pay_royalty(collection, item, &details.owner, &sender, price, |to, amount| {
+				T::RoyaltyAsset::transfer(&sender, to, amount, Preservation::Preserve).map(|_| ())
+			})?;
+			seller = Some(details.owner.clone());
+			Ok(())
+		})?;
+		let seller = seller.ok_or(Error::<T, I>::UnknownItem)?;
+
+		Self::deposit_event(Event::ItemSold { collection, item, price, seller, buyer: dest });
+		Ok(())
+	}
+
+	/// Pay the royalty of the specified collection for the sale of an item, returning the amount
+	/// paid.
+	///
+	/// No royalty is paid if none is set for the collection, or if the item is sold by the
+	/// beneficiary of the royalty.
+	///
+	/// - `collection`: The identifier of the collection of the sold item.
+	/// - `item`: The sold item.
+	/// - `seller`: The owner of the item before the sale.
+	/// - `payer`: The account paying the royalty.
+	/// - `price`: The price the item was sold for.
+	/// - `pay`: A closure paying the royalty amount to the beneficiary.
+	pub(crate) fn do_pay_royalty(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: &T::AccountId,
+		payer: &T::AccountId,
+		price: BalanceOf<T, I>,
+		pay: impl FnOnce(&T::AccountId, BalanceOf<T, I>) -> DispatchResult,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		let Some(RoyaltyConfig { beneficiary, royalty }) =
+			CollectionRoyaltyOf::<T, I>::get(&collection)
+		else {
+			return Ok(Zero::zero())
+		};
+		let amount = royalty * price;
+		if &beneficiary == seller || amount.is_zero() {
+			return Ok(Zero::zero())
+		}
+
+		pay(&beneficiary, amount)?;
+		Self::deposit_event(Event::RoyaltyPaid {
+			collection,
+			item,
+			payer: payer.clone(),
+			beneficiary,
+			amount,
+		});
+		Ok(amount)
+	}
+}
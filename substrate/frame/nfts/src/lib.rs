@@ -50,13 +50,13 @@ pub mod weights;
 
 use codec::{Decode, Encode};
 use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
-	ReservableCurrency,
+	fungible, tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg,
+	Incrementable, ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
 	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
-	RuntimeDebug,
+	Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -122,6 +122,13 @@ pub mod pallet {
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The asset in which royalties are paid for items transferred with
+		/// [`Pallet::transfer_sale`].
+		///
+		/// Royalties of items sold with [`Pallet::buy_item`] are paid in `Currency`, the currency
+		/// the items are priced in.
+		type RoyaltyAsset: fungible::Mutate<Self::AccountId, Balance = BalanceOf<Self, I>>;
+
 		/// The origin which may forcibly create or destroy an item or otherwise alter privileged
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -378,6 +385,11 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The royalty paid on sales of the items of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyConfig<T::AccountId>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -558,6 +570,30 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// A royalty was set for the `collection`.
+		CollectionRoyaltySet {
+			collection: T::CollectionId,
+			beneficiary: T::AccountId,
+			royalty: Permill,
+		},
+		/// The royalty of the `collection` was removed.
+		CollectionRoyaltyRemoved { collection: T::CollectionId },
+		/// An item was transferred as part of a sale.
+		ItemSold {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			price: BalanceOf<T, I>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+		},
+		/// A royalty was paid for the sale of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			payer: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -1722,7 +1758,9 @@ pub mod pallet {
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
-		/// Emits `ItemBought` on success.
+		/// If the collection has a royalty, it is paid from the price to its beneficiary.
+		///
+		/// Emits `ItemBought` on success and `RoyaltyPaid` if a royalty was paid.
 		#[pallet::call_index(32)]
 		#[pallet::weight(T::WeightInfo::buy_item())]
 		pub fn buy_item(
@@ -1895,6 +1933,60 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set or remove the royalty paid on sales of the items of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `royalty`: The beneficiary and the part of the sale price paid as royalty, or `None`
+		///   to remove the royalty.
+		///
+		/// Emits `CollectionRoyaltySet` or `CollectionRoyaltyRemoved` when successful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<RoyaltyConfig<T::AccountId>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_royalty(maybe_check_owner, collection, royalty)
+		}
+
+		/// Move an item from the sender account to another as part of a sale.
+		///
+		/// Unlike `transfer`, the royalty of the collection is charged on the `price`. It is paid
+		/// by the sender in the `RoyaltyAsset`, unless the item is owned by the beneficiary of the
+		/// royalty.
+		///
+		/// Origin must be Signed and the signing account must be either:
+		/// - the Owner of the `item`;
+		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		///
+		/// Arguments:
+		/// - `collection`: The collection of the item to be sold.
+		/// - `item`: The item to be sold.
+		/// - `dest`: The buyer of the item.
+		/// - `price`: The price the item was sold for.
+		///
+		/// Emits `Transferred` and `ItemSold`, and `RoyaltyPaid` if a royalty was paid.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::transfer_sale())]
+		pub fn transfer_sale(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+			price: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_transfer_sale(collection, item, origin, dest, price)
+		}
 	}
 }
 
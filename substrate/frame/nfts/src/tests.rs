@@ -30,7 +30,7 @@ use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
 use sp_runtime::{
 	traits::{Dispatchable, IdentifyAccount},
-	MultiSignature, MultiSigner,
+	MultiSignature, MultiSigner, TokenError,
 };
 use sp_std::prelude::*;
 
@@ -2585,6 +2585,237 @@ fn buy_item_should_work() {
 	});
 }
 
+#[test]
+fn set_collection_royalty_works() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		let beneficiary = account(2);
+		let collection_id = 0;
+		let royalty =
+			RoyaltyConfig { beneficiary: beneficiary.clone(), royalty: Permill::from_percent(10) };
+
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				Some(royalty.clone()),
+			),
+			Error::<Test>::UnknownCollection
+		);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.clone(),
+			default_collection_config()
+		));
+
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(beneficiary.clone()),
+				collection_id,
+				Some(royalty.clone()),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			Some(royalty.clone()),
+		));
+		assert_eq!(CollectionRoyaltyOf::<Test>::get(collection_id), Some(royalty));
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			beneficiary,
+			royalty: Permill::from_percent(10),
+		}));
+
+		assert_ok!(Nfts::set_collection_royalty(RuntimeOrigin::root(), collection_id, None));
+		assert_eq!(CollectionRoyaltyOf::<Test>::get(collection_id), None);
+		assert!(events()
+			.contains(&Event::<Test>::CollectionRoyaltyRemoved { collection: collection_id }));
+
+		// the royalty is removed together with its collection
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			Some(royalty.clone()),
+		));
+		let w = Nfts::get_destroy_witness(&collection_id).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(owner), collection_id, w));
+		assert_eq!(CollectionRoyaltyOf::<Test>::get(collection_id), None);
+	});
+}
+
+#[test]
+fn buy_item_pays_royalty() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let beneficiary = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 50;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&beneficiary, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(RoyaltyConfig {
+				beneficiary: beneficiary.clone(),
+				royalty: Permill::from_percent(10),
+			}),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+
+		// the royalty is taken out of the price paid to the seller
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price - 5);
+		assert_eq!(Balances::total_balance(&beneficiary), initial_balance + 5);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			payer: user_2.clone(),
+			beneficiary,
+			amount: 5,
+		}));
+	});
+}
+
+#[test]
+fn transfer_sale_pays_royalty() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let beneficiary = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			beneficiary.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(RoyaltyConfig {
+				beneficiary: beneficiary.clone(),
+				royalty: Permill::from_percent(20),
+			}),
+		));
+
+		// sales by the beneficiary are exempt
+		assert_ok!(Nfts::transfer_sale(
+			RuntimeOrigin::signed(beneficiary.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			50,
+		));
+		assert_eq!(Balances::total_balance(&beneficiary), 0);
+		assert!(events().contains(&Event::<Test>::ItemSold {
+			collection: collection_id,
+			item: item_id,
+			price: 50,
+			seller: beneficiary.clone(),
+			buyer: user_1.clone(),
+		}));
+
+		// the royalty is paid by the sender
+		assert_noop!(
+			Nfts::transfer_sale(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				user_2.clone(),
+				50,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::transfer_sale(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			50,
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - 10);
+		assert_eq!(Balances::total_balance(&beneficiary), 10);
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, user_2);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_id,
+			payer: user_1.clone(),
+			beneficiary: beneficiary.clone(),
+			amount: 10,
+		}));
+
+		// the sale fails if the royalty can't be paid
+		assert_noop!(
+			Nfts::transfer_sale(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				user_3.clone(),
+				1_000,
+			),
+			TokenError::FundsUnavailable
+		);
+
+		// plain transfers don't pay royalties
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			user_3.clone(),
+		));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance);
+		assert_eq!(Balances::total_balance(&beneficiary), 10);
+	});
+}
+
 #[test]
 fn pay_tips_should_work() {
 	new_test_ext().execute_with(|| {
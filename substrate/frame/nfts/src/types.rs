@@ -201,6 +201,15 @@ pub struct ItemTip<CollectionId, ItemId, AccountId, Amount> {
 	pub(super) amount: Amount,
 }
 
+/// The royalty paid on sales of the items of a collection.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyConfig<AccountId> {
+	/// The account receiving the royalty.
+	pub beneficiary: AccountId,
+	/// The part of the sale price paid as royalty.
+	pub royalty: Permill,
+}
+
 /// Information about the pending swap.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
@@ -147,7 +147,7 @@ benchmarks! {
 		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
 		Nis::<T>::process_queues(Perquintill::one(), 1, 2, &mut WeightCounter::unlimited());
 		Nis::<T>::communify(RawOrigin::Signed(caller.clone()).into(), 0)?;
-	}: _(RawOrigin::Signed(caller.clone()), 0)
+	}: _(RawOrigin::Signed(caller.clone()), 0, None)
 	verify {
 		assert_eq!(Nis::<T>::owner(&0), Some(caller));
 	}
@@ -187,6 +187,19 @@ benchmarks! {
 		assert!(Receipts::<T>::get(0).is_none());
 	}
 
+	split {
+		let caller: T::AccountId = whitelisted_caller();
+		let bid = T::MinBid::get().max(One::one());
+		let ed = T::Currency::minimum_balance();
+		T::Currency::set_balance(&caller, ed + bid);
+		Nis::<T>::place_bid(RawOrigin::Signed(caller.clone()).into(), bid, 1)?;
+		Nis::<T>::process_queues(Perquintill::one(), 1, 1, &mut WeightCounter::unlimited());
+		let proportion = Receipts::<T>::get(0).unwrap().proportion.deconstruct() / 2;
+	}: _(RawOrigin::Signed(caller.clone()), 0, Perquintill::from_parts(proportion))
+	verify {
+		assert!(Receipts::<T>::get(1).is_some());
+	}
+
 	process_queues {
 		fill_queues::<T>()?;
 	}: {
@@ -66,6 +66,11 @@
 //! The call must be made by the owner of both the "main" non-fungible receipt and the appropriate
 //! amount of counterpart tokens.
 //!
+//! A receipt need not be thawed all at once: it may be thawed in several tranches, each reducing
+//! the proportion of the receipt and burning the corresponding amount of counterpart tokens, or a
+//! private receipt may be `split` into separate receipts which then mature and thaw independently
+//! of one another.
+//!
 //! `NoCounterpart` may be provided as an implementation for the counterpart token system in which
 //! case they are completely disregarded from the thawing logic.
 //!
@@ -89,6 +94,7 @@ use sp_runtime::{
 };
 
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -299,7 +305,11 @@ pub mod pallet {
 		type ThawThrottle: Get<(Perquintill, BlockNumberFor<Self>)>;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// A single bid, an item of a *queue* in `Queues`.
@@ -326,6 +336,9 @@ pub mod pallet {
 		pub owner: Option<(AccountId, Balance)>,
 		/// The time after which this receipt can be thawed.
 		pub expiry: BlockNumber,
+		/// The proportion of the effective total issuance which has already been thawed from this
+		/// receipt in earlier tranches.
+		pub thawed: Perquintill,
 	}
 
 	/// An index for a receipt.
@@ -428,6 +441,15 @@ pub mod pallet {
 		Funded { deficit: BalanceOf<T> },
 		/// A receipt was transfered.
 		Transferred { from: T::AccountId, to: T::AccountId, index: ReceiptIndex },
+		/// A tranche of a receipt was split off into a new receipt.
+		Split {
+			/// The identity of the receipt which was split.
+			index: ReceiptIndex,
+			/// The identity of the new receipt.
+			new_index: ReceiptIndex,
+			/// The proportion of the effective total issuance which the new receipt represents.
+			proportion: Perquintill,
+		},
 	}
 
 	#[pallet::error]
@@ -672,7 +694,7 @@ pub mod pallet {
 			let mut receipt: ReceiptRecordOf<T> =
 				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
 			// If found, check the owner is `who`.
-			let (owner, mut on_hold) = receipt.owner.ok_or(Error::<T>::AlreadyCommunal)?;
+			let (owner, mut on_hold) = receipt.owner.take().ok_or(Error::<T>::AlreadyCommunal)?;
 			ensure!(owner == who, Error::<T>::NotOwner);
 
 			let now = frame_system::Pallet::<T>::block_number();
@@ -680,17 +702,7 @@ pub mod pallet {
 
 			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
 
-			let proportion = if let Some(proportion) = maybe_proportion {
-				ensure!(proportion <= receipt.proportion, Error::<T>::PortionTooBig);
-				let remaining = receipt.proportion.saturating_sub(proportion);
-				ensure!(
-					remaining.is_zero() || remaining >= T::MinReceipt::get(),
-					Error::<T>::MakesDust
-				);
-				proportion
-			} else {
-				receipt.proportion
-			};
+			let proportion = Self::thaw_proportion(&receipt, maybe_proportion)?;
 
 			let (throttle, throttle_period) = T::ThawThrottle::get();
 			if now.saturating_sub(summary.last_period) >= throttle_period {
@@ -707,6 +719,7 @@ pub mod pallet {
 			let amount = proportion * effective_issuance;
 
 			receipt.proportion.saturating_reduce(proportion);
+			receipt.thawed.saturating_accrue(proportion);
 			summary.proportion_owed.saturating_reduce(proportion);
 
 			let dropped = receipt.proportion.is_zero();
@@ -759,8 +772,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Reduce or remove an outstanding receipt, placing the according proportion of funds into
-		/// the account of the owner.
+		/// Remove an outstanding receipt, placing the according proportion of funds into the
+		/// account of the owner.
 		///
 		/// - `origin`: Must be Signed and the account must be the owner of the fungible counterpart
 		///   for receipt `index`.
@@ -772,47 +785,7 @@ pub mod pallet {
 			#[pallet::compact] index: ReceiptIndex,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			// Look for `index`
-			let receipt: ReceiptRecordOf<T> =
-				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
-			// If found, check it is actually communal.
-			ensure!(receipt.owner.is_none(), Error::<T>::NotOwner);
-			let now = frame_system::Pallet::<T>::block_number();
-			ensure!(now >= receipt.expiry, Error::<T>::NotExpired);
-
-			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
-
-			let (throttle, throttle_period) = T::ThawThrottle::get();
-			if now.saturating_sub(summary.last_period) >= throttle_period {
-				summary.thawed = Zero::zero();
-				summary.last_period = now;
-			}
-			summary.thawed.saturating_accrue(receipt.proportion);
-			ensure!(summary.thawed <= throttle, Error::<T>::Throttled);
-
-			let cp_amount = T::CounterpartAmount::convert(receipt.proportion);
-			T::Counterpart::burn_from(&who, cp_amount, Exact, Polite)?;
-
-			// Multiply the proportion it is by the total issued.
-			let our_account = Self::account_id();
-			let effective_issuance = Self::issuance_with(&our_account, &summary).effective;
-			let amount = receipt.proportion * effective_issuance;
-
-			summary.proportion_owed.saturating_reduce(receipt.proportion);
-
-			// Try to transfer amount owed from pot to receipt owner.
-			T::Currency::transfer(&our_account, &who, amount, Expendable)
-				.map_err(|_| Error::<T>::Unfunded)?;
-
-			Receipts::<T>::remove(index);
-			Summary::<T>::put(&summary);
-
-			let e =
-				Event::Thawed { index, who, amount, proportion: receipt.proportion, dropped: true };
-			Self::deposit_event(e);
-
-			Ok(())
+			Self::do_thaw_communal(who, index, None)
 		}
 
 		/// Make a private receipt communal and create fungible counterparts for its owner.
@@ -900,6 +873,85 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Split a tranche off a private receipt into a new receipt with the same expiry, which
+		/// may then be thawed, communified or transferred independently of the original receipt.
+		///
+		/// The funds on hold for the receipt are divided between both receipts in proportion.
+		///
+		/// - `origin`: Must be Signed and the account must be the owner of the receipt `index`.
+		/// - `index`: The index of the receipt.
+		/// - `proportion`: The portion of the receipt to be split off. Neither the new receipt
+		///   nor the remainder of the original receipt may be smaller than `MinReceipt`.
+		///
+		/// Emits `Split` when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::split())]
+		pub fn split(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: ReceiptIndex,
+			proportion: Perquintill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Look for `index`
+			let mut receipt: ReceiptRecordOf<T> =
+				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
+			// If found, check the owner is `who`.
+			let (owner, on_hold) = receipt.owner.take().ok_or(Error::<T>::AlreadyCommunal)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			ensure!(proportion < receipt.proportion, Error::<T>::PortionTooBig);
+			let remaining = receipt.proportion.saturating_sub(proportion);
+			let min_receipt = T::MinReceipt::get();
+			ensure!(proportion >= min_receipt && remaining >= min_receipt, Error::<T>::MakesDust);
+
+			// The funds on hold stay with the owner, only their accounting is divided.
+			let split_on_hold = Perquintill::from_rational(
+				proportion.deconstruct(),
+				receipt.proportion.deconstruct(),
+			) * on_hold;
+
+			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
+			let new_index = summary.index;
+			summary.index += 1;
+
+			receipt.proportion = remaining;
+			receipt.owner = Some((owner.clone(), on_hold.saturating_sub(split_on_hold)));
+			let tranche = ReceiptRecord {
+				proportion,
+				owner: Some((owner, split_on_hold)),
+				expiry: receipt.expiry,
+				thawed: Zero::zero(),
+			};
+
+			Summary::<T>::put(&summary);
+			Receipts::<T>::insert(index, &receipt);
+			Receipts::<T>::insert(new_index, &tranche);
+
+			Self::deposit_event(Event::Split { index, new_index, proportion });
+
+			Ok(())
+		}
+
+		/// Reduce an outstanding receipt, placing the according proportion of funds into the
+		/// account of the owner. The receipt is removed once all of it is thawed.
+		///
+		/// - `origin`: Must be Signed and the account must be the owner of the fungible counterpart
+		///   for the thawed portion of receipt `index`.
+		/// - `index`: The index of the receipt.
+		/// - `proportion`: The portion of the receipt to thaw. What remains of the receipt may not
+		///   be smaller than `MinReceipt`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::thaw_communal())]
+		pub fn thaw_communal_portion(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: ReceiptIndex,
+			proportion: Perquintill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw_communal(who, index, Some(proportion))
+		}
 	}
 
 	/// Issuance information returned by `issuance()`.
@@ -930,6 +982,7 @@ pub mod pallet {
 			match key {
 				b"proportion" => Some(item.proportion.encode()),
 				b"expiry" => Some(item.expiry.encode()),
+				b"thawed" => Some(item.thawed.encode()),
 				b"owner" => item.owner.as_ref().map(|x| x.0.encode()),
 				b"on_hold" => item.owner.as_ref().map(|x| x.1.encode()),
 				_ => None,
@@ -965,6 +1018,82 @@ pub mod pallet {
 			T::PalletId::get().into_account_truncating()
 		}
 
+		/// Thaw the portion `maybe_proportion`, or all, of the communal receipt `index` for `who`.
+		fn do_thaw_communal(
+			who: T::AccountId,
+			index: ReceiptIndex,
+			maybe_proportion: Option<Perquintill>,
+		) -> DispatchResult {
+			// Look for `index`
+			let mut receipt: ReceiptRecordOf<T> =
+				Receipts::<T>::get(index).ok_or(Error::<T>::UnknownReceipt)?;
+			// If found, check it is actually communal.
+			ensure!(receipt.owner.is_none(), Error::<T>::NotOwner);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= receipt.expiry, Error::<T>::NotExpired);
+
+			let mut summary: SummaryRecordOf<T> = Summary::<T>::get();
+
+			let proportion = Self::thaw_proportion(&receipt, maybe_proportion)?;
+
+			let (throttle, throttle_period) = T::ThawThrottle::get();
+			if now.saturating_sub(summary.last_period) >= throttle_period {
+				summary.thawed = Zero::zero();
+				summary.last_period = now;
+			}
+			summary.thawed.saturating_accrue(proportion);
+			ensure!(summary.thawed <= throttle, Error::<T>::Throttled);
+
+			// Burn the counterparts of the thawed portion. Taking the difference rather than
+			// converting the portion on its own ensures that thawing in several tranches burns
+			// exactly as much as the counterparts minted for the whole receipt.
+			let remaining = receipt.proportion.saturating_sub(proportion);
+			let cp_amount = T::CounterpartAmount::convert(receipt.proportion)
+				.saturating_sub(T::CounterpartAmount::convert(remaining));
+			T::Counterpart::burn_from(&who, cp_amount, Exact, Polite)?;
+
+			// Multiply the proportion it is by the total issued.
+			let our_account = Self::account_id();
+			let effective_issuance = Self::issuance_with(&our_account, &summary).effective;
+			let amount = proportion * effective_issuance;
+
+			summary.proportion_owed.saturating_reduce(proportion);
+
+			// Try to transfer amount owed from pot to receipt owner.
+			T::Currency::transfer(&our_account, &who, amount, Expendable)
+				.map_err(|_| Error::<T>::Unfunded)?;
+
+			let dropped = remaining.is_zero();
+			if dropped {
+				Receipts::<T>::remove(index);
+			} else {
+				receipt.proportion = remaining;
+				receipt.thawed.saturating_accrue(proportion);
+				Receipts::<T>::insert(index, &receipt);
+			}
+			Summary::<T>::put(&summary);
+
+			Self::deposit_event(Event::Thawed { index, who, amount, proportion, dropped });
+
+			Ok(())
+		}
+
+		/// Returns the portion of `receipt` to thaw, which is all of it if `maybe_proportion` is
+		/// `None`, ensuring that what remains of the receipt is not dust.
+		fn thaw_proportion(
+			receipt: &ReceiptRecordOf<T>,
+			maybe_proportion: Option<Perquintill>,
+		) -> Result<Perquintill, DispatchError> {
+			let Some(proportion) = maybe_proportion else { return Ok(receipt.proportion) };
+			ensure!(proportion <= receipt.proportion, Error::<T>::PortionTooBig);
+			let remaining = receipt.proportion.saturating_sub(proportion);
+			ensure!(
+				remaining.is_zero() || remaining >= T::MinReceipt::get(),
+				Error::<T>::MakesDust
+			);
+			Ok(proportion)
+		}
+
 		/// Returns information on the issuance within the system.
 		pub fn issuance() -> IssuanceInfo<BalanceOf<T>> {
 			Self::issuance_with(&Self::account_id(), &Summary::<T>::get())
@@ -1131,7 +1260,12 @@ pub mod pallet {
 
 			let e = Event::Issued { index, expiry, who: who.clone(), amount, proportion };
 			Self::deposit_event(e);
-			let receipt = ReceiptRecord { proportion, owner: Some((who, amount)), expiry };
+			let receipt = ReceiptRecord {
+				proportion,
+				owner: Some((who, amount)),
+				expiry,
+				thawed: Zero::zero(),
+			};
 			Receipts::<T>::insert(index, receipt);
 
 			result
@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the NIS pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{fungible::Inspect as FunInspect, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_arithmetic::Perquintill;
+use sp_runtime::traits::{Saturating, Zero};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+const TARGET: &'static str = "runtime::nis::migration";
+
+/// Initial version of storage types.
+pub mod v0 {
+	use super::*;
+
+	/// A receipt as it was before it tracked the proportion thawed in earlier tranches.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ReceiptRecord<AccountId, BlockNumber, Balance> {
+		pub proportion: Perquintill,
+		pub owner: Option<(AccountId, Balance)>,
+		pub expiry: BlockNumber,
+	}
+
+	pub type ReceiptRecordOf<T> = ReceiptRecord<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<<T as Config>::Currency as FunInspect<<T as frame_system::Config>::AccountId>>::Balance,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Adds the proportion thawed so far to every [`ReceiptRecord`], which is zero for all
+	/// receipts existing before receipts could be thawed in several tranches.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let receipt_count = Receipts::<T>::iter_keys().count() as u32;
+			log::info!(target: TARGET, "pre-upgrade state contains '{}' receipts.", receipt_count);
+			Ok(receipt_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			let mut weight = T::DbWeight::get().reads(1);
+			if onchain_version != 0 {
+				log::warn!(target: TARGET, "skipping migration from v0 to v1.");
+				return weight
+			}
+
+			let mut translated = 0u64;
+			Receipts::<T>::translate::<v0::ReceiptRecordOf<T>, _>(|_, old| {
+				translated.saturating_inc();
+				Some(ReceiptRecord {
+					proportion: old.proportion,
+					owner: old.owner,
+					expiry: old.expiry,
+					thawed: Zero::zero(),
+				})
+			});
+			log::info!(target: TARGET, "migrated {} receipts.", translated);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(translated, translated + 1));
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "must upgrade to version 1.");
+			let pre_receipt_count: u32 = Decode::decode(&mut &state[..])
+				.expect("failed to decode the state from pre-upgrade.");
+			let post_receipt_count = Receipts::<T>::iter_values().count() as u32;
+			ensure!(post_receipt_count == pre_receipt_count, "must migrate all receipts.");
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+	use frame_support::storage::unhashed;
+
+	#[test]
+	fn migration_v0_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			let old = v0::ReceiptRecordOf::<Test> {
+				proportion: Perquintill::from_percent(10),
+				owner: Some((1, 40)),
+				expiry: 7,
+			};
+			unhashed::put(&Receipts::<Test>::hashed_key_for(0), &old);
+
+			v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+			assert_eq!(
+				Receipts::<Test>::get(0),
+				Some(ReceiptRecord {
+					proportion: Perquintill::from_percent(10),
+					owner: Some((1, 40)),
+					expiry: 7,
+					thawed: Zero::zero(),
+				})
+			);
+		});
+	}
+}
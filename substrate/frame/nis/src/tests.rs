@@ -29,6 +29,7 @@ use frame_support::{
 };
 use sp_arithmetic::Perquintill;
 use sp_runtime::{
+	traits::Zero,
 	Saturating,
 	TokenError::{self, FundsUnavailable},
 };
@@ -231,7 +232,8 @@ fn basic_enlarge_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((2, 40)),
-				expiry: 7
+				expiry: 7,
+				thawed: Zero::zero(),
 			}
 		);
 	});
@@ -258,7 +260,8 @@ fn enlarge_respects_bids_limit() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((4, 40)),
-				expiry: 10
+				expiry: 10,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -266,7 +269,8 @@ fn enlarge_respects_bids_limit() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((2, 40)),
-				expiry: 7
+				expiry: 7,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -298,7 +302,8 @@ fn enlarge_respects_amount_limit_and_will_split() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((1, 40)),
-				expiry: 4
+				expiry: 4,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -532,6 +537,134 @@ fn privatize_and_thaw_with_another_receipt_works() {
 	});
 }
 
+#[test]
+fn partial_communal_thaw_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Nis::place_bid(signed(1), 80, 1));
+		enlarge(80, 1);
+		let expiry = Receipts::<Test>::get(0).unwrap().expiry;
+		run_to_block(4);
+		assert_ok!(Nis::communify(signed(1), 0));
+		assert_eq!(NisBalances::free_balance(&1), 4_200_000);
+
+		let prop = Perquintill::from_rational(4_100_000, 21_000_000u64);
+		assert_noop!(Nis::thaw_communal_portion(signed(1), 0, prop), Error::<Test>::MakesDust);
+		let prop = Perquintill::from_percent(25);
+		assert_noop!(Nis::thaw_communal_portion(signed(1), 0, prop), Error::<Test>::PortionTooBig);
+		assert_ok!(Nis::thaw_communal_portion(signed(1), 0, Perquintill::from_percent(5)));
+
+		// Only the counterparts of the thawed portion are burnt.
+		assert_eq!(NisBalances::free_balance(&1), 3_150_000);
+		assert_eq!(Balances::free_balance(1), 40);
+		assert_eq!(pot(), 60);
+		assert_eq!(
+			Receipts::<Test>::get(0).unwrap(),
+			ReceiptRecord {
+				proportion: Perquintill::from_percent(15),
+				owner: None,
+				expiry,
+				thawed: Perquintill::from_percent(5),
+			}
+		);
+
+		assert_ok!(Nis::thaw_communal(signed(1), 0));
+		assert_eq!(NisBalances::free_balance(&1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(pot(), 0);
+		assert_eq!(Receipts::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn split_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Nis::place_bid(signed(1), 80, 1));
+		enlarge(80, 1);
+		let expiry = Receipts::<Test>::get(0).unwrap().expiry;
+
+		let prop = Perquintill::from_percent(5);
+		assert_noop!(Nis::split(signed(2), 0, prop), Error::<Test>::NotOwner);
+		assert_noop!(Nis::split(signed(1), 1, prop), Error::<Test>::UnknownReceipt);
+		let prop = Perquintill::from_percent(20);
+		assert_noop!(Nis::split(signed(1), 0, prop), Error::<Test>::PortionTooBig);
+		let prop = Perquintill::from_rational(1u64, 1_000u64);
+		assert_noop!(Nis::split(signed(1), 0, prop), Error::<Test>::MakesDust);
+		let prop = Perquintill::from_rational(199u64, 1_000u64);
+		assert_noop!(Nis::split(signed(1), 0, prop), Error::<Test>::MakesDust);
+
+		assert_ok!(Nis::split(signed(1), 0, Perquintill::from_percent(5)));
+		System::assert_last_event(RuntimeEvent::Nis(Event::Split {
+			index: 0,
+			new_index: 1,
+			proportion: Perquintill::from_percent(5),
+		}));
+		assert_eq!(
+			Receipts::<Test>::get(0).unwrap(),
+			ReceiptRecord {
+				proportion: Perquintill::from_percent(15),
+				owner: Some((1, 60)),
+				expiry,
+				thawed: Zero::zero(),
+			}
+		);
+		assert_eq!(
+			Receipts::<Test>::get(1).unwrap(),
+			ReceiptRecord {
+				proportion: Perquintill::from_percent(5),
+				owner: Some((1, 20)),
+				expiry,
+				thawed: Zero::zero(),
+			}
+		);
+		assert_eq!(Summary::<Test>::get().index, 2);
+		assert_eq!(Balances::reserved_balance(&1), 80);
+		assert_eq!(holdings(), 80);
+
+		// Both receipts can be dealt with independently.
+		assert_ok!(Nis::transfer(&1, &2));
+		run_to_block(4);
+		assert_ok!(Nis::thaw_private(signed(2), 1, None));
+		assert_eq!(Balances::reserved_balance(&1), 60);
+		assert_eq!(Balances::total_balance(&2), 120);
+		assert_ok!(Nis::communify(signed(1), 0));
+		assert_eq!(NisBalances::free_balance(&1), 3_150_000);
+		assert_noop!(
+			Nis::split(signed(1), 0, Perquintill::from_percent(5)),
+			Error::<Test>::AlreadyCommunal
+		);
+	});
+}
+
+#[test]
+fn thaw_in_tranches_respects_throttle() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Balances::transfer_allow_death(signed(2), 1, 50));
+		assert_ok!(Nis::place_bid(signed(1), 140, 1));
+		enlarge(140, 1);
+		run_to_block(4);
+
+		// The receipt is worth more than the throttle allows to be thawed in one period...
+		assert_noop!(Nis::thaw_private(signed(1), 0, None), Error::<Test>::Throttled);
+		// ...but it can be thawed in tranches over several periods.
+		assert_ok!(Nis::thaw_private(signed(1), 0, Some(Perquintill::from_percent(20))));
+		assert_eq!(
+			Nis::typed_attribute::<_, Perquintill>(&0, b"thawed"),
+			Some(Perquintill::from_percent(20)),
+		);
+		let prop = Perquintill::from_percent(10);
+		assert_noop!(Nis::thaw_private(signed(1), 0, Some(prop)), Error::<Test>::Throttled);
+
+		run_to_block(9);
+		assert_ok!(Nis::thaw_private(signed(1), 0, None));
+		assert_eq!(Receipts::<Test>::get(0), None);
+		assert_eq!(Balances::total_balance(&1), 150);
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
+	});
+}
+
 #[test]
 fn communal_thaw_when_issuance_higher_works() {
 	new_test_ext().execute_with(|| {
@@ -756,7 +889,8 @@ fn enlargement_to_target_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((2, 40)),
-				expiry: 13
+				expiry: 13,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -764,7 +898,8 @@ fn enlargement_to_target_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((3, 40)),
-				expiry: 13
+				expiry: 13,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -798,7 +933,8 @@ fn enlargement_to_target_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((1, 40)),
-				expiry: 12
+				expiry: 12,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -806,7 +942,8 @@ fn enlargement_to_target_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((2, 40)),
-				expiry: 12
+				expiry: 12,
+				thawed: Zero::zero(),
 			}
 		);
 		assert_eq!(
@@ -843,7 +980,8 @@ fn enlargement_to_target_works() {
 			ReceiptRecord {
 				proportion: Perquintill::from_percent(10),
 				owner: Some((1, 40)),
-				expiry: 13
+				expiry: 13,
+				thawed: Zero::zero(),
 			}
 		);
 
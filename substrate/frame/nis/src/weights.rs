@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since `split` was added and communal receipts may be thawed in tranches.
+//! The weights of `split` and `thaw_communal` are PLACEHOLDERS. Re-run the benchmarks to replace
+//! them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -63,6 +67,7 @@ pub trait WeightInfo {
 	fn process_queues() -> Weight;
 	fn process_queue() -> Weight;
 	fn process_bid() -> Weight;
+	fn split() -> Weight;
 }
 
 /// Weights for pallet_nis using the Substrate node and recommended hardware.
@@ -202,10 +207,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn thaw_communal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `773`
-		//  Estimated: `3675`
-		// Minimum execution time: 107_248_000 picoseconds.
+		// PLACEHOLDER: not benchmarked since receipts may be thawed in tranches.
 		Weight::from_parts(109_923_000, 3675)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
@@ -246,6 +248,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(7_336_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Nis Receipts (r:1 w:2)
+	/// Proof: Nis Receipts (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nis Summary (r:1 w:1)
+	/// Proof: Nis Summary (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn split() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(20_476_000, 3554)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -384,10 +396,7 @@ impl WeightInfo for () {
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn thaw_communal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `773`
-		//  Estimated: `3675`
-		// Minimum execution time: 107_248_000 picoseconds.
+		// PLACEHOLDER: not benchmarked since receipts may be thawed in tranches.
 		Weight::from_parts(109_923_000, 3675)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
@@ -428,4 +437,14 @@ impl WeightInfo for () {
 		Weight::from_parts(7_336_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Nis Receipts (r:1 w:2)
+	/// Proof: Nis Receipts (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: Nis Summary (r:1 w:1)
+	/// Proof: Nis Summary (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn split() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(20_476_000, 3554)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -18,6 +18,10 @@
 //! # Offences Pallet
 //!
 //! Tracks reported offences
+//!
+//! By default the offenders are punished as soon as an offence is reported. With a non-zero
+//! [`Config::DeferPeriod`], the offenders are still disabled right away, but their slashes are
+//! deferred for that many blocks, during which [`Config::VetoOrigin`] can veto or reduce them.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -28,11 +32,19 @@ mod tests;
 
 use core::marker::PhantomData;
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::weights::Weight;
-use sp_runtime::{traits::Hash, Perbill};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Hash, Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
 use sp_staking::{
-	offence::{Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
+	offence::{
+		DisableStrategy, Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler,
+		ReportOffence,
+	},
 	SessionIndex,
 };
 use sp_std::prelude::*;
@@ -45,12 +57,37 @@ type OpaqueTimeSlot = Vec<u8>;
 /// A type alias for a report identifier.
 type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
+/// The identifier of a deferred offence.
+pub type DeferredOffenceId = u32;
+
+/// An offence whose slashes are deferred, see [`Config::DeferPeriod`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DeferredOffence<Reporter, Offender, BlockNumber> {
+	/// The offenders, along with the reporters of the offence.
+	pub offenders: Vec<OffenceDetails<Reporter, Offender>>,
+	/// The fraction of the stake of each offender to slash.
+	pub slash_fraction: Vec<Perbill>,
+	/// The session in which the offence happened.
+	pub session_index: SessionIndex,
+	/// Which conditions get the offenders disabled.
+	pub disable_strategy: DisableStrategy,
+	/// The block at which the slashes are applied.
+	pub apply_at: BlockNumber,
+}
+
+type DeferredOffenceOf<T> = DeferredOffence<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::IdentificationTuple,
+	BlockNumberFor<T>,
+>;
+
 const LOG_TARGET: &str = "runtime::offences";
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -68,6 +105,12 @@ pub mod pallet {
 		type IdentificationTuple: Parameter;
 		/// A handler called for every offence report.
 		type OnOffenceHandler: OnOffenceHandler<Self::AccountId, Self::IdentificationTuple, Weight>;
+		/// The number of blocks the slashes of reported offences are deferred for, zero to apply
+		/// them right away.
+		#[pallet::constant]
+		type DeferPeriod: Get<BlockNumberFor<Self>>;
+		/// Origin allowed to veto or reduce the slashes of deferred offences.
+		type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	/// The primary structure that holds all offence records keyed by report identifiers.
@@ -92,6 +135,20 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Offences whose slashes are yet to be applied, keyed by their identifier.
+	#[pallet::storage]
+	pub type DeferredOffences<T: Config> =
+		StorageMap<_, Twox64Concat, DeferredOffenceId, DeferredOffenceOf<T>>;
+
+	/// The identifier of the next deferred offence.
+	#[pallet::storage]
+	pub type NextDeferredOffenceId<T: Config> = StorageValue<_, DeferredOffenceId, ValueQuery>;
+
+	/// The deferred offences whose slashes are applied at a given block.
+	#[pallet::storage]
+	pub type DeferredOffencesDue<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<DeferredOffenceId>, ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -100,6 +157,87 @@ pub mod pallet {
 		/// (kind-specific) time slot. This event is not deposited for duplicate slashes.
 		/// \[kind, timeslot\].
 		Offence { kind: Kind, timeslot: OpaqueTimeSlot },
+		/// The slashes of the offence of the given `kind` and time slot are deferred as `id`.
+		OffenceDeferred { id: DeferredOffenceId, kind: Kind, timeslot: OpaqueTimeSlot },
+		/// The slashes of the deferred offence `id` have been applied.
+		DeferredOffenceApplied { id: DeferredOffenceId },
+		/// The slashes of the deferred offence `id` have been vetoed.
+		DeferredOffenceVetoed { id: DeferredOffenceId },
+		/// The slashes of the deferred offence `id` have been reduced to `slash_fraction`.
+		DeferredOffenceAdjusted { id: DeferredOffenceId, slash_fraction: Perbill },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There is no deferred offence with the given identifier.
+		UnknownDeferredOffence,
+		/// Slashes of deferred offences can only be reduced.
+		SlashFractionIncreased,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			for id in DeferredOffencesDue::<T>::take(now) {
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+				// Vetoed offences are already removed.
+				let Some(offence) = DeferredOffences::<T>::take(id) else { continue };
+				weight.saturating_accrue(T::OnOffenceHandler::on_offence(
+					&offence.offenders,
+					&offence.slash_fraction,
+					offence.session_index,
+					offence.disable_strategy,
+				));
+				Self::deposit_event(Event::DeferredOffenceApplied { id });
+			}
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Veto the slashes of the deferred offence `id`.
+		///
+		/// The offenders stay disabled, as they were when the offence was reported.
+		///
+		/// The dispatch origin for this call must be [`Config::VetoOrigin`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn veto_deferred_offence(
+			origin: OriginFor<T>,
+			id: DeferredOffenceId,
+		) -> DispatchResult {
+			T::VetoOrigin::ensure_origin(origin)?;
+			DeferredOffences::<T>::take(id).ok_or(Error::<T>::UnknownDeferredOffence)?;
+			Self::deposit_event(Event::DeferredOffenceVetoed { id });
+			Ok(())
+		}
+
+		/// Reduce the slashes of all the offenders of the deferred offence `id` to
+		/// `slash_fraction`.
+		///
+		/// The dispatch origin for this call must be [`Config::VetoOrigin`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn adjust_deferred_offence(
+			origin: OriginFor<T>,
+			id: DeferredOffenceId,
+			slash_fraction: Perbill,
+		) -> DispatchResult {
+			T::VetoOrigin::ensure_origin(origin)?;
+			DeferredOffences::<T>::try_mutate(id, |offence| {
+				let offence = offence.as_mut().ok_or(Error::<T>::UnknownDeferredOffence)?;
+				ensure!(
+					offence.slash_fraction.iter().all(|fraction| slash_fraction <= *fraction),
+					Error::<T>::SlashFractionIncreased
+				);
+				offence.slash_fraction.iter_mut().for_each(|fraction| *fraction = slash_fraction);
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::DeferredOffenceAdjusted { id, slash_fraction });
+			Ok(())
+		}
 	}
 }
 
@@ -128,9 +266,13 @@ where
 
 		let slash_perbill: Vec<_> = (0..concurrent_offenders.len()).map(|_| new_fraction).collect();
 
+		// With a defer period, the offenders are disabled right away, but only slashed once the
+		// veto period ends.
+		let defer_period = T::DeferPeriod::get();
+		let no_slash: Vec<_> = (0..concurrent_offenders.len()).map(|_| Perbill::zero()).collect();
 		T::OnOffenceHandler::on_offence(
 			&concurrent_offenders,
-			&slash_perbill,
+			if defer_period.is_zero() { &slash_perbill } else { &no_slash },
 			offence.session_index(),
 			offence.disable_strategy(),
 		);
@@ -138,6 +280,22 @@ where
 		// Deposit the event.
 		Self::deposit_event(Event::Offence { kind: O::ID, timeslot: time_slot.encode() });
 
+		if !defer_period.is_zero() {
+			let apply_at = frame_system::Pallet::<T>::block_number().saturating_add(defer_period);
+			let id = Self::defer_offence(DeferredOffence {
+				offenders: concurrent_offenders,
+				slash_fraction: slash_perbill,
+				session_index: offence.session_index(),
+				disable_strategy: offence.disable_strategy(),
+				apply_at,
+			});
+			Self::deposit_event(Event::OffenceDeferred {
+				id,
+				kind: O::ID,
+				timeslot: time_slot.encode(),
+			});
+		}
+
 		Ok(())
 	}
 
@@ -152,6 +310,18 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+	/// Store the deferred `offence` until its slashes are applied, returning its identifier.
+	fn defer_offence(offence: DeferredOffenceOf<T>) -> DeferredOffenceId {
+		let id = NextDeferredOffenceId::<T>::mutate(|next| {
+			let id = *next;
+			next.saturating_inc();
+			id
+		});
+		DeferredOffencesDue::<T>::append(offence.apply_at, id);
+		DeferredOffences::<T>::insert(id, offence);
+		id
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of offender.
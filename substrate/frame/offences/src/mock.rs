@@ -42,6 +42,7 @@ pub struct OnOffenceHandler;
 parameter_types! {
 	pub static OnOffencePerbill: Vec<Perbill> = Default::default();
 	pub static OffenceWeight: Weight = Default::default();
+	pub static DeferPeriod: u64 = 0;
 }
 
 impl<Reporter, Offender> offence::OnOffenceHandler<Reporter, Offender, Weight>
@@ -71,7 +72,7 @@ frame_support::construct_runtime!(
 	pub struct Runtime
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Offences: offences::{Pallet, Storage, Event},
+		Offences: offences::{Pallet, Call, Storage, Event},
 	}
 );
 
@@ -106,6 +107,8 @@ impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = u64;
 	type OnOffenceHandler = OnOffenceHandler;
+	type DeferPeriod = DeferPeriod;
+	type VetoOrigin = frame_system::EnsureRoot<u64>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -21,11 +21,12 @@
 
 use super::*;
 use crate::mock::{
-	new_test_ext, offence_reports, with_on_offence_fractions, Offence, Offences, RuntimeEvent,
-	System, KIND,
+	new_test_ext, offence_reports, with_on_offence_fractions, DeferPeriod, Offence, Offences,
+	RuntimeEvent, RuntimeOrigin, System, KIND,
 };
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use frame_system::{EventRecord, Phase};
-use sp_runtime::Perbill;
+use sp_runtime::{DispatchError, Perbill};
 
 #[test]
 fn should_report_an_authority_and_trigger_on_offence() {
@@ -245,3 +246,78 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn should_defer_slashes() {
+	new_test_ext().execute_with(|| {
+		// given
+		DeferPeriod::set(10);
+		let time_slot = 42;
+		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
+
+		// when
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		// the offender is reported without being slashed.
+		with_on_offence_fractions(|f| assert_eq!(f.clone(), vec![Perbill::zero()]));
+		System::assert_last_event(
+			Event::OffenceDeferred { id: 0, kind: KIND, timeslot: time_slot.encode() }.into(),
+		);
+		assert_eq!(DeferredOffences::<crate::mock::Runtime>::get(0).unwrap().apply_at, 11);
+
+		// the slash is applied at the end of the defer period.
+		Offences::on_initialize(10);
+		with_on_offence_fractions(|f| assert_eq!(f.clone(), vec![Perbill::zero()]));
+		Offences::on_initialize(11);
+		with_on_offence_fractions(|f| assert_eq!(f.clone(), vec![Perbill::from_percent(25)]));
+		System::assert_last_event(Event::DeferredOffenceApplied { id: 0 }.into());
+		assert_eq!(DeferredOffences::<crate::mock::Runtime>::get(0), None);
+	});
+}
+
+#[test]
+fn should_veto_and_adjust_deferred_slashes() {
+	new_test_ext().execute_with(|| {
+		// given
+		DeferPeriod::set(10);
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5] };
+		Offences::report_offence(vec![], offence).unwrap();
+		let offence = Offence { validator_set_count: 5, time_slot: 43, offenders: vec![4] };
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// when
+		assert_noop!(
+			Offences::veto_deferred_offence(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Offences::veto_deferred_offence(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::DeferredOffenceVetoed { id: 0 }.into());
+		assert_noop!(
+			Offences::veto_deferred_offence(RuntimeOrigin::root(), 0),
+			Error::<crate::mock::Runtime>::UnknownDeferredOffence
+		);
+
+		// slashes can only be reduced.
+		assert_noop!(
+			Offences::adjust_deferred_offence(RuntimeOrigin::root(), 1, Perbill::from_percent(30)),
+			Error::<crate::mock::Runtime>::SlashFractionIncreased
+		);
+		assert_ok!(Offences::adjust_deferred_offence(
+			RuntimeOrigin::root(),
+			1,
+			Perbill::from_percent(10)
+		));
+		System::assert_last_event(
+			Event::DeferredOffenceAdjusted { id: 1, slash_fraction: Perbill::from_percent(10) }
+				.into(),
+		);
+
+		// then
+		// only the adjusted slash is applied.
+		with_on_offence_fractions(|f| f.clear());
+		Offences::on_initialize(11);
+		with_on_offence_fractions(|f| assert_eq!(f.clone(), vec![Perbill::from_percent(10)]));
+		System::assert_last_event(Event::DeferredOffenceApplied { id: 1 }.into());
+	});
+}
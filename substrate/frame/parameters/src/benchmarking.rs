@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the parameters pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Parameters;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use sp_std::{vec, vec::Vec};
+
+fn max_value<T: Config>(byte: u8) -> ValueOf<T> {
+	vec![byte; T::MaxValueLen::get() as usize].try_into().expect("has the maximum length; qed")
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_parameter() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let id = [1u8; 32];
+		pallet::Parameters::<T>::insert(id, max_value::<T>(0));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id, Some(max_value::<T>(1)));
+
+		assert_eq!(pallet::Parameters::<T>::get(id), Some(max_value::<T>(1)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_parameters(n: Linear<1, { T::MaxBatchLen::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let parameters: Vec<_> = (0..n)
+			.map(|i| {
+				let mut id = [0u8; 32];
+				id[..4].copy_from_slice(&i.to_le_bytes());
+				pallet::Parameters::<T>::insert(id, max_value::<T>(0));
+				Parameter { id, value: Some(max_value::<T>(1)) }
+			})
+			.collect();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, parameters);
+
+		assert_eq!(pallet::Parameters::<T>::get([0u8; 32]), Some(max_value::<T>(1)));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite! { Parameters, crate::mock::new_test_ext(), crate::mock::Test }
+}
@@ -0,0 +1,250 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Parameters Pallet
+//!
+//! Stores the dynamic parameters of other pallets.
+//!
+//! An associated type of a pallet `Config` can be declared `#[pallet::dynamic]`, which makes the
+//! pallet macro generate a [`Key`] for it in the `dynamic_params` module of that pallet. The
+//! runtime can then configure it with [`Dynamic`](frame_support::traits::dynamic_params::Dynamic)
+//! to read its value from this pallet, which lets the `AdminOrigin` change it with
+//! [`Pallet::set_parameter`] without a runtime upgrade. The default value of the `Dynamic` is used
+//! while the parameter is not set.
+//!
+//! The values are stored SCALE encoded under the [`ParameterId`] of their key. A value that can
+//! not be decoded into the type of its key is ignored, i.e. the default value is used instead.
+//!
+//! Several parameters can be changed at once with [`Pallet::set_parameters`]. The runtime can
+//! check invariants between parameters, e.g. that a suspend threshold stays below a drop
+//! threshold, with the [`ValidateParameters`] hook. It runs after every change and reverts the
+//! whole change if it fails.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::dynamic_params::{Key, ParameterId, ParameterStore},
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::parameters";
+
+/// The encoded value of a parameter.
+pub type ValueOf<T> = BoundedVec<u8, <T as Config>::MaxValueLen>;
+
+/// A [`Parameter`] with the encoded value of the pallet.
+pub type ParameterOf<T> = Parameter<ValueOf<T>>;
+
+/// The value of a parameter, as set by [`Pallet::set_parameters`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Parameter<Value> {
+	/// The id of the parameter.
+	pub id: ParameterId,
+	/// The encoded value of the parameter, or `None` if it is not set.
+	pub value: Option<Value>,
+}
+
+/// Validates the parameters after they were changed, e.g. to check invariants between several
+/// parameters.
+///
+/// The new values are already stored when [`ValidateParameters::validate`] is called, so they can
+/// be read through [`Pallet`] as a [`ParameterStore`]. All the changes of the call are reverted if
+/// it returns an error. The weight of the validation is not accounted for, so it should be light.
+pub trait ValidateParameters {
+	/// Validate the parameters after the parameters `changed` were set or cleared.
+	fn validate(changed: &[ParameterId]) -> DispatchResult;
+}
+
+impl ValidateParameters for () {
+	fn validate(_changed: &[ParameterId]) -> DispatchResult {
+		Ok(())
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may set and clear parameters.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum length of the encoded value of a parameter.
+		#[pallet::constant]
+		type MaxValueLen: Get<u32>;
+
+		/// The maximum number of parameters that can be changed by one call of
+		/// [`Pallet::set_parameters`].
+		#[pallet::constant]
+		type MaxBatchLen: Get<u32>;
+
+		/// Validates the parameters after every change.
+		type Validator: ValidateParameters;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The encoded values of the parameters that are set.
+	#[pallet::storage]
+	pub type Parameters<T: Config> =
+		StorageMap<_, Blake2_128Concat, ParameterId, ValueOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A parameter was set or cleared.
+		Updated {
+			/// The id of the parameter.
+			id: ParameterId,
+			/// The previous encoded value of the parameter, if it was set.
+			old_value: Option<ValueOf<T>>,
+			/// The new encoded value of the parameter, if it is set.
+			new_value: Option<ValueOf<T>>,
+		},
+		/// Several parameters were set or cleared at once.
+		BatchUpdated {
+			/// The previous values of the parameters.
+			old_values: Vec<ParameterOf<T>>,
+			/// The new values of the parameters.
+			new_values: Vec<ParameterOf<T>>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// More than `MaxBatchLen` parameters were given.
+		TooManyParameters,
+		/// The same parameter was given more than once.
+		DuplicateParameter,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the SCALE encoded `value` of the parameter `id`, or clear it if `value` is `None`.
+		///
+		/// The origin must be the `AdminOrigin`. The id of a parameter is the
+		/// [`Key::id`](frame_support::traits::dynamic_params::Key::id) of its key.
+		///
+		/// The change is reverted if it is rejected by the `Validator`.
+		///
+		/// Emits [`Event::Updated`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			id: ParameterId,
+			value: Option<ValueOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let old_value = Self::replace(id, value.clone());
+			T::Validator::validate(&[id])?;
+			Self::deposit_event(Event::Updated { id, old_value, new_value: value });
+			Ok(())
+		}
+
+		/// Set or clear the values of several `parameters` at once.
+		///
+		/// The origin must be the `AdminOrigin`. The `Validator` checks the parameters once all
+		/// of them are changed, and none of the changes is kept if it rejects them.
+		///
+		/// Emits [`Event::BatchUpdated`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_parameters(parameters.len() as u32))]
+		pub fn set_parameters(
+			origin: OriginFor<T>,
+			parameters: Vec<ParameterOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				parameters.len() <= T::MaxBatchLen::get() as usize,
+				Error::<T>::TooManyParameters
+			);
+
+			let mut ids = Vec::with_capacity(parameters.len());
+			for Parameter { id, .. } in &parameters {
+				ensure!(!ids.contains(id), Error::<T>::DuplicateParameter);
+				ids.push(*id);
+			}
+
+			let old_values = parameters
+				.iter()
+				.map(|Parameter { id, value }| Parameter {
+					id: *id,
+					value: Self::replace(*id, value.clone()),
+				})
+				.collect();
+			T::Validator::validate(&ids)?;
+
+			Self::deposit_event(Event::BatchUpdated { old_values, new_values: parameters });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Replace the value of the parameter `id` by `value`, returning the previous value.
+	fn replace(id: ParameterId, value: Option<ValueOf<T>>) -> Option<ValueOf<T>> {
+		Parameters::<T>::mutate_exists(id, |v| sp_std::mem::replace(v, value))
+	}
+}
+
+impl<T: Config> ParameterStore for Pallet<T> {
+	fn get<K: Key>() -> Option<K::Value> {
+		let value = Parameters::<T>::get(K::id())?;
+		match K::Value::decode_all(&mut &value[..]) {
+			Ok(value) => Some(value),
+			Err(_) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to decode the parameter {}::{}, using its default value",
+					K::pallet_name(),
+					K::NAME,
+				);
+				None
+			},
+		}
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for the parameters pallet.
+
+use crate as pallet_parameters;
+use frame_support::{
+	derive_impl,
+	dispatch::DispatchResult,
+	traits::{
+		dynamic_params::{Dynamic, ParameterId},
+		ConstU16, ConstU32, ConstU64, Get,
+	},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+pub type Block = frame_system::mocking::MockBlock<Test>;
+
+/// A pallet with a dynamic parameter, to be configured from `pallet_parameters`.
+#[frame_support::pallet]
+pub mod pallet_dynamic {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// A deposit that can be changed through `pallet_parameters`.
+		#[pallet::constant]
+		#[pallet::dynamic]
+		type Deposit: Get<u64>;
+
+		/// A threshold that must stay below `DropThreshold`.
+		#[pallet::dynamic]
+		type SuspendThreshold: Get<u32>;
+
+		/// A threshold that must stay above `SuspendThreshold`.
+		#[pallet::dynamic]
+		type DropThreshold: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+}
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Parameters: pallet_parameters::{Pallet, Call, Storage, Event<T>},
+		DynamicPallet: pallet_dynamic::{Pallet},
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_parameters::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = EnsureRoot<u64>;
+	type MaxValueLen = ConstU32<16>;
+	type MaxBatchLen = ConstU32<4>;
+	type Validator = ThresholdsAreOrdered;
+	type WeightInfo = ();
+}
+
+pub type SuspendThreshold =
+	Dynamic<Parameters, pallet_dynamic::dynamic_params::SuspendThreshold<Test>, ConstU32<5>>;
+pub type DropThreshold =
+	Dynamic<Parameters, pallet_dynamic::dynamic_params::DropThreshold<Test>, ConstU32<10>>;
+
+impl pallet_dynamic::Config for Test {
+	type Deposit = Dynamic<Parameters, pallet_dynamic::dynamic_params::Deposit<Test>, ConstU64<10>>;
+	type SuspendThreshold = SuspendThreshold;
+	type DropThreshold = DropThreshold;
+}
+
+/// Ensures that `SuspendThreshold` stays below `DropThreshold`.
+pub struct ThresholdsAreOrdered;
+
+impl pallet_parameters::ValidateParameters for ThresholdsAreOrdered {
+	fn validate(_changed: &[ParameterId]) -> DispatchResult {
+		if SuspendThreshold::get() >= DropThreshold::get() {
+			return Err("`SuspendThreshold` must be below `DropThreshold`".into())
+		}
+		Ok(())
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = RuntimeGenesisConfig { system: Default::default() }.build_storage().unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,155 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the parameters pallet.
+
+use crate::{mock::*, pallet, Error, Event, Parameter, ValueOf};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{dynamic_params::Key, Get},
+};
+use sp_runtime::DispatchError;
+
+type Deposit = pallet_dynamic::dynamic_params::Deposit<Test>;
+type SuspendThresholdKey = pallet_dynamic::dynamic_params::SuspendThreshold<Test>;
+type DropThresholdKey = pallet_dynamic::dynamic_params::DropThreshold<Test>;
+
+fn value(v: impl Encode) -> Option<ValueOf<Test>> {
+	Some(v.encode().try_into().unwrap())
+}
+
+#[test]
+fn dynamic_parameter_can_be_changed() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 10);
+
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), Deposit::id(), value(42u64)));
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 42);
+		System::assert_last_event(
+			Event::Updated { id: Deposit::id(), old_value: None, new_value: value(42u64) }.into(),
+		);
+
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), Deposit::id(), None));
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 10);
+		assert!(pallet::Parameters::<Test>::get(Deposit::id()).is_none());
+		System::assert_last_event(
+			Event::Updated { id: Deposit::id(), old_value: value(42u64), new_value: None }.into(),
+		);
+	});
+}
+
+#[test]
+fn key_id_is_derived_from_the_pallet_and_parameter_names() {
+	assert_eq!(Deposit::NAME, "Deposit");
+	assert_eq!(Deposit::pallet_name(), "DynamicPallet");
+	assert_eq!(
+		Deposit::id(),
+		frame_support::traits::dynamic_params::parameter_id("DynamicPallet", "Deposit"),
+	);
+}
+
+#[test]
+fn undecodable_value_falls_back_to_the_default() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), Deposit::id(), value(42u32)));
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 10);
+
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), Deposit::id(), value(42u128)));
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 10);
+	});
+}
+
+#[test]
+fn set_parameter_requires_admin_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Parameters::set_parameter(RuntimeOrigin::signed(1), Deposit::id(), value(42u64)),
+			DispatchError::BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn set_parameters_applies_all_changes() {
+	new_test_ext().execute_with(|| {
+		let parameters = vec![
+			Parameter { id: DropThresholdKey::id(), value: value(20u32) },
+			Parameter { id: SuspendThresholdKey::id(), value: value(15u32) },
+		];
+		assert_ok!(Parameters::set_parameters(RuntimeOrigin::root(), parameters.clone()));
+		assert_eq!(SuspendThreshold::get(), 15);
+		assert_eq!(DropThreshold::get(), 20);
+		System::assert_last_event(
+			Event::BatchUpdated {
+				old_values: vec![
+					Parameter { id: DropThresholdKey::id(), value: None },
+					Parameter { id: SuspendThresholdKey::id(), value: None },
+				],
+				new_values: parameters,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_parameters_is_atomic() {
+	new_test_ext().execute_with(|| {
+		// Raising the suspend threshold above the default drop threshold is rejected.
+		assert_noop!(
+			Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				SuspendThresholdKey::id(),
+				value(15u32)
+			),
+			DispatchError::Other("`SuspendThreshold` must be below `DropThreshold`"),
+		);
+
+		let parameters = vec![
+			Parameter { id: Deposit::id(), value: value(42u64) },
+			Parameter { id: DropThresholdKey::id(), value: value(20u32) },
+			Parameter { id: SuspendThresholdKey::id(), value: value(25u32) },
+		];
+		assert_noop!(
+			Parameters::set_parameters(RuntimeOrigin::root(), parameters),
+			DispatchError::Other("`SuspendThreshold` must be below `DropThreshold`"),
+		);
+		assert_eq!(<Test as pallet_dynamic::Config>::Deposit::get(), 10);
+		assert_eq!(DropThreshold::get(), 10);
+	});
+}
+
+#[test]
+fn set_parameters_rejects_invalid_batches() {
+	new_test_ext().execute_with(|| {
+		let parameter = Parameter { id: Deposit::id(), value: value(42u64) };
+		assert_noop!(
+			Parameters::set_parameters(RuntimeOrigin::root(), vec![parameter.clone(); 2]),
+			Error::<Test>::DuplicateParameter,
+		);
+		let parameters = (0..5u8).map(|i| Parameter { id: [i; 32], value: None }).collect();
+		assert_noop!(
+			Parameters::set_parameters(RuntimeOrigin::root(), parameters),
+			Error::<Test>::TooManyParameters,
+		);
+		assert_noop!(
+			Parameters::set_parameters(RuntimeOrigin::signed(1), vec![parameter]),
+			DispatchError::BadOrigin,
+		);
+	});
+}
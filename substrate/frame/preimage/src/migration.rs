@@ -19,6 +19,7 @@
 
 use super::*;
 use frame_support::{
+	migrations::VersionedMigration,
 	storage_alias,
 	traits::{ConstU32, OnRuntimeUpgrade},
 };
@@ -188,6 +189,94 @@ pub mod v1 {
 	}
 }
 
+/// Migration of the preimage deposits to another [`Config::Consideration`].
+pub mod tickets {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::preimage::migration::tickets";
+
+	/// Converts the ticket of a preimage deposit from one consideration to another.
+	pub trait ConvertTicket<AccountId, Old, New> {
+		/// Convert the `old` ticket of `who` for `footprint`.
+		///
+		/// This should not do more than two storage reads and writes.
+		fn convert_ticket(who: &AccountId, old: Old, footprint: Footprint) -> New;
+	}
+
+	/// Converts the tickets of all the preimage deposits from `Old`, the previous
+	/// [`Config::Consideration`], to the current one with `C`.
+	///
+	/// The encoding of the tickets does not tell whether they were converted already, so this must
+	/// be run exactly once. Use [`MigrateTickets`], which only runs it on storage version 1.
+	pub struct VersionUncheckedMigrateTickets<T, Old, C>(
+		sp_std::marker::PhantomData<(T, Old, C)>,
+	);
+
+	impl<T, Old, C> OnRuntimeUpgrade for VersionUncheckedMigrateTickets<T, Old, C>
+	where
+		T: Config,
+		Old: Decode,
+		C: ConvertTicket<T::AccountId, Old, TicketOf<T>>,
+	{
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((crate::RequestStatusFor::<T>::iter_keys().count() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			let convert = |(who, old): (T::AccountId, Old), len: u32| {
+				let ticket = C::convert_ticket(&who, old, Footprint::from_parts(1, len as usize));
+				(who, ticket)
+			};
+			crate::RequestStatusFor::<T>::translate::<RequestStatus<T::AccountId, Old>, _>(
+				|_, status| {
+					translated.saturating_inc();
+					Some(match status {
+						RequestStatus::Unrequested { ticket, len } =>
+							RequestStatus::Unrequested { ticket: convert(ticket, len), len },
+						RequestStatus::Requested { maybe_ticket, count, maybe_len } => {
+							let len = maybe_len.unwrap_or_default();
+							let maybe_ticket = maybe_ticket.map(|ticket| convert(ticket, len));
+							RequestStatus::Requested { maybe_ticket, count, maybe_len }
+						},
+					})
+				},
+			);
+			log::info!(target: TARGET, "converted the tickets of {} preimages", translated);
+
+			// One read and write for the status, and up to two for the conversion.
+			T::DbWeight::get().reads_writes(translated * 3, translated * 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> DispatchResult {
+			let pre: u32 =
+				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+			let post = crate::RequestStatusFor::<T>::iter_values().count() as u32;
+			ensure!(pre == post, "must convert all the tickets");
+			Ok(())
+		}
+	}
+
+	/// [`VersionUncheckedMigrateTickets`] wrapped in a [`VersionedMigration`], which converts the
+	/// tickets in the runtime upgrade which changes [`Config::Consideration`], and bumps the
+	/// storage version to 2.
+	pub type MigrateTickets<T, Old, C> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateTickets<T, Old, C>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Bumps the storage version to 2 without converting any tickets, for runtimes which keep
+	/// their [`Config::Consideration`].
+	pub type KeepTickets<T> =
+		VersionedMigration<1, 2, (), Pallet<T>, <T as frame_system::Config>::DbWeight>;
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -21,7 +21,7 @@
 
 use super::*;
 use crate::Pallet as Proxy;
-use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::traits::Bounded;
 
@@ -260,5 +260,65 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
 	}
 
+	restore_pure_access {
+		let origin =
+			T::RecoveryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let spawner: T::AccountId = whitelisted_caller();
+		let pure_account = Pallet::<T>::pure_account(&spawner, &T::ProxyType::default(), 0, None);
+		let height = system::Pallet::<T>::block_number();
+		let ext_index = system::Pallet::<T>::extrinsic_index().unwrap_or(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+	}: _<T::RuntimeOrigin>(
+		origin,
+		T::Lookup::unlookup(pure_account.clone()),
+		T::Lookup::unlookup(spawner),
+		T::ProxyType::default(),
+		0,
+		height,
+		ext_index,
+		T::Lookup::unlookup(delegate)
+	)
+	verify {
+		assert!(PureRestorations::<T>::contains_key(&pure_account));
+	}
+
+	enact_pure_restoration {
+		let p in 1 .. (T::MaxProxies::get() - 1);
+
+		let spawner: T::AccountId = whitelisted_caller();
+		let pure_account = Pallet::<T>::pure_account(&spawner, &T::ProxyType::default(), 0, None);
+		add_proxies::<T>(p - 1, Some(pure_account.clone()))?;
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		PureRestorations::<T>::insert(&pure_account, PureRestoration {
+			spawner: spawner.clone(),
+			delegate: delegate.clone(),
+			proxy_type: T::ProxyType::default(),
+			enact_at: system::Pallet::<T>::block_number(),
+		});
+	}: _(RawOrigin::Signed(spawner), T::Lookup::unlookup(pure_account.clone()))
+	verify {
+		let (proxies, _) = Proxies::<T>::get(&pure_account);
+		assert_eq!(proxies.len() as u32, p);
+		assert_last_event::<T>(Event::PureAccessRestored {
+			pure: pure_account,
+			delegate,
+			proxy_type: T::ProxyType::default(),
+		}.into());
+	}
+
+	cancel_pure_restoration {
+		let spawner: T::AccountId = whitelisted_caller();
+		let pure_account = Pallet::<T>::pure_account(&spawner, &T::ProxyType::default(), 0, None);
+		PureRestorations::<T>::insert(&pure_account, PureRestoration {
+			spawner,
+			delegate: account("delegate", 0, SEED),
+			proxy_type: T::ProxyType::default(),
+			enact_at: system::Pallet::<T>::block_number(),
+		});
+	}: _(RawOrigin::Signed(pure_account.clone()), T::Lookup::unlookup(pure_account.clone()))
+	verify {
+		assert!(!PureRestorations::<T>::contains_key(&pure_account));
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -23,6 +23,12 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! Access to a pure account may be restored by the `RecoveryOrigin` to a new delegate, given the
+//! parameters with which the spawner created it, e.g. after its proxies were all removed. Its
+//! existing proxies, if any, are kept. The restoration is public for `RecoveryDelay` blocks before
+//! it may be enacted, during which it may be cancelled by the `RecoveryOrigin` or by the pure
+//! account itself, through any proxy it still has.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -36,8 +42,12 @@ pub mod weights;
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::GetDispatchInfo,
+	dispatch_context::dispatch_nested,
 	ensure,
-	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
+	traits::{
+		Currency, EnsureOrigin, Get, InstanceFilter, IsSubType, IsType, OriginTrait,
+		ReservableCurrency,
+	},
 };
 use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
 pub use pallet::*;
@@ -93,6 +103,19 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// A scheduled restoration of access to a pure account.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PureRestoration<AccountId, ProxyType, BlockNumber> {
+	/// The account which spawned the pure account.
+	pub spawner: AccountId,
+	/// The account which will be registered as a proxy of the pure account.
+	pub delegate: AccountId,
+	/// The proxy type which the pure account was spawned with, and which `delegate` will have.
+	pub proxy_type: ProxyType,
+	/// The block from which the restoration may be enacted.
+	pub enact_at: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -173,6 +196,14 @@ pub mod pallet {
 		/// into a pre-existing storage value.
 		#[pallet::constant]
 		type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
+
+		/// The origin which may schedule the restoration of access to a pure account.
+		type RecoveryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks a scheduled restoration of access to a pure account must be public
+		/// for before it may be enacted.
+		#[pallet::constant]
+		type RecoveryDelay: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::call]
@@ -512,6 +543,129 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Schedule the restoration of access to a pure account by registering `delegate` as its
+		/// proxy after `RecoveryDelay` blocks.
+		///
+		/// This does not require the pure account to have lost all its proxies; those it has are
+		/// kept, and may be used to cancel the restoration during the delay.
+		///
+		/// The dispatch origin for this call must be `RecoveryOrigin`.
+		///
+		/// - `pure`: The pure account to restore access to.
+		/// - `spawner`: The account that originally called `pure` to create this account.
+		/// - `proxy_type`: The proxy type originally passed to `pure`. `delegate` will be
+		/// registered with this type.
+		/// - `index`: The disambiguation index originally passed to `pure`. Probably `0`.
+		/// - `height`: The height of the chain when the call to `pure` was processed.
+		/// - `ext_index`: The extrinsic index in which the call to `pure` was processed.
+		/// - `delegate`: The account to register as a proxy of `pure`.
+		///
+		/// Fails with `NoPermission` in case `pure` was not created by `spawner` through a call
+		/// to `pure` with corresponding parameters, and with `Duplicate` in case a restoration of
+		/// `pure` is already scheduled.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::restore_pure_access())]
+		pub fn restore_pure_access(
+			origin: OriginFor<T>,
+			pure: AccountIdLookupOf<T>,
+			spawner: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			index: u16,
+			#[pallet::compact] height: BlockNumberFor<T>,
+			#[pallet::compact] ext_index: u32,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::RecoveryOrigin::ensure_origin(origin)?;
+			let pure = T::Lookup::lookup(pure)?;
+			let spawner = T::Lookup::lookup(spawner)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let when = (height, ext_index);
+			let account = Self::pure_account(&spawner, &proxy_type, index, Some(when));
+			ensure!(account == pure, Error::<T>::NoPermission);
+			ensure!(delegate != pure, Error::<T>::NoSelfProxy);
+			ensure!(!PureRestorations::<T>::contains_key(&pure), Error::<T>::Duplicate);
+
+			let enact_at =
+				system::Pallet::<T>::block_number().saturating_add(T::RecoveryDelay::get());
+			PureRestorations::<T>::insert(
+				&pure,
+				PureRestoration {
+					spawner: spawner.clone(),
+					delegate: delegate.clone(),
+					proxy_type: proxy_type.clone(),
+					enact_at,
+				},
+			);
+			Self::deposit_event(Event::PureRestorationScheduled {
+				pure,
+				spawner,
+				delegate,
+				proxy_type,
+				enact_at,
+			});
+
+			Ok(())
+		}
+
+		/// Enact a scheduled restoration of access to a pure account once its delay passed.
+		///
+		/// The delegate is registered as a proxy of the pure account, whose deposit is taken from
+		/// the pure account.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `pure`: The pure account whose access is restored.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::enact_pure_restoration(T::MaxProxies::get()))]
+		pub fn enact_pure_restoration(
+			origin: OriginFor<T>,
+			pure: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let pure = T::Lookup::lookup(pure)?;
+			let restoration = PureRestorations::<T>::get(&pure).ok_or(Error::<T>::NotFound)?;
+			let now = system::Pallet::<T>::block_number();
+			ensure!(now >= restoration.enact_at, Error::<T>::Premature);
+
+			PureRestorations::<T>::remove(&pure);
+			Self::add_proxy_delegate(
+				&pure,
+				restoration.delegate.clone(),
+				restoration.proxy_type.clone(),
+				Zero::zero(),
+			)?;
+			Self::deposit_event(Event::PureAccessRestored {
+				pure,
+				delegate: restoration.delegate,
+				proxy_type: restoration.proxy_type,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a scheduled restoration of access to a pure account.
+		///
+		/// The dispatch origin for this call must be `RecoveryOrigin` or _Signed_ by the pure
+		/// account itself.
+		///
+		/// - `pure`: The pure account whose restoration is cancelled.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_pure_restoration())]
+		pub fn cancel_pure_restoration(
+			origin: OriginFor<T>,
+			pure: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let pure = T::Lookup::lookup(pure)?;
+			if let Err(origin) = T::RecoveryOrigin::try_origin(origin) {
+				ensure!(ensure_signed(origin)? == pure, Error::<T>::NoPermission);
+			}
+			ensure!(PureRestorations::<T>::take(&pure).is_some(), Error::<T>::NotFound);
+			Self::deposit_event(Event::PureRestorationCancelled { pure });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -543,6 +697,18 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// The restoration of access to a pure account was scheduled.
+		PureRestorationScheduled {
+			pure: T::AccountId,
+			spawner: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			enact_at: BlockNumberFor<T>,
+		},
+		/// A scheduled restoration of access to a pure account was cancelled.
+		PureRestorationCancelled { pure: T::AccountId },
+		/// Access to a pure account was restored to a new delegate.
+		PureAccessRestored { pure: T::AccountId, delegate: T::AccountId, proxy_type: T::ProxyType },
 	}
 
 	#[pallet::error]
@@ -563,6 +729,8 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The restoration of access to a pure account is still within its delay.
+		Premature,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -596,6 +764,17 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The scheduled restorations of access to pure accounts, keyed by the pure account.
+	#[pallet::storage]
+	#[pallet::getter(fn pure_restorations)]
+	pub type PureRestorations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		PureRestoration<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -787,15 +966,17 @@ impl<T: Config> Pallet<T> {
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(proxy_type) =>
 					false,
-				// Proxy call cannot remove all proxies or kill pure proxies unless it has full
-				// permissions.
-				Some(Call::remove_proxies { .. }) | Some(Call::kill_pure { .. })
+				// Proxy call cannot remove all proxies, kill pure proxies or cancel their
+				// restoration unless it has full permissions.
+				Some(Call::remove_proxies { .. }) |
+				Some(Call::kill_pure { .. }) |
+				Some(Call::cancel_pure_restoration { .. })
 					if def.proxy_type != T::ProxyType::default() =>
 					false,
 				_ => def.proxy_type.filter(c),
 			}
 		});
-		let e = call.dispatch(origin);
+		let e = dispatch_nested::<T::NestedCallLimits, _, _, _>(call, |call| call.dispatch(origin));
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
 	}
 
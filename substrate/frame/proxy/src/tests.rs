@@ -126,6 +126,8 @@ impl Config for Test {
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type RecoveryOrigin = frame_system::EnsureRoot<u64>;
+	type RecoveryDelay = ConstU64<10>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -590,3 +592,112 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn restore_pure_access_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		let anon = Proxy::pure_account(&1, &ProxyType::Any, 0, None);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+		// All access to the pure account is lost.
+		let call = Box::new(RuntimeCall::Proxy(ProxyCall::remove_proxies {}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(1), anon, None, call));
+		assert!(!Proxies::<Test>::contains_key(anon));
+
+		assert_noop!(
+			Proxy::restore_pure_access(
+				RuntimeOrigin::signed(1),
+				anon,
+				1,
+				ProxyType::Any,
+				0,
+				1,
+				0,
+				2
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Proxy::restore_pure_access(RuntimeOrigin::root(), anon, 1, ProxyType::Any, 1, 1, 0, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Proxy::restore_pure_access(
+			RuntimeOrigin::root(),
+			anon,
+			1,
+			ProxyType::Any,
+			0,
+			1,
+			0,
+			2
+		));
+		System::assert_last_event(
+			ProxyEvent::PureRestorationScheduled {
+				pure: anon,
+				spawner: 1,
+				delegate: 2,
+				proxy_type: ProxyType::Any,
+				enact_at: 11,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Proxy::restore_pure_access(RuntimeOrigin::root(), anon, 1, ProxyType::Any, 0, 1, 0, 3),
+			Error::<Test>::Duplicate
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Proxy::enact_pure_restoration(RuntimeOrigin::signed(3), anon),
+			Error::<Test>::Premature
+		);
+		System::set_block_number(11);
+		assert_ok!(Proxy::enact_pure_restoration(RuntimeOrigin::signed(3), anon));
+		System::assert_last_event(
+			ProxyEvent::PureAccessRestored { pure: anon, delegate: 2, proxy_type: ProxyType::Any }
+				.into(),
+		);
+		assert!(!PureRestorations::<Test>::contains_key(anon));
+		assert_eq!(Balances::reserved_balance(anon), 2);
+
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), anon, None, call));
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_noop!(
+			Proxy::enact_pure_restoration(RuntimeOrigin::signed(3), anon),
+			Error::<Test>::NotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_pure_restoration_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		let anon = Proxy::pure_account(&1, &ProxyType::Any, 0, None);
+		let restore = || {
+			Proxy::restore_pure_access(RuntimeOrigin::root(), anon, 1, ProxyType::Any, 0, 1, 0, 2)
+		};
+
+		assert_ok!(restore());
+		assert_noop!(
+			Proxy::cancel_pure_restoration(RuntimeOrigin::signed(2), anon),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Proxy::cancel_pure_restoration(RuntimeOrigin::root(), anon));
+		System::assert_last_event(ProxyEvent::PureRestorationCancelled { pure: anon }.into());
+		assert_noop!(
+			Proxy::cancel_pure_restoration(RuntimeOrigin::root(), anon),
+			Error::<Test>::NotFound
+		);
+
+		// The controller of the pure account may object to the restoration.
+		assert_ok!(restore());
+		let call = Box::new(RuntimeCall::Proxy(ProxyCall::cancel_pure_restoration { pure: anon }));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(1), anon, None, call));
+		System::assert_has_event(ProxyEvent::PureRestorationCancelled { pure: anon }.into());
+		assert!(!PureRestorations::<Test>::contains_key(anon));
+	});
+}
@@ -173,5 +173,30 @@ benchmarks_instance_pallet! {
 		assert_eq!(Voting::<T, I>::iter().count(), 0);
 	}
 
+	set_exempt {
+		let who = make_member::<T, I>(0);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let origin =
+			T::ExemptOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::set_exempt { who: who_lookup, exempt: true };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Exempt::<T, I>::contains_key(&who));
+		assert_last_event::<T, I>(Event::ExemptionChanged { who, exempt: true }.into());
+	}
+
+	demote_inactive {
+		let period = T::InactivityPeriod::get().ok_or(BenchmarkError::Weightless)?;
+		let who = make_member::<T, I>(1);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let last_active = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(last_active.saturating_add(period));
+	}: _(SystemOrigin::Signed(whitelisted_caller()), who_lookup)
+	verify {
+		assert_eq!(Members::<T, I>::get(&who).map(|x| x.rank), Some(0));
+		assert_eq!(MemberCount::<T, I>::get(1), 0);
+		assert_last_event::<T, I>(Event::RankChanged { who, rank: 0 }.into());
+	}
+
 	impl_benchmark_test_suite!(RankedCollective, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -37,6 +37,10 @@
 //!
 //! An origin control, `EnsureRank`, ensures that the origin is a member of the collective of at
 //! least a particular rank.
+//!
+//! Members who do not vote in any poll for the `InactivityPeriod` can be demoted by one rank by
+//! anyone through `demote_inactive`, once per period, unless the `ExemptOrigin` exempted them.
+//! Changes of rank count as activity, so that an absent member decays one rank at a time.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -54,7 +58,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 use frame_support::{
 	dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
-	traits::{EnsureOrigin, EnsureOriginWithArg, PollStatus, Polling, RankedMembers, VoteTally},
+	traits::{
+		EnsureOrigin, EnsureOriginWithArg, MembershipSnapshot, PollStatus, Polling, RankedMembers,
+		SnapshotMembers, VoteTally,
+	},
 	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 
@@ -403,6 +410,13 @@ pub mod pallet {
 		/// Rank_delta is defined as the number of ranks above the minimum required to take part
 		/// in the poll.
 		type VoteWeight: Convert<Rank, Votes>;
+
+		/// The number of blocks a member may go without voting before they can be demoted with
+		/// [`Pallet::demote_inactive`]. `None` disables the demotion of inactive members.
+		type InactivityPeriod: Get<Option<BlockNumberFor<Self>>>;
+
+		/// The origin which may exempt members from being demoted for their inactivity.
+		type ExemptOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	/// The number of members in the collective who have at least the rank according to the index
@@ -442,6 +456,16 @@ pub mod pallet {
 	pub type VotingCleanup<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, PollIndexOf<T, I>, BoundedVec<u8, KeyLenOf<Voting<T, I>>>>;
 
+	/// The block in which each member last voted or changed rank.
+	#[pallet::storage]
+	pub type LastActive<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// The members who are exempted from being demoted for their inactivity.
+	#[pallet::storage]
+	pub type Exempt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -454,6 +478,11 @@ pub mod pallet {
 		/// The member `who` has voted for the `poll` with the given `vote` leading to an updated
 		/// `tally`.
 		Voted { who: T::AccountId, poll: PollIndexOf<T, I>, vote: VoteRecord, tally: TallyOf<T, I> },
+		/// The member `who` has been exempted from, or made subject to again, the demotion of
+		/// inactive members.
+		ExemptionChanged { who: T::AccountId, exempt: bool },
+		/// The member `who` has been demoted for not being active since the block `last_active`.
+		InactiveMemberDemoted { who: T::AccountId, last_active: BlockNumberFor<T> },
 	}
 
 	#[pallet::error]
@@ -476,6 +505,12 @@ pub mod pallet {
 		InvalidWitness,
 		/// The origin is not sufficiently privileged to do the operation.
 		NoPermission,
+		/// The demotion of inactive members is disabled.
+		InactivityDisabled,
+		/// The member is exempted from being demoted for their inactivity.
+		Exempted,
+		/// The member has been active within the `InactivityPeriod`.
+		NotInactive,
 	}
 
 	#[pallet::call]
@@ -547,7 +582,7 @@ pub mod pallet {
 			for r in 0..=rank {
 				Self::remove_from_rank(&who, r)?;
 			}
-			Members::<T, I>::remove(&who);
+			Self::remove_record(&who);
 			Self::deposit_event(Event::MemberRemoved { who, rank });
 			Ok(PostDispatchInfo {
 				actual_weight: Some(T::WeightInfo::remove_member(rank as u32)),
@@ -604,6 +639,7 @@ pub mod pallet {
 								false => tally.nays.saturating_accrue(votes),
 							}
 							Voting::<T, I>::insert(&poll, &who, &vote);
+							Self::note_activity(&who);
 							Ok((tally.clone(), vote))
 						},
 					}
@@ -650,6 +686,70 @@ pub mod pallet {
 				pays_fee: Pays::No,
 			})
 		}
+
+		/// Exempt a member from being demoted for their inactivity, or make them subject to it
+		/// again.
+		///
+		/// - `origin`: Must be the `ExemptOrigin`.
+		/// - `who`: Account of existing member.
+		/// - `exempt`: Whether the member is exempted.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_exempt())]
+		pub fn set_exempt(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			exempt: bool,
+		) -> DispatchResult {
+			T::ExemptOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::ensure_member(&who)?;
+
+			if exempt {
+				Exempt::<T, I>::insert(&who, ());
+			} else {
+				Exempt::<T, I>::remove(&who);
+			}
+			Self::deposit_event(Event::ExemptionChanged { who, exempt });
+			Ok(())
+		}
+
+		/// Demote a member by one rank, or remove them if they are at rank zero, if they have not
+		/// voted or changed rank for the `InactivityPeriod`.
+		///
+		/// - `origin`: Must be `Signed` by any account.
+		/// - `who`: Account of existing member who is not exempted.
+		///
+		/// If no activity was ever recorded for the member, e.g. because they joined before the
+		/// demotion of inactive members was introduced, their inactivity period starts now.
+		///
+		/// Transaction fees are waived if the member is demoted.
+		///
+		/// Weight: `O(1)`, less if the member's index is highest in its rank.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::demote_inactive())]
+		pub fn demote_inactive(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			Self::ensure_member(&who)?;
+			let period = T::InactivityPeriod::get().ok_or(Error::<T, I>::InactivityDisabled)?;
+			ensure!(!Exempt::<T, I>::contains_key(&who), Error::<T, I>::Exempted);
+
+			let Some(last_active) = LastActive::<T, I>::get(&who) else {
+				Self::note_activity(&who);
+				return Ok(Pays::Yes.into())
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= last_active.saturating_add(period), Error::<T, I>::NotInactive);
+
+			Self::deposit_event(Event::InactiveMemberDemoted { who: who.clone(), last_active });
+			Self::do_demote_member(who, None)?;
+			Ok(Pays::No.into())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -662,6 +762,18 @@ pub mod pallet {
 			Ok(T::VoteWeight::convert(excess))
 		}
 
+		/// Record that `who` was active in the current block.
+		fn note_activity(who: &T::AccountId) {
+			LastActive::<T, I>::insert(who, frame_system::Pallet::<T>::block_number());
+		}
+
+		/// Remove the records of `who`, who is no longer a member.
+		fn remove_record(who: &T::AccountId) {
+			Members::<T, I>::remove(who);
+			LastActive::<T, I>::remove(who);
+			Exempt::<T, I>::remove(who);
+		}
+
 		fn remove_from_rank(who: &T::AccountId, rank: Rank) -> DispatchResult {
 			let last_index = MemberCount::<T, I>::get(rank).saturating_sub(1);
 			let index = IdToIndex::<T, I>::get(rank, &who).ok_or(Error::<T, I>::Corruption)?;
@@ -687,6 +799,7 @@ pub mod pallet {
 			IdToIndex::<T, I>::insert(0, &who, index);
 			IndexToId::<T, I>::insert(0, index, &who);
 			MemberCount::<T, I>::insert(0, count);
+			Self::note_activity(&who);
 			Self::deposit_event(Event::MemberAdded { who });
 			Ok(())
 		}
@@ -709,6 +822,7 @@ pub mod pallet {
 			IdToIndex::<T, I>::insert(rank, &who, index);
 			IndexToId::<T, I>::insert(rank, index, &who);
 			Members::<T, I>::insert(&who, MemberRecord { rank });
+			Self::note_activity(&who);
 			Self::deposit_event(Event::RankChanged { who, rank });
 			Ok(())
 		}
@@ -728,12 +842,13 @@ pub mod pallet {
 			let maybe_rank = rank.checked_sub(1);
 			match maybe_rank {
 				None => {
-					Members::<T, I>::remove(&who);
+					Self::remove_record(&who);
 					Self::deposit_event(Event::MemberRemoved { who, rank: 0 });
 				},
 				Some(rank) => {
 					record.rank = rank;
 					Members::<T, I>::insert(&who, &record);
+					Self::note_activity(&who);
 					Self::deposit_event(Event::RankChanged { who, rank });
 				},
 			}
@@ -784,4 +899,13 @@ pub mod pallet {
 			Self::do_demote_member(who.clone(), None)
 		}
 	}
+
+	/// Iterates over all members, so it is meant to be used outside of the runtime's execution of
+	/// blocks, e.g. by a runtime API.
+	impl<T: Config<I>, I: 'static> SnapshotMembers<T::AccountId, T::Hash> for Pallet<T, I> {
+		fn membership_snapshot() -> MembershipSnapshot<T::AccountId, T::Hash> {
+			let members = Members::<T, I>::iter().map(|(who, r)| (who, Some(r.rank))).collect();
+			MembershipSnapshot::new::<T::Hashing>(members, None)
+		}
+	}
 }
@@ -23,7 +23,9 @@ use frame_support::{
 	assert_noop, assert_ok, derive_impl,
 	error::BadOrigin,
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64, EitherOf, Everything, MapSuccess, Polling},
+	traits::{
+		ConstU16, ConstU32, ConstU64, EitherOf, Everything, MapSuccess, Polling, SnapshotMembers,
+	},
 };
 use sp_core::{Get, H256};
 use sp_runtime::{
@@ -172,6 +174,7 @@ impl<Delta: Get<Rank>> Convert<Class, Rank> for MinRankOfClass<Delta> {
 
 parameter_types! {
 	pub static MinRankOfClassDelta: Rank = 0;
+	pub static InactivityPeriod: Option<u64> = Some(10);
 }
 
 impl Config for Test {
@@ -192,6 +195,8 @@ impl Config for Test {
 	type Polls = TestPolls;
 	type MinRankOfClass = MinRankOfClass<MinRankOfClassDelta>;
 	type VoteWeight = Geometric;
+	type InactivityPeriod = InactivityPeriod;
+	type ExemptOrigin = frame_system::EnsureRoot<Self::AccountId>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -253,6 +258,21 @@ fn member_lifecycle_works() {
 	});
 }
 
+#[test]
+fn membership_snapshot_includes_ranks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(3, 2));
+		assert_ok!(Club::do_add_member_to_rank(1, 0));
+		let snapshot = Club::membership_snapshot();
+		assert_eq!(snapshot.members, vec![(1, Some(0)), (3, Some(2))]);
+		assert_eq!(snapshot.prime, None);
+
+		let proof = snapshot.prove::<BlakeTwo256>(&3).unwrap();
+		assert_eq!(proof.leaf.rank, Some(2));
+		assert!(proof.verify::<BlakeTwo256>(&snapshot.root));
+	});
+}
+
 #[test]
 fn add_remove_works() {
 	new_test_ext().execute_with(|| {
@@ -567,3 +587,93 @@ fn tally_support_correct() {
 		MinRankOfClassDelta::set(0);
 	});
 }
+
+#[test]
+fn inactive_members_decay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_noop!(
+			Club::demote_inactive(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotInactive
+		);
+
+		// Voting counts as activity.
+		System::set_block_number(5);
+		assert_ok!(Club::vote(RuntimeOrigin::signed(1), 3, true));
+		System::set_block_number(14);
+		assert_noop!(
+			Club::demote_inactive(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotInactive
+		);
+
+		System::set_block_number(15);
+		assert_ok!(Club::demote_inactive(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Club::rank_of(&1), Some(1));
+		System::assert_has_event(
+			Event::<Test>::InactiveMemberDemoted { who: 1, last_active: 5 }.into(),
+		);
+
+		// A member decays one rank per period.
+		assert_noop!(
+			Club::demote_inactive(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotInactive
+		);
+		System::set_block_number(25);
+		assert_ok!(Club::demote_inactive(RuntimeOrigin::signed(2), 1));
+		System::set_block_number(35);
+		assert_ok!(Club::demote_inactive(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Club::rank_of(&1), None);
+		assert!(LastActive::<Test>::get(1).is_none());
+		System::assert_last_event(Event::<Test>::MemberRemoved { who: 1, rank: 0 }.into());
+	});
+}
+
+#[test]
+fn exempted_members_do_not_decay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 2));
+		assert_noop!(Club::set_exempt(RuntimeOrigin::signed(1), 1, true), BadOrigin);
+		assert_noop!(
+			Club::set_exempt(RuntimeOrigin::root(), 2, true),
+			Error::<Test>::NotMember
+		);
+		assert_ok!(Club::set_exempt(RuntimeOrigin::root(), 1, true));
+		System::assert_last_event(Event::<Test>::ExemptionChanged { who: 1, exempt: true }.into());
+
+		System::set_block_number(20);
+		assert_noop!(Club::demote_inactive(RuntimeOrigin::signed(2), 1), Error::<Test>::Exempted);
+
+		assert_ok!(Club::set_exempt(RuntimeOrigin::root(), 1, false));
+		assert_ok!(Club::demote_inactive(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Club::rank_of(&1), Some(1));
+
+		// The exemption is cleared with the membership.
+		assert_ok!(Club::set_exempt(RuntimeOrigin::root(), 1, true));
+		assert_ok!(Club::remove_member(RuntimeOrigin::root(), 1, 1));
+		assert!(!Exempt::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn decay_of_untracked_and_disabled_members() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Club::do_add_member_to_rank(1, 1));
+		// Members without any recorded activity start their period when first checked.
+		LastActive::<Test>::remove(1);
+		System::set_block_number(20);
+		assert_ok!(Club::demote_inactive(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Club::rank_of(&1), Some(1));
+		assert_eq!(LastActive::<Test>::get(1), Some(20));
+
+		InactivityPeriod::set(None);
+		System::set_block_number(40);
+		assert_noop!(
+			Club::demote_inactive(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::InactivityDisabled
+		);
+		assert_noop!(
+			Club::demote_inactive(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::NotMember
+		);
+	});
+}
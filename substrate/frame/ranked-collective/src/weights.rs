@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since inactive members can be demoted. `set_exempt`, `demote_inactive` and
+//! the extra `LastActive` write of `vote` are PLACEHOLDERS estimated by hand. Re-run the benchmarks
+//! to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -58,6 +62,8 @@ pub trait WeightInfo {
 	fn demote_member(r: u32, ) -> Weight;
 	fn vote() -> Weight;
 	fn cleanup_poll(n: u32, ) -> Weight;
+	fn set_exempt() -> Weight;
+	fn demote_inactive() -> Weight;
 }
 
 /// Weights for pallet_ranked_collective using the Substrate node and recommended hardware.
@@ -152,13 +158,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
 	fn vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `628`
-		//  Estimated: `219984`
-		// Minimum execution time: 45_474_000 picoseconds.
+		// PLACEHOLDER: not benchmarked, estimated by hand.
 		Weight::from_parts(47_228_000, 219984)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
 	/// Proof: RankedPolls ReferendumInfoFor (max_values: None, max_size: Some(330), added: 2805, mode: MaxEncodedLen)
@@ -180,6 +183,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2540).saturating_mul(n.into()))
 	}
+	/// Storage: RankedCollective Members (r:1 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: RankedCollective Exempt (r:0 w:1)
+	/// Proof: RankedCollective Exempt (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_exempt() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(13_681_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: RankedCollective Members (r:1 w:1)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: RankedCollective Exempt (r:1 w:0)
+	/// Proof: RankedCollective Exempt (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: RankedCollective LastActive (r:1 w:1)
+	/// Proof: RankedCollective LastActive (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: RankedCollective MemberCount (r:1 w:1)
+	/// Proof: RankedCollective MemberCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IdToIndex (r:2 w:2)
+	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IndexToId (r:1 w:2)
+	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn demote_inactive() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(36_104_000, 8548)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -273,13 +304,10 @@ impl WeightInfo for () {
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
 	fn vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `628`
-		//  Estimated: `219984`
-		// Minimum execution time: 45_474_000 picoseconds.
+		// PLACEHOLDER: not benchmarked, estimated by hand.
 		Weight::from_parts(47_228_000, 219984)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: RankedPolls ReferendumInfoFor (r:1 w:0)
 	/// Proof: RankedPolls ReferendumInfoFor (max_values: None, max_size: Some(330), added: 2805, mode: MaxEncodedLen)
@@ -301,4 +329,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2540).saturating_mul(n.into()))
 	}
+	/// Storage: RankedCollective Members (r:1 w:0)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: RankedCollective Exempt (r:0 w:1)
+	/// Proof: RankedCollective Exempt (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_exempt() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(13_681_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: RankedCollective Members (r:1 w:1)
+	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: RankedCollective Exempt (r:1 w:0)
+	/// Proof: RankedCollective Exempt (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: RankedCollective LastActive (r:1 w:1)
+	/// Proof: RankedCollective LastActive (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: RankedCollective MemberCount (r:1 w:1)
+	/// Proof: RankedCollective MemberCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IdToIndex (r:2 w:2)
+	/// Proof: RankedCollective IdToIndex (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	/// Storage: RankedCollective IndexToId (r:1 w:2)
+	/// Proof: RankedCollective IndexToId (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn demote_inactive() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated by hand.
+		Weight::from_parts(36_104_000, 8548)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
 }
@@ -150,6 +150,8 @@ impl pallet_proxy::Config for Test {
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type RecoveryOrigin = frame_system::EnsureRoot<u64>;
+	type RecoveryDelay = ConstU64<10>;
 }
 
 /// The calls that can always bypass safe-mode.
@@ -107,7 +107,7 @@ mod benchmarks {
 		System::<T>::set_block_number(System::<T>::block_number() + T::RegistrationPeriod::get());
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
-		T::Paymaster::ensure_successful(&caller, (), salary);
+		T::Paymaster::ensure_successful(&caller, Salary::<T, I>::payout_asset(), salary);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
@@ -134,7 +134,7 @@ mod benchmarks {
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		T::Paymaster::ensure_successful(&recipient, (), salary);
+		T::Paymaster::ensure_successful(&recipient, Salary::<T, I>::payout_asset(), salary);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), recipient.clone());
@@ -161,7 +161,7 @@ mod benchmarks {
 
 		let salary = T::Salary::get_salary(T::Members::rank_of(&caller).unwrap(), &caller);
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		T::Paymaster::ensure_successful(&recipient, (), salary);
+		T::Paymaster::ensure_successful(&recipient, Salary::<T, I>::payout_asset(), salary);
 		Salary::<T, I>::payout(RawOrigin::Signed(caller.clone()).into()).unwrap();
 		let id = match Claimant::<T, I>::get(&caller).unwrap().status {
 			Attempted { id, .. } => id,
@@ -175,6 +175,36 @@ mod benchmarks {
 		assert!(!matches!(Claimant::<T, I>::get(&caller).unwrap().status, Attempted { .. }));
 	}
 
+	#[benchmark]
+	fn set_payout_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PayoutAssetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset_kind = T::DefaultAssetKind::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(asset_kind.clone()));
+
+		assert_eq!(NextPayoutAsset::<T, I>::get(), Some(asset_kind));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn extend_claim_deadline() {
+		let caller = whitelisted_caller();
+		ensure_member_with_salary::<T, I>(&caller);
+		Salary::<T, I>::init(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		Salary::<T, I>::induct(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		System::<T>::set_block_number(System::<T>::block_number() + Salary::<T, I>::cycle_period());
+		Salary::<T, I>::bump(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		Salary::<T, I>::register(RawOrigin::Signed(caller.clone()).into()).unwrap();
+		System::<T>::set_block_number(System::<T>::block_number() + T::RegistrationPeriod::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Extensions::<T, I>::get(&caller).unwrap().cycle_index, 1u32.into());
+	}
+
 	impl_benchmark_test_suite! {
 		Salary,
 		crate::tests::new_test_ext(),
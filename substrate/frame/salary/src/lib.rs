@@ -22,7 +22,7 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::{Saturating, Zero};
+use sp_arithmetic::traits::{One, Saturating, Zero};
 use sp_runtime::{Perbill, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -85,6 +85,17 @@ pub struct ClaimantStatus<CycleIndex, Balance, Id> {
 	status: ClaimState<Balance, Id>,
 }
 
+/// A registered claim whose deadline was extended into the following cycle.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ClaimExtension<CycleIndex, Balance, AssetKind> {
+	/// The cycle for which the claim was registered.
+	cycle_index: CycleIndex,
+	/// The amount to be paid for the claim, i.e. the registered amount reduced pro-rata.
+	amount: Balance,
+	/// The kind of asset in which the claim is paid, i.e. the payout asset of its cycle.
+	asset_kind: AssetKind,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -103,9 +114,24 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The kind of asset in which payouts are made. Depending on the `Paymaster`, this may
+		/// identify the chain on which the payout is made, as well as the asset itself.
+		type AssetKind: Parameter + MaxEncodedLen;
+
 		/// Means by which we can make payments to accounts. This also defines the currency and the
 		/// balance which we use to denote that currency.
-		type Paymaster: Pay<Beneficiary = <Self as frame_system::Config>::AccountId, AssetKind = ()>;
+		type Paymaster: Pay<
+			Beneficiary = <Self as frame_system::Config>::AccountId,
+			AssetKind = Self::AssetKind,
+		>;
+
+		/// The kind of asset in which payouts are made until another one is set with
+		/// `set_payout_asset`.
+		type DefaultAssetKind: Get<Self::AssetKind>;
+
+		/// The origin which may set the kind of asset in which the payouts of the following cycles
+		/// are made.
+		type PayoutAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// The current membership of payees.
 		type Members: RankedMembers<AccountId = <Self as frame_system::Config>::AccountId>;
@@ -134,6 +160,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The number of blocks into the following cycle within which a member, who extended the
+		/// deadline of its registered claim, may still claim the payout.
+		///
+		/// Each member may extend the deadline of its claim once per cycle.
+		#[pallet::constant]
+		type ExtensionPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The total budget per cycle.
 		///
 		/// This may change over the course of a cycle without any problem.
@@ -146,6 +179,8 @@ pub mod pallet {
 	pub type IdOf<T, I> = <<T as Config<I>>::Paymaster as Pay>::Id;
 	pub type StatusOf<T, I> = StatusType<CycleIndexOf<T>, BlockNumberFor<T>, BalanceOf<T, I>>;
 	pub type ClaimantStatusOf<T, I> = ClaimantStatus<CycleIndexOf<T>, BalanceOf<T, I>, IdOf<T, I>>;
+	pub type ClaimExtensionOf<T, I> =
+		ClaimExtension<CycleIndexOf<T>, BalanceOf<T, I>, <T as Config<I>>::AssetKind>;
 
 	/// The overall status of the system.
 	#[pallet::storage]
@@ -157,6 +192,26 @@ pub mod pallet {
 	pub(super) type Claimant<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimantStatusOf<T, I>, OptionQuery>;
 
+	/// The latest claim extension of a claimant.
+	#[pallet::storage]
+	pub(super) type Extensions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, ClaimExtensionOf<T, I>, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultPayoutAsset<T: Config<I>, I: 'static>() -> T::AssetKind {
+		T::DefaultAssetKind::get()
+	}
+
+	/// The kind of asset in which the payouts of the current cycle are made.
+	#[pallet::storage]
+	pub(super) type PayoutAsset<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::AssetKind, ValueQuery, DefaultPayoutAsset<T, I>>;
+
+	/// The kind of asset in which the payouts are to be made from the next cycle onwards.
+	#[pallet::storage]
+	pub(super) type NextPayoutAsset<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::AssetKind, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -173,6 +228,10 @@ pub mod pallet {
 		},
 		/// The next cycle begins.
 		CycleStarted { index: CycleIndexOf<T> },
+		/// The kind of asset in which the payouts are made from the next cycle onwards was set.
+		PayoutAssetSet { asset_kind: T::AssetKind },
+		/// A member extended the deadline of its registered claim into the following cycle.
+		ClaimExtended { who: T::AccountId, cycle_index: CycleIndexOf<T>, amount: BalanceOf<T, I> },
 	}
 
 	#[pallet::error]
@@ -205,6 +264,11 @@ pub mod pallet {
 		Inconclusive,
 		/// The cycle is after that in which the payment was made.
 		NotCurrent,
+		/// The member already extended the deadline of its claim in this cycle.
+		AlreadyExtended,
+		/// The member must first claim the payout of the previous cycle, whose deadline it
+		/// extended.
+		ExtendedClaimPending,
 	}
 
 	#[pallet::call]
@@ -226,6 +290,7 @@ pub mod pallet {
 				total_unregistered_paid: Zero::zero(),
 			};
 			Status::<T, I>::put(&status);
+			Self::apply_next_payout_asset();
 
 			Self::deposit_event(Event::<T, I>::CycleStarted { index: status.cycle_index });
 			Ok(Pays::No.into())
@@ -248,6 +313,7 @@ pub mod pallet {
 			status.total_registrations = Zero::zero();
 			status.total_unregistered_paid = Zero::zero();
 			Status::<T, I>::put(&status);
+			Self::apply_next_payout_asset();
 
 			Self::deposit_event(Event::<T, I>::CycleStarted { index: status.cycle_index });
 			Ok(Pays::No.into())
@@ -290,6 +356,10 @@ pub mod pallet {
 				Error::<T, I>::TooLate
 			);
 			ensure!(claimant.last_active < status.cycle_index, Error::<T, I>::NoClaim);
+			ensure!(
+				Self::pending_extension(&who, &claimant, &status, now).is_none(),
+				Error::<T, I>::ExtendedClaimPending
+			);
 			let payout = T::Salary::get_salary(rank, &who);
 			ensure!(!payout.is_zero(), Error::<T, I>::ClaimZero);
 			claimant.last_active = status.cycle_index;
@@ -349,7 +419,15 @@ pub mod pallet {
 
 			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
 			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
-			ensure!(claimant.last_active == status.cycle_index, Error::<T, I>::NotCurrent);
+			// A payment of an extended claim is made in the cycle following that of the claim.
+			let extended = Extensions::<T, I>::get(&who).map_or(false, |extension| {
+				extension.cycle_index == claimant.last_active &&
+					extension.cycle_index.saturating_add(One::one()) == status.cycle_index
+			});
+			ensure!(
+				claimant.last_active == status.cycle_index || extended,
+				Error::<T, I>::NotCurrent
+			);
 			let (id, registered, amount) = match claimant.status {
 				Attempted { id, registered, amount } => (id, registered, amount),
 				_ => return Err(Error::<T, I>::NoClaim.into()),
@@ -378,6 +456,71 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Set the kind of asset in which the payouts are made from the next cycle onwards.
+		///
+		/// The payouts of the current cycle are still made in the current kind of asset.
+		///
+		/// - `origin`: Must be `PayoutAssetOrigin`.
+		/// - `asset_kind`: The kind of asset in which the payouts are to be made.
+		#[pallet::weight(T::WeightInfo::set_payout_asset())]
+		#[pallet::call_index(7)]
+		pub fn set_payout_asset(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+		) -> DispatchResult {
+			T::PayoutAssetOrigin::ensure_origin(origin)?;
+			NextPayoutAsset::<T, I>::put(&*asset_kind);
+
+			Self::deposit_event(Event::<T, I>::PayoutAssetSet { asset_kind: *asset_kind });
+			Ok(())
+		}
+
+		/// Extend the deadline of one's registered claim of the current cycle, such that it may
+		/// still be paid out within the first `ExtensionPeriod` blocks of the following cycle.
+		///
+		/// Will only work if we are after the first `RegistrationPeriod` blocks since the cycle
+		/// started and if the deadline was not yet extended in this cycle. The amount to be paid
+		/// and the asset to pay it in are fixed at this point.
+		///
+		/// - `origin`: A `Signed` origin of an account which registered for the current cycle.
+		#[pallet::weight(T::WeightInfo::extend_claim_deadline())]
+		#[pallet::call_index(8)]
+		pub fn extend_claim_deadline(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
+			let claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= status.cycle_start + T::RegistrationPeriod::get(),
+				Error::<T, I>::TooEarly
+			);
+			let unpaid = match claimant.status {
+				Registered(unpaid) if claimant.last_active == status.cycle_index => unpaid,
+				_ => return Err(Error::<T, I>::NoClaim.into()),
+			};
+			ensure!(
+				Extensions::<T, I>::get(&who)
+					.map_or(true, |extension| extension.cycle_index != status.cycle_index),
+				Error::<T, I>::AlreadyExtended
+			);
+			let amount = Self::registered_payout(&status, unpaid);
+			Extensions::<T, I>::insert(
+				&who,
+				ClaimExtension {
+					cycle_index: status.cycle_index,
+					amount,
+					asset_kind: Self::payout_asset(),
+				},
+			);
+
+			Self::deposit_event(Event::<T, I>::ClaimExtended {
+				who,
+				cycle_index: status.cycle_index,
+				amount,
+			});
+			Ok(Pays::No.into())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -390,30 +533,63 @@ pub mod pallet {
 		pub fn cycle_period() -> BlockNumberFor<T> {
 			T::RegistrationPeriod::get() + T::PayoutPeriod::get()
 		}
+		/// The kind of asset in which the payouts of the current cycle are made.
+		pub fn payout_asset() -> T::AssetKind {
+			PayoutAsset::<T, I>::get()
+		}
+		fn apply_next_payout_asset() {
+			if let Some(asset_kind) = NextPayoutAsset::<T, I>::take() {
+				PayoutAsset::<T, I>::put(asset_kind);
+			}
+		}
+		/// The payout of a claim registered for `unpaid`, reduced pro-rata if the registrations of
+		/// the cycle exceed its budget.
+		fn registered_payout(status: &StatusOf<T, I>, unpaid: BalanceOf<T, I>) -> BalanceOf<T, I> {
+			if status.total_registrations <= status.budget {
+				// Can pay in full.
+				unpaid
+			} else {
+				// Must be reduced pro-rata
+				Perbill::from_rational(status.budget, status.total_registrations).mul_floor(unpaid)
+			}
+		}
+		/// The extension of the claim of the previous cycle which `who` may still claim at `now`.
+		fn pending_extension(
+			who: &T::AccountId,
+			claimant: &ClaimantStatusOf<T, I>,
+			status: &StatusOf<T, I>,
+			now: BlockNumberFor<T>,
+		) -> Option<ClaimExtensionOf<T, I>> {
+			if !matches!(claimant.status, Registered(_)) {
+				return None
+			}
+			Extensions::<T, I>::get(who).filter(|extension| {
+				extension.cycle_index == claimant.last_active &&
+					extension.cycle_index.saturating_add(One::one()) == status.cycle_index &&
+					now < status.cycle_start.saturating_add(T::ExtensionPeriod::get())
+			})
+		}
 		fn do_payout(who: T::AccountId, beneficiary: T::AccountId) -> DispatchResult {
 			let mut status = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?;
 			let mut claimant = Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?;
 
 			let now = frame_system::Pallet::<T>::block_number();
+			let extension = Self::pending_extension(&who, &claimant, &status, now);
 			ensure!(
-				now >= status.cycle_start + T::RegistrationPeriod::get(),
+				extension.is_some() || now >= status.cycle_start + T::RegistrationPeriod::get(),
 				Error::<T, I>::TooEarly,
 			);
 
-			let (payout, registered) = match claimant.status {
-				Registered(unpaid) if claimant.last_active == status.cycle_index => {
+			let (payout, registered) = match (&claimant.status, &extension) {
+				(Registered(unpaid), Some(extension)) => {
+					// Registered for the previous cycle and extended. Pay what was fixed then.
+					(extension.amount, Some(*unpaid))
+				},
+				(Registered(unpaid), None) if claimant.last_active == status.cycle_index => {
 					// Registered for this cycle. Pay accordingly.
-					let payout = if status.total_registrations <= status.budget {
-						// Can pay in full.
-						unpaid
-					} else {
-						// Must be reduced pro-rata
-						Perbill::from_rational(status.budget, status.total_registrations)
-							.mul_floor(unpaid)
-					};
-					(payout, Some(unpaid))
+					(Self::registered_payout(&status, *unpaid), Some(*unpaid))
 				},
-				Nothing | Attempted { .. } if claimant.last_active < status.cycle_index => {
+				(Nothing | Attempted { .. }, _) if claimant.last_active < status.cycle_index => {
 					// Not registered for this cycle. Pay from whatever is left.
 					let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
 					let ideal_payout = T::Salary::get_salary(rank, &who);
@@ -432,10 +608,18 @@ pub mod pallet {
 				_ => return Err(Error::<T, I>::NoClaim.into()),
 			};
 
-			claimant.last_active = status.cycle_index;
+			if extension.is_none() {
+				// The claim of an extension stays with its cycle, leaving the claimant free to
+				// claim in the current cycle as well.
+				claimant.last_active = status.cycle_index;
+			}
 
-			let id =
-				T::Paymaster::pay(&beneficiary, (), payout).map_err(|_| Error::<T, I>::PayError)?;
+			let asset_kind = match extension {
+				Some(extension) => extension.asset_kind,
+				None => Self::payout_asset(),
+			};
+			let id = T::Paymaster::pay(&beneficiary, asset_kind, payout)
+				.map_err(|_| Error::<T, I>::PayError)?;
 
 			claimant.status = Attempted { registered, id, amount: payout };
 
@@ -25,10 +25,11 @@ use frame_support::{
 	parameter_types,
 	traits::{tokens::ConvertRank, ConstU32, ConstU64, Everything},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, Identity, IdentityLookup},
-	BuildStorage, DispatchResult,
+	BuildStorage, DispatchError, DispatchResult,
 };
 use sp_std::cell::RefCell;
 
@@ -79,6 +80,7 @@ impl frame_system::Config for Test {
 
 thread_local! {
 	pub static PAID: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+	pub static PAID_IN: RefCell<BTreeMap<(u64, u32), u64>> = RefCell::new(BTreeMap::new());
 	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
 	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
 }
@@ -86,6 +88,9 @@ thread_local! {
 fn paid(who: u64) -> u64 {
 	PAID.with(|p| p.borrow().get(&who).cloned().unwrap_or(0))
 }
+fn paid_in(who: u64, asset_kind: u32) -> u64 {
+	PAID_IN.with(|p| p.borrow().get(&(who, asset_kind)).cloned().unwrap_or(0))
+}
 fn unpay(who: u64, amount: u64) {
 	PAID.with(|p| p.borrow_mut().entry(who).or_default().saturating_reduce(amount))
 }
@@ -98,15 +103,16 @@ impl Pay for TestPay {
 	type Beneficiary = u64;
 	type Balance = u64;
 	type Id = u64;
-	type AssetKind = ();
+	type AssetKind = u32;
 	type Error = ();
 
 	fn pay(
 		who: &Self::Beneficiary,
-		_: Self::AssetKind,
+		asset_kind: Self::AssetKind,
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
 		PAID.with(|paid| *paid.borrow_mut().entry(*who).or_default() += amount);
+		PAID_IN.with(|paid| *paid.borrow_mut().entry((*who, asset_kind)).or_default() += amount);
 		Ok(LAST_ID.with(|lid| {
 			let x = *lid.borrow();
 			lid.replace(x + 1);
@@ -174,11 +180,15 @@ parameter_types! {
 impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
+	type AssetKind = u32;
 	type Paymaster = TestPay;
+	type DefaultAssetKind = ConstU32<0>;
+	type PayoutAssetOrigin = EnsureRoot<u64>;
 	type Members = TestClub;
 	type Salary = ConvertRank<Identity>;
 	type RegistrationPeriod = ConstU64<2>;
 	type PayoutPeriod = ConstU64<2>;
+	type ExtensionPeriod = ConstU64<1>;
 	type Budget = Budget;
 }
 
@@ -641,3 +651,180 @@ fn other_mixed_bankrupcy_fails_gracefully() {
 		assert_eq!(paid(3), 6);
 	});
 }
+
+#[test]
+fn payout_asset_changes_from_next_cycle() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 1);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		assert_eq!(Salary::payout_asset(), 0);
+
+		assert_noop!(
+			Salary::set_payout_asset(RuntimeOrigin::signed(1), Box::new(1)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Salary::set_payout_asset(RuntimeOrigin::root(), Box::new(1)));
+		// The current cycle is still paid in the previous asset.
+		assert_eq!(Salary::payout_asset(), 0);
+
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_eq!(Salary::payout_asset(), 1);
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid_in(1, 1), 1);
+		assert_eq!(paid_in(1, 0), 0);
+
+		// The asset stays the same until it is set again.
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_eq!(Salary::payout_asset(), 1);
+	});
+}
+
+#[test]
+fn claim_deadline_can_be_extended_once_per_cycle() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 2);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::extend_claim_deadline(RuntimeOrigin::signed(2)),
+			Error::<Test>::NotInducted
+		);
+
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::extend_claim_deadline(RuntimeOrigin::signed(1)),
+			Error::<Test>::TooEarly
+		);
+
+		run_to(7);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::extend_claim_deadline(RuntimeOrigin::signed(1)),
+			Error::<Test>::AlreadyExtended
+		);
+
+		// The claim survives the end of its cycle.
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			Salary::register(RuntimeOrigin::signed(1)),
+			Error::<Test>::ExtendedClaimPending
+		);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 2);
+		assert_noop!(Salary::payout(RuntimeOrigin::signed(1)), Error::<Test>::TooEarly);
+
+		// The member is still free to claim in the current cycle.
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(11);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 4);
+		assert_noop!(Salary::payout(RuntimeOrigin::signed(1)), Error::<Test>::NoClaim);
+	});
+}
+
+#[test]
+fn extended_claim_is_paid_in_the_asset_of_its_cycle() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 2);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::set_payout_asset(RuntimeOrigin::root(), Box::new(1)));
+
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_eq!(Salary::payout_asset(), 1);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid_in(1, 0), 2);
+		assert_eq!(paid_in(1, 1), 0);
+	});
+}
+
+#[test]
+fn extended_claim_expires() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 2);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		run_to(10);
+		assert_noop!(Salary::payout(RuntimeOrigin::signed(1)), Error::<Test>::TooEarly);
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(11);
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 2);
+	});
+}
+
+#[test]
+fn extended_claim_payment_can_be_retried() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 2);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		run_to(7);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+
+		// Payment failed.
+		unpay(1, 2);
+		set_status(0, PaymentStatus::Failure);
+
+		assert_noop!(Salary::payout(RuntimeOrigin::signed(1)), Error::<Test>::TooEarly);
+		assert_ok!(Salary::check_payment(RuntimeOrigin::signed(1)));
+		// Allowed to try again within the extension period.
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 2);
+	});
+}
+
+#[test]
+fn extended_claim_keeps_pro_rata_amount() {
+	new_test_ext().execute_with(|| {
+		set_rank(1, 6);
+		set_rank(2, 12);
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::induct(RuntimeOrigin::signed(2)));
+		run_to(5);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(2)));
+		run_to(7);
+		assert_ok!(Salary::extend_claim_deadline(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(2)));
+		assert_eq!(paid(2), 6);
+
+		// Registrations of the following cycle do not affect the extended claim.
+		run_to(9);
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::register(RuntimeOrigin::signed(2)));
+		assert_ok!(Salary::payout(RuntimeOrigin::signed(1)));
+		assert_eq!(paid(1), 3);
+	});
+}
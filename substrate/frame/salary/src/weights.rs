@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since salaries may be paid in another asset and claim deadlines may be
+//! extended. `set_payout_asset` and `extend_claim_deadline` are PLACEHOLDERS estimated by hand from
+//! their storage accesses. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -59,6 +63,8 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn payout_other() -> Weight;
 	fn check_payment() -> Weight;
+	fn set_payout_asset() -> Weight;
+	fn extend_claim_deadline() -> Weight;
 }
 
 /// Weights for pallet_salary using the Substrate node and recommended hardware.
@@ -66,25 +72,33 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary NextPayoutAsset (r:1 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn init() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
 		//  Estimated: `1541`
 		// Minimum execution time: 10_778_000 picoseconds.
 		Weight::from_parts(11_084_000, 1541)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary NextPayoutAsset (r:1 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn bump() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `86`
 		//  Estimated: `1541`
 		// Minimum execution time: 12_042_000 picoseconds.
 		Weight::from_parts(12_645_000, 1541)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:0)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
@@ -107,13 +121,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
 	/// Storage: Salary Claimant (r:1 w:1)
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3543`
 		// Minimum execution time: 22_696_000 picoseconds.
 		Weight::from_parts(23_275_000, 3543)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -122,13 +138,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn payout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3543`
 		// Minimum execution time: 63_660_000 picoseconds.
 		Weight::from_parts(65_006_000, 3543)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -139,53 +159,86 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn payout_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3593`
 		// Minimum execution time: 64_706_000 picoseconds.
 		Weight::from_parts(65_763_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
 	/// Storage: Salary Claimant (r:1 w:1)
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn check_payment() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
 		//  Estimated: `3543`
 		// Minimum execution time: 11_838_000 picoseconds.
 		Weight::from_parts(12_323_000, 3543)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Salary NextPayoutAsset (r:0 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	fn set_payout_asset() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(8_233_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Salary Status (r:1 w:0)
+	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary Claimant (r:1 w:0)
+	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:1)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn extend_claim_deadline() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(21_314_000, 3543)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary NextPayoutAsset (r:1 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn init() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
 		//  Estimated: `1541`
 		// Minimum execution time: 10_778_000 picoseconds.
 		Weight::from_parts(11_084_000, 1541)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary NextPayoutAsset (r:1 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:0 w:1)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn bump() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `86`
 		//  Estimated: `1541`
 		// Minimum execution time: 12_042_000 picoseconds.
 		Weight::from_parts(12_645_000, 1541)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:0)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
@@ -208,13 +261,15 @@ impl WeightInfo for () {
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
 	/// Storage: Salary Claimant (r:1 w:1)
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn register() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3543`
 		// Minimum execution time: 22_696_000 picoseconds.
 		Weight::from_parts(23_275_000, 3543)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -223,13 +278,17 @@ impl WeightInfo for () {
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
 	/// Storage: RankedCollective Members (r:1 w:0)
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn payout() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3543`
 		// Minimum execution time: 63_660_000 picoseconds.
 		Weight::from_parts(65_006_000, 3543)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
@@ -240,26 +299,51 @@ impl WeightInfo for () {
 	/// Proof: RankedCollective Members (max_values: None, max_size: Some(42), added: 2517, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Salary PayoutAsset (r:1 w:0)
+	/// Proof: Salary PayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
 	fn payout_other() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `462`
 		//  Estimated: `3593`
 		// Minimum execution time: 64_706_000 picoseconds.
 		Weight::from_parts(65_763_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: Salary Status (r:1 w:1)
 	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
 	/// Storage: Salary Claimant (r:1 w:1)
 	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:0)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn check_payment() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `170`
 		//  Estimated: `3543`
 		// Minimum execution time: 11_838_000 picoseconds.
 		Weight::from_parts(12_323_000, 3543)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Salary NextPayoutAsset (r:0 w:1)
+	/// Proof: Salary NextPayoutAsset (max_values: Some(1), max_size: Some(0), added: 495, mode: MaxEncodedLen)
+	fn set_payout_asset() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(8_233_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Salary Status (r:1 w:0)
+	/// Proof: Salary Status (max_values: Some(1), max_size: Some(56), added: 551, mode: MaxEncodedLen)
+	/// Storage: Salary Claimant (r:1 w:0)
+	/// Proof: Salary Claimant (max_values: None, max_size: Some(78), added: 2553, mode: MaxEncodedLen)
+	/// Storage: Salary Extensions (r:1 w:1)
+	/// Proof: Salary Extensions (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn extend_claim_deadline() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage accesses.
+		Weight::from_parts(21_314_000, 3543)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -142,6 +142,8 @@ impl system::Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxIndexedEvents = ConstU32<0>;
+	type NestedCallLimits = ();
 }
 impl logger::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
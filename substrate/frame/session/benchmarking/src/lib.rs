@@ -23,10 +23,10 @@
 
 mod mock;
 
-use sp_runtime::traits::{One, StaticLookup, TrailingZeroInput};
-use sp_std::{prelude::*, vec};
+use sp_runtime::traits::{One, OpaqueKeys, StaticLookup, TrailingZeroInput};
+use sp_std::prelude::*;
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use frame_benchmarking::v1::benchmarks;
 use frame_support::traits::{Get, KeyOwnerProofSystem, OnInitialize};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
@@ -62,8 +62,7 @@ benchmarks! {
 		)?;
 		let v_controller = pallet_staking::Pallet::<T>::bonded(&v_stash).ok_or("not stash")?;
 
-		let keys = T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = generate_keys::<T>(&v_controller);
 		// Whitelist controller account from further DB operations.
 		let v_controller_key = frame_system::Account::<T>::hashed_key_for(&v_controller);
 		frame_benchmarking::benchmarking::add_to_whitelist(v_controller_key.into());
@@ -79,8 +78,7 @@ benchmarks! {
 			RewardDestination::Staked,
 		)?;
 		let v_controller = pallet_staking::Pallet::<T>::bonded(&v_stash).ok_or("not stash")?;
-		let keys = T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
-		let proof: Vec<u8> = vec![0,1,2,3];
+		let (keys, proof) = generate_keys::<T>(&v_controller);
 		Session::<T>::set_keys(RawOrigin::Signed(v_controller.clone()).into(), keys, proof)?;
 		// Whitelist controller account from further DB operations.
 		let v_controller_key = frame_system::Account::<T>::hashed_key_for(&v_controller);
@@ -94,7 +92,7 @@ benchmarks! {
 		let (key, key_owner_proof1) = check_membership_proof_setup::<T>(n);
 		let key_owner_proof2 = key_owner_proof1.clone();
 	}: {
-		Historical::<T>::check_proof(key, key_owner_proof1);
+		Historical::<T>::check_proof(key.clone(), key_owner_proof1);
 	}
 	verify {
 		assert!(Historical::<T>::check_proof(key, key_owner_proof2).is_some());
@@ -112,7 +110,7 @@ benchmarks! {
 
 		let key_owner_proof2 = key_owner_proof1.clone();
 	}: {
-		Historical::<T>::check_proof(key, key_owner_proof1);
+		Historical::<T>::check_proof(key.clone(), key_owner_proof1);
 	}
 	verify {
 		assert!(Historical::<T>::check_proof(key, key_owner_proof2).is_some());
@@ -121,35 +119,33 @@ benchmarks! {
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test, extra = false);
 }
 
+/// Generates session keys for `owner` together with a proof of their ownership.
+///
+/// Falls back to zeroed keys and an empty proof if `T::Keys` cannot generate proofs.
+fn generate_keys<T: Config>(owner: &T::AccountId) -> (T::Keys, Vec<u8>) {
+	T::Keys::generate_with_ownership_proof(&owner.encode(), None).unwrap_or_else(|| {
+		(T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap(), Vec::new())
+	})
+}
+
 /// Sets up the benchmark for checking a membership proof. It creates the given
-/// number of validators, sets random session keys and then creates a membership
+/// number of validators, sets new session keys and then creates a membership
 /// proof for the first authority and returns its key and the proof.
 fn check_membership_proof_setup<T: Config>(
 	n: u32,
-) -> ((sp_runtime::KeyTypeId, &'static [u8; 32]), sp_session::MembershipProof) {
+) -> ((sp_runtime::KeyTypeId, Vec<u8>), sp_session::MembershipProof) {
 	pallet_staking::ValidatorCount::<T>::put(n);
 
-	// create validators and set random session keys
-	for (n, who) in create_validators::<T>(n, 1000).unwrap().into_iter().enumerate() {
-		use rand::{RngCore, SeedableRng};
+	let key_type = sp_runtime::KeyTypeId(*b"babe");
+	let mut first_key = None;
 
+	// create validators and set new session keys
+	for who in create_validators::<T>(n, 1000).unwrap() {
 		let validator = T::Lookup::lookup(who).unwrap();
 		let controller = pallet_staking::Pallet::<T>::bonded(&validator).unwrap();
 
-		let keys = {
-			let mut keys = [0u8; 128];
-
-			// we keep the keys for the first validator as 0x00000...
-			if n > 0 {
-				let mut rng = rand::rngs::StdRng::seed_from_u64(n as u64);
-				rng.fill_bytes(&mut keys);
-			}
-
-			keys
-		};
-
-		let keys: T::Keys = Decode::decode(&mut &keys[..]).unwrap();
-		let proof: Vec<u8> = vec![];
+		let (keys, proof) = generate_keys::<T>(&controller);
+		first_key.get_or_insert_with(|| keys.get_raw(key_type).to_vec());
 
 		Session::<T>::set_keys(RawOrigin::Signed(controller).into(), keys, proof).unwrap();
 	}
@@ -161,7 +157,7 @@ fn check_membership_proof_setup<T: Config>(
 		Session::<T>::rotate_session();
 	}
 
-	let key = (sp_runtime::KeyTypeId(*b"babe"), &[0u8; 32]);
+	let key = (key_type, first_key.expect("at least one validator was created; qed"));
 
-	(key, Historical::<T>::prove(key).unwrap())
+	(key.clone(), Historical::<T>::prove(key).unwrap())
 }
@@ -115,7 +115,7 @@ mod mock;
 mod tests;
 pub mod weights;
 
-use codec::{Decode, MaxEncodedLen};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -581,6 +581,10 @@ pub mod pallet {
 		///
 		/// The dispatch origin of this function must be signed.
 		///
+		/// `proof` must prove that the caller owns `keys`, i.e. possesses their private keys. It is
+		/// generated together with the keys by the `SessionKeys` runtime API for the SCALE encoded
+		/// account id of the caller.
+		///
 		/// ## Complexity
 		/// - `O(1)`. Actual cost depends on the number of length of `T::Keys::key_ids()` which is
 		///   fixed.
@@ -588,7 +592,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_keys())]
 		pub fn set_keys(origin: OriginFor<T>, keys: T::Keys, proof: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(keys.ownership_proof_is_valid(&proof), Error::<T>::InvalidProof);
+			ensure!(keys.ownership_proof_is_valid(&who.encode(), &proof), Error::<T>::InvalidProof);
 
 			Self::do_set_keys(&who, keys)?;
 			Ok(())
@@ -21,11 +21,11 @@ use super::*;
 use crate::mock::{
 	authorities, before_session_end_called, force_new_session, new_test_ext,
 	reset_before_session_end_called, session_changed, set_next_validators, set_session_length,
-	PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System, Test,
-	TestSessionChanged, TestValidatorIdOf,
+	MockSessionKeys, PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System,
+	Test, TestSessionChanged, TestValidatorIdOf,
 };
 
-use codec::Decode;
+use codec::{Decode, Encode};
 use sp_core::crypto::key_types::DUMMY;
 use sp_runtime::testing::UintAuthorityId;
 
@@ -34,6 +34,11 @@ use frame_support::{
 	traits::{ConstU64, OnInitialize},
 };
 
+fn set_keys(who: u64, keys: MockSessionKeys) -> DispatchResult {
+	let proof = keys.create_ownership_proof(&who.encode()).unwrap();
+	Session::set_keys(RuntimeOrigin::signed(who), keys, proof)
+}
+
 fn initialize_block(block: u64) {
 	SessionChanged::mutate(|l| *l = false);
 	System::set_block_number(block);
@@ -128,7 +133,7 @@ fn authorities_should_track_validators() {
 		reset_before_session_end_called();
 
 		set_next_validators(vec![1, 2, 4]);
-		assert_ok!(Session::set_keys(RuntimeOrigin::signed(4), UintAuthorityId(4).into(), vec![]));
+		assert_ok!(set_keys(4, UintAuthorityId(4).into()));
 		force_new_session();
 		initialize_block(3);
 		assert_eq!(
@@ -194,7 +199,7 @@ fn session_change_should_work() {
 
 		// Block 3: Set new key for validator 2; no visible change.
 		initialize_block(3);
-		assert_ok!(Session::set_keys(RuntimeOrigin::signed(2), UintAuthorityId(5).into(), vec![]));
+		assert_ok!(set_keys(2, UintAuthorityId(5).into()));
 		assert_eq!(authorities(), vec![UintAuthorityId(1), UintAuthorityId(2), UintAuthorityId(3)]);
 
 		// Block 4: Session rollover; no visible change.
@@ -219,13 +224,13 @@ fn duplicates_are_not_allowed() {
 		System::set_block_number(1);
 		Session::on_initialize(1);
 		assert_noop!(
-			Session::set_keys(RuntimeOrigin::signed(4), UintAuthorityId(1).into(), vec![]),
+			set_keys(4, UintAuthorityId(1).into()),
 			Error::<Test>::DuplicatedKey,
 		);
-		assert_ok!(Session::set_keys(RuntimeOrigin::signed(1), UintAuthorityId(10).into(), vec![]));
+		assert_ok!(set_keys(1, UintAuthorityId(10).into()));
 
 		// is fine now that 1 has migrated off.
-		assert_ok!(Session::set_keys(RuntimeOrigin::signed(4), UintAuthorityId(1).into(), vec![]));
+		assert_ok!(set_keys(4, UintAuthorityId(1).into()));
 	});
 }
 
@@ -268,7 +273,7 @@ fn session_changed_flag_works() {
 		assert!(before_session_end_called());
 		reset_before_session_end_called();
 
-		assert_ok!(Session::set_keys(RuntimeOrigin::signed(2), UintAuthorityId(5).into(), vec![]));
+		assert_ok!(set_keys(2, UintAuthorityId(5).into()));
 		force_new_session();
 		initialize_block(6);
 		assert!(!session_changed());
@@ -276,11 +281,7 @@ fn session_changed_flag_works() {
 		reset_before_session_end_called();
 
 		// changing the keys of a validator leads to change.
-		assert_ok!(Session::set_keys(
-			RuntimeOrigin::signed(69),
-			UintAuthorityId(69).into(),
-			vec![]
-		));
+		assert_ok!(set_keys(69, UintAuthorityId(69).into()));
 		force_new_session();
 		initialize_block(7);
 		assert!(session_changed());
@@ -358,11 +359,37 @@ fn periodic_session_works() {
 fn session_keys_generate_output_works_as_set_keys_input() {
 	new_test_ext().execute_with(|| {
 		let new_keys = mock::MockSessionKeys::generate(None);
-		assert_ok!(Session::set_keys(
-			RuntimeOrigin::signed(2),
-			<mock::Test as Config>::Keys::decode(&mut &new_keys[..]).expect("Decode keys"),
-			vec![],
-		));
+		let new_keys =
+			<mock::Test as Config>::Keys::decode(&mut &new_keys[..]).expect("Decode keys");
+		assert_ok!(set_keys(2, new_keys));
+	});
+}
+
+#[test]
+fn set_keys_requires_proof_of_ownership() {
+	new_test_ext().execute_with(|| {
+		let (keys, proof) =
+			MockSessionKeys::generate_with_ownership_proof(&2u64.encode(), None).unwrap();
+
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(2), keys.clone(), vec![]),
+			Error::<Test>::InvalidProof,
+		);
+		// The proof is only valid for the owner it was generated for.
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(3), keys.clone(), proof.clone()),
+			Error::<Test>::InvalidProof,
+		);
+		let other_proof = MockSessionKeys::from(UintAuthorityId(7))
+			.create_ownership_proof(&2u64.encode())
+			.unwrap();
+		assert_noop!(
+			Session::set_keys(RuntimeOrigin::signed(2), keys.clone(), other_proof),
+			Error::<Test>::InvalidProof,
+		);
+
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(2), keys.clone(), proof));
+		assert_eq!(Session::load_keys(&2), Some(keys));
 	});
 }
 
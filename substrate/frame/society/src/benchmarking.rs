@@ -115,6 +115,7 @@ benchmarks_instance_pallet! {
 		let founder = setup_society::<T, I>()?;
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
+		T::CandidateJudgement::ensure_minimum_judgement(&caller);
 	}: _(RawOrigin::Signed(caller.clone()), 10u32.into())
 	verify {
 		let first_bid: Bid<T::AccountId, BalanceOf<T, I>> = Bid {
@@ -143,6 +144,7 @@ benchmarks_instance_pallet! {
 		let vouched: T::AccountId = account("vouched", 0, 0);
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T, I>::max_value());
 		let _ = Society::<T, I>::insert_member(&caller, 1u32.into());
+		T::CandidateJudgement::ensure_minimum_judgement(&vouched);
 		let vouched_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(vouched.clone());
 	}: _(RawOrigin::Signed(caller.clone()), vouched_lookup, 0u32.into(), 0u32.into())
 	verify {
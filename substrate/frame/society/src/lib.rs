@@ -127,6 +127,9 @@
 //! A user can make a bid by reserving a deposit. Alternatively, an already existing member
 //! can create a bid on a user's behalf by "vouching" for them.
 //!
+//! A society may additionally require its bidders to have an identity with some minimum
+//! judgement, as told by the configured `CandidateJudgement` provider.
+//!
 //! A bid includes reward information that the user would like to receive for joining
 //! the society. A vouching bid can additionally request some portion of that reward as a tip
 //! to the voucher for vouching for the prospective candidate.
@@ -461,6 +464,28 @@ pub struct GroupParams<Balance> {
 
 pub type GroupParamsFor<T, I> = GroupParams<BalanceOf<T, I>>;
 
+/// Interface required for vetting the identity of prospective candidates.
+///
+/// The judgements need not be made on the same chain, e.g. they may be bridged from a chain
+/// dedicated to identities.
+pub trait IdentityJudgement<AccountId> {
+	/// Whether an account has an identity with at least the minimum judgement required of
+	/// prospective candidates.
+	fn has_minimum_judgement(who: &AccountId) -> bool;
+
+	/// Give `who` an identity with the minimum judgement, so that it may bid or be vouched for
+	/// in benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_minimum_judgement(_who: &AccountId) {}
+}
+
+/// The non-provider. Imposes no restrictions on the identity of prospective candidates.
+impl<AccountId> IdentityJudgement<AccountId> for () {
+	fn has_minimum_judgement(_who: &AccountId) -> bool {
+		true
+	}
+}
+
 pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 #[frame_support::pallet]
@@ -525,6 +550,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxBids: Get<u32>;
 
+		/// The provider of identity judgements. Users can only bid, or be vouched for, if they
+		/// have at least the minimum judgement it requires. Use `()` to not require any.
+		type CandidateJudgement: IdentityJudgement<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -595,6 +624,8 @@ pub mod pallet {
 		InsufficientFunds,
 		/// The candidate/defender has no stale votes to remove.
 		NoVotes,
+		/// The user does not have the identity judgement required of candidates.
+		InsufficientJudgement,
 	}
 
 	#[pallet::event]
@@ -820,6 +851,10 @@ pub mod pallet {
 			ensure!(!Candidates::<T, I>::contains_key(&who), Error::<T, I>::AlreadyCandidate);
 			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
 			ensure!(!SuspendedMembers::<T, I>::contains_key(&who), Error::<T, I>::Suspended);
+			ensure!(
+				T::CandidateJudgement::has_minimum_judgement(&who),
+				Error::<T, I>::InsufficientJudgement
+			);
 
 			let params = Parameters::<T, I>::get().ok_or(Error::<T, I>::NotGroup)?;
 			let deposit = params.candidate_deposit;
@@ -888,6 +923,11 @@ pub mod pallet {
 			ensure!(!Candidates::<T, I>::contains_key(&who), Error::<T, I>::AlreadyCandidate);
 			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
 			ensure!(!SuspendedMembers::<T, I>::contains_key(&who), Error::<T, I>::Suspended);
+			// Check user has the identity judgement required of candidates.
+			ensure!(
+				T::CandidateJudgement::has_minimum_judgement(&who),
+				Error::<T, I>::InsufficientJudgement
+			);
 
 			// Check sender can vouch.
 			let mut record = Members::<T, I>::get(&voucher).ok_or(Error::<T, I>::NotMember)?;
@@ -47,6 +47,15 @@ frame_support::construct_runtime!(
 
 parameter_types! {
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
+	/// The accounts with a sufficient identity judgement, or `None` if none is required.
+	pub static JudgedAccounts: Option<Vec<u128>> = None;
+}
+
+pub struct TestJudgement;
+impl IdentityJudgement<u128> for TestJudgement {
+	fn has_minimum_judgement(who: &u128) -> bool {
+		JudgedAccounts::get().map_or(true, |judged| judged.contains(who))
+	}
 }
 
 ord_parameter_types! {
@@ -116,6 +125,7 @@ impl Config for Test {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type CandidateJudgement = TestJudgement;
 	type WeightInfo = ();
 }
 
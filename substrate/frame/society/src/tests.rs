@@ -324,6 +324,28 @@ fn unbidding_works() {
 	});
 }
 
+#[test]
+fn bidding_requires_identity_judgement() {
+	EnvBuilder::new().execute(|| {
+		JudgedAccounts::set(Some(vec![20, 40]));
+		// 30 has no sufficient judgement, so can neither bid nor be vouched for.
+		assert_noop!(Society::bid(Origin::signed(30), 0), Error::<Test>::InsufficientJudgement);
+		assert_noop!(
+			Society::vouch(Origin::signed(10), 30, 0, 0),
+			Error::<Test>::InsufficientJudgement
+		);
+		assert_eq!(Balances::reserved_balance(30), 0);
+		assert_eq!(Members::<Test>::get(10).unwrap().vouching, None);
+		// 20 and 40 do.
+		assert_ok!(Society::bid(Origin::signed(20), 0));
+		assert_ok!(Society::vouch(Origin::signed(10), 40, 0, 0));
+		assert_eq!(
+			Bids::<Test>::get().into_inner(),
+			vec![bid(20, Deposit(25), 0), bid(40, Vouch(10, 0), 0)]
+		);
+	});
+}
+
 #[test]
 fn payout_works() {
 	EnvBuilder::new().execute(|| {
@@ -22,6 +22,7 @@
 use codec::Codec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -32,5 +33,11 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the page count of exposures for a validator in a given era.
 		fn eras_stakers_page_count(era: sp_staking::EraIndex, account: AccountId) -> sp_staking::Page;
+
+		/// Returns the forecast of the payout of the active era, as if the era ended now.
+		///
+		/// This is `None` if the active era has not started yet.
+		#[api_version(2)]
+		fn era_payout_forecast() -> Option<sp_staking::EraPayoutForecast<AccountId, Balance>>;
 	}
 }
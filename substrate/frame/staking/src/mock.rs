@@ -197,6 +197,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<5>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -239,6 +240,7 @@ parameter_types! {
 	pub static HistoryDepth: u32 = 80;
 	pub static MaxExposurePageSize: u32 = 64;
 	pub static MaxUnlockingChunks: u32 = 32;
+	pub static NominationExpiry: Option<EraIndex> = None;
 	pub static RewardOnUnbalanceWasCalled: bool = false;
 	pub static MaxWinners: u32 = 100;
 	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
@@ -315,6 +317,7 @@ impl crate::pallet::pallet::Config for Test {
 	type TargetList = UseValidatorsMap<Self>;
 	type NominationsQuota = WeightedNominationsQuota<16>;
 	type MaxUnlockingChunks = MaxUnlockingChunks;
+	type NominationExpiry = NominationExpiry;
 	type HistoryDepth = HistoryDepth;
 	type EventListeners = EventListenerMock;
 	type BenchmarkingConfig = TestBenchmarkingConfig;
@@ -601,11 +604,12 @@ pub(crate) fn bond(who: AccountId, val: Balance) {
 pub(crate) fn bond_validator(who: AccountId, val: Balance) {
 	bond(who, val);
 	assert_ok!(Staking::validate(RuntimeOrigin::signed(who), ValidatorPrefs::default()));
-	assert_ok!(Session::set_keys(
-		RuntimeOrigin::signed(who),
-		SessionKeys { other: who.into() },
-		vec![]
-	));
+	set_session_keys(who, SessionKeys { other: who.into() });
+}
+
+pub(crate) fn set_session_keys(who: AccountId, keys: SessionKeys) {
+	let proof = keys.create_ownership_proof(&who.encode()).unwrap();
+	assert_ok!(Session::set_keys(RuntimeOrigin::signed(who), keys, proof));
 }
 
 pub(crate) fn bond_nominator(who: AccountId, val: Balance, target: Vec<AccountId>) {
@@ -41,9 +41,9 @@ use sp_runtime::{
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
-	EraIndex, Page, SessionIndex, Stake,
+	EraIndex, EraPayoutForecast, Page, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, ValidatorPayoutForecast,
 };
 use sp_std::prelude::*;
 
@@ -836,6 +836,8 @@ impl<T: Config> Pallet<T> {
 		let mut validators_taken = 0u32;
 		let mut nominators_taken = 0u32;
 		let mut min_active_stake = u64::MAX;
+		let current_era = Self::current_era().unwrap_or(0);
+		let nomination_expiry = T::NominationExpiry::get();
 
 		let mut sorted_voters = T::VoterList::iter();
 		while all_voters.len() < final_predicted_len as usize &&
@@ -856,7 +858,23 @@ impl<T: Config> Pallet<T> {
 				continue
 			}
 
-			if let Some(Nominations { targets, .. }) = <Nominators<T>>::get(&voter) {
+			if let Some(Nominations { targets, submitted_in, .. }) = <Nominators<T>>::get(&voter) {
+				if let Some(expiry) = nomination_expiry {
+					let expires_at = submitted_in.saturating_add(expiry);
+					if expires_at <= current_era {
+						// only report the expiry once, at the first election it is missing from.
+						if expires_at == current_era {
+							Self::deposit_event(Event::<T>::NominationExpired { stash: voter });
+						}
+						continue
+					} else if expires_at == current_era.saturating_add(1) {
+						Self::deposit_event(Event::<T>::NominationExpiring {
+							stash: voter.clone(),
+							expires_at,
+						});
+					}
+				}
+
 				if !targets.is_empty() {
 					// Note on lazy nomination quota: we do not check the nomination quota of the
 					// voter at this point and accept all the current nominations. The nomination
@@ -1109,6 +1127,53 @@ impl<T: Config> Pallet<T> {
 	pub fn api_eras_stakers_page_count(era: EraIndex, account: T::AccountId) -> Page {
 		EraInfo::<T>::get_page_count(era, &account)
 	}
+
+	/// Returns the forecast of the payout of the active era, as if the era ended now.
+	///
+	/// The payout is computed with [`Config::EraPayout`] like at the end of the era, and split
+	/// between validators and nominators like [`Call::payout_stakers`] would, given the reward
+	/// points, commissions and exposures of the era so far. Returns `None` if the active era has
+	/// not started yet.
+	///
+	/// Used by the runtime API.
+	pub fn api_era_payout_forecast() -> Option<EraPayoutForecast<T::AccountId, BalanceOf<T>>> {
+		let active_era = Self::active_era()?;
+		let era = active_era.index;
+		let era_duration = T::UnixTime::now()
+			.as_millis()
+			.saturated_into::<u64>()
+			.saturating_sub(active_era.start?);
+		let (validator_payout, remainder) = T::EraPayout::era_payout(
+			Self::eras_total_stake(&era),
+			T::Currency::total_issuance(),
+			era_duration,
+		);
+
+		let era_reward_points = <ErasRewardPoints<T>>::get(&era);
+		let total_reward_points = era_reward_points.total;
+		let validators = era_reward_points
+			.individual
+			.into_iter()
+			.map(|(validator, reward_points)| {
+				let total_payout =
+					Perbill::from_rational(reward_points, total_reward_points) * validator_payout;
+				let commission =
+					EraInfo::<T>::get_validator_commission(era, &validator) * total_payout;
+				let leftover = total_payout.saturating_sub(commission);
+				let exposure = EraInfo::<T>::get_full_exposure(era, &validator);
+				let own = Perbill::from_rational(exposure.own, exposure.total) * leftover;
+				ValidatorPayoutForecast {
+					validator,
+					reward_points,
+					commission,
+					own,
+					nominators: leftover.saturating_sub(own),
+				}
+			})
+			.collect();
+
+		Some(EraPayoutForecast { era, validator_payout, remainder, validators })
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -269,6 +269,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUnlockingChunks: Get<u32>;
 
+		/// The number of eras after which nominations that have not been refreshed expire.
+		///
+		/// Expired nominations are left out of the election snapshot until the nominator calls
+		/// [`Call::nominate`] again. A [`Event::NominationExpiring`] warning is emitted at the
+		/// last election they are still part of. `None` means that nominations never expire.
+		#[pallet::constant]
+		type NominationExpiry: Get<Option<EraIndex>>;
+
 		/// Something that listens to staking updates and performs actions based on the data it
 		/// receives.
 		///
@@ -782,6 +790,11 @@ pub mod pallet {
 		SnapshotTargetsSizeExceeded { size: u32 },
 		/// A new force era mode was set.
 		ForceEra { mode: Forcing },
+		/// The nominations of a staker are part of the election snapshot for the last time, and
+		/// expire at the given era unless refreshed.
+		NominationExpiring { stash: T::AccountId, expires_at: EraIndex },
+		/// The nominations of a staker have expired and are left out of the election snapshot.
+		NominationExpired { stash: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -39,7 +39,7 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	SessionIndex, ValidatorPayoutForecast,
 };
 use sp_std::prelude::*;
 use substrate_test_utils::assert_eq_uvec;
@@ -419,11 +419,7 @@ fn staking_should_work() {
 		// add a new candidate for being a validator. account 3 controlled by 4.
 		assert_ok!(Staking::bond(RuntimeOrigin::signed(3), 1500, RewardDestination::Account(3)));
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(3), ValidatorPrefs::default()));
-		assert_ok!(Session::set_keys(
-			RuntimeOrigin::signed(3),
-			SessionKeys { other: 4.into() },
-			vec![]
-		));
+		set_session_keys(3, SessionKeys { other: 4.into() });
 
 		// No effects will be seen so far.
 		assert_eq_uvec!(validator_controllers(), vec![21, 11]);
@@ -1844,11 +1840,7 @@ fn switching_roles() {
 		// add a new validator candidate
 		assert_ok!(Staking::bond(RuntimeOrigin::signed(5), 1000, RewardDestination::Account(5)));
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(5), ValidatorPrefs::default()));
-		assert_ok!(Session::set_keys(
-			RuntimeOrigin::signed(5),
-			SessionKeys { other: 6.into() },
-			vec![]
-		));
+		set_session_keys(5, SessionKeys { other: 6.into() });
 
 		mock::start_active_era(1);
 
@@ -1857,11 +1849,7 @@ fn switching_roles() {
 
 		// 2 decides to be a validator. Consequences:
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(1), ValidatorPrefs::default()));
-		assert_ok!(Session::set_keys(
-			RuntimeOrigin::signed(1),
-			SessionKeys { other: 2.into() },
-			vec![]
-		));
+		set_session_keys(1, SessionKeys { other: 2.into() });
 		// new stakes:
 		// 11: 1000 self vote
 		// 21: 1000 self vote + 250 vote
@@ -1966,11 +1954,7 @@ fn bond_with_little_staked_value_bounded() {
 			// Stingy validator.
 			assert_ok!(Staking::bond(RuntimeOrigin::signed(1), 1, RewardDestination::Account(1)));
 			assert_ok!(Staking::validate(RuntimeOrigin::signed(1), ValidatorPrefs::default()));
-			assert_ok!(Session::set_keys(
-				RuntimeOrigin::signed(1),
-				SessionKeys { other: 1.into() },
-				vec![]
-			));
+			set_session_keys(1, SessionKeys { other: 1.into() });
 
 			// 1 era worth of reward. BUT, we set the timestamp after on_initialize, so outdated by
 			// one block.
@@ -5380,6 +5364,43 @@ mod election_data_provider {
 			assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
 		})
 	}
+
+	#[test]
+	fn expired_nominations_are_not_voters() {
+		ExtBuilder::default().nominate(true).build_and_execute(|| {
+			NominationExpiry::set(Some(2));
+			// 101 nominated at genesis.
+			assert_eq!(Nominators::<Test>::get(101).unwrap().submitted_in, 0);
+			let is_voter = |who| {
+				<Staking as ElectionDataProvider>::electing_voters(DataProviderBounds::default())
+					.unwrap()
+					.iter()
+					.any(|(voter, _, _)| *voter == who)
+			};
+
+			// the last election the nominations are part of warns about their expiry.
+			CurrentEra::<Test>::put(1);
+			assert!(is_voter(101));
+			assert_eq!(
+				*staking_events().last().unwrap(),
+				Event::NominationExpiring { stash: 101, expires_at: 2 }
+			);
+
+			CurrentEra::<Test>::put(2);
+			assert!(!is_voter(101));
+			assert_eq!(*staking_events().last().unwrap(), Event::NominationExpired { stash: 101 });
+
+			// the expiry is only reported once.
+			CurrentEra::<Test>::put(3);
+			let events_before = staking_events().len();
+			assert!(!is_voter(101));
+			assert_eq!(staking_events().len(), events_before);
+
+			// nominating again refreshes the nominations.
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11, 21]));
+			assert!(is_voter(101));
+		})
+	}
 }
 
 #[test]
@@ -5879,6 +5900,56 @@ fn api_nominations_quota_works() {
 	})
 }
 
+#[test]
+fn api_era_payout_forecast_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		start_active_era(1);
+		// the start of the era is only known once its first block is finalized.
+		assert_eq!(Staking::api_era_payout_forecast(), None);
+
+		let commission = Perbill::from_percent(10);
+		let prefs = ValidatorPrefs { commission, ..Default::default() };
+		ErasValidatorPrefs::<Test>::insert(1, 11, prefs);
+		Pallet::<Test>::reward_by_ids(vec![(11, 100), (21, 50)]);
+		advance_session();
+
+		let era_duration = Timestamp::get() - Staking::active_era().unwrap().start.unwrap();
+		let validator_payout = current_total_payout_for_duration(era_duration);
+		let forecast = Staking::api_era_payout_forecast().unwrap();
+		assert_eq!(forecast.era, 1);
+		assert_eq!(forecast.validator_payout, validator_payout);
+		let maximum_payout = maximum_payout_for_duration(era_duration);
+		assert_eq!(forecast.remainder, maximum_payout - validator_payout);
+
+		// 11 takes its commission, the rest is split with its nominator by exposure.
+		let payout_11 = Perbill::from_rational::<u32>(100, 150) * validator_payout;
+		let leftover_11 = payout_11 - commission * payout_11;
+		let own_11 = Perbill::from_rational::<Balance>(1000, 1125) * leftover_11;
+		// 21 takes no commission.
+		let payout_21 = Perbill::from_rational::<u32>(50, 150) * validator_payout;
+		let own_21 = Perbill::from_rational::<Balance>(1000, 1375) * payout_21;
+		assert_eq!(
+			forecast.validators,
+			vec![
+				ValidatorPayoutForecast {
+					validator: 11,
+					reward_points: 100,
+					commission: commission * payout_11,
+					own: own_11,
+					nominators: leftover_11 - own_11,
+				},
+				ValidatorPayoutForecast {
+					validator: 21,
+					reward_points: 50,
+					commission: 0,
+					own: own_21,
+					nominators: payout_21 - own_21,
+				},
+			]
+		);
+	})
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
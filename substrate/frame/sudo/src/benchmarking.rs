@@ -85,5 +85,50 @@ mod benchmarks {
 		assert_last_event::<T>(Event::KeyRemoved {});
 	}
 
+	#[benchmark]
+	fn announce(l: Linear<0, 10_000>) {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::remark { remark: vec![0; l as usize] }.into();
+		let call_hash = T::Hashing::hash_of(&call);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(call));
+
+		assert!(Announcements::<T>::contains_key(call_hash));
+	}
+
+	#[benchmark]
+	fn cancel_announcement() {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let call_hash = T::Hashing::hash_of(&call);
+		Announcements::<T>::insert(call_hash, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), call_hash);
+
+		assert_last_event::<T>(Event::AnnouncementCancelled { call_hash });
+	}
+
+	#[benchmark]
+	fn sudo_execute_announced() {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let call_hash = T::Hashing::hash_of(&call);
+		Announcements::<T>::insert(call_hash, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(call));
+
+		assert_last_event::<T>(Event::Sudid { sudo_result: Ok(()) })
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_bench_ext(), crate::mock::Test);
 }
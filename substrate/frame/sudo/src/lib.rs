@@ -94,6 +94,18 @@
 //! Learn more about the `Root` origin in the [`RawOrigin`](frame_system::RawOrigin) type
 //! documentation.
 //!
+//! ### Time-locked Sudo
+//!
+//! Chains that keep a sudo key after their launch can make its use transparent by configuring an
+//! [`AnnouncementDelay`](Config::AnnouncementDelay). The sudo key must then
+//! [`announce`](Pallet::announce) its calls, which emits them in [`Event::Announced`] for anyone
+//! to review, and can only dispatch them with
+//! [`sudo_execute_announced`](Pallet::sudo_execute_announced) once the delay has passed. Calls can
+//! no longer be dispatched directly with [`sudo`](Pallet::sudo),
+//! [`sudo_unchecked_weight`](Pallet::sudo_unchecked_weight) or [`sudo_as`](Pallet::sudo_as) in
+//! this mode, while [`set_key`](Pallet::set_key) and [`remove_key`](Pallet::remove_key) are not
+//! affected.
+//!
 //! ### Examples
 //!
 //! 1. You can make a privileged runtime call using `sudo` with an account that matches the sudo
@@ -116,15 +128,23 @@
 //!
 //! Once an origin is verified, sudo calls use `dispatch_bypass_filter` from the
 //! [`UnfilteredDispatchable`](frame_support::traits::UnfilteredDispatchable) trait to allow call
-//! execution without enforcing any further origin checks.
+//! execution without enforcing any further origin checks. The calls are subject to the
+//! [`frame_system::Config::NestedCallLimits`] shared by all pallets dispatching nested calls.
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sp_runtime::{traits::StaticLookup, DispatchResult};
+use sp_runtime::{
+	traits::{Hash, Saturating, StaticLookup},
+	DispatchResult,
+};
 use sp_std::prelude::*;
 
-use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable};
+use frame_support::{
+	dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
+	dispatch_context::dispatch_nested,
+	traits::UnfilteredDispatchable,
+};
 
 mod extension;
 #[cfg(test)]
@@ -141,6 +161,7 @@ pub use extension::CheckOnlySudoAccount;
 pub use pallet::*;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type CallHashOf<T> = <T as frame_system::Config>::Hash;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -166,6 +187,7 @@ pub mod pallet {
 			type RuntimeEvent = ();
 			#[inject_runtime_type]
 			type RuntimeCall = ();
+			type AnnouncementDelay = ();
 		}
 	}
 	#[pallet::config(with_default)]
@@ -180,6 +202,15 @@ pub mod pallet {
 			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
 			+ GetDispatchInfo;
 
+		/// The number of blocks for which a call must be [announced](Pallet::announce) before the
+		/// sudo key can dispatch it.
+		///
+		/// If `None`, the sudo key can dispatch calls directly and announced calls can be
+		/// dispatched right away.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type AnnouncementDelay: Get<Option<BlockNumberFor<Self>>>;
+
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -203,8 +234,9 @@ pub mod pallet {
 			call: Box<<T as Config>::RuntimeCall>,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_sudo(origin)?;
+			Self::ensure_not_time_locked()?;
 
-			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			let res = Self::dispatch_nested(*call, RawOrigin::Root.into());
 			Self::deposit_event(Event::Sudid { sudo_result: res.map(|_| ()).map_err(|e| e.error) });
 
 			// Sudo user does not pay a fee.
@@ -224,9 +256,10 @@ pub mod pallet {
 			weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_sudo(origin)?;
+			Self::ensure_not_time_locked()?;
 			let _ = weight; // We don't check the weight witness since it is a root call.
 
-			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			let res = Self::dispatch_nested(*call, RawOrigin::Root.into());
 			Self::deposit_event(Event::Sudid { sudo_result: res.map(|_| ()).map_err(|e| e.error) });
 
 			// Sudo user does not pay a fee.
@@ -269,9 +302,10 @@ pub mod pallet {
 			call: Box<<T as Config>::RuntimeCall>,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_sudo(origin)?;
+			Self::ensure_not_time_locked()?;
 
 			let who = T::Lookup::lookup(who)?;
-			let res = call.dispatch_bypass_filter(RawOrigin::Signed(who).into());
+			let res = Self::dispatch_nested(*call, RawOrigin::Signed(who).into());
 			Self::deposit_event(Event::SudoAsDone {
 				sudo_result: res.map(|_| ()).map_err(|e| e.error),
 			});
@@ -294,6 +328,81 @@ pub mod pallet {
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
 		}
+
+		/// Authenticates the sudo key and announces a function call, which can be dispatched with
+		/// [`sudo_execute_announced`](Pallet::sudo_execute_announced) once the
+		/// `AnnouncementDelay` has passed.
+		///
+		/// The call is emitted in [`Event::Announced`] for public preview.
+		///
+		/// The weight is linear in the encoded length of the call, which is hashed and emitted.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::announce(call.using_encoded(|c| c.len() as u32)))]
+		pub fn announce(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sudo(origin)?;
+
+			let call_hash = T::Hashing::hash_of(&call);
+			ensure!(!Announcements::<T>::contains_key(call_hash), Error::<T>::AlreadyAnnounced);
+			let executable_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::AnnouncementDelay::get().unwrap_or_default());
+			Announcements::<T>::insert(call_hash, executable_at);
+			Self::deposit_event(Event::Announced { call_hash, call: *call, executable_at });
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Authenticates the sudo key and cancels the announcement of the call with the hash
+		/// `call_hash`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_announcement())]
+		pub fn cancel_announcement(
+			origin: OriginFor<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sudo(origin)?;
+
+			Announcements::<T>::take(call_hash).ok_or(Error::<T>::NotAnnounced)?;
+			Self::deposit_event(Event::AnnouncementCancelled { call_hash });
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Authenticates the sudo key and dispatches an [announced](Pallet::announce) function
+		/// call with `Root` origin, once its `AnnouncementDelay` has passed.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::sudo_execute_announced().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn sudo_execute_announced(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sudo(origin)?;
+
+			let call_hash = T::Hashing::hash_of(&call);
+			let executable_at =
+				Announcements::<T>::get(call_hash).ok_or(Error::<T>::NotAnnounced)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= executable_at,
+				Error::<T>::AnnouncementNotDue
+			);
+			Announcements::<T>::remove(call_hash);
+
+			let res = Self::dispatch_nested(*call, RawOrigin::Root.into());
+			Self::deposit_event(Event::Sudid { sudo_result: res.map(|_| ()).map_err(|e| e.error) });
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::event]
@@ -318,6 +427,20 @@ pub mod pallet {
 			/// The result of the call made by the sudo user.
 			sudo_result: DispatchResult,
 		},
+		/// A call was [announced](Pallet::announce) by the sudo key.
+		Announced {
+			/// The hash of the announced call.
+			call_hash: CallHashOf<T>,
+			/// The announced call.
+			call: <T as Config>::RuntimeCall,
+			/// The block from which the call can be dispatched.
+			executable_at: BlockNumberFor<T>,
+		},
+		/// The announcement of a call was cancelled.
+		AnnouncementCancelled {
+			/// The hash of the call.
+			call_hash: CallHashOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -325,6 +448,14 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Sender must be the Sudo account.
 		RequireSudo,
+		/// Calls must be announced before they can be dispatched.
+		AnnouncementRequired,
+		/// The call was already announced.
+		AlreadyAnnounced,
+		/// The call was not announced.
+		NotAnnounced,
+		/// The announcement delay of the call has not yet passed.
+		AnnouncementNotDue,
 	}
 
 	/// The `AccountId` of the sudo key.
@@ -332,6 +463,12 @@ pub mod pallet {
 	#[pallet::getter(fn key)]
 	pub(super) type Key<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The block from which each [announced](Pallet::announce) call can be dispatched, by call
+	/// hash.
+	#[pallet::storage]
+	pub(super) type Announcements<T: Config> =
+		StorageMap<_, Identity, CallHashOf<T>, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -357,5 +494,21 @@ pub mod pallet {
 				Err(Error::<T>::RequireSudo.into())
 			}
 		}
+
+		/// Ensure that calls can be dispatched without announcing them first.
+		fn ensure_not_time_locked() -> DispatchResult {
+			ensure!(T::AnnouncementDelay::get().is_none(), Error::<T>::AnnouncementRequired);
+			Ok(())
+		}
+
+		/// Dispatch `call` from `origin` within the [`frame_system::Config::NestedCallLimits`].
+		fn dispatch_nested(
+			call: <T as Config>::RuntimeCall,
+			origin: T::RuntimeOrigin,
+		) -> DispatchResultWithPostInfo {
+			dispatch_nested::<T::NestedCallLimits, _, _, _>(call, |call| {
+				call.dispatch_bypass_filter(origin)
+			})
+		}
 	}
 }
@@ -20,7 +20,7 @@
 use super::*;
 use crate as sudo;
 use frame_support::{
-	derive_impl,
+	derive_impl, parameter_types,
 	traits::{ConstU32, Contains},
 };
 use sp_core::{ConstU64, H256};
@@ -143,10 +143,15 @@ impl logger::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+	pub static AnnouncementDelay: Option<u64> = None;
+}
+
 // Implement the sudo module's `Config` on the Test runtime.
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
+	type AnnouncementDelay = AnnouncementDelay;
 	type WeightInfo = ();
 }
 
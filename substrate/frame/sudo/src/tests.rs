@@ -20,8 +20,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok, weights::Weight};
 use mock::{
-	new_test_ext, Logger, LoggerCall, RuntimeCall, RuntimeEvent as TestEvent, RuntimeOrigin, Sudo,
-	SudoCall, System, Test,
+	new_test_ext, AnnouncementDelay, Logger, LoggerCall, RuntimeCall, RuntimeEvent as TestEvent,
+	RuntimeOrigin, Sudo, SudoCall, System, Test,
 };
 
 #[test]
@@ -213,3 +213,108 @@ fn sudo_as_emits_events_correctly() {
 		System::assert_has_event(TestEvent::Sudo(Event::SudoAsDone { sudo_result: Ok(()) }));
 	});
 }
+
+#[test]
+fn time_locked_sudo_requires_announcements() {
+	new_test_ext(1).execute_with(|| {
+		AnnouncementDelay::set(Some(10));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+			i: 42,
+			weight: Weight::from_parts(1_000, 0),
+		}));
+		let call_hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), call.clone()),
+			Error::<Test>::AnnouncementRequired
+		);
+		assert_noop!(
+			Sudo::sudo_as(RuntimeOrigin::signed(1), 2, call.clone()),
+			Error::<Test>::AnnouncementRequired
+		);
+		assert_noop!(
+			Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call.clone()),
+			Error::<Test>::NotAnnounced
+		);
+
+		assert_noop!(
+			Sudo::announce(RuntimeOrigin::signed(2), call.clone()),
+			Error::<Test>::RequireSudo
+		);
+		assert_ok!(Sudo::announce(RuntimeOrigin::signed(1), call.clone()));
+		System::assert_last_event(TestEvent::Sudo(Event::Announced {
+			call_hash,
+			call: *call.clone(),
+			executable_at: 11,
+		}));
+		assert_noop!(
+			Sudo::announce(RuntimeOrigin::signed(1), call.clone()),
+			Error::<Test>::AlreadyAnnounced
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call.clone()),
+			Error::<Test>::AnnouncementNotDue
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Sudo::sudo_execute_announced(RuntimeOrigin::signed(2), call.clone()),
+			Error::<Test>::RequireSudo
+		);
+		assert_ok!(Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call.clone()));
+		assert_eq!(Logger::i32_log(), vec![42i32]);
+		System::assert_last_event(TestEvent::Sudo(Event::Sudid { sudo_result: Ok(()) }));
+
+		// An announcement can only be executed once.
+		assert_noop!(
+			Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call),
+			Error::<Test>::NotAnnounced
+		);
+	});
+}
+
+#[test]
+fn announcements_can_be_cancelled() {
+	new_test_ext(1).execute_with(|| {
+		AnnouncementDelay::set(Some(10));
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+			i: 42,
+			weight: Weight::from_parts(1_000, 0),
+		}));
+		let call_hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+
+		assert_ok!(Sudo::announce(RuntimeOrigin::signed(1), call.clone()));
+		assert_noop!(
+			Sudo::cancel_announcement(RuntimeOrigin::signed(2), call_hash),
+			Error::<Test>::RequireSudo
+		);
+		assert_ok!(Sudo::cancel_announcement(RuntimeOrigin::signed(1), call_hash));
+		System::assert_last_event(TestEvent::Sudo(Event::AnnouncementCancelled { call_hash }));
+		assert_noop!(
+			Sudo::cancel_announcement(RuntimeOrigin::signed(1), call_hash),
+			Error::<Test>::NotAnnounced
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call),
+			Error::<Test>::NotAnnounced
+		);
+	});
+}
+
+#[test]
+fn announced_calls_can_be_executed_right_away_without_delay() {
+	new_test_ext(1).execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+			i: 42,
+			weight: Weight::from_parts(1_000, 0),
+		}));
+
+		assert_ok!(Sudo::announce(RuntimeOrigin::signed(1), call.clone()));
+		assert_ok!(Sudo::sudo_execute_announced(RuntimeOrigin::signed(1), call));
+		assert_eq!(Logger::i32_log(), vec![42i32]);
+	});
+}
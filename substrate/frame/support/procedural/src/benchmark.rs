@@ -42,6 +42,7 @@ mod keywords {
 	custom_keyword!(extra);
 	custom_keyword!(extrinsic_call);
 	custom_keyword!(skip_meta);
+	custom_keyword!(worst_case_storage);
 	custom_keyword!(BenchmarkError);
 	custom_keyword!(Result);
 
@@ -54,8 +55,17 @@ mod keywords {
 struct ParamDef {
 	name: String,
 	_typ: Type,
-	start: syn::GenericArgument,
-	end: syn::GenericArgument,
+	range: ParamRangeDef,
+}
+
+/// The range of a benchmark param.
+#[derive(Clone)]
+enum ParamRangeDef {
+	/// `x: Linear<start, end>`
+	Linear { start: syn::GenericArgument, end: syn::GenericArgument },
+	/// `x: StorageBound<Item, CAP>`, ranges from zero to the `max_values` of `Item`, capped at
+	/// `CAP`.
+	StorageBound { item: syn::GenericArgument, cap: syn::GenericArgument },
 }
 
 /// Allows easy parsing of the `<10, 20>` component of `x: Linear<10, 20>`.
@@ -68,16 +78,20 @@ struct RangeArgs {
 	_gt_token: Gt,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Default)]
 struct BenchmarkAttrs {
 	skip_meta: bool,
 	extra: bool,
+	/// The storage maps to fill to their bounds, if `worst_case_storage` is specified.
+	worst_case_storage: Option<Vec<Type>>,
 }
 
 /// Represents a single benchmark option
 enum BenchmarkAttrKeyword {
 	Extra,
 	SkipMeta,
+	/// `worst_case_storage(Map1<T>, Map2<T>)`
+	WorstCaseStorage(Vec<Type>),
 }
 
 impl syn::parse::Parse for BenchmarkAttrKeyword {
@@ -89,6 +103,12 @@ impl syn::parse::Parse for BenchmarkAttrKeyword {
 		} else if lookahead.peek(keywords::skip_meta) {
 			let _skip_meta: keywords::skip_meta = input.parse()?;
 			return Ok(BenchmarkAttrKeyword::SkipMeta)
+		} else if lookahead.peek(keywords::worst_case_storage) {
+			let _worst_case_storage: keywords::worst_case_storage = input.parse()?;
+			let content;
+			syn::parenthesized!(content in input);
+			let maps = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+			return Ok(BenchmarkAttrKeyword::WorstCaseStorage(maps.into_iter().collect()))
 		} else {
 			return Err(lookahead.error())
 		}
@@ -99,6 +119,7 @@ impl syn::parse::Parse for BenchmarkAttrs {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let mut extra = false;
 		let mut skip_meta = false;
+		let mut worst_case_storage = None;
 		let args = Punctuated::<BenchmarkAttrKeyword, Token![,]>::parse_terminated(&input)?;
 		for arg in args.into_iter() {
 			match arg {
@@ -114,9 +135,15 @@ impl syn::parse::Parse for BenchmarkAttrs {
 					}
 					skip_meta = true;
 				},
+				BenchmarkAttrKeyword::WorstCaseStorage(maps) => {
+					if worst_case_storage.is_some() {
+						return Err(input.error("`worst_case_storage` can only be specified once"))
+					}
+					worst_case_storage = Some(maps);
+				},
 			}
 		}
-		Ok(BenchmarkAttrs { extra, skip_meta })
+		Ok(BenchmarkAttrs { extra, skip_meta, worst_case_storage })
 	}
 }
 
@@ -191,7 +218,7 @@ fn ensure_valid_return_type(item_fn: &ItemFn) -> Result<()> {
 	Ok(())
 }
 
-/// Parses params such as `x: Linear<0, 1>`
+/// Parses params such as `x: Linear<0, 1>` or `x: StorageBound<Members<T>, 1_000>`
 fn parse_params(item_fn: &ItemFn) -> Result<Vec<ParamDef>> {
 	let mut params: Vec<ParamDef> = Vec::new();
 	for arg in &item_fn.sig.inputs {
@@ -228,8 +255,13 @@ fn parse_params(item_fn: &ItemFn) -> Result<Vec<ParamDef>> {
 		let Some(segment) = tpath.path.segments.last() else { return invalid_param(typ.span()) };
 		let args = segment.arguments.to_token_stream().into();
 		let Ok(args) = syn::parse::<RangeArgs>(args) else { return invalid_param(typ.span()) };
+		let range = if segment.ident == "StorageBound" {
+			ParamRangeDef::StorageBound { item: args.start, cap: args.end }
+		} else {
+			ParamRangeDef::Linear { start: args.start, end: args.end }
+		};
 
-		params.push(ParamDef { name, _typ: typ.clone(), start: args.start, end: args.end });
+		params.push(ParamDef { name, _typ: typ.clone(), range });
 	}
 	Ok(params)
 }
@@ -389,9 +421,10 @@ pub fn benchmarks(
 		benchmark_names.push(name.clone());
 
 		// Check if we need to parse any args
+		let mut benchmark_attrs = BenchmarkAttrs::default();
 		if benchmark_attr.meta.require_path_only().is_err() {
 			// parse any args provided to #[benchmark]
-			let benchmark_attrs: BenchmarkAttrs = benchmark_attr.parse_args()?;
+			benchmark_attrs = benchmark_attr.parse_args()?;
 
 			// record name sets
 			if benchmark_attrs.extra {
@@ -402,7 +435,13 @@ pub fn benchmarks(
 		}
 
 		// expand benchmark
-		let expanded = expand_benchmark(benchmark_def, name, instance, where_clause.clone());
+		let expanded = expand_benchmark(
+			benchmark_def,
+			name,
+			instance,
+			where_clause.clone(),
+			benchmark_attrs.worst_case_storage.unwrap_or_default(),
+		);
 
 		// replace original function def with expanded code
 		*stmt = Item::Verbatim(expanded);
@@ -691,14 +730,16 @@ struct UnrolledParams {
 
 impl UnrolledParams {
 	/// Constructs an [`UnrolledParams`] from a [`Vec<ParamDef>`]
-	fn from(params: &Vec<ParamDef>) -> UnrolledParams {
+	fn from(params: &Vec<ParamDef>, krate: &syn::Path) -> UnrolledParams {
 		let param_ranges: Vec<TokenStream2> = params
 			.iter()
 			.map(|p| {
 				let name = Ident::new(&p.name, Span::call_site());
-				let start = &p.start;
-				let end = &p.end;
-				quote!(#name, #start, #end)
+				match &p.range {
+					ParamRangeDef::Linear { start, end } => quote!(#name, #start, #end),
+					ParamRangeDef::StorageBound { item, cap } =>
+						quote!(#name, 0, #krate::storage_bound::<#item>(#cap)),
+				}
 			})
 			.collect();
 		let param_names: Vec<TokenStream2> = params
@@ -718,6 +759,7 @@ fn expand_benchmark(
 	name: &Ident,
 	is_instance: bool,
 	where_clause: TokenStream2,
+	worst_case_storage: Vec<Type>,
 ) -> TokenStream2 {
 	// set up variables needed during quoting
 	let krate = match generate_access_from_frame_or_crate("frame-benchmarking") {
@@ -736,7 +778,7 @@ fn expand_benchmark(
 	let test_ident = Ident::new(format!("test_{}", name.to_string()).as_str(), Span::call_site());
 
 	// unroll params (prepare for quoting)
-	let unrolled = UnrolledParams::from(&benchmark_def.params);
+	let unrolled = UnrolledParams::from(&benchmark_def.params, &krate);
 	let param_names = unrolled.param_names;
 	let param_ranges = unrolled.param_ranges;
 
@@ -750,6 +792,15 @@ fn expand_benchmark(
 		true => quote!(T: Config<I>, I: 'static),
 	};
 
+	// fill the given storage maps to their bounds before running the setup code
+	let fill_storage = quote! {
+		#(
+			<#worst_case_storage as #krate::v2::FillToBounds>::fill_to_bounds(
+				#krate::WORST_CASE_STORAGE_CAP,
+			);
+		)*
+	};
+
 	// used in the benchmarking impls
 	let (pre_call, post_call, fn_call_body) = match &benchmark_def.call_def {
 		BenchmarkCallDef::ExtrinsicCall { origin, expr_call, attr_span: _ } => {
@@ -871,6 +922,7 @@ fn expand_benchmark(
 			#fn_attrs_clone
 		)*
 		#vis #sig {
+			#fill_storage
 			#(
 				#setup_stmts
 			)*
@@ -920,6 +972,7 @@ fn expand_benchmark(
 				)*
 
 				// benchmark setup code
+				#fill_storage
 				#(
 					#setup_stmts
 				)*
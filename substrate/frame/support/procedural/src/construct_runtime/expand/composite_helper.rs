@@ -24,12 +24,14 @@ pub(crate) fn expand_conversion_fn(
 	path: &PalletPath,
 	instance: Option<&Ident>,
 	variant_name: &Ident,
+	attr: &TokenStream,
 ) -> TokenStream {
 	let composite_name = quote::format_ident!("{}", composite_name);
 	let runtime_composite_name = quote::format_ident!("Runtime{}", composite_name);
 
 	if let Some(inst) = instance {
 		quote! {
+			#attr
 			impl From<#path::#composite_name<#path::#inst>> for #runtime_composite_name {
 				fn from(hr: #path::#composite_name<#path::#inst>) -> Self {
 					#runtime_composite_name::#variant_name(hr)
@@ -38,6 +40,7 @@ pub(crate) fn expand_conversion_fn(
 		}
 	} else {
 		quote! {
+			#attr
 			impl From<#path::#composite_name> for #runtime_composite_name {
 				fn from(hr: #path::#composite_name) -> Self {
 					#runtime_composite_name::#variant_name(hr)
@@ -53,18 +56,29 @@ pub(crate) fn expand_variant(
 	path: &PalletPath,
 	instance: Option<&Ident>,
 	variant_name: &Ident,
+	attr: &TokenStream,
 ) -> TokenStream {
 	let composite_name = quote::format_ident!("{}", composite_name);
 
 	if let Some(inst) = instance {
 		quote! {
+			#attr
 			#[codec(index = #index)]
 			#variant_name(#path::#composite_name<#path::#inst>),
 		}
 	} else {
 		quote! {
+			#attr
 			#[codec(index = #index)]
 			#variant_name(#path::#composite_name),
 		}
 	}
 }
+
+/// The number of variants of a composite enum, counting only the variants of the pallets whose
+/// `#[cfg]` attributes `attrs` are enabled.
+pub(crate) fn expand_variant_count(attrs: &[TokenStream]) -> TokenStream {
+	quote! {
+		<[()]>::len(&[ #( #attrs () ),* ]) as u32
+	}
+}
@@ -23,18 +23,21 @@ use quote::quote;
 pub fn expand_outer_hold_reason(pallet_decls: &[Pallet], scrate: &TokenStream) -> TokenStream {
 	let mut conversion_fns = Vec::new();
 	let mut hold_reason_variants = Vec::new();
+	let mut pallet_attrs = Vec::new();
 	for decl in pallet_decls {
 		if let Some(_) = decl.find_part("HoldReason") {
 			let variant_name = &decl.name;
 			let path = &decl.path;
 			let index = decl.index;
 			let instance = decl.instance.as_ref();
+			let attr = decl.cfg_attrs();
 
 			conversion_fns.push(composite_helper::expand_conversion_fn(
 				"HoldReason",
 				path,
 				instance,
 				variant_name,
+				&attr,
 			));
 
 			hold_reason_variants.push(composite_helper::expand_variant(
@@ -43,10 +46,13 @@ pub fn expand_outer_hold_reason(pallet_decls: &[Pallet], scrate: &TokenStream) -
 				path,
 				instance,
 				variant_name,
+				&attr,
 			));
+
+			pallet_attrs.push(attr);
 		}
 	}
-	let hold_reason_variants_count = hold_reason_variants.len() as u32;
+	let hold_reason_variants_count = composite_helper::expand_variant_count(&pallet_attrs);
 
 	quote! {
 		/// A reason for placing a hold on funds.
@@ -29,12 +29,14 @@ pub fn expand_outer_lock_id(pallet_decls: &[Pallet], scrate: &TokenStream) -> To
 			let path = &decl.path;
 			let index = decl.index;
 			let instance = decl.instance.as_ref();
+			let attr = decl.cfg_attrs();
 
 			conversion_fns.push(composite_helper::expand_conversion_fn(
 				"LockId",
 				path,
 				instance,
 				variant_name,
+				&attr,
 			));
 
 			lock_id_variants.push(composite_helper::expand_variant(
@@ -43,6 +45,7 @@ pub fn expand_outer_lock_id(pallet_decls: &[Pallet], scrate: &TokenStream) -> To
 				path,
 				instance,
 				variant_name,
+				&attr,
 			));
 		}
 	}
@@ -18,7 +18,6 @@
 use crate::construct_runtime::{parse::PalletPath, Pallet};
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::str::FromStr;
 use syn::Ident;
 
 pub fn expand_runtime_metadata(
@@ -50,14 +49,7 @@ pub fn expand_runtime_metadata(
 			let constants = expand_pallet_metadata_constants(runtime, decl);
 			let errors = expand_pallet_metadata_errors(runtime, decl);
 			let docs = expand_pallet_metadata_docs(runtime, decl);
-			let attr = decl.cfg_pattern.iter().fold(TokenStream::new(), |acc, pattern| {
-				let attr = TokenStream::from_str(&format!("#[cfg({})]", pattern.original()))
-					.expect("was successfully parsed before; qed");
-				quote! {
-					#acc
-					#attr
-				}
-			});
+			let attr = decl.cfg_attrs();
 
 			quote! {
 				#attr
@@ -75,6 +67,16 @@ pub fn expand_runtime_metadata(
 		})
 		.collect::<Vec<_>>();
 
+	// All pallets execute their hooks in the order they are declared, see `AllPalletsWithSystem`.
+	let hooks_order = pallet_declarations.iter().map(|decl| {
+		let name = &decl.name;
+		let attr = decl.cfg_attrs();
+		quote! {
+			#attr
+			stringify!(#name)
+		}
+	});
+
 	quote! {
 		impl #runtime {
 			fn metadata_ir() -> #scrate::__private::metadata_ir::MetadataIR {
@@ -140,7 +142,10 @@ pub fn expand_runtime_metadata(
 							>(),
 						event_enum_ty: #scrate::__private::scale_info::meta_type::<RuntimeEvent>(),
 						error_enum_ty: #scrate::__private::scale_info::meta_type::<RuntimeError>(),
-					}
+					},
+					hooks_order: #scrate::__private::metadata_ir::HooksOrderIR::from_pallets(
+						#scrate::__private::sp_std::vec![ #(#hooks_order),* ]
+					),
 				}
 			}
 
@@ -17,8 +17,11 @@
 
 use frame_support_procedural_tools::syn_ext as ext;
 use proc_macro2::{Span, TokenStream};
-use quote::ToTokens;
-use std::collections::{HashMap, HashSet};
+use quote::{quote, ToTokens};
+use std::{
+	collections::{HashMap, HashSet},
+	str::FromStr,
+};
 use syn::{
 	ext::IdentExt,
 	parse::{Parse, ParseStream},
@@ -616,6 +619,18 @@ impl Pallet {
 	pub fn exists_part(&self, name: &str) -> bool {
 		self.find_part(name).is_some()
 	}
+
+	/// The `#[cfg]` attributes of the pallet, to gate the items generated for it.
+	pub fn cfg_attrs(&self) -> TokenStream {
+		self.cfg_pattern.iter().fold(TokenStream::new(), |acc, pattern| {
+			let attr = TokenStream::from_str(&format!("#[cfg({})]", pattern.original()))
+				.expect("was successfully parsed before; qed");
+			quote! {
+				#acc
+				#attr
+			}
+		})
+	}
 }
 
 /// Result of a conversion of a declaration of pallets.
@@ -32,6 +32,7 @@ mod pallet_error;
 mod storage_alias;
 mod transactional;
 mod tt_macro;
+mod worst_case;
 
 use frame_support_procedural_tools::generate_access_from_frame_or_crate;
 use macro_magic::{import_tokens_attr, import_tokens_attr_verbatim};
@@ -171,6 +172,11 @@ fn counter_prefix(prefix: &str) -> String {
 ///   pallet4 .., // Here pallet4 is given index 1
 ///   ```
 ///
+/// * `#[cfg(..)]` optional: only includes the pallet in the runtime if the predicate holds, e.g.
+///   `#[cfg(feature = "fast-runtime")]`. All the items generated for the pallet are gated the same
+///   way. Indices are resolved before the predicates are evaluated, so enabling or disabling a
+///   pallet never changes the index of another one. The `System` pallet can't be gated.
+///
 /// # Note
 ///
 /// The population of the genesis storage depends on the order of pallets. So, if one of your
@@ -263,6 +269,21 @@ pub fn construct_runtime(input: TokenStream) -> TokenStream {
 ///
 /// See `frame_support::pallet` docs for more info.
 ///
+/// ## Storage Analysis (`#[pallet(storage_analysis)]`)
+///
+/// Specifying the argument `storage_analysis` (which can be combined with `dev_mode`, e.g.
+/// `#[pallet(dev_mode, storage_analysis)]`) makes the macro analyze the storage writes of the
+/// dispatchables and emit a compile-time warning for:
+///
+/// * Writes of storage items declared with `#[pallet::whitelist_storage]`, since whitelisted
+///   items are not accounted for by the benchmarks.
+/// * Writes of the same storage item through different names in one dispatchable, e.g. through a
+///   type alias declared in the pallet module.
+///
+/// The analysis is syntactic and only finds writes like `Item::<T>::put(..)` done directly in the
+/// dispatchable. The warnings can be silenced for a dispatchable with
+/// `#[pallet::allow_storage_warnings(whitelisted_write, aliased_write)]`.
+///
 /// ## Runtime Metadata Documentation
 ///
 /// The documentation added to this pallet is included in the runtime metadata.
@@ -540,6 +561,13 @@ pub fn derive_pallet_error(input: TokenStream) -> TokenStream {
 	pallet_error::derive_pallet_error(input)
 }
 
+/// Derive `WorstCase`, building every field with its own worst case. Docs are at
+/// `frame_benchmarking::v2::WorstCase`.
+#[proc_macro_derive(WorstCase, attributes(worst_case))]
+pub fn derive_worst_case(input: TokenStream) -> TokenStream {
+	worst_case::derive_worst_case(input)
+}
+
 /// Internal macro used by `frame_support` to create tt-call-compliant macros
 #[proc_macro]
 pub fn __create_tt_macro(input: TokenStream) -> TokenStream {
@@ -989,6 +1017,16 @@ pub fn constant(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+///
+/// ---
+///
+/// **Rust-Analyzer users**: See the documentation of the Rust item in
+/// `frame_support::pallet_macros::dynamic`.
+#[proc_macro_attribute]
+pub fn dynamic(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 ///
 /// ---
 ///
@@ -1187,6 +1225,49 @@ pub fn feeless_if(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
 
+/// Each dispatchable may be annotated with the `#[pallet::errors($variants)]` attribute, which
+/// lists the variants of the pallet `Error` the dispatchable can return.
+///
+/// The variants must be declared in the `#[pallet::error]` enum of the pallet.
+///
+/// ### Example
+/// ```ignore
+/// #[pallet::errors(InsufficientBalance, NotOwner)]
+/// pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+///     ....
+/// }
+/// ```
+///
+/// ### Macro expansion
+///
+/// The errors are listed, together with the first line of their documentation, in an `Errors`
+/// section of the documentation of the call variant. This way they are part of the metadata, so
+/// that user interfaces can explain why a call failed.
+#[proc_macro_attribute]
+pub fn errors(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
+/// Each dispatchable may be annotated with the
+/// `#[pallet::allow_storage_warnings($kind, ..)]` attribute, which silences the given kinds of
+/// warnings of the storage analysis enabled with `#[pallet(storage_analysis)]`.
+///
+/// The kinds are `whitelisted_write`, for writes of storage items declared with
+/// `#[pallet::whitelist_storage]`, and `aliased_write`, for writes of the same storage item
+/// through different names.
+///
+/// ### Example
+/// ```ignore
+/// #[pallet::allow_storage_warnings(whitelisted_write)]
+/// pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
+///     ....
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn allow_storage_warnings(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 /// Allows you to define some extra constants to be added into constant metadata.
 ///
 /// Item must be defined as:
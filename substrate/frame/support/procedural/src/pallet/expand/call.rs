@@ -17,7 +17,9 @@
 
 use crate::{
 	pallet::{
-		expand::warnings::{weight_constant_warning, weight_witness_warning},
+		expand::warnings::{
+			storage_write_warnings, weight_constant_warning, weight_witness_warning,
+		},
 		parse::call::{CallVariantDef, CallWeightDef},
 		Def,
 	},
@@ -127,7 +129,14 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	};
 
-	let fn_doc = methods.iter().map(map_fn_docs).collect::<Vec<_>>();
+	let fn_doc = methods
+		.iter()
+		.map(|method| {
+			let mut docs = vec![map_fn_docs(method)];
+			docs.extend(errors_docs(def, method).into_iter().map(|line| quote!(#line)));
+			docs
+		})
+		.collect::<Vec<_>>();
 
 	let args_name = methods
 		.iter()
@@ -211,6 +220,24 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 
 	let capture_docs = if cfg!(feature = "no-metadata-docs") { "never" } else { "always" };
 
+	// Analyze the storage writes of the calls before they are wrapped.
+	let mut storage_warnings = Vec::new();
+	if let Some(syn::Item::Impl(item_impl)) = def
+		.call
+		.as_ref()
+		.map(|c| &def.item.content.as_ref().expect("Checked by def parser").1[c.index])
+	{
+		for method in &methods {
+			let block = item_impl.items.iter().find_map(|i| match i {
+				syn::ImplItem::Fn(f) if f.sig.ident == method.name => Some(&f.block),
+				_ => None,
+			});
+			if let Some(block) = block {
+				storage_write_warnings(def, method, block, &mut storage_warnings);
+			}
+		}
+	}
+
 	// Wrap all calls inside of storage layers
 	if let Some(syn::Item::Impl(item_impl)) = def
 		.call
@@ -259,6 +286,9 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			#(
 				#weight_warnings
 			)*
+			#(
+				#storage_warnings
+			)*
 		}
 
 		#[doc(hidden)]
@@ -297,7 +327,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::Never,
 			),
 			#(
-				#[doc = #fn_doc]
+				#( #[doc = #fn_doc] )*
 				#[codec(index = #call_index)]
 				#fn_name {
 					#(
@@ -420,9 +450,15 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 								#frame_support::__private::sp_tracing::enter_span!(
 									#frame_support::__private::sp_tracing::trace_span!(stringify!(#fn_name))
 								);
-								#maybe_allow_attrs
-								<#pallet_ident<#type_use_gen>>::#fn_name(origin, #( #args_name, )* )
-									.map(Into::into).map_err(Into::into)
+								#frame_support::storage::metrics::with_metrics(
+									<#pallet_ident<#type_use_gen> as #frame_support::traits::PalletInfoAccess>::name(),
+									stringify!(#fn_name),
+									|| {
+										#maybe_allow_attrs
+										<#pallet_ident<#type_use_gen>>::#fn_name(origin, #( #args_name, )* )
+											.map(Into::into).map_err(Into::into)
+									},
+								)
 							},
 						)*
 						Self::__Ignore(_, _) => {
@@ -448,3 +484,34 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	)
 }
+
+/// The documentation lines listing the errors the dispatchable can return, according to its
+/// `#[pallet::errors(..)]` attribute, together with the first line of their documentation.
+///
+/// They are added to the documentation of the call variant, so that they end up in the metadata.
+fn errors_docs(def: &Def, method: &CallVariantDef) -> Vec<String> {
+	if method.errors.is_empty() {
+		return Vec::new()
+	}
+
+	let variants = def.error.as_ref().map(|e| &e.variants[..]).unwrap_or_default();
+	let mut docs = vec![String::new(), "# Errors".into(), String::new()];
+	for error in &method.errors {
+		let summary = variants
+			.iter()
+			.find(|(variant, _, _)| variant == error)
+			.and_then(|(_, _, docs)| docs.first())
+			.and_then(|doc| match doc {
+				syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(doc), .. }) =>
+					Some(doc.value().trim().to_string()),
+				_ => None,
+			})
+			.filter(|summary| !summary.is_empty());
+
+		docs.push(match summary {
+			Some(summary) => format!("- `{}`: {}", error, summary),
+			None => format!("- `{}`", error),
+		});
+	}
+	docs
+}
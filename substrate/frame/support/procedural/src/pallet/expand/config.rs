@@ -48,9 +48,11 @@ Consequently, a runtime that wants to include this pallet must implement this tr
 		),
 	);
 
+	let dynamic_params = expand_dynamic_params(def);
+
 	// we only emit `DefaultConfig` if there are trait items, so an empty `DefaultConfig` is
 	// impossible consequently.
-	match &config.default_sub_trait {
+	let default_config = match &def.config.default_sub_trait {
 		Some(default_sub_trait) if default_sub_trait.items.len() > 0 => {
 			let trait_items = &default_sub_trait
 				.items
@@ -93,5 +95,66 @@ Consequently, a runtime that wants to include this pallet must implement this tr
 			)
 		},
 		_ => Default::default(),
+	};
+
+	quote!(
+		#dynamic_params
+		#default_config
+	)
+}
+
+/// Generate the `dynamic_params` module with a key for each `#[pallet::dynamic]` item.
+fn expand_dynamic_params(def: &Def) -> TokenStream {
+	let config = &def.config;
+	if config.dynamic_params.is_empty() {
+		return Default::default()
 	}
+
+	let frame_support = &def.frame_support;
+	let span = config.attr_span;
+	let type_impl_gen = &def.type_impl_generics(span);
+	let type_decl_gen = &def.type_decl_generics(span);
+	let type_use_gen = &def.type_use_generics(span);
+	let where_clause = &config.where_clause;
+	let pallet_ident = &def.pallet_struct.pallet;
+
+	let keys = config.dynamic_params.iter().map(|param| {
+		let ident = &param.ident;
+		let value = &param.type_;
+		let doc = format!(
+			"The key of the dynamic parameter [`Config::{}`](super::Config::{}).",
+			ident, ident,
+		);
+
+		quote!(
+			#[doc = #doc]
+			pub struct #ident<#type_decl_gen>(
+				#frame_support::__private::sp_std::marker::PhantomData<(#type_use_gen)>,
+			);
+
+			impl<#type_impl_gen> #frame_support::traits::dynamic_params::Key
+				for #ident<#type_use_gen>
+				#where_clause
+			{
+				type Value = #value;
+				const NAME: &'static str = stringify!(#ident);
+
+				fn pallet_name() -> &'static str {
+					<
+						#pallet_ident<#type_use_gen> as #frame_support::traits::PalletInfoAccess
+					>::name()
+				}
+			}
+		)
+	});
+
+	quote!(
+		/// The keys of the dynamic parameters of this pallet, to be used with
+		/// `frame_support::traits::dynamic_params::Dynamic`.
+		pub mod dynamic_params {
+			use super::*;
+
+			#( #keys )*
+		}
+	)
 }
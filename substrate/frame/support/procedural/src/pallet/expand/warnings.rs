@@ -17,13 +17,41 @@
 
 //! Generates warnings for undesirable pallet code.
 
-use crate::pallet::parse::call::{CallVariantDef, CallWeightDef};
+use crate::pallet::{
+	parse::call::{CallVariantDef, CallWeightDef, StorageWarningKind},
+	Def,
+};
 use proc_macro_warning::Warning;
+use std::collections::HashMap;
 use syn::{
 	spanned::Spanned,
 	visit::{self, Visit},
 };
 
+/// The functions of the storage types that write to storage.
+const STORAGE_WRITE_FUNCTIONS: &[&str] = &[
+	"put",
+	"set",
+	"insert",
+	"remove",
+	"kill",
+	"take",
+	"mutate",
+	"try_mutate",
+	"mutate_exists",
+	"try_mutate_exists",
+	"mutate_extant",
+	"append",
+	"try_append",
+	"translate",
+	"translate_values",
+	"swap",
+	"clear",
+	"clear_prefix",
+	"remove_all",
+	"remove_prefix",
+];
+
 /// Warn if any of the call arguments starts with a underscore and is used in a weight formula.
 pub(crate) fn weight_witness_warning(
 	method: &CallVariantDef,
@@ -96,3 +124,130 @@ fn contains_ident(mut expr: syn::Expr, ident: &syn::Ident) -> bool {
 	visit::visit_expr(&mut visitor, &mut expr);
 	visitor.found
 }
+
+/// Warn about the storage writes of the dispatchable `method` with the given `block` that are
+/// likely mistakes, if the pallet is in `storage_analysis` mode:
+///
+/// * Writes of storage items declared with `#[pallet::whitelist_storage]`, since they are not
+///   accounted for in the benchmarks.
+/// * Writes of the same storage item through different names, i.e. type aliases declared in the
+///   pallet module, which are easily missed when reviewing the call.
+///
+/// The analysis is syntactic, so it only finds writes like `Item::<T>::put(..)` or
+/// `<Item<T>>::put(..)` inside of the dispatchable itself. Each kind of warning can be silenced
+/// for a dispatchable with `#[pallet::allow_storage_warnings(whitelisted_write, aliased_write)]`.
+pub(crate) fn storage_write_warnings(
+	def: &Def,
+	method: &CallVariantDef,
+	block: &syn::Block,
+	warnings: &mut Vec<Warning>,
+) {
+	if !def.storage_analysis {
+		return
+	}
+
+	// Map the names of the storage items and their aliases to the storage item names.
+	let mut names = def
+		.storages
+		.iter()
+		.map(|storage| (storage.ident.to_string(), storage.ident.to_string()))
+		.collect::<HashMap<_, _>>();
+	let mut aliases = def
+		.item
+		.content
+		.as_ref()
+		.map(|(_, items)| &items[..])
+		.unwrap_or_default()
+		.iter()
+		.filter_map(|item| match item {
+			syn::Item::Type(alias) if !names.contains_key(&alias.ident.to_string()) => {
+				let syn::Type::Path(path) = &*alias.ty else { return None };
+				let target = path.path.segments.last()?.ident.to_string();
+				Some((alias.ident.to_string(), target))
+			},
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	// Resolve aliases of aliases.
+	while let Some(i) = aliases.iter().position(|(_, target)| names.contains_key(target)) {
+		let (alias, target) = aliases.swap_remove(i);
+		let storage = names[&target].clone();
+		names.insert(alias, storage);
+	}
+
+	let mut visitor = StorageWrites { names: &names, writes: Vec::new() };
+	visitor.visit_block(block);
+
+	let allowed = |kind| method.allowed_storage_warnings.contains(&kind);
+	let mut written_as = HashMap::<&str, syn::Ident>::new();
+	for (name, storage) in visitor.writes {
+		let is_whitelisted =
+			def.storages.iter().any(|s| s.whitelisted && s.ident == storage.as_str());
+		if is_whitelisted && !allowed(StorageWarningKind::WhitelistedWrite) {
+			let warning = Warning::new_deprecated("WhitelistedStorageWrite")
+				.index(warnings.len())
+				.old("write a whitelisted storage item in a call")
+				.new("account for the write in the weight of the call and allow it with \
+					`#[pallet::allow_storage_warnings(whitelisted_write)]`")
+				.help_link(
+					"https://paritytech.github.io/polkadot-sdk/master/frame_support/pallet_macros/attr.whitelist_storage.html",
+				)
+				.span(name.span())
+				.build_or_panic();
+			warnings.push(warning);
+		}
+
+		match written_as.get(storage.as_str()).cloned() {
+			None => {
+				written_as.insert(storage.as_str(), name);
+			},
+			Some(first) if first != name && !allowed(StorageWarningKind::AliasedWrite) => {
+				let warning = Warning::new_deprecated("AliasedStorageWrite")
+					.index(warnings.len())
+					.old(format!("write `{}` through both `{}` and `{}`", storage, first, name))
+					.new("write the storage item through a single name or allow it with \
+						`#[pallet::allow_storage_warnings(aliased_write)]`")
+					.help_link(
+						"https://paritytech.github.io/polkadot-sdk/master/frame_support/attr.pallet.html",
+					)
+					.span(name.span())
+					.build_or_panic();
+				warnings.push(warning);
+			},
+			Some(_) => (),
+		}
+	}
+}
+
+/// Collects the storage writes of a block, as the name used and the name of the storage item.
+struct StorageWrites<'a> {
+	names: &'a HashMap<String, String>,
+	writes: Vec<(syn::Ident, &'a String)>,
+}
+
+impl<'a, 'ast> Visit<'ast> for StorageWrites<'a> {
+	fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+		if let syn::Expr::Path(func) = &*call.func {
+			let segments = &func.path.segments;
+			// Either `<Item<T>>::function` or `Item::<T>::function`.
+			let name = match &func.qself {
+				Some(qself) => match &*qself.ty {
+					syn::Type::Path(ty) => ty.path.segments.last().map(|s| &s.ident),
+					_ => None,
+				},
+				None if segments.len() >= 2 => Some(&segments[segments.len() - 2].ident),
+				None => None,
+			};
+			let is_write = segments
+				.last()
+				.map_or(false, |f| STORAGE_WRITE_FUNCTIONS.iter().any(|w| f.ident == w));
+
+			let storage = name.filter(|_| is_write).and_then(|n| self.names.get(&n.to_string()));
+			if let (Some(name), Some(storage)) = (name, storage) {
+				self.writes.push((name.clone(), storage));
+			}
+		}
+
+		visit::visit_expr_call(self, call);
+	}
+}
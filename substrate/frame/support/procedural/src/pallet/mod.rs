@@ -29,10 +29,30 @@ mod expand;
 pub(crate) mod parse;
 
 pub use parse::{composite::keyword::CompositeKeyword, Def};
-use syn::spanned::Spanned;
+use syn::{parse::Parser, punctuated::Punctuated, spanned::Spanned};
 
 mod keyword {
 	syn::custom_keyword!(dev_mode);
+	syn::custom_keyword!(storage_analysis);
+}
+
+/// An argument of the pallet macro, e.g. `dev_mode` in `#[frame_support::pallet(dev_mode)]`.
+enum PalletMacroArg {
+	DevMode(keyword::dev_mode),
+	StorageAnalysis(keyword::storage_analysis),
+}
+
+impl syn::parse::Parse for PalletMacroArg {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let lookahead = input.lookahead1();
+		if lookahead.peek(keyword::dev_mode) {
+			Ok(Self::DevMode(input.parse()?))
+		} else if lookahead.peek(keyword::storage_analysis) {
+			Ok(Self::StorageAnalysis(input.parse()?))
+		} else {
+			Err(lookahead.error())
+		}
+	}
 }
 
 pub fn pallet(
@@ -40,14 +60,21 @@ pub fn pallet(
 	item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
 	let mut dev_mode = false;
+	let mut storage_analysis = false;
 	if !attr.is_empty() {
-		if let Ok(_) = syn::parse::<keyword::dev_mode>(attr.clone()) {
-			dev_mode = true;
+		let parser = Punctuated::<PalletMacroArg, syn::Token![,]>::parse_terminated;
+		if let Ok(args) = parser.parse(attr.clone()) {
+			for arg in args {
+				match arg {
+					PalletMacroArg::DevMode(_) => dev_mode = true,
+					PalletMacroArg::StorageAnalysis(_) => storage_analysis = true,
+				}
+			}
 		} else {
 			let msg = "Invalid pallet macro call: unexpected attribute. Macro call must be \
 				bare, such as `#[frame_support::pallet]` or `#[pallet]`, or must specify the \
-				`dev_mode` attribute, such as `#[frame_support::pallet(dev_mode)]` or \
-				#[pallet(dev_mode)].";
+				`dev_mode` and/or `storage_analysis` attributes, such as \
+				`#[frame_support::pallet(dev_mode)]` or #[pallet(dev_mode, storage_analysis)].";
 			let span = proc_macro2::TokenStream::from(attr).span();
 			return syn::Error::new(span, msg).to_compile_error().into()
 		}
@@ -55,7 +82,10 @@ pub fn pallet(
 
 	let item = syn::parse_macro_input!(item as syn::ItemMod);
 	match parse::Def::try_from(item, dev_mode) {
-		Ok(def) => expand::expand(def).into(),
+		Ok(mut def) => {
+			def.storage_analysis = storage_analysis;
+			expand::expand(def).into()
+		},
 		Err(e) => e.to_compile_error().into(),
 	}
 }
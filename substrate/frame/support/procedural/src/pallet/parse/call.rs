@@ -20,7 +20,7 @@ use frame_support_procedural_tools::get_doc_literals;
 use proc_macro2::Span;
 use quote::ToTokens;
 use std::collections::HashMap;
-use syn::{spanned::Spanned, ExprClosure};
+use syn::{parse::Parse, spanned::Spanned, ExprClosure};
 
 /// List of additional token to be used for parsing.
 mod keyword {
@@ -32,6 +32,10 @@ mod keyword {
 	syn::custom_keyword!(T);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(feeless_if);
+	syn::custom_keyword!(errors);
+	syn::custom_keyword!(allow_storage_warnings);
+	syn::custom_keyword!(whitelisted_write);
+	syn::custom_keyword!(aliased_write);
 }
 
 /// Definition of dispatchables typically `impl<T: Config> Pallet<T> { ... }`
@@ -86,6 +90,36 @@ pub struct CallVariantDef {
 	pub attrs: Vec<syn::Attribute>,
 	/// The optional `feeless_if` attribute on the `pallet::call`.
 	pub feeless_check: Option<syn::ExprClosure>,
+	/// The variants of the pallet `Error` the call can return, given with the optional
+	/// `#[pallet::errors(..)]` attribute.
+	pub errors: Vec<syn::Ident>,
+	/// The storage warnings allowed with the optional `#[pallet::allow_storage_warnings(..)]`
+	/// attribute.
+	pub allowed_storage_warnings: Vec<StorageWarningKind>,
+}
+
+/// A kind of warning of the storage analysis of `#[pallet(storage_analysis)]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StorageWarningKind {
+	/// A call writes a storage item declared with `#[pallet::whitelist_storage]`.
+	WhitelistedWrite,
+	/// A call writes the same storage item through different names, e.g. type aliases.
+	AliasedWrite,
+}
+
+impl syn::parse::Parse for StorageWarningKind {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let lookahead = input.lookahead1();
+		if lookahead.peek(keyword::whitelisted_write) {
+			input.parse::<keyword::whitelisted_write>()?;
+			Ok(Self::WhitelistedWrite)
+		} else if lookahead.peek(keyword::aliased_write) {
+			input.parse::<keyword::aliased_write>()?;
+			Ok(Self::AliasedWrite)
+		} else {
+			Err(lookahead.error())
+		}
+	}
 }
 
 /// Attributes for functions in call impl block.
@@ -96,6 +130,10 @@ pub enum FunctionAttr {
 	Weight(syn::Expr),
 	/// Parse for `#[pallet::feeless_if(expr)]`
 	FeelessIf(Span, syn::ExprClosure),
+	/// Parse for `#[pallet::errors(Variant, ..)]`
+	Errors(Span, Vec<syn::Ident>),
+	/// Parse for `#[pallet::allow_storage_warnings(kind, ..)]`
+	AllowStorageWarnings(Vec<StorageWarningKind>),
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -135,6 +173,32 @@ impl syn::parse::Parse for FunctionAttr {
 					err
 				})?,
 			))
+		} else if lookahead.peek(keyword::errors) {
+			content.parse::<keyword::errors>()?;
+			let errors_content;
+			syn::parenthesized!(errors_content in content);
+			let span = errors_content.span();
+			let errors = errors_content
+				.parse_terminated(syn::Ident::parse, syn::Token![,])
+				.map_err(|e| {
+					let msg = "Invalid pallet::errors attribute: expected a comma separated list \
+						of `Error` variants, e.g. `#[pallet::errors(InsufficientBalance)]`";
+					let mut err = syn::Error::new(span, msg);
+					err.combine(e);
+					err
+				})?;
+			if errors.is_empty() {
+				let msg = "Invalid pallet::errors attribute: expected at least one `Error` variant";
+				return Err(syn::Error::new(span, msg))
+			}
+			Ok(FunctionAttr::Errors(span, errors.into_iter().collect()))
+		} else if lookahead.peek(keyword::allow_storage_warnings) {
+			content.parse::<keyword::allow_storage_warnings>()?;
+			let allow_content;
+			syn::parenthesized!(allow_content in content);
+			let kinds =
+				allow_content.parse_terminated(StorageWarningKind::parse, syn::Token![,])?;
+			Ok(FunctionAttr::AllowStorageWarnings(kinds.into_iter().collect()))
 		} else {
 			Err(lookahead.error())
 		}
@@ -255,6 +319,8 @@ impl CallDef {
 				let mut call_idx_attrs = vec![];
 				let mut weight_attrs = vec![];
 				let mut feeless_attrs = vec![];
+				let mut errors_attrs = vec![];
+				let mut allowed_storage_warnings = vec![];
 				for attr in helper::take_item_pallet_attrs(&mut method.attrs)?.into_iter() {
 					match attr {
 						FunctionAttr::CallIndex(_) => {
@@ -266,6 +332,12 @@ impl CallDef {
 						FunctionAttr::FeelessIf(span, _) => {
 							feeless_attrs.push((span, attr));
 						},
+						FunctionAttr::Errors(span, errors) => {
+							errors_attrs.push((span, errors));
+						},
+						FunctionAttr::AllowStorageWarnings(kinds) => {
+							allowed_storage_warnings.extend(kinds);
+						},
 					}
 				}
 
@@ -421,6 +493,19 @@ impl CallDef {
 					}
 				}
 
+				if errors_attrs.len() > 1 {
+					let msg = "Invalid pallet::call, there can only be one errors attribute";
+					return Err(syn::Error::new(errors_attrs[1].0, msg))
+				}
+				let errors = errors_attrs.pop().map(|(_, errors)| errors).unwrap_or_default();
+				for (i, error) in errors.iter().enumerate() {
+					if errors[..i].contains(error) {
+						let msg =
+							format!("Invalid pallet::errors attribute, duplicated `{}`", error);
+						return Err(syn::Error::new(error.span(), msg))
+					}
+				}
+
 				methods.push(CallVariantDef {
 					name: method.sig.ident.clone(),
 					weight,
@@ -430,6 +515,8 @@ impl CallDef {
 					docs,
 					attrs: method.attrs.clone(),
 					feeless_check,
+					errors,
+					allowed_storage_warnings,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
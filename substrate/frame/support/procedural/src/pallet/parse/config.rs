@@ -36,6 +36,7 @@ mod keyword {
 	syn::custom_keyword!(no_default);
 	syn::custom_keyword!(no_default_bounds);
 	syn::custom_keyword!(constant);
+	syn::custom_keyword!(dynamic);
 }
 
 #[derive(Default)]
@@ -55,6 +56,8 @@ pub struct ConfigDef {
 	pub has_instance: bool,
 	/// Const associated type.
 	pub consts_metadata: Vec<ConstMetadataDef>,
+	/// Associated types declared as dynamic parameters with `#[pallet::dynamic]`.
+	pub dynamic_params: Vec<ConstMetadataDef>,
 	/// Whether the trait has the associated type `Event`, note that those bounds are
 	/// checked:
 	/// * `IsType<Self as frame_system::Config>::RuntimeEvent`
@@ -150,6 +153,8 @@ pub enum PalletAttrType {
 	NoBounds(keyword::no_default_bounds),
 	#[peek(keyword::constant, name = "constant")]
 	Constant(keyword::constant),
+	#[peek(keyword::dynamic, name = "dynamic")]
+	Dynamic(keyword::dynamic),
 }
 
 /// Parsing for `#[pallet::X]`
@@ -374,6 +379,7 @@ impl ConfigDef {
 
 		let mut has_event_type = false;
 		let mut consts_metadata = vec![];
+		let mut dynamic_params = vec![];
 		let mut default_sub_trait = if enable_default {
 			Some(DefaultTrait {
 				items: Default::default(),
@@ -388,6 +394,7 @@ impl ConfigDef {
 
 			let mut already_no_default = false;
 			let mut already_constant = false;
+			let mut already_dynamic = false;
 			let mut already_no_default_bounds = false;
 
 			while let Ok(Some(pallet_attr)) =
@@ -409,6 +416,27 @@ impl ConfigDef {
 							trait_item.span(),
 							"Invalid #[pallet::constant] in #[pallet::config], expected type item",
 						)),
+					(PalletAttrType::Dynamic(_), syn::TraitItem::Type(ref typ)) => {
+						if already_dynamic {
+							return Err(syn::Error::new(
+								pallet_attr._bracket.span.join(),
+								"Duplicate #[pallet::dynamic] attribute not allowed.",
+							))
+						}
+						already_dynamic = true;
+						dynamic_params.push(ConstMetadataDef::try_from(typ).map_err(|_| {
+							syn::Error::new(
+								typ.span(),
+								"Invalid #[pallet::dynamic] in #[pallet::config], expected a type \
+								with a single `Get<T>` bound",
+							)
+						})?);
+					},
+					(PalletAttrType::Dynamic(_), _) =>
+						return Err(syn::Error::new(
+							trait_item.span(),
+							"Invalid #[pallet::dynamic] in #[pallet::config], expected type item",
+						)),
 					(PalletAttrType::NoDefault(_), _) => {
 						if !enable_default {
 							return Err(syn::Error::new(
@@ -487,6 +515,7 @@ impl ConfigDef {
 			index,
 			has_instance,
 			consts_metadata,
+			dynamic_params,
 			has_event_type,
 			where_clause,
 			attr_span,
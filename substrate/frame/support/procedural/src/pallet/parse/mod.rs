@@ -36,6 +36,9 @@ pub mod storage;
 pub mod type_value;
 pub mod validate_unsigned;
 
+#[cfg(test)]
+pub mod tests;
+
 use composite::{keyword::CompositeKeyword, CompositeDef};
 use frame_support_procedural_tools::generate_access_from_frame_or_crate;
 use syn::spanned::Spanned;
@@ -63,6 +66,9 @@ pub struct Def {
 	pub frame_system: syn::Path,
 	pub frame_support: syn::Path,
 	pub dev_mode: bool,
+	/// Whether the storage accesses of the calls are analyzed, see
+	/// `expand::warnings::storage_write_warnings`.
+	pub storage_analysis: bool,
 }
 
 impl Def {
@@ -212,10 +218,12 @@ impl Def {
 			frame_system,
 			frame_support,
 			dev_mode,
+			storage_analysis: false,
 		};
 
 		def.check_instance_usage()?;
 		def.check_event_usage()?;
+		def.check_call_errors()?;
 
 		Ok(def)
 	}
@@ -240,6 +248,30 @@ impl Def {
 		}
 	}
 
+	/// Check that the errors given with `#[pallet::errors(..)]` on the dispatchables are variants
+	/// of the pallet `Error`.
+	fn check_call_errors(&self) -> syn::Result<()> {
+		let Some(call) = &self.call else { return Ok(()) };
+
+		for error in call.methods.iter().flat_map(|method| method.errors.iter()) {
+			let Some(error_def) = &self.error else {
+				let msg = "Invalid pallet::errors attribute, the pallet declares no `Error` \
+					(i.e. no use of `#[pallet::error]`)";
+				return Err(syn::Error::new(error.span(), msg))
+			};
+
+			if !error_def.variants.iter().any(|(variant, _, _)| variant == error) {
+				let msg = format!(
+					"Invalid pallet::errors attribute, `{}` is not a variant of the pallet `Error`",
+					error,
+				);
+				return Err(syn::Error::new(error.span(), msg))
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Check that usage of trait `Config` is consistent with the definition, i.e. it is used with
 	/// instance iff it is defined with instance.
 	fn check_instance_usage(&self) -> syn::Result<()> {
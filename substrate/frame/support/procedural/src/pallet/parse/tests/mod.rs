@@ -0,0 +1,154 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the pallet parser.
+
+use super::Def;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::sync::Mutex;
+
+/// Serializes the tests that change `CARGO_MANIFEST_DIR`.
+static MANIFEST_DIR: Mutex<()> = Mutex::new(());
+
+/// Parse `pallet` as if it was declared in a crate depending on `frame-support` and
+/// `frame-system`, so that the parser can find them.
+fn parse_pallet(pallet: TokenStream) -> syn::Result<Def> {
+	let _guard = MANIFEST_DIR.lock().unwrap_or_else(|e| e.into_inner());
+	let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR");
+	std::env::set_var(
+		"CARGO_MANIFEST_DIR",
+		std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../examples/basic"),
+	);
+
+	let def = Def::try_from(syn::parse2(pallet).expect("the pallet is a valid module"), false);
+
+	match manifest_dir {
+		Some(dir) => std::env::set_var("CARGO_MANIFEST_DIR", dir),
+		None => std::env::remove_var("CARGO_MANIFEST_DIR"),
+	}
+	def
+}
+
+/// Returns the error message of parsing `pallet`.
+fn parse_error(pallet: TokenStream) -> String {
+	match parse_pallet(pallet) {
+		Ok(_) => panic!("Expected the pallet to fail to parse"),
+		Err(e) => e.to_string(),
+	}
+}
+
+/// A pallet with a single dispatchable `foo` annotated with `call_attrs` and the `error` item.
+fn pallet_with(call_attrs: TokenStream, error: TokenStream) -> TokenStream {
+	quote! {
+		mod pallet {
+			#[pallet::config]
+			pub trait Config: frame_system::Config {}
+
+			#[pallet::pallet]
+			pub struct Pallet<T>(_);
+
+			#[pallet::call]
+			impl<T: Config> Pallet<T> {
+				#[pallet::call_index(0)]
+				#[pallet::weight(0)]
+				#call_attrs
+				pub fn foo(origin: OriginFor<T>) -> DispatchResult {
+					Ok(())
+				}
+			}
+
+			#error
+		}
+	}
+}
+
+fn error_enum() -> TokenStream {
+	quote! {
+		#[pallet::error]
+		pub enum Error<T> {
+			/// The first error.
+			First,
+			Second,
+		}
+	}
+}
+
+#[test]
+fn call_errors_are_parsed() {
+	let def = parse_pallet(pallet_with(quote!(#[pallet::errors(First, Second)]), error_enum()))
+		.unwrap();
+	let errors = &def.call.unwrap().methods[0].errors;
+	assert_eq!(errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(), vec!["First", "Second"]);
+
+	let def = parse_pallet(pallet_with(quote!(), error_enum())).unwrap();
+	assert!(def.call.unwrap().methods[0].errors.is_empty());
+}
+
+#[test]
+fn call_errors_must_not_be_empty() {
+	let error = parse_error(pallet_with(quote!(#[pallet::errors()]), error_enum()));
+	assert!(error.contains("expected at least one `Error` variant"), "{}", error);
+}
+
+#[test]
+fn call_errors_must_be_variant_idents() {
+	let error =
+		parse_error(pallet_with(quote!(#[pallet::errors(Error::<T>::First)]), error_enum()));
+	assert!(error.contains("expected a comma separated list of `Error` variants"), "{}", error);
+
+	let error = parse_error(pallet_with(quote!(#[pallet::errors = First]), error_enum()));
+	assert!(error.contains("expected parentheses"), "{}", error);
+}
+
+#[test]
+fn call_errors_must_not_be_duplicated() {
+	let error = parse_error(pallet_with(quote!(#[pallet::errors(First, First)]), error_enum()));
+	assert!(error.contains("duplicated `First`"), "{}", error);
+
+	let error = parse_error(pallet_with(
+		quote!(#[pallet::errors(First)] #[pallet::errors(Second)]),
+		error_enum(),
+	));
+	assert!(error.contains("there can only be one errors attribute"), "{}", error);
+}
+
+#[test]
+fn call_errors_must_be_declared() {
+	let error = parse_error(pallet_with(quote!(#[pallet::errors(Third)]), error_enum()));
+	assert!(error.contains("`Third` is not a variant of the pallet `Error`"), "{}", error);
+
+	let error = parse_error(pallet_with(quote!(#[pallet::errors(First)]), quote!()));
+	assert!(error.contains("the pallet declares no `Error`"), "{}", error);
+}
+
+#[test]
+fn allowed_storage_warnings_are_parsed() {
+	use crate::pallet::parse::call::StorageWarningKind;
+
+	let def = parse_pallet(pallet_with(
+		quote!(#[pallet::allow_storage_warnings(whitelisted_write, aliased_write)]),
+		quote!(),
+	))
+	.unwrap();
+	assert!(def.call.unwrap().methods[0].allowed_storage_warnings ==
+		vec![StorageWarningKind::WhitelistedWrite, StorageWarningKind::AliasedWrite]);
+
+	let error =
+		parse_error(pallet_with(quote!(#[pallet::allow_storage_warnings(all)]), quote!()));
+	assert!(error.contains("expected `whitelisted_write` or `aliased_write`"), "{}", error);
+}
@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support_procedural_tools::generate_access_from_frame_or_crate;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, Data, DeriveInput, Fields};
+
+// Derive `WorstCase`
+pub fn derive_worst_case(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = syn::parse_macro_input!(input as DeriveInput);
+	let name = &input.ident;
+
+	let frame_benchmarking = match generate_access_from_frame_or_crate("frame-benchmarking") {
+		Ok(c) => c,
+		Err(e) => return e.into_compile_error().into(),
+	};
+	let worst_case = quote!(#frame_benchmarking::v2::WorstCase);
+
+	let (constructor, fields) = match &input.data {
+		Data::Struct(struct_) => (quote!(Self), &struct_.fields),
+		Data::Enum(enum_) => {
+			let worst_case_variants = enum_
+				.variants
+				.iter()
+				.filter(|variant| {
+					variant.attrs.iter().any(|attr| attr.path().is_ident("worst_case"))
+				})
+				.collect::<Vec<_>>();
+
+			match &*worst_case_variants {
+				[variant] => {
+					let variant_name = &variant.ident;
+					(quote!(Self::#variant_name), &variant.fields)
+				},
+				[] =>
+					return syn::Error::new(
+						name.span(),
+						"no worst case declared, mark the variant with the largest encoding with \
+						`#[worst_case]`",
+					)
+					.into_compile_error()
+					.into(),
+				[_, additional, ..] =>
+					return syn::Error::new(
+						additional.span(),
+						"multiple worst case variants declared, only one variant may be marked \
+						with `#[worst_case]`",
+					)
+					.into_compile_error()
+					.into(),
+			}
+		},
+		Data::Union(union_) =>
+			return syn::Error::new(union_.union_token.span, "Union type not supported")
+				.into_compile_error()
+				.into(),
+	};
+
+	let field_value = |field: &syn::Field| -> TokenStream {
+		quote_spanned!(field.span() => <_ as #worst_case>::worst_case())
+	};
+	let value = match fields {
+		Fields::Named(named) => {
+			let values = named.named.iter().map(|field| {
+				let ident = &field.ident;
+				let value = field_value(field);
+				quote!(#ident: #value)
+			});
+			quote!(#constructor { #( #values, )* })
+		},
+		Fields::Unnamed(unnamed) => {
+			let values = unnamed.unnamed.iter().map(field_value);
+			quote!(#constructor( #( #values, )* ))
+		},
+		Fields::Unit => constructor,
+	};
+
+	// Bound the types of the fields rather than the generics, so that e.g. the bound of a
+	// `BoundedVec` need not be a worst case itself.
+	let mut generics = input.generics.clone();
+	let where_clause = generics.make_where_clause();
+	for field in fields.iter() {
+		let ty = &field.ty;
+		where_clause.predicates.push(parse_quote!(#ty: #worst_case));
+	}
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	quote!(
+		const _: () = {
+			impl #impl_generics #worst_case for #name #ty_generics #where_clause {
+				fn worst_case() -> Self {
+					#value
+				}
+			}
+		};
+	)
+	.into()
+}
@@ -80,7 +80,18 @@
 //!
 //! In your pallet you will only have to use [`with_context`], because as described above
 //! [`run_in_context`] will be handled by FRAME for you.
+//!
+//! # Nested calls
+//!
+//! Pallets dispatching calls on behalf of other calls, e.g. batches, proxies, multisigs or sudo,
+//! dispatch them with [`dispatch_nested`]. It uses the dispatch context to keep track of the depth
+//! of the nested calls, and rejects the calls exceeding the
+//! [`NestedCallLimits`](crate::traits::NestedCallLimits) of the runtime, which are configured once
+//! as `frame_system::Config::NestedCallLimits` and shared by all these pallets.
 
+use crate::traits::NestedCallLimits;
+use codec::Encode;
+use sp_runtime::DispatchError;
 use sp_std::{
 	any::{Any, TypeId},
 	boxed::Box,
@@ -196,6 +207,48 @@ pub fn run_in_context<R>(run: impl FnOnce() -> R) -> R {
 	DISPATCH_CONTEXT::using_once(&mut Default::default(), run)
 }
 
+/// The depth of the calls currently dispatched with [`dispatch_nested`].
+#[derive(Default)]
+struct NestedCallDepth(u32);
+
+/// Dispatch `call` on behalf of another call with `dispatch`, if `call` is within the limits `L`.
+///
+/// Pallets pass `frame_system::Config::NestedCallLimits` as `L`, so that the same limits apply to
+/// all nested calls.
+///
+/// Fails with [`DispatchError::Exhausted`] without running `dispatch` if `call` is longer than
+/// [`NestedCallLimits::max_encoded_len`], or if it would be dispatched beyond
+/// [`NestedCallLimits::max_depth`].
+pub fn dispatch_nested<L: NestedCallLimits, C: Encode, R, E: From<DispatchError>>(
+	call: C,
+	dispatch: impl FnOnce(C) -> Result<R, E>,
+) -> Result<R, E> {
+	if call.encoded_size() > L::max_encoded_len() as usize {
+		return Err(DispatchError::Exhausted.into())
+	}
+
+	run_in_context(|| {
+		let depth = with_context::<NestedCallDepth, _>(|depth| {
+			let depth = depth.or_default();
+			depth.0 = depth.0.saturating_add(1);
+			depth.0
+		})
+		.unwrap_or(u32::MAX);
+
+		let result = if depth > L::max_depth() {
+			Err(DispatchError::Exhausted.into())
+		} else {
+			dispatch(call)
+		};
+
+		with_context::<NestedCallDepth, _>(|depth| {
+			let depth = depth.or_default();
+			depth.0 = depth.0.saturating_sub(1);
+		});
+		result
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -229,4 +282,35 @@ mod tests {
 		// `run_in_context` calls.
 		assert_eq!(100, res);
 	}
+
+	#[test]
+	fn dispatch_nested_enforces_limits() {
+		use crate::traits::{ConstU32, NestedCallLimitsOf};
+
+		type Limits = NestedCallLimitsOf<ConstU32<2>, ConstU32<4>>;
+
+		fn nest(depth: u32) -> Result<u32, DispatchError> {
+			dispatch_nested::<Limits, _, _, _>(0u32, |_| {
+				if depth == 0 {
+					Ok(with_context::<NestedCallDepth, _>(|d| d.or_default().0).unwrap())
+				} else {
+					nest(depth - 1)
+				}
+			})
+		}
+
+		assert_eq!(nest(0), Ok(1));
+		assert_eq!(nest(1), Ok(2));
+		assert_eq!(nest(2), Err(DispatchError::Exhausted));
+
+		// The depth is restored after each nested call, also when it fails.
+		run_in_context(|| {
+			assert_eq!(nest(2), Err(DispatchError::Exhausted));
+			assert_eq!(nest(1), Ok(2));
+			assert_eq!(with_context::<NestedCallDepth, _>(|d| d.or_default().0), Some(0));
+		});
+
+		let dispatched = dispatch_nested::<Limits, _, _, DispatchError>(0u64, |_| Ok(()));
+		assert_eq!(dispatched, Err(DispatchError::Exhausted));
+	}
 }
@@ -2226,11 +2226,11 @@ pub use frame_support_procedural::pallet;
 /// Contains macro stubs for all of the pallet:: macros
 pub mod pallet_macros {
 	pub use frame_support_procedural::{
-		call_index, compact, composite_enum, config, disable_frame_system_supertrait_check, error,
-		event, extra_constants, feeless_if, generate_deposit, generate_store, getter, hooks,
-		import_section, inherent, no_default, no_default_bounds, origin, pallet_section,
-		storage_prefix, storage_version, type_value, unbounded, validate_unsigned, weight,
-		whitelist_storage,
+		allow_storage_warnings, call_index, compact, composite_enum, config,
+		disable_frame_system_supertrait_check, error, errors, event, extra_constants, feeless_if,
+		generate_deposit, generate_store, getter, hooks, import_section, inherent, no_default,
+		no_default_bounds, origin, pallet_section, storage_prefix, storage_version, type_value,
+		unbounded, validate_unsigned, weight, whitelist_storage,
 	};
 
 	/// Allows you to define the genesis configuration for the pallet.
@@ -2325,6 +2325,36 @@ pub mod pallet_macros {
 	/// ```
 	pub use frame_support_procedural::constant;
 
+	/// The `#[pallet::dynamic]` attribute can be used on an associated type trait bounded by
+	/// [`Get`](frame_support::pallet_prelude::Get) in [`pallet::config`](`macro@config`) to allow
+	/// the runtime to change its value without a runtime upgrade, e.g. through governance.
+	///
+	/// The macro generates a [`Key`](frame_support::traits::dynamic_params::Key) of the same name
+	/// as the associated type in the `dynamic_params` module of the pallet. The runtime can then
+	/// configure the associated type with
+	/// [`Dynamic`](frame_support::traits::dynamic_params::Dynamic) to read the parameter from a
+	/// [`ParameterStore`](frame_support::traits::dynamic_params::ParameterStore), like
+	/// `pallet-parameters`, and to fall back to a default value while it is not set.
+	///
+	/// ## Example
+	///
+	/// ```
+	/// #[frame_support::pallet]
+	/// mod pallet {
+	/// 	use frame_support::pallet_prelude::*;
+	/// 	# #[pallet::pallet]
+	/// 	# pub struct Pallet<T>(_);
+	/// 	#[pallet::config]
+	/// 	pub trait Config: frame_system::Config {
+	/// 		/// A deposit that governance can change, see `dynamic_params::Deposit`.
+	/// 		#[pallet::constant]
+	/// 		#[pallet::dynamic]
+	/// 		type Deposit: Get<u64>;
+	/// 	}
+	/// }
+	/// ```
+	pub use frame_support_procedural::dynamic;
+
 	/// Declares a type alias as a storage item. Storage items are pointers to data stored
 	/// on-chain (the *blockchain state*), under a specific key. The exact key is dependent on
 	/// the type of the storage.
@@ -288,6 +288,7 @@ where
 		V: StorageAppend<Item>,
 	{
 		let final_key = Self::storage_n_map_final_key::<K, _>(key);
+		crate::storage::metrics::note_write();
 		sp_io::storage::append(&final_key, item.encode());
 	}
 
@@ -156,6 +156,7 @@ impl<T: FullCodec, G: StorageValue<T>> storage::StorageValue<T> for G {
 		T: StorageAppend<Item>,
 	{
 		let key = Self::storage_value_final_key();
+		crate::storage::metrics::note_write();
 		sp_io::storage::append(&key, item.encode());
 	}
 }
@@ -0,0 +1,149 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instrumentation of the storage accesses done while dispatching a call.
+//!
+//! When the `storage-metrics` feature is enabled, every dispatch of a pallet call counts the
+//! storage reads and writes going through [`unhashed`](super::unhashed) (which backs all typed
+//! storage items) and the number of rolled back [transactional](super::transactional) layers.
+//! Once the outermost call returns, the counters are handed to the node through
+//! [`sp_io::storage_metrics::report`], which the node can export to Prometheus by registering the
+//! [`sp_io::StorageMetricsExt`] extension.
+//!
+//! Nested dispatches (e.g. calls dispatched by `pallet-utility`) are attributed to the outermost
+//! call, which means one report is issued per extrinsic.
+//!
+//! Without the feature all functions of this module compile down to nothing.
+//!
+//! # Warning
+//!
+//! Enabling the `storage-metrics` feature requires the `StorageMetrics::report` host function to
+//! be supported by the host. Do *not* enable it for your runtime without first upgrading your
+//! client.
+
+/// Storage access counters accumulated while dispatching a call.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StorageAccessCounters {
+	/// Number of storage reads.
+	pub reads: u32,
+	/// Number of storage writes (including removals and appends).
+	pub writes: u32,
+	/// Number of transactional layers that were rolled back.
+	pub rollbacks: u32,
+}
+
+#[cfg(feature = "storage-metrics")]
+environmental::environmental!(STORAGE_METRICS: StorageAccessCounters);
+
+/// Execute `f` while counting the storage accesses it performs and report them for `call` of
+/// `pallet` once it returns.
+///
+/// If the counters are already being collected by an outer call, the accesses of `f` are
+/// attributed to that outer call and nothing is reported.
+pub fn with_metrics<R>(pallet: &str, call: &str, f: impl FnOnce() -> R) -> R {
+	#[cfg(feature = "storage-metrics")]
+	{
+		if STORAGE_METRICS::with(|_| ()).is_some() {
+			return f()
+		}
+
+		let mut counters = StorageAccessCounters::default();
+		let result = STORAGE_METRICS::using(&mut counters, f);
+		sp_io::storage_metrics::report(
+			pallet,
+			call,
+			counters.reads,
+			counters.writes,
+			counters.rollbacks,
+		);
+		result
+	}
+
+	#[cfg(not(feature = "storage-metrics"))]
+	{
+		let _ = (pallet, call);
+		f()
+	}
+}
+
+/// Returns the counters accumulated so far by the enclosing [`with_metrics`] call.
+///
+/// Returns `None` outside of [`with_metrics`] or when the `storage-metrics` feature is disabled.
+pub fn current() -> Option<StorageAccessCounters> {
+	#[cfg(feature = "storage-metrics")]
+	{
+		STORAGE_METRICS::with(|c| *c)
+	}
+
+	#[cfg(not(feature = "storage-metrics"))]
+	{
+		None
+	}
+}
+
+/// Note a storage read.
+#[inline]
+pub(crate) fn note_read() {
+	#[cfg(feature = "storage-metrics")]
+	STORAGE_METRICS::with(|c| c.reads = c.reads.saturating_add(1));
+}
+
+/// Note a storage write.
+#[inline]
+pub(crate) fn note_write() {
+	#[cfg(feature = "storage-metrics")]
+	STORAGE_METRICS::with(|c| c.writes = c.writes.saturating_add(1));
+}
+
+/// Note a rolled back transactional layer.
+#[inline]
+pub(crate) fn note_rollback() {
+	#[cfg(feature = "storage-metrics")]
+	STORAGE_METRICS::with(|c| c.rollbacks = c.rollbacks.saturating_add(1));
+}
+
+#[cfg(all(test, feature = "storage-metrics"))]
+mod tests {
+	use super::*;
+	use crate::storage::{transactional::with_transaction, unhashed};
+	use sp_io::TestExternalities;
+	use sp_runtime::{DispatchError, TransactionOutcome};
+
+	#[test]
+	fn counts_accesses_of_the_outermost_call() {
+		TestExternalities::default().execute_with(|| {
+			with_metrics("Test", "outer", || {
+				unhashed::put(b"foo", &1u32);
+				let _ = unhashed::get::<u32>(b"foo");
+
+				with_metrics("Test", "inner", || {
+					unhashed::kill(b"foo");
+				});
+
+				let _: Result<(), DispatchError> =
+					with_transaction(|| TransactionOutcome::Rollback(Ok(())));
+
+				let counters = current().unwrap();
+				assert_eq!(counters.rollbacks, 1);
+				assert!(counters.reads >= 1);
+				assert!(counters.writes >= 2);
+			});
+
+			assert!(current().is_none());
+		});
+	}
+}
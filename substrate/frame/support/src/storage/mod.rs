@@ -46,6 +46,7 @@ pub mod child;
 #[doc(hidden)]
 pub mod generator;
 pub mod hashed;
+pub mod metrics;
 pub mod migration;
 pub mod storage_noop_guard;
 mod stream_iter;
@@ -1604,6 +1605,7 @@ where
 			// NOTE: we cannot reuse the implementation for `Vec<T>` here because we never want to
 			// mark `BoundedVec<T, S>` as `StorageAppend`.
 			let key = Self::storage_value_final_key();
+			crate::storage::metrics::note_write();
 			sp_io::storage::append(&key, item.encode());
 			Ok(())
 		} else {
@@ -1638,6 +1640,7 @@ where
 		let current = Self::decode_len(key.clone()).unwrap_or_default();
 		if current < bound {
 			let key = Self::storage_map_final_key(key);
+			crate::storage::metrics::note_write();
 			sp_io::storage::append(&key, item.encode());
 			Ok(())
 		} else {
@@ -1683,6 +1686,7 @@ where
 		let current = Self::decode_len(key1.clone(), key2.clone()).unwrap_or_default();
 		if current < bound {
 			let double_map_key = Self::storage_double_map_final_key(key1, key2);
+			crate::storage::metrics::note_write();
 			sp_io::storage::append(&double_map_key, item.encode());
 			Ok(())
 		} else {
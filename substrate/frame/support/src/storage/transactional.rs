@@ -122,6 +122,7 @@ where
 		},
 		TransactionOutcome::Rollback(res) => {
 			rollback_transaction();
+			super::metrics::note_rollback();
 			res
 		},
 	}
@@ -159,6 +160,7 @@ where
 		},
 		TransactionOutcome::Rollback(res) => {
 			rollback_transaction();
+			super::metrics::note_rollback();
 			res
 		},
 	}
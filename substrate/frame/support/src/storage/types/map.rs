@@ -172,6 +172,20 @@ where
 		<Self as crate::storage::StorageMap<Key, Value>>::get(key)
 	}
 
+	/// Load the values associated with each of the given keys from the map, in the same order.
+	///
+	/// All values are read with a single call into the host, which is cheaper than calling
+	/// [`Self::get`] for each key.
+	pub fn get_many<KeyArg: EncodeLike<Key>>(
+		keys: impl IntoIterator<Item = KeyArg>,
+	) -> Vec<QueryKind::Query> {
+		let keys = keys.into_iter().map(Self::hashed_key_for).collect::<Vec<_>>();
+		crate::storage::unhashed::get_many(&keys)
+			.into_iter()
+			.map(QueryKind::from_optional_value_to_query)
+			.collect()
+	}
+
 	/// Try to get the value for the given key from the map.
 	///
 	/// Returns `Ok` if it exists, `Err` if not.
@@ -603,6 +617,22 @@ mod test {
 		assert_eq!(KeyLenOf::<C>::get(), size);
 	}
 
+	#[test]
+	fn get_many_works() {
+		type A = StorageMap<Prefix, Blake2_128Concat, u16, u32, OptionQuery>;
+		type B = StorageMap<Prefix, Blake2_128Concat, u16, u32, ValueQuery, ADefault>;
+
+		TestExternalities::default().execute_with(|| {
+			assert_eq!(A::get_many(Vec::<u16>::new()), vec![]);
+
+			A::insert(1, 10);
+			A::insert(3, 30);
+			assert_eq!(A::get_many([3, 2, 1]), vec![Some(30), None, Some(10)]);
+			assert_eq!(A::get_many([3, 2, 1]), vec![A::get(3), A::get(2), A::get(1)]);
+			assert_eq!(B::get_many([1, 2]), vec![10, 97]);
+		})
+	}
+
 	#[test]
 	fn test() {
 		type A = StorageMap<Prefix, Blake2_128Concat, u16, u32, OptionQuery>;
@@ -22,17 +22,34 @@ use sp_std::prelude::*;
 
 /// Return the value of the item in storage under `key`, or `None` if there is no explicit entry.
 pub fn get<T: Decode + Sized>(key: &[u8]) -> Option<T> {
-	sp_io::storage::get(key).and_then(|val| {
-		Decode::decode(&mut &val[..]).map(Some).unwrap_or_else(|e| {
-			// TODO #3700: error should be handleable.
-			log::error!(
-				target: "runtime::storage",
-				"Corrupted state at `{:?}: {:?}`",
-				key,
-				e,
-			);
-			None
-		})
+	super::metrics::note_read();
+	sp_io::storage::get(key).and_then(|val| decode_value(key, &val))
+}
+
+/// Return the value of the item in storage under each of `keys`, in the same order, or `None`
+/// for the keys that have no explicit entry.
+///
+/// All keys are read with a single call into the host, which is cheaper than calling [`get`] for
+/// each of them.
+pub fn get_many<T: Decode + Sized>(keys: &[Vec<u8>]) -> Vec<Option<T>> {
+	get_raw_many(keys)
+		.into_iter()
+		.zip(keys)
+		.map(|(value, key)| value.and_then(|val| decode_value(key, &val)))
+		.collect()
+}
+
+/// Decode the `value` stored under `key`, logging an error if the state is corrupted.
+fn decode_value<T: Decode + Sized>(key: &[u8], value: &[u8]) -> Option<T> {
+	Decode::decode(&mut &value[..]).map(Some).unwrap_or_else(|e| {
+		// TODO #3700: error should be handleable.
+		log::error!(
+			target: "runtime::storage",
+			"Corrupted state at `{:?}: {:?}`",
+			key,
+			e,
+		);
+		None
 	})
 }
 
@@ -56,6 +73,7 @@ pub fn get_or_else<T: Decode + Sized, F: FnOnce() -> T>(key: &[u8], default_valu
 
 /// Put `value` in storage under `key`.
 pub fn put<T: Encode + ?Sized>(key: &[u8], value: &T) {
+	super::metrics::note_write();
 	value.using_encoded(|slice| sp_io::storage::set(key, slice));
 }
 
@@ -88,11 +106,13 @@ pub fn take_or_else<T: Decode + Sized, F: FnOnce() -> T>(key: &[u8], default_val
 
 /// Check to see if `key` has an explicit entry in storage.
 pub fn exists(key: &[u8]) -> bool {
+	super::metrics::note_read();
 	sp_io::storage::exists(key)
 }
 
 /// Ensure `key` has no explicit entry in storage.
 pub fn kill(key: &[u8]) {
+	super::metrics::note_write();
 	sp_io::storage::clear(key);
 }
 
@@ -102,6 +122,7 @@ pub fn kill_prefix(prefix: &[u8], limit: Option<u32>) -> sp_io::KillStorageResul
 	// TODO: Once the network has upgraded to include the new host functions, this code can be
 	// enabled.
 	// clear_prefix(prefix, limit).into()
+	super::metrics::note_write();
 	sp_io::storage::clear_prefix(prefix, limit)
 }
 
@@ -158,6 +179,7 @@ pub fn clear_prefix(
 /// and is longer than said prefix.
 /// This means that a key which equals the prefix will not be counted.
 pub fn contains_prefixed_key(prefix: &[u8]) -> bool {
+	super::metrics::note_read();
 	match sp_io::storage::next_key(prefix) {
 		Some(key) => key.starts_with(prefix),
 		None => false,
@@ -166,14 +188,22 @@ pub fn contains_prefixed_key(prefix: &[u8]) -> bool {
 
 /// Get a Vec of bytes from storage.
 pub fn get_raw(key: &[u8]) -> Option<Vec<u8>> {
+	super::metrics::note_read();
 	sp_io::storage::get(key).map(|value| value.to_vec())
 }
 
+/// Get a Vec of bytes from storage for each of `keys`, with a single call into the host.
+pub fn get_raw_many(keys: &[Vec<u8>]) -> Vec<Option<Vec<u8>>> {
+	keys.iter().for_each(|_| super::metrics::note_read());
+	sp_io::storage::batch_get(keys)
+}
+
 /// Put a raw byte slice into storage.
 ///
 /// **WARNING**: If you set the storage of the Substrate Wasm (`well_known_keys::CODE`),
 /// you should also call `frame_system::RuntimeUpgraded::put(true)` to trigger the
 /// `on_runtime_upgrade` logic.
 pub fn put_raw(key: &[u8], value: &[u8]) {
+	super::metrics::note_write();
 	sp_io::storage::set(key, value)
 }
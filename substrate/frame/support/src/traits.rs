@@ -36,8 +36,9 @@ mod members;
 pub use members::{AllowAll, DenyAll, Filter};
 pub use members::{
 	AsContains, ChangeMembers, Contains, ContainsLengthBound, ContainsPair, Equals, Everything,
-	EverythingBut, FromContainsPair, InitializeMembers, InsideBoth, IsInVec, Nothing,
-	RankedMembers, SortedMembers, TheseExcept,
+	EverythingBut, FromContainsPair, InitializeMembers, InsideBoth, IsInVec, MemberLeaf,
+	MemberProof, MembershipSnapshot, Nothing, RankedMembers, SnapshotMembers, SortedMembers,
+	TheseExcept, VersionedMembershipSnapshot,
 };
 
 mod validation;
@@ -88,6 +89,7 @@ pub use hooks::{
 	OnIdle, OnInitialize, OnRuntimeUpgrade, OnTimestampSet,
 };
 
+pub mod dynamic_params;
 pub mod schedule;
 mod storage;
 pub use storage::{
@@ -100,8 +102,9 @@ mod dispatch;
 pub use dispatch::EnsureOneOf;
 pub use dispatch::{
 	AsEnsureOriginWithArg, CallerTrait, EitherOf, EitherOfDiverse, EnsureOrigin,
-	EnsureOriginEqualOrHigherPrivilege, EnsureOriginWithArg, MapSuccess, NeverEnsureOrigin,
-	OriginTrait, TryMapSuccess, TryWithMorphedArg, UnfilteredDispatchable,
+	EnsureOriginEqualOrHigherPrivilege, EnsureOriginWithArg, MapSuccess, NestedCallLimits,
+	NestedCallLimitsOf, NeverEnsureOrigin, OriginTrait, TryMapSuccess, TryWithMorphedArg,
+	UnfilteredDispatchable, DEFAULT_MAX_NESTED_CALL_DEPTH,
 };
 
 mod voting;
@@ -112,9 +115,9 @@ pub use preimages::{Bounded, BoundedInline, FetchResult, QueryPreimage, StorePre
 
 mod messages;
 pub use messages::{
-	EnqueueMessage, EnqueueWithOrigin, ExecuteOverweightError, HandleMessage, NoopServiceQueues,
-	ProcessMessage, ProcessMessageError, QueueFootprint, QueuePausedQuery, ServiceQueues,
-	TransformOrigin,
+	DescribeMessage, EnqueueMessage, EnqueueWithOrigin, ExecuteOverweightError, HandleMessage,
+	NoopServiceQueues, ProcessMessage, ProcessMessageError, QueueFootprint, QueuePausedQuery,
+	ServiceQueues, TransformOrigin,
 };
 
 mod safe_mode;
@@ -127,6 +130,6 @@ pub use tx_pause::{TransactionPause, TransactionPauseError};
 mod try_runtime;
 #[cfg(feature = "try-runtime")]
 pub use try_runtime::{
-	Select as TryStateSelect, TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState,
-	UpgradeCheckSelect,
+	Select as TryStateSelect, Severity as TryStateSeverity, TryDecodeEntireStorage,
+	TryDecodeEntireStorageError, TryState, UpgradeCheckSelect,
 };
@@ -449,6 +449,52 @@ pub trait UnfilteredDispatchable {
 	fn dispatch_bypass_filter(self, origin: Self::RuntimeOrigin) -> DispatchResultWithPostInfo;
 }
 
+/// The default maximum depth of [`NestedCallLimits`].
+pub const DEFAULT_MAX_NESTED_CALL_DEPTH: u32 = 8;
+
+/// Limits of the calls dispatched by other calls, e.g. by batches, proxies, multisigs or sudo.
+///
+/// The limits are enforced by
+/// [`dispatch_nested`](crate::dispatch_context::dispatch_nested), which keeps track of the depth
+/// of the nested calls across all pallets dispatching them. The runtime configures them as
+/// `frame_system::Config::NestedCallLimits`.
+///
+/// `()` limits the depth to [`DEFAULT_MAX_NESTED_CALL_DEPTH`] and does not limit the length of
+/// the calls beyond the length of the extrinsic.
+pub trait NestedCallLimits {
+	/// The maximum number of calls dispatched by other calls which may be dispatched at the same
+	/// time, i.e. a call dispatched by a call dispatched by an extrinsic has depth 2.
+	fn max_depth() -> u32;
+
+	/// The maximum encoded length of a call dispatched by another call.
+	fn max_encoded_len() -> u32;
+}
+
+impl NestedCallLimits for () {
+	fn max_depth() -> u32 {
+		DEFAULT_MAX_NESTED_CALL_DEPTH
+	}
+
+	fn max_encoded_len() -> u32 {
+		u32::MAX
+	}
+}
+
+/// [`NestedCallLimits`] given by `MaxDepth` and `MaxEncodedLen`.
+pub struct NestedCallLimitsOf<MaxDepth, MaxEncodedLen>(PhantomData<(MaxDepth, MaxEncodedLen)>);
+
+impl<MaxDepth: Get<u32>, MaxEncodedLen: Get<u32>> NestedCallLimits
+	for NestedCallLimitsOf<MaxDepth, MaxEncodedLen>
+{
+	fn max_depth() -> u32 {
+		MaxDepth::get()
+	}
+
+	fn max_encoded_len() -> u32 {
+		MaxEncodedLen::get()
+	}
+}
+
 /// The trait implemented by the overarching enumeration of the different pallets' origins.
 /// Unlike `OriginTrait` impls, this does not include any kind of dispatch/call filter. Also, this
 /// trait is more flexible in terms of how it can be used: it is a `Parameter` and `Member`, so it
@@ -0,0 +1,141 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for parameters that can be changed at runtime, e.g. by governance, without a runtime
+//! upgrade.
+//!
+//! A `Get` item of a pallet `Config` can be declared `#[pallet::dynamic]`, which makes the pallet
+//! macro generate a [`Key`] for it in the `dynamic_params` module of the pallet. The runtime can
+//! then configure it with [`Dynamic`] to read it from a [`ParameterStore`], such as
+//! `pallet-parameters`:
+//!
+//! ```ignore
+//! impl pallet_identity::Config for Runtime {
+//! 	type BasicDeposit = Dynamic<
+//! 		Parameters,
+//! 		pallet_identity::dynamic_params::BasicDeposit<Runtime>,
+//! 		ConstU128<{ 10 * DOLLARS }>,
+//! 	>;
+//! 	// ...
+//! }
+//! ```
+
+use crate::traits::Get;
+use codec::Decode;
+use sp_std::marker::PhantomData;
+
+/// The identifier of a dynamic parameter.
+///
+/// It is the concatenation of the `twox_128` hashes of the name of the pallet and the name of the
+/// parameter, see [`parameter_id`].
+pub type ParameterId = [u8; 32];
+
+/// Returns the [`ParameterId`] of the parameter `name` of the pallet `pallet_name`.
+pub fn parameter_id(pallet_name: &str, name: &str) -> ParameterId {
+	let mut id = [0u8; 32];
+	id[..16].copy_from_slice(&sp_io::hashing::twox_128(pallet_name.as_bytes()));
+	id[16..].copy_from_slice(&sp_io::hashing::twox_128(name.as_bytes()));
+	id
+}
+
+/// The typed key of a dynamic parameter.
+pub trait Key {
+	/// The type of the value of the parameter.
+	type Value: Decode;
+
+	/// The name of the parameter within its pallet.
+	const NAME: &'static str;
+
+	/// The name of the pallet the parameter belongs to, as configured in the runtime.
+	fn pallet_name() -> &'static str;
+
+	/// The identifier of the parameter.
+	fn id() -> ParameterId {
+		parameter_id(Self::pallet_name(), Self::NAME)
+	}
+}
+
+/// A store of dynamic parameters.
+pub trait ParameterStore {
+	/// Returns the value of the parameter `K`, or `None` if it is not set.
+	fn get<K: Key>() -> Option<K::Value>;
+}
+
+/// A store without any parameter set.
+impl ParameterStore for () {
+	fn get<K: Key>() -> Option<K::Value> {
+		None
+	}
+}
+
+/// A [`Get`] implementation returning the value of the parameter `K` in `Store`, or the value of
+/// `Default` if the parameter is not set.
+pub struct Dynamic<Store, K, Default>(PhantomData<(Store, K, Default)>);
+
+impl<Store, K, Default> Get<K::Value> for Dynamic<Store, K, Default>
+where
+	Store: ParameterStore,
+	K: Key,
+	Default: Get<K::Value>,
+{
+	fn get() -> K::Value {
+		Store::get::<K>().unwrap_or_else(Default::get)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::traits::ConstU32;
+	use codec::Encode;
+
+	struct Deposit;
+
+	impl Key for Deposit {
+		type Value = u32;
+		const NAME: &'static str = "Deposit";
+
+		fn pallet_name() -> &'static str {
+			"Identity"
+		}
+	}
+
+	struct Store;
+
+	impl ParameterStore for Store {
+		fn get<K: Key>() -> Option<K::Value> {
+			if K::id() != parameter_id("Identity", "Deposit") {
+				return None
+			}
+			K::Value::decode(&mut &5u32.encode()[..]).ok()
+		}
+	}
+
+	#[test]
+	fn dynamic_falls_back_to_the_default() {
+		assert_eq!(<Dynamic<(), Deposit, ConstU32<3>>>::get(), 3);
+		assert_eq!(<Dynamic<Store, Deposit, ConstU32<3>>>::get(), 5);
+	}
+
+	#[test]
+	fn parameter_ids_are_distinct() {
+		assert_ne!(parameter_id("Identity", "Deposit"), parameter_id("Identity", "Deposits"));
+		assert_ne!(parameter_id("Identity", "Deposit"), parameter_id("Society", "Deposit"));
+		let id = parameter_id("Identity", "Deposit");
+		assert_eq!(id[..16], sp_io::hashing::twox_128(b"Identity"));
+	}
+}
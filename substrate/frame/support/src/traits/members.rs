@@ -17,9 +17,11 @@
 
 //! Traits for dealing with the idea of membership.
 
+use codec::{Decode, Encode};
 use impl_trait_for_tuples::impl_for_tuples;
+use scale_info::TypeInfo;
 use sp_arithmetic::traits::AtLeast16BitUnsigned;
-use sp_runtime::DispatchResult;
+use sp_runtime::{traits::Hash, DispatchResult, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// A trait for querying whether a type can be said to "contain" a value.
@@ -217,6 +219,30 @@ mod tests {
 			assert_eq!(OneOrTenToTwenty::contains(&i), i == 1 || i >= 10 && i <= 20);
 		}
 	}
+
+	#[test]
+	fn membership_snapshot_proofs_work() {
+		use sp_runtime::traits::BlakeTwo256;
+
+		let members = vec![(3u64, Some(1)), (1, Some(0)), (2, None)];
+		let snapshot = MembershipSnapshot::new::<BlakeTwo256>(members, Some(2));
+		assert_eq!(snapshot.members, vec![(1, Some(0)), (2, None), (3, Some(1))]);
+		assert!(snapshot.prove::<BlakeTwo256>(&4).is_none());
+
+		let proof = snapshot.prove::<BlakeTwo256>(&2).unwrap();
+		assert_eq!(proof.leaf, MemberLeaf { who: 2, rank: None, prime: true });
+		assert!(proof.verify::<BlakeTwo256>(&snapshot.root));
+
+		let mut forged = proof.clone();
+		forged.leaf.rank = Some(2);
+		assert!(!forged.verify::<BlakeTwo256>(&snapshot.root));
+		let mut forged = snapshot.prove::<BlakeTwo256>(&3).unwrap();
+		forged.leaf.prime = true;
+		assert!(!forged.verify::<BlakeTwo256>(&snapshot.root));
+
+		let versioned = VersionedMembershipSnapshot::from(snapshot.clone());
+		assert_eq!(versioned.root(), &snapshot.root);
+	}
 }
 
 /// A trait for a set which can enumerate its members in order.
@@ -391,3 +417,125 @@ impl<T: Clone + Ord> ChangeMembers<T> for () {
 	fn set_members_sorted(_: &[T], _: &[T]) {}
 	fn set_prime(_: Option<T>) {}
 }
+
+/// A member of a [`MembershipSnapshot`], as committed to by a leaf of its merkle tree.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MemberLeaf<AccountId> {
+	/// The account of the member.
+	pub who: AccountId,
+	/// The rank of the member, if the membership set ranks its members.
+	pub rank: Option<u16>,
+	/// Whether the member is the prime member.
+	pub prime: bool,
+}
+
+/// A snapshot of a membership set, committed to by the root of a binary merkle tree whose leaves
+/// are the encoded [`MemberLeaf`]s of the members, in order of their accounts.
+///
+/// Chains which mirror the membership set learn the root through a trusted channel, such as a
+/// bridge or the state proof of the chain, and can verify any member against it with a
+/// [`MemberProof`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MembershipSnapshot<AccountId, Hash> {
+	/// The members and their ranks, ordered by account.
+	pub members: Vec<(AccountId, Option<u16>)>,
+	/// The prime member, if any.
+	pub prime: Option<AccountId>,
+	/// The merkle root of the member leaves.
+	pub root: Hash,
+}
+
+impl<AccountId: Encode + Ord + Clone, Output> MembershipSnapshot<AccountId, Output> {
+	/// Take a snapshot of `members`, which need not be sorted, and their `prime`.
+	pub fn new<H: Hash<Output = Output>>(
+		mut members: Vec<(AccountId, Option<u16>)>,
+		prime: Option<AccountId>,
+	) -> Self {
+		members.sort_by(|a, b| a.0.cmp(&b.0));
+		let root = binary_merkle_tree::merkle_root::<H, _>(Self::leaves(&members, &prime));
+		Self { members, prime, root }
+	}
+
+	/// Prove the membership of `who` against the root of the snapshot.
+	pub fn prove<H: Hash<Output = Output>>(
+		&self,
+		who: &AccountId,
+	) -> Option<MemberProof<AccountId, Output>> {
+		let index = self.members.binary_search_by(|(m, _)| m.cmp(who)).ok()?;
+		let leaves = Self::leaves(&self.members, &self.prime);
+		let proof = binary_merkle_tree::merkle_proof::<H, _, _>(leaves, index);
+		Some(MemberProof {
+			leaf: MemberLeaf {
+				who: who.clone(),
+				rank: self.members[index].1,
+				prime: self.prime.as_ref() == Some(who),
+			},
+			leaf_index: index as u32,
+			number_of_leaves: self.members.len() as u32,
+			proof: proof.proof,
+		})
+	}
+
+	fn leaves(members: &[(AccountId, Option<u16>)], prime: &Option<AccountId>) -> Vec<Vec<u8>> {
+		members
+			.iter()
+			.map(|(who, rank)| (who, rank, prime.as_ref() == Some(who)).encode())
+			.collect()
+	}
+}
+
+/// A proof that a [`MemberLeaf`] is committed to by the root of a [`MembershipSnapshot`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MemberProof<AccountId, Hash> {
+	/// The proven member.
+	pub leaf: MemberLeaf<AccountId>,
+	/// The index of the leaf of the member.
+	pub leaf_index: u32,
+	/// The number of members in the snapshot.
+	pub number_of_leaves: u32,
+	/// The inner nodes of the merkle tree needed to reconstruct the root from the leaf.
+	pub proof: Vec<Hash>,
+}
+
+impl<AccountId: Encode, Output> MemberProof<AccountId, Output> {
+	/// Whether the proof is valid against `root`.
+	pub fn verify<H: Hash<Output = Output>>(&self, root: &Output) -> bool {
+		binary_merkle_tree::verify_proof::<H, _, _>(
+			root,
+			self.proof.clone(),
+			self.number_of_leaves as usize,
+			self.leaf_index as usize,
+			&self.leaf.encode(),
+		)
+	}
+}
+
+/// A versioned [`MembershipSnapshot`], for consumption outside of the chain.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum VersionedMembershipSnapshot<AccountId, Hash> {
+	#[codec(index = 1)]
+	V1(MembershipSnapshot<AccountId, Hash>),
+}
+
+impl<AccountId, Hash> VersionedMembershipSnapshot<AccountId, Hash> {
+	/// The merkle root of the member leaves.
+	pub fn root(&self) -> &Hash {
+		match self {
+			Self::V1(snapshot) => &snapshot.root,
+		}
+	}
+}
+
+impl<AccountId, Hash> From<MembershipSnapshot<AccountId, Hash>>
+	for VersionedMembershipSnapshot<AccountId, Hash>
+{
+	fn from(snapshot: MembershipSnapshot<AccountId, Hash>) -> Self {
+		Self::V1(snapshot)
+	}
+}
+
+/// A membership set of which a [`MembershipSnapshot`] can be taken.
+pub trait SnapshotMembers<AccountId, Hash> {
+	/// Take a snapshot of the current members.
+	fn membership_snapshot() -> MembershipSnapshot<AccountId, Hash>;
+}
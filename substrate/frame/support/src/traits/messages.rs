@@ -64,6 +64,25 @@ pub trait ProcessMessage {
 	) -> Result<bool, ProcessMessageError>;
 }
 
+/// Describes a message in a human readable way, e.g. for inspecting the contents of a queue.
+pub trait DescribeMessage {
+	/// The description of a message.
+	type Summary: FullCodec + TypeInfo + Debug;
+
+	/// Describe the given `message`.
+	///
+	/// Returns `None` if the message cannot be described, e.g. because it does not decode.
+	fn describe(message: &[u8]) -> Option<Self::Summary>;
+}
+
+impl DescribeMessage for () {
+	type Summary = ();
+
+	fn describe(_message: &[u8]) -> Option<Self::Summary> {
+		None
+	}
+}
+
 /// Errors that can happen when attempting to execute an overweight message with
 /// [`ServiceQueues::execute_overweight()`].
 #[derive(Eq, PartialEq, RuntimeDebug)]
@@ -139,6 +139,42 @@ impl core::str::FromStr for UpgradeCheckSelect {
 	}
 }
 
+/// The severity of an invariant checked in [`TryState::try_state`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Severity {
+	/// Breaking the invariant is reported, but does not fail the checks.
+	///
+	/// Allows adopting a new invariant while the existing state of some chains is known to break
+	/// it, e.g. because of legacy discrepancies which are yet to be fixed.
+	Warning,
+	/// Breaking the invariant fails the checks.
+	Critical,
+}
+
+impl Severity {
+	/// Check an invariant of this severity, `holds` being whether it holds.
+	///
+	/// A broken critical invariant returns `error`, while a broken warning is only logged.
+	pub fn ensure(
+		self,
+		holds: bool,
+		error: impl Into<TryRuntimeError>,
+	) -> Result<(), TryRuntimeError> {
+		if holds {
+			return Ok(())
+		}
+
+		let error = error.into();
+		match self {
+			Severity::Warning => {
+				log::warn!(target: "try-runtime", "Invariant broken (warning): {:?}", error);
+				Ok(())
+			},
+			Severity::Critical => Err(error),
+		}
+	}
+}
+
 /// Execute some checks to ensure the internal state of a pallet is consistent.
 ///
 /// Usually, these checks should check all of the invariants that are expected to be held on all of
@@ -222,3 +258,18 @@ impl<BlockNumber: Clone + sp_std::fmt::Debug + AtLeast32BitUnsigned> TryState<Bl
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn severity_ensure_works() {
+		assert_eq!(Severity::Warning.ensure(true, "broken"), Ok(()));
+		assert_eq!(Severity::Critical.ensure(true, "broken"), Ok(()));
+
+		// only critical invariants fail the checks.
+		assert_eq!(Severity::Warning.ensure(false, "broken"), Ok(()));
+		assert_eq!(Severity::Critical.ensure(false, "broken"), Err("broken".into()));
+	}
+}
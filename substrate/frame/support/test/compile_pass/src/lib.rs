@@ -73,6 +73,8 @@ impl renamed_frame_system::Config for Runtime {
 	type OnKilledAccount = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxIndexedEvents = ConstU32<0>;
+	type NestedCallLimits = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<0>;
 }
@@ -0,0 +1,36 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_benchmarking::v2::*;
+use frame_support::{storage_alias, Blake2_128Concat};
+use frame_support_test::{Config, Pallet};
+
+#[storage_alias]
+type Members = StorageMap<Test, Blake2_128Concat, u32, (u64, Option<[u8; 32]>)>;
+
+#[benchmarks]
+mod benches {
+	use super::*;
+
+	#[benchmark(worst_case_storage(Members))]
+	fn bench() {
+		#[block]
+		{}
+	}
+}
+
+fn main() {}
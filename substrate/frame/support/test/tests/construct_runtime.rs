@@ -895,3 +895,28 @@ fn pallet_in_runtime_is_correct() {
 	assert_eq!(PalletInfo::module_name::<Module1_9>().unwrap(), "module1");
 	assert!(PalletInfo::crate_version::<Module1_9>().is_some());
 }
+
+#[test]
+fn hooks_order_follows_declaration_order() {
+	let pallets = vec![
+		"System",
+		"Module1_1",
+		"Module2",
+		"Module1_2",
+		"NestedModule3",
+		"Module3",
+		"Module1_3",
+		"Module1_4",
+		"Module1_5",
+		"Module1_6",
+		"Module1_7",
+		"Module1_8",
+		"Module1_9",
+	];
+
+	let hooks_order = Runtime::metadata_ir().hooks_order;
+
+	assert_eq!(hooks_order.on_initialize, pallets);
+	assert_eq!(hooks_order.on_idle, pallets);
+	assert_eq!(hooks_order.on_finalize, pallets);
+}
@@ -227,6 +227,7 @@ pub mod pallet {
 		/// Doc comment put in metadata
 		#[pallet::call_index(1)]
 		#[pallet::weight({1})]
+		#[pallet::errors(InsufficientProposersBalance)]
 		pub fn foo_storage_layer(
 			_origin: OriginFor<T>,
 			#[pallet::compact] foo: u32,
@@ -676,6 +677,11 @@ pub mod pallet5 {
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
+
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		Reserve,
+	}
 }
 
 frame_support::parameter_types!(
@@ -819,6 +825,28 @@ fn call_expand_index() {
 	assert_eq!(pallet::Call::<Runtime>::get_call_indices(), &[0u8, 1u8, 4u8, 2u8, 3u8])
 }
 
+#[test]
+fn call_errors_are_documented() {
+	let scale_info::TypeDef::Variant(call) = pallet::Call::<Runtime>::type_info().type_def else {
+		panic!("`Call` is an enum")
+	};
+	let variant_docs = |name: &str| {
+		call.variants.iter().find(|v| v.name == name).expect("call exists").docs.clone()
+	};
+
+	assert_eq!(
+		variant_docs("foo_storage_layer"),
+		vec![
+			"See [`Pallet::foo_storage_layer`].",
+			"",
+			"# Errors",
+			"",
+			"- `InsufficientProposersBalance`: doc comment put into metadata",
+		],
+	);
+	assert_eq!(variant_docs("foo"), vec!["See [`Pallet::foo`]."]);
+}
+
 #[test]
 fn error_expand() {
 	assert_eq!(
@@ -1038,6 +1066,7 @@ fn validate_unsigned_expand() {
 #[test]
 fn composite_expand() {
 	use codec::Encode;
+	use frame_support::traits::VariantCount;
 
 	let hold_reason: RuntimeHoldReason = pallet::HoldReason::Staking.into();
 	let hold_reason2: RuntimeHoldReason = pallet2::HoldReason::Governance.into();
@@ -1050,6 +1079,16 @@ fn composite_expand() {
 	assert_eq!(hold_reason.encode(), [1, 0]);
 	assert_eq!(hold_reason2.encode(), [2, 0]);
 	assert_eq!(slash_reason.encode(), [2, 0]);
+
+	// The variants of feature gated pallets only exist when the feature is enabled.
+	#[cfg(not(feature = "frame-feature-testing-2"))]
+	assert_eq!(RuntimeHoldReason::VARIANT_COUNT, 2);
+	#[cfg(feature = "frame-feature-testing-2")]
+	{
+		assert_eq!(RuntimeHoldReason::VARIANT_COUNT, 3);
+		let hold_reason5: RuntimeHoldReason = pallet5::HoldReason::Reserve.into();
+		assert_eq!(hold_reason5.encode(), [5, 0]);
+	}
 }
 
 #[test]
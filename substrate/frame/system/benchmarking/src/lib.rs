@@ -26,7 +26,7 @@ use frame_benchmarking::{
 	BenchmarkError,
 };
 use frame_support::{dispatch::DispatchClass, storage, traits::Get};
-use frame_system::{Call, Pallet as System, RawOrigin};
+use frame_system::{BlockLengthOverride, Call, Pallet as System, RawOrigin};
 use sp_core::storage::well_known_keys;
 use sp_runtime::traits::Hash;
 use sp_std::{prelude::*, vec};
@@ -71,6 +71,13 @@ benchmarks! {
 	set_heap_pages {
 	}: _(RawOrigin::Root, Default::default())
 
+	set_block_length {
+		let block_length = T::BlockLength::get();
+	}: _(RawOrigin::Root, Some(block_length.clone()))
+	verify {
+		assert_eq!(BlockLengthOverride::<T>::get(), Some(block_length));
+	}
+
 	set_code {
 		let runtime_blob = T::prepare_set_code_data();
 		T::setup_set_code_requirements(&runtime_blob)?;
@@ -32,4 +32,15 @@ sp_api::decl_runtime_apis! {
 		/// Get current account nonce of given `AccountId`.
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
+
+	/// The API to query the weight declared by the dispatches of a block compared to the weight
+	/// they actually consumed.
+	pub trait WeightReconciliationApi<Report> where
+		Report: codec::Codec,
+	{
+		/// Get the declared and consumed weight of the dispatches of the block, per dispatch class.
+		///
+		/// Empty unless the runtime records it.
+		fn weight_reconciliation() -> Report;
+	}
 }
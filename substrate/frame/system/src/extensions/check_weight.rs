@@ -82,7 +82,7 @@ where
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		len: usize,
 	) -> Result<u32, TransactionValidityError> {
-		let length_limit = T::BlockLength::get();
+		let length_limit = Pallet::<T>::block_length();
 		let current_len = Pallet::<T>::all_extrinsics_len();
 		let added_len = len as u32;
 		let next_len = current_len.saturating_add(added_len);
@@ -553,6 +553,21 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn signed_ext_check_weight_respects_block_length_override() {
+		new_test_ext().execute_with(|| {
+			let normal = DispatchInfo::default();
+			let len = normal_length_limit() as usize + 1;
+			assert_err!(
+				CheckWeight::<Test>(PhantomData).pre_dispatch(&1, CALL, &normal, len),
+				InvalidTransaction::ExhaustsResources
+			);
+
+			crate::BlockLengthOverride::<Test>::put(crate::limits::BlockLength::max(1024));
+			assert_ok!(CheckWeight::<Test>(PhantomData).pre_dispatch(&1, CALL, &normal, len));
+		})
+	}
+
 	#[test]
 	fn signed_ext_check_weight_works_normal_tx() {
 		new_test_ext().execute_with(|| {
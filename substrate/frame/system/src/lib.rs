@@ -96,7 +96,8 @@ use frame_support::{
 	storage::{self, StorageStreamIter},
 	traits::{
 		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, Get, HandleLifetime,
-		OnKilledAccount, OnNewAccount, OriginTrait, PalletInfo, SortedMembers, StoredMap, TypedGet,
+		NestedCallLimits, OnKilledAccount, OnNewAccount, OriginTrait, PalletInfo, SortedMembers,
+		StoredMap, TypedGet,
 	},
 	Parameter,
 };
@@ -153,6 +154,35 @@ pub fn extrinsics_data_root<H: Hash>(xts: Vec<Vec<u8>>) -> H::Output {
 /// An object to track the currently used extrinsic weight in a block.
 pub type ConsumedWeight = PerDispatchClass<Weight>;
 
+/// The weight declared by the dispatches of a block compared to the weight they actually consumed.
+///
+/// Neither weight includes the `base_extrinsic` weight of the dispatches.
+#[derive(
+	Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub struct DispatchWeightReport {
+	/// The number of dispatches.
+	pub dispatches: u32,
+	/// The sum of the weight declared by the dispatches ahead of their execution.
+	pub declared: Weight,
+	/// The sum of the weight the dispatches actually consumed, after refunds.
+	pub actual: Weight,
+}
+
+impl DispatchWeightReport {
+	/// Account for a dispatch which declared `declared` and actually consumed `actual` weight.
+	pub fn note_dispatch(&mut self, declared: Weight, actual: Weight) {
+		self.dispatches.saturating_inc();
+		self.declared.saturating_accrue(declared);
+		self.actual.saturating_accrue(actual);
+	}
+
+	/// The weight declared but not consumed by the dispatches.
+	pub fn overestimation(&self) -> Weight {
+		self.declared.saturating_sub(self.actual)
+	}
+}
+
 pub use pallet::*;
 
 /// Do something when we should be setting the code.
@@ -223,6 +253,8 @@ pub mod pallet {
 			type AccountId = u64;
 			type Lookup = sp_runtime::traits::IdentityLookup<u64>;
 			type MaxConsumers = frame_support::traits::ConstU32<16>;
+			type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+			type NestedCallLimits = ();
 			type AccountData = ();
 			type OnNewAccount = ();
 			type OnKilledAccount = ();
@@ -270,6 +302,8 @@ pub mod pallet {
 			type AccountId = sp_runtime::AccountId32;
 			type Lookup = sp_runtime::traits::AccountIdLookup<Self::AccountId, ()>;
 			type MaxConsumers = frame_support::traits::ConstU32<128>;
+			type MaxIndexedEvents = frame_support::traits::ConstU32<0>;
+			type NestedCallLimits = ();
 			type AccountData = crate::AccountInfo<Self::Nonce, ()>;
 			type OnNewAccount = ();
 			type OnKilledAccount = ();
@@ -448,6 +482,20 @@ pub mod pallet {
 
 		/// The maximum number of consumers allowed on a single account.
 		type MaxConsumers: ConsumerLimits;
+
+		/// The maximum number of events of a single pallet kept in [`EventsByPallet`] per block.
+		///
+		/// The events of a pallet which deposits more events in a block are not indexed for that
+		/// block. Set to `0` to disable the index.
+		#[pallet::constant]
+		type MaxIndexedEvents: Get<u32>;
+
+		/// The limits of the depth and length of the calls dispatched on behalf of other calls,
+		/// e.g. by batches, proxies, multisigs or sudo.
+		///
+		/// The limits are shared by all pallets dispatching such calls with
+		/// [`dispatch_nested`](frame_support::dispatch_context::dispatch_nested).
+		type NestedCallLimits: NestedCallLimits;
 	}
 
 	#[pallet::pallet]
@@ -568,6 +616,29 @@ pub mod pallet {
 			Self::deposit_event(Event::Remarked { sender: who, hash });
 			Ok(().into())
 		}
+
+		/// Override the block length limits of [`Config::BlockLength`], or remove the override
+		/// with `None`.
+		///
+		/// No class may exceed the maximal block length of [`Config::BlockLength`], which stays
+		/// the hard cap, but e.g. the limit of normal extrinsics can be lifted up to it.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::SystemWeightInfo::set_block_length(), DispatchClass::Operational))]
+		pub fn set_block_length(
+			origin: OriginFor<T>,
+			block_length: Option<limits::BlockLength>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			if let Some(ref block_length) = block_length {
+				ensure!(
+					block_length.max_total() <= T::BlockLength::get().max_total(),
+					Error::<T>::BlockLengthExceedsLimit,
+				);
+			}
+			BlockLengthOverride::<T>::set(block_length.clone());
+			Self::deposit_event(Event::BlockLengthSet { block_length });
+			Ok(().into())
+		}
 	}
 
 	/// Event for the System pallet.
@@ -585,6 +656,8 @@ pub mod pallet {
 		KilledAccount { account: T::AccountId },
 		/// On on-chain remark happened.
 		Remarked { sender: T::AccountId, hash: T::Hash },
+		/// The block length limits were overridden, or the override was removed.
+		BlockLengthSet { block_length: Option<limits::BlockLength> },
 	}
 
 	/// Error for the System pallet
@@ -606,6 +679,8 @@ pub mod pallet {
 		NonZeroRefCount,
 		/// The origin filter prevent the call to be dispatched.
 		CallFiltered,
+		/// The block length limits exceed the maximal block length of the runtime.
+		BlockLengthExceedsLimit,
 	}
 
 	/// Exposed trait-generic origin type.
@@ -633,10 +708,25 @@ pub mod pallet {
 	#[pallet::getter(fn block_weight)]
 	pub(super) type BlockWeight<T: Config> = StorageValue<_, ConsumedWeight, ValueQuery>;
 
+	/// The weight declared by the dispatches of the current block compared to the weight they
+	/// actually consumed, per dispatch class.
+	///
+	/// Only recorded if the runtime enables it on its `frame_executive::Executive`.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	#[pallet::getter(fn weight_reconciliation)]
+	pub(super) type WeightReconciliation<T: Config> =
+		StorageValue<_, PerDispatchClass<DispatchWeightReport>, ValueQuery>;
+
 	/// Total length (in bytes) for all extrinsics put together, for the current block.
 	#[pallet::storage]
 	pub(super) type AllExtrinsicsLen<T: Config> = StorageValue<_, u32>;
 
+	/// The block length limits set by [`Pallet::set_block_length`] in place of
+	/// [`Config::BlockLength`].
+	#[pallet::storage]
+	pub type BlockLengthOverride<T: Config> = StorageValue<_, limits::BlockLength>;
+
 	/// Map of block numbers to block hashes.
 	#[pallet::storage]
 	#[pallet::getter(fn block_hash)]
@@ -702,6 +792,45 @@ pub mod pallet {
 	pub(super) type EventTopics<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, Vec<(BlockNumberFor<T>, EventIndex)>, ValueQuery>;
 
+	/// The events deposited in the current block, keyed by the index of the depositing pallet in
+	/// the runtime, together with their index in the `<Events<T>>` list.
+	///
+	/// Only maintained if [`Config::MaxIndexedEvents`] is not zero. This allows light-clients to
+	/// fetch the events of a single pallet with a proof of this item, instead of the whole
+	/// `<Events<T>>` list. The entry of a pallet is incomplete if it is in
+	/// [`EventIndexTruncated`].
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub(super) type EventsByPallet<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u8,
+		Vec<(EventIndex, EventRecord<T::RuntimeEvent, T::Hash>)>,
+		ValueQuery,
+	>;
+
+	/// Mapping between the index of a pallet and a topic, and the indexes of the events in the
+	/// `<Events<T>>` list deposited by this pallet with this topic in the current block.
+	///
+	/// Maintained alongside [`EventsByPallet`].
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub(super) type EventTopicsByPallet<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u8,
+		Blake2_128Concat,
+		T::Hash,
+		Vec<EventIndex>,
+		ValueQuery,
+	>;
+
+	/// The pallets which deposited more than [`Config::MaxIndexedEvents`] events in the current
+	/// block, i.e. whose entries in [`EventsByPallet`] and [`EventTopicsByPallet`] are incomplete.
+	#[pallet::storage]
+	pub(super) type EventIndexTruncated<T: Config> =
+		StorageMap<_, Twox64Concat, u8, bool, ValueQuery>;
+
 	/// Stores the `spec_version` and `spec_name` of when the last runtime upgrade happened.
 	#[pallet::storage]
 	#[pallet::unbounded]
@@ -1393,6 +1522,7 @@ impl<T: Config> Pallet<T> {
 			old_event_count
 		};
 
+		Self::index_event(event_idx, &event);
 		Events::<T>::append(event);
 
 		for topic in topics {
@@ -1400,6 +1530,29 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Add `event` to the events of its pallet in [`EventsByPallet`], if the index is enabled.
+	fn index_event(event_idx: EventIndex, event: &EventRecord<T::RuntimeEvent, T::Hash>) {
+		let max_events = T::MaxIndexedEvents::get();
+		if max_events == 0 {
+			return
+		}
+
+		// The first byte of an encoded runtime event is the index of the depositing pallet.
+		let Some(pallet_index) = event.event.using_encoded(|e| e.first().copied()) else { return };
+		if EventIndexTruncated::<T>::get(pallet_index) {
+			return
+		}
+		if EventsByPallet::<T>::decode_len(pallet_index).unwrap_or(0) >= max_events as usize {
+			EventIndexTruncated::<T>::insert(pallet_index, true);
+			return
+		}
+
+		EventsByPallet::<T>::append(pallet_index, (event_idx, event));
+		for topic in &event.topics {
+			EventTopicsByPallet::<T>::append(pallet_index, topic, event_idx);
+		}
+	}
+
 	/// Gets the index of extrinsic that is currently executing.
 	pub fn extrinsic_index() -> Option<u32> {
 		storage::unhashed::get(well_known_keys::EXTRINSIC_INDEX)
@@ -1410,6 +1563,12 @@ impl<T: Config> Pallet<T> {
 		ExtrinsicCount::<T>::get().unwrap_or_default()
 	}
 
+	/// The block length limits in effect, i.e. the override set by [`Pallet::set_block_length`] if
+	/// any or else [`Config::BlockLength`].
+	pub fn block_length() -> limits::BlockLength {
+		BlockLengthOverride::<T>::get().unwrap_or_else(T::BlockLength::get)
+	}
+
 	pub fn all_extrinsics_len() -> u32 {
 		AllExtrinsicsLen::<T>::get().unwrap_or_default()
 	}
@@ -1435,6 +1594,14 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Account for a dispatch of `class` in [`WeightReconciliation`], which declared `declared`
+	/// and actually consumed `actual` weight.
+	pub fn note_dispatch_weight(class: DispatchClass, declared: Weight, actual: Weight) {
+		WeightReconciliation::<T>::mutate(|report| {
+			report.get_mut(class).note_dispatch(declared, actual);
+		});
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize(number: &BlockNumberFor<T>, parent_hash: &T::Hash, digest: &generic::Digest) {
 		// populate environment
@@ -1449,6 +1616,7 @@ impl<T: Config> Pallet<T> {
 
 		// Remove previous block data from storage
 		BlockWeight::<T>::kill();
+		WeightReconciliation::<T>::kill();
 	}
 
 	/// Remove temporary "environment" entries in storage, compute the storage root and return the
@@ -1463,15 +1631,15 @@ impl<T: Config> Pallet<T> {
 			Self::all_extrinsics_len(),
 			sp_runtime::Percent::from_rational(
 				Self::all_extrinsics_len(),
-				*T::BlockLength::get().max.get(DispatchClass::Normal)
+				*Self::block_length().max.get(DispatchClass::Normal)
 			).deconstruct(),
 			sp_runtime::Percent::from_rational(
 				Self::all_extrinsics_len(),
-				*T::BlockLength::get().max.get(DispatchClass::Operational)
+				*Self::block_length().max.get(DispatchClass::Operational)
 			).deconstruct(),
 			sp_runtime::Percent::from_rational(
 				Self::all_extrinsics_len(),
-				*T::BlockLength::get().max.get(DispatchClass::Mandatory)
+				*Self::block_length().max.get(DispatchClass::Mandatory)
 			).deconstruct(),
 			Self::block_weight().get(DispatchClass::Normal),
 			sp_runtime::Percent::from_rational(
@@ -1581,6 +1749,29 @@ impl<T: Config> Pallet<T> {
 		Events::<T>::stream_iter()
 	}
 
+	/// Get the events deposited by the pallet at `pallet_index` in the runtime in the current
+	/// block, together with their index in the list of all events.
+	///
+	/// Returns `None` if the pallet deposited more than [`Config::MaxIndexedEvents`] events, i.e.
+	/// its events are not indexed in this block. An empty list is returned if the index is
+	/// disabled.
+	pub fn pallet_events(
+		pallet_index: u8,
+	) -> Option<Vec<(EventIndex, EventRecord<T::RuntimeEvent, T::Hash>)>> {
+		(!EventIndexTruncated::<T>::get(pallet_index))
+			.then(|| EventsByPallet::<T>::get(pallet_index))
+	}
+
+	/// Get the indexes of the events deposited by the pallet at `pallet_index` in the runtime with
+	/// `topic` in the current block.
+	///
+	/// Returns `None` if the events of the pallet are not indexed in this block, see
+	/// [`Pallet::pallet_events`].
+	pub fn pallet_event_topics(pallet_index: u8, topic: T::Hash) -> Option<Vec<EventIndex>> {
+		(!EventIndexTruncated::<T>::get(pallet_index))
+			.then(|| EventTopicsByPallet::<T>::get(pallet_index, topic))
+	}
+
 	/// Set the block number to something in particular. Can be used as an alternative to
 	/// `initialize` for tests that don't need to bother with the other environment entries.
 	#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
@@ -1618,6 +1809,9 @@ impl<T: Config> Pallet<T> {
 		<Events<T>>::kill();
 		EventCount::<T>::kill();
 		let _ = <EventTopics<T>>::clear(u32::max_value(), None);
+		let _ = EventsByPallet::<T>::clear(u32::max_value(), None);
+		let _ = EventTopicsByPallet::<T>::clear(u32::max_value(), None);
+		let _ = EventIndexTruncated::<T>::clear(u32::max_value(), None);
 	}
 
 	/// Assert the given `event` exists.
@@ -33,7 +33,16 @@ use scale_info::TypeInfo;
 use sp_runtime::{traits::Bounded, Perbill, RuntimeDebug};
 
 /// Block length limit configuration.
-#[derive(RuntimeDebug, Clone, codec::Encode, codec::Decode, TypeInfo)]
+#[derive(
+	RuntimeDebug,
+	Clone,
+	PartialEq,
+	Eq,
+	codec::Encode,
+	codec::Decode,
+	codec::MaxEncodedLen,
+	TypeInfo,
+)]
 pub struct BlockLength {
 	/// Maximal total length in bytes for each extrinsic class.
 	///
@@ -49,6 +58,11 @@ impl Default for BlockLength {
 }
 
 impl BlockLength {
+	/// The maximal length of a block in bytes, i.e. the maximum of the limits of all classes.
+	pub fn max_total(&self) -> u32 {
+		DispatchClass::all().iter().map(|class| *self.max.get(*class)).max().unwrap_or_default()
+	}
+
 	/// Create new `BlockLength` with `max` for every class.
 	pub fn max(max: u32) -> Self {
 		Self { max: PerDispatchClass::new(|_| max) }
@@ -109,6 +109,8 @@ impl Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type MaxIndexedEvents = ConstU32<3>;
+	type NestedCallLimits = ();
 }
 
 pub type SysEvent = frame_system::Event<Test>;
@@ -557,6 +557,48 @@ fn deposit_event_topics() {
 	});
 }
 
+#[test]
+fn events_are_indexed_by_pallet_and_topic() {
+	new_test_ext().execute_with(|| {
+		System::reset_events();
+		System::initialize(&1, &[0u8; 32].into(), &Default::default());
+		System::note_finished_extrinsics();
+
+		let topics = vec![H256::repeat_byte(1), H256::repeat_byte(2)];
+		System::deposit_event_indexed(&topics[0..2], SysEvent::NewAccount { account: 1 }.into());
+		System::deposit_event_indexed(&[], SysEvent::NewAccount { account: 2 }.into());
+		System::deposit_event_indexed(&topics[1..2], SysEvent::NewAccount { account: 3 }.into());
+
+		// `System` is the pallet at index 0 of the runtime.
+		let record = |account, topics: &[H256]| EventRecord {
+			phase: Phase::Finalization,
+			event: SysEvent::NewAccount { account }.into(),
+			topics: topics.to_vec(),
+		};
+		assert_eq!(
+			System::pallet_events(0),
+			Some(vec![
+				(0, record(1, &topics[0..2])),
+				(1, record(2, &[])),
+				(2, record(3, &topics[1..2]))
+			])
+		);
+		assert_eq!(System::pallet_event_topics(0, topics[0]), Some(vec![0]));
+		assert_eq!(System::pallet_event_topics(0, topics[1]), Some(vec![0, 2]));
+		assert_eq!(System::pallet_events(1), Some(vec![]));
+
+		// Exceeding `MaxIndexedEvents` drops the pallet from the index for this block.
+		System::deposit_event_indexed(&topics[0..1], SysEvent::NewAccount { account: 4 }.into());
+		assert_eq!(System::pallet_events(0), None);
+		assert_eq!(System::pallet_event_topics(0, topics[0]), None);
+		assert_eq!(System::events().len(), 4);
+
+		System::reset_events();
+		assert_eq!(System::pallet_events(0), Some(vec![]));
+		assert_eq!(System::pallet_event_topics(0, topics[1]), Some(vec![]));
+	});
+}
+
 #[test]
 fn event_util_functions_should_work() {
 	new_test_ext().execute_with(|| {
@@ -736,6 +778,36 @@ fn runtime_updated_digest_emitted_when_heap_pages_changed() {
 	});
 }
 
+#[test]
+fn set_block_length_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let lifted = limits::BlockLength::max(1024);
+		assert_noop!(
+			System::set_block_length(RuntimeOrigin::signed(1), Some(lifted.clone())),
+			DispatchError::BadOrigin,
+		);
+		// The configured maximal block length is a hard cap.
+		assert_noop!(
+			System::set_block_length(RawOrigin::Root.into(), Some(limits::BlockLength::max(1025))),
+			Error::<Test>::BlockLengthExceedsLimit,
+		);
+
+		assert_eq!(*System::block_length().max.get(DispatchClass::Normal), 768);
+		assert_ok!(System::set_block_length(RawOrigin::Root.into(), Some(lifted.clone())));
+		assert_eq!(System::block_length(), lifted);
+		System::assert_last_event(
+			crate::Event::<Test>::BlockLengthSet { block_length: Some(lifted) }.into(),
+		);
+
+		assert_ok!(System::set_block_length(RawOrigin::Root.into(), None));
+		assert_eq!(System::block_length(), RuntimeBlockLength::get());
+		System::assert_last_event(
+			crate::Event::<Test>::BlockLengthSet { block_length: None }.into(),
+		);
+	});
+}
+
 #[test]
 fn ensure_signed_stuff_works() {
 	struct Members;
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-s7kdgajz-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since the block length limits may be overridden. The weight of
+//! `set_block_length` is a PLACEHOLDER estimated by hand from its storage access. Re-run the
+//! benchmarks to replace it.
 
 // Executed Command:
 // target/production/substrate
@@ -53,6 +57,7 @@ pub trait WeightInfo {
 	fn remark(b: u32, ) -> Weight;
 	fn remark_with_event(b: u32, ) -> Weight;
 	fn set_heap_pages() -> Weight;
+	fn set_block_length() -> Weight;
 	fn set_code() -> Weight;
 	fn set_storage(i: u32, ) -> Weight;
 	fn kill_storage(i: u32, ) -> Weight;
@@ -95,6 +100,13 @@ impl<T: crate::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: System BlockLengthOverride (r:0 w:1)
+	/// Proof: System BlockLengthOverride (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn set_block_length() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage access.
+		Weight::from_parts(2_247_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: System Digest (r:1 w:1)
 	/// Proof Skipped: System Digest (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: unknown `0x3a636f6465` (r:0 w:1)
@@ -186,6 +198,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: System BlockLengthOverride (r:0 w:1)
+	/// Proof: System BlockLengthOverride (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	fn set_block_length() -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from the storage access.
+		Weight::from_parts(2_247_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: System Digest (r:1 w:1)
 	/// Proof Skipped: System Digest (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: unknown `0x3a636f6465` (r:0 w:1)
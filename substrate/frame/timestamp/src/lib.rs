@@ -162,6 +162,7 @@ pub mod pallet {
 			type Moment = u64;
 			type OnTimestampSet = ();
 			type MinimumPeriod = frame_support::traits::ConstU64<1>;
+			type DriftCheck = ();
 			type WeightInfo = ();
 		}
 	}
@@ -193,6 +194,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumPeriod: Get<Self::Moment>;
 
+		/// The check of the timestamp against the time expected by the block production
+		/// apparatus, e.g. the start of the slot of the block.
+		///
+		/// This can be set to `()` if it is not needed.
+		type DriftCheck: DriftCheck<Self::Moment>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -240,7 +247,8 @@ pub mod pallet {
 		/// phase, if this call hasn't been invoked by that time.
 		///
 		/// The timestamp should be greater than the previous one by the amount specified by
-		/// [`Config::MinimumPeriod`].
+		/// [`Config::MinimumPeriod`] and, if [`Config::DriftCheck`] knows the expected time of the
+		/// block, must not drift from it by more than [`DriftCheck::max_drift`].
 		///
 		/// The dispatch origin for this call must be _None_.
 		///
@@ -266,6 +274,16 @@ pub mod pallet {
 				prev.is_zero() || now >= prev + T::MinimumPeriod::get(),
 				"Timestamp must increment by at least <MinimumPeriod> between sequential blocks"
 			);
+			if let Some(expected) = T::DriftCheck::expected_time() {
+				let drift = if now > expected { now - expected } else { expected - now };
+				assert!(
+					drift <= T::DriftCheck::max_drift(),
+					"Timestamp must not drift from the expected time by more than <MaxDrift>"
+				);
+				if !drift.is_zero() {
+					T::DriftCheck::on_drift(now, expected);
+				}
+			}
 			Now::<T>::put(now);
 			DidUpdate::<T>::put(true);
 
@@ -296,7 +314,20 @@ pub mod pallet {
 				.expect("Timestamp inherent data must be provided");
 			let data = (*inherent_data).saturated_into::<T::Moment>();
 
-			let next_time = cmp::max(data, Self::now() + T::MinimumPeriod::get());
+			let earliest = Self::now() + T::MinimumPeriod::get();
+			let mut next_time = cmp::max(data, earliest);
+			// Don't produce a block that would be rejected because of the drift of the local clock,
+			// but never go below the minimum period either.
+			if let Some(expected) = T::DriftCheck::expected_time() {
+				let max_drift = T::DriftCheck::max_drift();
+				next_time = cmp::max(
+					earliest,
+					next_time.clamp(
+						expected.saturating_sub(max_drift),
+						expected.saturating_add(max_drift),
+					),
+				);
+			}
 			Some(Call::set { now: next_time })
 		}
 
@@ -351,6 +382,46 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// A check of the timestamp of a block against the time expected by the block production
+/// apparatus.
+///
+/// The check is done when the timestamp is set, i.e. after `on_initialize`, so the expected time
+/// can be derived from state initialized for the current block, such as its slot.
+pub trait DriftCheck<Moment> {
+	/// The time expected for the current block, or `None` if it is not known.
+	fn expected_time() -> Option<Moment>;
+
+	/// The maximum difference between the timestamp of a block and its expected time.
+	///
+	/// Blocks whose timestamp drifts further are invalid.
+	fn max_drift() -> Moment;
+
+	/// Called with the timestamp and the expected time of every block whose timestamp drifts from
+	/// the expected time, but not by more than [`Self::max_drift`], e.g. to report the author of
+	/// the block.
+	fn on_drift(_now: Moment, _expected: Moment) {}
+}
+
+impl<Moment: Zero> DriftCheck<Moment> for () {
+	fn expected_time() -> Option<Moment> {
+		None
+	}
+
+	fn max_drift() -> Moment {
+		Zero::zero()
+	}
+}
+
+/// Something that can be notified of a timestamp drifting from the expected time.
+pub trait OnTimestampDrift<Moment> {
+	/// Called with the timestamp of the block and the time that was expected.
+	fn on_timestamp_drift(now: Moment, expected: Moment);
+}
+
+impl<Moment> OnTimestampDrift<Moment> for () {
+	fn on_timestamp_drift(_now: Moment, _expected: Moment) {}
+}
+
 impl<T: Config> Time for Pallet<T> {
 	/// A type that represents a unit of time.
 	type Moment = T::Moment;
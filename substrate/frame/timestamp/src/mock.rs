@@ -71,6 +71,23 @@ impl frame_system::Config for Test {
 
 parameter_types! {
 	pub static CapturedMoment: Option<Moment> = None;
+	pub static ExpectedTime: Option<Moment> = None;
+	pub static ReportedDrifts: Vec<(Moment, Moment)> = Vec::new();
+}
+
+pub struct MockDriftCheck;
+impl DriftCheck<Moment> for MockDriftCheck {
+	fn expected_time() -> Option<Moment> {
+		ExpectedTime::get()
+	}
+
+	fn max_drift() -> Moment {
+		10
+	}
+
+	fn on_drift(now: Moment, expected: Moment) {
+		ReportedDrifts::mutate(|d| d.push((now, expected)));
+	}
 }
 
 pub struct MockOnTimestampSet;
@@ -84,6 +101,7 @@ impl Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = MockOnTimestampSet;
 	type MinimumPeriod = ConstU64<5>;
+	type DriftCheck = MockDriftCheck;
 	type WeightInfo = ();
 }
 
@@ -98,5 +116,7 @@ pub(crate) fn get_captured_moment() -> Option<Moment> {
 pub(crate) fn new_test_ext() -> TestExternalities {
 	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	clear_captured_moment();
+	ExpectedTime::set(None);
+	ReportedDrifts::take();
 	TestExternalities::new(t)
 }
@@ -51,3 +51,58 @@ fn block_period_minimum_enforced() {
 		let _ = Timestamp::set(RuntimeOrigin::none(), 46);
 	});
 }
+
+#[test]
+fn drift_within_max_drift_is_reported() {
+	new_test_ext().execute_with(|| {
+		ExpectedTime::set(Some(60));
+		crate::Now::<Test>::put(46);
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 69));
+		assert_eq!(Timestamp::now(), 69);
+		assert_eq!(ReportedDrifts::get(), vec![(69, 60)]);
+	});
+}
+
+#[test]
+fn expected_timestamp_is_not_reported() {
+	new_test_ext().execute_with(|| {
+		ExpectedTime::set(Some(69));
+		crate::Now::<Test>::put(46);
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 69));
+		assert!(ReportedDrifts::get().is_empty());
+	});
+}
+
+#[test]
+#[should_panic(
+	expected = "Timestamp must not drift from the expected time by more than <MaxDrift>"
+)]
+fn drift_beyond_max_drift_is_rejected() {
+	new_test_ext().execute_with(|| {
+		ExpectedTime::set(Some(80));
+		crate::Now::<Test>::put(46);
+		let _ = Timestamp::set(RuntimeOrigin::none(), 69);
+	});
+}
+
+#[test]
+fn created_inherent_stays_within_max_drift() {
+	use frame_support::inherent::{InherentData, ProvideInherent};
+
+	new_test_ext().execute_with(|| {
+		ExpectedTime::set(Some(100));
+		crate::Now::<Test>::put(46);
+		let created = |time: u64| {
+			let mut data = InherentData::new();
+			data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &time).unwrap();
+			Timestamp::create_inherent(&data)
+		};
+		assert_eq!(created(69), Some(crate::Call::set { now: 90 }));
+		assert_eq!(created(105), Some(crate::Call::set { now: 105 }));
+		assert_eq!(created(150), Some(crate::Call::set { now: 110 }));
+
+		// The minimum period takes precedence over the drift.
+		ExpectedTime::set(Some(40));
+		assert_eq!(created(30), Some(crate::Call::set { now: 51 }));
+	});
+}
@@ -26,6 +26,7 @@ use jsonrpsee::{
 	types::error::{CallError, ErrorCode, ErrorObject},
 };
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+use sc_rpc_spec_v2::transaction::{FeeEstimate, TransactionEstimator, TransactionWeight};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
@@ -175,3 +176,37 @@ where
 		})
 	}
 }
+
+/// Estimates the transactions submitted with the `transaction` RPC methods of the new JSON-RPC
+/// spec, with the `query_info` runtime API.
+pub struct TransactionPaymentEstimator<C, Balance> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Balance>,
+}
+
+impl<C, Balance> TransactionPaymentEstimator<C, Balance> {
+	/// Creates a new estimator calling into the runtime of `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Balance> TransactionEstimator<Block> for TransactionPaymentEstimator<C, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + Send + Sync,
+	C::Api: TransactionPaymentRuntimeApi<Block, Balance>,
+	Balance: Codec + MaybeDisplay + TryInto<u128> + Send + Sync,
+{
+	fn estimate(&self, at: Block::Hash, xt: &Block::Extrinsic, len: u32) -> Option<FeeEstimate> {
+		let info = self.client.runtime_api().query_info(at, xt.clone(), len).ok()?;
+		Some(FeeEstimate {
+			fee: info.partial_fee.try_into().ok()?,
+			weight: TransactionWeight {
+				ref_time: info.weight.ref_time(),
+				proof_size: info.weight.proof_size(),
+			},
+		})
+	}
+}
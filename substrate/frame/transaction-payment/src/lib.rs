@@ -44,6 +44,10 @@
 //!     final state of the chain at the end of the previous block. This can be configured via
 //!     [`Config::FeeMultiplierUpdate`]
 //!   - How the fees are paid via [`Config::OnChargeTransaction`].
+//!
+//! The parameters of the fee calculation are exposed in the metadata as the `FeeCalculation`
+//! constant, see [`FeeCalculation`], so that fees can be estimated without a node, e.g. by offline
+//! signers.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -70,7 +74,7 @@ use sp_runtime::{
 	FixedPointNumber, FixedU128, Perbill, Perquintill, RuntimeDebug,
 };
 use sp_std::prelude::*;
-pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use types::{FeeCalculation, FeeDetails, InclusionFee, RuntimeDispatchInfo};
 
 #[cfg(test)]
 mod mock;
@@ -431,6 +435,20 @@ pub mod pallet {
 		TransactionFeePaid { who: T::AccountId, actual_fee: BalanceOf<T>, tip: BalanceOf<T> },
 	}
 
+	#[pallet::extra_constants]
+	impl<T: Config> Pallet<T> {
+		/// The parameters of the fee calculation, to compute fees without executing the runtime.
+		#[pallet::constant_name(FeeCalculation)]
+		pub fn fee_calculation() -> FeeCalculation<BalanceOf<T>> {
+			FeeCalculation {
+				weight_to_fee: T::WeightToFee::polynomial_coefficients().map(|p| p.into_vec()),
+				length_to_fee: T::LengthToFee::polynomial_coefficients().map(|p| p.into_vec()),
+				min_multiplier: T::FeeMultiplierUpdate::min(),
+				max_multiplier: T::FeeMultiplierUpdate::max(),
+			}
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_finalize(_: frame_system::pallet_prelude::BlockNumberFor<T>) {
@@ -753,7 +771,8 @@ where
 		// Calculate how many such extrinsics we could fit into an empty block and take the
 		// limiting factor.
 		let max_block_weight = T::BlockWeights::get().max_block;
-		let max_block_length = *T::BlockLength::get().max.get(info.class) as u64;
+		let max_block_length =
+			*frame_system::Pallet::<T>::block_length().max.get(info.class) as u64;
 
 		// bounded_weight is used as a divisor later so we keep it non-zero.
 		let bounded_weight = info.weight.max(Weight::from_parts(1, 1)).min(max_block_weight);
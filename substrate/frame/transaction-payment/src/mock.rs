@@ -26,7 +26,7 @@ use frame_support::{
 	dispatch::DispatchClass,
 	parameter_types,
 	traits::{ConstU32, ConstU64, Imbalance, OnUnbalanced},
-	weights::{Weight, WeightToFee as WeightToFeeT},
+	weights::{ConstantMultiplier, Weight, WeightToFee as WeightToFeeT, WeightToFeeCoefficients},
 };
 use frame_system as system;
 use pallet_balances::Call as BalancesCall;
@@ -121,6 +121,10 @@ impl WeightToFeeT for WeightToFee {
 		Self::Balance::saturated_from(weight.ref_time())
 			.saturating_mul(WEIGHT_TO_FEE.with(|v| *v.borrow()))
 	}
+
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		ConstantMultiplier::<u64, Self>::polynomial_coefficients()
+	}
 }
 
 impl WeightToFeeT for TransactionByteFee {
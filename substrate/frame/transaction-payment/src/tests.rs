@@ -28,7 +28,7 @@ use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
 	traits::Currency,
-	weights::Weight,
+	weights::{Weight, WeightToFeeCoefficient},
 };
 use frame_system as system;
 use mock::*;
@@ -822,6 +822,34 @@ fn post_info_can_change_pays_fee() {
 		});
 }
 
+#[test]
+fn fee_calculation_is_in_metadata() {
+	ExtBuilder::default().weight_fee(2).build().execute_with(|| {
+		let calculation = Pallet::<Runtime>::fee_calculation();
+		assert_eq!(
+			calculation,
+			FeeCalculation {
+				weight_to_fee: Some(vec![WeightToFeeCoefficient {
+					coeff_integer: 2,
+					coeff_frac: Perbill::zero(),
+					negative: false,
+					degree: 1,
+				}]),
+				// The `LengthToFee` of the mock is not described as a polynomial.
+				length_to_fee: None,
+				min_multiplier: Multiplier::zero(),
+				max_multiplier: <Multiplier as sp_runtime::traits::Bounded>::max_value(),
+			}
+		);
+
+		let constant = Pallet::<Runtime>::pallet_constants_metadata()
+			.into_iter()
+			.find(|constant| constant.name == "FeeCalculation")
+			.expect("the fee calculation is a constant of the pallet");
+		assert_eq!(constant.value, calculation.encode());
+	});
+}
+
 #[test]
 fn genesis_config_works() {
 	ExtBuilder::default()
@@ -26,7 +26,9 @@ use scale_info::TypeInfo;
 use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
 use sp_std::prelude::*;
 
-use frame_support::dispatch::DispatchClass;
+use frame_support::{dispatch::DispatchClass, weights::WeightToFeeCoefficient};
+
+use crate::Multiplier;
 
 /// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
@@ -91,6 +93,34 @@ impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
 	}
 }
 
+/// The parameters of the fee calculation, to compute fees without executing the runtime.
+///
+/// ```ignore
+/// base_fee = weight_to_fee(base_extrinsic);
+/// weight_fee = weight_to_fee(weight);
+/// length_fee = length_to_fee(length);
+/// ```
+///
+/// The `base_extrinsic` weight of each dispatch class is in the `BlockWeights` of
+/// `frame_system`. The fee multiplier applied to the `weight_fee` changes every block, within
+/// the bounds given here.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FeeCalculation<Balance> {
+	/// The polynomial of `weight_to_fee`, evaluated at the `ref_time` of the weight.
+	///
+	/// This is `None` if `WeightToFee` cannot be described as a polynomial.
+	pub weight_to_fee: Option<Vec<WeightToFeeCoefficient<Balance>>>,
+	/// The polynomial of `length_to_fee`, evaluated at the encoded length of the transaction.
+	///
+	/// This is `None` if `LengthToFee` cannot be described as a polynomial.
+	pub length_to_fee: Option<Vec<WeightToFeeCoefficient<Balance>>>,
+	/// The minimum of the fee multiplier.
+	pub min_multiplier: Multiplier,
+	/// The maximum of the fee multiplier.
+	pub max_multiplier: Multiplier,
+}
+
 /// Information related to a dispatchable's class, weight, and fee that can be queried from the
 /// runtime.
 #[derive(Eq, PartialEq, Encode, Decode, Default, TypeInfo)]
@@ -0,0 +1,161 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the transaction storage pallet.
+//!
+//! Serves the data stored by the pallet chunk by chunk, along with a proof of each chunk against
+//! the chunk root the pallet committed to, so that retrieval layers need not trust the node.
+//!
+//! Each request rebuilds the trie of the whole transaction, so the methods are unsafe.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sc_client_api::BlockBackend;
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_transaction_storage_proof::registration::build_chunk_proof;
+
+pub use sp_transaction_storage_proof::TransactionStorageApi as TransactionStorageRuntimeApi;
+
+/// A chunk of stored data with a proof against the chunk root of its transaction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkWithProof<Hash> {
+	/// The chunk root of the transaction the chunk is part of.
+	pub chunk_root: Hash,
+	/// The number of chunks of the transaction.
+	pub num_chunks: u32,
+	/// The chunk.
+	pub chunk: Bytes,
+	/// Trie nodes proving the chunk against the chunk root.
+	pub proof: Vec<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait TransactionStorageApi<BlockHash, BlockNumber> {
+	/// Fetch the chunk `chunk_index` of the transaction `index` stored or renewed in the block
+	/// `block`, along with its proof.
+	///
+	/// Returns `None` if the transaction or chunk does not exist, or the node does not have the
+	/// data of the transaction.
+	///
+	/// This method is unsafe, as building the proof reads and hashes the whole transaction.
+	#[method(name = "transactionStorage_chunk")]
+	fn chunk(
+		&self,
+		block: BlockNumber,
+		index: u32,
+		chunk_index: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ChunkWithProof<H256>>>;
+}
+
+/// Provides RPC methods to retrieve the data stored by the transaction storage pallet.
+pub struct TransactionStorage<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Whether to deny unsafe calls.
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> TransactionStorage<C, P> {
+	/// Creates a new instance of the TransactionStorage Rpc helper.
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The indexed transactions could not be read or proven.
+	BackendError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::BackendError => 2,
+		}
+	}
+}
+
+fn map_err(code: Error, error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(code.into(), desc, Some(error.to_string())))
+}
+
+impl<C, Block> TransactionStorageApiServer<<Block as BlockT>::Hash, NumberFor<Block>>
+	for TransactionStorage<C, Block>
+where
+	Block: BlockT,
+	Block::Hash: From<H256>,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockBackend<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: TransactionStorageRuntimeApi<Block, NumberFor<Block>>,
+{
+	fn chunk(
+		&self,
+		block: NumberFor<Block>,
+		index: u32,
+		chunk_index: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ChunkWithProof<H256>>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let Some(commitment) = api.transaction_commitment(at_hash, block, index).map_err(|e| {
+			map_err(Error::RuntimeError, e, "Unable to query transaction commitment.")
+		})?
+		else {
+			return Ok(None)
+		};
+
+		let Some(transaction) = self
+			.client
+			.indexed_transaction(commitment.content_hash.into())
+			.map_err(|e| map_err(Error::BackendError, e, "Unable to read indexed transaction."))?
+		else {
+			return Ok(None)
+		};
+
+		let proof = build_chunk_proof(&transaction, chunk_index)
+			.map_err(|e| map_err(Error::BackendError, e, "Unable to build chunk proof."))?;
+		Ok(proof.map(|proof| ChunkWithProof {
+			chunk_root: commitment.chunk_root,
+			num_chunks: commitment.num_chunks(),
+			chunk: proof.chunk.into(),
+			proof: proof.proof.into_iter().map(Into::into).collect(),
+		}))
+	}
+}
@@ -148,6 +148,21 @@ benchmarks! {
 		assert_last_event::<T>(Event::Renewed { index: 0 }.into());
 	}
 
+	renew_with_data {
+		let l in 1 .. T::MaxTransactionSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let initial_balance = BalanceOf::<T>::max_value().checked_div(&2u32.into()).unwrap();
+		T::Currency::set_balance(&caller, initial_balance);
+		TransactionStorage::<T>::store(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![0u8; l as usize],
+		)?;
+		run_to_block::<T>(1u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), BlockNumberFor::<T>::zero(), 0, vec![0u8; l as usize])
+	verify {
+		assert_last_event::<T>(Event::Renewed { index: 0 }.into());
+	}
+
 	check_proof_max {
 		run_to_block::<T>(1u32.into());
 		let caller: T::AccountId = whitelisted_caller();
@@ -40,11 +40,14 @@ use frame_support::{
 		OnUnbalanced,
 	},
 };
-use sp_runtime::traits::{BlakeTwo256, Dispatchable, Hash, One, Saturating, Zero};
+use sp_runtime::{
+	traits::{BlakeTwo256, Dispatchable, Hash, One, Saturating, Zero},
+	Perbill,
+};
 use sp_std::{prelude::*, result};
 use sp_transaction_storage_proof::{
-	encode_index, random_chunk, InherentError, TransactionStorageProof, CHUNK_SIZE,
-	INHERENT_IDENTIFIER,
+	encode_index, random_chunk, InherentError, TransactionCommitment, TransactionStorageProof,
+	CHUNK_SIZE, INHERENT_IDENTIFIER,
 };
 
 /// A type alias for the balance type from this pallet's point of view.
@@ -152,6 +155,8 @@ pub mod pallet {
 		TooManyTransactions,
 		/// Attempted to call `store` outside of block execution.
 		BadContext,
+		/// The data does not match the renewed transaction.
+		RenewedContentMismatch,
 	}
 
 	#[pallet::pallet]
@@ -209,10 +214,9 @@ pub mod pallet {
 				Error::<T>::TransactionTooLarge
 			);
 			let sender = ensure_signed(origin)?;
-			Self::apply_fee(sender, data.len() as u32)?;
+			Self::apply_fee(sender, data.len() as u32, Perbill::zero())?;
 
 			// Chunk data and compute storage root
-			let chunk_count = num_chunks(data.len() as u32);
 			let chunks = data.chunks(CHUNK_SIZE).map(|c| c.to_vec()).collect();
 			let root = sp_io::trie::blake2_256_ordered_root(chunks, sp_runtime::StateVersion::V1);
 
@@ -221,23 +225,7 @@ pub mod pallet {
 				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
 			sp_io::transaction_index::index(extrinsic_index, data.len() as u32, content_hash);
 
-			let mut index = 0;
-			<BlockTransactions<T>>::mutate(|transactions| {
-				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
-					return Err(Error::<T>::TooManyTransactions)
-				}
-				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunk_count;
-				index = transactions.len() as u32;
-				transactions
-					.try_push(TransactionInfo {
-						chunk_root: root,
-						size: data.len() as u32,
-						content_hash: content_hash.into(),
-						block_chunks: total_chunks,
-					})
-					.map_err(|_| Error::<T>::TooManyTransactions)?;
-				Ok(())
-			})?;
+			let index = Self::push_transaction(root, content_hash.into(), data.len() as u32)?;
 			Self::deposit_event(Event::Stored { index });
 			Ok(())
 		}
@@ -261,27 +249,11 @@ pub mod pallet {
 			let extrinsic_index =
 				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
 
-			Self::apply_fee(sender, info.size)?;
+			Self::apply_fee(sender, info.size, Perbill::zero())?;
 
 			sp_io::transaction_index::renew(extrinsic_index, info.content_hash.into());
 
-			let mut index = 0;
-			<BlockTransactions<T>>::mutate(|transactions| {
-				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
-					return Err(Error::<T>::TooManyTransactions)
-				}
-				let chunks = num_chunks(info.size);
-				let total_chunks = transactions.last().map_or(0, |t| t.block_chunks) + chunks;
-				index = transactions.len() as u32;
-				transactions
-					.try_push(TransactionInfo {
-						chunk_root: info.chunk_root,
-						size: info.size,
-						content_hash: info.content_hash,
-						block_chunks: total_chunks,
-					})
-					.map_err(|_| Error::<T>::TooManyTransactions)
-			})?;
+			let index = Self::push_transaction(info.chunk_root, info.content_hash, info.size)?;
 			Self::deposit_event(Event::Renewed { index });
 			Ok(().into())
 		}
@@ -337,6 +309,53 @@ pub mod pallet {
 			Self::deposit_event(Event::ProofChecked);
 			Ok(().into())
 		}
+
+		/// Renew previously stored data by uploading it again, so that nodes which no longer
+		/// have the data may index it again. Parameters are the block number that contains
+		/// previous `store` or `renew` call, transaction index within that block and the data,
+		/// which must match the renewed transaction.
+		///
+		/// Applies same fees as `store`, except that the byte fee is credited for the part of the
+		/// storage period of the renewed transaction which has not passed yet, as the data was
+		/// already paid for until then.
+		/// ## Complexity
+		/// - O(n) of data size, as the data is hashed.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::renew_with_data(data.len() as u32))]
+		pub fn renew_with_data(
+			origin: OriginFor<T>,
+			block: BlockNumberFor<T>,
+			index: u32,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let transactions = <Transactions<T>>::get(block).ok_or(Error::<T>::RenewedNotFound)?;
+			let info = transactions.get(index as usize).ok_or(Error::<T>::RenewedNotFound)?;
+			let content_hash = sp_io::hashing::blake2_256(&data);
+			ensure!(
+				info.content_hash == content_hash.into(),
+				Error::<T>::RenewedContentMismatch
+			);
+			let extrinsic_index =
+				<frame_system::Pallet<T>>::extrinsic_index().ok_or(Error::<T>::BadContext)?;
+
+			let period = <StoragePeriod<T>>::get();
+			let now = <frame_system::Pallet<T>>::block_number();
+			let remaining = block.saturating_add(period).saturating_sub(now);
+			// Without a storage period, no part of the renewed data is still paid for.
+			let credit = if period.is_zero() {
+				Perbill::zero()
+			} else {
+				Perbill::from_rational(remaining, period)
+			};
+			Self::apply_fee(sender, info.size, credit)?;
+
+			sp_io::transaction_index::index(extrinsic_index, info.size, content_hash);
+
+			let index = Self::push_transaction(info.chunk_root, info.content_hash, info.size)?;
+			Self::deposit_event(Event::Renewed { index });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -442,10 +461,52 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn apply_fee(sender: T::AccountId, size: u32) -> DispatchResult {
+		/// The commitment to the data of the transaction `index` stored or renewed in the block
+		/// `block`, if it is still within its storage period.
+		pub fn transaction_commitment(
+			block: BlockNumberFor<T>,
+			index: u32,
+		) -> Option<TransactionCommitment> {
+			let transactions = <Transactions<T>>::get(block)?;
+			let info = transactions.get(index as usize)?;
+			Some(TransactionCommitment {
+				chunk_root: info.chunk_root,
+				content_hash: info.content_hash,
+				size: info.size,
+			})
+		}
+
+		/// Append a transaction to the transactions indexed in this block, returning its index.
+		fn push_transaction(
+			chunk_root: <BlakeTwo256 as Hash>::Output,
+			content_hash: <BlakeTwo256 as Hash>::Output,
+			size: u32,
+		) -> Result<u32, Error<T>> {
+			<BlockTransactions<T>>::try_mutate(|transactions| {
+				if transactions.len() + 1 > T::MaxBlockTransactions::get() as usize {
+					return Err(Error::<T>::TooManyTransactions)
+				}
+				let total_chunks =
+					transactions.last().map_or(0, |t| t.block_chunks) + num_chunks(size);
+				let index = transactions.len() as u32;
+				transactions
+					.try_push(TransactionInfo {
+						chunk_root,
+						size,
+						content_hash,
+						block_chunks: total_chunks,
+					})
+					.map_err(|_| Error::<T>::TooManyTransactions)?;
+				Ok(index)
+			})
+		}
+
+		/// Charge the fee for storing `size` bytes, of which `credit` of the byte fee is waived.
+		fn apply_fee(sender: T::AccountId, size: u32, credit: Perbill) -> DispatchResult {
 			let byte_fee = ByteFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
 			let entry_fee = EntryFee::<T>::get().ok_or(Error::<T>::NotConfigured)?;
-			let fee = byte_fee.saturating_mul(size.into()).saturating_add(entry_fee);
+			let fee = byte_fee.saturating_mul(size.into());
+			let fee = fee.saturating_sub(credit * fee).saturating_add(entry_fee);
 			T::Currency::hold(&HoldReason::StorageFeeHold.into(), &sender, fee)?;
 			let (credit, _remainder) =
 				T::Currency::slash(&HoldReason::StorageFeeHold.into(), &sender, fee);
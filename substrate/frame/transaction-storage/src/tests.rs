@@ -147,3 +147,79 @@ fn renews_data() {
 		assert!(Transactions::<Test>::get(6).is_none());
 	});
 }
+
+#[test]
+fn renews_data_by_upload() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1, || None);
+		let caller = 1;
+		assert_ok!(TransactionStorage::<Test>::store(
+			RawOrigin::Signed(caller).into(),
+			vec![0u8; 2000]
+		));
+		let info = BlockTransactions::<Test>::get().last().unwrap().clone();
+		run_to_block(6, || None);
+		assert_noop!(
+			TransactionStorage::<Test>::renew_with_data(
+				RawOrigin::Signed(caller).into(),
+				1, // block
+				0, // transaction
+				vec![1u8; 2000],
+			),
+			Error::<Test>::RenewedContentMismatch,
+		);
+		assert_ok!(TransactionStorage::<Test>::renew_with_data(
+			RawOrigin::Signed(caller).into(),
+			1, // block
+			0, // transaction
+			vec![0u8; 2000],
+		));
+		// Half of the storage period of the renewed data has passed, so half of the byte fee is
+		// credited.
+		assert_eq!(Balances::free_balance(1), 1_000_000_000 - 4000 * 2 - 200 * 2 + 2000);
+		run_to_block(7, || None);
+		assert_eq!(Transactions::<Test>::get(6).unwrap().get(0), Some(info).as_ref());
+	});
+}
+
+#[test]
+fn renews_data_by_upload_without_storage_period() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1, || None);
+		let caller = 1;
+		assert_ok!(TransactionStorage::<Test>::store(
+			RawOrigin::Signed(caller).into(),
+			vec![0u8; 2000]
+		));
+		run_to_block(2, || None);
+		StoragePeriod::<Test>::put(0);
+		assert_ok!(TransactionStorage::<Test>::renew_with_data(
+			RawOrigin::Signed(caller).into(),
+			1, // block
+			0, // transaction
+			vec![0u8; 2000],
+		));
+		// Nothing is credited, the full fee is paid again.
+		assert_eq!(Balances::free_balance(1), 1_000_000_000 - 4000 * 2 - 200 * 2);
+	});
+}
+
+#[test]
+fn transaction_commitment_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1, || None);
+		let caller = 1;
+		let data = vec![3u8; 3000];
+		assert_ok!(TransactionStorage::<Test>::store(
+			RawOrigin::Signed(caller).into(),
+			data.clone()
+		));
+		run_to_block(2, || None);
+		let commitment = TransactionStorage::<Test>::transaction_commitment(1, 0).unwrap();
+		assert_eq!(commitment.content_hash, sp_io::hashing::blake2_256(&data).into());
+		assert_eq!(commitment.size, 3000);
+		assert_eq!(commitment.num_chunks(), 12);
+		assert!(TransactionStorage::<Test>::transaction_commitment(1, 1).is_none());
+		assert!(TransactionStorage::<Test>::transaction_commitment(2, 0).is_none());
+	});
+}
@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since data may be renewed by uploading it again. The weight of
+//! `renew_with_data` is a PLACEHOLDER estimated by hand. Re-run the benchmarks to replace it.
 
 // Executed Command:
 // ./target/production/substrate
@@ -54,6 +57,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn store(l: u32, ) -> Weight;
 	fn renew() -> Weight;
+	fn renew_with_data(l: u32, ) -> Weight;
 	fn check_proof_max() -> Weight;
 }
 
@@ -95,6 +99,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: TransactionStorage Transactions (r:1 w:0)
+	/// Proof: TransactionStorage Transactions (max_values: None, max_size: Some(36886), added: 39361, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage StoragePeriod (r:1 w:0)
+	/// Proof: TransactionStorage StoragePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 8388608]`.
+	fn renew_with_data(l: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from `store` and the storage accesses of `renew`.
+		Weight::from_parts(52_418_000, 40351)
+			.saturating_add(Weight::from_parts(6_912, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: TransactionStorage ProofChecked (r:1 w:1)
 	/// Proof: TransactionStorage ProofChecked (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: TransactionStorage StoragePeriod (r:1 w:0)
@@ -153,6 +175,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: TransactionStorage Transactions (r:1 w:0)
+	/// Proof: TransactionStorage Transactions (max_values: None, max_size: Some(36886), added: 39361, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage StoragePeriod (r:1 w:0)
+	/// Proof: TransactionStorage StoragePeriod (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage ByteFee (r:1 w:0)
+	/// Proof: TransactionStorage ByteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage EntryFee (r:1 w:0)
+	/// Proof: TransactionStorage EntryFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: TransactionStorage BlockTransactions (r:1 w:1)
+	/// Proof: TransactionStorage BlockTransactions (max_values: Some(1), max_size: Some(36866), added: 37361, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 8388608]`.
+	fn renew_with_data(l: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, estimated from `store` and the storage accesses of `renew`.
+		Weight::from_parts(52_418_000, 40351)
+			.saturating_add(Weight::from_parts(6_912, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: TransactionStorage ProofChecked (r:1 w:1)
 	/// Proof: TransactionStorage ProofChecked (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	/// Storage: TransactionStorage StoragePeriod (r:1 w:0)
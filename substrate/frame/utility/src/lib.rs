@@ -58,7 +58,10 @@ pub mod weights;
 
 use codec::{Decode, Encode};
 use frame_support::{
-	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+	dispatch::{
+		extract_actual_weight, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo,
+	},
+	dispatch_context::dispatch_nested,
 	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
 };
 use sp_core::TypeId;
@@ -216,11 +219,7 @@ pub mod pallet {
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
-				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
-				} else {
-					call.dispatch(origin.clone())
-				};
+				let result = Self::dispatch_nested(call, origin.clone(), is_root);
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				if let Err(e) = result {
@@ -274,7 +273,7 @@ pub mod pallet {
 			let pseudonym = Self::derivative_account_id(who, index);
 			origin.set_caller_from(frame_system::RawOrigin::Signed(pseudonym));
 			let info = call.get_dispatch_info();
-			let result = call.dispatch(origin);
+			let result = Self::dispatch_nested(*call, origin, false);
 			// Always take into account the base weight of this call.
 			let mut weight = T::WeightInfo::as_derivative()
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
@@ -339,7 +338,7 @@ pub mod pallet {
 				let info = call.get_dispatch_info();
 				// If origin is root, bypass any dispatch filter; root can call anything.
 				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
+					Self::dispatch_nested(call, origin.clone(), true)
 				} else {
 					let mut filtered_origin = origin.clone();
 					// Don't allow users to nest `batch_all` calls.
@@ -349,7 +348,7 @@ pub mod pallet {
 							!matches!(c.is_sub_type(), Some(Call::batch_all { .. }))
 						},
 					);
-					call.dispatch(filtered_origin)
+					Self::dispatch_nested(call, filtered_origin, false)
 				};
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
@@ -389,7 +388,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			let res = call.dispatch_bypass_filter((*as_origin).into());
+			let res = Self::dispatch_nested(*call, (*as_origin).into(), true);
 
 			Self::deposit_event(Event::DispatchedAs {
 				result: res.map(|_| ()).map_err(|e| e.error),
@@ -449,11 +448,7 @@ pub mod pallet {
 			for call in calls.into_iter() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
-				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
-				} else {
-					call.dispatch(origin.clone())
-				};
+				let result = Self::dispatch_nested(call, origin.clone(), is_root);
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				if let Err(e) = result {
@@ -488,7 +483,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let _ = weight; // Explicitly don't check the the weight witness.
 
-			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
+			let res = Self::dispatch_nested(*call, frame_system::RawOrigin::Root.into(), true);
 			res.map(|_| ()).map_err(|e| e.error)
 		}
 	}
@@ -509,4 +504,20 @@ impl<T: Config> Pallet<T> {
 		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
+
+	/// Dispatch `call` within the [`frame_system::Config::NestedCallLimits`], bypassing the filter
+	/// of `origin` if `bypass_filter`.
+	fn dispatch_nested(
+		call: <T as Config>::RuntimeCall,
+		origin: T::RuntimeOrigin,
+		bypass_filter: bool,
+	) -> DispatchResultWithPostInfo {
+		dispatch_nested::<T::NestedCallLimits, _, _, _>(call, |call| {
+			if bypass_filter {
+				call.dispatch_bypass_filter(origin)
+			} else {
+				call.dispatch(origin)
+			}
+		})
+	}
 }
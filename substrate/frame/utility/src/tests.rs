@@ -27,7 +27,7 @@ use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, Pays},
 	error::BadOrigin,
 	parameter_types, storage,
-	traits::{ConstU32, ConstU64, Contains},
+	traits::{ConstU32, ConstU64, Contains, NestedCallLimitsOf},
 	weights::Weight,
 };
 use pallet_collective::{EnsureProportionAtLeast, Instance1};
@@ -169,6 +169,7 @@ impl frame_system::Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = ConstU32<16>;
+	type NestedCallLimits = NestedCallLimitsOf<ConstU32<4>, ConstU32<1024>>;
 }
 
 impl pallet_balances::Config for Test {
@@ -196,6 +197,7 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<3>;
+	type DriftCheck = ();
 	type WeightInfo = ();
 }
 
@@ -219,6 +221,7 @@ impl pallet_collective::Config<CouncilCollective> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MemberRank = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type MaxProposalWeight = MaxProposalWeight;
@@ -734,6 +737,32 @@ fn batch_limit() {
 	});
 }
 
+#[test]
+fn nested_calls_are_limited() {
+	new_test_ext().execute_with(|| {
+		let remark = |len| RuntimeCall::System(SystemCall::remark { remark: vec![0; len] });
+		let nest = |call: RuntimeCall, depth: u32| {
+			(1..depth).fold(call, |call, _| {
+				RuntimeCall::Utility(UtilityCall::batch_all { calls: vec![call] })
+			})
+		};
+
+		// The remark is dispatched at the maximum depth.
+		assert_ok!(Utility::batch_all(RuntimeOrigin::root(), vec![nest(remark(0), 4)]));
+		assert_err_ignore_postinfo!(
+			Utility::batch_all(RuntimeOrigin::root(), vec![nest(remark(0), 5)]),
+			DispatchError::Exhausted
+		);
+
+		// Calls longer than the limit are rejected at any depth.
+		assert_ok!(Utility::batch_all(RuntimeOrigin::root(), vec![remark(1000)]));
+		assert_err_ignore_postinfo!(
+			Utility::batch_all(RuntimeOrigin::root(), vec![remark(1024)]),
+			DispatchError::Exhausted
+		);
+	});
+}
+
 #[test]
 fn force_batch_works() {
 	new_test_ext().execute_with(|| {
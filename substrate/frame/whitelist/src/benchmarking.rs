@@ -21,7 +21,10 @@
 
 use super::*;
 use frame_benchmarking::v1::{benchmarks, BenchmarkError};
-use frame_support::{ensure, traits::EnsureOrigin};
+use frame_support::{
+	ensure,
+	traits::{EnsureOrigin, Hooks},
+};
 
 #[cfg(test)]
 use crate::Pallet as Whitelist;
@@ -43,6 +46,29 @@ benchmarks! {
 		);
 	}
 
+	whitelist_call_with_expiry {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call_hash = Default::default();
+		// Worst case: the call expires at a block where other calls expire already.
+		let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		for i in 1 .. T::MaxExpiringPerBlock::get() {
+			let hash = T::Hashing::hash_of(&i);
+			Pallet::<T>::whitelist_call_with_expiry(origin.clone(), hash, expiry)
+				.expect("whitelisting call must be successful");
+		}
+	}: _<T::RuntimeOrigin>(origin, call_hash, expiry)
+	verify {
+		ensure!(
+			WhitelistedCall::<T>::contains_key(call_hash),
+			"call not whitelisted"
+		);
+		ensure!(
+			CallExpiry::<T>::get(call_hash) == Some(expiry),
+			"expiry not set"
+		);
+	}
+
 	remove_whitelisted_call {
 		let origin =
 			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -122,5 +148,26 @@ benchmarks! {
 		);
 	}
 
+	expire_whitelisted_calls {
+		let n in 0 .. T::MaxExpiringPerBlock::get();
+
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let call_hashes = (0..n).map(|i| T::Hashing::hash_of(&i)).collect::<Vec<_>>();
+		for call_hash in &call_hashes {
+			Pallet::<T>::whitelist_call_with_expiry(origin.clone(), *call_hash, expiry)
+				.expect("whitelisting call must be successful");
+		}
+	}: {
+		Pallet::<T>::on_initialize(expiry);
+	}
+	verify {
+		for call_hash in &call_hashes {
+			ensure!(!WhitelistedCall::<T>::contains_key(call_hash), "whitelist not removed");
+			ensure!(!T::Preimages::is_requested(call_hash), "preimage still requested");
+		}
+	}
+
 	impl_benchmark_test_suite!(Whitelist, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -28,6 +28,9 @@
 //!
 //! In the meantime the call corresponding to the hash must have been submitted to the pre-image
 //! handler [`pallet::Config::Preimages`].
+//!
+//! A call can be whitelisted until some block, at the beginning of which it is removed from the
+//! whitelist if it wasn't dispatched, and its pre-image is unrequested.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -82,6 +85,10 @@ pub mod pallet {
 		/// The handler of pre-images.
 		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
+		/// The maximum number of whitelisted calls expiring at the same block.
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+
 		/// The weight information for this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -95,6 +102,8 @@ pub mod pallet {
 		CallWhitelisted { call_hash: T::Hash },
 		WhitelistedCallRemoved { call_hash: T::Hash },
 		WhitelistedCallDispatched { call_hash: T::Hash, result: DispatchResultWithPostInfo },
+		/// A whitelisted call expired before being dispatched and was removed from the whitelist.
+		WhitelistedCallExpired { call_hash: T::Hash },
 	}
 
 	#[pallet::error]
@@ -109,11 +118,46 @@ pub mod pallet {
 		CallIsNotWhitelisted,
 		/// The call was already whitelisted; No-Op.
 		CallAlreadyWhitelisted,
+		/// The expiry of the call is not in the future.
+		ExpiryInPast,
+		/// Too many whitelisted calls expire at the given block already.
+		TooManyExpiring,
 	}
 
 	#[pallet::storage]
 	pub type WhitelistedCall<T: Config> = StorageMap<_, Twox64Concat, T::Hash, (), OptionQuery>;
 
+	/// The block at the beginning of which a whitelisted call expires, if any.
+	#[pallet::storage]
+	pub type CallExpiry<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	/// The whitelisted calls expiring at the beginning of a block.
+	#[pallet::storage]
+	pub type Expiring<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::Hash, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = Expiring::<T>::take(now);
+			let weight = T::WeightInfo::expire_whitelisted_calls(expiring.len() as u32);
+			for call_hash in expiring {
+				CallExpiry::<T>::remove(call_hash);
+				if WhitelistedCall::<T>::take(call_hash).is_some() {
+					T::Preimages::unrequest(&call_hash);
+					Self::deposit_event(Event::<T>::WhitelistedCallExpired { call_hash });
+				}
+			}
+			weight
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
@@ -121,17 +165,7 @@ pub mod pallet {
 		pub fn whitelist_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				!WhitelistedCall::<T>::contains_key(call_hash),
-				Error::<T>::CallAlreadyWhitelisted,
-			);
-
-			WhitelistedCall::<T>::insert(call_hash, ());
-			T::Preimages::request(&call_hash);
-
-			Self::deposit_event(Event::<T>::CallWhitelisted { call_hash });
-
-			Ok(())
+			Self::do_whitelist_call(call_hash, None)
 		}
 
 		#[pallet::call_index(1)]
@@ -141,6 +175,7 @@ pub mod pallet {
 
 			WhitelistedCall::<T>::take(call_hash).ok_or(Error::<T>::CallIsNotWhitelisted)?;
 
+			Self::clear_expiry(call_hash);
 			T::Preimages::unrequest(&call_hash);
 
 			Self::deposit_event(Event::<T>::WhitelistedCallRemoved { call_hash });
@@ -215,16 +250,56 @@ pub mod pallet {
 
 			Ok(actual_weight.into())
 		}
+
+		/// Whitelist a call until the block `expiry`, at the beginning of which it is removed from
+		/// the whitelist if it wasn't dispatched.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::whitelist_call_with_expiry())]
+		pub fn whitelist_call_with_expiry(
+			origin: OriginFor<T>,
+			call_hash: T::Hash,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			Self::do_whitelist_call(call_hash, Some(expiry))
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whitelist a call, optionally until the block `expiry`.
+	fn do_whitelist_call(call_hash: T::Hash, expiry: Option<BlockNumberFor<T>>) -> DispatchResult {
+		ensure!(
+			!WhitelistedCall::<T>::contains_key(call_hash),
+			Error::<T>::CallAlreadyWhitelisted,
+		);
+
+		if let Some(expiry) = expiry {
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryInPast
+			);
+			Expiring::<T>::try_mutate(expiry, |expiring| expiring.try_push(call_hash))
+				.map_err(|_| Error::<T>::TooManyExpiring)?;
+			CallExpiry::<T>::insert(call_hash, expiry);
+		}
+
+		WhitelistedCall::<T>::insert(call_hash, ());
+		T::Preimages::request(&call_hash);
+
+		Self::deposit_event(Event::<T>::CallWhitelisted { call_hash });
+
+		Ok(())
+	}
+
 	/// Clean whitelisting/preimage and dispatch call.
 	///
 	/// Return the call actual weight of the dispatched call if there is some.
 	fn clean_and_dispatch(call_hash: T::Hash, call: <T as Config>::RuntimeCall) -> Option<Weight> {
 		WhitelistedCall::<T>::remove(call_hash);
 
+		Self::clear_expiry(call_hash);
 		T::Preimages::unrequest(&call_hash);
 
 		let result = call.dispatch(frame_system::Origin::<T>::Root.into());
@@ -238,4 +313,18 @@ impl<T: Config> Pallet<T> {
 
 		call_actual_weight
 	}
+
+	/// Remove the expiry of a call which is no longer whitelisted.
+	fn clear_expiry(call_hash: T::Hash) {
+		if let Some(expiry) = CallExpiry::<T>::take(call_hash) {
+			Expiring::<T>::mutate_exists(expiry, |expiring| {
+				if let Some(calls) = expiring {
+					calls.retain(|h| h != &call_hash);
+					if calls.is_empty() {
+						*expiring = None;
+					}
+				}
+			});
+		}
+	}
 }
@@ -102,6 +102,7 @@ impl pallet_whitelist::Config for Test {
 	type WhitelistOrigin = EnsureRoot<Self::AccountId>;
 	type DispatchWhitelistedOrigin = EnsureRoot<Self::AccountId>;
 	type Preimages = Preimage;
+	type MaxExpiringPerBlock = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -22,7 +22,7 @@ use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::GetDispatchInfo,
-	traits::{QueryPreimage, StorePreimage},
+	traits::{Hooks, QueryPreimage, StorePreimage},
 	weights::Weight,
 };
 use sp_runtime::{traits::Hash, DispatchError};
@@ -223,3 +223,60 @@ fn test_whitelist_call_and_execute_decode_consumes_all() {
 		);
 	});
 }
+
+#[test]
+fn test_whitelisted_call_expires() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] });
+		let call_hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let other_hash = <Test as frame_system::Config>::Hashing::hash_of(&1u32);
+		let another_hash = <Test as frame_system::Config>::Hashing::hash_of(&2u32);
+
+		assert_noop!(
+			Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), call_hash, 1),
+			crate::Error::<Test>::ExpiryInPast,
+		);
+		assert_ok!(Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), call_hash, 3));
+		assert_ok!(Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), other_hash, 3));
+		assert_noop!(
+			Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), another_hash, 3),
+			crate::Error::<Test>::TooManyExpiring,
+		);
+
+		// Removing a call from the whitelist frees its place at the expiry block.
+		assert_ok!(Whitelist::remove_whitelisted_call(RuntimeOrigin::root(), other_hash));
+		assert_eq!(crate::CallExpiry::<Test>::get(other_hash), None);
+		assert_ok!(Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), another_hash, 3));
+
+		Whitelist::on_initialize(2);
+		assert!(crate::WhitelistedCall::<Test>::contains_key(call_hash));
+
+		Whitelist::on_initialize(3);
+		assert!(!crate::WhitelistedCall::<Test>::contains_key(call_hash));
+		assert!(!crate::WhitelistedCall::<Test>::contains_key(another_hash));
+		assert!(!Preimage::is_requested(&call_hash));
+		assert_eq!(crate::CallExpiry::<Test>::get(call_hash), None);
+		assert!(!crate::Expiring::<Test>::contains_key(3));
+		System::assert_has_event(crate::Event::<Test>::WhitelistedCallExpired { call_hash }.into());
+
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call_with_preimage(RuntimeOrigin::root(), Box::new(call)),
+			crate::Error::<Test>::CallIsNotWhitelisted,
+		);
+	});
+}
+
+#[test]
+fn test_dispatched_call_does_not_expire() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: vec![1],
+		}));
+		let call_hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+
+		assert_ok!(Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), call_hash, 3));
+		assert_ok!(Whitelist::dispatch_whitelisted_call_with_preimage(RuntimeOrigin::root(), call));
+		assert_eq!(crate::CallExpiry::<Test>::get(call_hash), None);
+		assert!(!crate::Expiring::<Test>::contains_key(3));
+	});
+}
@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-145-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! NOTE: Not regenerated since whitelisted calls may expire. The storage accesses of the existing
+//! weights were adjusted by hand, and `whitelist_call_with_expiry` and `expire_whitelisted_calls`
+//! are PLACEHOLDERS derived from `whitelist_call` and `remove_whitelisted_call`. Re-run the
+//! benchmarks to replace them.
 
 // Executed Command:
 // ./target/production/substrate
@@ -53,9 +58,11 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_whitelist.
 pub trait WeightInfo {
 	fn whitelist_call() -> Weight;
+	fn whitelist_call_with_expiry() -> Weight;
 	fn remove_whitelisted_call() -> Weight;
 	fn dispatch_whitelisted_call(n: u32, ) -> Weight;
 	fn dispatch_whitelisted_call_with_preimage(n: u32, ) -> Weight;
+	fn expire_whitelisted_calls(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_whitelist using the Substrate node and recommended hardware.
@@ -78,14 +85,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:0 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn whitelist_call_with_expiry() -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `whitelist_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3990`
+		// Minimum execution time: 19_914_000 picoseconds.
+		Weight::from_parts(20_892_000, 3990)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
+	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	fn remove_whitelisted_call() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `346`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 18_142_000 picoseconds.
-		Weight::from_parts(18_529_000, 3556)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_parts(18_529_000, 3990)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
@@ -93,34 +122,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 4194294]`.
 	fn dispatch_whitelisted_call(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `422 + n * (1 ±0)`
-		//  Estimated: `3886 + n * (1 ±0)`
+		//  Estimated: `3990 + n * (1 ±0)`
 		// Minimum execution time: 30_671_000 picoseconds.
-		Weight::from_parts(31_197_000, 3886)
+		Weight::from_parts(31_197_000, 3990)
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
 	}
 	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10000]`.
 	fn dispatch_whitelisted_call_with_preimage(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `346`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 22_099_000 picoseconds.
-		Weight::from_parts(23_145_477, 3556)
+		Weight::from_parts(23_145_477, 3990)
 			// Standard Error: 5
 			.saturating_add(Weight::from_parts(1_422, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:0 w:n)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist WhitelistedCall (r:n w:n)
+	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:n w:n)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_whitelisted_calls(n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `remove_whitelisted_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `183 + n * (112 ±0)`
+		//  Estimated: `3990 + n * (2566 ±0)`
+		// Minimum execution time: 3_208_000 picoseconds.
+		Weight::from_parts(3_614_000, 3990)
+			// Standard Error: 9_250
+			.saturating_add(Weight::from_parts(17_985_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2566).saturating_mul(n.into()))
 	}
 }
 
@@ -143,14 +204,36 @@ impl WeightInfo for () {
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:0 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn whitelist_call_with_expiry() -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `whitelist_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3990`
+		// Minimum execution time: 19_914_000 picoseconds.
+		Weight::from_parts(20_892_000, 3990)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
+	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:1 w:1)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	fn remove_whitelisted_call() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `346`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 18_142_000 picoseconds.
-		Weight::from_parts(18_529_000, 3556)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_parts(18_529_000, 3990)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
@@ -158,33 +241,65 @@ impl WeightInfo for () {
 	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 4194294]`.
 	fn dispatch_whitelisted_call(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `422 + n * (1 ±0)`
-		//  Estimated: `3886 + n * (1 ±0)`
+		//  Estimated: `3990 + n * (1 ±0)`
 		// Minimum execution time: 30_671_000 picoseconds.
-		Weight::from_parts(31_197_000, 3886)
+		Weight::from_parts(31_197_000, 3990)
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_163, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
 	}
 	/// Storage: Whitelist WhitelistedCall (r:1 w:1)
 	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:1 w:1)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
 	/// The range of component `n` is `[1, 10000]`.
 	fn dispatch_whitelisted_call_with_preimage(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `346`
-		//  Estimated: `3556`
+		//  Estimated: `3990`
 		// Minimum execution time: 22_099_000 picoseconds.
-		Weight::from_parts(23_145_477, 3556)
+		Weight::from_parts(23_145_477, 3990)
 			// Standard Error: 5
 			.saturating_add(Weight::from_parts(1_422, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Whitelist Expiring (r:1 w:1)
+	/// Proof: Whitelist Expiring (max_values: None, max_size: Some(525), added: 3000, mode: MaxEncodedLen)
+	/// Storage: Whitelist CallExpiry (r:0 w:n)
+	/// Proof: Whitelist CallExpiry (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist WhitelistedCall (r:n w:n)
+	/// Proof: Whitelist WhitelistedCall (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Preimage StatusFor (r:n w:n)
+	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn expire_whitelisted_calls(n: u32, ) -> Weight {
+		// PLACEHOLDER: not benchmarked, derived from `remove_whitelisted_call`.
+		// Proof Size summary in bytes:
+		//  Measured:  `183 + n * (112 ±0)`
+		//  Estimated: `3990 + n * (2566 ±0)`
+		// Minimum execution time: 3_208_000 picoseconds.
+		Weight::from_parts(3_614_000, 3990)
+			// Standard Error: 9_250
+			.saturating_add(Weight::from_parts(17_985_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2566).saturating_mul(n.into()))
 	}
 }
@@ -146,6 +146,35 @@ pub trait RuntimeAppPublic: Sized {
 
 	/// Returns `Self` as raw vec.
 	fn to_raw_vec(&self) -> Vec<u8>;
+
+	/// Generate a proof that the owner of the private key of this public key allows `owner` to
+	/// register it, e.g. as a session key.
+	///
+	/// The private key will be requested from the keystore.
+	///
+	/// Returns the proof or `None` if the private key could not be found or some other error
+	/// occurred.
+	fn generate_proof_of_possession(&self, owner: &[u8]) -> Option<Self::Signature> {
+		self.sign(&proof_of_possession_statement(Self::ID, &self.to_raw_vec(), owner))
+	}
+
+	/// Verify that `proof` was generated by [`Self::generate_proof_of_possession`] for `owner`.
+	///
+	/// Schemes whose signatures may be aggregated rely on this to rule out rogue keys, i.e. keys
+	/// derived from the keys of others whose private key is unknown to their owner.
+	fn verify_proof_of_possession(&self, owner: &[u8], proof: &Self::Signature) -> bool {
+		self.verify(&proof_of_possession_statement(Self::ID, &self.to_raw_vec(), owner), proof)
+	}
+}
+
+/// The statement signed by a proof of possession of the private key of the `public` key of
+/// type `key_type` for `owner`.
+pub fn proof_of_possession_statement(key_type: KeyTypeId, public: &[u8], owner: &[u8]) -> Vec<u8> {
+	let mut statement = b"POP_".to_vec();
+	statement.extend_from_slice(&key_type.0);
+	statement.extend_from_slice(public);
+	statement.extend_from_slice(owner);
+	statement
 }
 
 impl<T> RuntimeAppPublic for T
@@ -0,0 +1,543 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decimal fixed point number with a 256-bit inner value.
+//!
+//! [`FixedU256`] has the same accuracy as [`FixedU128`], but a far larger range and 512-bit
+//! intermediate values in multiplications and divisions. It is meant for math whose intermediate
+//! values overflow or lose precision with [`FixedU128`], e.g. products of pool reserves or fee
+//! curves with large exponents. Results are converted back into [`FixedU128`] or integers once
+//! computed.
+
+use crate::{
+	fixed_point::{FixedPointNumber, FixedU128, FixedU64},
+	traits::{Bounded, One, Saturating, Zero},
+	ArithmeticError, PerThing, Rounding,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitive_types::{U256, U512};
+use scale_info::TypeInfo;
+use sp_std::ops;
+
+/// The accuracy of [`FixedU256`], equal to the accuracy of [`FixedU128`].
+const DIV: U256 = U256([1_000_000_000_000_000_000, 0, 0, 0]);
+
+/// An unsigned fixed point number with 18 decimals of accuracy and a 256-bit inner value. Its
+/// range is
+/// `[0.000000000000000000, 115792089237316195423570985008687907853269984665640564039457.584007913129639935]`.
+///
+/// Multiplications and divisions are computed with a 512-bit intermediate value, so they only fail
+/// if their result overflows.
+#[derive(
+	Encode, Decode, Default, Copy, Clone, MaxEncodedLen, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
+pub struct FixedU256(U256);
+
+impl FixedU256 {
+	/// The accuracy of the type.
+	pub const DIV: U256 = DIV;
+
+	/// Create a new instance from the given `inner` value.
+	pub const fn from_inner(inner: U256) -> Self {
+		Self(inner)
+	}
+
+	/// Return the instance's inner value.
+	pub const fn into_inner(self) -> U256 {
+		self.0
+	}
+
+	/// Create a new instance from an integer.
+	pub fn from_integer(n: u128) -> Self {
+		Self(U256::from(n).saturating_mul(DIV))
+	}
+
+	/// Calculate the rational `n / d`, returning `None` if `d` is zero.
+	///
+	/// Result will be rounded to the nearest representable value, rounding down if it is
+	/// equidistant between two neighbours.
+	pub fn checked_from_rational(n: u128, d: u128) -> Option<Self> {
+		Self::checked_from_rational_with_rounding(n, d, Rounding::NearestPrefDown)
+	}
+
+	/// Calculate the rational `n / d` with custom rounding, returning `None` if `d` is zero.
+	pub fn checked_from_rational_with_rounding(
+		n: u128,
+		d: u128,
+		rounding: Rounding,
+	) -> Option<Self> {
+		if d == 0 {
+			return None
+		}
+		let inner = div_rounded(U256::from(n).full_mul(DIV), U256::from(d).into(), rounding);
+		U256::try_from(inner).ok().map(Self)
+	}
+
+	/// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+	pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		self.0.checked_add(rhs.0).map(Self)
+	}
+
+	/// Checked subtraction. Computes `self - rhs`, returning `None` if underflow occurred.
+	pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		self.0.checked_sub(rhs.0).map(Self)
+	}
+
+	/// Checked multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+	///
+	/// Result will be rounded to the nearest representable value, rounding down if it is
+	/// equidistant between two neighbours.
+	pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		self.checked_mul_with_rounding(rhs, Rounding::NearestPrefDown)
+	}
+
+	/// Checked multiplication with custom rounding. Computes `self * rhs`, returning `None` if
+	/// overflow occurred.
+	pub fn checked_mul_with_rounding(&self, rhs: &Self, rounding: Rounding) -> Option<Self> {
+		let inner = div_rounded(self.0.full_mul(rhs.0), DIV.into(), rounding);
+		U256::try_from(inner).ok().map(Self)
+	}
+
+	/// Checked division. Computes `self / rhs`, returning `None` if `rhs` is zero or overflow
+	/// occurred.
+	///
+	/// Result will be rounded to the nearest representable value, rounding down if it is
+	/// equidistant between two neighbours.
+	pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		self.checked_div_with_rounding(rhs, Rounding::NearestPrefDown)
+	}
+
+	/// Checked division with custom rounding. Computes `self / rhs`, returning `None` if `rhs` is
+	/// zero or overflow occurred.
+	pub fn checked_div_with_rounding(&self, rhs: &Self, rounding: Rounding) -> Option<Self> {
+		if rhs.0.is_zero() {
+			return None
+		}
+		let inner = div_rounded(self.0.full_mul(DIV), rhs.0.into(), rounding);
+		U256::try_from(inner).ok().map(Self)
+	}
+
+	/// Checked exponentiation. Computes `self ^ exp`, returning `None` if overflow occurred.
+	///
+	/// Every intermediate multiplication is rounded as in [`Self::checked_mul`].
+	pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+		let mut result = Self::one();
+		let mut base = *self;
+		let mut exp = exp;
+		while exp > 0 {
+			if exp & 1 == 1 {
+				result = result.checked_mul(&base)?;
+			}
+			exp >>= 1;
+			if exp > 0 {
+				base = base.checked_mul(&base)?;
+			}
+		}
+		Some(result)
+	}
+
+	/// Take the square root, rounded down.
+	///
+	/// This never overflows, as the square root of every value fits into the type.
+	pub fn sqrt(self) -> Self {
+		// sqrt(n / D) = sqrt(n * D) / D, where `n * D` fits into 512 bits.
+		let root = self.0.full_mul(DIV).integer_sqrt();
+		Self(U256::try_from(root).expect("the root of a 512-bit value fits in 256 bits; qed"))
+	}
+
+	/// Multiply an integer by `self`, rounding down, and return the integer part of the result.
+	/// Returns `None` if the result doesn't fit into a `u128`.
+	pub fn checked_mul_int(&self, n: u128) -> Option<u128> {
+		let product = div_rounded(self.0.full_mul(U256::from(n)), DIV.into(), Rounding::Down);
+		u128::try_from(product).ok()
+	}
+
+	/// Multiply an integer by `self`, rounding down, saturating at the numeric bounds of `u128`.
+	pub fn saturating_mul_int(&self, n: u128) -> u128 {
+		self.checked_mul_int(n).unwrap_or(u128::MAX)
+	}
+
+	/// Return the integer part of `self`, rounded down. Returns `None` if it doesn't fit into a
+	/// `u128`.
+	pub fn checked_into_integer(&self) -> Option<u128> {
+		u128::try_from(self.0 / DIV).ok()
+	}
+
+	/// Convert into a [`FixedU128`], returning an error if `self` is out of its range.
+	pub fn checked_into_fixed_u128(&self) -> Result<FixedU128, ArithmeticError> {
+		u128::try_from(self.0)
+			.map(FixedU128::from_inner)
+			.map_err(|_| ArithmeticError::Overflow)
+	}
+
+	/// Convert into a [`FixedU128`], saturating at its maximum value.
+	pub fn saturating_into_fixed_u128(&self) -> FixedU128 {
+		self.checked_into_fixed_u128().unwrap_or_else(|_| FixedU128::max_value())
+	}
+
+	/// Convert into a `PerThing`, rounding down. Saturates if `self` is greater than one.
+	pub fn into_clamped_perthing<P: PerThing>(self) -> P {
+		if self >= Self::one() {
+			return P::one()
+		}
+		// `self` is below one, so its inner value fits into a `u128`.
+		P::from_rational_with_rounding(self.0.low_u128(), DIV.low_u128(), Rounding::Down)
+			.unwrap_or_else(|_| P::one())
+	}
+
+	/// Convert into a `float` value.
+	#[cfg(any(feature = "std", test))]
+	pub fn to_float(self) -> f64 {
+		let inner = (self.0).0.iter().rev().fold(0f64, |acc, l| acc * 2f64.powi(64) + *l as f64);
+		inner / DIV.low_u64() as f64
+	}
+}
+
+/// Divide `n` by `d` with the given `rounding`. `d` must not be zero.
+fn div_rounded(n: U512, d: U512, rounding: Rounding) -> U512 {
+	let (quotient, remainder) = n.div_mod(d);
+	if remainder.is_zero() {
+		return quotient
+	}
+	// `d` is at most 256 bits, so doubling the remainder can't overflow.
+	let round_up = match rounding {
+		Rounding::Down => false,
+		Rounding::Up => true,
+		Rounding::NearestPrefDown => remainder * 2 > d,
+		Rounding::NearestPrefUp => remainder * 2 >= d,
+	};
+	if round_up {
+		quotient.saturating_add(U512::one())
+	} else {
+		quotient
+	}
+}
+
+impl Zero for FixedU256 {
+	fn zero() -> Self {
+		Self(U256::zero())
+	}
+
+	fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+}
+
+impl One for FixedU256 {
+	fn one() -> Self {
+		Self(DIV)
+	}
+}
+
+impl Bounded for FixedU256 {
+	fn min_value() -> Self {
+		Self(U256::zero())
+	}
+
+	fn max_value() -> Self {
+		Self(U256::max_value())
+	}
+}
+
+impl Saturating for FixedU256 {
+	fn saturating_add(self, rhs: Self) -> Self {
+		Self(self.0.saturating_add(rhs.0))
+	}
+
+	fn saturating_sub(self, rhs: Self) -> Self {
+		Self(self.0.saturating_sub(rhs.0))
+	}
+
+	fn saturating_mul(self, rhs: Self) -> Self {
+		self.checked_mul(&rhs).unwrap_or_else(Self::max_value)
+	}
+
+	fn saturating_pow(self, exp: usize) -> Self {
+		u32::try_from(exp)
+			.ok()
+			.and_then(|exp| self.checked_pow(exp))
+			.unwrap_or_else(|| if self > Self::one() { Self::max_value() } else { Self::zero() })
+	}
+}
+
+impl ops::Add for FixedU256 {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		self.checked_add(&rhs).expect("attempt to add with overflow")
+	}
+}
+
+impl ops::Sub for FixedU256 {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.checked_sub(&rhs).expect("attempt to subtract with overflow")
+	}
+}
+
+impl ops::Mul for FixedU256 {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.checked_mul(&rhs).expect("attempt to multiply with overflow")
+	}
+}
+
+impl ops::Div for FixedU256 {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		if rhs.is_zero() {
+			panic!("attempt to divide by zero")
+		}
+		self.checked_div(&rhs).expect("attempt to divide with overflow")
+	}
+}
+
+impl From<u128> for FixedU256 {
+	fn from(n: u128) -> Self {
+		Self::from_integer(n)
+	}
+}
+
+impl From<FixedU128> for FixedU256 {
+	fn from(n: FixedU128) -> Self {
+		// Both types have the same accuracy.
+		Self(U256::from(n.into_inner()))
+	}
+}
+
+impl From<FixedU64> for FixedU256 {
+	fn from(n: FixedU64) -> Self {
+		let scale = DIV / U256::from(FixedU64::DIV);
+		Self(U256::from(n.into_inner()) * scale)
+	}
+}
+
+impl<P: PerThing> From<P> for FixedU256 {
+	fn from(p: P) -> Self {
+		let accuracy: u128 = P::ACCURACY.into();
+		let parts: u128 = p.deconstruct().into();
+		Self::checked_from_rational(parts, accuracy)
+			.expect("accuracy of a `PerThing` is non-zero; qed")
+	}
+}
+
+impl TryFrom<FixedU256> for FixedU128 {
+	type Error = ArithmeticError;
+
+	fn try_from(n: FixedU256) -> Result<Self, Self::Error> {
+		n.checked_into_fixed_u128()
+	}
+}
+
+impl sp_std::fmt::Debug for FixedU256 {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "FixedU256({}.{:0>18})", self.0 / DIV, (self.0 % DIV).low_u128())
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{traits::CheckedMul, Perbill, Percent};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	const RUNS: usize = 10_000;
+
+	fn rng() -> StdRng {
+		StdRng::seed_from_u64(0x5eed)
+	}
+
+	/// A random value spread over the whole range of the type, with a bias towards small values.
+	fn random_u256(rng: &mut StdRng) -> U256 {
+		let bits = rng.gen_range(0..=256u32);
+		let value = U256([rng.gen(), rng.gen(), rng.gen(), rng.gen()]);
+		if bits == 256 {
+			value
+		} else {
+			value & ((U256::one() << bits) - 1)
+		}
+	}
+
+	fn random_fixed(rng: &mut StdRng) -> FixedU256 {
+		FixedU256::from_inner(random_u256(rng))
+	}
+
+	#[test]
+	fn basic_arithmetic_works() {
+		let a = FixedU256::from_integer(6);
+		let b = FixedU256::checked_from_rational(3, 2).unwrap();
+
+		assert_eq!(a + b, FixedU256::checked_from_rational(15, 2).unwrap());
+		assert_eq!(a - b, FixedU256::checked_from_rational(9, 2).unwrap());
+		assert_eq!(a * b, FixedU256::from_integer(9));
+		assert_eq!(a / b, FixedU256::from_integer(4));
+		assert_eq!(b.checked_pow(3), FixedU256::checked_from_rational(27, 8));
+		assert_eq!(FixedU256::from_integer(16).sqrt(), FixedU256::from_integer(4));
+		assert_eq!(b.checked_mul_int(7), Some(10));
+		assert_eq!(FixedU256::checked_from_rational(1, 0), None);
+		assert_eq!(a.checked_div(&FixedU256::zero()), None);
+	}
+
+	#[test]
+	fn rounding_works() {
+		let third = |rounding| {
+			FixedU256::checked_from_rational_with_rounding(1, 3, rounding).unwrap().into_inner()
+		};
+		assert_eq!(third(Rounding::Down), U256::from(333_333_333_333_333_333u128));
+		assert_eq!(third(Rounding::Up), U256::from(333_333_333_333_333_334u128));
+		assert_eq!(third(Rounding::NearestPrefUp), U256::from(333_333_333_333_333_333u128));
+
+		// Multiplying the smallest values rounds to either of its neighbours when equidistant.
+		let five = FixedU256::from_inner(U256::from(5));
+		let tenth = FixedU256::checked_from_rational(1, 10).unwrap();
+		assert_eq!(five.checked_mul(&tenth), Some(FixedU256::zero()));
+		assert_eq!(
+			five.checked_mul_with_rounding(&tenth, Rounding::NearestPrefUp),
+			Some(FixedU256::from_inner(U256::one())),
+		);
+	}
+
+	#[test]
+	fn intermediate_values_do_not_overflow() {
+		// The product of two reserves overflows `FixedU128`, but not its square root.
+		let reserve = FixedU128::from_u32(u32::MAX).saturating_mul(FixedU128::from_u32(u32::MAX));
+		assert!(reserve.checked_mul(&reserve).is_none());
+
+		let wide_reserve = FixedU256::from(reserve);
+		let product = wide_reserve.checked_mul(&wide_reserve).unwrap();
+		assert_eq!(product.sqrt(), wide_reserve);
+		assert_eq!(FixedU128::try_from(product.sqrt()), Ok(reserve));
+	}
+
+	#[test]
+	fn conversions_work() {
+		let half = FixedU256::checked_from_rational(1, 2).unwrap();
+		let quarter = FixedU256::checked_from_rational(1, 4).unwrap();
+		let third = FixedU128::from_rational(1, 3);
+		assert_eq!(FixedU256::from(third).into_inner(), U256::from(third.into_inner()));
+		assert_eq!(FixedU256::from(FixedU64::from_rational(1, 4)), quarter);
+		assert_eq!(FixedU256::from(Percent::from_percent(50)), half);
+		assert_eq!(quarter.into_clamped_perthing::<Perbill>(), Perbill::from_percent(25));
+		assert_eq!(FixedU256::from_integer(2).into_clamped_perthing::<Perbill>(), Perbill::one());
+		assert_eq!(FixedU128::try_from(FixedU256::max_value()), Err(ArithmeticError::Overflow));
+		assert_eq!(FixedU256::max_value().saturating_into_fixed_u128(), FixedU128::max_value());
+		assert_eq!(FixedU256::from_integer(u128::MAX).checked_into_integer(), Some(u128::MAX));
+	}
+
+	#[test]
+	fn fixed_u128_roundtrip_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let n = FixedU128::from_inner(rng.gen());
+			assert_eq!(FixedU128::try_from(FixedU256::from(n)), Ok(n));
+		}
+	}
+
+	#[test]
+	fn mul_matches_fixed_u128_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let a = FixedU128::from_inner(rng.gen::<u64>() as u128 * rng.gen::<u32>() as u128);
+			let b = FixedU128::from_inner(rng.gen::<u64>() as u128);
+			let wide = FixedU256::from(a).checked_mul(&FixedU256::from(b)).unwrap();
+			assert_eq!(a.checked_mul(&b).map(FixedU256::from), Some(wide));
+		}
+	}
+
+	#[test]
+	fn mul_div_roundtrip_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let a = random_fixed(&mut rng);
+			let b = random_fixed(&mut rng);
+			let Some(product) = a.checked_mul_with_rounding(&b, Rounding::Down) else {
+				// Overflow only happens if the exact product exceeds the range.
+				assert!(a.0.full_mul(b.0) / U512::from(DIV) > U512::from(U256::max_value()));
+				continue
+			};
+			if b.is_zero() {
+				continue
+			}
+			// Rounding down loses less than one unit of accuracy in the product, which becomes at
+			// most `1 / b` after dividing back.
+			let quotient = product.checked_div_with_rounding(&b, Rounding::Up).unwrap();
+			assert!(quotient <= a);
+			let error = a.0 - quotient.0;
+			assert!(error <= U256::one() + DIV / b.0, "{:?} * {:?} = {:?}", a, b, product);
+		}
+	}
+
+	#[test]
+	fn rounding_bounds_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let a = random_fixed(&mut rng);
+			let b = random_fixed(&mut rng);
+			let (Some(down), Some(up)) = (
+				a.checked_div_with_rounding(&b, Rounding::Down),
+				a.checked_div_with_rounding(&b, Rounding::Up),
+			) else {
+				continue
+			};
+			let nearest = a.checked_div(&b).unwrap();
+			assert!(down <= nearest && nearest <= up);
+			assert!(up.0 - down.0 <= U256::one());
+		}
+	}
+
+	#[test]
+	fn sqrt_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let a = random_fixed(&mut rng);
+			let root = a.sqrt();
+			// `root` is the largest value whose square doesn't exceed `a`.
+			let square = root.0.full_mul(root.0);
+			let next = root.0 + 1;
+			assert!(square <= a.0.full_mul(DIV));
+			assert!(next.full_mul(next) > a.0.full_mul(DIV));
+		}
+	}
+
+	#[test]
+	fn pow_matches_repeated_mul_property() {
+		let mut rng = rng();
+		for _ in 0..RUNS {
+			let a = FixedU256::checked_from_rational(rng.gen_range(0..4_000), 1_000).unwrap();
+			let exp = rng.gen_range(0..64);
+			let expected = (0..exp).try_fold(FixedU256::one(), |acc, _| acc.checked_mul(&a));
+			let pow = a.checked_pow(exp);
+			match (pow, expected) {
+				(Some(pow), Some(expected)) => {
+					// Squaring rounds differently than repeated multiplication.
+					let error = if pow > expected { pow - expected } else { expected - pow };
+					assert!(error.to_float() <= pow.to_float() * 1e-15 + 1e-15);
+				},
+				(pow, expected) => assert_eq!(pow.is_none(), expected.is_none()),
+			}
+		}
+	}
+}
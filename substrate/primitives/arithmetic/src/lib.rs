@@ -35,6 +35,7 @@ macro_rules! assert_eq_error_rate {
 
 pub mod biguint;
 pub mod fixed_point;
+pub mod fixed_u256;
 pub mod helpers_128bit;
 pub mod per_things;
 pub mod rational;
@@ -43,6 +44,7 @@ pub mod traits;
 pub use fixed_point::{
 	FixedI128, FixedI64, FixedPointNumber, FixedPointOperand, FixedU128, FixedU64,
 };
+pub use fixed_u256::FixedU256;
 pub use per_things::{
 	InnerOf, MultiplyArg, PerThing, PerU16, Perbill, Percent, Permill, Perquintill, RationalArg,
 	ReciprocalArg, Rounding, SignedRounding, UpperOf,
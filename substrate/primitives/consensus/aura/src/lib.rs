@@ -62,7 +62,7 @@ pub mod ed25519 {
 	pub type AuthorityId = app_ed25519::Public;
 }
 
-pub use sp_consensus_slots::{Slot, SlotDuration};
+pub use sp_consensus_slots::{Slot, SlotDuration, SlotPerformance};
 
 /// The `ConsensusEngineId` of AuRa.
 pub const AURA_ENGINE_ID: ConsensusEngineId = [b'a', b'u', b'r', b'a'];
@@ -92,4 +92,10 @@ sp_api::decl_runtime_apis! {
 		/// Return the current set of authorities.
 		fn authorities() -> Vec<AuthorityId>;
 	}
+
+	/// API to query the authoring performance of the aura authorities.
+	pub trait AuraPerformanceApi<AuthorityId: Codec> {
+		/// Returns the authoring performance of each current authority in the current session.
+		fn authoring_performance() -> Vec<(AuthorityId, SlotPerformance)>;
+	}
 }
@@ -43,7 +43,7 @@ pub use payload::{known_payloads, BeefyPayloadId, Payload, PayloadProvider};
 #[cfg(feature = "std")]
 pub use test_utils::*;
 
-use codec::{Codec, Decode, Encode};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_application_crypto::RuntimeAppPublic;
 use sp_core::H256;
@@ -241,6 +241,35 @@ impl<AuthorityId> ValidatorSet<AuthorityId> {
 /// The index of an authority.
 pub type AuthorityIndex = u32;
 
+/// The signature scheme a BEEFY validator set commits with.
+///
+/// The scheme is negotiated by the runtime and only changes at session boundaries, i.e. together
+/// with the validator set, so that all commitments of a validator set are signed with the same
+/// scheme.
+#[derive(Decode, Encode, Debug, Default, PartialEq, Eq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum SignatureScheme {
+	/// Individual ECDSA signatures of the validators.
+	#[default]
+	#[codec(index = 0)]
+	Ecdsa,
+	/// ECDSA signatures of the validators, alongside BLS signatures which can be aggregated into
+	/// a single signature to shrink the size of the commitment proofs.
+	#[codec(index = 1)]
+	EcdsaBls,
+}
+
+impl SignatureScheme {
+	/// Whether validator sets can be scheduled to commit with the signature scheme.
+	///
+	/// The BEEFY voter doesn't sign BLS commitments yet, so only ECDSA is supported for now.
+	pub fn is_supported(&self) -> bool {
+		match self {
+			SignatureScheme::Ecdsa => true,
+			SignatureScheme::EcdsaBls => false,
+		}
+	}
+}
+
 /// The Hashing used within MMR.
 pub type MmrHashing = Keccak256;
 /// The type used to represent an MMR root hash.
@@ -258,6 +287,10 @@ pub enum ConsensusLog<AuthorityId: Codec> {
 	/// MMR root hash.
 	#[codec(index = 3)]
 	MmrRoot(MmrRootHash),
+	/// The validator set with the given id commits with a different signature scheme than its
+	/// predecessor.
+	#[codec(index = 4)]
+	SignatureSchemeChange(ValidatorSetId, SignatureScheme),
 }
 
 /// BEEFY vote message.
@@ -390,7 +423,7 @@ impl OpaqueKeyOwnershipProof {
 
 sp_api::decl_runtime_apis! {
 	/// API necessary for BEEFY voters.
-	#[api_version(3)]
+	#[api_version(4)]
 	pub trait BeefyApi<AuthorityId> where
 		AuthorityId : Codec + RuntimeAppPublic,
 	{
@@ -429,6 +462,10 @@ sp_api::decl_runtime_apis! {
 			set_id: ValidatorSetId,
 			authority_id: AuthorityId,
 		) -> Option<OpaqueKeyOwnershipProof>;
+
+		/// Return the signature scheme the current active BEEFY validator set commits with.
+		#[api_version(4)]
+		fn signature_scheme() -> SignatureScheme;
 	}
 
 }
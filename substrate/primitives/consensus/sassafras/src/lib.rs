@@ -38,7 +38,7 @@ pub mod vrf;
 
 pub use ticket::{
 	ticket_id_threshold, EphemeralPublic, EphemeralSignature, TicketBody, TicketClaim,
-	TicketEnvelope, TicketId,
+	TicketEnvelope, TicketId, TicketsStatistics,
 };
 
 mod app {
@@ -124,6 +124,7 @@ pub struct OpaqueKeyOwnershipProof(Vec<u8>);
 // Runtime API.
 sp_api::decl_runtime_apis! {
 	/// API necessary for block authorship with Sassafras.
+	#[api_version(2)]
 	pub trait SassafrasApi {
 		/// Get ring context to be used for ticket construction and verification.
 		fn ring_context() -> Option<vrf::RingContext>;
@@ -144,6 +145,13 @@ sp_api::decl_runtime_apis! {
 		/// Next epoch information.
 		fn next_epoch() -> Epoch;
 
+		/// Statistics of the tickets submitted for the epoch `epoch_idx`.
+		///
+		/// Returns `None` if the statistics of the epoch are not known, e.g. because they were
+		/// already pruned.
+		#[api_version(2)]
+		fn tickets_statistics(epoch_idx: u64) -> Option<TicketsStatistics>;
+
 		/// Generates a proof of key ownership for the given authority in the current epoch.
 		///
 		/// An example usage of this module is coupled with the session historical module to prove
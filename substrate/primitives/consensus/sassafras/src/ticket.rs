@@ -55,6 +55,25 @@ pub struct TicketEnvelope {
 	pub signature: TicketSignature,
 }
 
+/// Statistics of the tickets submitted for an epoch.
+///
+/// Tickets are anonymous, so the submissions of each validator are bounded by the number of
+/// attempts it is allowed, i.e. `EpochConfiguration::attempts_number`, which the attempt index of
+/// a ticket is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct TicketsStatistics {
+	/// Number of tickets which were submitted.
+	pub submitted: u32,
+	/// Number of submitted tickets which were accepted.
+	pub accepted: u32,
+	/// Number of submitted tickets which were rejected because their attempt index exceeds the
+	/// number of attempts allowed per validator.
+	pub over_quota: u32,
+	/// Number of submitted tickets which were rejected for any other reason, e.g. for an invalid
+	/// signature or a score above the epoch threshold.
+	pub invalid: u32,
+}
+
 /// Ticket claim information filled by the block author.
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct TicketClaim {
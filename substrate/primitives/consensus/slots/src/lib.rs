@@ -174,3 +174,12 @@ pub struct EquivocationProof<Header, Id> {
 	/// The second header involved in the equivocation.
 	pub second_header: Header,
 }
+
+/// The authoring performance of an authority over a period of time, e.g. a session.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct SlotPerformance {
+	/// The number of slots in which the authority was expected to author a block.
+	pub expected: u32,
+	/// The number of slots in which the authority authored a block.
+	pub produced: u32,
+}
@@ -205,6 +205,15 @@ pub trait Storage {
 		self.exists_storage(key)
 	}
 
+	/// Returns the data for each of the given `keys` in the storage, in the same order, with
+	/// `None` for every key that can not be found.
+	///
+	/// This is equivalent to calling [`get`](storage::get) for each key, but crosses the wasm
+	/// boundary only once.
+	fn batch_get(&self, keys: &[Vec<u8>]) -> Vec<Option<Vec<u8>>> {
+		keys.iter().map(|key| self.storage(key)).collect()
+	}
+
 	/// Clear the storage of each key-value pair where the key starts with the given `prefix`.
 	fn clear_prefix(&mut self, prefix: &[u8]) {
 		let _ = Externalities::clear_prefix(*self, prefix, None, None);
@@ -682,6 +691,37 @@ pub trait Trie {
 	}
 }
 
+/// Receiver of the storage access counters reported by the runtime.
+///
+/// See [`storage_metrics::report`].
+#[cfg(feature = "std")]
+pub trait StorageMetricsSink: Send + Sync {
+	/// Record the storage accesses performed while dispatching `call` of `pallet`.
+	fn report(&self, pallet: &str, call: &str, reads: u32, writes: u32, rollbacks: u32);
+}
+
+#[cfg(feature = "std")]
+sp_externalities::decl_extension! {
+	/// Extension that receives the storage access counters reported by the runtime.
+	///
+	/// When this extension is not registered, [`storage_metrics::report`] is a no-op.
+	pub struct StorageMetricsExt(std::sync::Arc<dyn StorageMetricsSink>);
+}
+
+/// Interface for reporting storage access counters of the runtime to the node.
+#[runtime_interface]
+pub trait StorageMetrics {
+	/// Report the number of storage reads, writes and rolled back transactional layers which
+	/// happened while dispatching `call` of `pallet`.
+	///
+	/// The values are forwarded to the [`StorageMetricsExt`], if one is registered.
+	fn report(&mut self, pallet: &str, call: &str, reads: u32, writes: u32, rollbacks: u32) {
+		if let Some(sink) = self.extension::<StorageMetricsExt>() {
+			sink.report(pallet, call, reads, writes, rollbacks);
+		}
+	}
+}
+
 /// Interface that provides miscellaneous functions for communicating between the runtime and the
 /// node.
 #[runtime_interface]
@@ -1815,6 +1855,7 @@ pub type SubstrateHostFunctions = (
 	crate::trie::HostFunctions,
 	offchain_index::HostFunctions,
 	transaction_index::HostFunctions,
+	storage_metrics::HostFunctions,
 );
 
 #[cfg(test)]
@@ -422,7 +422,7 @@ impl Error {
 
 sp_api::decl_runtime_apis! {
 	/// API to interact with MMR pallet.
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait MmrApi<Hash: codec::Codec, BlockNumber: codec::Codec> {
 		/// Return the on-chain MMR root hash.
 		fn mmr_root() -> Result<Hash, Error>;
@@ -437,6 +437,18 @@ sp_api::decl_runtime_apis! {
 			best_known_block_number: Option<BlockNumber>
 		) -> Result<(Vec<EncodableOpaqueLeaf>, Proof<Hash>), Error>;
 
+		/// Generate a single MMR proof for an arbitrary, not necessarily contiguous, set of leaf
+		/// indices. If `best_known_block_number = Some(n)`, use historical MMR state at given block
+		/// height `n`. Else, use current MMR state.
+		///
+		/// The leaf indices are sorted and deduplicated, and the returned leaves have the same
+		/// position as their indices in the `leaf_indices` vector contained in the [Proof].
+		#[api_version(3)]
+		fn generate_leaf_proof(
+			leaf_indices: Vec<LeafIndex>,
+			best_known_block_number: Option<BlockNumber>
+		) -> Result<(Vec<EncodableOpaqueLeaf>, Proof<Hash>), Error>;
+
 		/// Verify MMR proof against on-chain MMR for a batch of leaves.
 		///
 		/// Note this function will use on-chain MMR root hash and check if the proof matches the hash.
@@ -20,11 +20,11 @@
 use codec::Encode;
 use mmr_lib::helper;
 
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Header, One};
+use sp_runtime::traits::{self, CheckedAdd, CheckedSub, Header, One};
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::Vec;
 
-use crate::{Error, LeafIndex, NodeIndex};
+use crate::{DataOrHash, Error, FullLeaf, LeafIndex, NodeIndex, Proof};
 
 /// Get the first block with MMR.
 pub fn first_mmr_block_num<H: Header>(
@@ -59,6 +59,60 @@ pub fn block_num_to_leaf_index<H: Header>(
 	})
 }
 
+/// Default Merging & Hashing behavior for MMR.
+pub struct Hasher<H, L>(sp_std::marker::PhantomData<(H, L)>);
+
+impl<H: traits::Hash, L: FullLeaf> mmr_lib::Merge for Hasher<H, L> {
+	type Item = DataOrHash<H, L>;
+
+	fn merge(left: &Self::Item, right: &Self::Item) -> mmr_lib::Result<Self::Item> {
+		let mut concat = left.hash().as_ref().to_vec();
+		concat.extend_from_slice(right.hash().as_ref());
+
+		Ok(DataOrHash::Hash(<H as traits::Hash>::hash(&concat)))
+	}
+}
+
+/// Stateless verification of the proof for a batch of leaves.
+///
+/// The leaves don't need to be contiguous, nor sorted: the leaves should only be in the same order
+/// as their indices in the `leaf_indices` vector contained in the [`Proof`].
+///
+/// Returns `Ok(false)` if the proof is well-formed but doesn't match `root`.
+pub fn verify_leaves_proof<H, L>(
+	root: H::Output,
+	leaves: Vec<DataOrHash<H, L>>,
+	proof: Proof<H::Output>,
+) -> Result<bool, Error>
+where
+	H: traits::Hash,
+	L: FullLeaf,
+{
+	let size = NodesUtils::new(proof.leaf_count).size();
+
+	if leaves.len() != proof.leaf_indices.len() {
+		return Err(Error::Verify.log_debug("Proof leaf_indices not same length with leaves"))
+	}
+
+	let mut leaves_and_position_data: Vec<_> = proof
+		.leaf_indices
+		.into_iter()
+		.map(|index| helper::leaf_index_to_pos(index))
+		.zip(leaves.into_iter())
+		.collect();
+	leaves_and_position_data.sort_by_key(|(pos, _)| *pos);
+	if leaves_and_position_data.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+		return Err(Error::Verify.log_debug("Proof contains duplicate leaf indices"))
+	}
+
+	let p = mmr_lib::MerkleProof::<DataOrHash<H, L>, Hasher<H, L>>::new(
+		size,
+		proof.items.into_iter().map(DataOrHash::Hash).collect(),
+	);
+	p.verify(DataOrHash::Hash(root), leaves_and_position_data)
+		.map_err(|e| Error::Verify.log_debug(e))
+}
+
 /// MMR nodes & size -related utilities.
 pub struct NodesUtils {
 	no_of_leaves: LeafIndex,
@@ -71,6 +71,7 @@ pub fn into_v14(metadata: MetadataIR) -> RuntimeMetadataPrefixed {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use codec::Encode;
 	use frame_metadata::{v14::META_RESERVED, RuntimeMetadata};
 	use scale_info::meta_type;
 
@@ -93,6 +94,7 @@ mod test {
 				event_enum_ty: meta_type::<()>(),
 				error_enum_ty: meta_type::<()>(),
 			},
+			hooks_order: HooksOrderIR::from_pallets(vec!["System", "Balances"]),
 		}
 	}
 
@@ -115,4 +117,19 @@ mod test {
 
 		assert!(matches!(metadata.1, RuntimeMetadata::V15(_)));
 	}
+
+	#[test]
+	fn hooks_order_in_custom_metadata_of_v15() {
+		let ir = ir_metadata();
+		let hooks_order = ir.hooks_order.clone();
+		let metadata = into_version(ir, V15).expect("Should return prefixed metadata");
+
+		let RuntimeMetadata::V15(metadata) = metadata.1 else { panic!("Should be V15") };
+		let value = metadata
+			.custom
+			.map
+			.get(HooksOrderIR::CUSTOM_KEY)
+			.expect("Hooks order should be in the custom metadata");
+		assert_eq!(value.value, hooks_order.encode());
+	}
 }
@@ -19,7 +19,7 @@ use codec::Encode;
 use scale_info::{
 	form::{Form, MetaForm, PortableForm},
 	prelude::vec::Vec,
-	IntoPortable, MetaType, Registry,
+	IntoPortable, MetaType, Registry, TypeInfo,
 };
 
 /// The intermediate representation for the runtime metadata.
@@ -41,6 +41,8 @@ pub struct MetadataIR<T: Form = MetaForm> {
 	pub apis: Vec<RuntimeApiMetadataIR<T>>,
 	/// The outer enums types as found in the runtime.
 	pub outer_enums: OuterEnumsIR<T>,
+	/// The order in which the hooks of the pallets are executed.
+	pub hooks_order: HooksOrderIR,
 }
 
 /// Metadata of a runtime trait.
@@ -451,3 +453,30 @@ impl IntoPortable for OuterEnumsIR {
 		}
 	}
 }
+
+/// The order in which the hooks of the pallets of a runtime are executed, as the names of the
+/// pallets.
+///
+/// Only exposed through the custom metadata of V15, under [`HooksOrderIR::CUSTOM_KEY`].
+#[derive(Clone, PartialEq, Eq, Encode, Debug, TypeInfo)]
+pub struct HooksOrderIR {
+	/// The order in which `on_initialize` is executed.
+	pub on_initialize: Vec<&'static str>,
+	/// The order in which `on_idle` is executed.
+	///
+	/// Each block starts at a different pallet of this list, the one at index
+	/// `block_number % on_idle.len()`, and wraps around to its beginning.
+	pub on_idle: Vec<&'static str>,
+	/// The order in which `on_finalize` is executed.
+	pub on_finalize: Vec<&'static str>,
+}
+
+impl HooksOrderIR {
+	/// The key of the hooks order in the custom metadata.
+	pub const CUSTOM_KEY: &str = "hooks_order";
+
+	/// The same order for all hooks, as the pallets are executed in the order they are declared.
+	pub fn from_pallets(pallets: Vec<&'static str>) -> Self {
+		Self { on_initialize: pallets.clone(), on_idle: pallets.clone(), on_finalize: pallets }
+	}
+}
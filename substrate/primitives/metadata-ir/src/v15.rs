@@ -20,15 +20,17 @@
 use crate::OuterEnumsIR;
 
 use super::types::{
-	ExtrinsicMetadataIR, MetadataIR, PalletMetadataIR, RuntimeApiMetadataIR,
+	ExtrinsicMetadataIR, HooksOrderIR, MetadataIR, PalletMetadataIR, RuntimeApiMetadataIR,
 	RuntimeApiMethodMetadataIR, RuntimeApiMethodParamMetadataIR, SignedExtensionMetadataIR,
 };
 
+use codec::Encode;
 use frame_metadata::v15::{
-	CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata,
-	RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata, RuntimeMetadataV15,
-	SignedExtensionMetadata,
+	CustomMetadata, CustomValueMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata,
+	RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata,
+	RuntimeMetadataV15, SignedExtensionMetadata,
 };
+use scale_info::meta_type;
 
 impl From<MetadataIR> for RuntimeMetadataV15 {
 	fn from(ir: MetadataIR) -> Self {
@@ -38,9 +40,17 @@ impl From<MetadataIR> for RuntimeMetadataV15 {
 			ir.ty,
 			ir.apis.into_iter().map(Into::into).collect(),
 			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V15 easily.
-			CustomMetadata { map: Default::default() },
+			CustomMetadata {
+				map: [(
+					HooksOrderIR::CUSTOM_KEY,
+					CustomValueMetadata {
+						ty: meta_type::<HooksOrderIR>(),
+						value: ir.hooks_order.encode(),
+					},
+				)]
+				.into_iter()
+				.collect(),
+			},
 		)
 	}
 }
@@ -20,9 +20,27 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+use codec::{Decode, Encode};
+
 /// Re-export of parent module scope storage prefix.
 pub use sp_core::offchain::STORAGE_PREFIX;
 
+/// Prefix under which the node records the [`StorageUsage`] of each namespace of the persistent
+/// offchain storage, keyed by the namespace.
+pub const USAGE_PREFIX: &[u8] = b"usage";
+
+/// The usage of a namespace of the persistent offchain storage.
+///
+/// A namespace is a key prefix of the persistent offchain storage, e.g. the prefix under which a
+/// pallet keeps the data of its offchain worker.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct StorageUsage {
+	/// The number of bytes of the keys and values stored in the namespace.
+	pub used: u64,
+	/// The maximum number of bytes which may be stored in the namespace, if limited.
+	pub quota: Option<u64>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The offchain worker api.
 	#[api_version(2)]
@@ -1783,10 +1783,24 @@ pub trait OpaqueKeys: Clone {
 	fn get<T: Decode>(&self, i: super::KeyTypeId) -> Option<T> {
 		T::decode(&mut self.get_raw(i)).ok()
 	}
-	/// Verify a proof of ownership for the keys.
-	fn ownership_proof_is_valid(&self, _proof: &[u8]) -> bool {
+	/// Verify a proof that the keys are owned by `owner`, i.e. that `owner` possesses the private
+	/// key of each of them.
+	fn ownership_proof_is_valid(&self, _owner: &[u8], _proof: &[u8]) -> bool {
 		true
 	}
+	/// Generate a set of keys, optionally using the given seed, together with a proof that they
+	/// are owned by `owner`.
+	///
+	/// The generated key pairs are stored in the keystore.
+	///
+	/// Returns `None` if the keys or the proof could not be generated, which is the default for
+	/// keys that do not support proofs of ownership.
+	fn generate_with_ownership_proof(
+		_owner: &[u8],
+		_seed: Option<Vec<u8>>,
+	) -> Option<(Self, Vec<u8>)> {
+		None
+	}
 }
 
 /// Input that adds infinite number of zero after wrapped input.
@@ -2037,6 +2051,28 @@ macro_rules! impl_opaque_keys_inner {
 				keys
 			}
 
+			/// Generate a proof that the keys are owned by `owner`, to be verified by
+			/// `OpaqueKeys::ownership_proof_is_valid`.
+			///
+			/// The proof consists of a proof of possession of the private key of each key, which
+			/// are requested from the keystore.
+			///
+			/// Returns `None` if any of the private keys could not be found.
+			pub fn create_ownership_proof(
+				&self,
+				owner: &[u8],
+			) -> Option<$crate::sp_std::vec::Vec<u8>> {
+				let proof = (
+					$(
+						$crate::RuntimeAppPublic::generate_proof_of_possession(
+							&self.$field,
+							owner,
+						)?,
+					)*
+				);
+				Some($crate::codec::Encode::encode(&proof))
+			}
+
 			/// Decode `Self` from the given `encoded` slice and convert `Self` into the raw public
 			/// keys (see [`Self::into_raw_public_keys`]).
 			///
@@ -2078,6 +2114,44 @@ macro_rules! impl_opaque_keys_inner {
 					_ => &[],
 				}
 			}
+
+			fn ownership_proof_is_valid(&self, owner: &[u8], proof: &[u8]) -> bool {
+				let Ok(( $( $field, )* )) = <(
+					$(
+						<
+							<
+								$type as $crate::BoundToRuntimeAppPublic
+							>::Public as $crate::RuntimeAppPublic
+						>::Signature,
+					)*
+				) as $crate::codec::DecodeAll>::decode_all(&mut &proof[..]) else {
+					return false
+				};
+				true $(
+					&& $crate::RuntimeAppPublic::verify_proof_of_possession(
+						&self.$field,
+						owner,
+						&$field,
+					)
+				)*
+			}
+
+			fn generate_with_ownership_proof(
+				owner: &[u8],
+				seed: Option<$crate::sp_std::vec::Vec<u8>>,
+			) -> Option<(Self, $crate::sp_std::vec::Vec<u8>)> {
+				let keys = Self{
+					$(
+						$field: <
+							<
+								$type as $crate::BoundToRuntimeAppPublic
+							>::Public as $crate::RuntimeAppPublic
+						>::generate_pair(seed.clone()),
+					)*
+				};
+				let proof = keys.create_ownership_proof(owner)?;
+				Some((keys, proof))
+			}
 		}
 	};
 }
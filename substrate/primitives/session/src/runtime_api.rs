@@ -15,11 +15,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 pub use sp_core::crypto::KeyTypeId;
+use sp_core::RuntimeDebug;
 use sp_std::prelude::*;
 
+/// Opaque session keys generated together with a proof of their ownership.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct OpaqueGeneratedSessionKeys {
+	/// The concatenated SCALE encoded public keys.
+	pub keys: Vec<u8>,
+	/// The proof that the keys are owned by the account they were generated for.
+	pub proof: Vec<u8>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// Session keys runtime api.
+	#[api_version(2)]
 	pub trait SessionKeys {
 		/// Generate a set of session keys with optionally using the given seed.
 		/// The keys should be stored within the keystore exposed via runtime
@@ -34,5 +47,18 @@ sp_api::decl_runtime_apis! {
 		///
 		/// Returns the list of public raw public keys + key type.
 		fn decode_session_keys(encoded: Vec<u8>) -> Option<Vec<(Vec<u8>, sp_core::crypto::KeyTypeId)>>;
+
+		/// Generate a set of session keys with optionally using the given seed, together with a
+		/// proof that they are owned by `owner`, the SCALE encoded account which will register
+		/// them. The keys should be stored within the keystore exposed via runtime externalities.
+		///
+		/// The seed needs to be a valid `utf8` string.
+		///
+		/// Returns the concatenated SCALE encoded public keys and the proof of their ownership.
+		#[api_version(2)]
+		fn generate_session_keys_and_proof(
+			owner: Vec<u8>,
+			seed: Option<Vec<u8>>,
+		) -> OpaqueGeneratedSessionKeys;
 	}
 }
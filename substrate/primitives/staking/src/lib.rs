@@ -416,4 +416,32 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// The forecast of the payout of the active era, computed as if the era ended now.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EraPayoutForecast<AccountId, Balance> {
+	/// The index of the era.
+	pub era: EraIndex,
+	/// The total payout to the validators and their nominators.
+	pub validator_payout: Balance,
+	/// The rest of the era inflation, not paid out to stakers.
+	pub remainder: Balance,
+	/// The split of the payout for each validator that earned reward points in the era.
+	pub validators: Vec<ValidatorPayoutForecast<AccountId, Balance>>,
+}
+
+/// The forecast of the payout of a single validator and its nominators.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorPayoutForecast<AccountId, Balance> {
+	/// The stash account of the validator.
+	pub validator: AccountId,
+	/// The reward points earned by the validator so far.
+	pub reward_points: u32,
+	/// The commission taken by the validator.
+	pub commission: Balance,
+	/// The payout to the validator for its own stake, excluding the commission.
+	pub own: Balance,
+	/// The payout to the nominators of the validator, together.
+	pub nominators: Balance,
+}
+
 sp_core::generate_feature_enabled_macro!(runtime_benchmarks_enabled, feature = "runtime-benchmarks", $);
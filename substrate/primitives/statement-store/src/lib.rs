@@ -46,7 +46,7 @@ pub const MAX_TOPICS: usize = 4;
 
 #[cfg(feature = "std")]
 pub use store_api::{
-	Error, NetworkPriority, Result, StatementSource, StatementStore, SubmitResult,
+	AccountUsage, Error, NetworkPriority, Result, StatementSource, StatementStore, SubmitResult,
 };
 
 #[cfg(feature = "std")]
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 pub use crate::runtime_api::StatementSource;
-use crate::{Hash, Statement, Topic};
+use crate::{AccountId, Hash, Statement, Topic};
 
 /// Statement store error.
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -58,6 +58,19 @@ pub enum SubmitResult {
 	InternalError(Error),
 }
 
+/// Usage of the store by the statements of an account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccountUsage {
+	/// Number of statements stored for the account.
+	pub statements: u32,
+	/// Total data size of the statements stored for the account.
+	pub size: u32,
+	/// Max statement count allowed for the account.
+	pub max_count: u32,
+	/// Max total data size allowed for the account.
+	pub max_size: u32,
+}
+
 /// Result type for `Error`
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -87,4 +100,7 @@ pub trait StatementStore: Send + Sync {
 
 	/// Remove a statement from the store.
 	fn remove(&self, hash: &Hash) -> Result<()>;
+
+	/// Return the usage of the store by `account`, or `None` if it has no statements stored.
+	fn account_usage(&self, account: &AccountId) -> Result<Option<AccountUsage>>;
 }
@@ -22,7 +22,8 @@
 
 use sp_std::{prelude::*, result::Result};
 
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
+use sp_core::H256;
 use sp_inherents::{InherentData, InherentIdentifier, IsFatalError};
 use sp_runtime::traits::{Block as BlockT, NumberFor};
 
@@ -59,6 +60,35 @@ pub struct TransactionStorageProof {
 	pub proof: Vec<Vec<u8>>,
 }
 
+/// The on-chain commitment to the data of a stored transaction.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, sp_runtime::RuntimeDebug, scale_info::TypeInfo)]
+pub struct TransactionCommitment {
+	/// Root of the trie of the data chunks, keyed by their [`encode_index`]ed index.
+	pub chunk_root: H256,
+	/// Plain hash of the data.
+	pub content_hash: H256,
+	/// Size of the data in bytes.
+	pub size: u32,
+}
+
+impl TransactionCommitment {
+	/// Number of chunks of the data.
+	pub fn num_chunks(&self) -> u32 {
+		((self.size as u64 + CHUNK_SIZE as u64 - 1) / CHUNK_SIZE as u64) as u32
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to query the on-chain commitments to stored transactions.
+	pub trait TransactionStorageApi<BlockNumber: Codec> {
+		/// The commitment to the data of the transaction `index` stored or renewed in the block
+		/// `block`.
+		///
+		/// Returns `None` if there is no such transaction or its storage period has ended.
+		fn transaction_commitment(block: BlockNumber, index: u32) -> Option<TransactionCommitment>;
+	}
+}
+
 /// Auxiliary trait to extract storage proof.
 pub trait TransactionStorageProofInherentData {
 	/// Get the proof.
@@ -228,6 +258,38 @@ pub mod registration {
 		Ok(TransactionStorageProof { proof: chunk_proof, chunk: target_chunk.unwrap() })
 	}
 
+	/// Build a proof of the chunk `chunk_index` of `transaction` against its chunk root.
+	///
+	/// Returns `None` if the transaction has no such chunk.
+	pub fn build_chunk_proof(
+		transaction: &[u8],
+		chunk_index: u32,
+	) -> Result<Option<TransactionStorageProof>, Error> {
+		let mut db = sp_trie::MemoryDB::<Hasher>::default();
+		let mut root = sp_trie::empty_trie_root::<TrieLayout>();
+		let mut target_chunk = None;
+		{
+			let mut trie = sp_trie::TrieDBMutBuilder::<TrieLayout>::new(&mut db, &mut root).build();
+			for (index, chunk) in transaction.chunks(CHUNK_SIZE).enumerate() {
+				let index = index as u32;
+				trie.insert(&encode_index(index), chunk)
+					.map_err(|e| Error::Application(Box::new(e)))?;
+				if index == chunk_index {
+					target_chunk = Some(chunk.to_vec());
+				}
+			}
+			trie.commit();
+		}
+		let Some(chunk) = target_chunk else { return Ok(None) };
+		let proof = sp_trie::generate_trie_proof::<TrieLayout, _, _, _>(
+			&db,
+			root,
+			&[encode_index(chunk_index)],
+		)
+		.map_err(|e| Error::Application(Box::new(e)))?;
+		Ok(Some(TransactionStorageProof { chunk, proof }))
+	}
+
 	#[test]
 	fn build_proof_check() {
 		use std::str::FromStr;
@@ -244,4 +306,21 @@ pub mod registration {
 		)
 		.unwrap();
 	}
+
+	#[test]
+	fn build_chunk_proof_check() {
+		use sp_trie::TrieConfiguration;
+		let data: Vec<u8> = (0..600).map(|i| i as u8).collect();
+		let root = TrieLayout::ordered_trie_root(data.chunks(CHUNK_SIZE));
+
+		let proof = build_chunk_proof(&data, 2).unwrap().unwrap();
+		assert_eq!(proof.chunk, data[2 * CHUNK_SIZE..].to_vec());
+		sp_trie::verify_trie_proof::<TrieLayout, _, _, _>(
+			&root,
+			&proof.proof,
+			&[(encode_index(2), Some(proof.chunk))],
+		)
+		.unwrap();
+		assert_eq!(build_chunk_proof(&data, 3).unwrap(), None);
+	}
 }
@@ -24,6 +24,9 @@ pub mod cache;
 mod error;
 mod node_codec;
 mod node_header;
+mod proof_codec;
+#[cfg(feature = "std")]
+pub mod proof_size_extension;
 #[cfg(feature = "std")]
 pub mod recorder;
 mod storage_proof;
@@ -39,6 +42,11 @@ use hash_db::{Hasher, Prefix};
 pub use memory_db::{prefixed_key, HashKey, KeyFunction, PrefixedKey};
 /// The Substrate format implementation of `NodeCodec`.
 pub use node_codec::NodeCodec;
+/// Versioned encoding of compact proofs.
+pub use proof_codec::{
+	decode_versioned_proof, encode_versioned_proof, ProofCodecError, ProofCodecVersion,
+	PROOF_BOMB_LIMIT,
+};
 use sp_std::{borrow::Borrow, boxed::Box, marker::PhantomData, vec::Vec};
 pub use storage_proof::{CompactProof, StorageProof};
 /// Trie codec reexport, mainly child trie support
@@ -56,6 +64,12 @@ pub use trie_db::{proof::VerifyError, MerkleValue};
 /// The Substrate format implementation of `TrieStream`.
 pub use trie_stream::TrieStream;
 
+/// Provides the size of the storage proof recorded so far.
+pub trait ProofSizeProvider {
+	/// Returns the estimated encoded size of the storage proof recorded so far.
+	fn estimate_encoded_size(&self) -> usize;
+}
+
 /// substrate trie layout
 pub struct LayoutV0<H>(PhantomData<H>);
 
@@ -0,0 +1,420 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Versioned encoding of compact proofs.
+//!
+//! Every encoded proof starts with a version byte, so that the receiver can tell the encodings
+//! apart and reject the ones it does not support:
+//!
+//! - Version 1 is the SCALE encoding of the [`CompactProof`].
+//! - Version 2 is followed by a flags byte. It stores every child and value hash referenced by
+//!   more than one node of the proof only once and can compress the result with zstd, optionally
+//!   using a dictionary trained on the proofs of the chain (e.g. shipped with the runtime).
+//!
+//! Compression is only available with `std`, but uncompressed version 2 proofs can be encoded and
+//! decoded everywhere.
+
+use crate::{node_codec::NodeCodec, trie_constants, CompactProof};
+use codec::{Compact, Decode, DecodeAll, Encode};
+use hash_db::Hasher;
+use sp_std::{collections::btree_map::BTreeMap, ops::Range, vec::Vec};
+use trie_db::{
+	node::{NodeHandlePlan, NodePlan, ValuePlan},
+	NodeCodec as NodeCodecT,
+};
+
+/// Flag of a version 2 proof whose payload is compressed with zstd.
+const FLAG_ZSTD: u8 = 0b1;
+
+/// The zstd compression level used for proofs.
+#[cfg(feature = "std")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// The maximum size of a decompressed proof payload, anything bigger is rejected as a possible
+/// compression bomb.
+pub const PROOF_BOMB_LIMIT: usize = 64 * 1024 * 1024;
+
+/// The version of the encoding of a compact proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofCodecVersion {
+	/// The SCALE encoding of the [`CompactProof`].
+	V1 = 1,
+	/// Hashes referenced by more than one node are deduplicated, optionally compressed.
+	V2 = 2,
+}
+
+impl ProofCodecVersion {
+	/// The most recent version.
+	pub const LATEST: Self = Self::V2;
+}
+
+impl TryFrom<u8> for ProofCodecVersion {
+	type Error = ProofCodecError;
+
+	fn try_from(version: u8) -> Result<Self, Self::Error> {
+		match version {
+			1 => Ok(Self::V1),
+			2 => Ok(Self::V2),
+			v => Err(ProofCodecError::UnsupportedVersion(v)),
+		}
+	}
+}
+
+/// Error of the versioned proof encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum ProofCodecError {
+	#[cfg_attr(feature = "std", error("Empty encoded proof"))]
+	Empty,
+	#[cfg_attr(feature = "std", error("Unsupported proof encoding version {0}"))]
+	UnsupportedVersion(u8),
+	#[cfg_attr(feature = "std", error("Unsupported proof encoding flags {0:#b}"))]
+	UnsupportedFlags(u8),
+	#[cfg_attr(feature = "std", error("Proof compression is not supported"))]
+	CompressionUnsupported,
+	#[cfg_attr(feature = "std", error("Failed to compress or decompress the proof"))]
+	Compression,
+	#[cfg_attr(feature = "std", error("Decompressed proof exceeds the size limit"))]
+	PossibleBomb,
+	#[cfg_attr(feature = "std", error("Invalid encoded proof"))]
+	Invalid,
+}
+
+/// A node of a version 2 proof.
+#[derive(Encode, Decode)]
+struct NodeV2 {
+	/// The encoded node, with its deduplicated hashes cut out.
+	data: Vec<u8>,
+	/// The deduplicated hashes to insert into `data`, as the offset in `data` relative to the
+	/// previous insertion and the index of the hash.
+	hashes: Vec<(Compact<u32>, Compact<u32>)>,
+}
+
+/// The payload of a version 2 proof.
+#[derive(Encode, Decode)]
+struct ProofV2 {
+	/// The concatenation of the hashes referenced by more than one node.
+	hashes: Vec<u8>,
+	nodes: Vec<NodeV2>,
+}
+
+/// Encode `proof` with the given `version`.
+///
+/// If `compression` is `Some`, the proof is compressed with zstd using the given dictionary,
+/// which can be empty. Only version 2 supports compression, and only with `std`.
+pub fn encode_versioned_proof<H: Hasher>(
+	proof: &CompactProof,
+	version: ProofCodecVersion,
+	compression: Option<&[u8]>,
+) -> Result<Vec<u8>, ProofCodecError> {
+	match version {
+		ProofCodecVersion::V1 => {
+			if compression.is_some() {
+				return Err(ProofCodecError::CompressionUnsupported)
+			}
+			let mut encoded = Vec::with_capacity(proof.encoded_size() + 1);
+			encoded.push(version as u8);
+			proof.encode_to(&mut encoded);
+			Ok(encoded)
+		},
+		ProofCodecVersion::V2 => {
+			let payload = deduplicate::<H>(proof).encode();
+			let (flags, payload) = match compression {
+				Some(dictionary) => (FLAG_ZSTD, compress(&payload, dictionary)?),
+				None => (0, payload),
+			};
+			let mut encoded = Vec::with_capacity(payload.len() + 2);
+			encoded.push(version as u8);
+			encoded.push(flags);
+			encoded.extend_from_slice(&payload);
+			Ok(encoded)
+		},
+	}
+}
+
+/// Decode a proof encoded with [`encode_versioned_proof`].
+///
+/// `dictionary` must be the dictionary the proof was compressed with, if any.
+pub fn decode_versioned_proof<H: Hasher>(
+	encoded: &[u8],
+	dictionary: Option<&[u8]>,
+) -> Result<CompactProof, ProofCodecError> {
+	let (&version, rest) = encoded.split_first().ok_or(ProofCodecError::Empty)?;
+	match ProofCodecVersion::try_from(version)? {
+		ProofCodecVersion::V1 =>
+			CompactProof::decode_all(&mut &rest[..]).map_err(|_| ProofCodecError::Invalid),
+		ProofCodecVersion::V2 => {
+			let (&flags, payload) = rest.split_first().ok_or(ProofCodecError::Invalid)?;
+			let proof = match flags {
+				0 => ProofV2::decode_all(&mut &payload[..]),
+				FLAG_ZSTD => {
+					let payload = decompress(payload, dictionary.unwrap_or_default())?;
+					ProofV2::decode_all(&mut &payload[..])
+				},
+				flags => return Err(ProofCodecError::UnsupportedFlags(flags)),
+			}
+			.map_err(|_| ProofCodecError::Invalid)?;
+			restore::<H>(proof)
+		},
+	}
+}
+
+/// The ranges of the child and value hashes in the encoded `node`, in order.
+///
+/// Nodes that can not be decoded have no hashes.
+fn hash_ranges<H: Hasher>(node: &[u8]) -> Vec<Range<usize>> {
+	let (offset, data) = match node.split_first() {
+		Some((&trie_constants::ESCAPE_COMPACT_HEADER, rest)) => (1, rest),
+		_ => (0, node),
+	};
+	let Ok(plan) = NodeCodec::<H>::decode_plan(data) else { return Vec::new() };
+	let (value, children) = match plan {
+		NodePlan::Leaf { value, .. } => (Some(value), None),
+		NodePlan::Branch { value, children } | NodePlan::NibbledBranch { value, children, .. } =>
+			(value, Some(children)),
+		_ => (None, None),
+	};
+
+	let mut ranges = Vec::new();
+	if let Some(ValuePlan::Node(range)) = value {
+		ranges.push(range);
+	}
+	for child in children.iter().flatten().flatten() {
+		if let NodeHandlePlan::Hash(range) = child {
+			ranges.push(range.clone());
+		}
+	}
+	ranges.retain(|r| r.len() == H::LENGTH && r.end <= data.len());
+	ranges.sort_by_key(|r| r.start);
+	ranges.dedup_by(|b, a| b.start < a.end);
+	ranges.into_iter().map(|r| r.start + offset..r.end + offset).collect()
+}
+
+/// Cut the hashes referenced by more than one node out of the nodes of `proof`.
+fn deduplicate<H: Hasher>(proof: &CompactProof) -> ProofV2 {
+	let ranges: Vec<_> = proof.encoded_nodes.iter().map(|n| hash_ranges::<H>(n)).collect();
+
+	let mut counts = BTreeMap::<&[u8], u32>::new();
+	for (node, ranges) in proof.encoded_nodes.iter().zip(&ranges) {
+		for range in ranges {
+			*counts.entry(&node[range.clone()]).or_default() += 1;
+		}
+	}
+	let mut hashes = Vec::new();
+	let mut indices = BTreeMap::new();
+	for (hash, _) in counts.into_iter().filter(|(_, count)| *count > 1) {
+		indices.insert(hash, indices.len() as u32);
+		hashes.extend_from_slice(hash);
+	}
+
+	let nodes = proof
+		.encoded_nodes
+		.iter()
+		.zip(ranges)
+		.map(|(node, ranges)| {
+			let mut data = Vec::with_capacity(node.len());
+			let mut refs = Vec::new();
+			let (mut read, mut last) = (0, 0);
+			for range in ranges {
+				let Some(&index) = indices.get(&node[range.clone()]) else { continue };
+				data.extend_from_slice(&node[read..range.start]);
+				refs.push((Compact((data.len() - last) as u32), Compact(index)));
+				last = data.len();
+				read = range.end;
+			}
+			data.extend_from_slice(&node[read..]);
+			NodeV2 { data, hashes: refs }
+		})
+		.collect();
+
+	ProofV2 { hashes, nodes }
+}
+
+/// Reinsert the deduplicated hashes into the nodes of `proof`.
+fn restore<H: Hasher>(proof: ProofV2) -> Result<CompactProof, ProofCodecError> {
+	if proof.hashes.len() % H::LENGTH != 0 {
+		return Err(ProofCodecError::Invalid)
+	}
+	let hashes: Vec<_> = proof.hashes.chunks_exact(H::LENGTH).collect();
+
+	let encoded_nodes = proof
+		.nodes
+		.into_iter()
+		.map(|node| {
+			let mut encoded = Vec::with_capacity(node.data.len() + node.hashes.len() * H::LENGTH);
+			let mut read = 0usize;
+			for (Compact(offset), Compact(index)) in node.hashes {
+				let next = read
+					.checked_add(offset as usize)
+					.filter(|next| *next <= node.data.len())
+					.ok_or(ProofCodecError::Invalid)?;
+				let hash = hashes.get(index as usize).ok_or(ProofCodecError::Invalid)?;
+				encoded.extend_from_slice(&node.data[read..next]);
+				encoded.extend_from_slice(hash);
+				read = next;
+			}
+			encoded.extend_from_slice(&node.data[read..]);
+			Ok(encoded)
+		})
+		.collect::<Result<_, _>>()?;
+
+	Ok(CompactProof { encoded_nodes })
+}
+
+#[cfg(feature = "std")]
+fn compress(payload: &[u8], dictionary: &[u8]) -> Result<Vec<u8>, ProofCodecError> {
+	use std::io::Write;
+
+	let mut buf = Vec::new();
+	let mut encoder =
+		zstd::stream::write::Encoder::with_dictionary(&mut buf, ZSTD_LEVEL, dictionary)
+			.map_err(|_| ProofCodecError::Compression)?;
+	encoder.write_all(payload).map_err(|_| ProofCodecError::Compression)?;
+	encoder.finish().map_err(|_| ProofCodecError::Compression)?;
+	Ok(buf)
+}
+
+#[cfg(not(feature = "std"))]
+fn compress(_payload: &[u8], _dictionary: &[u8]) -> Result<Vec<u8>, ProofCodecError> {
+	Err(ProofCodecError::CompressionUnsupported)
+}
+
+#[cfg(feature = "std")]
+fn decompress(payload: &[u8], dictionary: &[u8]) -> Result<Vec<u8>, ProofCodecError> {
+	use std::io::Read;
+
+	let decoder = zstd::stream::read::Decoder::with_dictionary(payload, dictionary)
+		.map_err(|_| ProofCodecError::Compression)?;
+	let mut buf = Vec::new();
+	decoder
+		.take(PROOF_BOMB_LIMIT as u64 + 1)
+		.read_to_end(&mut buf)
+		.map_err(|_| ProofCodecError::Compression)?;
+	if buf.len() > PROOF_BOMB_LIMIT {
+		return Err(ProofCodecError::PossibleBomb)
+	}
+	Ok(buf)
+}
+
+#[cfg(not(feature = "std"))]
+fn decompress(_payload: &[u8], _dictionary: &[u8]) -> Result<Vec<u8>, ProofCodecError> {
+	Err(ProofCodecError::CompressionUnsupported)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{recorder::Recorder, LayoutV1, MemoryDB, TrieDBBuilder, TrieDBMutBuilder};
+	use sp_core::Blake2Hasher;
+	use trie_db::{Trie, TrieMut};
+
+	type Layout = LayoutV1<Blake2Hasher>;
+
+	/// A proof of the same keys in two identical subtries, so that the hashes of the nodes
+	/// omitted from both appear twice.
+	fn proof() -> CompactProof {
+		let mut db = MemoryDB::<Blake2Hasher>::default();
+		let mut root = Default::default();
+		{
+			let mut trie = TrieDBMutBuilder::<Layout>::new(&mut db, &mut root).build();
+			for prefix in [0x10u8, 0x20] {
+				for i in 0u8..64 {
+					trie.insert(&[prefix, i, i], &[i; 40]).unwrap();
+				}
+			}
+		}
+
+		let recorder = Recorder::<Blake2Hasher>::default();
+		{
+			let mut trie_recorder = recorder.as_trie_recorder(root);
+			let trie = TrieDBBuilder::<Layout>::new(&db, &root)
+				.with_recorder(&mut trie_recorder)
+				.build();
+			for prefix in [0x10u8, 0x20] {
+				assert!(trie.get(&[prefix, 7, 7]).unwrap().is_some());
+			}
+		}
+		recorder.drain_storage_proof().into_compact_proof::<Blake2Hasher>(root).unwrap()
+	}
+
+	#[test]
+	fn v1_round_trip() {
+		let proof = proof();
+		let encoded =
+			encode_versioned_proof::<Blake2Hasher>(&proof, ProofCodecVersion::V1, None).unwrap();
+		assert_eq!(encoded[0], 1);
+		assert_eq!(&encoded[1..], &proof.encode()[..]);
+		assert_eq!(decode_versioned_proof::<Blake2Hasher>(&encoded, None), Ok(proof));
+	}
+
+	#[test]
+	fn v2_deduplicates_hashes() {
+		let proof = proof();
+		let v1 =
+			encode_versioned_proof::<Blake2Hasher>(&proof, ProofCodecVersion::V1, None).unwrap();
+		let v2 =
+			encode_versioned_proof::<Blake2Hasher>(&proof, ProofCodecVersion::V2, None).unwrap();
+		assert_eq!(&v2[..2], &[2, 0]);
+		assert!(v2.len() < v1.len());
+		assert_eq!(decode_versioned_proof::<Blake2Hasher>(&v2, None), Ok(proof));
+	}
+
+	#[test]
+	fn v2_compressed_round_trip() {
+		let proof = proof();
+		let dictionary = proof.encoded_nodes.concat();
+		for dictionary in [&[][..], &dictionary[..]] {
+			let encoded = encode_versioned_proof::<Blake2Hasher>(
+				&proof,
+				ProofCodecVersion::V2,
+				Some(dictionary),
+			)
+			.unwrap();
+			assert_eq!(&encoded[..2], &[2, FLAG_ZSTD]);
+			assert_eq!(
+				decode_versioned_proof::<Blake2Hasher>(&encoded, Some(dictionary)),
+				Ok(proof.clone())
+			);
+		}
+	}
+
+	#[test]
+	fn rejects_invalid_encodings() {
+		let proof = proof();
+		assert_eq!(
+			encode_versioned_proof::<Blake2Hasher>(&proof, ProofCodecVersion::V1, Some(&[])),
+			Err(ProofCodecError::CompressionUnsupported),
+		);
+		assert_eq!(decode_versioned_proof::<Blake2Hasher>(&[], None), Err(ProofCodecError::Empty));
+		assert_eq!(
+			decode_versioned_proof::<Blake2Hasher>(&[3, 0], None),
+			Err(ProofCodecError::UnsupportedVersion(3)),
+		);
+		assert_eq!(
+			decode_versioned_proof::<Blake2Hasher>(&[2, 0b10], None),
+			Err(ProofCodecError::UnsupportedFlags(0b10)),
+		);
+
+		let mut encoded = vec![2, 0];
+		let node = NodeV2 { data: vec![0; 4], hashes: vec![(Compact(2), Compact(1))] };
+		ProofV2 { hashes: vec![0; 32], nodes: vec![node] }.encode_to(&mut encoded);
+		assert_eq!(
+			decode_versioned_proof::<Blake2Hasher>(&encoded, None),
+			Err(ProofCodecError::Invalid),
+		);
+	}
+}
@@ -95,6 +95,12 @@ impl<H: Hasher> Default for Recorder<H> {
 	}
 }
 
+impl<H: Hasher> crate::ProofSizeProvider for Recorder<H> {
+	fn estimate_encoded_size(&self) -> usize {
+		Recorder::estimate_encoded_size(self)
+	}
+}
+
 impl<H: Hasher> Clone for Recorder<H> {
 	fn clone(&self) -> Self {
 		Self {
@@ -33,7 +33,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use sp_arithmetic::{
-	traits::{BaseArithmetic, SaturatedConversion, Unsigned},
+	traits::{BaseArithmetic, One, SaturatedConversion, Unsigned},
 	Perbill,
 };
 use sp_core::Get;
@@ -110,7 +110,7 @@ impl RuntimeDbWeight {
 ///
 /// The `negative` value encodes whether the term is added or subtracted from the
 /// overall polynomial result.
-#[derive(Clone, Encode, Decode, TypeInfo)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct WeightToFeeCoefficient<Balance> {
 	/// The integral part of the coefficient.
 	pub coeff_integer: Balance,
@@ -200,6 +200,14 @@ pub trait WeightToFee {
 
 	/// Calculates the fee from the passed `weight`.
 	fn weight_to_fee(weight: &Weight) -> Self::Balance;
+
+	/// Returns the polynomial of the calculation, evaluated at the `ref_time` of the weight, or
+	/// `None` if it cannot be described as one.
+	///
+	/// This allows to calculate fees without executing the runtime, e.g. in offline signers.
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		None
+	}
 }
 
 /// A trait that describes the weight to fee calculation as polynomial.
@@ -232,6 +240,20 @@ where
 		let poly: FeePolynomial<Self::Balance> = Self::polynomial().into();
 		poly.eval(weight.ref_time())
 	}
+
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		Some(Self::polynomial())
+	}
+}
+
+/// A polynomial of a single term, `coeff_integer * x^(degree)`.
+fn monomial<Balance>(coeff_integer: Balance, degree: u8) -> WeightToFeeCoefficients<Balance> {
+	smallvec::smallvec![WeightToFeeCoefficient {
+		coeff_integer,
+		coeff_frac: Perbill::zero(),
+		negative: false,
+		degree,
+	}]
 }
 
 /// Implementor of `WeightToFee` that maps one unit of weight to one unit of fee.
@@ -246,6 +268,10 @@ where
 	fn weight_to_fee(weight: &Weight) -> Self::Balance {
 		Self::Balance::saturated_from(weight.ref_time())
 	}
+
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		Some(monomial(Self::Balance::one(), 1))
+	}
 }
 
 /// Implementor of [`WeightToFee`] such that it maps any unit of weight to a fixed fee.
@@ -260,6 +286,10 @@ where
 	fn weight_to_fee(_: &Weight) -> Self::Balance {
 		F.into()
 	}
+
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		Some(monomial(F.into(), 0))
+	}
 }
 
 /// An implementation of [`WeightToFee`] that collects no fee.
@@ -287,6 +317,10 @@ where
 	fn weight_to_fee(weight: &Weight) -> Self::Balance {
 		Self::Balance::saturated_from(weight.ref_time()).saturating_mul(M::get())
 	}
+
+	fn polynomial_coefficients() -> Option<WeightToFeeCoefficients<Self::Balance>> {
+		Some(monomial(M::get(), 1))
+	}
 }
 
 #[cfg(test)]
@@ -384,4 +418,21 @@ mod tests {
 			u128::MAX
 		);
 	}
+
+	#[test]
+	fn polynomial_coefficients_describe_the_fee() {
+		use sp_core::ConstU128;
+		fn assert_described<W: WeightToFee<Balance = u128>>() {
+			let poly: FeePolynomial<u128> = W::polynomial_coefficients().unwrap().into();
+			for ref_time in [0, 1, 50, 10_123, u64::MAX] {
+				let weight = Weight::from_parts(ref_time, 0);
+				assert_eq!(poly.eval(ref_time), W::weight_to_fee(&weight));
+			}
+		}
+
+		assert_eq!(Poly::polynomial_coefficients(), Some(Poly::polynomial()));
+		assert_described::<IdentityFee<u128>>();
+		assert_described::<FixedFee<42, u128>>();
+		assert_described::<ConstantMultiplier<u128, ConstU128<10u128>>>();
+	}
 }
@@ -48,11 +48,11 @@ const LOG_TARGET: &'static str = "frame::benchmark::pallet";
 #[derive(Serialize, Debug, Clone, Eq, PartialEq)]
 pub(crate) struct ComponentRange {
 	/// Name of the component.
-	name: String,
+	pub(crate) name: String,
 	/// Minimal valid value of the component.
-	min: u32,
+	pub(crate) min: u32,
 	/// Maximal valid value of the component.
-	max: u32,
+	pub(crate) max: u32,
 }
 
 /// How the PoV size of a storage item should be estimated.
@@ -190,6 +190,28 @@ pub struct PalletCmd {
 	#[clap(long, default_value = "2")]
 	pub additional_trie_layers: u8,
 
+	/// The maximum ref time of a block in picoseconds.
+	///
+	/// Used together with `--max-block-proof-size` to determine whether the proof size rather than
+	/// the ref time is the binding constraint of a benchmark. Benchmarks that use a larger fraction
+	/// of the proof size limit than of the ref time limit are annotated as such in the output file.
+	/// Defaults to the two seconds of a relay chain block.
+	#[arg(long, default_value = "2000000000000")]
+	pub max_block_ref_time: u64,
+
+	/// The maximum proof size of a block in bytes.
+	///
+	/// See `--max-block-ref-time`. Defaults to the 5 MiB PoV limit of a parachain block.
+	#[arg(long, default_value = "5242880")]
+	pub max_block_proof_size: u64,
+
+	/// Implement `WeightInfo::is_pov_bound` in the generated runtime weight files.
+	///
+	/// Only use this together with pallets whose `WeightInfo` declares `is_pov_bound`, which is
+	/// the case for weight files generated with the FRAME weight template.
+	#[arg(long)]
+	pub pov_bound_api: bool,
+
 	/// A path to a `.json` file with existing benchmark results generated with `--json` or
 	/// `--json-file`. When specified the benchmarks are not actually executed, and the data for
 	/// the analysis is read from this file.
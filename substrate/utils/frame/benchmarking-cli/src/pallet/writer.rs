@@ -35,7 +35,10 @@ use crate::{
 use frame_benchmarking::{
 	Analysis, AnalysisChoice, BenchmarkBatchSplitResults, BenchmarkResult, BenchmarkSelector,
 };
-use frame_support::traits::StorageInfo;
+use frame_support::{
+	traits::{Get, StorageInfo},
+	weights::constants::RocksDbWeight,
+};
 use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::traits::Zero;
 
@@ -81,6 +84,8 @@ struct BenchmarkData {
 	comments: Vec<String>,
 	#[serde(serialize_with = "string_serialize")]
 	min_execution_time: u128,
+	// Whether the proof size rather than the ref time is the binding constraint.
+	pov_bound: bool,
 }
 
 // This forwards some specific metadata from the `PalletCmd`
@@ -96,6 +101,7 @@ struct CmdData {
 	analysis_choice: String,
 	worst_case_map_values: u32,
 	additional_trie_layers: u8,
+	pov_bound_api: bool,
 }
 
 // This encodes the component name and whether that component is used.
@@ -371,6 +377,43 @@ fn get_benchmark_data(
 		component_ranges,
 		comments,
 		min_execution_time: extrinsic_time.minimum,
+		pov_bound: false,
+	}
+}
+
+// Mark the benchmarks for which the proof size rather than the ref time is the binding constraint.
+//
+// The worst case weight of a benchmark is evaluated with all components at their maximum. A
+// benchmark is considered PoV bound if it uses a larger fraction of `max_proof_size` than of
+// `max_ref_time`. Database accesses are accounted with the `RocksDbWeight`.
+fn mark_pov_bound(benchmarks: &mut [BenchmarkData], max_ref_time: u64, max_proof_size: u64) {
+	let db_weight = RocksDbWeight::get();
+	for benchmark in benchmarks.iter_mut() {
+		let max_of = |name: &str| -> u128 {
+			benchmark
+				.component_ranges
+				.iter()
+				.find(|range| range.name == name)
+				.map_or(0, |range| range.max.into())
+		};
+		let worst_case = |base: u128, slopes: &[ComponentSlope]| -> u128 {
+			slopes.iter().fold(base, |acc, component| {
+				acc.saturating_add(component.slope.saturating_mul(max_of(&component.name)))
+			})
+		};
+
+		let reads = worst_case(benchmark.base_reads, &benchmark.component_reads);
+		let writes = worst_case(benchmark.base_writes, &benchmark.component_writes);
+		let ref_time = worst_case(benchmark.base_weight, &benchmark.component_weight)
+			.saturating_add(reads.saturating_mul(db_weight.read.into()))
+			.saturating_add(writes.saturating_mul(db_weight.write.into()));
+		let proof_size = worst_case(
+			benchmark.base_calculated_proof_size,
+			&benchmark.component_calculated_proof_size,
+		);
+
+		benchmark.pov_bound = proof_size.saturating_mul(max_ref_time.into()) >
+			ref_time.saturating_mul(max_proof_size.into());
 	}
 }
 
@@ -430,17 +473,19 @@ pub(crate) fn write_results(
 		analysis_choice: format!("{:?}", analysis_choice),
 		worst_case_map_values: cmd.worst_case_map_values,
 		additional_trie_layers: cmd.additional_trie_layers,
+		pov_bound_api: cmd.pov_bound_api,
 	};
 
 	// New Handlebars instance with helpers.
 	let mut handlebars = handlebars::Handlebars::new();
 	handlebars.register_helper("underscore", Box::new(UnderscoreHelper));
 	handlebars.register_helper("join", Box::new(JoinHelper));
+	handlebars.register_helper("pascal_case", Box::new(PascalCaseHelper));
 	// Don't HTML escape any characters.
 	handlebars.register_escape_fn(|s| -> String { s.to_string() });
 
 	// Organize results by pallet into a JSON map
-	let all_results = map_results(
+	let mut all_results = map_results(
 		batches,
 		storage_info,
 		component_ranges,
@@ -451,6 +496,9 @@ pub(crate) fn write_results(
 		cmd.worst_case_map_values,
 		cmd.additional_trie_layers,
 	)?;
+	for results in all_results.values_mut() {
+		mark_pov_bound(results, cmd.max_block_ref_time, cmd.max_block_proof_size);
+	}
 	let mut created_files = Vec::new();
 
 	for ((pallet, instance), results) in all_results.iter() {
@@ -824,6 +872,25 @@ impl handlebars::HelperDef for JoinHelper {
 	}
 }
 
+// A helper to convert a `snake_case` name, e.g. of a benchmark, into `PascalCase`.
+#[derive(Clone, Copy)]
+struct PascalCaseHelper;
+impl handlebars::HelperDef for PascalCaseHelper {
+	fn call<'reg: 'rc, 'rc>(
+		&self,
+		h: &handlebars::Helper,
+		_: &handlebars::Handlebars,
+		_: &handlebars::Context,
+		_rc: &mut handlebars::RenderContext,
+		out: &mut dyn handlebars::Output,
+	) -> handlebars::HelperResult {
+		use handlebars::JsonRender;
+		let param = h.param(0).unwrap();
+		out.write(&param.value().render().to_pascal_case())?;
+		Ok(())
+	}
+}
+
 // u128 does not serialize well into JSON for `handlebars`, so we represent it as a string.
 fn string_serialize<S>(x: &u128, s: S) -> Result<S::Ok, S::Error>
 where
@@ -1321,6 +1388,7 @@ mod test {
 		let mut handlebars = handlebars::Handlebars::new();
 		handlebars.register_helper("underscore", Box::new(UnderscoreHelper));
 		handlebars.register_helper("join", Box::new(JoinHelper));
+		handlebars.register_helper("pascal_case", Box::new(PascalCaseHelper));
 		// Don't HTML escape any characters.
 		handlebars.register_escape_fn(|s| -> String { s.to_string() });
 
@@ -1333,6 +1401,55 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn host_function_weights_template_works() {
+		const TEMPLATE: &str =
+			include_str!("../../../../../.maintain/host-function-weights-template.hbs");
+		let mut handlebars = handlebars::Handlebars::new();
+		handlebars.register_helper("underscore", Box::new(UnderscoreHelper));
+		handlebars.register_helper("pascal_case", Box::new(PascalCaseHelper));
+		handlebars.register_escape_fn(|s| -> String { s.to_string() });
+
+		let benchmark = BenchmarkData {
+			name: "sr25519_verify".into(),
+			component_weight: vec![ComponentSlope {
+				name: "i".into(),
+				slope: 45_000_000,
+				error: 0,
+			}],
+			..Default::default()
+		};
+		let hbs_data = TemplateData { benchmarks: vec![benchmark], ..Default::default() };
+		let output = handlebars.render_template(TEMPLATE, &hbs_data).unwrap();
+		assert!(output.contains(
+			"pub const Sr25519VerifyWeight: Weight = Weight::from_parts(45_000_000, 0);"
+		));
+	}
+
+	#[test]
+	fn mark_pov_bound_works() {
+		let benchmark = |base_weight, base_calculated_proof_size| BenchmarkData {
+			base_weight,
+			base_calculated_proof_size,
+			..Default::default()
+		};
+		let mut benchmarks = vec![benchmark(1_000, 10), benchmark(1_000, 1_000)];
+		benchmarks.push(BenchmarkData {
+			component_calculated_proof_size: vec![ComponentSlope {
+				name: "n".into(),
+				slope: 100,
+				error: 0,
+			}],
+			component_ranges: vec![ComponentRange { name: "n".into(), min: 0, max: 10 }],
+			..benchmark(1_000, 10)
+		});
+
+		// 1 ps of ref time is worth as much as 1 byte of proof size.
+		mark_pov_bound(&mut benchmarks, 1_000_000, 1_000_000);
+
+		assert_eq!(benchmarks.iter().map(|b| b.pov_bound).collect::<Vec<_>>(), [false, false, true]);
+	}
+
 	#[test]
 	fn easy_log_16_works() {
 		assert_eq!(easy_log_16(0), 0);
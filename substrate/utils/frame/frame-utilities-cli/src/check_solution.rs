@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `check-solution` subcommand
+
+use clap::Parser;
+use codec::Decode;
+use pallet_election_provider_multi_phase::{
+	unsigned::MinerVoterOf, ElectionCompute, Miner, MinerConfig, RawSolution, RoundSnapshot,
+	SolutionOf,
+};
+use sc_cli::Error;
+use sp_npos_elections::ElectionScore;
+use std::path::{Path, PathBuf};
+
+/// The `check-solution` command
+#[derive(Debug, Parser)]
+#[command(
+	name = "check-solution",
+	about = "Check the feasibility of an election solution against the snapshot of its round"
+)]
+pub struct CheckSolutionCmd {
+	/// Path to the SCALE encoded `RawSolution`, as submitted to the multi-phase election
+	/// provider. Either raw bytes or a `0x` prefixed hex string.
+	#[arg(long, value_name = "PATH")]
+	pub solution: PathBuf,
+
+	/// Path to the SCALE encoded `Snapshot` of the round. Either raw bytes or a `0x` prefixed hex
+	/// string, e.g. as returned by the `state_getStorage` RPC.
+	#[arg(long, value_name = "PATH")]
+	pub snapshot: PathBuf,
+
+	/// The number of winners the solution must elect, as stored in `DesiredTargets`.
+	#[arg(long)]
+	pub desired_targets: u32,
+
+	/// The current round, as stored in `Round`. Defaults to the round of the solution.
+	#[arg(long)]
+	pub round: Option<u32>,
+
+	/// The hex encoded minimum score of untrusted solutions, as stored in
+	/// `MinimumUntrustedScore`.
+	#[arg(long, value_name = "HEX")]
+	pub minimum_untrusted_score: Option<String>,
+}
+
+impl CheckSolutionCmd {
+	/// runs the command
+	pub fn run<T: MinerConfig>(&self) -> Result<(), Error> {
+		let raw_solution = RawSolution::<SolutionOf<T>>::decode(&mut &read(&self.solution)?[..])?;
+		let snapshot = RoundSnapshot::<T::AccountId, MinerVoterOf<T>>::decode(
+			&mut &read(&self.snapshot)?[..],
+		)?;
+		let minimum_untrusted_score = self
+			.minimum_untrusted_score
+			.as_ref()
+			.map(|hex| -> Result<_, Error> {
+				Ok(ElectionScore::decode(&mut &array_bytes::hex2bytes(hex)?[..])?)
+			})
+			.transpose()?;
+		let round = self.round.unwrap_or(raw_solution.round);
+
+		let ready_solution = Miner::<T>::feasibility_check(
+			raw_solution,
+			ElectionCompute::Signed,
+			self.desired_targets,
+			snapshot,
+			round,
+			minimum_untrusted_score,
+		)
+		.map_err(|e| format!("The solution is not feasible: {:?}", e))?;
+
+		println!(
+			"The solution is feasible: {} winners, score {:?}",
+			ready_solution.supports.len(),
+			ready_solution.score,
+		);
+		Ok(())
+	}
+}
+
+/// Read the SCALE encoded value at `path`, hex decoding it if needed.
+fn read(path: &Path) -> Result<Vec<u8>, Error> {
+	let content = std::fs::read(path)?;
+	match std::str::from_utf8(&content).map(str::trim) {
+		Ok(hex) if hex.starts_with("0x") => Ok(array_bytes::hex2bytes(hex)?),
+		_ => Ok(content),
+	}
+}
@@ -17,6 +17,8 @@
 
 //! frame-system CLI utilities
 
+mod check_solution;
 mod pallet_id;
 
+pub use check_solution::CheckSolutionCmd;
 pub use pallet_id::PalletIdCmd;
@@ -0,0 +1,348 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reproducible build attestations of wasm binaries.
+//!
+//! When the `WASM_BUILD_ATTESTATION` environment variable is set to `1`, the wasm builder writes
+//! an attestation next to the final wasm binary. It records the toolchain and flags the binary
+//! was built with, a hash of the sources it was built from and the blake2-256 hash of the binary
+//! itself, which is the hash a runtime upgrade is authorized with.
+//!
+//! Anybody can rebuild the runtime from the claimed commit and compare the resulting attestation
+//! with the published one using [`Attestation::ensure_reproduces`], and check that a binary
+//! matches an attestation using [`verify_attestation`].
+
+use std::{
+	fs,
+	path::{Component, Path, PathBuf},
+	process::Command,
+};
+use toml::value::{Table, Value};
+
+/// The extension of the attestation files written next to the wasm binaries.
+pub const ATTESTATION_FILE_EXTENSION: &str = "attestation.toml";
+
+/// The attestation of a wasm binary built by the wasm builder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attestation {
+	/// The version of `rustc` used to build the binary.
+	pub rustc_version: String,
+	/// The `RUSTFLAGS` passed to the build.
+	pub rustflags: String,
+	/// The cargo profile the binary was built with.
+	pub profile: String,
+	/// The git commit of the workspace the binary was built from, if it is a git repository.
+	pub git_commit: Option<String>,
+	/// The blake2-256 hash of the sources the binary was built from.
+	///
+	/// Covers the `Cargo.lock` and all the files of the workspace packages the runtime depends
+	/// on. Packages from outside of the workspace are pinned by the `Cargo.lock`.
+	pub source_hash: [u8; 32],
+	/// The blake2-256 hash of the wasm binary.
+	pub wasm_hash: [u8; 32],
+}
+
+impl Attestation {
+	/// Serialize the attestation to TOML.
+	pub fn to_toml(&self) -> String {
+		let mut table = Table::new();
+		table.insert("rustc_version".into(), self.rustc_version.clone().into());
+		table.insert("rustflags".into(), self.rustflags.clone().into());
+		table.insert("profile".into(), self.profile.clone().into());
+		if let Some(git_commit) = &self.git_commit {
+			table.insert("git_commit".into(), git_commit.clone().into());
+		}
+		table.insert("source_hash".into(), to_hex(&self.source_hash).into());
+		table.insert("wasm_hash".into(), to_hex(&self.wasm_hash).into());
+
+		toml::to_string_pretty(&table).expect("Serializing a table of strings can not fail; qed")
+	}
+
+	/// Parse an attestation from TOML.
+	pub fn from_toml(toml: &str) -> Result<Self, String> {
+		let table: Table =
+			toml::from_str(toml).map_err(|e| format!("Invalid attestation: {}", e))?;
+
+		let string = |key: &str| -> Result<Option<String>, String> {
+			match table.get(key) {
+				None => Ok(None),
+				Some(Value::String(value)) => Ok(Some(value.clone())),
+				Some(_) => Err(format!("Invalid attestation: `{}` is not a string", key)),
+			}
+		};
+		let required = |key: &str| -> Result<String, String> {
+			string(key)?.ok_or_else(|| format!("Invalid attestation: `{}` is missing", key))
+		};
+		let hash = |key: &str| -> Result<[u8; 32], String> {
+			from_hex(&required(key)?)
+				.ok_or_else(|| format!("Invalid attestation: `{}` is not a 32 byte hex hash", key))
+		};
+
+		Ok(Self {
+			rustc_version: required("rustc_version")?,
+			rustflags: required("rustflags")?,
+			profile: required("profile")?,
+			git_commit: string("git_commit")?,
+			source_hash: hash("source_hash")?,
+			wasm_hash: hash("wasm_hash")?,
+		})
+	}
+
+	/// Read an attestation from the file at `path`.
+	pub fn read(path: &Path) -> Result<Self, String> {
+		let toml = fs::read_to_string(path)
+			.map_err(|e| format!("Failed to read `{}`: {}", path.display(), e))?;
+		Self::from_toml(&toml)
+	}
+
+	/// Ensure that `rebuilt`, the attestation of an independent build, reproduces this attestation.
+	///
+	/// Returns an error listing all the fields that differ. The git commit is not compared, as it
+	/// is implied by the sources.
+	pub fn ensure_reproduces(&self, rebuilt: &Self) -> Result<(), String> {
+		let mut mismatches = Vec::new();
+		if self.rustc_version != rebuilt.rustc_version {
+			mismatches.push("rustc_version");
+		}
+		if self.rustflags != rebuilt.rustflags {
+			mismatches.push("rustflags");
+		}
+		if self.profile != rebuilt.profile {
+			mismatches.push("profile");
+		}
+		if self.source_hash != rebuilt.source_hash {
+			mismatches.push("source_hash");
+		}
+		if self.wasm_hash != rebuilt.wasm_hash {
+			mismatches.push("wasm_hash");
+		}
+
+		if mismatches.is_empty() {
+			Ok(())
+		} else {
+			Err(format!("The rebuilt attestation differs in: {}", mismatches.join(", ")))
+		}
+	}
+}
+
+/// Verify that the wasm binary at `wasm` is the one attested by the attestation file at
+/// `attestation`.
+///
+/// Returns the attestation on success, so that the caller can compare it with its own build using
+/// [`Attestation::ensure_reproduces`].
+pub fn verify_attestation(attestation: &Path, wasm: &Path) -> Result<Attestation, String> {
+	let attestation = Attestation::read(attestation)?;
+	let wasm_hash = hash_file(wasm)?;
+
+	if attestation.wasm_hash != wasm_hash {
+		return Err(format!(
+			"The hash of `{}` is 0x{}, but the attestation is for 0x{}",
+			wasm.display(),
+			to_hex(&wasm_hash),
+			to_hex(&attestation.wasm_hash),
+		))
+	}
+
+	Ok(attestation)
+}
+
+/// Returns whether attestations should be written.
+pub(crate) fn attestation_enabled() -> bool {
+	crate::get_bool_environment_variable(crate::WASM_BUILD_ATTESTATION).unwrap_or(false)
+}
+
+/// Create the attestation of `wasm`, built from the `source_files` of the workspace at
+/// `workspace_root`, and write it next to `wasm` with the [`ATTESTATION_FILE_EXTENSION`].
+///
+/// Returns the path of the written attestation.
+pub(crate) fn write_attestation(
+	wasm: &Path,
+	workspace_root: &Path,
+	source_files: &[PathBuf],
+	rustc_version: &str,
+	rustflags: &str,
+	profile: &str,
+) -> PathBuf {
+	let attestation = Attestation {
+		rustc_version: rustc_version.into(),
+		rustflags: rustflags.into(),
+		profile: profile.into(),
+		git_commit: git_commit(workspace_root),
+		source_hash: source_hash(workspace_root, source_files),
+		wasm_hash: hash_file(wasm).unwrap_or_else(|e| panic!("{}", e)),
+	};
+
+	let path = attestation_path(wasm);
+	crate::write_file_if_changed(&path, attestation.to_toml());
+	path
+}
+
+/// Returns the path of the attestation of `wasm`.
+pub(crate) fn attestation_path(wasm: &Path) -> PathBuf {
+	let name = wasm
+		.file_name()
+		.and_then(|n| n.to_str())
+		.expect("Wasm binaries have a valid file name; qed");
+	let stem = name.split('.').next().unwrap_or(name);
+	wasm.with_file_name(format!("{}.{}", stem, ATTESTATION_FILE_EXTENSION))
+}
+
+/// Hash the `files` that are part of the workspace at `workspace_root`.
+///
+/// Every file contributes its path relative to the workspace root and the hash of its content,
+/// so that the hash does not depend on where the workspace is checked out. Files outside of the
+/// workspace are ignored.
+fn source_hash(workspace_root: &Path, files: &[PathBuf]) -> [u8; 32] {
+	let mut files = files
+		.iter()
+		.filter_map(|file| Some((relative_path(workspace_root, file)?, file)))
+		.collect::<Vec<_>>();
+	files.sort();
+	files.dedup();
+
+	let mut input = Vec::new();
+	for (relative, file) in files {
+		input.extend_from_slice(relative.as_bytes());
+		input.push(0);
+		input.extend_from_slice(&hash_file(file).unwrap_or_else(|e| panic!("{}", e)));
+	}
+
+	sp_core_hashing::blake2_256(&input)
+}
+
+/// Returns the path of `file` relative to `root`, with `/` as separator.
+fn relative_path(root: &Path, file: &Path) -> Option<String> {
+	let components = file
+		.strip_prefix(root)
+		.ok()?
+		.components()
+		.map(|c| match c {
+			Component::Normal(c) => c.to_str(),
+			_ => None,
+		})
+		.collect::<Option<Vec<_>>>()?;
+	Some(components.join("/"))
+}
+
+/// Returns the blake2-256 hash of the content of `file`.
+fn hash_file(file: &Path) -> Result<[u8; 32], String> {
+	fs::read(file)
+		.map(|content| sp_core_hashing::blake2_256(&content))
+		.map_err(|e| format!("Failed to read `{}`: {}", file.display(), e))
+}
+
+/// Returns the commit checked out in the git repository at `workspace_root`.
+fn git_commit(workspace_root: &Path) -> Option<String> {
+	let output = Command::new("git")
+		.args(["rev-parse", "HEAD"])
+		.current_dir(workspace_root)
+		.output()
+		.ok()?;
+	if !output.status.success() {
+		return None
+	}
+	String::from_utf8(output.stdout).ok().map(|c| c.trim().into())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<[u8; 32]> {
+	let hex = hex.strip_prefix("0x").unwrap_or(hex);
+	if hex.len() != 64 || !hex.is_ascii() {
+		return None
+	}
+	let mut bytes = [0u8; 32];
+	for (i, byte) in bytes.iter_mut().enumerate() {
+		*byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+	}
+	Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn attestation(wasm_hash: [u8; 32]) -> Attestation {
+		Attestation {
+			rustc_version: "rustc 1.74.0 (79e9716c9 2023-11-13)".into(),
+			rustflags: "-C target-cpu=mvp".into(),
+			profile: "production".into(),
+			git_commit: Some("44af46b".into()),
+			source_hash: [1; 32],
+			wasm_hash,
+		}
+	}
+
+	#[test]
+	fn toml_roundtrip_works() {
+		let attestation = attestation([2; 32]);
+		assert_eq!(Attestation::from_toml(&attestation.to_toml()), Ok(attestation.clone()));
+
+		let without_commit = Attestation { git_commit: None, ..attestation };
+		assert_eq!(Attestation::from_toml(&without_commit.to_toml()), Ok(without_commit));
+	}
+
+	#[test]
+	fn verify_attestation_checks_the_wasm_hash() {
+		let dir = tempfile::tempdir().unwrap();
+		let wasm = dir.path().join("runtime.compact.compressed.wasm");
+		fs::write(&wasm, b"wasm").unwrap();
+
+		let path = attestation_path(&wasm);
+		assert_eq!(path, dir.path().join("runtime.attestation.toml"));
+
+		fs::write(&path, attestation(sp_core_hashing::blake2_256(b"wasm")).to_toml()).unwrap();
+		assert!(verify_attestation(&path, &wasm).is_ok());
+
+		fs::write(&wasm, b"other wasm").unwrap();
+		assert!(verify_attestation(&path, &wasm).is_err());
+	}
+
+	#[test]
+	fn source_hash_does_not_depend_on_the_checkout_location() {
+		let checkout = |dir: &Path| {
+			fs::create_dir_all(dir.join("src")).unwrap();
+			fs::write(dir.join("Cargo.lock"), b"lock").unwrap();
+			fs::write(dir.join("src/lib.rs"), b"code").unwrap();
+			let outside = dir.parent().unwrap().join("outside.rs");
+			vec![dir.join("src/lib.rs"), dir.join("Cargo.lock"), outside]
+		};
+
+		let dir = tempfile::tempdir().unwrap();
+		let (a, b) = (dir.path().join("a"), dir.path().join("b"));
+		let (files_a, files_b) = (checkout(&a), checkout(&b));
+		assert_eq!(source_hash(&a, &files_a), source_hash(&b, &files_b));
+
+		fs::write(b.join("src/lib.rs"), b"changed").unwrap();
+		assert_ne!(source_hash(&a, &files_a), source_hash(&b, &files_b));
+	}
+
+	#[test]
+	fn ensure_reproduces_lists_the_differences() {
+		let claimed = attestation([2; 32]);
+		let rebuilt = Attestation { git_commit: None, ..claimed.clone() };
+		assert!(claimed.ensure_reproduces(&rebuilt).is_ok());
+
+		let rebuilt = Attestation { profile: "release".into(), ..attestation([3; 32]) };
+		assert_eq!(
+			claimed.ensure_reproduces(&rebuilt),
+			Err("The rebuilt attestation differs in: profile, wasm_hash".into()),
+		);
+	}
+}
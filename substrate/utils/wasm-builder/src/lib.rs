@@ -90,6 +90,10 @@
 //! - `WASM_BUILD_STD` - Sets whether the Rust's standard library crates will also be built. This is
 //!   necessary to make sure the standard library crates only use the exact WASM feature set that
 //!   our executor supports. Enabled by default.
+//! - `WASM_BUILD_ATTESTATION` - If set to `1`, writes a reproducible build attestation (rustc
+//!   version, flags, source hash and blake2-256 hash of the binary) next to the final Wasm binary
+//!   as `<name>.attestation.toml`. See [`verify_attestation`] for checking it. Disabled by
+//!   default.
 //! - `CARGO_NET_OFFLINE` - If `true`, `--offline` will be passed to all processes launched to
 //!   prevent network access. Useful in offline environments.
 //!
@@ -120,11 +124,13 @@ use std::{
 };
 use version::Version;
 
+mod attestation;
 mod builder;
 mod prerequisites;
 mod version;
 mod wasm_project;
 
+pub use attestation::{verify_attestation, Attestation, ATTESTATION_FILE_EXTENSION};
 pub use builder::{WasmBuilder, WasmBuilderSelectProject};
 
 /// Environment variable that tells us to skip building the wasm binary.
@@ -164,6 +170,9 @@ const WASM_BUILD_WORKSPACE_HINT: &str = "WASM_BUILD_WORKSPACE_HINT";
 /// Environment variable to set whether we'll build `core`/`std`.
 const WASM_BUILD_STD: &str = "WASM_BUILD_STD";
 
+/// Environment variable to enable writing a build attestation next to the wasm binary.
+const WASM_BUILD_ATTESTATION: &str = "WASM_BUILD_ATTESTATION";
+
 /// Write to the given `file` if the `content` is different.
 fn write_file_if_changed(file: impl AsRef<Path>, content: impl AsRef<str>) {
 	if fs::read_to_string(file.as_ref()).ok().as_deref() != Some(content.as_ref()) {
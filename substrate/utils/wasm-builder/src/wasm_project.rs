@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{write_file_if_changed, CargoCommandVersioned, OFFLINE};
+use crate::{
+	attestation::{attestation_enabled, write_attestation, ATTESTATION_FILE_EXTENSION},
+	write_file_if_changed, CargoCommandVersioned, OFFLINE,
+};
 
 use build_helper::rerun_if_changed;
 use cargo_metadata::{DependencyKind, Metadata, MetadataCommand};
@@ -133,8 +136,11 @@ pub(crate) fn create_and_compile(
 
 	let build_config = BuildConfiguration::detect(&project);
 
+	let rustflags = wasm_rustflags(default_rustflags);
+	let rustc_version = cargo_cmd.rustc_version().to_owned();
+
 	// Build the bloaty runtime blob
-	build_bloaty_blob(&build_config.blob_build_profile, &project, default_rustflags, cargo_cmd);
+	build_bloaty_blob(&build_config.blob_build_profile, &project, &rustflags, cargo_cmd);
 
 	// Get the name of the bloaty runtime blob.
 	let bloaty_blob_default_name = get_blob_name(project_cargo_toml);
@@ -183,7 +189,7 @@ pub(crate) fn create_and_compile(
 
 	let final_blob_binary = compact_compressed_blob_path.or(compact_blob_path);
 
-	generate_rerun_if_changed_instructions(
+	let source_files = generate_rerun_if_changed_instructions(
 		project_cargo_toml,
 		&project,
 		&runtime_workspace,
@@ -191,6 +197,20 @@ pub(crate) fn create_and_compile(
 		&bloaty_blob_binary,
 	);
 
+	if let Some(final_blob_binary) = final_blob_binary.as_ref() {
+		if attestation_enabled() {
+			let attestation = write_attestation(
+				final_blob_binary.wasm_binary_path(),
+				crate_metadata.workspace_root.as_ref(),
+				&source_files,
+				&rustc_version,
+				&rustflags,
+				build_config.blob_build_profile.name(),
+			);
+			copy_attestation_to_target_directory(project_cargo_toml, &attestation);
+		}
+	}
+
 	if let Err(err) = adjust_mtime(&bloaty_blob_binary, final_blob_binary.as_ref()) {
 		build_helper::warning!("Error while adjusting the mtime of the blob binaries: {}", err)
 	}
@@ -708,22 +728,25 @@ fn offline_build() -> bool {
 	env::var(OFFLINE).map_or(false, |v| v == "true")
 }
 
+/// Returns the `RUSTFLAGS` to build the wasm binary with.
+fn wasm_rustflags(default_rustflags: &str) -> String {
+	format!(
+		"-C target-cpu=mvp -C target-feature=-sign-ext -C link-arg=--export-table {} {}",
+		default_rustflags,
+		env::var(crate::WASM_BUILD_RUSTFLAGS_ENV).unwrap_or_default(),
+	)
+}
+
 /// Build the project and create the bloaty runtime blob.
 fn build_bloaty_blob(
 	blob_build_profile: &Profile,
 	project: &Path,
-	default_rustflags: &str,
+	rustflags: &str,
 	cargo_cmd: CargoCommandVersioned,
 ) {
 	let manifest_path = project.join("Cargo.toml");
 	let mut build_cmd = cargo_cmd.command();
 
-	let rustflags = format!(
-		"-C target-cpu=mvp -C target-feature=-sign-ext -C link-arg=--export-table {} {}",
-		default_rustflags,
-		env::var(crate::WASM_BUILD_RUSTFLAGS_ENV).unwrap_or_default(),
-	);
-
 	build_cmd
 		.args(&["rustc", "--target=wasm32-unknown-unknown"])
 		.arg(format!("--manifest-path={}", manifest_path.display()))
@@ -903,16 +926,22 @@ fn create_metadata_command(path: impl Into<PathBuf>) -> MetadataCommand {
 
 /// Generate the `rerun-if-changed` instructions for cargo to make sure that the WASM binary is
 /// rebuilt when needed.
+///
+/// Returns the tracked source files, i.e. the `Cargo.lock` and the files of all the packages the
+/// WASM binary is built from.
 fn generate_rerun_if_changed_instructions(
 	cargo_manifest: &Path,
 	project_folder: &Path,
 	wasm_workspace: &Path,
 	compressed_or_compact_wasm: Option<&WasmBinary>,
 	bloaty_wasm: &WasmBinaryBloaty,
-) {
+) -> Vec<PathBuf> {
+	let mut source_files = Vec::new();
+
 	// Rerun `build.rs` if the `Cargo.lock` changes
 	if let Some(cargo_lock) = find_cargo_lock(cargo_manifest) {
-		rerun_if_changed(cargo_lock);
+		rerun_if_changed(&cargo_lock);
+		source_files.push(cargo_lock);
 	}
 
 	let metadata = create_metadata_command(project_folder.join("Cargo.toml"))
@@ -960,7 +989,11 @@ fn generate_rerun_if_changed_instructions(
 	}
 
 	// Make sure that if any file/folder of a dependency change, we need to rerun the `build.rs`
-	packages.iter().for_each(package_rerun_if_changed);
+	for package in &packages {
+		let files = package_source_files(package);
+		files.iter().for_each(rerun_if_changed);
+		source_files.extend(files);
+	}
 
 	compressed_or_compact_wasm.map(|w| rerun_if_changed(w.wasm_binary_path()));
 	rerun_if_changed(bloaty_wasm.bloaty_path());
@@ -972,10 +1005,14 @@ fn generate_rerun_if_changed_instructions(
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_TARGET_DIRECTORY);
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_TOOLCHAIN);
 	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_STD);
+	println!("cargo:rerun-if-env-changed={}", crate::WASM_BUILD_ATTESTATION);
+
+	source_files
 }
 
-/// Track files and paths related to the given package to rerun `build.rs` on any relevant change.
-fn package_rerun_if_changed(package: &DeduplicatePackage) {
+/// Returns the files related to the given package, which are tracked to rerun `build.rs` on any
+/// relevant change.
+fn package_source_files(package: &DeduplicatePackage) -> Vec<PathBuf> {
 	let mut manifest_path = package.manifest_path.clone();
 	if manifest_path.ends_with("Cargo.toml") {
 		manifest_path.pop();
@@ -992,7 +1029,7 @@ fn package_rerun_if_changed(package: &DeduplicatePackage) {
 		})
 		.filter_map(|p| p.ok().map(|p| p.into_path()))
 		.filter(|p| p.extension().map(|e| e == "rs" || e == "toml").unwrap_or_default())
-		.for_each(rerun_if_changed);
+		.collect()
 }
 
 /// Copy the blob binary to the target directory set in `WASM_TARGET_DIRECTORY` environment
@@ -1021,3 +1058,17 @@ fn copy_blob_to_target_directory(cargo_manifest: &Path, blob_binary: &WasmBinary
 	)
 	.expect("Copies blob binary to `WASM_TARGET_DIRECTORY`.");
 }
+
+/// Copy the attestation of the blob binary to the target directory set in
+/// `WASM_TARGET_DIRECTORY` environment variable. If the variable is not set, this is a no-op.
+fn copy_attestation_to_target_directory(cargo_manifest: &Path, attestation: &Path) {
+	let Ok(target_dir) = env::var(crate::WASM_TARGET_DIRECTORY) else { return };
+
+	// `copy_blob_to_target_directory` already ensured that the directory is valid and exists.
+	fs::copy(
+		attestation,
+		PathBuf::from(target_dir)
+			.join(format!("{}.{}", get_blob_name(cargo_manifest), ATTESTATION_FILE_EXTENSION)),
+	)
+	.expect("Copies attestation to `WASM_TARGET_DIRECTORY`.");
+}